@@ -0,0 +1,83 @@
+/**
+ * Cancellation Proof Circuit for RWA Dark Pool
+ *
+ * Verifies:
+ * 1. The prover knows the secrets of an order commitment
+ * 2. The commitment is in the orderbook's commitment tree
+ * 3. The unlocked amount is what the order locked
+ * 4. The cancel nullifier is correctly computed
+ *
+ * The commitment itself stays private, so a cancellation does not reveal
+ * which resting order belonged to the canceller.
+ */
+pragma circom 2.1.0;
+
+include "circomlib/circuits/poseidon.circom";
+include "../merkle/merkle_proof.circom";
+
+/**
+ * Cancellation Proof Template
+ * @param TREE_DEPTH - Order commitment Merkle tree depth
+ */
+template CancelProof(TREE_DEPTH) {
+    /** PRIVATE INPUTS (known only to prover) */
+
+    /** Order contents and secrets: Poseidon(asset, side, qty, price, nonce, secret) */
+    signal input assetHash;
+    signal input side;
+    signal input quantity;
+    signal input price;
+    signal input orderNonce;
+    signal input orderSecret;
+
+    /** Commitment tree membership */
+    signal input commitmentProof[TREE_DEPTH];
+    signal input commitmentIndices[TREE_DEPTH];
+
+    /** PUBLIC INPUTS (visible on-chain) */
+    signal input orderRoot;
+    signal input unlockAmount;
+    signal input traderHash;
+
+    /** PUBLIC OUTPUT */
+    signal output cancelNullifier;
+
+    /** 1. Recompute the order commitment */
+    component commitHasher = Poseidon(6);
+    commitHasher.inputs[0] <== assetHash;
+    commitHasher.inputs[1] <== side;
+    commitHasher.inputs[2] <== quantity;
+    commitHasher.inputs[3] <== price;
+    commitHasher.inputs[4] <== orderNonce;
+    commitHasher.inputs[5] <== orderSecret;
+
+    /** 2. Verify the commitment is in the order tree */
+    component commitmentMerkle = MerkleTreeVerifier(TREE_DEPTH);
+    commitmentMerkle.leaf <== commitHasher.out;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        commitmentMerkle.pathElements[i] <== commitmentProof[i];
+        commitmentMerkle.pathIndices[i] <== commitmentIndices[i];
+    }
+    commitmentMerkle.expectedRoot <== orderRoot;
+
+    /** 3. Buy orders lock the payment (the execution price), sell orders the quantity */
+    side * (side - 1) === 0;
+    unlockAmount === price + side * (quantity - price);
+
+    /** 4. Cancel nullifier: Poseidon(commitment, secret), distinct from settlement nullifiers */
+    component nullifierHasher = Poseidon(2);
+    nullifierHasher.inputs[0] <== commitHasher.out;
+    nullifierHasher.inputs[1] <== orderSecret;
+    cancelNullifier <== nullifierHasher.out;
+
+    /** 5. Bind the proof to the cancelling trader */
+    signal traderSquare;
+    traderSquare <== traderHash * traderHash;
+}
+
+/** Tree depth = 20, matching the whitelist tree */
+component main {public [
+    orderRoot,
+    unlockAmount,
+    traderHash
+]} = CancelProof(20);
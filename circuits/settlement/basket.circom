@@ -0,0 +1,110 @@
+/**
+ * Basket Settlement Proof Template for RWA Dark Pool
+ *
+ * Shared by basket_proof.circom and basket_proof_bound.circom.
+ *
+ * Verifies:
+ * 1. Buyer and seller are on the whitelist (Merkle proofs)
+ * 2. Both order commitments open to the same basket of legs
+ * 3. Nullifier is correctly computed
+ *
+ * Public inputs are declared in the order the settlement contract reads
+ * them: whitelistRoot, buyCommitment, sellCommitment, then assetHash,
+ * quantity and price for each leg.
+ */
+pragma circom 2.1.0;
+
+include "circomlib/circuits/poseidon.circom";
+include "../merkle/merkle_proof.circom";
+
+/**
+ * Basket Settlement Proof Template
+ * @param TREE_DEPTH - Whitelist Merkle tree depth (matches registry)
+ * @param LEGS - Number of asset legs in the basket
+ */
+template BasketProof(TREE_DEPTH, LEGS) {
+    /** PRIVATE INPUTS (known only to prover) */
+
+    /** Buyer's whitelist proof */
+    signal input buyerIdHash;
+    signal input buyerMerkleProof[TREE_DEPTH];
+    signal input buyerMerkleIndices[TREE_DEPTH];
+
+    /** Seller's whitelist proof */
+    signal input sellerIdHash;
+    signal input sellerMerkleProof[TREE_DEPTH];
+    signal input sellerMerkleIndices[TREE_DEPTH];
+
+    /** Order secrets for commitment verification */
+    signal input buyOrderSecret;
+    signal input buyOrderNonce;
+    signal input sellOrderSecret;
+    signal input sellOrderNonce;
+
+    /** PUBLIC INPUTS (visible on-chain) */
+    signal input whitelistRoot;
+    signal input buyCommitment;
+    signal input sellCommitment;
+    /** Per leg: assetHash, quantity, price */
+    signal input legs[LEGS][3];
+
+    /** PUBLIC OUTPUT */
+    signal output nullifierHash;
+
+    /** 1. Verify buyer is on whitelist */
+    component buyerMerkle = MerkleTreeVerifier(TREE_DEPTH);
+    buyerMerkle.leaf <== buyerIdHash;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        buyerMerkle.pathElements[i] <== buyerMerkleProof[i];
+        buyerMerkle.pathIndices[i] <== buyerMerkleIndices[i];
+    }
+    buyerMerkle.expectedRoot <== whitelistRoot;
+
+    /** 2. Verify seller is on whitelist */
+    component sellerMerkle = MerkleTreeVerifier(TREE_DEPTH);
+    sellerMerkle.leaf <== sellerIdHash;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        sellerMerkle.pathElements[i] <== sellerMerkleProof[i];
+        sellerMerkle.pathIndices[i] <== sellerMerkleIndices[i];
+    }
+    sellerMerkle.expectedRoot <== whitelistRoot;
+
+    /** 3. Hash the basket: chain of Poseidon(assetHash, quantity, price) per leg */
+    component legHashers[LEGS];
+    component chainHashers[LEGS];
+    signal basketHash[LEGS + 1];
+    basketHash[0] <== 0;
+    for (var i = 0; i < LEGS; i++) {
+        legHashers[i] = Poseidon(3);
+        for (var j = 0; j < 3; j++) {
+            legHashers[i].inputs[j] <== legs[i][j];
+        }
+        chainHashers[i] = Poseidon(2);
+        chainHashers[i].inputs[0] <== basketHash[i];
+        chainHashers[i].inputs[1] <== legHashers[i].out;
+        basketHash[i + 1] <== chainHashers[i].out;
+    }
+
+    /** 4. Verify buy order commitment: Poseidon(basket, side=0, nonce, secret) */
+    component buyCommitHasher = Poseidon(4);
+    buyCommitHasher.inputs[0] <== basketHash[LEGS];
+    buyCommitHasher.inputs[1] <== 0;
+    buyCommitHasher.inputs[2] <== buyOrderNonce;
+    buyCommitHasher.inputs[3] <== buyOrderSecret;
+    buyCommitHasher.out === buyCommitment;
+
+    /** 5. Verify sell order commitment: Poseidon(basket, side=1, nonce, secret) */
+    component sellCommitHasher = Poseidon(4);
+    sellCommitHasher.inputs[0] <== basketHash[LEGS];
+    sellCommitHasher.inputs[1] <== 1;
+    sellCommitHasher.inputs[2] <== sellOrderNonce;
+    sellCommitHasher.inputs[3] <== sellOrderSecret;
+    sellCommitHasher.out === sellCommitment;
+
+    /** 6. Compute nullifier: Poseidon(buyCommit, sellCommit, combinedSecret) */
+    component nullifierHasher = Poseidon(3);
+    nullifierHasher.inputs[0] <== buyCommitment;
+    nullifierHasher.inputs[1] <== sellCommitment;
+    nullifierHasher.inputs[2] <== buyOrderSecret + sellOrderSecret;
+    nullifierHash <== nullifierHasher.out;
+}
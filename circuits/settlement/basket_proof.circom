@@ -0,0 +1,16 @@
+/**
+ * Basket Settlement Proof Circuit for RWA Dark Pool
+ *
+ * See basket.circom for the constraints. Each basket size needs its own
+ * key; this build covers four legs.
+ */
+pragma circom 2.1.0;
+
+include "basket.circom";
+
+component main {public [
+    whitelistRoot,
+    buyCommitment,
+    sellCommitment,
+    legs
+]} = BasketProof(20, 4);
@@ -0,0 +1,70 @@
+/**
+ * Domain-bound Basket Settlement Proof Circuit
+ *
+ * Same constraints as basket_proof.circom, plus a public poolDomain after
+ * the legs, folded into the nullifier. The settlement contract checks
+ * poolDomain against its own `get_domain_separator`, so a basket proof
+ * generated for one pool instance or network cannot be replayed against
+ * another.
+ */
+pragma circom 2.1.0;
+
+include "basket.circom";
+
+template DomainBoundBasketProof(TREE_DEPTH, LEGS) {
+    signal input buyerIdHash;
+    signal input buyerMerkleProof[TREE_DEPTH];
+    signal input buyerMerkleIndices[TREE_DEPTH];
+    signal input sellerIdHash;
+    signal input sellerMerkleProof[TREE_DEPTH];
+    signal input sellerMerkleIndices[TREE_DEPTH];
+    signal input buyOrderSecret;
+    signal input buyOrderNonce;
+    signal input sellOrderSecret;
+    signal input sellOrderNonce;
+
+    signal input whitelistRoot;
+    signal input buyCommitment;
+    signal input sellCommitment;
+    signal input legs[LEGS][3];
+    /** SHA-256 of (tag, network id, contract address) with the top byte cleared */
+    signal input poolDomain;
+
+    signal output nullifierHash;
+
+    component inner = BasketProof(TREE_DEPTH, LEGS);
+    inner.buyerIdHash <== buyerIdHash;
+    inner.sellerIdHash <== sellerIdHash;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        inner.buyerMerkleProof[i] <== buyerMerkleProof[i];
+        inner.buyerMerkleIndices[i] <== buyerMerkleIndices[i];
+        inner.sellerMerkleProof[i] <== sellerMerkleProof[i];
+        inner.sellerMerkleIndices[i] <== sellerMerkleIndices[i];
+    }
+    inner.buyOrderSecret <== buyOrderSecret;
+    inner.buyOrderNonce <== buyOrderNonce;
+    inner.sellOrderSecret <== sellOrderSecret;
+    inner.sellOrderNonce <== sellOrderNonce;
+    inner.whitelistRoot <== whitelistRoot;
+    inner.buyCommitment <== buyCommitment;
+    inner.sellCommitment <== sellCommitment;
+    for (var i = 0; i < LEGS; i++) {
+        for (var j = 0; j < 3; j++) {
+            inner.legs[i][j] <== legs[i][j];
+        }
+    }
+
+    /** Nullifier: Poseidon(unbound nullifier, poolDomain) */
+    component domainHasher = Poseidon(2);
+    domainHasher.inputs[0] <== inner.nullifierHash;
+    domainHasher.inputs[1] <== poolDomain;
+    nullifierHash <== domainHasher.out;
+}
+
+component main {public [
+    whitelistRoot,
+    buyCommitment,
+    sellCommitment,
+    legs,
+    poolDomain
+]} = DomainBoundBasketProof(20, 4);
@@ -0,0 +1,71 @@
+/**
+ * Domain-bound Settlement Proof Circuit
+ *
+ * Same constraints as settlement_proof.circom, plus a public poolDomain
+ * folded into the nullifier. The settlement contract checks poolDomain
+ * against its own `get_domain_separator`, so a proof generated for one
+ * pool instance or network cannot be replayed against another.
+ */
+pragma circom 2.1.0;
+
+include "settlement.circom";
+
+template DomainBoundSettlementProof(TREE_DEPTH) {
+    signal input buyerIdHash;
+    signal input buyerMerkleProof[TREE_DEPTH];
+    signal input buyerMerkleIndices[TREE_DEPTH];
+    signal input sellerIdHash;
+    signal input sellerMerkleProof[TREE_DEPTH];
+    signal input sellerMerkleIndices[TREE_DEPTH];
+    signal input buyOrderSecret;
+    signal input buyOrderNonce;
+    signal input sellOrderSecret;
+    signal input sellOrderNonce;
+
+    signal input buyCommitment;
+    signal input sellCommitment;
+    signal input assetHash;
+    signal input matchedQuantity;
+    signal input executionPrice;
+    signal input whitelistRoot;
+    /** SHA-256 of (tag, network id, contract address) with the top byte cleared */
+    signal input poolDomain;
+
+    signal output nullifierHash;
+
+    component inner = SettlementProof(TREE_DEPTH);
+    inner.buyerIdHash <== buyerIdHash;
+    inner.sellerIdHash <== sellerIdHash;
+    for (var i = 0; i < TREE_DEPTH; i++) {
+        inner.buyerMerkleProof[i] <== buyerMerkleProof[i];
+        inner.buyerMerkleIndices[i] <== buyerMerkleIndices[i];
+        inner.sellerMerkleProof[i] <== sellerMerkleProof[i];
+        inner.sellerMerkleIndices[i] <== sellerMerkleIndices[i];
+    }
+    inner.buyOrderSecret <== buyOrderSecret;
+    inner.buyOrderNonce <== buyOrderNonce;
+    inner.sellOrderSecret <== sellOrderSecret;
+    inner.sellOrderNonce <== sellOrderNonce;
+    inner.buyCommitment <== buyCommitment;
+    inner.sellCommitment <== sellCommitment;
+    inner.assetHash <== assetHash;
+    inner.matchedQuantity <== matchedQuantity;
+    inner.executionPrice <== executionPrice;
+    inner.whitelistRoot <== whitelistRoot;
+
+    /** Nullifier: Poseidon(unbound nullifier, poolDomain) */
+    component domainHasher = Poseidon(2);
+    domainHasher.inputs[0] <== inner.nullifierHash;
+    domainHasher.inputs[1] <== poolDomain;
+    nullifierHash <== domainHasher.out;
+}
+
+component main {public [
+    buyCommitment,
+    sellCommitment,
+    assetHash,
+    matchedQuantity,
+    executionPrice,
+    whitelistRoot,
+    poolDomain
+]} = DomainBoundSettlementProof(20);
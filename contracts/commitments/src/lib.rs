@@ -0,0 +1,111 @@
+#![no_std]
+
+use soroban_sdk::{contract, contractclient, contracterror, contractimpl, Address, BytesN, Env};
+
+#[cfg(test)]
+mod test;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CommitmentError {
+    InvalidAmount = 1,
+    /// An input is not a canonical BN254 scalar
+    InvalidScalar = 2,
+    InvalidOrderSide = 3,
+}
+
+/// Domain binding views of the settlement contract
+#[contractclient(name = "SettlementPoolClient")]
+pub trait SettlementPool {
+    fn is_domain_bound(env: Env) -> bool;
+    fn get_domain_separator(env: Env) -> BytesN<32>;
+}
+
+/// Poseidon helpers mirroring the settlement circuits
+///
+/// Kept apart from the settlement contract so the pool's Wasm does not
+/// carry the Poseidon parameters it never needs on-chain. Both calls are
+/// views meant for simulation.
+#[contract]
+pub struct DarkPoolCommitments;
+
+#[contractimpl]
+impl DarkPoolCommitments {
+    /// Derive the nullifier a settlement proof is expected to output
+    ///
+    /// Diagnostic view for integrators: runs the circuit's nullifier
+    /// derivation with the on-chain Poseidon, folding in the pool's domain
+    /// separator while its domain binding is on, so a prover's output can be
+    /// cross-checked before submitting a real settlement.
+    ///
+    /// # Arguments
+    /// * `pool` - Settlement contract the proof will be submitted to
+    /// * `buy_commitment` - Buy order commitment
+    /// * `sell_commitment` - Sell order commitment
+    /// * `quantity` - Matched quantity
+    /// * `salt` - Sum of the two order secrets in the scalar field
+    pub fn derive_expected_nullifier(
+        env: Env,
+        pool: Address,
+        buy_commitment: BytesN<32>,
+        sell_commitment: BytesN<32>,
+        quantity: i128,
+        salt: BytesN<32>,
+    ) -> Result<BytesN<32>, CommitmentError> {
+        if quantity <= 0 {
+            return Err(CommitmentError::InvalidAmount);
+        }
+        let quantity_signal = Self::amount_signal(&env, quantity);
+        let nullifier = zk_bn254::settlement_nullifier(&env, &buy_commitment, &sell_commitment, &quantity_signal, &salt)
+            .map_err(|_| CommitmentError::InvalidScalar)?;
+        let pool = SettlementPoolClient::new(&env, &pool);
+        if !pool.is_domain_bound() {
+            return Ok(nullifier);
+        }
+        zk_bn254::bind_nullifier(&env, &nullifier, &pool.get_domain_separator())
+            .map_err(|_| CommitmentError::InvalidScalar)
+    }
+
+    /// Compute the order commitment a settlement proof opens
+    ///
+    /// Runs the circuit's `Poseidon(asset, side, qty, price, nonce, secret)`
+    /// with the on-chain Poseidon, so a wallet can check its locally
+    /// computed commitment before locking funds. The order secret is an
+    /// argument: simulate this call, never submit it in a transaction.
+    ///
+    /// # Arguments
+    /// * `side` - 0 for a buy, 1 for a sell
+    /// * `asset_hash` - Poseidon hash of the asset address
+    /// * `quantity` - Order quantity
+    /// * `price` - Order price
+    /// * `nonce` - Order nonce
+    /// * `secret` - Order secret
+    pub fn compute_order_commitment(
+        env: Env,
+        side: u32,
+        asset_hash: BytesN<32>,
+        quantity: i128,
+        price: i128,
+        nonce: BytesN<32>,
+        secret: BytesN<32>,
+    ) -> Result<BytesN<32>, CommitmentError> {
+        if side > 1 {
+            return Err(CommitmentError::InvalidOrderSide);
+        }
+        if quantity <= 0 || price <= 0 {
+            return Err(CommitmentError::InvalidAmount);
+        }
+        let quantity = Self::amount_signal(&env, quantity);
+        let price = Self::amount_signal(&env, price);
+        zk_bn254::order_commitment(&env, &asset_hash, side, &quantity, &price, &nonce, &secret)
+            .map_err(|_| CommitmentError::InvalidScalar)
+    }
+
+    /// Encode a positive amount as a big-endian public signal
+    fn amount_signal(env: &Env, amount: i128) -> BytesN<32> {
+        let mut signal = [0u8; 32];
+        signal[16..].copy_from_slice(&amount.to_be_bytes());
+        BytesN::from_array(env, &signal)
+    }
+}
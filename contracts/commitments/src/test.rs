@@ -0,0 +1,70 @@
+#![cfg(test)]
+
+use super::*;
+use darkpool_settlement::{DarkPoolSettlement, DarkPoolSettlementClient};
+use darkpool_testdata::scalar;
+use soroban_sdk::{testutils::Address as _, Bytes};
+
+fn register_pool(env: &Env, admin: &Address) -> Address {
+    let (registry, verifier) = (Address::generate(env), Address::generate(env));
+    let vk_bytes = Bytes::from_slice(env, &[0u8; 100]);
+    env.register(DarkPoolSettlement, (admin, &registry, &verifier, &vk_bytes))
+}
+
+#[test]
+fn test_derive_expected_nullifier() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let pool_id = register_pool(&env, &admin);
+    let pool = DarkPoolSettlementClient::new(&env, &pool_id);
+    let client = DarkPoolCommitmentsClient::new(&env, &env.register(DarkPoolCommitments, ()));
+
+    let s = |v: u64| BytesN::from_array(&env, &scalar(v));
+    let (buy, sell, salt) = (s(11), s(12), s(30));
+    let unbound = client.derive_expected_nullifier(&pool_id, &buy, &sell, &100, &salt);
+    let expected = zk_bn254::settlement_nullifier(&env, &buy, &sell, &s(100), &salt).unwrap();
+    assert_eq!(unbound, expected);
+
+    // Binding folds the pool's domain into the nullifier
+    pool.set_domain_binding(&admin, &true);
+    let bound = client.derive_expected_nullifier(&pool_id, &buy, &sell, &100, &salt);
+    assert_eq!(bound, zk_bn254::bind_nullifier(&env, &unbound, &pool.get_domain_separator()).unwrap());
+    assert_ne!(bound, unbound);
+
+    let wide = BytesN::from_array(&env, &[0xffu8; 32]);
+    assert_eq!(
+        client.try_derive_expected_nullifier(&pool_id, &wide, &sell, &100, &salt),
+        Err(Ok(CommitmentError::InvalidScalar))
+    );
+    assert_eq!(
+        client.try_derive_expected_nullifier(&pool_id, &buy, &sell, &0, &salt),
+        Err(Ok(CommitmentError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_compute_order_commitment() {
+    let env = Env::default();
+    let client = DarkPoolCommitmentsClient::new(&env, &env.register(DarkPoolCommitments, ()));
+
+    let s = |v: u64| BytesN::from_array(&env, &scalar(v));
+    let buy = client.compute_order_commitment(&0, &s(13), &100, &5_000, &s(7), &s(8));
+    let expected = zk_bn254::order_commitment(&env, &s(13), 0, &s(100), &s(5_000), &s(7), &s(8)).unwrap();
+    assert_eq!(buy, expected);
+    assert_ne!(client.compute_order_commitment(&1, &s(13), &100, &5_000, &s(7), &s(8)), buy);
+
+    let wide = BytesN::from_array(&env, &[0xffu8; 32]);
+    assert_eq!(
+        client.try_compute_order_commitment(&2, &s(13), &100, &5_000, &s(7), &s(8)),
+        Err(Ok(CommitmentError::InvalidOrderSide))
+    );
+    assert_eq!(
+        client.try_compute_order_commitment(&0, &s(13), &100, &0, &s(7), &s(8)),
+        Err(Ok(CommitmentError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_compute_order_commitment(&0, &wide, &100, &5_000, &s(7), &s(8)),
+        Err(Ok(CommitmentError::InvalidScalar))
+    );
+}
@@ -1,8 +1,9 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, vec,
-    Address, Bytes, BytesN, Env, Symbol, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token, vec,
+    Address, Bytes, BytesN, Env, Map, Symbol, Vec,
 };
 
 #[cfg(test)]
@@ -13,7 +14,17 @@ const ADMIN_KEY: Symbol = symbol_short!("admin");
 const REGISTRY_KEY: Symbol = symbol_short!("registry");
 const SETTLEMENT_KEY: Symbol = symbol_short!("settl");
 const ORDERS_KEY: Symbol = symbol_short!("orders");
+const NEXT_INDEX_KEY: Symbol = symbol_short!("next_idx");
 const MATCHES_KEY: Symbol = symbol_short!("matches");
+const BOUNTY_CFG_KEY: Symbol = symbol_short!("bounty");
+const BOUNTY_POOL_KEY: Symbol = symbol_short!("bty_pool");
+const DEPTH_KEY: Symbol = symbol_short!("depth");
+const NOTIONAL_KEY: Symbol = symbol_short!("notional");
+const SCHEME_KEY: Symbol = symbol_short!("cmt_schm");
+const OPEN_SCHEMES_KEY: Symbol = symbol_short!("schm_open");
+
+/// Order commitment scheme: `Poseidon(asset, side, qty, price, nonce, secret)`
+pub const COMMITMENT_SCHEME_V1: u32 = 1;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -29,10 +40,15 @@ pub enum OrderbookError {
     MatchNotFound = 8,
     InvalidOrderSide = 9,
     AssetMismatch = 10,
+    BountyNotConfigured = 11,
+    InvalidAmount = 12,
+    InvalidCommitment = 13,
+    InvalidCommitmentScheme = 14,
+    CommitmentSchemeMismatch = 15,
 }
 
 /// Order side (buy or sell)
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
 #[repr(u32)]
 pub enum OrderSide {
@@ -41,7 +57,7 @@ pub enum OrderSide {
 }
 
 /// Order status
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[contracttype]
 #[repr(u32)]
 pub enum OrderStatus {
@@ -65,6 +81,8 @@ pub struct OrderCommitment {
     pub expiry: u64,
     pub status: OrderStatus,
     pub tree_index: u32,
+    /// Commitment scheme the order was committed under
+    pub scheme_version: u32,
 }
 
 /// Matched trade record
@@ -81,6 +99,38 @@ pub struct MatchRecord {
     pub price: i128,
     pub timestamp: u64,
     pub is_settled: bool,
+    /// Commitment scheme shared by both orders
+    pub scheme_version: u32,
+}
+
+/// Keeper bounty paid for each expired commitment swept
+#[derive(Clone)]
+#[contracttype]
+pub struct SweepBounty {
+    pub token: Address,
+    pub per_order: i128,
+}
+
+/// Aggregate escrow committed to an asset's active orders
+///
+/// Each order contributes its escrow rounded down to a power of ten, so the
+/// totals show roughly how much liquidity rests on each side without
+/// revealing any single order's size. Buy depth is in payment asset units
+/// and sell depth in units of the traded asset. Only orders placed through
+/// `deposit_for_order` are counted, and an expired order is counted until it
+/// is swept.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[contracttype]
+pub struct MarketDepth {
+    pub buy_notional: i128,
+    pub sell_notional: i128,
+}
+
+/// Escrow entry points of the settlement contract used by the orderbook
+#[contractclient(name = "SettlementEscrowClient")]
+pub trait SettlementEscrow {
+    fn deposit(env: Env, depositor: Address, asset_address: Address, amount: i128) -> i128;
+    fn lock_escrow(env: Env, trader: Address, asset_address: Address, amount: i128);
 }
 
 #[contract]
@@ -132,32 +182,48 @@ impl DarkPoolOrderbook {
     ) -> Result<u32, OrderbookError> {
         trader.require_auth();
 
-        let current_time = env.ledger().timestamp();
-        let expiry = current_time + expiry_seconds;
-
-        let mut orders: Vec<OrderCommitment> = env
-            .storage()
-            .instance()
-            .get(&ORDERS_KEY)
-            .unwrap_or(vec![&env]);
+        Self::insert_order(&env, &trader, &commitment, &asset_address, side, expiry_seconds)
+    }
 
-        let tree_index = orders.len() as u32;
+    /// Deposit escrow, lock it and submit the order commitment in one call
+    ///
+    /// Replaces the separate deposit, lock and submit transactions so the
+    /// funds are never sitting unlocked while the order is live.
+    ///
+    /// # Arguments
+    /// * `trader` - Address of the trader (must authenticate)
+    /// * `commitment` - Hash commitment of the order
+    /// * `asset_address` - The RWA token address (public for matching)
+    /// * `side` - Buy or Sell (public for matching)
+    /// * `escrow_asset` - Token deposited and locked in settlement escrow
+    /// * `amount` - Amount to deposit and lock
+    /// * `expiry_seconds` - How many seconds until order expires
+    ///
+    /// # Returns
+    /// * The index of the order in the orderbook
+    pub fn deposit_for_order(
+        env: Env,
+        trader: Address,
+        commitment: BytesN<32>,
+        asset_address: Address,
+        side: OrderSide,
+        escrow_asset: Address,
+        amount: i128,
+        expiry_seconds: u64,
+    ) -> Result<u32, OrderbookError> {
+        trader.require_auth();
 
-        let order = OrderCommitment {
-            commitment: commitment.clone(),
-            trader: trader.clone(),
-            asset_address: asset_address.clone(),
-            side,
-            timestamp: current_time,
-            expiry,
-            status: OrderStatus::Active,
-            tree_index,
-        };
+        if amount <= 0 {
+            return Err(OrderbookError::InvalidAmount);
+        }
 
-        orders.push_back(order);
-        env.storage().instance().set(&ORDERS_KEY, &orders);
+        let settlement = SettlementEscrowClient::new(&env, &Self::get_settlement(env.clone()));
+        settlement.deposit(&trader, &escrow_asset, &amount);
+        settlement.lock_escrow(&trader, &escrow_asset, &amount);
 
-        Ok(tree_index)
+        let index = Self::insert_order(&env, &trader, &commitment, &asset_address, side, expiry_seconds)?;
+        Self::add_depth(&env, &commitment, &asset_address, side, amount);
+        Ok(index)
     }
 
     /// Cancel an order with ownership proof
@@ -206,6 +272,7 @@ impl DarkPoolOrderbook {
                 // TODO: In production, verify the ZK proof of ownership
                 // For now, we just check the trader address matches
 
+                Self::remove_depth(&env, &order);
                 let mut cancelled_order = order.clone();
                 cancelled_order.status = OrderStatus::Cancelled;
                 updated_orders.push_back(cancelled_order);
@@ -258,33 +325,44 @@ impl DarkPoolOrderbook {
             .unwrap_or(vec![&env]);
 
         let mut updated_orders: Vec<OrderCommitment> = vec![&env];
-        let mut buy_found = false;
-        let mut sell_found = false;
+        let mut buy_scheme = None;
+        let mut sell_scheme = None;
 
         for order in orders.iter() {
             if order.commitment == buy_commitment {
                 if order.asset_address != asset_address {
                     return Err(OrderbookError::AssetMismatch);
                 }
+                Self::remove_depth(&env, &order);
                 let mut matched_order = order.clone();
                 matched_order.status = OrderStatus::Matched;
                 updated_orders.push_back(matched_order);
-                buy_found = true;
+                buy_scheme = Some(order.scheme_version);
             } else if order.commitment == sell_commitment {
                 if order.asset_address != asset_address {
                     return Err(OrderbookError::AssetMismatch);
                 }
+                Self::remove_depth(&env, &order);
                 let mut matched_order = order.clone();
                 matched_order.status = OrderStatus::Matched;
                 updated_orders.push_back(matched_order);
-                sell_found = true;
+                sell_scheme = Some(order.scheme_version);
             } else {
                 updated_orders.push_back(order);
             }
         }
 
-        if !buy_found || !sell_found {
+        let (Some(scheme_version), Some(sell_scheme)) = (buy_scheme, sell_scheme) else {
             return Err(OrderbookError::OrderNotFound);
+        };
+
+        // One settlement proof opens both commitments, so they must share a
+        // scheme that is still accepted
+        if scheme_version != sell_scheme {
+            return Err(OrderbookError::CommitmentSchemeMismatch);
+        }
+        if !Self::is_scheme_accepted(&env, scheme_version) {
+            return Err(OrderbookError::InvalidCommitmentScheme);
         }
 
         env.storage().instance().set(&ORDERS_KEY, &updated_orders);
@@ -301,6 +379,7 @@ impl DarkPoolOrderbook {
             price,
             timestamp: env.ledger().timestamp(),
             is_settled: false,
+            scheme_version,
         };
 
         let mut matches: Vec<MatchRecord> = env
@@ -377,6 +456,188 @@ impl DarkPoolOrderbook {
         Ok(())
     }
 
+    /// Move new orders to a new commitment scheme
+    ///
+    /// Orders already committed under the current scheme can still be
+    /// matched until `close_commitment_scheme` ends its upgrade window.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be admin
+    /// * `version` - New scheme version, above the current one
+    pub fn upgrade_commitment_scheme(env: Env, admin: Address, version: u32) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let current = Self::get_commitment_scheme(env.clone());
+        if version <= current {
+            return Err(OrderbookError::InvalidCommitmentScheme);
+        }
+
+        let mut open = Self::get_open_commitment_schemes(env.clone());
+        open.push_back(current);
+        env.storage().instance().set(&OPEN_SCHEMES_KEY, &open);
+        env.storage().instance().set(&SCHEME_KEY, &version);
+        Ok(())
+    }
+
+    /// End the upgrade window of a superseded commitment scheme
+    ///
+    /// Orders committed under `version` can no longer be matched.
+    pub fn close_commitment_scheme(env: Env, admin: Address, version: u32) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut open = Self::get_open_commitment_schemes(env.clone());
+        let index = open
+            .first_index_of(version)
+            .ok_or(OrderbookError::InvalidCommitmentScheme)?;
+        open.remove(index);
+        env.storage().instance().set(&OPEN_SCHEMES_KEY, &open);
+        Ok(())
+    }
+
+    /// Get the commitment scheme new orders are committed under
+    pub fn get_commitment_scheme(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEME_KEY).unwrap_or(COMMITMENT_SCHEME_V1)
+    }
+
+    /// Get superseded commitment schemes whose orders can still be matched
+    pub fn get_open_commitment_schemes(env: Env) -> Vec<u32> {
+        env.storage().instance().get(&OPEN_SCHEMES_KEY).unwrap_or(vec![&env])
+    }
+
+    /// Configure the keeper bounty paid per swept commitment
+    ///
+    /// # Arguments
+    /// * `admin` - Must be admin
+    /// * `token` - Token the bounty is paid in
+    /// * `per_order` - Bounty paid for each expired commitment removed
+    pub fn set_sweep_bounty(
+        env: Env,
+        admin: Address,
+        token: Address,
+        per_order: i128,
+    ) -> Result<(), OrderbookError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if per_order < 0 {
+            return Err(OrderbookError::InvalidAmount);
+        }
+
+        env.storage()
+            .instance()
+            .set(&BOUNTY_CFG_KEY, &SweepBounty { token, per_order });
+        Ok(())
+    }
+
+    /// Add protocol fees to the keeper bounty pool
+    ///
+    /// # Arguments
+    /// * `funder` - Address paying into the pool (must authenticate)
+    /// * `amount` - Amount of the bounty token to add
+    pub fn fund_sweep_bounty(env: Env, funder: Address, amount: i128) -> Result<i128, OrderbookError> {
+        funder.require_auth();
+
+        if amount <= 0 {
+            return Err(OrderbookError::InvalidAmount);
+        }
+        let bounty: SweepBounty = env
+            .storage()
+            .instance()
+            .get(&BOUNTY_CFG_KEY)
+            .ok_or(OrderbookError::BountyNotConfigured)?;
+
+        token::Client::new(&env, &bounty.token).transfer(&funder, env.current_contract_address(), &amount);
+
+        let pool = Self::get_sweep_pool(env.clone()) + amount;
+        env.storage().instance().set(&BOUNTY_POOL_KEY, &pool);
+        Ok(pool)
+    }
+
+    /// Remove expired active commitments, paying the caller a bounty
+    ///
+    /// Anyone may call this. Up to `limit` expired commitments are removed
+    /// from storage and the caller receives the configured bounty for each,
+    /// capped by the funds remaining in the bounty pool.
+    ///
+    /// # Returns
+    /// * The number of commitments removed
+    pub fn sweep_expired_commitments(env: Env, caller: Address, limit: u32) -> u32 {
+        caller.require_auth();
+
+        let orders: Vec<OrderCommitment> = env
+            .storage()
+            .instance()
+            .get(&ORDERS_KEY)
+            .unwrap_or(vec![&env]);
+
+        let current_time = env.ledger().timestamp();
+        let mut kept: Vec<OrderCommitment> = vec![&env];
+        let mut swept = 0u32;
+
+        for order in orders.iter() {
+            if swept < limit && order.status == OrderStatus::Active && order.expiry <= current_time {
+                Self::remove_depth(&env, &order);
+                swept += 1;
+            } else {
+                kept.push_back(order);
+            }
+        }
+
+        if swept == 0 {
+            return 0;
+        }
+        env.storage().instance().set(&ORDERS_KEY, &kept);
+
+        // Pay the keeper out of the bounty pool
+        if let Some(bounty) = env.storage().instance().get::<_, SweepBounty>(&BOUNTY_CFG_KEY) {
+            let pool = Self::get_sweep_pool(env.clone());
+            let payout = (bounty.per_order * swept as i128).min(pool);
+            if payout > 0 {
+                env.storage().instance().set(&BOUNTY_POOL_KEY, &(pool - payout));
+                token::Client::new(&env, &bounty.token).transfer(&env.current_contract_address(), &caller, &payout);
+            }
+        }
+
+        swept
+    }
+
+    /// Get commitments that `sweep_expired_commitments` would remove
+    ///
+    /// Returns up to `limit` active commitments whose expiry has passed, in
+    /// submission order, so keepers can check for work before sweeping.
+    pub fn get_expired_commitments(env: Env, limit: u32) -> Vec<OrderCommitment> {
+        let orders: Vec<OrderCommitment> = env
+            .storage()
+            .instance()
+            .get(&ORDERS_KEY)
+            .unwrap_or(vec![&env]);
+
+        let current_time = env.ledger().timestamp();
+        let mut expired: Vec<OrderCommitment> = vec![&env];
+
+        for order in orders.iter() {
+            if expired.len() >= limit {
+                break;
+            }
+            if order.status == OrderStatus::Active && order.expiry <= current_time {
+                expired.push_back(order);
+            }
+        }
+        expired
+    }
+
+    /// Get the keeper bounty configuration
+    pub fn get_sweep_bounty(env: Env) -> Option<SweepBounty> {
+        env.storage().instance().get(&BOUNTY_CFG_KEY)
+    }
+
+    /// Get the funds remaining in the keeper bounty pool
+    pub fn get_sweep_pool(env: Env) -> i128 {
+        env.storage().instance().get(&BOUNTY_POOL_KEY).unwrap_or(0)
+    }
+
     /// Get all orders for an asset and side
     pub fn get_orders_by_asset(
         env: Env,
@@ -424,6 +685,16 @@ impl DarkPoolOrderbook {
         active
     }
 
+    /// Get the aggregate depth of an asset's active orders
+    pub fn get_depth(env: Env, asset_address: Address) -> MarketDepth {
+        let depth: Map<Address, MarketDepth> = env
+            .storage()
+            .instance()
+            .get(&DEPTH_KEY)
+            .unwrap_or(Map::new(&env));
+        depth.get(asset_address).unwrap_or_default()
+    }
+
     /// Get an order by commitment
     pub fn get_order(env: Env, commitment: BytesN<32>) -> Option<OrderCommitment> {
         let orders: Vec<OrderCommitment> = env
@@ -432,12 +703,7 @@ impl DarkPoolOrderbook {
             .get(&ORDERS_KEY)
             .unwrap_or(vec![&env]);
 
-        for order in orders.iter() {
-            if order.commitment == commitment {
-                return Some(order);
-            }
-        }
-        None
+        orders.iter().find(|order| order.commitment == commitment)
     }
 
     /// Get all matches
@@ -456,12 +722,7 @@ impl DarkPoolOrderbook {
             .get(&MATCHES_KEY)
             .unwrap_or(vec![&env]);
 
-        for m in matches.iter() {
-            if m.match_id == match_id {
-                return Some(m);
-            }
-        }
-        None
+        matches.iter().find(|m| m.match_id == match_id)
     }
 
     /// Get pending (unsettle) matches
@@ -496,7 +757,128 @@ impl DarkPoolOrderbook {
         env.storage().instance().get(&SETTLEMENT_KEY).unwrap()
     }
 
-    // Internal helper
+    // Internal helpers
+
+    /// Append a new active order commitment
+    fn insert_order(
+        env: &Env,
+        trader: &Address,
+        commitment: &BytesN<32>,
+        asset_address: &Address,
+        side: OrderSide,
+        expiry_seconds: u64,
+    ) -> Result<u32, OrderbookError> {
+        Self::validate_commitment(commitment)?;
+        let scheme_version = Self::get_commitment_scheme(env.clone());
+
+        let current_time = env.ledger().timestamp();
+        let expiry = current_time + expiry_seconds;
+
+        let mut orders: Vec<OrderCommitment> = env
+            .storage()
+            .instance()
+            .get(&ORDERS_KEY)
+            .unwrap_or(vec![env]);
+
+        // Indices are never reused, even after sweeps shrink the order list.
+        // Books written before the counter existed resume past their highest index.
+        let tree_index: u32 = env
+            .storage()
+            .instance()
+            .get(&NEXT_INDEX_KEY)
+            .unwrap_or_else(|| orders.iter().map(|o| o.tree_index + 1).max().unwrap_or(0));
+        env.storage().instance().set(&NEXT_INDEX_KEY, &(tree_index + 1));
+
+        let order = OrderCommitment {
+            commitment: commitment.clone(),
+            trader: trader.clone(),
+            asset_address: asset_address.clone(),
+            side,
+            timestamp: current_time,
+            expiry,
+            status: OrderStatus::Active,
+            tree_index,
+            scheme_version,
+        };
+
+        orders.push_back(order);
+        env.storage().instance().set(&ORDERS_KEY, &orders);
+
+        Ok(tree_index)
+    }
+
+    /// Check a commitment could be the output of a commitment scheme
+    ///
+    /// Every scheme hashes with Poseidon, so commitments are canonical BN254
+    /// scalars; schemes differ in the circuit settlement proofs open them with.
+    fn validate_commitment(commitment: &BytesN<32>) -> Result<(), OrderbookError> {
+        if !zk_bn254::is_canonical_scalar(&commitment.to_array()) {
+            return Err(OrderbookError::InvalidCommitment);
+        }
+        Ok(())
+    }
+
+    /// Whether orders committed under a scheme can still be matched
+    fn is_scheme_accepted(env: &Env, scheme_version: u32) -> bool {
+        scheme_version == Self::get_commitment_scheme(env.clone())
+            || Self::get_open_commitment_schemes(env.clone()).contains(scheme_version)
+    }
+
+    /// Round an escrow amount down to its power-of-ten depth bucket
+    fn depth_bucket(amount: i128) -> i128 {
+        let mut bucket = 1i128;
+        while bucket <= amount / 10 {
+            bucket *= 10;
+        }
+        bucket
+    }
+
+    /// Count a new order's bucketed escrow in its asset's depth
+    fn add_depth(env: &Env, commitment: &BytesN<32>, asset_address: &Address, side: OrderSide, amount: i128) {
+        let bucket = Self::depth_bucket(amount);
+        let mut notional: Map<BytesN<32>, i128> = env
+            .storage()
+            .instance()
+            .get(&NOTIONAL_KEY)
+            .unwrap_or(Map::new(env));
+        notional.set(commitment.clone(), bucket);
+        env.storage().instance().set(&NOTIONAL_KEY, &notional);
+
+        Self::update_depth(env, asset_address, side, bucket);
+    }
+
+    /// Drop an order leaving the active set from its asset's depth
+    fn remove_depth(env: &Env, order: &OrderCommitment) {
+        let mut notional: Map<BytesN<32>, i128> = env
+            .storage()
+            .instance()
+            .get(&NOTIONAL_KEY)
+            .unwrap_or(Map::new(env));
+        let bucket = match notional.get(order.commitment.clone()) {
+            Some(bucket) => bucket,
+            None => return,
+        };
+        notional.remove(order.commitment.clone());
+        env.storage().instance().set(&NOTIONAL_KEY, &notional);
+
+        Self::update_depth(env, &order.asset_address, order.side, -bucket);
+    }
+
+    fn update_depth(env: &Env, asset_address: &Address, side: OrderSide, delta: i128) {
+        let mut depth: Map<Address, MarketDepth> = env
+            .storage()
+            .instance()
+            .get(&DEPTH_KEY)
+            .unwrap_or(Map::new(env));
+        let mut entry = depth.get(asset_address.clone()).unwrap_or_default();
+        match side {
+            OrderSide::Buy => entry.buy_notional += delta,
+            OrderSide::Sell => entry.sell_notional += delta,
+        }
+        depth.set(asset_address.clone(), entry);
+        env.storage().instance().set(&DEPTH_KEY, &depth);
+    }
+
     fn require_admin(env: &Env, caller: &Address) -> Result<(), OrderbookError> {
         let admin: Address = env.storage().instance().get(&ADMIN_KEY).unwrap();
         if *caller != admin {
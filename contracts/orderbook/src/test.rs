@@ -1,7 +1,12 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, BytesN, Env};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    BytesN, Env,
+};
 
 #[test]
 fn test_constructor() {
@@ -122,6 +127,72 @@ fn test_record_match() {
     assert!(!match_record.unwrap().is_settled);
 }
 
+#[test]
+fn test_commitment_scheme_versions() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let settlement = Address::generate(&env);
+
+    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let trader = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let commitment = |byte: u8| BytesN::from_array(&env, &[byte; 32]);
+    let submit = |byte: u8, side: OrderSide| client.submit_order(&trader, &commitment(byte), &asset, &side, &3600);
+    let record = |id: u8, buy: u8, sell: u8| {
+        client.try_record_match(
+            &admin,
+            &commitment(id),
+            &commitment(buy),
+            &commitment(sell),
+            &asset,
+            &trader,
+            &trader,
+            &1000,
+            &50000,
+        )
+    };
+
+    // Commitments must be field elements
+    let oversized = client.try_submit_order(&trader, &commitment(0xff), &asset, &OrderSide::Buy, &3600);
+    assert_eq!(oversized, Err(Ok(OrderbookError::InvalidCommitment)));
+
+    submit(1, OrderSide::Buy);
+    submit(2, OrderSide::Sell);
+    submit(3, OrderSide::Buy);
+    submit(4, OrderSide::Sell);
+    client.upgrade_commitment_scheme(&admin, &2);
+    submit(5, OrderSide::Buy);
+    submit(6, OrderSide::Sell);
+    assert_eq!(client.get_order(&commitment(1)).unwrap().scheme_version, COMMITMENT_SCHEME_V1);
+    assert_eq!(client.get_order(&commitment(5)).unwrap().scheme_version, 2);
+    assert_eq!(client.get_open_commitment_schemes(), vec![&env, COMMITMENT_SCHEME_V1]);
+
+    // Orders under different schemes cannot be opened by one proof
+    assert_eq!(record(20, 5, 2), Err(Ok(OrderbookError::CommitmentSchemeMismatch)));
+
+    // Old orders still match during the upgrade window
+    record(21, 1, 2).unwrap().unwrap();
+    assert_eq!(client.get_match(&commitment(21)).unwrap().scheme_version, COMMITMENT_SCHEME_V1);
+    record(22, 5, 6).unwrap().unwrap();
+    assert_eq!(client.get_match(&commitment(22)).unwrap().scheme_version, 2);
+
+    client.close_commitment_scheme(&admin, &COMMITMENT_SCHEME_V1);
+    assert_eq!(record(23, 3, 4), Err(Ok(OrderbookError::InvalidCommitmentScheme)));
+    assert_eq!(
+        client.try_close_commitment_scheme(&admin, &COMMITMENT_SCHEME_V1),
+        Err(Ok(OrderbookError::InvalidCommitmentScheme))
+    );
+    assert_eq!(
+        client.try_upgrade_commitment_scheme(&admin, &2),
+        Err(Ok(OrderbookError::InvalidCommitmentScheme))
+    );
+}
+
 #[test]
 fn test_get_active_orders() {
     let env = Env::default();
@@ -186,3 +257,166 @@ fn test_get_orders_by_side() {
     assert_eq!(buy_orders.len(), 3);
     assert_eq!(sell_orders.len(), 2);
 }
+
+#[test]
+fn test_sweep_expired_commitments_pays_bounty() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let settlement = Address::generate(&env);
+
+    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let fee_token = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    StellarAssetClient::new(&env, &fee_token).mint(&admin, &1_000);
+    client.set_sweep_bounty(&admin, &fee_token, &100);
+    client.fund_sweep_bounty(&admin, &250);
+
+    let trader = Address::generate(&env);
+    let asset = Address::generate(&env);
+    for i in 0..4u8 {
+        let commitment = BytesN::from_array(&env, &[i + 1; 32]);
+        let expiry = if i < 3 { 60 } else { 3600 };
+        client.submit_order(&trader, &commitment, &asset, &OrderSide::Buy, &expiry);
+    }
+
+    let keeper = Address::generate(&env);
+    assert_eq!(client.sweep_expired_commitments(&keeper, &10), 0);
+
+    env.ledger().with_mut(|li| li.timestamp += 120);
+
+    // Keepers can see the pending work before sweeping
+    assert_eq!(client.get_expired_commitments(&10).len(), 3);
+    let first = client.get_expired_commitments(&1);
+    assert_eq!(first.len(), 1);
+    assert_eq!(first.get(0).unwrap().commitment, BytesN::from_array(&env, &[1u8; 32]));
+
+    // Limit is respected
+    assert_eq!(client.sweep_expired_commitments(&keeper, &2), 2);
+    assert_eq!(client.get_sweep_pool(), 50);
+
+    // The pool caps the payout of the remaining sweep
+    assert_eq!(client.sweep_expired_commitments(&keeper, &10), 1);
+    assert_eq!(client.get_sweep_pool(), 0);
+    assert_eq!(TokenClient::new(&env, &fee_token).balance(&keeper), 250);
+
+    // Only the unexpired order remains
+    assert_eq!(client.get_expired_commitments(&10).len(), 0);
+    assert_eq!(client.get_orders_by_asset(&asset, &None).len(), 1);
+
+    // Swept indices are not handed out again
+    let commitment = BytesN::from_array(&env, &[9u8; 32]);
+    assert_eq!(client.submit_order(&trader, &commitment, &asset, &OrderSide::Buy, &3600), 4);
+}
+
+/// Minimal stand-in for the settlement escrow
+#[contract]
+struct MockSettlement;
+
+#[contractimpl]
+impl MockSettlement {
+    pub fn deposit(env: Env, depositor: Address, asset_address: Address, amount: i128) -> i128 {
+        depositor.require_auth();
+        TokenClient::new(&env, &asset_address).transfer(&depositor, env.current_contract_address(), &amount);
+        amount
+    }
+
+    pub fn lock_escrow(env: Env, trader: Address, _asset_address: Address, amount: i128) {
+        trader.require_auth();
+        env.storage().instance().set(&trader, &amount);
+    }
+
+    pub fn locked(env: Env, trader: Address) -> i128 {
+        env.storage().instance().get(&trader).unwrap_or(0)
+    }
+}
+
+#[test]
+fn test_deposit_for_order() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let settlement = env.register(MockSettlement, ());
+
+    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let payment = env.register_stellar_asset_contract_v2(issuer).address();
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &payment).mint(&trader, &1_000);
+
+    let asset = Address::generate(&env);
+    let commitment = BytesN::from_array(&env, &[4u8; 32]);
+    let index = client.deposit_for_order(&trader, &commitment, &asset, &OrderSide::Buy, &payment, &700, &3600);
+    assert_eq!(index, 0);
+
+    // Funds reached escrow and were locked alongside the commitment
+    assert_eq!(TokenClient::new(&env, &payment).balance(&settlement), 700);
+    assert_eq!(MockSettlementClient::new(&env, &settlement).locked(&trader), 700);
+    let order = client.get_order(&commitment).unwrap();
+    assert_eq!(order.trader, trader);
+    assert_eq!(order.status, OrderStatus::Active);
+
+    let zero = client.try_deposit_for_order(
+        &trader,
+        &BytesN::from_array(&env, &[5u8; 32]),
+        &asset,
+        &OrderSide::Buy,
+        &payment,
+        &0,
+        &3600,
+    );
+    assert_eq!(zero, Err(Ok(OrderbookError::InvalidAmount)));
+}
+
+#[test]
+fn test_depth_aggregates_bucketed_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let registry = Address::generate(&env);
+    let settlement = env.register(MockSettlement, ());
+
+    let contract_id = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+    let client = DarkPoolOrderbookClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let payment = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let asset = env.register_stellar_asset_contract_v2(issuer).address();
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    StellarAssetClient::new(&env, &payment).mint(&buyer, &100_000);
+    StellarAssetClient::new(&env, &asset).mint(&seller, &100_000);
+
+    let commitment = |byte: u8| BytesN::from_array(&env, &[byte; 32]);
+    client.deposit_for_order(&buyer, &commitment(1), &asset, &OrderSide::Buy, &payment, &12_345, &3600);
+    client.deposit_for_order(&buyer, &commitment(2), &asset, &OrderSide::Buy, &payment, &999, &3600);
+    client.deposit_for_order(&seller, &commitment(3), &asset, &OrderSide::Sell, &asset, &70, &3600);
+
+    // Each order only contributes its power-of-ten bucket
+    let depth = client.get_depth(&asset);
+    assert_eq!(depth.buy_notional, 10_000 + 100);
+    assert_eq!(depth.sell_notional, 10);
+
+    client.cancel_order(&buyer, &commitment(2), &Bytes::new(&env), &Bytes::new(&env));
+    assert_eq!(client.get_depth(&asset).buy_notional, 10_000);
+
+    client.record_match(
+        &admin,
+        &commitment(9),
+        &commitment(1),
+        &commitment(3),
+        &asset,
+        &buyer,
+        &seller,
+        &70,
+        &150,
+    );
+    assert_eq!(client.get_depth(&asset), MarketDepth::default());
+}
@@ -1,8 +1,8 @@
 #![no_std]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, vec,
-    Address, Bytes, BytesN, Env, Symbol, Vec,
+    contract, contracterror, contractevent, contractimpl, contracttype, symbol_short, vec,
+    Address, Bytes, BytesN, Env, Map, Symbol, Vec,
 };
 
 use lean_imt_bn254::{LeanIMTBN254, TREE_DEPTH_KEY, TREE_LEAVES_KEY, TREE_ROOT_KEY};
@@ -16,10 +16,31 @@ const VERIFIER_KEY: Symbol = symbol_short!("verifier");
 const ELIGIBILITY_VK_KEY: Symbol = symbol_short!("elig_vk");
 const PARTICIPANTS_KEY: Symbol = symbol_short!("parts");
 const ASSETS_KEY: Symbol = symbol_short!("assets");
+const ROOT_HISTORY_KEY: Symbol = symbol_short!("root_hist");
+const ROOT_COUNT_KEY: Symbol = symbol_short!("root_cnt");
+const CREDENTIALS_KEY: Symbol = symbol_short!("creds");
+const REG_QUEUE_KEY: Symbol = symbol_short!("reg_queue");
+const REGISTRARS_KEY: Symbol = symbol_short!("registrar");
+const CLAWBACK_ASSETS_KEY: Symbol = symbol_short!("clawback");
+const REJECT_CLAWBACK_KEY: Symbol = symbol_short!("no_clawbk");
+const ADAPTERS_KEY: Symbol = symbol_short!("adapters");
+const ROOT_ANCHORS_KEY: Symbol = symbol_short!("root_anch");
+const SERVICES_KEY: Symbol = symbol_short!("services");
 
 // Merkle tree depth for whitelist
 const WHITELIST_TREE_DEPTH: u32 = 20;
 
+/// Maximum leaves returned by one `export_leaves` call
+pub const MAX_EXPORT_LEAVES: u32 = 200;
+
+/// Number of most recent whitelist roots whose timestamps are kept
+pub const ROOT_HISTORY_SIZE: u32 = 64;
+
+// Root history entries are bumped to ~30 days whenever they drop below ~15 days
+// (at 5s ledgers)
+const ROOT_TTL_THRESHOLD: u32 = 259_200;
+const ROOT_TTL_EXTEND_TO: u32 = 518_400;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -33,6 +54,13 @@ pub enum RegistryError {
     InvalidKYCExpiry = 7,
     ParticipantNotActive = 8,
     AssetNotActive = 9,
+    LeafNotFound = 10,
+    OnlyRegistrar = 11,
+    RequestAlreadyPending = 12,
+    RequestNotFound = 13,
+    ClawbackAssetRejected = 14,
+    RootAlreadyAnchored = 15,
+    ServiceNotFound = 16,
 }
 
 /// Participant category for institutional classification
@@ -88,6 +116,116 @@ pub struct RWAAsset {
     pub is_active: bool,
 }
 
+/// How the settlement contract moves a listed token
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum TokenAdapter {
+    /// Stellar Asset Contract
+    Sac,
+    /// Any other SEP-41 token
+    Sep41,
+    /// SEP-41 token whose issuer hook contract must approve every transfer
+    Hooked(Address),
+}
+
+/// Pending self-registration awaiting a registrar decision
+#[derive(Clone)]
+#[contracttype]
+pub struct RegistrationRequest {
+    pub id_hash: BytesN<32>,
+    pub trading_address: Address,
+    pub category: ParticipantCategory,
+    pub credential_hash: BytesN<32>,
+    pub submitted_at: u64,
+}
+
+/// Off-chain attestation a whitelist root was bound to
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RootAnchor {
+    /// Reference to the external evidence, e.g. the hash of a signed KYC batch
+    pub external_ref: BytesN<32>,
+    pub anchored_at: u64,
+}
+
+/// Operational role a service may hold across the pool contracts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum ServiceRole {
+    /// Submits matched trades for settlement
+    Relayer = 0,
+    /// Runs permissionless maintenance such as queued payouts
+    Keeper = 1,
+    /// May read every settlement record
+    Auditor = 2,
+}
+
+/// Operator approval for a service to act in one role
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ServiceRegistration {
+    /// Reference to the stake or bond backing the service, e.g. a deposit receipt hash
+    pub stake_ref: BytesN<32>,
+    pub registered_at: u64,
+}
+
+/// Event emitted when a service role is granted or revoked
+#[contractevent]
+#[derive(Clone)]
+pub struct ServiceRoleUpdated {
+    #[topic]
+    pub service: Address,
+    pub role: ServiceRole,
+    pub operator: Address,
+    pub enabled: bool,
+}
+
+/// Event emitted when the current whitelist root is bound to an attestation
+#[contractevent]
+#[derive(Clone)]
+pub struct RootAnchored {
+    #[topic]
+    pub root: BytesN<32>,
+    pub external_ref: BytesN<32>,
+}
+
+/// Event emitted when a leaf is appended to the whitelist tree
+///
+/// Provers can maintain the tree from these events alone instead of
+/// re-exporting every leaf after each registration.
+#[contractevent]
+#[derive(Clone)]
+pub struct ParticipantAdded {
+    #[topic]
+    pub leaf_index: u32,
+    pub leaf_hash: BytesN<32>,
+    pub new_root: BytesN<32>,
+}
+
+/// Event emitted when a participant is deactivated
+///
+/// Deactivation is a soft delete: the leaf stays in the tree, so `root` is
+/// unchanged and only eligibility checks stop accepting the participant.
+#[contractevent]
+#[derive(Clone)]
+pub struct ParticipantDeactivated {
+    #[topic]
+    pub leaf_index: u32,
+    pub leaf_hash: BytesN<32>,
+    pub root: BytesN<32>,
+}
+
+/// Event emitted when a registrar approves or rejects a registration request
+#[contractevent]
+#[derive(Clone)]
+pub struct RegistrationDecided {
+    #[topic]
+    pub applicant: Address,
+    pub registrar: Address,
+    pub approved: bool,
+}
+
 #[contract]
 pub struct DarkPoolRegistry;
 
@@ -120,6 +258,7 @@ impl DarkPoolRegistry {
         env.storage().instance().set(&TREE_LEAVES_KEY, &leaves);
         env.storage().instance().set(&TREE_DEPTH_KEY, &depth);
         env.storage().instance().set(&TREE_ROOT_KEY, &root);
+        Self::record_root(&env, &root);
 
         // Initialize empty participants and assets lists
         let participants: Vec<Participant> = vec![&env];
@@ -144,37 +283,174 @@ impl DarkPoolRegistry {
         admin.require_auth();
         Self::require_admin(&env, &admin)?;
 
-        // Check participant doesn't already exist
-        let mut participants: Vec<Participant> = env
+        Self::insert_participant(&env, participant)
+    }
+
+    /// Grant or revoke the registrar role for processing registration requests
+    ///
+    /// The admin can always act as a registrar.
+    pub fn set_registrar(
+        env: Env,
+        admin: Address,
+        registrar: Address,
+        enabled: bool,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut registrars: Map<Address, bool> = env
             .storage()
             .instance()
-            .get(&PARTICIPANTS_KEY)
-            .unwrap_or(vec![&env]);
+            .get(&REGISTRARS_KEY)
+            .unwrap_or(Map::new(&env));
+        if enabled {
+            registrars.set(registrar, true);
+        } else {
+            registrars.remove(registrar);
+        }
+        env.storage().instance().set(&REGISTRARS_KEY, &registrars);
+        Ok(())
+    }
 
-        for p in participants.iter() {
-            if p.trading_address == participant.trading_address {
-                return Err(RegistryError::ParticipantAlreadyExists);
-            }
+    /// Check whether an address may approve or reject registration requests
+    pub fn is_registrar(env: Env, address: Address) -> bool {
+        if address == Self::get_admin(env.clone()) {
+            return true;
         }
+        let registrars: Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&REGISTRARS_KEY)
+            .unwrap_or(Map::new(&env));
+        registrars.get(address).unwrap_or(false)
+    }
 
-        // Validate KYC expiry is in the future
-        let current_time = env.ledger().timestamp();
-        if participant.kyc_expiry <= current_time {
-            return Err(RegistryError::InvalidKYCExpiry);
+    /// Submit a request to join the whitelist
+    ///
+    /// The request waits in the queue until a registrar approves or rejects
+    /// it; nothing is inserted into the Merkle tree before approval.
+    ///
+    /// # Arguments
+    /// * `applicant` - Trading address requesting access (must authenticate)
+    /// * `id_hash` - Identity hash to use as the Merkle leaf
+    /// * `category` - Institutional category of the applicant
+    /// * `credential_hash` - Hash of the applicant's KYC credential
+    pub fn request_registration(
+        env: Env,
+        applicant: Address,
+        id_hash: BytesN<32>,
+        category: ParticipantCategory,
+        credential_hash: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        applicant.require_auth();
+
+        if Self::get_participant(env.clone(), applicant.clone()).is_some() {
+            return Err(RegistryError::ParticipantAlreadyExists);
         }
 
-        // Add participant's id_hash to the Merkle tree
-        let tree_index = Self::add_to_whitelist_tree(&env, participant.id_hash.clone())?;
+        let mut queue: Map<Address, RegistrationRequest> = env
+            .storage()
+            .instance()
+            .get(&REG_QUEUE_KEY)
+            .unwrap_or(Map::new(&env));
+        if queue.contains_key(applicant.clone()) {
+            return Err(RegistryError::RequestAlreadyPending);
+        }
 
-        // Store participant with tree index
-        let mut new_participant = participant.clone();
-        new_participant.tree_index = tree_index;
-        participants.push_back(new_participant);
-        env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
+        queue.set(
+            applicant.clone(),
+            RegistrationRequest {
+                id_hash,
+                trading_address: applicant,
+                category,
+                credential_hash,
+                submitted_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&REG_QUEUE_KEY, &queue);
+        Ok(())
+    }
+
+    /// Approve a pending registration request and insert its Merkle leaf
+    ///
+    /// # Arguments
+    /// * `registrar` - Registrar or admin address
+    /// * `applicant` - Address of the pending request
+    /// * `kyc_expiry` - KYC expiry assigned by the registrar
+    ///
+    /// # Returns
+    /// * The tree index where the participant was added
+    pub fn approve_registration(
+        env: Env,
+        registrar: Address,
+        applicant: Address,
+        kyc_expiry: u64,
+    ) -> Result<u32, RegistryError> {
+        registrar.require_auth();
+        Self::require_registrar(&env, &registrar)?;
+
+        let request = Self::take_registration_request(&env, &applicant)?;
+        let tree_index = Self::insert_participant(
+            &env,
+            Participant {
+                id_hash: request.id_hash,
+                trading_address: applicant.clone(),
+                category: request.category,
+                kyc_expiry,
+                is_active: true,
+                tree_index: 0,
+            },
+        )?;
+
+        let mut credentials: Map<Address, BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&CREDENTIALS_KEY)
+            .unwrap_or(Map::new(&env));
+        credentials.set(applicant.clone(), request.credential_hash);
+        env.storage().instance().set(&CREDENTIALS_KEY, &credentials);
+
+        RegistrationDecided {
+            applicant,
+            registrar,
+            approved: true,
+        }
+        .publish(&env);
 
         Ok(tree_index)
     }
 
+    /// Reject a pending registration request
+    pub fn reject_registration(
+        env: Env,
+        registrar: Address,
+        applicant: Address,
+    ) -> Result<(), RegistryError> {
+        registrar.require_auth();
+        Self::require_registrar(&env, &registrar)?;
+
+        Self::take_registration_request(&env, &applicant)?;
+
+        RegistrationDecided {
+            applicant,
+            registrar,
+            approved: false,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get the pending registration requests
+    pub fn get_registration_queue(env: Env) -> Vec<RegistrationRequest> {
+        let queue: Map<Address, RegistrationRequest> = env
+            .storage()
+            .instance()
+            .get(&REG_QUEUE_KEY)
+            .unwrap_or(Map::new(&env));
+        queue.values()
+    }
+
     /// Deactivate a participant (soft delete)
     ///
     /// # Arguments
@@ -194,7 +470,7 @@ impl DarkPoolRegistry {
             .get(&PARTICIPANTS_KEY)
             .unwrap_or(vec![&env]);
 
-        let mut found = false;
+        let mut found = None;
         let mut updated_participants: Vec<Participant> = vec![&env];
 
         for p in participants.iter() {
@@ -202,20 +478,89 @@ impl DarkPoolRegistry {
                 let mut updated = p.clone();
                 updated.is_active = false;
                 updated_participants.push_back(updated);
-                found = true;
+                found = Some(p);
             } else {
                 updated_participants.push_back(p);
             }
         }
 
-        if !found {
+        let Some(participant) = found else {
             return Err(RegistryError::ParticipantNotFound);
-        }
+        };
 
         env.storage().instance().set(&PARTICIPANTS_KEY, &updated_participants);
+
+        ParticipantDeactivated {
+            leaf_index: participant.tree_index,
+            leaf_hash: participant.id_hash,
+            root: Self::get_whitelist_root(env.clone()),
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Anchor a credential hash for a registered participant
+    ///
+    /// Off-chain KYC providers can commit to a verifiable credential (e.g. the
+    /// SHA-256 of a W3C VC) without publishing it. Circuits may take the hash
+    /// as an input alongside the participant's membership proof.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `trading_address` - Participant the credential belongs to
+    /// * `credential_hash` - Hash of the credential document
+    pub fn set_credential_hash(
+        env: Env,
+        admin: Address,
+        trading_address: Address,
+        credential_hash: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if Self::get_participant(env.clone(), trading_address.clone()).is_none() {
+            return Err(RegistryError::ParticipantNotFound);
+        }
+
+        let mut credentials: Map<Address, BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&CREDENTIALS_KEY)
+            .unwrap_or(Map::new(&env));
+        credentials.set(trading_address, credential_hash);
+        env.storage().instance().set(&CREDENTIALS_KEY, &credentials);
         Ok(())
     }
 
+    /// Remove a participant's anchored credential hash
+    pub fn clear_credential_hash(
+        env: Env,
+        admin: Address,
+        trading_address: Address,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut credentials: Map<Address, BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&CREDENTIALS_KEY)
+            .unwrap_or(Map::new(&env));
+        credentials.remove(trading_address);
+        env.storage().instance().set(&CREDENTIALS_KEY, &credentials);
+        Ok(())
+    }
+
+    /// Get the credential hash anchored for a participant, if any
+    pub fn get_credential_hash(env: Env, trading_address: Address) -> Option<BytesN<32>> {
+        let credentials: Map<Address, BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&CREDENTIALS_KEY)
+            .unwrap_or(Map::new(&env));
+        credentials.get(trading_address)
+    }
+
     /// Register a new RWA asset
     ///
     /// # Arguments
@@ -242,11 +587,100 @@ impl DarkPoolRegistry {
             }
         }
 
+        if Self::rejects_clawback_assets(env.clone())
+            && Self::is_clawback_asset(env.clone(), asset.token_address.clone())
+        {
+            return Err(RegistryError::ClawbackAssetRejected);
+        }
+
         assets.push_back(asset);
         env.storage().instance().set(&ASSETS_KEY, &assets);
         Ok(())
     }
 
+    /// Select how the settlement contract transfers a token
+    ///
+    /// Tokens without an explicit adapter are treated as plain SEP-41 tokens.
+    pub fn set_token_adapter(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+        adapter: TokenAdapter,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut adapters: Map<Address, TokenAdapter> = env
+            .storage()
+            .instance()
+            .get(&ADAPTERS_KEY)
+            .unwrap_or(Map::new(&env));
+        adapters.set(token_address, adapter);
+        env.storage().instance().set(&ADAPTERS_KEY, &adapters);
+        Ok(())
+    }
+
+    /// Get the transfer adapter for a token
+    pub fn get_token_adapter(env: Env, token_address: Address) -> TokenAdapter {
+        let adapters: Map<Address, TokenAdapter> = env
+            .storage()
+            .instance()
+            .get(&ADAPTERS_KEY)
+            .unwrap_or(Map::new(&env));
+        adapters.get(token_address).unwrap_or(TokenAdapter::Sep41)
+    }
+
+    /// Declare whether a token's issuer has clawback enabled
+    ///
+    /// Clawback-enabled assets can be pulled out of the settlement pool by
+    /// their issuer, so listing them is an explicit operator decision.
+    pub fn set_clawback_asset(
+        env: Env,
+        admin: Address,
+        token_address: Address,
+        clawback_enabled: bool,
+    ) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut clawback_assets: Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&CLAWBACK_ASSETS_KEY)
+            .unwrap_or(Map::new(&env));
+        if clawback_enabled {
+            clawback_assets.set(token_address, true);
+        } else {
+            clawback_assets.remove(token_address);
+        }
+        env.storage().instance().set(&CLAWBACK_ASSETS_KEY, &clawback_assets);
+        Ok(())
+    }
+
+    /// Check whether a token has been declared clawback-enabled
+    pub fn is_clawback_asset(env: Env, token_address: Address) -> bool {
+        let clawback_assets: Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&CLAWBACK_ASSETS_KEY)
+            .unwrap_or(Map::new(&env));
+        clawback_assets.get(token_address).unwrap_or(false)
+    }
+
+    /// Refuse to list clawback-enabled assets
+    pub fn set_reject_clawback_assets(env: Env, admin: Address, reject: bool) -> Result<(), RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&REJECT_CLAWBACK_KEY, &reject);
+        Ok(())
+    }
+
+    /// Check whether clawback-enabled assets are refused at listing
+    pub fn rejects_clawback_assets(env: Env) -> bool {
+        env.storage().instance().get(&REJECT_CLAWBACK_KEY).unwrap_or(false)
+    }
+
     /// Deactivate an RWA asset
     pub fn deactivate_asset(
         env: Env,
@@ -292,6 +726,62 @@ impl DarkPoolRegistry {
             .unwrap_or(BytesN::from_array(&env, &[0u8; 32]))
     }
 
+    /// Get the ledger timestamp at which a whitelist root became current
+    ///
+    /// Returns `None` for roots this registry never produced, or that are no
+    /// longer among the last `ROOT_HISTORY_SIZE` roots.
+    pub fn get_root_timestamp(env: Env, root: BytesN<32>) -> Option<u64> {
+        env.storage().persistent().get(&(ROOT_HISTORY_KEY, root))
+    }
+
+    /// Bind the current whitelist root to an external attestation
+    ///
+    /// Links each on-chain root to the off-chain KYC evidence behind it. An
+    /// anchor is permanent, so a root can only be anchored once.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `external_ref` - Reference to the attestation, e.g. a signed KYC batch hash
+    pub fn anchor_root(env: Env, admin: Address, external_ref: BytesN<32>) -> Result<BytesN<32>, RegistryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let root = Self::get_whitelist_root(env.clone());
+        let mut anchors: Map<BytesN<32>, RootAnchor> = env
+            .storage()
+            .instance()
+            .get(&ROOT_ANCHORS_KEY)
+            .unwrap_or(Map::new(&env));
+        if anchors.contains_key(root.clone()) {
+            return Err(RegistryError::RootAlreadyAnchored);
+        }
+        anchors.set(
+            root.clone(),
+            RootAnchor {
+                external_ref: external_ref.clone(),
+                anchored_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&ROOT_ANCHORS_KEY, &anchors);
+
+        RootAnchored {
+            root: root.clone(),
+            external_ref,
+        }
+        .publish(&env);
+        Ok(root)
+    }
+
+    /// Get the external attestation a whitelist root was anchored to, if any
+    pub fn get_root_anchor(env: Env, root: BytesN<32>) -> Option<RootAnchor> {
+        let anchors: Map<BytesN<32>, RootAnchor> = env
+            .storage()
+            .instance()
+            .get(&ROOT_ANCHORS_KEY)
+            .unwrap_or(Map::new(&env));
+        anchors.get(root)
+    }
+
     /// Get all registered participants
     pub fn get_participants(env: Env) -> Vec<Participant> {
         env.storage()
@@ -325,12 +815,7 @@ impl DarkPoolRegistry {
             .get(&PARTICIPANTS_KEY)
             .unwrap_or(vec![&env]);
 
-        for p in participants.iter() {
-            if p.trading_address == trading_address {
-                return Some(p);
-            }
-        }
-        None
+        participants.iter().find(|p| p.trading_address == trading_address)
     }
 
     /// Check if a participant is eligible (active and KYC not expired)
@@ -375,12 +860,7 @@ impl DarkPoolRegistry {
             .get(&ASSETS_KEY)
             .unwrap_or(vec![&env]);
 
-        for a in assets.iter() {
-            if a.token_address == token_address {
-                return Some(a);
-            }
-        }
-        None
+        assets.iter().find(|a| a.token_address == token_address)
     }
 
     /// Check if an asset is eligible for trading
@@ -409,6 +889,30 @@ impl DarkPoolRegistry {
             .unwrap_or(WHITELIST_TREE_DEPTH)
     }
 
+    /// Get the Merkle sibling path for a whitelist leaf
+    ///
+    /// Siblings are ordered from the leaf level up to just below the root, so
+    /// wallets can build membership proofs without an off-chain path service.
+    ///
+    /// # Arguments
+    /// * `leaf_index` - Tree index assigned when the participant registered
+    pub fn get_merkle_path(env: Env, leaf_index: u32) -> Result<Vec<BytesN<32>>, RegistryError> {
+        let leaves: Vec<BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&TREE_LEAVES_KEY)
+            .unwrap_or(vec![&env]);
+        let depth: u32 = env
+            .storage()
+            .instance()
+            .get(&TREE_DEPTH_KEY)
+            .unwrap_or(WHITELIST_TREE_DEPTH);
+        let root = Self::get_whitelist_root(env.clone());
+
+        let tree = LeanIMTBN254::from_storage(&env, leaves, depth, root);
+        tree.generate_path(leaf_index).ok_or(RegistryError::LeafNotFound)
+    }
+
     /// Get the number of participants in the whitelist tree
     pub fn get_whitelist_count(env: Env) -> u32 {
         let leaves: Vec<BytesN<32>> = env
@@ -416,7 +920,128 @@ impl DarkPoolRegistry {
             .instance()
             .get(&TREE_LEAVES_KEY)
             .unwrap_or(vec![&env]);
-        leaves.len() as u32
+        leaves.len()
+    }
+
+    /// Export a page of whitelist leaves with their tree indices
+    ///
+    /// Provers can rebuild the membership tree from chain state by walking
+    /// pages until fewer than `limit` leaves come back, then check the
+    /// rebuilt root against `get_whitelist_root`.
+    ///
+    /// # Arguments
+    /// * `start` - Index of the first leaf
+    /// * `limit` - Maximum leaves to return, capped at `MAX_EXPORT_LEAVES`
+    pub fn export_leaves(env: Env, start: u32, limit: u32) -> Vec<(u32, BytesN<32>)> {
+        let leaves: Vec<BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&TREE_LEAVES_KEY)
+            .unwrap_or(vec![&env]);
+
+        let end = start
+            .saturating_add(limit.min(MAX_EXPORT_LEAVES))
+            .min(leaves.len());
+        let mut page = vec![&env];
+        for index in start..end {
+            page.push_back((index, leaves.get(index).unwrap()));
+        }
+        page
+    }
+
+    /// Register a service for a role, or refresh its stake reference
+    ///
+    /// The registry is the single source of truth for which relayers,
+    /// keepers and auditors may operate; the pool contracts query it
+    /// rather than keeping their own lists.
+    ///
+    /// # Arguments
+    /// * `operator` - A registrar or the admin
+    /// * `service` - Address of the service
+    /// * `role` - Role being granted
+    /// * `stake_ref` - Reference to the stake backing the service
+    pub fn register_service(
+        env: Env,
+        operator: Address,
+        service: Address,
+        role: ServiceRole,
+        stake_ref: BytesN<32>,
+    ) -> Result<(), RegistryError> {
+        operator.require_auth();
+        Self::require_registrar(&env, &operator)?;
+
+        let mut services: Map<(Address, ServiceRole), ServiceRegistration> = env
+            .storage()
+            .instance()
+            .get(&SERVICES_KEY)
+            .unwrap_or(Map::new(&env));
+        services.set(
+            (service.clone(), role),
+            ServiceRegistration {
+                stake_ref,
+                registered_at: env.ledger().timestamp(),
+            },
+        );
+        env.storage().instance().set(&SERVICES_KEY, &services);
+
+        ServiceRoleUpdated {
+            service,
+            role,
+            operator,
+            enabled: true,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Revoke a service's role
+    ///
+    /// # Arguments
+    /// * `operator` - A registrar or the admin
+    /// * `service` - Address of the service
+    /// * `role` - Role being revoked
+    pub fn revoke_service(
+        env: Env,
+        operator: Address,
+        service: Address,
+        role: ServiceRole,
+    ) -> Result<(), RegistryError> {
+        operator.require_auth();
+        Self::require_registrar(&env, &operator)?;
+
+        let mut services: Map<(Address, ServiceRole), ServiceRegistration> = env
+            .storage()
+            .instance()
+            .get(&SERVICES_KEY)
+            .unwrap_or(Map::new(&env));
+        if services.remove((service.clone(), role)).is_none() {
+            return Err(RegistryError::ServiceNotFound);
+        }
+        env.storage().instance().set(&SERVICES_KEY, &services);
+
+        ServiceRoleUpdated {
+            service,
+            role,
+            operator,
+            enabled: false,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Get a service's registration for a role, if any
+    pub fn get_service(env: Env, service: Address, role: ServiceRole) -> Option<ServiceRegistration> {
+        let services: Map<(Address, ServiceRole), ServiceRegistration> = env
+            .storage()
+            .instance()
+            .get(&SERVICES_KEY)
+            .unwrap_or(Map::new(&env));
+        services.get((service, role))
+    }
+
+    /// Check whether a service currently holds a role
+    pub fn has_service_role(env: Env, service: Address, role: ServiceRole) -> bool {
+        Self::get_service(env, service, role).is_some()
     }
 
     // Internal helper functions
@@ -430,6 +1055,89 @@ impl DarkPoolRegistry {
         Ok(())
     }
 
+    /// Verify caller is a registrar or the admin
+    fn require_registrar(env: &Env, caller: &Address) -> Result<(), RegistryError> {
+        if !Self::is_registrar(env.clone(), caller.clone()) {
+            return Err(RegistryError::OnlyRegistrar);
+        }
+        Ok(())
+    }
+
+    /// Remove and return a pending registration request
+    fn take_registration_request(
+        env: &Env,
+        applicant: &Address,
+    ) -> Result<RegistrationRequest, RegistryError> {
+        let mut queue: Map<Address, RegistrationRequest> = env
+            .storage()
+            .instance()
+            .get(&REG_QUEUE_KEY)
+            .unwrap_or(Map::new(env));
+        let request = queue
+            .get(applicant.clone())
+            .ok_or(RegistryError::RequestNotFound)?;
+        queue.remove(applicant.clone());
+        env.storage().instance().set(&REG_QUEUE_KEY, &queue);
+        Ok(request)
+    }
+
+    /// Validate a participant and add it to the list and whitelist tree
+    fn insert_participant(env: &Env, participant: Participant) -> Result<u32, RegistryError> {
+        // Check participant doesn't already exist
+        let mut participants: Vec<Participant> = env
+            .storage()
+            .instance()
+            .get(&PARTICIPANTS_KEY)
+            .unwrap_or(vec![env]);
+
+        for p in participants.iter() {
+            if p.trading_address == participant.trading_address {
+                return Err(RegistryError::ParticipantAlreadyExists);
+            }
+        }
+
+        // Validate KYC expiry is in the future
+        let current_time = env.ledger().timestamp();
+        if participant.kyc_expiry <= current_time {
+            return Err(RegistryError::InvalidKYCExpiry);
+        }
+
+        // Add participant's id_hash to the Merkle tree
+        let tree_index = Self::add_to_whitelist_tree(env, participant.id_hash.clone())?;
+
+        // Store participant with tree index
+        let mut new_participant = participant.clone();
+        new_participant.tree_index = tree_index;
+        participants.push_back(new_participant);
+        env.storage().instance().set(&PARTICIPANTS_KEY, &participants);
+
+        Ok(tree_index)
+    }
+
+    /// Record the time a whitelist root became current
+    ///
+    /// Roots go into a ring of `ROOT_HISTORY_SIZE` slots; the root in the
+    /// slot being reused is forgotten.
+    fn record_root(env: &Env, root: &BytesN<32>) {
+        let count: u32 = env.storage().instance().get(&ROOT_COUNT_KEY).unwrap_or(0);
+        let slot = (ROOT_HISTORY_KEY, count % ROOT_HISTORY_SIZE);
+        let evicted: Option<BytesN<32>> = env.storage().persistent().get(&slot);
+        if let Some(evicted) = evicted.filter(|evicted| evicted != root) {
+            env.storage().persistent().remove(&(ROOT_HISTORY_KEY, evicted));
+        }
+
+        let entry = (ROOT_HISTORY_KEY, root.clone());
+        env.storage().persistent().set(&slot, root);
+        env.storage().persistent().set(&entry, &env.ledger().timestamp());
+        env.storage()
+            .persistent()
+            .extend_ttl(&slot, ROOT_TTL_THRESHOLD, ROOT_TTL_EXTEND_TO);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, ROOT_TTL_THRESHOLD, ROOT_TTL_EXTEND_TO);
+        env.storage().instance().set(&ROOT_COUNT_KEY, &(count + 1));
+    }
+
     /// Add an ID hash to the whitelist Merkle tree
     fn add_to_whitelist_tree(env: &Env, id_hash: BytesN<32>) -> Result<u32, RegistryError> {
         // Load current tree state
@@ -447,11 +1155,11 @@ impl DarkPoolRegistry {
             .storage()
             .instance()
             .get(&TREE_ROOT_KEY)
-            .unwrap_or(BytesN::from_array(&env, &[0u8; 32]));
+            .unwrap_or(BytesN::from_array(env, &[0u8; 32]));
 
         // Create tree and insert
         let mut tree = LeanIMTBN254::from_storage(env, leaves, depth, root);
-        tree.insert(id_hash).map_err(|_| RegistryError::TreeAtCapacity)?;
+        tree.insert(id_hash.clone()).map_err(|_| RegistryError::TreeAtCapacity)?;
 
         // Get the leaf index
         let leaf_index = tree.get_leaf_count() - 1;
@@ -461,6 +1169,14 @@ impl DarkPoolRegistry {
         env.storage().instance().set(&TREE_LEAVES_KEY, &new_leaves);
         env.storage().instance().set(&TREE_DEPTH_KEY, &new_depth);
         env.storage().instance().set(&TREE_ROOT_KEY, &new_root);
+        Self::record_root(env, &new_root);
+
+        ParticipantAdded {
+            leaf_index,
+            leaf_hash: id_hash,
+            new_root,
+        }
+        .publish(env);
 
         Ok(leaf_index)
     }
@@ -1,7 +1,10 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Bytes, BytesN, Env, Symbol};
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Bytes, BytesN, Env, Event, Symbol,
+};
 
 fn create_test_participant(env: &Env) -> Participant {
     Participant {
@@ -127,3 +130,345 @@ fn test_whitelist_root_changes() {
     let new_root = client.get_whitelist_root();
     assert_ne!(initial_root, new_root);
 }
+
+#[test]
+fn test_root_timestamps_recorded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let initial_root = client.get_whitelist_root();
+    assert_eq!(client.get_root_timestamp(&initial_root), Some(1_000));
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    client.register_participant(&admin, &create_test_participant(&env));
+
+    let new_root = client.get_whitelist_root();
+    assert_eq!(client.get_root_timestamp(&new_root), Some(5_000));
+    assert_eq!(client.get_root_timestamp(&initial_root), Some(1_000));
+    assert_eq!(client.get_root_timestamp(&BytesN::from_array(&env, &[7u8; 32])), None);
+
+    // Only the last ROOT_HISTORY_SIZE roots are remembered
+    for _ in 1..ROOT_HISTORY_SIZE {
+        client.register_participant(&admin, &create_test_participant(&env));
+    }
+    assert_eq!(client.get_root_timestamp(&initial_root), None);
+    assert_eq!(client.get_root_timestamp(&new_root), Some(5_000));
+    assert_eq!(client.get_root_timestamp(&client.get_whitelist_root()), Some(5_000));
+}
+
+#[test]
+fn test_root_anchors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    client.register_participant(&admin, &create_test_participant(&env));
+    let root = client.get_whitelist_root();
+    assert_eq!(client.get_root_anchor(&root), None);
+
+    let batch_hash = BytesN::from_array(&env, &[5u8; 32]);
+    assert_eq!(client.anchor_root(&admin, &batch_hash), root);
+    let anchor = client.get_root_anchor(&root).unwrap();
+    assert_eq!(anchor.external_ref, batch_hash);
+    assert_eq!(anchor.anchored_at, 1_000);
+
+    // Anchors are permanent
+    let result = client.try_anchor_root(&admin, &BytesN::from_array(&env, &[6u8; 32]));
+    assert_eq!(result, Err(Ok(RegistryError::RootAlreadyAnchored)));
+
+    let outsider = Address::generate(&env);
+    let result = client.try_anchor_root(&outsider, &batch_hash);
+    assert_eq!(result, Err(Ok(RegistryError::OnlyAdmin)));
+
+    // A new root starts unanchored while the old anchor stays queryable
+    let mut second = create_test_participant(&env);
+    second.id_hash = BytesN::from_array(&env, &[2u8; 32]);
+    client.register_participant(&admin, &second);
+    assert_eq!(client.get_root_anchor(&client.get_whitelist_root()), None);
+    assert_eq!(client.get_root_anchor(&root), Some(anchor));
+}
+
+#[test]
+fn test_credential_hash() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let participant = create_test_participant(&env);
+    let credential_hash = BytesN::from_array(&env, &[9u8; 32]);
+
+    // Credentials can only be anchored for registered participants
+    let result = client.try_set_credential_hash(&admin, &participant.trading_address, &credential_hash);
+    assert_eq!(result, Err(Ok(RegistryError::ParticipantNotFound)));
+
+    client.register_participant(&admin, &participant);
+    assert_eq!(client.get_credential_hash(&participant.trading_address), None);
+
+    client.set_credential_hash(&admin, &participant.trading_address, &credential_hash);
+    assert_eq!(client.get_credential_hash(&participant.trading_address), Some(credential_hash));
+
+    client.clear_credential_hash(&admin, &participant.trading_address);
+    assert_eq!(client.get_credential_hash(&participant.trading_address), None);
+}
+
+#[test]
+fn test_get_merkle_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+
+    let path = client.get_merkle_path(&0);
+    assert_eq!(path.len(), WHITELIST_TREE_DEPTH);
+    // A lone leaf's sibling is the empty leaf
+    assert_eq!(path.get(0).unwrap(), BytesN::from_array(&env, &[0u8; 32]));
+
+    let result = client.try_get_merkle_path(&1);
+    assert_eq!(result, Err(Ok(RegistryError::LeafNotFound)));
+}
+
+#[test]
+fn test_tree_mutation_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let participant = create_test_participant(&env);
+    client.register_participant(&admin, &participant);
+    let events = env.events().all().filter_by_contract(&contract_id);
+    let root = client.get_whitelist_root();
+    let added = ParticipantAdded {
+        leaf_index: 0,
+        leaf_hash: participant.id_hash.clone(),
+        new_root: root.clone(),
+    };
+    assert_eq!(events, [added.to_xdr(&env, &contract_id)]);
+
+    client.deactivate_participant(&admin, &participant.trading_address);
+    let deactivated = ParticipantDeactivated {
+        leaf_index: 0,
+        leaf_hash: participant.id_hash,
+        root,
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [deactivated.to_xdr(&env, &contract_id)]
+    );
+}
+
+#[test]
+fn test_export_leaves_pages() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    for i in 1..=3u8 {
+        let mut participant = create_test_participant(&env);
+        participant.id_hash = BytesN::from_array(&env, &[i; 32]);
+        client.register_participant(&admin, &participant);
+    }
+
+    let first = client.export_leaves(&0, &2);
+    assert_eq!(first.len(), 2);
+    assert_eq!(first.get(1).unwrap(), (1, BytesN::from_array(&env, &[2u8; 32])));
+
+    let rest = client.export_leaves(&2, &2);
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest.get(0).unwrap(), (2, BytesN::from_array(&env, &[3u8; 32])));
+
+    assert_eq!(client.export_leaves(&3, &2).len(), 0);
+    assert_eq!(client.export_leaves(&0, &u32::MAX).len(), 3);
+}
+
+#[test]
+fn test_self_registration_queue() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let registrar = Address::generate(&env);
+    client.set_registrar(&admin, &registrar, &true);
+
+    let applicant = Address::generate(&env);
+    let rejected = Address::generate(&env);
+    let id_hash = BytesN::from_array(&env, &[3u8; 32]);
+    let credential_hash = BytesN::from_array(&env, &[4u8; 32]);
+    client.request_registration(&applicant, &id_hash, &ParticipantCategory::Bank, &credential_hash);
+    client.request_registration(&rejected, &id_hash, &ParticipantCategory::Other, &credential_hash);
+
+    let duplicate =
+        client.try_request_registration(&applicant, &id_hash, &ParticipantCategory::Bank, &credential_hash);
+    assert_eq!(duplicate, Err(Ok(RegistryError::RequestAlreadyPending)));
+
+    // Queued requests do not touch the whitelist tree
+    assert_eq!(client.get_registration_queue().len(), 2);
+    assert_eq!(client.get_whitelist_count(), 0);
+
+    let kyc_expiry = env.ledger().timestamp() + 31536000;
+    let outsider = Address::generate(&env);
+    let denied = client.try_approve_registration(&outsider, &applicant, &kyc_expiry);
+    assert_eq!(denied, Err(Ok(RegistryError::OnlyRegistrar)));
+
+    let tree_index = client.approve_registration(&registrar, &applicant, &kyc_expiry);
+    assert_eq!(tree_index, 0);
+    assert_eq!(client.get_whitelist_count(), 1);
+    assert!(client.is_participant_eligible(&applicant));
+    assert_eq!(client.get_credential_hash(&applicant), Some(credential_hash));
+
+    client.reject_registration(&admin, &rejected);
+    assert_eq!(client.get_registration_queue().len(), 0);
+    assert!(client.get_participant(&rejected).is_none());
+    assert_eq!(
+        client.try_reject_registration(&admin, &rejected),
+        Err(Ok(RegistryError::RequestNotFound))
+    );
+}
+
+#[test]
+fn test_reject_clawback_assets() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let asset = create_test_asset(&env);
+    client.set_clawback_asset(&admin, &asset.token_address, &true);
+    assert!(client.is_clawback_asset(&asset.token_address));
+
+    client.set_reject_clawback_assets(&admin, &true);
+    let result = client.try_register_asset(&admin, &asset);
+    assert_eq!(result, Err(Ok(RegistryError::ClawbackAssetRejected)));
+
+    // Plain assets are still listed
+    client.register_asset(&admin, &create_test_asset(&env));
+
+    client.set_reject_clawback_assets(&admin, &false);
+    client.register_asset(&admin, &asset);
+    assert!(client.is_asset_eligible(&asset.token_address));
+}
+
+#[test]
+fn test_token_adapter_selection() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let token = Address::generate(&env);
+    assert_eq!(client.get_token_adapter(&token), TokenAdapter::Sep41);
+
+    let hook = Address::generate(&env);
+    client.set_token_adapter(&admin, &token, &TokenAdapter::Hooked(hook.clone()));
+    assert_eq!(client.get_token_adapter(&token), TokenAdapter::Hooked(hook));
+
+    let outsider = Address::generate(&env);
+    let result = client.try_set_token_adapter(&outsider, &token, &TokenAdapter::Sac);
+    assert_eq!(result, Err(Ok(RegistryError::OnlyAdmin)));
+}
+
+#[test]
+fn test_service_roles() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let verifier = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+
+    let contract_id = env.register(DarkPoolRegistry, (&admin, &verifier, &vk_bytes));
+    let client = DarkPoolRegistryClient::new(&env, &contract_id);
+
+    let operator = Address::generate(&env);
+    client.set_registrar(&admin, &operator, &true);
+
+    let relayer = Address::generate(&env);
+    let stake_ref = BytesN::from_array(&env, &[9u8; 32]);
+    let outsider = Address::generate(&env);
+    let denied = client.try_register_service(&outsider, &relayer, &ServiceRole::Relayer, &stake_ref);
+    assert_eq!(denied, Err(Ok(RegistryError::OnlyRegistrar)));
+
+    env.ledger().set_timestamp(500);
+    client.register_service(&operator, &relayer, &ServiceRole::Relayer, &stake_ref);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [ServiceRoleUpdated {
+            service: relayer.clone(),
+            role: ServiceRole::Relayer,
+            operator: operator.clone(),
+            enabled: true,
+        }
+        .to_xdr(&env, &contract_id)]
+    );
+    assert_eq!(
+        client.get_service(&relayer, &ServiceRole::Relayer),
+        Some(ServiceRegistration { stake_ref, registered_at: 500 })
+    );
+
+    // Roles are held independently
+    assert!(client.has_service_role(&relayer, &ServiceRole::Relayer));
+    assert!(!client.has_service_role(&relayer, &ServiceRole::Auditor));
+
+    client.revoke_service(&admin, &relayer, &ServiceRole::Relayer);
+    assert!(!client.has_service_role(&relayer, &ServiceRole::Relayer));
+    assert_eq!(
+        client.try_revoke_service(&admin, &relayer, &ServiceRole::Relayer),
+        Err(Ok(RegistryError::ServiceNotFound))
+    );
+}
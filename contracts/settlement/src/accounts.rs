@@ -0,0 +1,430 @@
+//! Sub-accounts, memo and path deposits, auto relock and membership badges
+//!
+//! Only compiled with the `accounts` feature.
+
+use soroban_sdk::{
+    contractclient, contractevent, contractimpl, contracttype, symbol_short, token, Address, Bytes, BytesN, Env, String,
+    Symbol, Vec,
+};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError, SettlementRecord,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD,
+};
+#[cfg(feature = "corporate-actions")]
+use crate::LotSource;
+
+const AUTO_RELOCK_KEY: Symbol = symbol_short!("relock");
+
+const BADGES_KEY: Symbol = symbol_short!("badges");
+
+const BADGE_COUNT_KEY: Symbol = symbol_short!("badge_cnt");
+
+const BADGE_URI_KEY: Symbol = symbol_short!("badge_uri");
+
+const PATH_ROUTER_KEY: Symbol = symbol_short!("path_rtr");
+
+/// Path payment router used by `deposit_via_path`
+///
+/// Sells `amount_in` of `path[0]` held by `from` through the intermediate
+/// assets of `path`, delivers the last asset to `to`, and returns the
+/// amount delivered. Fails if that is below `min_out`.
+#[contractclient(name = "PathRouterClient")]
+pub trait PathRouter {
+    fn swap_exact_in(env: Env, from: Address, path: Vec<Address>, amount_in: i128, min_out: i128, to: Address) -> i128;
+}
+
+/// Event emitted for a deposit routed to a memo sub-account
+///
+/// Lets a custodian reconcile deposits into its omnibus address against
+/// the client memos they were made for.
+#[contractevent]
+#[derive(Clone)]
+pub struct MemoDeposit {
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub memo: BytesN<32>,
+    pub sub_account: Symbol,
+    pub asset: Address,
+    pub amount: i128,
+}
+
+/// Event emitted for a deposit funded through a path payment
+#[contractevent]
+#[derive(Clone)]
+pub struct PathDeposit {
+    #[topic]
+    pub depositor: Address,
+    #[topic]
+    pub asset: Address,
+    pub send_asset: Address,
+    pub send_amount: i128,
+    pub received: i128,
+}
+
+/// Non-transferable pool membership badge, issued on a participant's first deposit
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct MembershipBadge {
+    /// Issue order, starting at 1
+    pub number: u32,
+    pub issued_at: u64,
+    /// Asset of the deposit that earned the badge
+    pub first_asset: Address,
+    /// Metadata URI configured when the badge was issued
+    pub metadata_uri: String,
+}
+
+/// Event emitted when a membership badge is issued
+#[contractevent]
+#[derive(Clone)]
+pub struct BadgeIssued {
+    #[topic]
+    pub holder: Address,
+    pub number: u32,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Set the metadata URI recorded on membership badges issued from now on
+    pub fn set_badge_uri(env: Env, admin: Address, uri: String) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&BADGE_URI_KEY, &uri);
+        Ok(())
+    }
+
+    /// Get a participant's membership badge, if they have deposited
+    ///
+    /// Badges cannot be transferred; frontends can gate analytics and show
+    /// fee tiers on them without extra infrastructure.
+    pub fn get_badge(env: Env, holder: Address) -> Option<MembershipBadge> {
+        env.storage().persistent().get(&(BADGES_KEY, holder))
+    }
+
+    /// Get how many membership badges have been issued
+    pub fn get_badge_count(env: Env) -> u32 {
+        env.storage().instance().get(&BADGE_COUNT_KEY).unwrap_or(0)
+    }
+
+    /// Keep settlement proceeds in an asset locked for the next order
+    ///
+    /// With auto-relock on, assets and payments received from settlements
+    /// are credited as locked escrow, skipping the unlock/relock round trip.
+    ///
+    /// # Arguments
+    /// * `participant` - Participant setting the preference (must authenticate)
+    /// * `asset_address` - Asset the preference applies to
+    /// * `enabled` - Whether proceeds stay locked
+    pub fn set_auto_relock(env: Env, participant: Address, asset_address: Address, enabled: bool) {
+        participant.require_auth();
+
+        let entry = (AUTO_RELOCK_KEY, participant, asset_address);
+        if enabled {
+            env.storage().persistent().set(&entry, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        } else {
+            env.storage().persistent().remove(&entry);
+        }
+    }
+
+    /// Check whether settlement proceeds in an asset stay locked
+    pub fn is_auto_relock(env: Env, participant: Address, asset_address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(AUTO_RELOCK_KEY, participant, asset_address))
+            .unwrap_or(false)
+    }
+
+    /// Settle a matched trade against named escrow sub-accounts
+    ///
+    /// Identical to `settle_trade`, except that the buyer's payment and the
+    /// seller's asset are drawn from (and proceeds credited to) the given
+    /// sub-accounts instead of the default one. The proof does not commit to
+    /// sub-accounts, so each party naming a non-default one must authorize
+    /// the call or have its settle-scoped broker approve the match.
+    pub fn settle_trade_subaccounts(
+        env: Env,
+        match_id: BytesN<32>,
+        buyer: Address,
+        buyer_account: Symbol,
+        seller: Address,
+        seller_account: Symbol,
+        asset_address: Address,
+        payment_asset: Address,
+        quantity: i128,
+        price: i128,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<SettlementRecord, SettlementError> {
+        Self::require_unmetered(&env)?;
+        Self::execute_settlement(
+            &env,
+            None,
+            Self::get_auth_mode(env.clone()),
+            &match_id,
+            &buyer,
+            &buyer_account,
+            &seller,
+            &seller_account,
+            &asset_address,
+            &payment_asset,
+            quantity,
+            price,
+            &proof_bytes,
+            &pub_signals_bytes,
+        )
+    }
+
+    /// Deposit tokens into a named escrow sub-account
+    ///
+    /// # Arguments
+    /// * `depositor` - Address of the depositor (must authenticate)
+    /// * `sub_account` - Sub-account to credit (e.g., a client or strategy name)
+    /// * `asset_address` - Token contract address
+    /// * `amount` - Amount to deposit
+    pub fn deposit_to(
+        env: Env,
+        depositor: Address,
+        sub_account: Symbol,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        depositor.require_auth();
+        Self::require_asset_active(&env, &asset_address)?;
+
+        Self::asset_adapter(&env, &asset_address).transfer(&env, &depositor, &env.current_contract_address(), amount);
+
+        let key = EscrowKey::new(&depositor, &sub_account, &asset_address);
+        #[cfg(feature = "corporate-actions")]
+        Self::open_lot(&env, &depositor, &asset_address, amount, LotSource::Deposit);
+        Self::issue_badge(&env, &depositor, &asset_address);
+        Ok(Self::credit_escrow(&env, &key, amount))
+    }
+
+    /// Deposit tokens for a client of an omnibus address, identified by memo hash
+    ///
+    /// Credits the sub-account derived from the memo by
+    /// `get_memo_sub_account`. Locks, settlements and withdrawals for the
+    /// client then use the `_in`, `_subaccounts` and `_from` entry points
+    /// with that sub-account, so each (address, memo) pair is funded and
+    /// settled separately.
+    ///
+    /// # Arguments
+    /// * `depositor` - Custodian address (must authenticate)
+    /// * `memo` - Hash of the client's deposit memo
+    /// * `asset_address` - Token contract address
+    /// * `amount` - Amount to deposit
+    pub fn deposit_with_memo(
+        env: Env,
+        depositor: Address,
+        memo: BytesN<32>,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        let sub_account = Self::get_memo_sub_account(env.clone(), memo.clone());
+        let balance = Self::deposit_to(env.clone(), depositor.clone(), sub_account.clone(), asset_address.clone(), amount)?;
+        MemoDeposit { depositor, memo, sub_account, asset: asset_address, amount }.publish(&env);
+        Ok(balance)
+    }
+
+    /// Set the router that converts assets for `deposit_via_path`
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `router` - Path payment router, or `None` to disable path deposits
+    pub fn set_path_router(env: Env, admin: Address, router: Option<Address>) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        match router {
+            Some(router) => env.storage().instance().set(&PATH_ROUTER_KEY, &router),
+            None => env.storage().instance().remove(&PATH_ROUTER_KEY),
+        }
+        Ok(())
+    }
+
+    /// Get the path payment router, if set
+    pub fn get_path_router(env: Env) -> Option<Address> {
+        env.storage().instance().get(&PATH_ROUTER_KEY)
+    }
+
+    /// Deposit the proceeds of a path payment into escrow
+    ///
+    /// Converts `send_amount` of the first asset in `path` into the last one
+    /// through the path router, delivered to the depositor, then deposits
+    /// what actually arrived. A path payment can deliver a little more or
+    /// less than quoted, so the credit is measured from the depositor's
+    /// balance rather than taken from the request, and only has to reach
+    /// `min_received`.
+    ///
+    /// # Arguments
+    /// * `depositor` - Address of the depositor (must authenticate, including the router's transfer)
+    /// * `path` - Assets to convert through, from the asset sent to the asset deposited
+    /// * `send_amount` - Amount of the first asset to sell
+    /// * `min_received` - Least amount of the deposited asset to accept
+    ///
+    /// # Returns
+    /// The amount credited to escrow
+    pub fn deposit_via_path(
+        env: Env,
+        depositor: Address,
+        path: Vec<Address>,
+        send_amount: i128,
+        min_received: i128,
+    ) -> Result<i128, SettlementError> {
+        depositor.require_auth();
+        let router: Address = env.storage().instance().get(&PATH_ROUTER_KEY).ok_or(SettlementError::PathRouterNotSet)?;
+        if path.len() < 2 {
+            return Err(SettlementError::InvalidPath);
+        }
+        if send_amount <= 0 || min_received <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        let send_asset = path.first_unchecked();
+        let asset = path.last_unchecked();
+        Self::require_asset_active(&env, &asset)?;
+
+        let token = token::Client::new(&env, &asset);
+        let before = token.balance(&depositor);
+        PathRouterClient::new(&env, &router).swap_exact_in(&depositor, &path, &send_amount, &min_received, &depositor);
+        let received = token.balance(&depositor) - before;
+        if received < min_received {
+            return Err(SettlementError::ReceivedBelowMinimum);
+        }
+
+        Self::asset_adapter(&env, &asset).transfer(&env, &depositor, &env.current_contract_address(), received);
+        Self::add_escrow_balance(&env, &depositor, &asset, received);
+        #[cfg(feature = "corporate-actions")]
+        Self::open_lot(&env, &depositor, &asset, received, LotSource::Deposit);
+        Self::issue_badge(&env, &depositor, &asset);
+        PathDeposit { depositor, asset, send_asset, send_amount, received }.publish(&env);
+        Ok(received)
+    }
+
+    /// Withdraw unlocked tokens from a named escrow sub-account
+    pub fn withdraw_from(
+        env: Env,
+        withdrawer: Address,
+        sub_account: Symbol,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        withdrawer.require_auth();
+
+        let key = EscrowKey::new(&withdrawer, &sub_account, &asset_address);
+        if Self::available_balance(&env, &key) < amount {
+            return Err(SettlementError::InsufficientBalance);
+        }
+        let new_balance = Self::debit_escrow(&env, &key, amount)?;
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(&env, &withdrawer, &asset_address, amount);
+
+        Self::pay_out(&env, &withdrawer, &asset_address, amount)?;
+
+        Ok(new_balance)
+    }
+
+    /// Move unlocked escrow between two of a participant's sub-accounts
+    ///
+    /// # Arguments
+    /// * `participant` - Owner of both sub-accounts (must authenticate)
+    /// * `asset_address` - Token contract address
+    /// * `from_account` - Sub-account to debit
+    /// * `to_account` - Sub-account to credit
+    /// * `amount` - Amount to move
+    pub fn transfer_between_subaccounts(
+        env: Env,
+        participant: Address,
+        asset_address: Address,
+        from_account: Symbol,
+        to_account: Symbol,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        participant.require_auth();
+
+        if amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+
+        let from = EscrowKey::new(&participant, &from_account, &asset_address);
+        let to = EscrowKey::new(&participant, &to_account, &asset_address);
+        if Self::available_balance(&env, &from) < amount {
+            return Err(SettlementError::InsufficientBalance);
+        }
+
+        Self::debit_escrow(&env, &from, amount)?;
+        Self::credit_escrow(&env, &to, amount);
+        Ok(())
+    }
+
+    /// Lock escrow held in a named sub-account for a pending order
+    pub fn lock_escrow_in(
+        env: Env,
+        trader: Address,
+        sub_account: Symbol,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        trader.require_auth();
+        Self::require_asset_active(&env, &asset_address)?;
+
+        let key = EscrowKey::new(&trader, &sub_account, &asset_address);
+        if Self::available_balance(&env, &key) < amount {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+
+        Self::credit_locked(&env, &key, amount);
+        Ok(())
+    }
+
+    /// Unlock escrow held in a named sub-account when an order is cancelled
+    pub fn unlock_escrow_in(
+        env: Env,
+        trader: Address,
+        sub_account: Symbol,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        trader.require_auth();
+
+        let key = EscrowKey::new(&trader, &sub_account, &asset_address);
+        Self::require_unpledged(&env, &key, amount)?;
+        Self::debit_locked(&env, &key, amount)
+    }
+
+    /// Issue a membership badge on a participant's first deposit
+    pub(crate) fn issue_badge(env: &Env, holder: &Address, asset: &Address) {
+        let entry = (BADGES_KEY, holder.clone());
+        if env.storage().persistent().has(&entry) {
+            return;
+        }
+
+        let number = Self::get_badge_count(env.clone()) + 1;
+        env.storage().instance().set(&BADGE_COUNT_KEY, &number);
+        let badge = MembershipBadge {
+            number,
+            issued_at: env.ledger().timestamp(),
+            first_asset: asset.clone(),
+            metadata_uri: env
+                .storage()
+                .instance()
+                .get(&BADGE_URI_KEY)
+                .unwrap_or(String::from_str(env, "")),
+        };
+        env.storage().persistent().set(&entry, &badge);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+
+        BadgeIssued {
+            holder: holder.clone(),
+            number,
+        }
+        .publish(env);
+    }
+}
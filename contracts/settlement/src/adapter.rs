@@ -0,0 +1,41 @@
+//! Uniform token transfers across the asset kinds the registry can list
+
+use soroban_sdk::{contractclient, token, Address, Env};
+
+use crate::registry_wasm::{self, TokenAdapter};
+
+/// Issuer hook consulted before the pool moves a wrapped RWA token
+///
+/// The hook vetoes a transfer by failing, which aborts the whole call.
+#[contractclient(name = "TransferHookClient")]
+pub trait TransferHook {
+    fn on_transfer(env: Env, token: Address, from: Address, to: Address, amount: i128);
+}
+
+/// Transfers a token the way its registry listing requires
+pub struct AssetAdapter {
+    token: Address,
+    kind: TokenAdapter,
+}
+
+impl AssetAdapter {
+    /// Look up the token's adapter in the registry
+    pub fn load(env: &Env, registry: &Address, token: &Address) -> Self {
+        let kind = registry_wasm::Client::new(env, registry).get_token_adapter(token);
+        Self {
+            token: token.clone(),
+            kind,
+        }
+    }
+
+    pub fn transfer(&self, env: &Env, from: &Address, to: &Address, amount: i128) {
+        match &self.kind {
+            // SAC and SEP-41 tokens share the standard token interface
+            TokenAdapter::Sac | TokenAdapter::Sep41 => {}
+            TokenAdapter::Hooked(hook) => {
+                TransferHookClient::new(env, hook).on_transfer(&self.token, from, to, &amount);
+            }
+        }
+        token::Client::new(env, &self.token).transfer(from, to, &amount);
+    }
+}
@@ -0,0 +1,286 @@
+//! Multi-leg basket settlements
+//!
+//! Only compiled with the `baskets` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, Symbol, Vec};
+#[cfg(feature = "records")]
+use soroban_sdk::xdr::ToXdr;
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementAuthMode,
+    SettlementError, BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, BASKET_PROOF, BASKET_VK_KEY,
+};
+#[cfg(feature = "debug-events")]
+use crate::debug;
+
+const BASKETS_KEY: Symbol = symbol_short!("baskets");
+
+/// One asset leg of a basket trade
+#[derive(Clone)]
+#[contracttype]
+pub struct SettlementLeg {
+    pub asset_address: Address,
+    pub quantity: i128,
+    pub price: i128,
+}
+
+/// Settlement record for a basket of legs settled under one proof
+#[derive(Clone)]
+#[contracttype]
+pub struct BasketRecord {
+    pub match_id: BytesN<32>,
+    pub buyer: Address,
+    pub seller: Address,
+    pub payment_asset: Address,
+    pub legs: Vec<SettlementLeg>,
+    pub payment_amount: i128,
+    pub timestamp: u64,
+    pub nullifier: BytesN<32>,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Set the verification key for basket settlement proofs
+    ///
+    /// The key must parse, have valid curve points and carry two signals
+    /// per leg after the two shared ones; otherwise it is rejected.
+    pub fn set_basket_vk(env: Env, admin: Address, vk_bytes: Bytes) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::validate_vk(&env, &BASKET_PROOF, &vk_bytes)?;
+
+        env.storage().instance().set(&BASKET_VK_KEY, &vk_bytes);
+        Ok(())
+    }
+
+    /**
+     * Settle several asset legs between the same counterparties under one proof
+     *
+     * All legs execute atomically, so a portfolio trade does not leak through
+     * a series of correlated single-asset settlements.
+     *
+     * Basket circuit public signals format (4 + 3 * legs signals):
+     * [0] nullifierHash (output)
+     * [1] whitelistRoot
+     * [2] buyCommitment
+     * [3] sellCommitment
+     * [4 + 3i] assetHash of leg i: SHA-256 of its asset address XDR, top byte cleared
+     * [5 + 3i] quantity of leg i
+     * [6 + 3i] price of leg i
+     * [4 + 3 * legs] poolDomain (domain-bound circuit only)
+     *
+     * TWAP and iceberg parents linked to either commitment are filled with
+     * the basket's total quantity, as a single-asset settlement fills them.
+     *
+     * # Arguments
+     * * `match_id` - Unique identifier for this basket match
+     * * `buyer` - Buyer's address
+     * * `seller` - Seller's address
+     * * `payment_asset` - The payment token for every leg
+     * * `legs` - Asset legs, in the order committed to by the proof
+     * * `proof_bytes` - Serialized ZK proof
+     * * `pub_signals_bytes` - Serialized public signals
+     */
+    pub fn settle_basket(
+        env: Env,
+        match_id: BytesN<32>,
+        buyer: Address,
+        seller: Address,
+        payment_asset: Address,
+        legs: Vec<SettlementLeg>,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<BasketRecord, SettlementError> {
+        Self::require_current_storage(&env)?;
+        Self::require_unmetered(&env)?;
+        Self::mark_in_flight(&env, &match_id, None)?;
+        if legs.is_empty() {
+            return Err(SettlementError::EmptyBasket);
+        }
+        Self::check_counterparties(&env, &buyer, &seller)?;
+        Self::take_confirmed_match(&env, &match_id, &buyer, &seller)?;
+        Self::require_payment_asset(&env, &payment_asset)?;
+        Self::require_asset_active(&env, &payment_asset)?;
+        for leg in legs.iter() {
+            Self::require_asset_active(&env, &leg.asset_address)?;
+            Self::require_fresh_oracle(&env, &leg.asset_address)?;
+        }
+
+        let pub_signals = Self::parse_public_signals(&env, &pub_signals_bytes)?;
+        #[cfg(feature = "debug-events")]
+        debug::signals(&env, &match_id, &pub_signals);
+        Self::check_basket_signals(&env, &legs, &pub_signals)?;
+
+        if Self::get_auth_mode(env.clone()) == SettlementAuthMode::BothParties {
+            Self::require_party_auth(&env, &buyer, &match_id);
+            Self::require_party_auth(&env, &seller, &match_id);
+        }
+
+        if Self::is_whitelist_check_enabled(env.clone()) {
+            Self::check_whitelist_root(&env, &pub_signals.get(1).unwrap())?;
+        }
+
+        let nullifier = pub_signals.get(0).unwrap();
+        if Self::is_nullifier_used(env.clone(), nullifier.clone()) {
+            return Err(SettlementError::NullifierUsed);
+        }
+
+        let mut total_quantity = 0i128;
+        for leg in legs.iter() {
+            total_quantity = total_quantity
+                .checked_add(leg.quantity)
+                .ok_or(SettlementError::NotionalOverflow)?;
+        }
+        #[cfg(feature = "schedules")]
+        let (twap_fills, iceberg_fills) = {
+            let (buy_commitment, sell_commitment) = (pub_signals.get(2).unwrap(), pub_signals.get(3).unwrap());
+            (
+                Self::check_twap_slices(&env, &buyer, &seller, &buy_commitment, &sell_commitment, total_quantity)?,
+                Self::check_iceberg_slices(&env, &buyer, &seller, &buy_commitment, &sell_commitment, total_quantity)?,
+            )
+        };
+
+        let vk_bytes: Bytes = env
+            .storage()
+            .instance()
+            .get(&BASKET_VK_KEY)
+            .ok_or(SettlementError::BasketVkNotSet)?;
+        if !Self::verify_proof(&env, &BASKET_PROOF, &vk_bytes, &proof_bytes, &pub_signals_bytes)? {
+            return Err(SettlementError::InvalidProof);
+        }
+
+        // Check every leg, summing quantities per asset in case legs repeat
+        let mut payment_amount = 0i128;
+        let mut deliveries: Map<Address, i128> = Map::new(&env);
+        for leg in legs.iter() {
+            Self::check_trade_amounts(leg.quantity, leg.price)?;
+            #[cfg(feature = "fx")]
+            let leg_payment = Self::convert_payment(&env, &leg.asset_address, &payment_asset, leg.price)?;
+            #[cfg(not(feature = "fx"))]
+            let leg_payment = leg.price;
+
+            let delivered = deliveries
+                .get(leg.asset_address.clone())
+                .unwrap_or(0)
+                .checked_add(leg.quantity)
+                .ok_or(SettlementError::NotionalOverflow)?;
+            Self::check_transfer(&env, &EscrowKey::main(&seller, &leg.asset_address), delivered)?;
+            Self::check_inventory(&env, &EscrowKey::main(&buyer, &leg.asset_address), delivered)?;
+            deliveries.set(leg.asset_address.clone(), delivered);
+            payment_amount = payment_amount
+                .checked_add(leg_payment)
+                .ok_or(SettlementError::NotionalOverflow)?;
+        }
+        Self::check_notional(&env, &payment_asset, payment_amount)?;
+        let buyer_payment = EscrowKey::main(&buyer, &payment_asset);
+        let seller_payment = EscrowKey::main(&seller, &payment_asset);
+        let fees = Self::check_payment(&env, &buyer_payment, &seller_payment, payment_amount)?;
+
+        for leg in legs.iter() {
+            Self::deliver_leg(
+                &env,
+                &match_id,
+                &EscrowKey::main(&seller, &leg.asset_address),
+                &EscrowKey::main(&buyer, &leg.asset_address),
+                leg.quantity,
+            );
+        }
+        Self::commit_payment(&env, &match_id, &buyer_payment, &seller_payment, payment_amount, fees);
+
+        Self::mark_nullifier_used(&env, &nullifier);
+        #[cfg(feature = "schedules")]
+        {
+            for (parent, order) in twap_fills.iter() {
+                Self::write_twap(&env, &parent, &order);
+            }
+            for (parent, order) in iceberg_fills.iter() {
+                Self::write_iceberg(&env, &parent, &order);
+            }
+        }
+
+        let record = BasketRecord {
+            match_id: match_id.clone(),
+            buyer,
+            seller,
+            payment_asset,
+            legs,
+            payment_amount,
+            timestamp: env.ledger().timestamp(),
+            nullifier,
+        };
+
+        let entry = (BASKETS_KEY, match_id.clone());
+        #[cfg(not(feature = "records"))]
+        if env.storage().persistent().has(&entry) {
+            return Err(SettlementError::AlreadySettled);
+        }
+        env.storage().persistent().set(&entry, &record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        #[cfg(feature = "records")]
+        Self::assign_sequence(&env, &match_id)?;
+        Self::bump_instance(&env);
+        #[cfg(feature = "records")]
+        {
+            Self::store_receipt(&env, &match_id, &record.clone().to_xdr(&env));
+            Self::record_compliance_evidence(&env, &match_id, &pub_signals.get(1).unwrap());
+        }
+        #[cfg(feature = "ops")]
+        Self::tally_activity(&env, &record.payment_asset, record.payment_amount, None);
+
+        Ok(record)
+    }
+
+    /// Get a basket settlement by match ID
+    ///
+    /// Gated like `get_settlement` when record privacy is on.
+    pub fn get_basket(
+        env: Env,
+        viewer: Option<Address>,
+        match_id: BytesN<32>,
+    ) -> Result<Option<BasketRecord>, SettlementError> {
+        let see_all = Self::require_record_viewer(&env, &viewer)?;
+        let record = match env.storage().persistent().get::<_, BasketRecord>(&(BASKETS_KEY, match_id)) {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        if !see_all && !viewer.is_some_and(|v| v == record.buyer || v == record.seller) {
+            return Err(SettlementError::RecordAccessDenied);
+        }
+        Ok(Some(record))
+    }
+
+    /// Check basket public signals commit to exactly the given legs
+    ///
+    /// Each leg's asset hash must be its asset's `address_field_hash`, so a
+    /// proof for one asset cannot settle a leg in another. Under domain
+    /// binding the proof must also end with this pool's domain separator.
+    pub(crate) fn check_basket_signals(
+        env: &Env,
+        legs: &Vec<SettlementLeg>,
+        pub_signals: &Vec<BytesN<32>>,
+    ) -> Result<(), SettlementError> {
+        let domain_bound = Self::is_domain_bound(env.clone());
+        let leg_signals = 4 + 3 * legs.len();
+        if pub_signals.len() != if domain_bound { leg_signals + 1 } else { leg_signals } {
+            return Err(SettlementError::InvalidProof);
+        }
+        if domain_bound && pub_signals.get(leg_signals).unwrap() != Self::get_domain_separator(env.clone()) {
+            return Err(SettlementError::DomainMismatch);
+        }
+
+        for (i, leg) in legs.iter().enumerate() {
+            let base = 4 + 3 * i as u32;
+            if pub_signals.get(base).unwrap() != Self::address_field_hash(env, &leg.asset_address) {
+                return Err(SettlementError::InvalidProof);
+            }
+            let quantity = Self::signal_to_i128(&pub_signals.get(base + 1).unwrap())?;
+            let price = Self::signal_to_i128(&pub_signals.get(base + 2).unwrap())?;
+            if quantity != leg.quantity || price != leg.price {
+                return Err(SettlementError::InvalidProof);
+            }
+        }
+        Ok(())
+    }
+}
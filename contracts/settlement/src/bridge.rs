@@ -0,0 +1,311 @@
+//! Hashlock bridge locks for cross-pool matches
+//!
+//! Only compiled with the `bridge` feature.
+
+use soroban_sdk::{
+    contractclient, contractevent, contractimpl, contracttype, symbol_short, vec, Address, Bytes, BytesN, Env, Map,
+    Symbol, Vec,
+};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, BPS_DENOMINATOR,
+};
+
+const BRIDGE_KEY: Symbol = symbol_short!("bridge");
+
+pub(crate) const OPEN_LOCKS_KEY: Symbol = symbol_short!("open_lcks");
+
+const STALE_PENALTY_KEY: Symbol = symbol_short!("stale_pen");
+
+const INSURANCE_KEY: Symbol = symbol_short!("insurance");
+
+/// Hashlock/timelock escrow shared by DuskPool deployments
+///
+/// A cross-pool match locks each leg in its own pool under the same
+/// hashlock. Revealing the preimage claims both legs, atomically when done
+/// through `claim_cross_pool`; otherwise each leg is refundable to its
+/// sender once its timelock passes.
+#[contractclient(name = "SettlementBridgeClient")]
+pub trait SettlementBridge {
+    fn claim_bridge_lock(env: Env, hashlock: BytesN<32>, preimage: Bytes) -> Result<(), SettlementError>;
+    fn get_bridge_lock(env: Env, hashlock: BytesN<32>) -> Option<BridgeLock>;
+}
+
+/// Lifecycle of a cross-pool bridge lock
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum BridgeLockStatus {
+    Open = 0,
+    Claimed = 1,
+    Refunded = 2,
+}
+
+/// One leg of a cross-pool match, held by this pool until claimed or refunded
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BridgeLock {
+    pub sender: EscrowKey,
+    /// Account credited in this pool when the preimage is revealed
+    pub recipient: Address,
+    pub amount: i128,
+    /// Ledger timestamp after which the sender can reclaim the funds
+    pub timelock: u64,
+    pub status: BridgeLockStatus,
+}
+
+/// Event emitted when a bridge lock is claimed, publishing the preimage
+#[contractevent]
+#[derive(Clone)]
+pub struct BridgeClaimed {
+    #[topic]
+    pub hashlock: BytesN<32>,
+    pub preimage: Bytes,
+}
+
+/// Event emitted when an expired bridge lock is refunded minus a penalty
+#[contractevent]
+#[derive(Clone)]
+pub struct StaleLockPenalized {
+    #[topic]
+    pub hashlock: BytesN<32>,
+    pub asset: Address,
+    pub penalty: i128,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Lock one leg of a cross-pool match under a hashlock
+    ///
+    /// The sender's locked escrow moves into the bridge lock. The counterparty
+    /// locks the other leg in the peer pool under the same hashlock with a
+    /// shorter timelock, so the preimage holder must claim before either can
+    /// refund.
+    ///
+    /// # Arguments
+    /// * `sender` - Participant delivering this leg (must authenticate)
+    /// * `asset_address` - Asset delivered in this pool
+    /// * `amount` - Amount delivered, taken from the sender's locked escrow
+    /// * `recipient` - Counterparty credited in this pool on claim
+    /// * `hashlock` - SHA-256 of the secret preimage
+    /// * `timelock` - Ledger timestamp after which the sender may refund
+    pub fn open_bridge_lock(
+        env: Env,
+        sender: Address,
+        asset_address: Address,
+        amount: i128,
+        recipient: Address,
+        hashlock: BytesN<32>,
+        timelock: u64,
+    ) -> Result<BridgeLock, SettlementError> {
+        sender.require_auth();
+        Self::require_asset_active(&env, &asset_address)?;
+        if amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        if timelock <= env.ledger().timestamp() {
+            return Err(SettlementError::BridgeLockExpired);
+        }
+
+        let entry = (BRIDGE_KEY, hashlock);
+        if env.storage().persistent().has(&entry) {
+            return Err(SettlementError::BridgeLockExists);
+        }
+
+        let key = EscrowKey::main(&sender, &asset_address);
+        Self::check_transfer(&env, &key, amount)?;
+        Self::commit_debit(&env, &key, amount);
+
+        let lock = BridgeLock {
+            sender: key,
+            recipient,
+            amount,
+            timelock,
+            status: BridgeLockStatus::Open,
+        };
+        env.storage().persistent().set(&entry, &lock);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Self::track_open_lock(&env, &entry.1, true);
+        Ok(lock)
+    }
+
+    /// Claim a bridge lock for its recipient by revealing the preimage
+    pub fn claim_bridge_lock(env: Env, hashlock: BytesN<32>, preimage: Bytes) -> Result<(), SettlementError> {
+        let mut lock = Self::open_bridge_lock_entry(&env, &hashlock)?;
+        if BytesN::from(env.crypto().sha256(&preimage)) != hashlock {
+            return Err(SettlementError::HashlockMismatch);
+        }
+        if env.ledger().timestamp() >= lock.timelock {
+            return Err(SettlementError::BridgeLockExpired);
+        }
+
+        Self::credit_escrow(&env, &EscrowKey::main(&lock.recipient, &lock.sender.asset), lock.amount);
+        lock.status = BridgeLockStatus::Claimed;
+        env.storage().persistent().set(&(BRIDGE_KEY, hashlock.clone()), &lock);
+        Self::track_open_lock(&env, &hashlock, false);
+        BridgeClaimed { hashlock, preimage }.publish(&env);
+        Ok(())
+    }
+
+    /// Claim this pool's leg and the peer pool's leg in one transaction
+    ///
+    /// # Arguments
+    /// * `hashlock` - Hashlock shared by both legs
+    /// * `preimage` - Secret whose SHA-256 is the hashlock
+    /// * `peer_pool` - Settlement contract holding the other leg
+    pub fn claim_cross_pool(
+        env: Env,
+        hashlock: BytesN<32>,
+        preimage: Bytes,
+        peer_pool: Address,
+    ) -> Result<(), SettlementError> {
+        Self::claim_bridge_lock(env.clone(), hashlock.clone(), preimage.clone())?;
+        SettlementBridgeClient::new(&env, &peer_pool).claim_bridge_lock(&hashlock, &preimage);
+        Ok(())
+    }
+
+    /// Return an unclaimed bridge lock to its sender after the timelock
+    ///
+    /// Anyone may call this once the timelock has passed. If the asset has a
+    /// stale-lock penalty, that share of the lock goes to the insurance fund
+    /// and the rest is returned.
+    pub fn refund_bridge_lock(env: Env, hashlock: BytesN<32>) -> Result<(), SettlementError> {
+        let mut lock = Self::open_bridge_lock_entry(&env, &hashlock)?;
+        if env.ledger().timestamp() < lock.timelock {
+            return Err(SettlementError::BridgeLockActive);
+        }
+
+        let asset = lock.sender.asset.clone();
+        let penalty_bps = Self::get_stale_lock_penalty(env.clone(), asset.clone());
+        let penalty = lock.amount * penalty_bps as i128 / BPS_DENOMINATOR;
+        if penalty > 0 {
+            Self::credit_insurance(&env, &asset, penalty);
+            StaleLockPenalized {
+                hashlock: hashlock.clone(),
+                asset,
+                penalty,
+            }
+            .publish(&env);
+        }
+
+        Self::credit_escrow(&env, &lock.sender, lock.amount - penalty);
+        lock.status = BridgeLockStatus::Refunded;
+        env.storage().persistent().set(&(BRIDGE_KEY, hashlock.clone()), &lock);
+        Self::track_open_lock(&env, &hashlock, false);
+        Ok(())
+    }
+
+    /// Set the share of an expired bridge lock kept when it is refunded
+    ///
+    /// The penalty is paid into the asset's insurance fund, so leaving locks
+    /// to expire costs the sender instead of only tying up liquidity.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `asset_address` - Asset the penalty applies to
+    /// * `penalty_bps` - Penalty in basis points of the lock, 0 to disable
+    pub fn set_stale_lock_penalty(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        penalty_bps: u32,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if penalty_bps as i128 > BPS_DENOMINATOR {
+            return Err(SettlementError::InvalidFee);
+        }
+        let mut penalties: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&STALE_PENALTY_KEY)
+            .unwrap_or(Map::new(&env));
+        if penalty_bps == 0 {
+            penalties.remove(asset_address);
+        } else {
+            penalties.set(asset_address, penalty_bps);
+        }
+        env.storage().instance().set(&STALE_PENALTY_KEY, &penalties);
+        Ok(())
+    }
+
+    /// Get the stale-lock penalty for an asset in basis points
+    pub fn get_stale_lock_penalty(env: Env, asset_address: Address) -> u32 {
+        let penalties: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&STALE_PENALTY_KEY)
+            .unwrap_or(Map::new(&env));
+        penalties.get(asset_address).unwrap_or(0)
+    }
+
+    /// Get the insurance fund balance held in an asset
+    pub fn get_insurance_fund(env: Env, asset: Address) -> i128 {
+        let fund: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&INSURANCE_KEY)
+            .unwrap_or(Map::new(&env));
+        fund.get(asset).unwrap_or(0)
+    }
+
+    /// Get a bridge lock by hashlock
+    pub fn get_bridge_lock(env: Env, hashlock: BytesN<32>) -> Option<BridgeLock> {
+        env.storage().persistent().get(&(BRIDGE_KEY, hashlock))
+    }
+
+    /// Get hashlocks of open bridge locks whose timelock has passed
+    ///
+    /// Each returned lock can be returned to its sender with
+    /// `refund_bridge_lock`. At most `limit` hashlocks are returned.
+    pub fn get_expired_locks(env: Env, limit: u32) -> Vec<BytesN<32>> {
+        let now = env.ledger().timestamp();
+        let mut expired: Vec<BytesN<32>> = vec![&env];
+        for position in 0..Self::index_len(&env, &OPEN_LOCKS_KEY) {
+            if expired.len() >= limit {
+                break;
+            }
+            let Some(hashlock) = Self::index_get(&env, &OPEN_LOCKS_KEY, position) else {
+                continue;
+            };
+            let lock = Self::get_bridge_lock(env.clone(), hashlock.clone());
+            if lock.is_some_and(|l| now >= l.timelock) {
+                expired.push_back(hashlock);
+            }
+        }
+        expired
+    }
+
+    /// Load a bridge lock that has not been claimed or refunded yet
+    fn open_bridge_lock_entry(env: &Env, hashlock: &BytesN<32>) -> Result<BridgeLock, SettlementError> {
+        let lock = Self::get_bridge_lock(env.clone(), hashlock.clone()).ok_or(SettlementError::BridgeLockNotFound)?;
+        if lock.status != BridgeLockStatus::Open {
+            return Err(SettlementError::BridgeLockClosed);
+        }
+        Ok(lock)
+    }
+
+    /// Add or remove a hashlock from the index of open bridge locks
+    fn track_open_lock(env: &Env, hashlock: &BytesN<32>, open: bool) {
+        if open {
+            Self::index_add(env, &OPEN_LOCKS_KEY, hashlock);
+        } else {
+            Self::index_remove(env, &OPEN_LOCKS_KEY, hashlock);
+        }
+    }
+
+    /// Add a stale-lock penalty to an asset's insurance fund
+    fn credit_insurance(env: &Env, asset: &Address, amount: i128) {
+        let mut fund: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&INSURANCE_KEY)
+            .unwrap_or(Map::new(env));
+        fund.set(asset.clone(), fund.get(asset.clone()).unwrap_or(0) + amount);
+        env.storage().instance().set(&INSURANCE_KEY, &fund);
+    }
+}
@@ -0,0 +1,108 @@
+//! Order cancellation proofs
+//!
+//! Only compiled with the `cancellation` feature.
+
+use soroban_sdk::{contractimpl, symbol_short, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, CANCEL_PROOF, CANCEL_VK_KEY,
+};
+
+const ORDER_ROOT_KEY: Symbol = symbol_short!("ord_root");
+
+pub(crate) const CANCEL_NULLS_KEY: Symbol = symbol_short!("cncl_null");
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Set the verification key for order cancellation proofs
+    pub fn set_cancel_vk(env: Env, admin: Address, vk_bytes: Bytes) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::validate_vk(&env, &CANCEL_PROOF, &vk_bytes)?;
+
+        env.storage().instance().set(&CANCEL_VK_KEY, &vk_bytes);
+        Ok(())
+    }
+
+    /// Publish the root of the orderbook's commitment tree
+    ///
+    /// Cancellation proofs show membership of the order under this root.
+    pub fn set_order_root(env: Env, admin: Address, root: BytesN<32>) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&ORDER_ROOT_KEY, &root);
+        Ok(())
+    }
+
+    /// Get the published order commitment root
+    pub fn get_order_root(env: Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&ORDER_ROOT_KEY)
+    }
+
+    /**
+     * Cancel an order by proving ownership of its commitment
+     *
+     * The proof shows the caller knows the secret of some order in the
+     * published commitment tree, without revealing which one, and releases
+     * the escrow that order locked. Public signals (from cancel_proof.circom):
+     * [0] cancelNullifier, [1] orderRoot, [2] unlockAmount, [3] traderHash.
+     * `traderHash` binds the proof to the caller (see `address_field_hash`),
+     * so a copied proof cannot be replayed by another account.
+     *
+     * # Arguments
+     * * `trader` - Order owner (must authenticate)
+     * * `asset_address` - Asset the order locked
+     * * `proof_bytes` - Cancellation proof
+     * * `pub_signals_bytes` - Public signals for the proof
+     *
+     * # Returns
+     * * The amount unlocked
+     */
+    pub fn cancel_with_proof(
+        env: Env,
+        trader: Address,
+        asset_address: Address,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<i128, SettlementError> {
+        trader.require_auth();
+
+        let pub_signals = Self::parse_public_signals(&env, &pub_signals_bytes)?;
+        if pub_signals.len() != 4 || pub_signals.get(3).unwrap() != Self::address_field_hash(&env, &trader) {
+            return Err(SettlementError::InvalidProof);
+        }
+        if Self::get_order_root(env.clone()) != Some(pub_signals.get(1).unwrap()) {
+            return Err(SettlementError::OrderRootMismatch);
+        }
+        let nullifier = pub_signals.get(0).unwrap();
+        if Self::is_cancel_nullifier_used(env.clone(), nullifier.clone()) {
+            return Err(SettlementError::NullifierUsed);
+        }
+        let amount = Self::signal_to_i128(&pub_signals.get(2).unwrap())?;
+
+        let vk_bytes: Bytes = env
+            .storage()
+            .instance()
+            .get(&CANCEL_VK_KEY)
+            .ok_or(SettlementError::CancelVkNotSet)?;
+        if !Self::verify_proof(&env, &CANCEL_PROOF, &vk_bytes, &proof_bytes, &pub_signals_bytes)? {
+            return Err(SettlementError::InvalidProof);
+        }
+
+        Self::debit_locked(&env, &EscrowKey::main(&trader, &asset_address), amount)?;
+
+        let entry = (CANCEL_NULLS_KEY, nullifier);
+        env.storage().persistent().set(&entry, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(amount)
+    }
+
+    /// Check if a cancellation nullifier has been used
+    pub fn is_cancel_nullifier_used(env: Env, nullifier: BytesN<32>) -> bool {
+        env.storage().persistent().has(&(CANCEL_NULLS_KEY, nullifier))
+    }
+}
@@ -0,0 +1,948 @@
+//! Splits, asset migrations, balance checkpoints, tax lots and distributions
+//!
+//! Only compiled with the `corporate-actions` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, vec, Address, Env, Map, Symbol, Vec};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, DEFAULT_SUB_ACCOUNT, DUST_KEY, ESCROW_KEY, FX_RATE_SCALE, LOCKED_KEY,
+    PAUSED_KEY,
+};
+
+const CHECKPOINT_IVL_KEY: Symbol = symbol_short!("ckpt_ivl");
+
+const CHECKPOINT_KEY: Symbol = symbol_short!("ckpt");
+
+const CHECKPOINT_HEAD_KEY: Symbol = symbol_short!("ckpt_head");
+
+// Ledger each asset's checkpoints were enabled at, with its split count then
+const CHECKPOINT_FROM_KEY: Symbol = symbol_short!("ckpt_from");
+
+// Escrow held outside the main sub-account, summed per participant and asset
+const SUB_HOLDING_KEY: Symbol = symbol_short!("sub_hold");
+
+/// Most checkpoints `get_balance_at` walks back through
+const MAX_CHECKPOINT_STEPS: u32 = 64;
+
+const DIST_COUNT_KEY: Symbol = symbol_short!("dist_cnt");
+
+const DIST_KEY: Symbol = symbol_short!("dist");
+
+const DIST_CLAIM_KEY: Symbol = symbol_short!("dist_clm");
+
+const DIST_REM_KEY: Symbol = symbol_short!("dist_rem");
+
+const SPLITS_KEY: Symbol = symbol_short!("splits");
+
+// Splits seen by each balance; kept no longer than the balance ledger
+// symbols so the entry key fits wherever the balance key does
+const ESCROW_SPLITS_KEY: Symbol = symbol_short!("escr_spl");
+
+const LOCKED_SPLITS_KEY: Symbol = symbol_short!("lock_spl");
+
+const MIGRATIONS_KEY: Symbol = symbol_short!("migrate");
+
+const LOTS_KEY: Symbol = symbol_short!("lots");
+
+/// A participant's total escrow in an asset over one checkpoint interval
+///
+/// `balance` is the holding at the end of the interval starting at
+/// `start_ledger`, in units after the first `splits` of the asset's splits;
+/// `prev_start` links to the previous checkpoint.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BalanceCheckpoint {
+    pub start_ledger: u32,
+    pub balance: i128,
+    pub prev_start: Option<u32>,
+    pub splits: u32,
+}
+
+/// How a participant acquired a tax lot
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum LotSource {
+    /// Tokens deposited from the participant's wallet
+    Deposit = 0,
+    /// Proceeds received in a settlement
+    Settlement = 1,
+}
+
+/// Unconsumed part of the tokens a participant acquired in one ledger
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TaxLot {
+    pub amount: i128,
+    /// Ledger sequence the lot was acquired in
+    pub ledger: u32,
+    pub source: LotSource,
+}
+
+/// Payment pro-rated across holders of an asset at a record ledger
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Distribution {
+    pub asset: Address,
+    pub payment_asset: Address,
+    pub total_amount: i128,
+    pub record_ledger: u32,
+    /// Pool-wide holding of `asset` at the record ledger
+    pub total_holding: i128,
+    pub claimed_amount: i128,
+    /// Splits of `asset` applied when the distribution was made; holdings
+    /// are compared in units after these
+    pub splits: u32,
+}
+
+/// Corporate action rescaling an asset's balances by `numerator / denominator`
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SplitRatio {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+/// Replacement of a deprecated token contract by a re-issued one
+///
+/// Holders convert at `rate` (new units per old unit, scaled by
+/// `FX_RATE_SCALE`), paid from a reserve of the new token funded by the issuer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AssetMigration {
+    pub new_asset: Address,
+    pub issuer: Address,
+    pub rate: i128,
+    /// New tokens still available for conversions
+    pub reserve: i128,
+    /// Old tokens converted so far
+    pub converted: i128,
+    /// Converted old tokens already returned to the issuer
+    pub reclaimed: i128,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /**
+     * Apply a stock split or reverse split to an escrowed asset
+     *
+     * Escrow and locked balances and pending deliveries are rescaled
+     * lazily: each records how many splits it has seen and catches up
+     * (rounding down) the next time it is read.
+     *
+     * # Arguments
+     * * `admin` - Must be the admin address
+     * * `asset_address` - Asset being split
+     * * `numerator` - New units per `denominator` old units
+     * * `denominator` - Old units per `numerator` new units
+     */
+    pub fn apply_split(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        numerator: u32,
+        denominator: u32,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if numerator == 0 || denominator == 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        let ratio = SplitRatio { numerator, denominator };
+
+        let mut splits: Map<Address, Vec<SplitRatio>> = env
+            .storage()
+            .instance()
+            .get(&SPLITS_KEY)
+            .unwrap_or(Map::new(&env));
+        let mut history = splits.get(asset_address.clone()).unwrap_or(vec![&env]);
+        history.push_back(ratio.clone());
+        splits.set(asset_address.clone(), history);
+        env.storage().instance().set(&SPLITS_KEY, &splits);
+
+        for ledger in [ESCROW_KEY, LOCKED_KEY] {
+            let total = Self::read_total(&env, &ledger, &asset_address);
+            Self::write_total(&env, &ledger, &asset_address, Self::apply_ratio(total, &ratio));
+        }
+        Ok(())
+    }
+
+    /**
+     * Retire a token contract in favour of its re-issued replacement
+     *
+     * The old asset is paused, so it can no longer be deposited, locked or
+     * settled, and each holder moves their unlocked balance across with
+     * `convert_migrated_asset`. The issuer funds the conversion reserve with
+     * the new token up front and can top it up with `fund_migration_reserve`.
+     *
+     * # Arguments
+     * * `admin` - Must be the admin address
+     * * `issuer` - Issuer of the new token (must authenticate)
+     * * `old_asset` - Deprecated token contract
+     * * `new_asset` - Replacement token contract
+     * * `rate` - New units per old unit, scaled by `FX_RATE_SCALE`
+     * * `reserve` - New tokens transferred from the issuer into the reserve
+     */
+    pub fn migrate_asset(
+        env: Env,
+        admin: Address,
+        issuer: Address,
+        old_asset: Address,
+        new_asset: Address,
+        rate: i128,
+        reserve: i128,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        issuer.require_auth();
+        if rate <= 0 || reserve < 0 || old_asset == new_asset {
+            return Err(SettlementError::InvalidAmount);
+        }
+
+        let mut migrations: Map<Address, AssetMigration> = env
+            .storage()
+            .instance()
+            .get(&MIGRATIONS_KEY)
+            .unwrap_or(Map::new(&env));
+        if migrations.contains_key(old_asset.clone()) {
+            return Err(SettlementError::AssetAlreadyMigrated);
+        }
+
+        if reserve > 0 {
+            Self::asset_adapter(&env, &new_asset).transfer(&env, &issuer, &env.current_contract_address(), reserve);
+        }
+        migrations.set(old_asset.clone(), AssetMigration {
+            new_asset,
+            issuer,
+            rate,
+            reserve,
+            converted: 0,
+            reclaimed: 0,
+        });
+        env.storage().instance().set(&MIGRATIONS_KEY, &migrations);
+
+        let mut paused: Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&PAUSED_KEY)
+            .unwrap_or(Map::new(&env));
+        paused.set(old_asset, true);
+        env.storage().instance().set(&PAUSED_KEY, &paused);
+        Ok(())
+    }
+
+    /// Get the migration recorded for a deprecated asset
+    pub fn get_asset_migration(env: Env, old_asset: Address) -> Option<AssetMigration> {
+        let migrations: Map<Address, AssetMigration> = env
+            .storage()
+            .instance()
+            .get(&MIGRATIONS_KEY)
+            .unwrap_or(Map::new(&env));
+        migrations.get(old_asset)
+    }
+
+    /// Add new tokens to a migration's conversion reserve
+    pub fn fund_migration_reserve(
+        env: Env,
+        issuer: Address,
+        old_asset: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        issuer.require_auth();
+        if amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+
+        let mut migration = Self::get_asset_migration(env.clone(), old_asset.clone())
+            .ok_or(SettlementError::AssetNotMigrated)?;
+        Self::asset_adapter(&env, &migration.new_asset).transfer(
+            &env,
+            &issuer,
+            &env.current_contract_address(),
+            amount,
+        );
+        migration.reserve += amount;
+        Self::store_migration(&env, &old_asset, &migration);
+        Ok(())
+    }
+
+    /// Convert a sub-account's unlocked balance of a migrated asset
+    ///
+    /// Locked funds stay behind until they are unlocked and converted.
+    ///
+    /// # Arguments
+    /// * `participant` - Holder (must authenticate)
+    /// * `sub_account` - Sub-account holding the old asset
+    /// * `old_asset` - Deprecated token contract
+    ///
+    /// # Returns
+    /// * Amount of the new asset credited
+    pub fn convert_migrated_asset(
+        env: Env,
+        participant: Address,
+        sub_account: Symbol,
+        old_asset: Address,
+    ) -> Result<i128, SettlementError> {
+        participant.require_auth();
+
+        let mut migration = Self::get_asset_migration(env.clone(), old_asset.clone())
+            .ok_or(SettlementError::AssetNotMigrated)?;
+        let old_key = EscrowKey::new(&participant, &sub_account, &old_asset);
+        let amount = Self::available_balance(&env, &old_key);
+        if amount <= 0 {
+            return Ok(0);
+        }
+
+        let converted = Self::mul_div(amount, migration.rate, FX_RATE_SCALE)?;
+        if converted > migration.reserve {
+            return Err(SettlementError::MigrationReserveInsufficient);
+        }
+        Self::debit_escrow(&env, &old_key, amount)?;
+        Self::credit_escrow(&env, &EscrowKey::new(&participant, &sub_account, &migration.new_asset), converted);
+
+        migration.reserve -= converted;
+        migration.converted += amount;
+        Self::store_migration(&env, &old_asset, &migration);
+        Ok(converted)
+    }
+
+    /// Return converted old tokens to the issuer
+    ///
+    /// # Returns
+    /// * Amount of the old asset transferred
+    pub fn reclaim_migrated_asset(env: Env, old_asset: Address) -> Result<i128, SettlementError> {
+        let mut migration = Self::get_asset_migration(env.clone(), old_asset.clone())
+            .ok_or(SettlementError::AssetNotMigrated)?;
+        migration.issuer.require_auth();
+
+        let amount = migration.converted - migration.reclaimed;
+        if amount > 0 {
+            Self::asset_adapter(&env, &old_asset).transfer(
+                &env,
+                &env.current_contract_address(),
+                &migration.issuer,
+                amount,
+            );
+            migration.reclaimed += amount;
+            Self::store_migration(&env, &old_asset, &migration);
+        }
+        Ok(amount)
+    }
+
+    /// Get the splits applied to an asset, oldest first
+    pub fn get_splits(env: Env, asset_address: Address) -> Vec<SplitRatio> {
+        let splits: Map<Address, Vec<SplitRatio>> = env
+            .storage()
+            .instance()
+            .get(&SPLITS_KEY)
+            .unwrap_or(Map::new(&env));
+        splits.get(asset_address).unwrap_or(vec![&env])
+    }
+
+    /// Enable (or disable) balance checkpoints for an asset
+    ///
+    /// Every escrow change records the participant's total holding across
+    /// sub-accounts, one checkpoint per `interval` ledgers. A holder's first
+    /// checkpoint is seeded with what they held when checkpoints were
+    /// enabled, so holdings that never move still count. Disabling drops the
+    /// history; enabling again starts a new one.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `asset_address` - Asset to checkpoint
+    /// * `interval` - Checkpoint resolution in ledgers, or `None` to stop
+    pub fn set_checkpoint_interval(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        interval: Option<u32>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut intervals: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&CHECKPOINT_IVL_KEY)
+            .unwrap_or(Map::new(&env));
+        let mut since: Map<Address, (u32, u32)> = env
+            .storage()
+            .instance()
+            .get(&CHECKPOINT_FROM_KEY)
+            .unwrap_or(Map::new(&env));
+        match interval {
+            Some(0) => return Err(SettlementError::InvalidAmount),
+            Some(interval) => {
+                intervals.set(asset_address.clone(), interval);
+                if !since.contains_key(asset_address.clone()) {
+                    let origin = (env.ledger().sequence(), Self::get_splits(env.clone(), asset_address.clone()).len());
+                    since.set(asset_address.clone(), origin);
+                    Self::seed_pool_checkpoint(&env, &asset_address, origin);
+                }
+            }
+            None => {
+                intervals.remove(asset_address.clone());
+                since.remove(asset_address);
+            }
+        }
+        env.storage().instance().set(&CHECKPOINT_IVL_KEY, &intervals);
+        env.storage().instance().set(&CHECKPOINT_FROM_KEY, &since);
+        Ok(())
+    }
+
+    /// Get the checkpoint interval for an asset
+    pub fn get_checkpoint_interval(env: Env, asset_address: Address) -> Option<u32> {
+        let intervals: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&CHECKPOINT_IVL_KEY)
+            .unwrap_or(Map::new(&env));
+        intervals.get(asset_address)
+    }
+
+    /// Get a participant's checkpointed holding of an asset at a ledger
+    ///
+    /// Returns the holding at the end of the checkpoint interval containing
+    /// `ledger`, or 0 if checkpoints were not enabled by then. Fails with
+    /// `RecordLedgerTooOld` if `ledger` is more than `MAX_CHECKPOINT_STEPS`
+    /// checkpoints back.
+    pub fn get_balance_at(
+        env: Env,
+        participant: Address,
+        asset: Address,
+        ledger: u32,
+    ) -> Result<i128, SettlementError> {
+        let splits = Self::get_splits(env.clone(), asset.clone()).len();
+        let current = Self::holding_after(&env, &participant, &asset, splits);
+        Self::balance_at(&env, &participant, &asset, ledger, splits, current)
+    }
+
+    /// Get a participant's escrow in an asset summed across sub-accounts
+    ///
+    /// Sub-accounts other than the main one count from their first escrow
+    /// change in a build with corporate actions.
+    pub fn get_holding(env: Env, participant: Address, asset: Address) -> i128 {
+        let splits = Self::get_splits(env.clone(), asset.clone()).len();
+        Self::holding_after(&env, &participant, &asset, splits)
+    }
+
+    /// Get a participant's open tax lots in an asset, oldest first
+    ///
+    /// Deposits and settlement proceeds open lots; withdrawals, settlement
+    /// debits and clawbacks consume them first in, first out, across all
+    /// sub-accounts. Other escrow changes, such as dividends and migrations,
+    /// leave lots untouched.
+    pub fn get_lots(env: Env, participant: Address, asset: Address) -> Vec<TaxLot> {
+        env.storage()
+            .persistent()
+            .get(&(LOTS_KEY, participant, asset))
+            .unwrap_or(vec![&env])
+    }
+
+    /// Get the pool-wide checkpointed holding of an asset at a ledger
+    pub fn get_total_holding_at(env: Env, asset: Address, ledger: u32) -> Result<i128, SettlementError> {
+        let splits = Self::get_splits(env.clone(), asset.clone()).len();
+        let current = Self::read_total(&env, &ESCROW_KEY, &asset);
+        Self::balance_at(&env, &env.current_contract_address(), &asset, ledger, splits, current)
+    }
+
+    /**
+     * Fund a dividend or coupon for holders of an escrowed asset
+     *
+     * Each holder's share is pro-rated by its checkpointed holding at
+     * `record_ledger` (see `set_checkpoint_interval`) and claimed with
+     * `claim_distribution`. The checkpoint interval containing the record
+     * ledger must already be closed so the snapshot cannot change. Splits
+     * applied after the record ledger rescale every holding alike and leave
+     * the shares as they were.
+     *
+     * # Arguments
+     * * `distributor` - Funds the payment (must authenticate)
+     * * `asset` - Escrowed asset whose holders are paid
+     * * `payment_asset` - Token the payment is made in
+     * * `total_amount` - Total paid across all holders
+     * * `record_ledger` - Ledger at which holdings are measured
+     *
+     * # Returns
+     * * Distribution id
+     */
+    pub fn distribute(
+        env: Env,
+        distributor: Address,
+        asset: Address,
+        payment_asset: Address,
+        total_amount: i128,
+        record_ledger: u32,
+    ) -> Result<u32, SettlementError> {
+        distributor.require_auth();
+        if total_amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        let closes = match Self::get_checkpoint_interval(env.clone(), asset.clone()) {
+            Some(interval) => (record_ledger / interval * interval).saturating_add(interval),
+            None => record_ledger.saturating_add(1),
+        };
+        if closes > env.ledger().sequence() {
+            return Err(SettlementError::RecordLedgerNotFinal);
+        }
+        let splits = Self::get_splits(env.clone(), asset.clone()).len();
+        let total_holding = Self::get_total_holding_at(env.clone(), asset.clone(), record_ledger)?;
+        if total_holding <= 0 {
+            return Err(SettlementError::NoHoldingsAtRecord);
+        }
+
+        Self::asset_adapter(&env, &payment_asset).transfer(
+            &env,
+            &distributor,
+            &env.current_contract_address(),
+            total_amount,
+        );
+
+        let id: u32 = env.storage().instance().get(&DIST_COUNT_KEY).unwrap_or(0);
+        env.storage().instance().set(&DIST_COUNT_KEY, &(id + 1));
+        let entry = (DIST_KEY, id);
+        env.storage().persistent().set(&entry, &Distribution {
+            asset,
+            payment_asset,
+            total_amount,
+            record_ledger,
+            total_holding,
+            claimed_amount: 0,
+            splits,
+        });
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(id)
+    }
+
+    /// Get a distribution by id
+    pub fn get_distribution(env: Env, id: u32) -> Option<Distribution> {
+        env.storage().persistent().get(&(DIST_KEY, id))
+    }
+
+    /// Amount a holder can still claim from a distribution
+    pub fn get_distribution_claimable(env: Env, id: u32, holder: Address) -> i128 {
+        let distribution = match Self::get_distribution(env.clone(), id) {
+            Some(distribution) => distribution,
+            None => return 0,
+        };
+        if env.storage().persistent().has(&(DIST_CLAIM_KEY, id, holder.clone())) {
+            return 0;
+        }
+        Self::distribution_share(&env, &distribution, &holder)
+            .map(|(share, _)| share)
+            .unwrap_or(0)
+    }
+
+    /// Claim a holder's share of a distribution
+    ///
+    /// # Arguments
+    /// * `holder` - Holder at the record ledger (must authenticate)
+    /// * `id` - Distribution id
+    ///
+    /// # Returns
+    /// * Amount transferred to the holder
+    pub fn claim_distribution(env: Env, holder: Address, id: u32) -> Result<i128, SettlementError> {
+        holder.require_auth();
+
+        let entry = (DIST_KEY, id);
+        let mut distribution: Distribution = env
+            .storage()
+            .persistent()
+            .get(&entry)
+            .ok_or(SettlementError::DistributionNotFound)?;
+        let claim_entry = (DIST_CLAIM_KEY, id, holder.clone());
+        if env.storage().persistent().has(&claim_entry) {
+            return Err(SettlementError::DistributionClaimed);
+        }
+
+        let (amount, remainder) = Self::distribution_share(&env, &distribution, &holder)?;
+        Self::accrue_distribution_remainder(&env, id, &distribution, remainder);
+        env.storage().persistent().set(&claim_entry, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&claim_entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        distribution.claimed_amount += amount;
+        env.storage().persistent().set(&entry, &distribution);
+
+        if amount > 0 {
+            Self::asset_adapter(&env, &distribution.payment_asset).transfer(
+                &env,
+                &env.current_contract_address(),
+                &holder,
+                amount,
+            );
+        }
+        Ok(amount)
+    }
+
+    /// Record how many of its asset's splits a balance written to `ledger` reflects
+    pub(crate) fn write_splits_seen(env: &Env, ledger: &Symbol, key: &EscrowKey, balance: i128) {
+        let splits = Self::get_splits(env.clone(), key.asset.clone()).len();
+        if splits == 0 {
+            return;
+        }
+        let entry = (Self::splits_ledger(ledger), key.clone());
+        if balance == 0 {
+            env.storage().persistent().remove(&entry);
+        } else {
+            env.storage().persistent().set(&entry, &splits);
+            env.storage()
+                .persistent()
+                .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        }
+    }
+
+    /// Ledger recording how many splits each balance in `ledger` has seen
+    pub(crate) fn splits_ledger(ledger: &Symbol) -> Symbol {
+        if *ledger == ESCROW_KEY {
+            ESCROW_SPLITS_KEY
+        } else {
+            LOCKED_SPLITS_KEY
+        }
+    }
+
+    fn store_migration(env: &Env, old_asset: &Address, migration: &AssetMigration) {
+        let mut migrations: Map<Address, AssetMigration> = env
+            .storage()
+            .instance()
+            .get(&MIGRATIONS_KEY)
+            .unwrap_or(Map::new(env));
+        migrations.set(old_asset.clone(), migration.clone());
+        env.storage().instance().set(&MIGRATIONS_KEY, &migrations);
+    }
+
+    /// Rescale an amount by a split ratio, rounding down
+    pub(crate) fn apply_ratio(amount: i128, ratio: &SplitRatio) -> i128 {
+        amount * ratio.numerator as i128 / ratio.denominator as i128
+    }
+
+    /// Rescale an amount from units after `from` of an asset's splits to units after `to`
+    fn rescale(env: &Env, asset: &Address, amount: i128, from: u32, to: u32) -> i128 {
+        Self::get_splits(env.clone(), asset.clone())
+            .iter()
+            .skip(from as usize)
+            .take(to.saturating_sub(from) as usize)
+            .fold(amount, |amount, ratio| Self::apply_ratio(amount, &ratio))
+    }
+
+    /// Holder's pro-rated share of a distribution, rounded down
+    ///
+    /// Also returns the fraction rounded away, in units of
+    /// `1 / total_holding`.
+    fn distribution_share(
+        env: &Env,
+        distribution: &Distribution,
+        holder: &Address,
+    ) -> Result<(i128, i128), SettlementError> {
+        let current = Self::holding_after(env, holder, &distribution.asset, distribution.splits);
+        let holding = Self::balance_at(
+            env,
+            holder,
+            &distribution.asset,
+            distribution.record_ledger,
+            distribution.splits,
+            current,
+        )?;
+        let entitlement = distribution
+            .total_amount
+            .checked_mul(holding)
+            .ok_or(SettlementError::NotionalOverflow)?;
+        Ok((
+            entitlement / distribution.total_holding,
+            entitlement % distribution.total_holding,
+        ))
+    }
+
+    /// Add a claim's rounded-away fraction, moving whole units to the dust bucket
+    ///
+    /// Holdings at the record ledger sum to `total_holding`, so once every
+    /// holder has claimed, claims plus dust equal the distributed amount.
+    fn accrue_distribution_remainder(env: &Env, id: u32, distribution: &Distribution, remainder: i128) {
+        if remainder == 0 {
+            return;
+        }
+        let entry = (DIST_REM_KEY, id);
+        let accrued: i128 = env.storage().persistent().get(&entry).unwrap_or(0);
+        let accrued = accrued + remainder;
+        env.storage()
+            .persistent()
+            .set(&entry, &(accrued % distribution.total_holding));
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Self::credit_dust(env, &distribution.payment_asset, accrued / distribution.total_holding);
+    }
+
+    /// Add to an asset's dust bucket
+    fn credit_dust(env: &Env, asset: &Address, amount: i128) {
+        if amount == 0 {
+            return;
+        }
+        let mut dust: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DUST_KEY)
+            .unwrap_or(Map::new(env));
+        dust.set(asset.clone(), dust.get(asset.clone()).unwrap_or(0) + amount);
+        env.storage().instance().set(&DUST_KEY, &dust);
+    }
+
+    /// Apply an escrow change to the participant's holding and checkpoints
+    ///
+    /// The pool-wide total is checkpointed under the contract's own address.
+    /// Must run before the change reaches the balance and the asset's running
+    /// total.
+    pub(crate) fn checkpoint_holding(env: &Env, key: &EscrowKey, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+        let checkpoints = Self::get_checkpoint_interval(env.clone(), key.asset.clone())
+            .zip(Self::checkpoints_since(env, &key.asset));
+        if key.sub_account == DEFAULT_SUB_ACCOUNT && checkpoints.is_none() {
+            return;
+        }
+
+        let splits = Self::get_splits(env.clone(), key.asset.clone()).len();
+        let holding = Self::holding_after(env, &key.participant, &key.asset, splits);
+        if key.sub_account != DEFAULT_SUB_ACCOUNT {
+            let (sub_holding, seen) = Self::read_sub_holding(env, &key.participant, &key.asset);
+            let sub_holding = Self::rescale(env, &key.asset, sub_holding, seen, splits) + delta;
+            let entry = (SUB_HOLDING_KEY, key.participant.clone(), key.asset.clone());
+            if sub_holding == 0 {
+                env.storage().persistent().remove(&entry);
+            } else {
+                env.storage().persistent().set(&entry, &(sub_holding, splits));
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+            }
+        }
+
+        let Some((interval, origin)) = checkpoints else {
+            return;
+        };
+        Self::push_checkpoint(env, &key.participant, &key.asset, interval, origin, holding, delta);
+        let total = Self::read_total(env, &ESCROW_KEY, &key.asset);
+        Self::push_checkpoint(env, &env.current_contract_address(), &key.asset, interval, origin, total, delta);
+    }
+
+    /// Escrow outside the main sub-account, with the split count it reflects
+    fn read_sub_holding(env: &Env, participant: &Address, asset: &Address) -> (i128, u32) {
+        env.storage()
+            .persistent()
+            .get(&(SUB_HOLDING_KEY, participant.clone(), asset.clone()))
+            .unwrap_or((0, 0))
+    }
+
+    /// A participant's holding in units after `splits` of the asset's splits
+    ///
+    /// Only exact while neither of the stored balances was written after
+    /// split `splits` was applied.
+    fn holding_after(env: &Env, participant: &Address, asset: &Address, splits: u32) -> i128 {
+        let key = EscrowKey::main(participant, asset);
+        let main: i128 = env
+            .storage()
+            .persistent()
+            .get(&(ESCROW_KEY, key.clone()))
+            .unwrap_or(0);
+        let seen: u32 = env
+            .storage()
+            .persistent()
+            .get(&(ESCROW_SPLITS_KEY, key))
+            .unwrap_or(0);
+        let (sub_holding, sub_seen) = Self::read_sub_holding(env, participant, asset);
+        Self::rescale(env, asset, main, seen, splits) + Self::rescale(env, asset, sub_holding, sub_seen, splits)
+    }
+
+    /// Ledger an asset's checkpoints were enabled at and its split count then
+    fn checkpoints_since(env: &Env, asset: &Address) -> Option<(u32, u32)> {
+        let since: Map<Address, (u32, u32)> = env
+            .storage()
+            .instance()
+            .get(&CHECKPOINT_FROM_KEY)
+            .unwrap_or(Map::new(env));
+        since.get(asset.clone())
+    }
+
+    /// Start the pool-wide checkpoints from the asset's running total
+    fn seed_pool_checkpoint(env: &Env, asset: &Address, (since, splits): (u32, u32)) {
+        let balance = Self::read_total(env, &ESCROW_KEY, asset);
+        if balance == 0 {
+            return;
+        }
+        let pool = env.current_contract_address();
+        Self::write_checkpoint(env, &pool, asset, &BalanceCheckpoint {
+            start_ledger: since,
+            balance,
+            prev_start: None,
+            splits,
+        });
+        let head_entry = (CHECKPOINT_HEAD_KEY, pool, asset.clone(), since);
+        env.storage().persistent().set(&head_entry, &since);
+        env.storage()
+            .persistent()
+            .extend_ttl(&head_entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    /// Record a holder's holding after a change of `delta` from `previous`
+    ///
+    /// A holder's first checkpoint links back to a seed recording what they
+    /// held since checkpoints were enabled; the pool's is seeded on enabling.
+    fn push_checkpoint(
+        env: &Env,
+        holder: &Address,
+        asset: &Address,
+        interval: u32,
+        (since, since_splits): (u32, u32),
+        previous: i128,
+        delta: i128,
+    ) {
+        let head_entry = (CHECKPOINT_HEAD_KEY, holder.clone(), asset.clone(), since);
+        let head: Option<u32> = env.storage().persistent().get(&head_entry);
+        let splits = Self::get_splits(env.clone(), asset.clone()).len();
+
+        // Never start before the head, in case the interval was changed
+        let start_ledger = (env.ledger().sequence() / interval * interval).max(head.unwrap_or(0));
+        let prev_start = match head {
+            Some(head) if head == start_ledger => env
+                .storage()
+                .persistent()
+                .get::<_, BalanceCheckpoint>(&(CHECKPOINT_KEY, holder.clone(), asset.clone(), head))
+                .unwrap()
+                .prev_start,
+            Some(head) => Some(head),
+            // Nothing was written since enabling, so the stored balances
+            // still hold the amounts from then
+            None if since < start_ledger => {
+                let seed = Self::holding_after(env, holder, asset, since_splits);
+                (seed != 0).then(|| {
+                    Self::write_checkpoint(env, holder, asset, &BalanceCheckpoint {
+                        start_ledger: since,
+                        balance: seed,
+                        prev_start: None,
+                        splits: since_splits,
+                    });
+                    since
+                })
+            }
+            None => None,
+        };
+
+        Self::write_checkpoint(env, holder, asset, &BalanceCheckpoint {
+            start_ledger,
+            balance: previous + delta,
+            prev_start,
+            splits,
+        });
+        env.storage().persistent().set(&head_entry, &start_ledger);
+        env.storage()
+            .persistent()
+            .extend_ttl(&head_entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    fn write_checkpoint(env: &Env, holder: &Address, asset: &Address, checkpoint: &BalanceCheckpoint) {
+        let entry = (CHECKPOINT_KEY, holder.clone(), asset.clone(), checkpoint.start_ledger);
+        env.storage().persistent().set(&entry, checkpoint);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    /// Walk a holder's checkpoints back to the one covering `ledger`
+    ///
+    /// Returns the holding in units after `splits` of the asset's splits. A
+    /// holder with no checkpoints has held `current`, in those units, since
+    /// checkpoints were enabled.
+    fn balance_at(
+        env: &Env,
+        holder: &Address,
+        asset: &Address,
+        ledger: u32,
+        splits: u32,
+        current: i128,
+    ) -> Result<i128, SettlementError> {
+        let since = match Self::checkpoints_since(env, asset) {
+            Some((since, _)) if since <= ledger => since,
+            _ => return Ok(0),
+        };
+        let mut start: Option<u32> = env
+            .storage()
+            .persistent()
+            .get(&(CHECKPOINT_HEAD_KEY, holder.clone(), asset.clone(), since));
+        if start.is_none() {
+            return Ok(current);
+        }
+
+        let mut steps = 0;
+        while let Some(start_ledger) = start {
+            if steps == MAX_CHECKPOINT_STEPS {
+                return Err(SettlementError::RecordLedgerTooOld);
+            }
+            steps += 1;
+            let checkpoint: BalanceCheckpoint = env
+                .storage()
+                .persistent()
+                .get(&(CHECKPOINT_KEY, holder.clone(), asset.clone(), start_ledger))
+                .unwrap();
+            if checkpoint.start_ledger <= ledger {
+                return Ok(Self::rescale(env, asset, checkpoint.balance, checkpoint.splits, splits));
+            }
+            start = checkpoint.prev_start;
+        }
+        Ok(0)
+    }
+
+    /// Record tokens a participant acquired, merging with a lot from the same ledger and source
+    pub(crate) fn open_lot(env: &Env, participant: &Address, asset: &Address, amount: i128, source: LotSource) {
+        if amount <= 0 {
+            return;
+        }
+        let mut lots = Self::get_lots(env.clone(), participant.clone(), asset.clone());
+        let ledger = env.ledger().sequence();
+        match lots.last() {
+            Some(mut last) if last.ledger == ledger && last.source == source => {
+                last.amount += amount;
+                lots.set(lots.len() - 1, last);
+            }
+            _ => lots.push_back(TaxLot { amount, ledger, source }),
+        }
+        Self::write_lots(env, participant, asset, &lots);
+    }
+
+    /// Consume a participant's oldest lots first
+    ///
+    /// Stops once the lots run out, since untracked credits may have added
+    /// balance no lot covers.
+    pub(crate) fn consume_lots(env: &Env, participant: &Address, asset: &Address, amount: i128) {
+        let mut lots = Self::get_lots(env.clone(), participant.clone(), asset.clone());
+        let mut remaining = amount;
+        while remaining > 0 {
+            let Some(mut lot) = lots.first() else {
+                break;
+            };
+            if lot.amount > remaining {
+                lot.amount -= remaining;
+                lots.set(0, lot);
+                break;
+            }
+            remaining -= lot.amount;
+            lots.pop_front();
+        }
+        Self::write_lots(env, participant, asset, &lots);
+    }
+
+    fn write_lots(env: &Env, participant: &Address, asset: &Address, lots: &Vec<TaxLot>) {
+        let entry = (LOTS_KEY, participant.clone(), asset.clone());
+        if lots.is_empty() {
+            env.storage().persistent().remove(&entry);
+            return;
+        }
+        env.storage().persistent().set(&entry, lots);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+}
@@ -0,0 +1,71 @@
+//! Diagnostic events for debugging settlements on testnet
+//!
+//! Only compiled with the `debug-events` feature, so release builds carry
+//! neither the code nor the event fees.
+
+use soroban_sdk::{contractevent, Address, BytesN, Env, Symbol, Vec};
+
+use crate::{EscrowKey, VerificationRoute};
+
+/// Public signals of a settlement proof as parsed, in circuit order
+#[contractevent]
+#[derive(Clone)]
+pub struct DebugSignals {
+    #[topic]
+    pub match_id: BytesN<32>,
+    pub signals: Vec<BytesN<32>>,
+}
+
+/// Result of a proof verification and the route that produced it
+#[contractevent]
+#[derive(Clone)]
+pub struct DebugVerification {
+    #[topic]
+    pub proof_type: Symbol,
+    pub route: VerificationRoute,
+    pub valid: bool,
+}
+
+/// A write to an escrow or locked balance
+#[contractevent]
+#[derive(Clone)]
+pub struct DebugBalance {
+    #[topic]
+    pub participant: Address,
+    #[topic]
+    pub asset: Address,
+    /// `escrow` or `locked`
+    pub ledger: Symbol,
+    pub sub_account: Symbol,
+    pub delta: i128,
+    pub balance: i128,
+}
+
+pub fn signals(env: &Env, match_id: &BytesN<32>, signals: &Vec<BytesN<32>>) {
+    DebugSignals {
+        match_id: match_id.clone(),
+        signals: signals.clone(),
+    }
+    .publish(env);
+}
+
+pub fn verification(env: &Env, proof_type: &Symbol, route: VerificationRoute, valid: bool) {
+    DebugVerification {
+        proof_type: proof_type.clone(),
+        route,
+        valid,
+    }
+    .publish(env);
+}
+
+pub fn balance(env: &Env, ledger: &Symbol, key: &EscrowKey, delta: i128, balance: i128) {
+    DebugBalance {
+        participant: key.participant.clone(),
+        asset: key.asset.clone(),
+        ledger: ledger.clone(),
+        sub_account: key.sub_account.clone(),
+        delta,
+        balance,
+    }
+    .publish(env);
+}
@@ -0,0 +1,322 @@
+//! Brokers, fee payers and signed settlement intents
+//!
+//! Only compiled with the `delegation` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, xdr::ToXdr, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementAuthMode,
+    SettlementError, SettlementRecord, BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, DEFAULT_SUB_ACCOUNT,
+    PROPOSAL_TTL_LEDGERS,
+};
+
+const BROKERS_KEY: Symbol = symbol_short!("brokers");
+
+const INTENT_KEYS_KEY: Symbol = symbol_short!("int_keys");
+
+/// Domain tag mixed into every settlement intent hash
+const INTENT_DOMAIN: Symbol = symbol_short!("dp_intent");
+
+const FEE_PAYERS_KEY: Symbol = symbol_short!("fee_payer");
+
+pub(crate) const BROKER_APPROVALS_KEY: Symbol = symbol_short!("brk_appr");
+
+/// Match terms a party signs off-chain to consent to a settlement
+///
+/// Parties sign `intent_hash(intent)`, which binds the terms to this
+/// contract and network, with the ed25519 key registered via
+/// `set_intent_key`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SettlementIntent {
+    pub match_id: BytesN<32>,
+    pub buyer: Address,
+    pub seller: Address,
+    pub asset_address: Address,
+    pub payment_asset: Address,
+    pub quantity: i128,
+    pub price: i128,
+    /// Latest ledger timestamp at which the intent may be settled
+    pub expires_at: u64,
+}
+
+/// Broker delegated by a participant to manage its orders
+///
+/// Brokers never hold withdrawal rights; they can only lock/unlock escrow
+/// and authorize settlements on the participant's behalf.
+#[derive(Clone)]
+#[contracttype]
+pub struct BrokerGrant {
+    pub broker: Address,
+    pub can_lock: bool,
+    pub can_settle: bool,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Designate a broker to manage the participant's orders
+    ///
+    /// # Arguments
+    /// * `participant` - Participant granting the delegation (must authenticate)
+    /// * `broker` - Broker address
+    /// * `can_lock` - Allow the broker to lock and unlock escrow
+    /// * `can_settle` - Allow the broker to authorize settlements
+    pub fn set_broker(
+        env: Env,
+        participant: Address,
+        broker: Address,
+        can_lock: bool,
+        can_settle: bool,
+    ) {
+        participant.require_auth();
+
+        let entry = (BROKERS_KEY, participant);
+        env.storage().persistent().set(
+            &entry,
+            &BrokerGrant {
+                broker,
+                can_lock,
+                can_settle,
+            },
+        );
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    /// Revoke the participant's broker delegation
+    pub fn remove_broker(env: Env, participant: Address) {
+        participant.require_auth();
+        env.storage().persistent().remove(&(BROKERS_KEY, participant));
+    }
+
+    /// Get the broker delegation for a participant
+    pub fn get_broker(env: Env, participant: Address) -> Option<BrokerGrant> {
+        env.storage().persistent().get(&(BROKERS_KEY, participant))
+    }
+
+    /// Lock a client's escrow on their behalf
+    ///
+    /// # Arguments
+    /// * `broker` - Broker address (must authenticate and hold lock scope)
+    /// * `trader` - Client whose escrow is locked
+    /// * `sub_account` - Client sub-account holding the funds
+    /// * `asset_address` - Token contract address
+    /// * `amount` - Amount to lock
+    pub fn broker_lock_escrow(
+        env: Env,
+        broker: Address,
+        trader: Address,
+        sub_account: Symbol,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        broker.require_auth();
+        Self::require_broker_scope(&env, &trader, &broker, false)?;
+        Self::require_asset_active(&env, &asset_address)?;
+
+        let key = EscrowKey::new(&trader, &sub_account, &asset_address);
+        if Self::available_balance(&env, &key) < amount {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+
+        Self::credit_locked(&env, &key, amount);
+        Ok(())
+    }
+
+    /// Unlock a client's escrow on their behalf
+    pub fn broker_unlock_escrow(
+        env: Env,
+        broker: Address,
+        trader: Address,
+        sub_account: Symbol,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        broker.require_auth();
+        Self::require_broker_scope(&env, &trader, &broker, false)?;
+
+        let key = EscrowKey::new(&trader, &sub_account, &asset_address);
+        Self::require_unpledged(&env, &key, amount)?;
+        Self::debit_locked(&env, &key, amount)
+    }
+
+    /// Approve a client's side of one match on their behalf
+    ///
+    /// Under `BothParties` auth the approval stands in for the client's own
+    /// authorization and is used up by the settlement. It expires with the
+    /// match proposal window and is ignored if the delegation is withdrawn.
+    ///
+    /// # Arguments
+    /// * `broker` - Broker address (must authenticate and hold settle scope)
+    /// * `trader` - Client whose side of the match is approved
+    /// * `match_id` - Match being approved
+    pub fn broker_approve_settlement(
+        env: Env,
+        broker: Address,
+        trader: Address,
+        match_id: BytesN<32>,
+    ) -> Result<(), SettlementError> {
+        broker.require_auth();
+        Self::require_broker_scope(&env, &trader, &broker, true)?;
+
+        let entry = (BROKER_APPROVALS_KEY, match_id, trader);
+        env.storage().temporary().set(&entry, &broker);
+        env.storage()
+            .temporary()
+            .extend_ttl(&entry, PROPOSAL_TTL_LEDGERS, PROPOSAL_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Register the ed25519 key a participant signs settlement intents with
+    ///
+    /// # Arguments
+    /// * `participant` - Participant (must authenticate)
+    /// * `public_key` - Signing key, or `None` to stop accepting signed intents
+    pub fn set_intent_key(env: Env, participant: Address, public_key: Option<BytesN<32>>) {
+        participant.require_auth();
+
+        let entry = (INTENT_KEYS_KEY, participant);
+        match public_key {
+            Some(public_key) => {
+                env.storage().persistent().set(&entry, &public_key);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+            }
+            None => env.storage().persistent().remove(&entry),
+        }
+    }
+
+    /// Get a participant's intent signing key
+    pub fn get_intent_key(env: Env, participant: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(INTENT_KEYS_KEY, participant))
+    }
+
+    /// Hash a party signs to consent to a settlement intent
+    ///
+    /// SHA-256 over the XDR of the domain tag, network id, this contract's
+    /// address and the intent, so a signature cannot be replayed on another
+    /// deployment or network.
+    pub fn intent_hash(env: Env, intent: SettlementIntent) -> BytesN<32> {
+        let payload = (INTENT_DOMAIN, env.ledger().network_id(), env.current_contract_address(), intent);
+        env.crypto().sha256(&payload.to_xdr(&env)).into()
+    }
+
+    /// Settle a matched trade both parties consented to by signed intent
+    ///
+    /// Satisfies the `BothParties` auth mode without either party being
+    /// online at submission: each party's signature over `intent_hash(intent)`
+    /// is checked against its registered intent key in place of live
+    /// authorization. An invalid signature aborts the call.
+    ///
+    /// # Arguments
+    /// * `intent` - Signed match terms
+    /// * `buyer_signature` - Buyer's ed25519 signature over the intent hash
+    /// * `seller_signature` - Seller's ed25519 signature over the intent hash
+    /// * `proof_bytes` - Serialized ZK proof
+    /// * `pub_signals_bytes` - Serialized public signals
+    pub fn settle_trade_signed(
+        env: Env,
+        intent: SettlementIntent,
+        buyer_signature: BytesN<64>,
+        seller_signature: BytesN<64>,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<SettlementRecord, SettlementError> {
+        Self::require_unmetered(&env)?;
+        if env.ledger().timestamp() > intent.expires_at {
+            return Err(SettlementError::IntentExpired);
+        }
+
+        let hash: Bytes = Self::intent_hash(env.clone(), intent.clone()).into();
+        for (party, signature) in [(&intent.buyer, &buyer_signature), (&intent.seller, &seller_signature)] {
+            let public_key =
+                Self::get_intent_key(env.clone(), party.clone()).ok_or(SettlementError::IntentKeyNotSet)?;
+            env.crypto().ed25519_verify(&public_key, &hash, signature);
+        }
+
+        Self::execute_settlement(
+            &env,
+            None,
+            SettlementAuthMode::ProofOnly,
+            &intent.match_id,
+            &intent.buyer,
+            &DEFAULT_SUB_ACCOUNT,
+            &intent.seller,
+            &DEFAULT_SUB_ACCOUNT,
+            &intent.asset_address,
+            &intent.payment_asset,
+            intent.quantity,
+            intent.price,
+            &proof_bytes,
+            &pub_signals_bytes,
+        )
+    }
+
+    /// Absorb a client's settlement fees as its broker
+    ///
+    /// The client's fees are then taken from the broker's main escrow account
+    /// in the payment asset (or in the protocol token, if the broker opted
+    /// in) instead of from the client. The arrangement lapses if the client
+    /// stops delegating to the broker.
+    ///
+    /// # Arguments
+    /// * `client` - Participant whose fees the broker pays
+    /// * `broker` - The client's current broker (must authenticate)
+    pub fn set_fee_payer(env: Env, client: Address, broker: Address) -> Result<(), SettlementError> {
+        broker.require_auth();
+        match Self::get_broker(env.clone(), client.clone()) {
+            Some(grant) if grant.broker == broker => {}
+            _ => return Err(SettlementError::BrokerNotAuthorized),
+        }
+
+        let entry = (FEE_PAYERS_KEY, client);
+        env.storage().persistent().set(&entry, &broker);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Stop paying a client's settlement fees
+    ///
+    /// # Arguments
+    /// * `client` - Participant whose fees the broker pays
+    /// * `broker` - Current fee payer (must authenticate)
+    pub fn remove_fee_payer(env: Env, client: Address, broker: Address) -> Result<(), SettlementError> {
+        broker.require_auth();
+
+        let entry = (FEE_PAYERS_KEY, client);
+        if env.storage().persistent().get(&entry) != Some(broker) {
+            return Err(SettlementError::BrokerNotAuthorized);
+        }
+        env.storage().persistent().remove(&entry);
+        Ok(())
+    }
+
+    /// Get the broker paying a client's settlement fees, if it is still the client's broker
+    pub fn get_fee_payer(env: Env, client: Address) -> Option<Address> {
+        let payer: Address = env.storage().persistent().get(&(FEE_PAYERS_KEY, client.clone()))?;
+        match Self::get_broker(env, client) {
+            Some(grant) if grant.broker == payer => Some(payer),
+            _ => None,
+        }
+    }
+
+    /// Check that `broker` is the participant's broker with the required scope
+    pub(crate) fn require_broker_scope(
+        env: &Env,
+        participant: &Address,
+        broker: &Address,
+        settle: bool,
+    ) -> Result<(), SettlementError> {
+        let grant = Self::get_broker(env.clone(), participant.clone())
+            .ok_or(SettlementError::BrokerNotAuthorized)?;
+        let in_scope = if settle { grant.can_settle } else { grant.can_lock };
+        if grant.broker != *broker || !in_scope {
+            return Err(SettlementError::BrokerNotAuthorized);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,541 @@
+//! Settlement delays, claimable deliveries and watchtowers
+//!
+//! Only compiled with the `delivery` feature.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, Map, Symbol, Vec};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError, SettlementRecord,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD,
+};
+#[cfg(feature = "corporate-actions")]
+use crate::LotSource;
+#[cfg(feature = "forwards")]
+use crate::{FWD_IDX_KEY, ForwardStatus};
+
+const DELAYS_KEY: Symbol = symbol_short!("delays");
+
+const PENDING_KEY: Symbol = symbol_short!("pending");
+
+pub(crate) const PENDING_IDX_KEY: Symbol = symbol_short!("pend_idx");
+
+const WATCHERS_KEY: Symbol = symbol_short!("watchers");
+
+const WATCH_THRESH_KEY: Symbol = symbol_short!("watch_thr");
+
+pub(crate) const FROZEN_KEY: Symbol = symbol_short!("frozen");
+
+const CLAIMABLES_KEY: Symbol = symbol_short!("claimable");
+
+/// Maximum watchtowers subscribed at once
+pub const MAX_WATCHERS: u32 = 20;
+
+/// Event emitted when a settlement reaches its payment asset's watch threshold
+///
+/// Watchtowers monitor these to catch unexpected large settlements while
+/// their delayed legs can still be frozen.
+#[contractevent]
+#[derive(Clone)]
+pub struct HighValueSettlement {
+    #[topic]
+    pub match_id: BytesN<32>,
+    #[topic]
+    pub payment_asset: Address,
+    pub payment_amount: i128,
+    pub buyer: Address,
+    pub seller: Address,
+}
+
+/// Event emitted when a watchtower freezes a match's delayed legs
+#[contractevent]
+#[derive(Clone)]
+pub struct DeliveryFrozenByWatcher {
+    #[topic]
+    pub match_id: BytesN<32>,
+    pub watcher: Address,
+}
+
+/// Settled tokens held for an external recipient to claim
+///
+/// Supports delivery-versus-payment to custodians that never hold an
+/// escrow account: the recipient claims before `expires_at`, after which
+/// the buyer may reclaim the tokens into escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct ClaimableDelivery {
+    pub buyer: Address,
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+    pub status: ClaimableStatus,
+}
+
+/// Lifecycle of a claimable delivery
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum ClaimableStatus {
+    /// Held for the recipient
+    Pending = 0,
+    /// Paid out to the recipient
+    Claimed = 1,
+    /// Expired and returned to the buyer's escrow
+    Reclaimed = 2,
+}
+
+/// Event emitted when a buyer directs settled tokens to an external recipient
+#[contractevent]
+#[derive(Clone)]
+pub struct DeliveryClaimable {
+    #[topic]
+    pub match_id: BytesN<32>,
+    #[topic]
+    pub recipient: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+}
+
+/// A settlement leg held back until the asset's finality delay has elapsed
+#[derive(Clone)]
+#[contracttype]
+pub struct PendingDelivery {
+    pub recipient: EscrowKey,
+    pub amount: i128,
+    pub claimable_at: u32,
+    /// Splits of the recipient's asset `amount` already reflects
+    pub splits_seen: u32,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Set the finality delay for an asset
+    ///
+    /// Settlement legs delivering this asset are held for `ledgers` ledgers
+    /// before the recipient can claim them with `claim_settled`, emulating
+    /// transfer-agent settlement cycles (T+N). A delay of zero delivers instantly.
+    pub fn set_settlement_delay(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        ledgers: u32,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut delays: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DELAYS_KEY)
+            .unwrap_or(Map::new(&env));
+        delays.set(asset_address, ledgers);
+        env.storage().instance().set(&DELAYS_KEY, &delays);
+        Ok(())
+    }
+
+    /// Get the finality delay (in ledgers) for an asset
+    pub fn get_settlement_delay(env: Env, asset_address: Address) -> u32 {
+        let delays: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&DELAYS_KEY)
+            .unwrap_or(Map::new(&env));
+        delays.get(asset_address).unwrap_or(0)
+    }
+
+    /// Release delayed settlement legs of a match whose delay has elapsed
+    ///
+    /// Ready legs are credited to their recipients' escrow. Anyone may call
+    /// this, since funds can only move to the recorded recipient.
+    ///
+    /// # Returns
+    /// * The number of legs released
+    pub fn claim_settled(env: Env, match_id: BytesN<32>) -> Result<u32, SettlementError> {
+        if Self::get_frozen_by(env.clone(), match_id.clone()).is_some() {
+            return Err(SettlementError::DeliveryFrozen);
+        }
+
+        let deliveries = Self::get_pending_deliveries(env.clone(), match_id.clone());
+        if deliveries.is_empty() {
+            return Err(SettlementError::DeliveryNotFound);
+        }
+
+        let current_ledger = env.ledger().sequence();
+        let mut remaining: Vec<PendingDelivery> = vec![&env];
+        let mut released = 0u32;
+
+        for d in deliveries.iter() {
+            if d.claimable_at <= current_ledger {
+                Self::credit_proceeds(&env, &d.recipient, d.amount);
+                released += 1;
+            } else {
+                remaining.push_back(d);
+            }
+        }
+
+        if released == 0 {
+            return Err(SettlementError::DeliveryNotReady);
+        }
+
+        Self::store_pending_deliveries(&env, &match_id, &remaining);
+        Ok(released)
+    }
+
+    /// Get match IDs with settlement legs that can be finalized now
+    ///
+    /// Covers delayed legs whose finality delay has elapsed, which
+    /// `claim_settled` releases, followed by pending forwards past their
+    /// delivery time, which `deliver_forward` closes. At most `limit` match
+    /// IDs are returned.
+    pub fn get_unfinalized_settlements(env: Env, limit: u32) -> Vec<BytesN<32>> {
+        let mut ready: Vec<BytesN<32>> = vec![&env];
+
+        let current_ledger = env.ledger().sequence();
+        for position in 0..Self::index_len(&env, &PENDING_IDX_KEY) {
+            if ready.len() >= limit {
+                return ready;
+            }
+            let Some(match_id) = Self::index_get(&env, &PENDING_IDX_KEY, position) else {
+                continue;
+            };
+            let deliveries: Vec<PendingDelivery> = env
+                .storage()
+                .persistent()
+                .get(&(PENDING_KEY, match_id.clone()))
+                .unwrap_or(vec![&env]);
+            if deliveries.iter().any(|d| d.claimable_at <= current_ledger) {
+                ready.push_back(match_id);
+            }
+        }
+
+        #[cfg(feature = "forwards")]
+        {
+            let now = env.ledger().timestamp();
+            for position in 0..Self::index_len(&env, &FWD_IDX_KEY) {
+                if ready.len() >= limit {
+                    break;
+                }
+                let Some(match_id) = Self::index_get(&env, &FWD_IDX_KEY, position) else {
+                    continue;
+                };
+                let Some(fwd) = Self::get_forward(env.clone(), match_id.clone()) else {
+                    continue;
+                };
+                if fwd.status == ForwardStatus::Pending && now >= fwd.delivery_after && !ready.contains(&match_id) {
+                    ready.push_back(match_id);
+                }
+            }
+        }
+        ready
+    }
+
+    /// Direct a settlement's asset leg to an external recipient
+    ///
+    /// Moves the settled quantity out of the buyer's escrow into a
+    /// claimable entry for `recipient`, who need not be a pool participant.
+    /// Each match can be redirected once.
+    ///
+    /// # Arguments
+    /// * `buyer` - Buyer of the settled match (must authenticate)
+    /// * `match_id` - Settled match whose asset leg is redirected
+    /// * `recipient` - Address that may claim the tokens
+    /// * `expires_at` - Ledger timestamp after which the buyer may reclaim
+    pub fn send_claimable(
+        env: Env,
+        buyer: Address,
+        match_id: BytesN<32>,
+        recipient: Address,
+        expires_at: u64,
+    ) -> Result<ClaimableDelivery, SettlementError> {
+        buyer.require_auth();
+
+        let record = Self::load_settlement(&env, &match_id).ok_or(SettlementError::MatchNotFound)?;
+        if record.buyer != buyer {
+            return Err(SettlementError::MatchNotFound);
+        }
+        if expires_at <= env.ledger().timestamp() {
+            return Err(SettlementError::ClaimableExpired);
+        }
+        let entry = (CLAIMABLES_KEY, match_id.clone());
+        if env.storage().persistent().has(&entry) {
+            return Err(SettlementError::ClaimableExists);
+        }
+
+        let key = EscrowKey::main(&buyer, &record.asset_address);
+        if Self::available_balance(&env, &key) < record.quantity {
+            return Err(SettlementError::InsufficientBalance);
+        }
+        Self::debit_escrow(&env, &key, record.quantity)?;
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(&env, &buyer, &record.asset_address, record.quantity);
+
+        let delivery = ClaimableDelivery {
+            buyer,
+            recipient: recipient.clone(),
+            asset: record.asset_address,
+            amount: record.quantity,
+            expires_at,
+            status: ClaimableStatus::Pending,
+        };
+        env.storage().persistent().set(&entry, &delivery);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+
+        DeliveryClaimable {
+            match_id,
+            recipient,
+            amount: delivery.amount,
+            expires_at,
+        }
+        .publish(&env);
+        Ok(delivery)
+    }
+
+    /// Claim a delivery as its recipient, before it expires
+    ///
+    /// # Returns
+    /// * Amount paid out, subject to the asset's redemption delay
+    pub fn claim_delivery(env: Env, recipient: Address, match_id: BytesN<32>) -> Result<i128, SettlementError> {
+        recipient.require_auth();
+
+        let mut delivery = Self::get_claimable(env.clone(), match_id.clone())
+            .filter(|d| d.recipient == recipient && d.status == ClaimableStatus::Pending)
+            .ok_or(SettlementError::ClaimableNotFound)?;
+        if env.ledger().timestamp() > delivery.expires_at {
+            return Err(SettlementError::ClaimableExpired);
+        }
+
+        delivery.status = ClaimableStatus::Claimed;
+        env.storage().persistent().set(&(CLAIMABLES_KEY, match_id), &delivery);
+        Self::pay_out(&env, &recipient, &delivery.asset, delivery.amount)?;
+        Ok(delivery.amount)
+    }
+
+    /// Return an expired, unclaimed delivery to the buyer's escrow
+    pub fn reclaim_delivery(env: Env, buyer: Address, match_id: BytesN<32>) -> Result<i128, SettlementError> {
+        buyer.require_auth();
+
+        let mut delivery = Self::get_claimable(env.clone(), match_id.clone())
+            .filter(|d| d.buyer == buyer && d.status == ClaimableStatus::Pending)
+            .ok_or(SettlementError::ClaimableNotFound)?;
+        if env.ledger().timestamp() <= delivery.expires_at {
+            return Err(SettlementError::ClaimableNotExpired);
+        }
+
+        delivery.status = ClaimableStatus::Reclaimed;
+        env.storage().persistent().set(&(CLAIMABLES_KEY, match_id), &delivery);
+        Self::credit_escrow(&env, &EscrowKey::main(&buyer, &delivery.asset), delivery.amount);
+        #[cfg(feature = "corporate-actions")]
+        Self::open_lot(&env, &buyer, &delivery.asset, delivery.amount, LotSource::Settlement);
+        Ok(delivery.amount)
+    }
+
+    /// Get the claimable delivery for a settled match, if any
+    pub fn get_claimable(env: Env, match_id: BytesN<32>) -> Option<ClaimableDelivery> {
+        env.storage().persistent().get(&(CLAIMABLES_KEY, match_id))
+    }
+
+    /// Subscribe a watchtower allowed to freeze delayed settlement legs
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `watcher` - Watchtower contract or service account
+    pub fn subscribe_watcher(env: Env, admin: Address, watcher: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut watchers = Self::get_watchers(env.clone());
+        if watchers.contains(&watcher) {
+            return Ok(());
+        }
+        if watchers.len() >= MAX_WATCHERS {
+            return Err(SettlementError::TooManyWatchers);
+        }
+        watchers.push_back(watcher);
+        env.storage().instance().set(&WATCHERS_KEY, &watchers);
+        Ok(())
+    }
+
+    /// Remove a watchtower; freezes it already placed stay until resolved
+    pub fn unsubscribe_watcher(env: Env, admin: Address, watcher: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut watchers = Self::get_watchers(env.clone());
+        let index = watchers.first_index_of(&watcher).ok_or(SettlementError::WatcherNotSubscribed)?;
+        watchers.remove(index);
+        env.storage().instance().set(&WATCHERS_KEY, &watchers);
+        Ok(())
+    }
+
+    /// Get the subscribed watchtowers
+    pub fn get_watchers(env: Env) -> Vec<Address> {
+        env.storage().instance().get(&WATCHERS_KEY).unwrap_or(vec![&env])
+    }
+
+    /// Set the payment amount at which settlements emit `HighValueSettlement`
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `payment_asset` - Payment asset the threshold applies to
+    /// * `threshold` - Minimum payment amount, or `None` to stop reporting
+    pub fn set_watch_threshold(
+        env: Env,
+        admin: Address,
+        payment_asset: Address,
+        threshold: Option<i128>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut thresholds: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&WATCH_THRESH_KEY)
+            .unwrap_or(Map::new(&env));
+        match threshold {
+            Some(threshold) if threshold <= 0 => return Err(SettlementError::InvalidAmount),
+            Some(threshold) => thresholds.set(payment_asset, threshold),
+            None => {
+                thresholds.remove(payment_asset);
+            }
+        }
+        env.storage().instance().set(&WATCH_THRESH_KEY, &thresholds);
+        Ok(())
+    }
+
+    /// Get the high-value reporting threshold for a payment asset
+    pub fn get_watch_threshold(env: Env, payment_asset: Address) -> Option<i128> {
+        let thresholds: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&WATCH_THRESH_KEY)
+            .unwrap_or(Map::new(&env));
+        thresholds.get(payment_asset)
+    }
+
+    /// Freeze a match's delayed legs pending dispute resolution
+    ///
+    /// The asset's finality delay is the challenge window: only legs still
+    /// awaiting `claim_settled` can be frozen. Frozen legs stay unclaimable
+    /// until the admin resolves the dispute with `unfreeze_delivery`.
+    ///
+    /// # Arguments
+    /// * `watcher` - Subscribed watchtower (must authenticate)
+    /// * `match_id` - Match whose delivery is disputed
+    pub fn freeze_delivery(env: Env, watcher: Address, match_id: BytesN<32>) -> Result<(), SettlementError> {
+        watcher.require_auth();
+        if !Self::get_watchers(env.clone()).contains(&watcher) {
+            return Err(SettlementError::WatcherNotSubscribed);
+        }
+        if Self::get_pending_deliveries(env.clone(), match_id.clone()).is_empty() {
+            return Err(SettlementError::DeliveryNotFound);
+        }
+
+        let entry = (FROZEN_KEY, match_id.clone());
+        env.storage().persistent().set(&entry, &watcher);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        DeliveryFrozenByWatcher { match_id, watcher }.publish(&env);
+        Ok(())
+    }
+
+    /// Lift a watchtower freeze once the dispute is resolved
+    pub fn unfreeze_delivery(env: Env, admin: Address, match_id: BytesN<32>) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().persistent().remove(&(FROZEN_KEY, match_id));
+        Ok(())
+    }
+
+    /// Get the watchtower that froze a match's delivery, if frozen
+    pub fn get_frozen_by(env: Env, match_id: BytesN<32>) -> Option<Address> {
+        env.storage().persistent().get(&(FROZEN_KEY, match_id))
+    }
+
+    /// Get settlement legs of a match still awaiting their finality delay
+    ///
+    /// Amounts include any splits of the recipient's asset applied since
+    /// the leg was held back.
+    pub fn get_pending_deliveries(env: Env, match_id: BytesN<32>) -> Vec<PendingDelivery> {
+        let deliveries: Vec<PendingDelivery> = env
+            .storage()
+            .persistent()
+            .get(&(PENDING_KEY, match_id))
+            .unwrap_or(vec![&env]);
+
+        #[cfg(feature = "corporate-actions")]
+        let deliveries = {
+            let mut current = vec![&env];
+            for mut delivery in deliveries.iter() {
+                let splits = Self::get_splits(env.clone(), delivery.recipient.asset.clone());
+                delivery.amount = splits
+                    .iter()
+                    .skip(delivery.splits_seen as usize)
+                    .fold(delivery.amount, |amount, ratio| Self::apply_ratio(amount, &ratio));
+                delivery.splits_seen = splits.len();
+                current.push_back(delivery);
+            }
+            current
+        };
+        deliveries
+    }
+
+    /// Emit `HighValueSettlement` if the payment reached the watch threshold
+    pub(crate) fn report_high_value(env: &Env, record: &SettlementRecord) {
+        match Self::get_watch_threshold(env.clone(), record.payment_asset.clone()) {
+            Some(threshold) if record.payment_amount >= threshold => HighValueSettlement {
+                match_id: record.match_id.clone(),
+                payment_asset: record.payment_asset.clone(),
+                payment_amount: record.payment_amount,
+                buyer: record.buyer.clone(),
+                seller: record.seller.clone(),
+            }
+            .publish(env),
+            _ => {}
+        }
+    }
+
+    /// Debit a settlement leg now and hold it for the recipient for `delay` ledgers
+    pub(crate) fn queue_delivery(
+        env: &Env,
+        match_id: &BytesN<32>,
+        from: &EscrowKey,
+        to: &EscrowKey,
+        amount: i128,
+        delay: u32,
+    ) {
+        // Debit the sender now; the recipient is credited on claim
+        Self::commit_debit(env, from, amount);
+
+        let mut deliveries = Self::get_pending_deliveries(env.clone(), match_id.clone());
+        deliveries.push_back(PendingDelivery {
+            recipient: to.clone(),
+            amount,
+            claimable_at: env.ledger().sequence() + delay,
+            #[cfg(feature = "corporate-actions")]
+            splits_seen: Self::get_splits(env.clone(), to.asset.clone()).len(),
+            #[cfg(not(feature = "corporate-actions"))]
+            splits_seen: 0,
+        });
+        Self::store_pending_deliveries(env, match_id, &deliveries);
+    }
+
+    /// Overwrite a match's pending deliveries, dropping the entry once empty
+    fn store_pending_deliveries(env: &Env, match_id: &BytesN<32>, deliveries: &Vec<PendingDelivery>) {
+        let entry = (PENDING_KEY, match_id.clone());
+        if deliveries.is_empty() {
+            env.storage().persistent().remove(&entry);
+            Self::index_remove(env, &PENDING_IDX_KEY, match_id);
+            return;
+        }
+        env.storage().persistent().set(&entry, deliveries);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Self::index_add(env, &PENDING_IDX_KEY, match_id);
+    }
+}
@@ -0,0 +1,621 @@
+//! Fee schedules, the protocol fee token, treasury, tiers, referrals and relayer shares
+//!
+//! Only compiled with the `fees` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, vec, Address, Env, Map, Symbol, Vec};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, FxOracleClient, SettlementError,
+    SettlementFees, BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, BPS_DENOMINATOR, DUST_KEY, ESCROW_KEY, FX_ORACLE_KEY,
+    FX_RATE_SCALE,
+};
+
+const FEES_KEY: Symbol = symbol_short!("fees");
+
+const FEE_TIERS_KEY: Symbol = symbol_short!("fee_tiers");
+
+const FEE_TOKEN_KEY: Symbol = symbol_short!("fee_token");
+
+const FEE_OPT_IN_KEY: Symbol = symbol_short!("fee_optin");
+
+pub(crate) const FEES_COLLECTED_KEY: Symbol = symbol_short!("fees_coll");
+
+const REFERRER_KEY: Symbol = symbol_short!("referrer");
+
+const REFERRAL_SHARE_KEY: Symbol = symbol_short!("ref_share");
+
+const REFERRAL_FEES_KEY: Symbol = symbol_short!("ref_fees");
+
+const RELAYER_SHARE_KEY: Symbol = symbol_short!("rly_share");
+
+const RELAYER_FEES_KEY: Symbol = symbol_short!("rly_fees");
+
+const TREASURY_KEY: Symbol = symbol_short!("treasury");
+
+/// Fees charged on the payment leg, in basis points of the payment amount
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FeeSchedule {
+    pub buyer_fee_bps: u32,
+    pub seller_fee_bps: u32,
+    /// Receives fees into its main escrow account, unless a treasury is set
+    pub recipient: Address,
+}
+
+/// Amounts each side sends and receives for a prospective settlement
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SettlementQuote {
+    pub quantity: i128,
+    /// Payment amount before fees, after any FX conversion
+    pub payment_amount: i128,
+    pub buyer_fee: i128,
+    pub seller_fee: i128,
+    /// Fees paid in the protocol token by parties that opted in
+    pub buyer_token_fee: i128,
+    pub seller_token_fee: i128,
+    /// Total the buyer sends in the payment asset, excluding fees a fee payer covers
+    pub buyer_pays: i128,
+    /// Net the seller receives in the payment asset
+    pub seller_receives: i128,
+}
+
+/// Protocol token accepted for settlement fees at a discount
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ProtocolFeeToken {
+    pub token: Address,
+    /// Discount on the converted fee, in basis points
+    pub discount_bps: u32,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Record who referred a participant
+    ///
+    /// The referrer is fixed once set, so it cannot be swapped after the fact.
+    ///
+    /// # Arguments
+    /// * `participant` - Referred participant (must authenticate)
+    /// * `referrer` - Address credited with a share of the participant's fees
+    pub fn set_referrer(env: Env, participant: Address, referrer: Address) -> Result<(), SettlementError> {
+        participant.require_auth();
+        if participant == referrer {
+            return Err(SettlementError::InvalidReferrer);
+        }
+
+        let entry = (REFERRER_KEY, participant);
+        if env.storage().persistent().has(&entry) {
+            return Err(SettlementError::ReferrerAlreadySet);
+        }
+        env.storage().persistent().set(&entry, &referrer);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Get the participant's referrer
+    pub fn get_referrer(env: Env, participant: Address) -> Option<Address> {
+        env.storage().persistent().get(&(REFERRER_KEY, participant))
+    }
+
+    /// Set the share of a referred participant's fees paid to the referrer
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `share_bps` - Referrer share in basis points of each fee
+    pub fn set_referral_share(env: Env, admin: Address, share_bps: u32) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if share_bps as i128 + Self::get_relayer_share(env.clone()) as i128 > BPS_DENOMINATOR {
+            return Err(SettlementError::InvalidFee);
+        }
+        env.storage().instance().set(&REFERRAL_SHARE_KEY, &share_bps);
+        Ok(())
+    }
+
+    /// Get the referrer share in basis points
+    pub fn get_referral_share(env: Env) -> u32 {
+        env.storage().instance().get(&REFERRAL_SHARE_KEY).unwrap_or(0)
+    }
+
+    /// Get referral fees a referrer can claim in an asset
+    pub fn get_referral_fees(env: Env, referrer: Address, asset: Address) -> i128 {
+        env.storage().persistent().get(&(REFERRAL_FEES_KEY, referrer, asset)).unwrap_or(0)
+    }
+
+    /// Claim accrued referral fees
+    ///
+    /// # Arguments
+    /// * `referrer` - Referrer address (must authenticate)
+    /// * `asset` - Asset the fees accrued in
+    ///
+    /// # Returns
+    /// * Amount transferred to the referrer
+    pub fn claim_referral_fees(env: Env, referrer: Address, asset: Address) -> i128 {
+        referrer.require_auth();
+
+        let entry = (REFERRAL_FEES_KEY, referrer.clone(), asset.clone());
+        let amount: i128 = env.storage().persistent().get(&entry).unwrap_or(0);
+        if amount > 0 {
+            env.storage().persistent().remove(&entry);
+            Self::asset_adapter(&env, &asset).transfer(&env, &env.current_contract_address(), &referrer, amount);
+        }
+        amount
+    }
+
+    /// Set the share of each fee paid to the relayer that submitted the settlement
+    ///
+    /// Only settlements submitted through `settle_trade_relayed` or
+    /// `settle_trade_idempotent` name a relayer. Together with the referral
+    /// share it may not exceed the whole fee.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `share_bps` - Relayer share in basis points of each fee
+    pub fn set_relayer_share(env: Env, admin: Address, share_bps: u32) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if share_bps as i128 + Self::get_referral_share(env.clone()) as i128 > BPS_DENOMINATOR {
+            return Err(SettlementError::InvalidFee);
+        }
+        env.storage().instance().set(&RELAYER_SHARE_KEY, &share_bps);
+        Ok(())
+    }
+
+    /// Get the relayer share in basis points
+    pub fn get_relayer_share(env: Env) -> u32 {
+        env.storage().instance().get(&RELAYER_SHARE_KEY).unwrap_or(0)
+    }
+
+    /// Get relayer fees a relayer can claim in an asset
+    pub fn get_relayer_fees(env: Env, relayer: Address, asset: Address) -> i128 {
+        env.storage().persistent().get(&(RELAYER_FEES_KEY, relayer, asset)).unwrap_or(0)
+    }
+
+    /// Claim accrued relayer fees
+    ///
+    /// # Arguments
+    /// * `relayer` - Relayer address (must authenticate)
+    /// * `asset` - Asset the fees accrued in
+    ///
+    /// # Returns
+    /// * Amount transferred to the relayer
+    pub fn claim_relayer_fees(env: Env, relayer: Address, asset: Address) -> i128 {
+        relayer.require_auth();
+
+        let entry = (RELAYER_FEES_KEY, relayer.clone(), asset.clone());
+        let amount: i128 = env.storage().persistent().get(&entry).unwrap_or(0);
+        if amount > 0 {
+            env.storage().persistent().remove(&entry);
+            Self::asset_adapter(&env, &asset).transfer(&env, &env.current_contract_address(), &relayer, amount);
+        }
+        amount
+    }
+
+    /// Set the fees charged on settlement payment legs
+    ///
+    /// Forward settlements are not charged fees.
+    pub fn set_fee_schedule(env: Env, admin: Address, schedule: FeeSchedule) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let denominator = BPS_DENOMINATOR as u32;
+        if schedule.buyer_fee_bps > denominator || schedule.seller_fee_bps > denominator {
+            return Err(SettlementError::InvalidFee);
+        }
+
+        env.storage().instance().set(&FEES_KEY, &schedule);
+        Ok(())
+    }
+
+    /// Get the fee schedule, if fees are enabled
+    pub fn get_fee_schedule(env: Env) -> Option<FeeSchedule> {
+        env.storage().instance().get(&FEES_KEY)
+    }
+
+    /// Register (or clear) the protocol token accepted for fees
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `fee_token` - Token and discount, or `None` to stop accepting it
+    pub fn set_protocol_fee_token(
+        env: Env,
+        admin: Address,
+        fee_token: Option<ProtocolFeeToken>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        match fee_token {
+            Some(fee_token) => {
+                if fee_token.discount_bps as i128 > BPS_DENOMINATOR {
+                    return Err(SettlementError::InvalidFee);
+                }
+                env.storage().instance().set(&FEE_TOKEN_KEY, &fee_token);
+            }
+            None => env.storage().instance().remove(&FEE_TOKEN_KEY),
+        }
+        Ok(())
+    }
+
+    /// Get the protocol fee token, if one is registered
+    pub fn get_protocol_fee_token(env: Env) -> Option<ProtocolFeeToken> {
+        env.storage().instance().get(&FEE_TOKEN_KEY)
+    }
+
+    /// Opt in or out of paying settlement fees in the protocol token
+    ///
+    /// Opted-in participants pay from the protocol token balance in their main
+    /// escrow account; settlement fails if it cannot cover the fee.
+    pub fn set_fee_token_opt_in(env: Env, participant: Address, opted_in: bool) {
+        participant.require_auth();
+
+        let entry = (FEE_OPT_IN_KEY, participant);
+        if opted_in {
+            env.storage().persistent().set(&entry, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        } else {
+            env.storage().persistent().remove(&entry);
+        }
+    }
+
+    /// Check whether a participant pays fees in the protocol token
+    pub fn is_fee_token_opted_in(env: Env, participant: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(FEE_OPT_IN_KEY, participant))
+            .unwrap_or(false)
+    }
+
+    /// Total fees collected in an asset
+    pub fn get_fees_collected(env: Env, asset: Address) -> i128 {
+        let collected: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&FEES_COLLECTED_KEY)
+            .unwrap_or(Map::new(&env));
+        collected.get(asset).unwrap_or(0)
+    }
+
+    /// Set the treasury that receives fees and may claim dust
+    ///
+    /// Once set, settlement fees are credited to the treasury's main escrow
+    /// account instead of the fee schedule's recipient.
+    pub fn set_treasury(env: Env, admin: Address, treasury: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&TREASURY_KEY, &treasury);
+        Ok(())
+    }
+
+    /// Get the treasury, if one is set
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&TREASURY_KEY)
+    }
+
+    /// Dust accumulated in an asset and not yet claimed
+    ///
+    /// Rounding never strands value. Fees and FX conversions round down in
+    /// the payer's favour, so the fraction stays with the payer; a
+    /// referral share rounds down and the fraction stays with the fee
+    /// recipient. Pro-rata distribution payouts round down, and once the
+    /// fractions left over by claims add up to a whole unit it moves here.
+    pub fn get_dust(env: Env, asset: Address) -> i128 {
+        let dust: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DUST_KEY)
+            .unwrap_or(Map::new(&env));
+        dust.get(asset).unwrap_or(0)
+    }
+
+    /// Transfer an asset's accumulated dust to the treasury
+    ///
+    /// # Arguments
+    /// * `treasury` - Configured treasury (must authenticate)
+    /// * `asset` - Asset the dust accumulated in
+    ///
+    /// # Returns
+    /// * Amount transferred
+    pub fn claim_dust(env: Env, treasury: Address, asset: Address) -> Result<i128, SettlementError> {
+        treasury.require_auth();
+        if Self::get_treasury(env.clone()) != Some(treasury.clone()) {
+            return Err(SettlementError::OnlyTreasury);
+        }
+
+        let mut dust: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DUST_KEY)
+            .unwrap_or(Map::new(&env));
+        let amount = dust.get(asset.clone()).unwrap_or(0);
+        if amount > 0 {
+            dust.remove(asset.clone());
+            env.storage().instance().set(&DUST_KEY, &dust);
+            Self::asset_adapter(&env, &asset).transfer(&env, &env.current_contract_address(), &treasury, amount);
+        }
+        Ok(amount)
+    }
+
+    /// Set a participant's fee rebate tier, in basis points off each fee
+    pub fn set_fee_tier(
+        env: Env,
+        admin: Address,
+        participant: Address,
+        rebate_bps: u32,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut tiers: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&FEE_TIERS_KEY)
+            .unwrap_or(Map::new(&env));
+        if rebate_bps == 0 {
+            tiers.remove(participant);
+        } else {
+            tiers.set(participant, rebate_bps);
+        }
+        env.storage().instance().set(&FEE_TIERS_KEY, &tiers);
+        Ok(())
+    }
+
+    /// Get a participant's fee rebate in basis points
+    pub fn get_fee_tier(env: Env, participant: Address) -> u32 {
+        let tiers: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&FEE_TIERS_KEY)
+            .unwrap_or(Map::new(&env));
+        tiers.get(participant).unwrap_or(0)
+    }
+
+    /**
+     * Preview what each side sends and receives for a settlement
+     *
+     * Applies the same FX conversion, fee schedule and rebate tiers as
+     * settlement, so frontends can show exact fill confirmations before a
+     * proof is submitted.
+     *
+     * # Arguments
+     * * `quantity` - Amount of RWA tokens
+     * * `price` - Total price in the asset's quote currency
+     * * `asset_address` - The RWA token
+     * * `payment_asset` - The payment token
+     * * `buyer` - Buyer's address
+     * * `seller` - Seller's address
+     */
+    #[cfg_attr(not(feature = "fx"), allow(unused_variables))]
+    pub fn quote_settlement(
+        env: Env,
+        quantity: i128,
+        price: i128,
+        asset_address: Address,
+        payment_asset: Address,
+        buyer: Address,
+        seller: Address,
+    ) -> Result<SettlementQuote, SettlementError> {
+        Self::check_trade_amounts(quantity, price)?;
+
+        #[cfg(feature = "fx")]
+        let payment_amount = Self::convert_payment(&env, &asset_address, &payment_asset, price)?;
+        #[cfg(not(feature = "fx"))]
+        let payment_amount = price;
+        let fees = Self::compute_fees(&env, &buyer, &seller, &payment_asset, payment_amount)?;
+
+        Ok(SettlementQuote {
+            quantity,
+            payment_amount,
+            buyer_fee: fees.buyer_fee,
+            seller_fee: fees.seller_fee,
+            buyer_token_fee: fees.buyer_token_fee,
+            seller_token_fee: fees.seller_token_fee,
+            buyer_pays: payment_amount + fees.buyer_fee_from_buyer(),
+            seller_receives: payment_amount - fees.seller_fee_from_payment(),
+        })
+    }
+
+    /// Buyer and seller fees owed on a payment amount after rebates
+    pub(crate) fn compute_fees(
+        env: &Env,
+        buyer: &Address,
+        seller: &Address,
+        payment_asset: &Address,
+        payment_amount: i128,
+    ) -> Result<SettlementFees, SettlementError> {
+        let schedule = match Self::get_fee_schedule(env.clone()) {
+            Some(schedule) => schedule,
+            None => return Ok(SettlementFees::default()),
+        };
+
+        let buyer_bps = schedule
+            .buyer_fee_bps
+            .saturating_sub(Self::get_fee_tier(env.clone(), buyer.clone()));
+        let seller_bps = schedule
+            .seller_fee_bps
+            .saturating_sub(Self::get_fee_tier(env.clone(), seller.clone()));
+
+        let mut fees = SettlementFees {
+            buyer_fee: Self::mul_div(payment_amount, buyer_bps as i128, BPS_DENOMINATOR)?,
+            seller_fee: Self::mul_div(payment_amount, seller_bps as i128, BPS_DENOMINATOR)?,
+            // Brokers only cover fees with the delegation feature
+            #[cfg(feature = "delegation")]
+            buyer_payer: Self::get_fee_payer(env.clone(), buyer.clone()),
+            #[cfg(feature = "delegation")]
+            seller_payer: Self::get_fee_payer(env.clone(), seller.clone()),
+            ..SettlementFees::default()
+        };
+        // Opting in has no effect until a protocol token is registered
+        let fee_token = match Self::get_protocol_fee_token(env.clone()) {
+            Some(fee_token) => fee_token,
+            None => return Ok(fees),
+        };
+        // Whoever pays the fee decides which token it is paid in
+        if Self::is_fee_token_opted_in(env.clone(), fees.buyer_payer.clone().unwrap_or(buyer.clone())) {
+            fees.buyer_token_fee = Self::to_fee_token(env, &fee_token, payment_asset, fees.buyer_fee)?;
+            fees.buyer_fee = 0;
+        }
+        if Self::is_fee_token_opted_in(env.clone(), fees.seller_payer.clone().unwrap_or(seller.clone())) {
+            fees.seller_token_fee = Self::to_fee_token(env, &fee_token, payment_asset, fees.seller_fee)?;
+            fees.seller_fee = 0;
+        }
+        Ok(fees)
+    }
+
+    /// Convert a payment-asset fee to the discounted protocol token amount
+    fn to_fee_token(
+        env: &Env,
+        fee_token: &ProtocolFeeToken,
+        payment_asset: &Address,
+        fee: i128,
+    ) -> Result<i128, SettlementError> {
+        let discounted = Self::mul_div(
+            fee,
+            BPS_DENOMINATOR - fee_token.discount_bps as i128,
+            BPS_DENOMINATOR,
+        )?;
+        if fee_token.token == *payment_asset {
+            return Ok(discounted);
+        }
+
+        let oracle_address: Address = env
+            .storage()
+            .instance()
+            .get(&FX_ORACLE_KEY)
+            .ok_or(SettlementError::FxOracleNotSet)?;
+        let rate = FxOracleClient::new(env, &oracle_address).get_rate(payment_asset, &fee_token.token);
+        if rate <= 0 {
+            return Err(SettlementError::InvalidFxRate);
+        }
+        Self::mul_div(discounted, rate, FX_RATE_SCALE)
+    }
+
+    /// Move checked fees to the fee recipient
+    ///
+    /// A seller's payment-asset fee withheld from the payment must already
+    /// have been debited from the buyer's payment; everything else is taken
+    /// here.
+    pub(crate) fn collect_fees(env: &Env, buyer: &EscrowKey, seller: &EscrowKey, fees: &SettlementFees) {
+        if fees.is_zero() {
+            return;
+        }
+        let recipient = match Self::get_treasury(env.clone()) {
+            Some(treasury) => treasury,
+            None => Self::get_fee_schedule(env.clone()).unwrap().recipient,
+        };
+
+        for (key, fee) in Self::fee_debits(env, buyer, seller, fees) {
+            Self::debit_fee(env, &key, fee);
+        }
+        let relayer = fees.relayer.as_ref();
+        Self::credit_fee(env, &recipient, relayer, &buyer.participant, &buyer.asset, fees.buyer_fee);
+        Self::credit_fee(env, &recipient, relayer, &seller.participant, &buyer.asset, fees.seller_fee);
+
+        if fees.buyer_token_fee > 0 || fees.seller_token_fee > 0 {
+            let token = Self::get_protocol_fee_token(env.clone()).unwrap().token;
+            Self::credit_fee(env, &recipient, relayer, &buyer.participant, &token, fees.buyer_token_fee);
+            Self::credit_fee(env, &recipient, relayer, &seller.participant, &token, fees.seller_token_fee);
+        }
+    }
+
+    /// Accounts fees are taken from, other than the seller's fee withheld from the payment
+    ///
+    /// The buyer's payment-asset fee comes from the buyer's paying account,
+    /// fees covered by a fee payer from its main account, and protocol token
+    /// fees from the paying side's main account in that token.
+    pub(crate) fn fee_debits(
+        env: &Env,
+        buyer: &EscrowKey,
+        seller: &EscrowKey,
+        fees: &SettlementFees,
+    ) -> Vec<(EscrowKey, i128)> {
+        let mut debits = vec![env];
+        match &fees.buyer_payer {
+            Some(payer) => debits.push_back((EscrowKey::main(payer, &buyer.asset), fees.buyer_fee)),
+            None => debits.push_back((buyer.clone(), fees.buyer_fee)),
+        }
+        if let Some(payer) = &fees.seller_payer {
+            debits.push_back((EscrowKey::main(payer, &buyer.asset), fees.seller_fee));
+        }
+        if let Some(fee_token) = Self::get_protocol_fee_token(env.clone()) {
+            for (party, payer, token_fee) in [
+                (&buyer.participant, &fees.buyer_payer, fees.buyer_token_fee),
+                (&seller.participant, &fees.seller_payer, fees.seller_token_fee),
+            ] {
+                let account = payer.as_ref().unwrap_or(party);
+                debits.push_back((EscrowKey::main(account, &fee_token.token), token_fee));
+            }
+        }
+        debits
+    }
+
+    /// Take a checked fee out of an account's unlocked escrow
+    fn debit_fee(env: &Env, key: &EscrowKey, fee: i128) {
+        if fee <= 0 {
+            return;
+        }
+        let escrow = Self::read_balance(env, &ESCROW_KEY, key);
+        Self::write_balance(env, &ESCROW_KEY, key, escrow - fee);
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(env, &key.participant, &key.asset, fee);
+    }
+
+    /// Credit a fee to the recipient's main account and tally it per asset
+    ///
+    /// If the payer was referred, the referral share is set aside for the
+    /// referrer to claim instead, and likewise the relayer share for the
+    /// submitting relayer.
+    fn credit_fee(
+        env: &Env,
+        recipient: &Address,
+        relayer: Option<&Address>,
+        payer: &Address,
+        asset: &Address,
+        amount: i128,
+    ) {
+        if amount == 0 {
+            return;
+        }
+        let mut referral = 0;
+        if let Some(referrer) = Self::get_referrer(env.clone(), payer.clone()) {
+            referral = amount * Self::get_referral_share(env.clone()) as i128 / BPS_DENOMINATOR;
+            if referral > 0 {
+                let entry = (REFERRAL_FEES_KEY, referrer.clone(), asset.clone());
+                let accrued = Self::get_referral_fees(env.clone(), referrer, asset.clone());
+                env.storage().persistent().set(&entry, &(accrued + referral));
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+            }
+        }
+        let mut relayer_share = 0;
+        if let Some(relayer) = relayer {
+            relayer_share = amount * Self::get_relayer_share(env.clone()) as i128 / BPS_DENOMINATOR;
+            if relayer_share > 0 {
+                let entry = (RELAYER_FEES_KEY, relayer.clone(), asset.clone());
+                let accrued = Self::get_relayer_fees(env.clone(), relayer.clone(), asset.clone());
+                env.storage().persistent().set(&entry, &(accrued + relayer_share));
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+            }
+        }
+        if amount > referral + relayer_share {
+            Self::credit_escrow(env, &EscrowKey::main(recipient, asset), amount - referral - relayer_share);
+        }
+
+        let mut collected: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&FEES_COLLECTED_KEY)
+            .unwrap_or(Map::new(env));
+        collected.set(asset.clone(), collected.get(asset.clone()).unwrap_or(0) + amount);
+        env.storage().instance().set(&FEES_COLLECTED_KEY, &collected);
+    }
+}
@@ -0,0 +1,202 @@
+//! Forward settlements
+//!
+//! Only compiled with the `forwards` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Symbol};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, BPS_DENOMINATOR, LOCKED_KEY,
+};
+
+const FWD_REG_KEY: Symbol = symbol_short!("fwd_reg");
+
+const FORWARDS_KEY: Symbol = symbol_short!("forwards");
+
+pub(crate) const FWD_IDX_KEY: Symbol = symbol_short!("fwd_idx");
+
+const FWD_GRACE_KEY: Symbol = symbol_short!("fwd_grace");
+
+const FWD_BOND_KEY: Symbol = symbol_short!("fwd_bond");
+
+/// Lifecycle of a forward (deferred delivery) settlement
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum ForwardStatus {
+    /// Payment held, awaiting asset delivery
+    Pending = 0,
+    /// Asset delivered to the buyer and payment released to the seller
+    Delivered = 1,
+    /// Seller failed to deliver; payment refunded and bond paid to the buyer
+    Defaulted = 2,
+}
+
+/// A settled match whose asset leg is delivered at a later date
+///
+/// The buyer's payment and the seller's bond are held by the contract at
+/// settlement; the seller's asset stays locked in their escrow until
+/// `deliver_forward`.
+#[derive(Clone)]
+#[contracttype]
+pub struct ForwardSettlement {
+    pub buyer: EscrowKey,
+    pub seller: EscrowKey,
+    pub quantity: i128,
+    pub payment_asset: Address,
+    pub payment_amount: i128,
+    /// Payment asset taken from the seller, returned on delivery and paid
+    /// to the buyer on default
+    pub seller_bond: i128,
+    pub delivery_after: u64,
+    pub status: ForwardStatus,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Register a match for deferred asset delivery
+    ///
+    /// When the match settles, the payment leg is taken from the buyer into a
+    /// pending bucket while the asset leg is only delivered at or after
+    /// `delivery_after` via `deliver_forward`, supporting primary issuance flows.
+    /// The seller must have the forward bond locked in the payment asset.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `match_id` - Match to settle as a forward
+    /// * `delivery_after` - Earliest ledger timestamp for asset delivery
+    pub fn register_forward(
+        env: Env,
+        admin: Address,
+        match_id: BytesN<32>,
+        delivery_after: u64,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let entry = (FWD_REG_KEY, match_id);
+        env.storage().persistent().set(&entry, &delivery_after);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Set how long after `delivery_after` a seller has to deliver before default
+    pub fn set_forward_grace(env: Env, admin: Address, seconds: u64) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&FWD_GRACE_KEY, &seconds);
+        Ok(())
+    }
+
+    /// Get the forward delivery grace period in seconds
+    pub fn get_forward_grace(env: Env) -> u64 {
+        env.storage().instance().get(&FWD_GRACE_KEY).unwrap_or(0)
+    }
+
+    /// Set the bond a forward seller posts, in basis points of the payment
+    ///
+    /// The bond is taken from the seller's locked payment asset when the
+    /// forward settles, so defaulting costs the seller instead of only
+    /// unwinding the trade.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `bond_bps` - Bond in basis points of the payment amount
+    pub fn set_forward_bond(env: Env, admin: Address, bond_bps: u32) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if bond_bps as i128 > BPS_DENOMINATOR {
+            return Err(SettlementError::InvalidFee);
+        }
+        env.storage().instance().set(&FWD_BOND_KEY, &bond_bps);
+        Ok(())
+    }
+
+    /// Get the forward seller bond in basis points, the full payment until set
+    pub fn get_forward_bond(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&FWD_BOND_KEY)
+            .unwrap_or(BPS_DENOMINATOR as u32)
+    }
+
+    /// Deliver the asset leg of a forward settlement
+    ///
+    /// Anyone may call this once `delivery_after` has passed. The seller's
+    /// locked asset moves to the buyer and the held payment and bond are
+    /// released to the seller. If the seller no longer has the asset locked
+    /// and the grace period has elapsed, the forward defaults: the buyer is
+    /// refunded and receives the seller's bond.
+    pub fn deliver_forward(env: Env, match_id: BytesN<32>) -> Result<ForwardStatus, SettlementError> {
+        let mut fwd = Self::get_forward(env.clone(), match_id.clone()).ok_or(SettlementError::ForwardNotFound)?;
+
+        if fwd.status != ForwardStatus::Pending {
+            return Err(SettlementError::ForwardClosed);
+        }
+        let now = env.ledger().timestamp();
+        if now < fwd.delivery_after {
+            return Err(SettlementError::ForwardNotDue);
+        }
+
+        let buyer_payment = EscrowKey::new(&fwd.buyer.participant, &fwd.buyer.sub_account, &fwd.payment_asset);
+        let seller_payment = EscrowKey::new(&fwd.seller.participant, &fwd.seller.sub_account, &fwd.payment_asset);
+
+        if Self::read_balance(&env, &LOCKED_KEY, &fwd.seller) >= fwd.quantity {
+            Self::transfer_between(&env, &fwd.seller, &fwd.buyer, fwd.quantity)?;
+            Self::credit_proceeds(&env, &seller_payment, fwd.payment_amount);
+            Self::credit_escrow(&env, &seller_payment, fwd.seller_bond);
+            fwd.status = ForwardStatus::Delivered;
+        } else if now >= fwd.delivery_after + Self::get_forward_grace(env.clone()) {
+            Self::credit_escrow(&env, &buyer_payment, fwd.payment_amount + fwd.seller_bond);
+            fwd.status = ForwardStatus::Defaulted;
+        } else {
+            return Err(SettlementError::InsufficientLockedFunds);
+        }
+
+        Self::store_forward(&env, &match_id, &fwd);
+        Ok(fwd.status)
+    }
+
+    /// Get a forward settlement by match ID
+    pub fn get_forward(env: Env, match_id: BytesN<32>) -> Option<ForwardSettlement> {
+        env.storage().persistent().get(&(FORWARDS_KEY, match_id))
+    }
+
+    /// Move locked funds from one escrow account to another
+    pub(crate) fn transfer_between(
+        env: &Env,
+        from: &EscrowKey,
+        to: &EscrowKey,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        Self::check_transfer(env, from, amount)?;
+        Self::commit_transfer(env, from, to, amount);
+        Ok(())
+    }
+
+    /// Remove and return the forward registration for a match, if any
+    pub(crate) fn take_forward_registration(env: &Env, match_id: &BytesN<32>) -> Option<u64> {
+        let entry = (FWD_REG_KEY, match_id.clone());
+        let delivery_after = env.storage().persistent().get(&entry)?;
+        env.storage().persistent().remove(&entry);
+        Some(delivery_after)
+    }
+
+    /// Store a forward, indexing it while it is still pending delivery
+    pub(crate) fn store_forward(env: &Env, match_id: &BytesN<32>, forward: &ForwardSettlement) {
+        let entry = (FORWARDS_KEY, match_id.clone());
+        env.storage().persistent().set(&entry, forward);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        if forward.status == ForwardStatus::Pending {
+            Self::index_add(env, &FWD_IDX_KEY, match_id);
+        } else {
+            Self::index_remove(env, &FWD_IDX_KEY, match_id);
+        }
+    }
+}
@@ -0,0 +1,274 @@
+//! Quote assets, FX rates and unit-priced settlements
+//!
+//! Only compiled with the `fx` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, Symbol};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, FxOracleClient, SettlementError,
+    SettlementRecord, BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, BPS_DENOMINATOR, DEFAULT_SUB_ACCOUNT, FX_ORACLE_KEY,
+    FX_RATE_SCALE,
+};
+
+const FX_TOLERANCE_KEY: Symbol = symbol_short!("fx_tol");
+
+const FX_RATES_KEY: Symbol = symbol_short!("fx_rates");
+
+const QUOTE_ASSETS_KEY: Symbol = symbol_short!("quotes");
+
+const UNIT_QUOTES_KEY: Symbol = symbol_short!("unit_qts");
+
+/// Fixed-point scale for per-unit prices (7 decimals)
+pub const UNIT_PRICE_SCALE: i128 = 10_000_000;
+
+/// How a unit-priced trade's total is rounded to whole payment units
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum RoundingMode {
+    /// Round down; the seller absorbs the fraction
+    FloorToSeller = 0,
+    /// Round up; the buyer pays the fraction
+    CeilToBuyer = 1,
+}
+
+/// Per-unit quote a settlement's total price was derived from
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct UnitQuote {
+    /// Price per unit of the asset, scaled by `UNIT_PRICE_SCALE`
+    pub unit_price: i128,
+    pub rounding: RoundingMode,
+}
+
+/// Currency pair for FX conversion of the payment leg
+#[derive(Clone)]
+#[contracttype]
+pub struct FxPair {
+    pub base: Address,
+    pub quote: Address,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Settle a matched trade quoted per unit
+    ///
+    /// Identical to `settle_trade`, except that the total price is derived
+    /// on-chain as `quantity * unit_price / UNIT_PRICE_SCALE`, rounded as
+    /// `rounding` specifies. The quote is recorded alongside the settlement.
+    ///
+    /// # Arguments
+    /// * `unit_price` - Price per unit of the asset, scaled by `UNIT_PRICE_SCALE`
+    /// * `rounding` - Which party absorbs the fraction of a payment unit
+    pub fn settle_trade_unit_priced(
+        env: Env,
+        match_id: BytesN<32>,
+        buyer: Address,
+        seller: Address,
+        asset_address: Address,
+        payment_asset: Address,
+        quantity: i128,
+        unit_price: i128,
+        rounding: RoundingMode,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<SettlementRecord, SettlementError> {
+        Self::require_unmetered(&env)?;
+        Self::check_trade_amounts(quantity, unit_price)?;
+        let price = Self::unit_priced_total(quantity, unit_price, rounding)?;
+
+        let record = Self::execute_settlement(
+            &env,
+            None,
+            Self::get_auth_mode(env.clone()),
+            &match_id,
+            &buyer,
+            &DEFAULT_SUB_ACCOUNT,
+            &seller,
+            &DEFAULT_SUB_ACCOUNT,
+            &asset_address,
+            &payment_asset,
+            quantity,
+            price,
+            &proof_bytes,
+            &pub_signals_bytes,
+        )?;
+
+        let entry = (UNIT_QUOTES_KEY, match_id);
+        env.storage().persistent().set(&entry, &UnitQuote { unit_price, rounding });
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(record)
+    }
+
+    /// Set the quote currency that orders for an asset are priced in
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `asset_address` - The RWA token
+    /// * `quote_asset` - The currency its orders are quoted in (e.g., USDC)
+    pub fn set_quote_asset(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        quote_asset: Address,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut quotes: Map<Address, Address> = env
+            .storage()
+            .instance()
+            .get(&QUOTE_ASSETS_KEY)
+            .unwrap_or(Map::new(&env));
+        quotes.set(asset_address, quote_asset);
+        env.storage().instance().set(&QUOTE_ASSETS_KEY, &quotes);
+        Ok(())
+    }
+
+    /// Set the maximum allowed deviation between the pair rate and the oracle rate
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `tolerance_bps` - Maximum deviation in basis points
+    pub fn set_fx_tolerance(
+        env: Env,
+        admin: Address,
+        tolerance_bps: u32,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&FX_TOLERANCE_KEY, &tolerance_bps);
+        Ok(())
+    }
+
+    /// Set the conversion rate used to pay for `base`-quoted trades in `quote`
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `base` - The quote currency of the order
+    /// * `quote` - The payment currency actually delivered
+    /// * `rate` - Units of `quote` per unit of `base`, scaled by `FX_RATE_SCALE`
+    pub fn set_fx_rate(
+        env: Env,
+        admin: Address,
+        base: Address,
+        quote: Address,
+        rate: i128,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if rate <= 0 {
+            return Err(SettlementError::InvalidFxRate);
+        }
+
+        let mut rates: Map<FxPair, i128> = env
+            .storage()
+            .instance()
+            .get(&FX_RATES_KEY)
+            .unwrap_or(Map::new(&env));
+        rates.set(FxPair { base, quote }, rate);
+        env.storage().instance().set(&FX_RATES_KEY, &rates);
+        Ok(())
+    }
+
+    /// Get the quote currency configured for an asset
+    pub fn get_quote_asset(env: Env, asset_address: Address) -> Option<Address> {
+        let quotes: Map<Address, Address> = env
+            .storage()
+            .instance()
+            .get(&QUOTE_ASSETS_KEY)
+            .unwrap_or(Map::new(&env));
+        quotes.get(asset_address)
+    }
+
+    /// Get the configured conversion rate for a currency pair
+    pub fn get_fx_rate(env: Env, base: Address, quote: Address) -> Option<i128> {
+        let rates: Map<FxPair, i128> = env
+            .storage()
+            .instance()
+            .get(&FX_RATES_KEY)
+            .unwrap_or(Map::new(&env));
+        rates.get(FxPair { base, quote })
+    }
+
+    /// Get the FX tolerance in basis points
+    pub fn get_fx_tolerance(env: Env) -> u32 {
+        env.storage().instance().get(&FX_TOLERANCE_KEY).unwrap_or(0)
+    }
+
+    /// Get the per-unit quote a settlement was priced from
+    ///
+    /// Returns `None` for settlements priced in total. Gated like
+    /// `get_settlement`.
+    pub fn get_unit_quote(
+        env: Env,
+        viewer: Option<Address>,
+        match_id: BytesN<32>,
+    ) -> Result<Option<UnitQuote>, SettlementError> {
+        if Self::get_settlement(env.clone(), viewer, match_id.clone())?.is_none() {
+            return Ok(None);
+        }
+        Ok(env.storage().persistent().get(&(UNIT_QUOTES_KEY, match_id)))
+    }
+
+    /// Convert a quote-currency price into the payment asset
+    ///
+    /// Returns `price` unchanged when the asset has no quote currency configured
+    /// or is paid in its own quote currency.
+    pub(crate) fn convert_payment(
+        env: &Env,
+        asset: &Address,
+        payment_asset: &Address,
+        price: i128,
+    ) -> Result<i128, SettlementError> {
+        let quote_asset = match Self::get_quote_asset(env.clone(), asset.clone()) {
+            Some(q) if q != *payment_asset => q,
+            _ => return Ok(price),
+        };
+
+        let rate = Self::get_fx_rate(env.clone(), quote_asset.clone(), payment_asset.clone())
+            .ok_or(SettlementError::FxRateNotSet)?;
+
+        let oracle_address: Address = env
+            .storage()
+            .instance()
+            .get(&FX_ORACLE_KEY)
+            .ok_or(SettlementError::FxOracleNotSet)?;
+        let oracle_rate = FxOracleClient::new(env, &oracle_address).get_rate(&quote_asset, payment_asset);
+        if oracle_rate <= 0 {
+            return Err(SettlementError::InvalidFxRate);
+        }
+
+        // Reject if the pair rate has drifted from the oracle beyond tolerance
+        let tolerance = Self::get_fx_tolerance(env.clone()) as i128;
+        let deviation = (rate - oracle_rate)
+            .checked_abs()
+            .and_then(|d| d.checked_mul(BPS_DENOMINATOR))
+            .ok_or(SettlementError::NotionalOverflow)?;
+        let allowed = tolerance
+            .checked_mul(oracle_rate)
+            .ok_or(SettlementError::NotionalOverflow)?;
+        if deviation > allowed {
+            return Err(SettlementError::FxRateOutOfTolerance);
+        }
+
+        Self::mul_div(price, rate, FX_RATE_SCALE)
+    }
+
+    /// Total price of `quantity` units at a scaled per-unit price
+    fn unit_priced_total(quantity: i128, unit_price: i128, rounding: RoundingMode) -> Result<i128, SettlementError> {
+        let notional = quantity
+            .checked_mul(unit_price)
+            .ok_or(SettlementError::NotionalOverflow)?;
+        let total = notional / UNIT_PRICE_SCALE;
+        match rounding {
+            RoundingMode::FloorToSeller => Ok(total),
+            RoundingMode::CeilToBuyer if notional % UNIT_PRICE_SCALE != 0 => Ok(total + 1),
+            RoundingMode::CeilToBuyer => Ok(total),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Hashed public inputs, folding a proof's signals into one Poseidon hash
+//!
+//! Only compiled with the `input-modes` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Env, Map, Symbol};
+
+use crate::{DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, SettlementError};
+
+const INPUT_MODES_KEY: Symbol = symbol_short!("in_modes");
+
+/// How a proof type's public signals are presented to the verifier
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum PublicInputMode {
+    /// Every signal is a public input of the circuit
+    Full = 0,
+    /// The circuit takes one public input, the Poseidon fold of the signals
+    /// computed by `zk_bn254::hash_public_signals`
+    Hashed = 1,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Choose how a proof type's public signals are verified
+    ///
+    /// In `Hashed` mode callers still submit every signal, since the contract
+    /// reads them, but the proof is checked against their hash alone. The
+    /// proof type's verification key must then have a single public input.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `proof_type` - Proof type identifier (e.g., `SETTLEMENT_PROOF`)
+    /// * `mode` - Full or hashed public inputs
+    pub fn set_input_mode(
+        env: Env,
+        admin: Address,
+        proof_type: Symbol,
+        mode: PublicInputMode,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut modes: Map<Symbol, PublicInputMode> = env
+            .storage()
+            .instance()
+            .get(&INPUT_MODES_KEY)
+            .unwrap_or(Map::new(&env));
+        modes.set(proof_type, mode);
+        env.storage().instance().set(&INPUT_MODES_KEY, &modes);
+        Ok(())
+    }
+
+    /// Get the public input mode for a proof type
+    pub fn get_input_mode(env: Env, proof_type: Symbol) -> PublicInputMode {
+        let modes: Map<Symbol, PublicInputMode> = env
+            .storage()
+            .instance()
+            .get(&INPUT_MODES_KEY)
+            .unwrap_or(Map::new(&env));
+        modes.get(proof_type).unwrap_or(PublicInputMode::Full)
+    }
+}
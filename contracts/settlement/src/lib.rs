@@ -1,13 +1,112 @@
 #![no_std]
+#![allow(clippy::too_many_arguments)]
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec,
-    Address, Bytes, BytesN, Env, Map, Symbol, Vec,
+    contract, contractclient, contracterror, contractevent, contractimpl, contracttype, symbol_short, vec, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, Map, FromVal, IntoVal, Symbol, Val, Vec,
 };
 
+mod adapter;
+#[cfg(feature = "accounts")]
+mod accounts;
+#[cfg(feature = "baskets")]
+mod baskets;
+#[cfg(feature = "bridge")]
+mod bridge;
+#[cfg(feature = "cancellation")]
+mod cancellation;
+#[cfg(feature = "corporate-actions")]
+mod corporate_actions;
+#[cfg(feature = "debug-events")]
+mod debug;
+#[cfg(feature = "delegation")]
+mod delegation;
+#[cfg(feature = "delivery")]
+mod delivery;
+#[cfg(feature = "fees")]
+mod fees;
+#[cfg(feature = "forwards")]
+mod forwards;
+#[cfg(feature = "fx")]
+mod fx;
+#[cfg(feature = "input-modes")]
+mod input_modes;
+#[cfg(feature = "margin")]
+mod margin;
+#[cfg(not(all(
+    feature = "margin",
+    feature = "order-controls",
+    feature = "records",
+    feature = "relayers",
+    feature = "withdrawal-queue"
+)))]
+mod missing_features;
+#[cfg(feature = "netting")]
+mod netting;
+#[cfg(feature = "ops")]
+mod ops;
+#[cfg(feature = "order-controls")]
+mod order_controls;
+#[cfg(feature = "proofs")]
+mod proofs;
+#[cfg(feature = "records")]
+mod records;
+#[cfg(feature = "relayers")]
+mod relayers;
+#[cfg(feature = "schedules")]
+mod schedules;
+#[cfg(feature = "verification-routes")]
+mod verification_routes;
+#[cfg(feature = "withdrawal-queue")]
+mod withdrawal_queue;
 #[cfg(test)]
 mod test;
 
+use adapter::AssetAdapter;
+pub use adapter::{TransferHook, TransferHookClient};
+#[cfg(feature = "accounts")]
+pub use accounts::*;
+#[cfg(feature = "baskets")]
+pub use baskets::*;
+#[cfg(feature = "bridge")]
+pub use bridge::*;
+#[cfg(feature = "cancellation")]
+pub use cancellation::*;
+#[cfg(feature = "corporate-actions")]
+pub use corporate_actions::*;
+#[cfg(feature = "delegation")]
+pub use delegation::*;
+#[cfg(feature = "delivery")]
+pub use delivery::*;
+#[cfg(feature = "fees")]
+pub use fees::*;
+#[cfg(feature = "forwards")]
+pub use forwards::*;
+#[cfg(feature = "fx")]
+pub use fx::*;
+#[cfg(feature = "input-modes")]
+pub use input_modes::*;
+#[cfg(feature = "margin")]
+pub use margin::*;
+#[cfg(feature = "netting")]
+pub use netting::*;
+#[cfg(feature = "ops")]
+pub use ops::*;
+#[cfg(feature = "order-controls")]
+pub use order_controls::*;
+#[cfg(feature = "proofs")]
+pub use proofs::*;
+#[cfg(feature = "records")]
+pub use records::*;
+#[cfg(feature = "relayers")]
+pub use relayers::*;
+#[cfg(feature = "schedules")]
+pub use schedules::*;
+#[cfg(feature = "verification-routes")]
+pub use verification_routes::*;
+#[cfg(feature = "withdrawal-queue")]
+pub use withdrawal_queue::*;
+
 // Import the verifier contract
 mod verifier_wasm {
     soroban_sdk::contractimport!(
@@ -31,6 +130,114 @@ const NULLIFIERS_KEY: Symbol = symbol_short!("nulls");
 const ESCROW_KEY: Symbol = symbol_short!("escrow");
 const LOCKED_KEY: Symbol = symbol_short!("locked");
 const SETTLEMENTS_KEY: Symbol = symbol_short!("settls");
+const FX_ORACLE_KEY: Symbol = symbol_short!("fx_orcl");
+#[cfg(any(feature = "bridge", feature = "delivery", feature = "forwards"))]
+const INDEX_LEN_KEY: Symbol = symbol_short!("idx_len");
+const AUTH_MODE_KEY: Symbol = symbol_short!("auth_mode");
+const WL_CHECK_KEY: Symbol = symbol_short!("wl_check");
+const MAX_ROOT_AGE_KEY: Symbol = symbol_short!("root_age");
+#[cfg(any(feature = "baskets", feature = "ops", feature = "proofs"))]
+const BASKET_VK_KEY: Symbol = symbol_short!("bskt_vk");
+const PAUSED_KEY: Symbol = symbol_short!("paused");
+const STORAGE_VERSION_KEY: Symbol = symbol_short!("st_ver");
+const SETTLEMENT_IDS_KEY: Symbol = symbol_short!("settl_ids");
+const SETTLEMENT_COUNT_KEY: Symbol = symbol_short!("settl_cnt");
+const VERIFY_STATS_KEY: Symbol = symbol_short!("vrf_stats");
+const NULLIFIER_COUNT_KEY: Symbol = symbol_short!("null_cnt");
+const INSTANCE_LIVE_KEY: Symbol = symbol_short!("inst_live");
+const PAY_ASSETS_KEY: Symbol = symbol_short!("pay_asset");
+const HEARTBEAT_KEY: Symbol = symbol_short!("heartbeat");
+#[cfg(any(feature = "cancellation", feature = "ops", feature = "proofs"))]
+const CANCEL_VK_KEY: Symbol = symbol_short!("cancel_vk");
+const ORACLE_AGE_KEY: Symbol = symbol_short!("orcl_age");
+#[cfg(any(feature = "corporate-actions", feature = "fees", feature = "ops"))]
+const DUST_KEY: Symbol = symbol_short!("dust");
+const ESCROW_TOTAL_KEY: Symbol = symbol_short!("esc_total");
+const LOCKED_TOTAL_KEY: Symbol = symbol_short!("lck_total");
+const DOMAIN_BINDING_KEY: Symbol = symbol_short!("dom_bind");
+const POOL_DOMAIN: Symbol = symbol_short!("dp_pool");
+const IN_FLIGHT_KEY: Symbol = symbol_short!("in_flight");
+
+// Settings of optional features, also read by builds without them so that a
+// setting left behind by a dropped feature is refused rather than ignored
+const RATE_LIMIT_KEY: Symbol = symbol_short!("rate_lim");
+const REGISTERED_RELAYERS_KEY: Symbol = symbol_short!("reg_rly");
+const MAX_NOTIONAL_KEY: Symbol = symbol_short!("max_notl");
+const COUNTERPARTY_KEY: Symbol = symbol_short!("cpty_pol");
+const LAST_LOOK_KEY: Symbol = symbol_short!("last_look");
+const INVENTORY_KEY: Symbol = symbol_short!("inventory");
+const TICKS_KEY: Symbol = symbol_short!("ticks");
+const RECORD_PRIVACY_KEY: Symbol = symbol_short!("rec_priv");
+const RECORD_FORMAT_KEY: Symbol = symbol_short!("rec_fmt");
+const PLEDGED_KEY: Symbol = symbol_short!("pledged");
+const REDEEM_DELAY_KEY: Symbol = symbol_short!("rdm_delay");
+
+/// Proof type identifier for settlement proofs
+pub const SETTLEMENT_PROOF: Symbol = symbol_short!("settle");
+
+/// Proof type identifier for basket settlement proofs
+pub const BASKET_PROOF: Symbol = symbol_short!("basket");
+
+/// Proof type identifier for order cancellation proofs
+pub const CANCEL_PROOF: Symbol = symbol_short!("cancel");
+
+/// Balance entries are bumped to ~30 days whenever they drop below ~15 days
+/// (at 5s ledgers)
+const BALANCE_TTL_THRESHOLD: u32 = 259_200;
+const BALANCE_TTL_EXTEND_TO: u32 = 518_400;
+
+// Match proposals live in temporary storage for about a day (5s ledgers)
+#[cfg(any(feature = "delegation", feature = "order-controls"))]
+const PROPOSAL_TTL_LEDGERS: u32 = 17_280;
+
+// A settled match stays marked in flight for about a minute
+const IN_FLIGHT_TTL_LEDGERS: u32 = 12;
+
+// Instance storage (config and contract code) is extended on each settlement
+const INSTANCE_TTL_EXTEND_TO: u32 = 518_400;
+
+/// Storage layout version written by this build
+///
+/// 1: nullifiers, escrow and locked balances, and settlement records held as
+///    vectors and maps in instance storage
+/// 2: each of those as its own persistent entry, balances per sub-account
+pub const STORAGE_VERSION: u32 = 2;
+
+/// Maximum public signals accepted in one proof
+///
+/// Bounds the work done on an untrusted length prefix; basket proofs are
+/// therefore limited to 15 legs.
+pub const MAX_PUBLIC_SIGNALS: u32 = 32;
+
+/// Order commitment scheme opened by settlement proofs
+///
+/// 1: `Poseidon(asset, side, qty, price, nonce, secret)`
+pub const COMMITMENT_SCHEME_V1: u32 = 1;
+
+/// Maximum records returned by one paged getter call
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Settlement IDs stored per index entry, so a page touches at most two
+const SETTLEMENT_ID_CHUNK: u32 = 64;
+
+/// Instance-stored items moved to persistent entries per `migrate` call
+const MIGRATION_BATCH: u32 = 16;
+
+/// Sub-account holding escrow that was not deposited into a named sub-account
+pub const DEFAULT_SUB_ACCOUNT: Symbol = symbol_short!("main");
+
+/// Fixed-point scale for FX rates (7 decimals, matching Stellar asset precision)
+pub const FX_RATE_SCALE: i128 = 10_000_000;
+
+/// Basis point denominator used for tolerances
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+/// Version of the error code map behind `error_description`
+///
+/// Error codes are append-only: a variant keeps its number once released,
+/// so clients matching on codes never break. The version only moves if a
+/// code's meaning changes, letting frontends cache the map.
+pub const ERROR_MAP_VERSION: u32 = 1;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -48,10 +255,269 @@ pub enum SettlementError {
     AlreadySettled = 10,
     InsufficientLockedFunds = 11,
     TransferFailed = 12,
+    FxOracleNotSet = 13,
+    FxRateNotSet = 14,
+    FxRateOutOfTolerance = 15,
+    InvalidFxRate = 16,
+    DeliveryNotFound = 17,
+    DeliveryNotReady = 18,
+    InvalidAmount = 19,
+    BrokerNotAuthorized = 20,
+    WhitelistRootStale = 21,
+    ForwardNotFound = 22,
+    ForwardNotDue = 23,
+    ForwardClosed = 24,
+    BasketVkNotSet = 25,
+    EmptyBasket = 26,
+    NotionalOverflow = 27,
+    NotionalTooLarge = 28,
+    AssetPaused = 29,
+    UnknownProofType = 30,
+    VkUploadNotStarted = 31,
+    VkUploadTooLarge = 32,
+    VkUploadIncomplete = 33,
+    VkHashMismatch = 34,
+    InvalidFee = 35,
+    StorageMigrationRequired = 36,
+    MigrationVersionMismatch = 37,
+    RateLimited = 38,
+    RelayerRequired = 39,
+    CounterpartyBlocked = 40,
+    PaymentAssetNotAllowed = 41,
+    QuantityNotBucketed = 42,
+    NettingNotDue = 43,
+    RecoveryNotAvailable = 44,
+    GuardianQuorumNotMet = 45,
+    TooManySignals = 46,
+    ProposalNotFound = 47,
+    ProposalExpired = 48,
+    MatchNotConfirmed = 49,
+    MatchAlreadyProposed = 50,
+    CancelVkNotSet = 51,
+    OrderRootMismatch = 52,
+    BridgeLockExists = 53,
+    BridgeLockNotFound = 54,
+    HashlockMismatch = 55,
+    BridgeLockExpired = 56,
+    BridgeLockActive = 57,
+    BridgeLockClosed = 58,
+    InvalidReferrer = 59,
+    ReferrerAlreadySet = 60,
+    OracleStale = 61,
+    DistributionNotFound = 62,
+    RecordLedgerNotFinal = 63,
+    DistributionClaimed = 64,
+    NoHoldingsAtRecord = 65,
+    AssetAlreadyMigrated = 66,
+    AssetNotMigrated = 67,
+    MigrationReserveInsufficient = 68,
+    RecordAccessDenied = 69,
+    RecordFormatLocked = 70,
+    InventoryLimitExceeded = 71,
+    IdempotencyKeyReused = 72,
+    InvalidTick = 73,
+    InvalidCommitmentScheme = 74,
+    OnlyTreasury = 75,
+    IntentKeyNotSet = 76,
+    IntentExpired = 77,
+    WatcherNotSubscribed = 78,
+    DeliveryFrozen = 79,
+    TooManyWatchers = 80,
+    RelayerNotRegistered = 81,
+    MalformedVerificationKey = 82,
+    VkSignalCountMismatch = 83,
+    DomainMismatch = 84,
+    TwapNotFound = 85,
+    TwapAlreadyExists = 86,
+    TwapSliceTooLarge = 87,
+    TwapSliceTooEarly = 88,
+    TwapOverfilled = 89,
+    IcebergNotFound = 90,
+    IcebergAlreadyExists = 91,
+    IcebergSliceMismatch = 92,
+    IcebergSlicePending = 93,
+    IcebergExhausted = 94,
+    IcebergOverfilled = 95,
+    ClaimableNotFound = 96,
+    ClaimableExists = 97,
+    ClaimableExpired = 98,
+    ClaimableNotExpired = 99,
+    CollateralNotApproved = 100,
+    InvalidHaircut = 101,
+    InsufficientCollateral = 102,
+    CollateralConverterNotSet = 103,
+    LiquidationDisabled = 104,
+    InvalidLiquidationConfig = 105,
+    PositionHealthy = 106,
+    LiquidationNotFound = 107,
+    LiquidationInProgress = 108,
+    KeeperNotRegistered = 109,
+    PaymentAboveMaximum = 110,
+    CommitmentMismatch = 111,
+    FeeAboveMaximum = 112,
+    VkRevoked = 113,
+    VkNotRevoked = 114,
+    SettlementInFlight = 115,
+    VkNotSet = 116,
+    ConverterLiquidityInsufficient = 117,
+    InvalidOrderSide = 118,
+    PathRouterNotSet = 119,
+    InvalidPath = 120,
+    ReceivedBelowMinimum = 121,
+    LegacyPaymentAssetRequired = 122,
+    CollateralPledged = 123,
+    RecordLedgerTooOld = 124,
+    RelayerRegistrationRequired = 125,
+    FeatureNotBuilt = 126,
+}
+
+impl SettlementError {
+    /// Stable snake_case name of the error, as returned by `error_description`
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::OnlyAdmin => "only_admin",
+            Self::InsufficientBalance => "insufficient_balance",
+            Self::InsufficientEscrow => "insufficient_escrow",
+            Self::NullifierUsed => "nullifier_used",
+            Self::InvalidProof => "invalid_proof",
+            Self::WhitelistRootMismatch => "whitelist_root_mismatch",
+            Self::AssetNotEligible => "asset_not_eligible",
+            Self::ParticipantNotEligible => "participant_not_eligible",
+            Self::MatchNotFound => "match_not_found",
+            Self::AlreadySettled => "already_settled",
+            Self::InsufficientLockedFunds => "insufficient_locked_funds",
+            Self::TransferFailed => "transfer_failed",
+            Self::FxOracleNotSet => "fx_oracle_not_set",
+            Self::FxRateNotSet => "fx_rate_not_set",
+            Self::FxRateOutOfTolerance => "fx_rate_out_of_tolerance",
+            Self::InvalidFxRate => "invalid_fx_rate",
+            Self::DeliveryNotFound => "delivery_not_found",
+            Self::DeliveryNotReady => "delivery_not_ready",
+            Self::InvalidAmount => "invalid_amount",
+            Self::BrokerNotAuthorized => "broker_not_authorized",
+            Self::WhitelistRootStale => "whitelist_root_stale",
+            Self::ForwardNotFound => "forward_not_found",
+            Self::ForwardNotDue => "forward_not_due",
+            Self::ForwardClosed => "forward_closed",
+            Self::BasketVkNotSet => "basket_vk_not_set",
+            Self::EmptyBasket => "empty_basket",
+            Self::NotionalOverflow => "notional_overflow",
+            Self::NotionalTooLarge => "notional_too_large",
+            Self::AssetPaused => "asset_paused",
+            Self::UnknownProofType => "unknown_proof_type",
+            Self::VkUploadNotStarted => "vk_upload_not_started",
+            Self::VkUploadTooLarge => "vk_upload_too_large",
+            Self::VkUploadIncomplete => "vk_upload_incomplete",
+            Self::VkHashMismatch => "vk_hash_mismatch",
+            Self::InvalidFee => "invalid_fee",
+            Self::StorageMigrationRequired => "storage_migration_required",
+            Self::MigrationVersionMismatch => "migration_version_mismatch",
+            Self::RateLimited => "rate_limited",
+            Self::RelayerRequired => "relayer_required",
+            Self::CounterpartyBlocked => "counterparty_blocked",
+            Self::PaymentAssetNotAllowed => "payment_asset_not_allowed",
+            Self::QuantityNotBucketed => "quantity_not_bucketed",
+            Self::NettingNotDue => "netting_not_due",
+            Self::RecoveryNotAvailable => "recovery_not_available",
+            Self::GuardianQuorumNotMet => "guardian_quorum_not_met",
+            Self::TooManySignals => "too_many_signals",
+            Self::ProposalNotFound => "proposal_not_found",
+            Self::ProposalExpired => "proposal_expired",
+            Self::MatchNotConfirmed => "match_not_confirmed",
+            Self::MatchAlreadyProposed => "match_already_proposed",
+            Self::CancelVkNotSet => "cancel_vk_not_set",
+            Self::OrderRootMismatch => "order_root_mismatch",
+            Self::BridgeLockExists => "bridge_lock_exists",
+            Self::BridgeLockNotFound => "bridge_lock_not_found",
+            Self::HashlockMismatch => "hashlock_mismatch",
+            Self::BridgeLockExpired => "bridge_lock_expired",
+            Self::BridgeLockActive => "bridge_lock_active",
+            Self::BridgeLockClosed => "bridge_lock_closed",
+            Self::InvalidReferrer => "invalid_referrer",
+            Self::ReferrerAlreadySet => "referrer_already_set",
+            Self::OracleStale => "oracle_stale",
+            Self::DistributionNotFound => "distribution_not_found",
+            Self::RecordLedgerNotFinal => "record_ledger_not_final",
+            Self::DistributionClaimed => "distribution_claimed",
+            Self::NoHoldingsAtRecord => "no_holdings_at_record",
+            Self::AssetAlreadyMigrated => "asset_already_migrated",
+            Self::AssetNotMigrated => "asset_not_migrated",
+            Self::MigrationReserveInsufficient => "migration_reserve_insufficient",
+            Self::RecordAccessDenied => "record_access_denied",
+            Self::RecordFormatLocked => "record_format_locked",
+            Self::InventoryLimitExceeded => "inventory_limit_exceeded",
+            Self::IdempotencyKeyReused => "idempotency_key_reused",
+            Self::InvalidTick => "invalid_tick",
+            Self::InvalidCommitmentScheme => "invalid_commitment_scheme",
+            Self::OnlyTreasury => "only_treasury",
+            Self::IntentKeyNotSet => "intent_key_not_set",
+            Self::IntentExpired => "intent_expired",
+            Self::WatcherNotSubscribed => "watcher_not_subscribed",
+            Self::DeliveryFrozen => "delivery_frozen",
+            Self::TooManyWatchers => "too_many_watchers",
+            Self::RelayerNotRegistered => "relayer_not_registered",
+            Self::MalformedVerificationKey => "malformed_verification_key",
+            Self::VkSignalCountMismatch => "vk_signal_count_mismatch",
+            Self::DomainMismatch => "domain_mismatch",
+            Self::TwapNotFound => "twap_not_found",
+            Self::TwapAlreadyExists => "twap_already_exists",
+            Self::TwapSliceTooLarge => "twap_slice_too_large",
+            Self::TwapSliceTooEarly => "twap_slice_too_early",
+            Self::TwapOverfilled => "twap_overfilled",
+            Self::IcebergNotFound => "iceberg_not_found",
+            Self::IcebergAlreadyExists => "iceberg_already_exists",
+            Self::IcebergSliceMismatch => "iceberg_slice_mismatch",
+            Self::IcebergSlicePending => "iceberg_slice_pending",
+            Self::IcebergExhausted => "iceberg_exhausted",
+            Self::IcebergOverfilled => "iceberg_overfilled",
+            Self::ClaimableNotFound => "claimable_not_found",
+            Self::ClaimableExists => "claimable_exists",
+            Self::ClaimableExpired => "claimable_expired",
+            Self::ClaimableNotExpired => "claimable_not_expired",
+            Self::CollateralNotApproved => "collateral_not_approved",
+            Self::InvalidHaircut => "invalid_haircut",
+            Self::InsufficientCollateral => "insufficient_collateral",
+            Self::CollateralConverterNotSet => "collateral_converter_not_set",
+            Self::LiquidationDisabled => "liquidation_disabled",
+            Self::InvalidLiquidationConfig => "invalid_liquidation_config",
+            Self::PositionHealthy => "position_healthy",
+            Self::LiquidationNotFound => "liquidation_not_found",
+            Self::LiquidationInProgress => "liquidation_in_progress",
+            Self::KeeperNotRegistered => "keeper_not_registered",
+            Self::PaymentAboveMaximum => "payment_above_maximum",
+            Self::CommitmentMismatch => "commitment_mismatch",
+            Self::FeeAboveMaximum => "fee_above_maximum",
+            Self::VkRevoked => "vk_revoked",
+            Self::VkNotRevoked => "vk_not_revoked",
+            Self::SettlementInFlight => "settlement_in_flight",
+            Self::VkNotSet => "vk_not_set",
+            Self::ConverterLiquidityInsufficient => "converter_liquidity_insufficient",
+            Self::InvalidOrderSide => "invalid_order_side",
+            Self::PathRouterNotSet => "path_router_not_set",
+            Self::InvalidPath => "invalid_path",
+            Self::ReceivedBelowMinimum => "received_below_minimum",
+            Self::LegacyPaymentAssetRequired => "legacy_payment_asset_required",
+            Self::CollateralPledged => "collateral_pledged",
+            Self::RecordLedgerTooOld => "record_ledger_too_old",
+            Self::RelayerRegistrationRequired => "relayer_registration_required",
+            Self::FeatureNotBuilt => "feature_not_built",
+        }
+    }
+}
+
+/// Price oracle used to sanity-check configured FX conversion rates.
+///
+/// Rates are expressed as units of `quote` per unit of `base`, scaled by
+/// `FX_RATE_SCALE`. `last_updated` is the ledger timestamp of the asset's
+/// latest price update.
+#[contractclient(name = "FxOracleClient")]
+pub trait FxOracle {
+    fn get_rate(env: Env, base: Address, quote: Address) -> i128;
+    fn last_updated(env: Env, asset: Address) -> u64;
 }
 
 /// Settlement record for completed trades
-#[derive(Clone)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 #[contracttype]
 pub struct SettlementRecord {
     pub match_id: BytesN<32>,
@@ -60,18 +526,260 @@ pub struct SettlementRecord {
     pub asset_address: Address,
     pub quantity: i128,
     pub price: i128,
+    pub payment_asset: Address,
+    pub payment_amount: i128,
     pub timestamp: u64,
     pub nullifier: BytesN<32>,
+    /// Commitment scheme of the orders the settlement proof opened
+    pub scheme_version: u32,
+}
+
+/// Settlement record as stored by storage version 1
+///
+/// Payments were not recorded; the buyer paid `price` in a payment asset
+/// the record does not name.
+#[derive(Clone)]
+#[contracttype]
+struct SettlementRecordV1 {
+    match_id: BytesN<32>,
+    buyer: Address,
+    seller: Address,
+    asset_address: Address,
+    quantity: i128,
+    price: i128,
+    timestamp: u64,
+    nullifier: BytesN<32>,
+}
+
+impl SettlementRecordV1 {
+    fn upgrade(self, payment_asset: &Address) -> SettlementRecord {
+        SettlementRecord {
+            match_id: self.match_id,
+            buyer: self.buyer,
+            seller: self.seller,
+            asset_address: self.asset_address,
+            quantity: self.quantity,
+            price: self.price,
+            payment_asset: payment_asset.clone(),
+            payment_amount: self.price,
+            timestamp: self.timestamp,
+            nullifier: self.nullifier,
+            scheme_version: COMMITMENT_SCHEME_V1,
+        }
+    }
+}
+
+/// Escrow account key as stored by storage version 1, before sub-accounts
+#[derive(Clone)]
+#[contracttype]
+struct EscrowKeyV1 {
+    participant: Address,
+    asset: Address,
+}
+
+/// Settlement record as stored before commitment schemes were versioned
+#[derive(Clone)]
+#[contracttype]
+struct SettlementRecordV2 {
+    match_id: BytesN<32>,
+    buyer: Address,
+    seller: Address,
+    asset_address: Address,
+    quantity: i128,
+    price: i128,
+    payment_asset: Address,
+    payment_amount: i128,
+    timestamp: u64,
+    nullifier: BytesN<32>,
+}
+
+impl From<SettlementRecordV2> for SettlementRecord {
+    fn from(record: SettlementRecordV2) -> Self {
+        SettlementRecord {
+            match_id: record.match_id,
+            buyer: record.buyer,
+            seller: record.seller,
+            asset_address: record.asset_address,
+            quantity: record.quantity,
+            price: record.price,
+            payment_asset: record.payment_asset,
+            payment_amount: record.payment_amount,
+            timestamp: record.timestamp,
+            nullifier: record.nullifier,
+            scheme_version: COMMITMENT_SCHEME_V1,
+        }
+    }
+}
+
+/// Fees owed by each side of one settlement
+///
+/// A party that opted into the protocol token owes its fee there instead of
+/// in the payment asset. A side with a fee payer has its fee taken from the
+/// payer's main account rather than its own.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SettlementFees {
+    pub buyer_fee: i128,
+    pub seller_fee: i128,
+    pub buyer_token_fee: i128,
+    pub seller_token_fee: i128,
+    pub buyer_payer: Option<Address>,
+    pub seller_payer: Option<Address>,
+    /// Relayer that submitted the settlement and earns the relayer share
+    pub relayer: Option<Address>,
+}
+
+impl SettlementFees {
+    /// Whether no fee is owed in any asset
+    #[cfg(feature = "fees")]
+    fn is_zero(&self) -> bool {
+        self.buyer_fee == 0 && self.seller_fee == 0 && self.buyer_token_fee == 0 && self.seller_token_fee == 0
+    }
+
+    /// Part of the seller's fee withheld from the payment it receives
+    fn seller_fee_from_payment(&self) -> i128 {
+        if self.seller_payer.is_some() {
+            0
+        } else {
+            self.seller_fee
+        }
+    }
+
+    /// Part of the buyer's fee taken from the buyer's own escrow
+    #[cfg(feature = "fees")]
+    fn buyer_fee_from_buyer(&self) -> i128 {
+        if self.buyer_payer.is_some() {
+            0
+        } else {
+            self.buyer_fee
+        }
+    }
+}
+
+/// How a settlement's legs move, see `leg_delivery`
+enum LegDelivery {
+    /// Both legs swap at once
+    Spot,
+    /// Both legs wait for the next netting round
+    #[cfg(feature = "netting")]
+    Residual,
+    /// The payment is held now and the asset delivered after the given time
+    #[cfg(feature = "forwards")]
+    Forward(u64),
 }
 
-/// Escrow balance for a participant and asset
+/// Cumulative proof verification counters
+///
+/// Only verifications in committed transactions are counted, since a failed
+/// settlement rolls back its counter updates.
 #[derive(Clone)]
 #[contracttype]
+pub struct VerificationStats {
+    pub total: u64,
+    /// Verifications routed to the verifier contract
+    pub contract_calls: u64,
+    /// Verifications run natively in the settlement contract
+    pub native_calls: u64,
+    /// Verifications per proof type
+    pub by_proof_type: Map<Symbol, u64>,
+}
+
+/// Escrow balance for a participant's sub-account and asset
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
 pub struct EscrowKey {
     pub participant: Address,
+    pub sub_account: Symbol,
     pub asset: Address,
 }
 
+impl EscrowKey {
+    pub fn new(participant: &Address, sub_account: &Symbol, asset: &Address) -> Self {
+        Self {
+            participant: participant.clone(),
+            sub_account: sub_account.clone(),
+            asset: asset.clone(),
+        }
+    }
+
+    /// Key for the participant's default sub-account
+    pub fn main(participant: &Address, asset: &Address) -> Self {
+        Self::new(participant, &DEFAULT_SUB_ACCOUNT, asset)
+    }
+}
+
+/// How settlement authorization is enforced for the trading parties
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum SettlementAuthMode {
+    /// The ZK proof alone authorizes settlement
+    ProofOnly = 0,
+    /// Each party must also authorize, or have its settle-scoped broker
+    /// approve the match with `broker_approve_settlement`
+    BothParties = 1,
+}
+
+/// Terms of a `settle_trade_v2` call
+///
+/// Optional fields default to `settle_trade`'s behaviour; new options are
+/// added here rather than as new entry points.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SettleTradeRequest {
+    pub match_id: BytesN<32>,
+    pub buyer: Address,
+    pub seller: Address,
+    pub asset_address: Address,
+    pub payment_asset: Address,
+    pub quantity: i128,
+    pub price: i128,
+    /// Buy order commitment the proof must carry
+    pub buy_commitment: Option<BytesN<32>>,
+    /// Sell order commitment the proof must carry
+    pub sell_commitment: Option<BytesN<32>>,
+    /// Highest fee, in basis points, either party accepts
+    pub max_fee_bps: Option<u32>,
+    /// Memo hash routing the buyer's side to a memo sub-account; the buyer
+    /// must then authorize as under `BothParties`
+    pub buyer_memo: Option<BytesN<32>>,
+    /// Memo hash routing the seller's side to a memo sub-account; the seller
+    /// must then authorize as under `BothParties`
+    pub seller_memo: Option<BytesN<32>>,
+    /// `BothParties` requires party authorization even when the contract
+    /// accepts the proof alone; it cannot relax the contract's mode
+    pub auth_mode: SettlementAuthMode,
+}
+
+/// Settlement attempt recorded against a match in temporary storage
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct InFlightSettlement {
+    /// Relayer that submitted the attempt, if it identified itself
+    pub relayer: Option<Address>,
+    /// Ledger the attempt landed in
+    pub ledger: u32,
+}
+
+/// Event emitted when a deprecated entry point is called
+#[contractevent]
+#[derive(Clone)]
+pub struct EntryPointDeprecated {
+    #[topic]
+    pub entry_point: Symbol,
+    pub replacement: Symbol,
+}
+
+/// Where a proof type is verified
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum VerificationRoute {
+    /// Cross-contract call to the Groth16 verifier contract
+    Contract = 0,
+    /// In-contract verification with the host BN254 primitives
+    Native = 1,
+}
+
 #[contract]
 pub struct DarkPoolSettlement;
 
@@ -96,13 +804,82 @@ impl DarkPoolSettlement {
         env.storage().instance().set(&VERIFIER_KEY, &verifier_address);
         env.storage().instance().set(&SETTLEMENT_VK_KEY, &settlement_vk_bytes);
 
-        // Initialize empty nullifiers list
-        let nullifiers: Vec<BytesN<32>> = vec![&env];
-        env.storage().instance().set(&NULLIFIERS_KEY, &nullifiers);
+        // Fresh deployments start on the current storage layout
+        env.storage().instance().set(&STORAGE_VERSION_KEY, &STORAGE_VERSION);
+    }
+
+    /// Replace the contract code
+    ///
+    /// If the new code changes the storage layout, settlement stays blocked
+    /// until `migrate` has brought storage up to the new version.
+    pub fn upgrade(env: Env, admin: Address, new_wasm_hash: BytesN<32>) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
+
+    /// Migrate storage from an older layout to `STORAGE_VERSION`
+    ///
+    /// Runs each step from `from_version` up to the current version in order.
+    /// `from_version` must match the stored version, so a migration cannot be
+    /// applied twice or out of sequence.
+    ///
+    /// Each call moves at most `MIGRATION_BATCH` items, so large pools stay
+    /// within per-transaction limits. Call again with the same version until
+    /// the returned version moves on.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `from_version` - Storage version the contract is currently on
+    /// * `legacy_payment_asset` - Payment asset of version 1 settlements,
+    ///   whose records did not store one; required while any remain
+    ///
+    /// # Returns
+    /// * The storage version after this call
+    pub fn migrate(
+        env: Env,
+        admin: Address,
+        from_version: u32,
+        legacy_payment_asset: Option<Address>,
+    ) -> Result<u32, SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut version = Self::get_storage_version(env.clone());
+        if version != from_version || version > STORAGE_VERSION {
+            return Err(SettlementError::MigrationVersionMismatch);
+        }
+
+        while version < STORAGE_VERSION {
+            let finished = match version {
+                1 => Self::migrate_v1_to_v2(&env, legacy_payment_asset.as_ref())?,
+                _ => return Err(SettlementError::MigrationVersionMismatch),
+            };
+            if !finished {
+                break;
+            }
+            version += 1;
+            env.storage().instance().set(&STORAGE_VERSION_KEY, &version);
+        }
+
+        Ok(version)
+    }
+
+    /// Get the storage layout version
+    ///
+    /// Deployments that predate versioning report version 1.
+    pub fn get_storage_version(env: Env) -> u32 {
+        env.storage().instance().get(&STORAGE_VERSION_KEY).unwrap_or(1)
+    }
 
-        // Initialize empty settlements list
-        let settlements: Vec<SettlementRecord> = vec![&env];
-        env.storage().instance().set(&SETTLEMENTS_KEY, &settlements);
+    /// Extend the contract instance's TTL
+    ///
+    /// Permissionless, so anyone monitoring the pool can keep it from being
+    /// archived. Settlements extend it as well.
+    pub fn extend_instance_ttl(env: Env) {
+        Self::bump_instance(&env);
     }
 
     /// Deposit tokens into escrow
@@ -118,13 +895,17 @@ impl DarkPoolSettlement {
         amount: i128,
     ) -> Result<i128, SettlementError> {
         depositor.require_auth();
+        Self::require_asset_active(&env, &asset_address)?;
 
         // Transfer tokens from depositor to contract
-        let token_client = token::Client::new(&env, &asset_address);
-        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+        Self::asset_adapter(&env, &asset_address).transfer(&env, &depositor, &env.current_contract_address(), amount);
 
         // Update escrow balance
         let new_balance = Self::add_escrow_balance(&env, &depositor, &asset_address, amount);
+        #[cfg(feature = "corporate-actions")]
+        Self::open_lot(&env, &depositor, &asset_address, amount, LotSource::Deposit);
+        #[cfg(feature = "accounts")]
+        Self::issue_badge(&env, &depositor, &asset_address);
 
         Ok(new_balance)
     }
@@ -154,10 +935,11 @@ impl DarkPoolSettlement {
 
         // Subtract from escrow
         let new_balance = Self::subtract_escrow_balance(&env, &withdrawer, &asset_address, amount)?;
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(&env, &withdrawer, &asset_address, amount);
 
-        // Transfer tokens from contract to withdrawer
-        let token_client = token::Client::new(&env, &asset_address);
-        token_client.transfer(&env.current_contract_address(), &withdrawer, &amount);
+        // Transfer tokens from contract to withdrawer, or queue the redemption
+        Self::pay_out(&env, &withdrawer, &asset_address, amount)?;
 
         Ok(new_balance)
     }
@@ -175,6 +957,7 @@ impl DarkPoolSettlement {
         amount: i128,
     ) -> Result<(), SettlementError> {
         trader.require_auth();
+        Self::require_asset_active(&env, &asset_address)?;
 
         let escrow_balance = Self::get_escrow_balance(env.clone(), trader.clone(), asset_address.clone());
         let locked_balance = Self::get_locked_balance(env.clone(), trader.clone(), asset_address.clone());
@@ -206,11 +989,144 @@ impl DarkPoolSettlement {
         if locked_balance < amount {
             return Err(SettlementError::InsufficientLockedFunds);
         }
+        Self::require_unpledged(&env, &EscrowKey::main(&trader, &asset_address), amount)?;
 
         Self::subtract_locked_balance(&env, &trader, &asset_address, amount)?;
         Ok(())
     }
 
+    /// Unlock escrow and withdraw it in a single step
+    ///
+    /// Avoids the window between separate unlock and withdraw calls in which
+    /// the freed funds could be locked again for a match.
+    ///
+    /// # Arguments
+    /// * `trader` - Address of the trader (must authenticate)
+    /// * `asset_address` - Token contract address
+    /// * `amount` - Amount to unlock and withdraw
+    pub fn unlock_and_withdraw(
+        env: Env,
+        trader: Address,
+        asset_address: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        trader.require_auth();
+
+        let key = EscrowKey::main(&trader, &asset_address);
+        Self::require_unpledged(&env, &key, amount)?;
+        Self::debit_locked(&env, &key, amount)?;
+        if Self::available_balance(&env, &key) < amount {
+            return Err(SettlementError::InsufficientBalance);
+        }
+        let new_balance = Self::debit_escrow(&env, &key, amount)?;
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(&env, &trader, &asset_address, amount);
+
+        Self::pay_out(&env, &trader, &asset_address, amount)?;
+
+        Ok(new_balance)
+    }
+
+    /// Set how trading parties must authorize settlements
+    pub fn set_auth_mode(
+        env: Env,
+        admin: Address,
+        mode: SettlementAuthMode,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&AUTH_MODE_KEY, &mode);
+        Ok(())
+    }
+
+    /// Require settlement proofs to be bound to this pool instance
+    ///
+    /// Once enabled, settlement proofs come from the domain-bound circuit:
+    /// an eighth public signal must equal `get_domain_separator`, which the
+    /// circuit folds into the nullifier. Basket proofs likewise end with the
+    /// separator. Install the bound circuits' keys with
+    /// `upgrade_commitment_scheme` and `set_basket_vk` before enabling, since
+    /// unbound proofs are rejected from then on.
+    pub fn set_domain_binding(env: Env, admin: Address, enabled: bool) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&DOMAIN_BINDING_KEY, &enabled);
+        Ok(())
+    }
+
+    /// Check whether settlement proofs must carry the domain separator
+    pub fn is_domain_bound(env: Env) -> bool {
+        env.storage().instance().get(&DOMAIN_BINDING_KEY).unwrap_or(false)
+    }
+
+    /// Field element identifying this pool instance on this network
+    ///
+    /// SHA-256 over the XDR of the domain tag, network id and this
+    /// contract's address, with the top byte cleared so it is a canonical
+    /// BN254 scalar.
+    pub fn get_domain_separator(env: Env) -> BytesN<32> {
+        let payload = (POOL_DOMAIN, env.ledger().network_id(), env.current_contract_address());
+        let mut hash = env.crypto().sha256(&payload.to_xdr(&env)).to_array();
+        hash[0] = 0;
+        BytesN::from_array(&env, &hash)
+    }
+
+    /// Get the settlement authorization mode
+    pub fn get_auth_mode(env: Env) -> SettlementAuthMode {
+        env.storage()
+            .instance()
+            .get(&AUTH_MODE_KEY)
+            .unwrap_or(SettlementAuthMode::ProofOnly)
+    }
+
+    /// Enable or disable checking the proof's whitelist root against the registry
+    pub fn set_whitelist_check(env: Env, admin: Address, enabled: bool) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&WL_CHECK_KEY, &enabled);
+        Ok(())
+    }
+
+    /// Set the maximum age of a superseded whitelist root
+    ///
+    /// Proofs against the registry's current root are always accepted. Proofs
+    /// against an earlier root are accepted only while that root is at most
+    /// `max_root_age` seconds old, bounding how long a removed participant can
+    /// keep trading. Zero accepts the current root only.
+    pub fn set_max_root_age(env: Env, admin: Address, max_root_age: u64) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&MAX_ROOT_AGE_KEY, &max_root_age);
+        Ok(())
+    }
+
+    /// Check whether settlement verifies the whitelist root
+    pub fn is_whitelist_check_enabled(env: Env) -> bool {
+        env.storage().instance().get(&WL_CHECK_KEY).unwrap_or(false)
+    }
+
+    /// Get cumulative proof verification counts by route and proof type
+    pub fn get_verification_stats(env: Env) -> VerificationStats {
+        env.storage()
+            .instance()
+            .get(&VERIFY_STATS_KEY)
+            .unwrap_or(VerificationStats {
+                total: 0,
+                contract_calls: 0,
+                native_calls: 0,
+                by_proof_type: Map::new(&env),
+            })
+    }
+
+    /// Get the maximum age (in seconds) of a superseded whitelist root
+    pub fn get_max_root_age(env: Env) -> u64 {
+        env.storage().instance().get(&MAX_ROOT_AGE_KEY).unwrap_or(0)
+    }
+
     /**
      * Settle a matched trade with ZK proof verification
      *
@@ -236,9 +1152,15 @@ impl DarkPoolSettlement {
      * * `asset_address` - The RWA token being traded
      * * `payment_asset` - The payment token (e.g., USDC)
      * * `quantity` - Amount of RWA tokens
-     * * `price` - Total price in payment tokens
+     * * `price` - Total price in the asset's quote currency (payment tokens
+     *   when no quote currency is configured). If `payment_asset` differs from
+     *   the quote currency, the payment leg is converted at the configured pair
+     *   rate, which must be within the FX tolerance of the oracle rate.
      * * `proof_bytes` - Serialized ZK proof
      * * `pub_signals_bytes` - Serialized public signals
+     *
+     * Deprecated: delegates to `settle_trade_v2` with every option at its
+     * default and emits `EntryPointDeprecated`.
      */
     pub fn settle_trade(
         env: Env,
@@ -252,160 +1174,370 @@ impl DarkPoolSettlement {
         proof_bytes: Bytes,
         pub_signals_bytes: Bytes,
     ) -> Result<SettlementRecord, SettlementError> {
-        // NOTE: require_auth removed for both parties because:
-        // 1. ZK proof cryptographically proves both parties agreed to the trade
-        // 2. Funds are already in escrow (deposited with proper auth)
-        // 3. Nullifier prevents replay attacks
-        // 4. Multi-party auth is complex to implement in frontend
-        //
-        // For production, consider re-enabling with proper multi-party signing flow
-        // buyer.require_auth();
-        // seller.require_auth();
-
-        // Parse public signals - format from settlement_proof.circom
-        // snarkjs outputs signals in order: [output, ...public_inputs]
-        // [0] nullifierHash (output)
-        // [1] buyCommitment
-        // [2] sellCommitment
-        // [3] assetHash
-        // [4] matchedQuantity
-        // [5] executionPrice
-        // [6] whitelistRoot
-        let pub_signals = Self::parse_public_signals(&env, &pub_signals_bytes)?;
-
-        if pub_signals.len() != 7 {
-            return Err(SettlementError::InvalidProof);
+        let request = SettleTradeRequest {
+            match_id,
+            buyer,
+            seller,
+            asset_address,
+            payment_asset,
+            quantity,
+            price,
+            buy_commitment: None,
+            sell_commitment: None,
+            max_fee_bps: None,
+            buyer_memo: None,
+            seller_memo: None,
+            auth_mode: SettlementAuthMode::ProofOnly,
+        };
+        let record = Self::settle_trade_v2(env.clone(), request, proof_bytes, pub_signals_bytes)?;
+        EntryPointDeprecated {
+            entry_point: Symbol::new(&env, "settle_trade"),
+            replacement: Symbol::new(&env, "settle_trade_v2"),
         }
+        .publish(&env);
+        Ok(record)
+    }
 
-        // TODO: Re-enable whitelist check for production
-        // For testnet testing, whitelist check is temporarily disabled
-        // because on-chain registry uses different Poseidon computation
-        //
-        // let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
-        // let registry_client = registry_wasm::Client::new(&env, &registry_address);
-        // let whitelist_root = registry_client.get_whitelist_root();
-        // let proof_whitelist_root = pub_signals.get(6).unwrap();
-        // if proof_whitelist_root != whitelist_root {
-        //     return Err(SettlementError::WhitelistRootMismatch);
-        // }
+    /// Settle a matched trade with optional commitment binding, fee cap, memo routing and auth mode
+    ///
+    /// The stable settlement entry point. With every option at its default
+    /// it behaves exactly like `settle_trade`.
+    ///
+    /// # Arguments
+    /// * `request` - Match terms and options
+    /// * `proof_bytes` - Serialized ZK proof
+    /// * `pub_signals_bytes` - Serialized public signals
+    pub fn settle_trade_v2(
+        env: Env,
+        request: SettleTradeRequest,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<SettlementRecord, SettlementError> {
+        Self::require_unmetered(&env)?;
+
+        // The proof must be for the orders the caller expects
+        if request.buy_commitment.is_some() || request.sell_commitment.is_some() {
+            let pub_signals = Self::parse_public_signals(&env, &pub_signals_bytes)?;
+            for (expected, index) in [(&request.buy_commitment, 1), (&request.sell_commitment, 2)] {
+                if expected.is_some() && pub_signals.get(index) != *expected {
+                    return Err(SettlementError::CommitmentMismatch);
+                }
+            }
+        }
 
-        // Check nullifier not used (signal index 0 - it's the output)
-        let nullifier = pub_signals.get(0).unwrap();
-        if Self::is_nullifier_used(env.clone(), nullifier.clone()) {
-            return Err(SettlementError::NullifierUsed);
+        #[cfg(feature = "fees")]
+        if let (Some(max_fee_bps), Some(schedule)) = (request.max_fee_bps, Self::get_fee_schedule(env.clone())) {
+            let buyer_bps = schedule
+                .buyer_fee_bps
+                .saturating_sub(Self::get_fee_tier(env.clone(), request.buyer.clone()));
+            let seller_bps = schedule
+                .seller_fee_bps
+                .saturating_sub(Self::get_fee_tier(env.clone(), request.seller.clone()));
+            if buyer_bps.max(seller_bps) > max_fee_bps {
+                return Err(SettlementError::FeeAboveMaximum);
+            }
         }
 
-        // Verify ZK proof
-        let verifier_address: Address = env.storage().instance().get(&VERIFIER_KEY).unwrap();
-        let vk_bytes: Bytes = env.storage().instance().get(&SETTLEMENT_VK_KEY).unwrap();
+        // A stricter mode requested here applies on top of the contract's
+        let auth_mode = match request.auth_mode {
+            SettlementAuthMode::BothParties => SettlementAuthMode::BothParties,
+            SettlementAuthMode::ProofOnly => Self::get_auth_mode(env.clone()),
+        };
 
-        let verifier_client = verifier_wasm::Client::new(&env, &verifier_address);
+        let sub_account = |memo: &Option<BytesN<32>>| match memo {
+            Some(memo) => Self::get_memo_sub_account(env.clone(), memo.clone()),
+            None => DEFAULT_SUB_ACCOUNT,
+        };
+        Self::execute_settlement(
+            &env,
+            None,
+            auth_mode,
+            &request.match_id,
+            &request.buyer,
+            &sub_account(&request.buyer_memo),
+            &request.seller,
+            &sub_account(&request.seller_memo),
+            &request.asset_address,
+            &request.payment_asset,
+            request.quantity,
+            request.price,
+            &proof_bytes,
+            &pub_signals_bytes,
+        )
+    }
 
-        let is_valid = verifier_client.verify_proof_bytes(&vk_bytes, &proof_bytes, &pub_signals_bytes);
-        if !is_valid {
-            return Err(SettlementError::InvalidProof);
+    /// Sub-account a memo hash routes to
+    ///
+    /// `m` followed by the hex of the memo's first 9 bytes. Anything longer
+    /// would push the balance entry keys past the ledger's key size limit.
+    pub fn get_memo_sub_account(env: Env, memo: BytesN<32>) -> Symbol {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let bytes = memo.to_array();
+        let mut name = [b'm'; 19];
+        for (i, byte) in bytes[..9].iter().enumerate() {
+            name[1 + 2 * i] = HEX[(byte >> 4) as usize];
+            name[2 + 2 * i] = HEX[(byte & 0x0f) as usize];
         }
+        Symbol::new(&env, core::str::from_utf8(&name).unwrap())
+    }
 
-        // Execute atomic swap - seller sends asset to buyer
-        Self::transfer_from_escrow(&env, &seller, &buyer, &asset_address, quantity)?;
+    /// Get the in-flight marker of a recently settled match
+    ///
+    /// Relayers can check it before submitting to avoid racing a settlement
+    /// that already landed.
+    pub fn get_in_flight(env: Env, match_id: BytesN<32>) -> Option<InFlightSettlement> {
+        env.storage().temporary().get(&(IN_FLIGHT_KEY, match_id))
+    }
 
-        // Buyer sends payment to seller
-        Self::transfer_from_escrow(&env, &buyer, &seller, &payment_asset, price)?;
+    /// Suspend deposits, locks and settlements involving one asset
+    ///
+    /// Withdrawals and unlocks stay available so participants can exit, e.g.
+    /// while the asset's issuer processes a corporate action.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `asset_address` - Token contract address to pause
+    pub fn pause_asset(env: Env, admin: Address, asset_address: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
 
-        // Mark nullifier as used
-        Self::mark_nullifier_used(&env, &nullifier);
+        Self::set_asset_paused(&env, asset_address, true);
+        Ok(())
+    }
 
-        // Create settlement record
-        let record = SettlementRecord {
-            match_id: match_id.clone(),
-            buyer: buyer.clone(),
-            seller: seller.clone(),
-            asset_address: asset_address.clone(),
-            quantity,
-            price,
-            timestamp: env.ledger().timestamp(),
-            nullifier: nullifier.clone(),
-        };
+    /// Resume activity for a paused asset
+    pub fn unpause_asset(env: Env, admin: Address, asset_address: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
 
-        // Store settlement record
-        let mut settlements: Vec<SettlementRecord> = env
+        Self::set_asset_paused(&env, asset_address, false);
+        Ok(())
+    }
+
+    /// Check whether an asset is paused
+    pub fn is_asset_paused(env: Env, asset_address: Address) -> bool {
+        let paused: Map<Address, bool> = env
             .storage()
             .instance()
-            .get(&SETTLEMENTS_KEY)
-            .unwrap_or(vec![&env]);
-        settlements.push_back(record.clone());
-        env.storage().instance().set(&SETTLEMENTS_KEY, &settlements);
-
-        Ok(record)
+            .get(&PAUSED_KEY)
+            .unwrap_or(Map::new(&env));
+        paused.get(asset_address).unwrap_or(false)
     }
 
-    /// Check if a nullifier has been used
-    pub fn is_nullifier_used(env: Env, nullifier: BytesN<32>) -> bool {
-        let nullifiers: Vec<BytesN<32>> = env
+    /// Allow a token to be used as the payment leg of settlements
+    ///
+    /// Kept separate from the registry's RWA whitelist: payment assets are
+    /// settlement currencies (e.g., USDC), and settlements naming any other
+    /// payment asset are rejected so a relayer cannot substitute its own token.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `asset_address` - Token contract address to allow
+    pub fn add_payment_asset(env: Env, admin: Address, asset_address: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut payment_assets: Map<Address, bool> = env
             .storage()
             .instance()
-            .get(&NULLIFIERS_KEY)
-            .unwrap_or(vec![&env]);
-        nullifiers.contains(&nullifier)
+            .get(&PAY_ASSETS_KEY)
+            .unwrap_or(Map::new(&env));
+        payment_assets.set(asset_address, true);
+        env.storage().instance().set(&PAY_ASSETS_KEY, &payment_assets);
+        Ok(())
     }
 
-    /// Get escrow balance for a participant and asset
-    pub fn get_escrow_balance(env: Env, participant: Address, asset: Address) -> i128 {
-        let key = EscrowKey {
-            participant: participant.clone(),
-            asset: asset.clone(),
-        };
-        let escrow: Map<EscrowKey, i128> = env
+    /// Stop accepting a token as the payment leg of settlements
+    pub fn remove_payment_asset(env: Env, admin: Address, asset_address: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut payment_assets: Map<Address, bool> = env
             .storage()
             .instance()
-            .get(&ESCROW_KEY)
+            .get(&PAY_ASSETS_KEY)
             .unwrap_or(Map::new(&env));
-        escrow.get(key).unwrap_or(0)
+        payment_assets.remove(asset_address);
+        env.storage().instance().set(&PAY_ASSETS_KEY, &payment_assets);
+        Ok(())
     }
 
-    /// Get locked balance for a participant and asset
-    pub fn get_locked_balance(env: Env, participant: Address, asset: Address) -> i128 {
-        let key = EscrowKey {
-            participant: participant.clone(),
-            asset: asset.clone(),
-        };
-        let locked: Map<EscrowKey, i128> = env
+    /// Check whether a token is an allowed payment asset
+    pub fn is_payment_asset(env: Env, asset_address: Address) -> bool {
+        let payment_assets: Map<Address, bool> = env
             .storage()
             .instance()
-            .get(&LOCKED_KEY)
+            .get(&PAY_ASSETS_KEY)
             .unwrap_or(Map::new(&env));
-        locked.get(key).unwrap_or(0)
+        payment_assets.get(asset_address).unwrap_or(false)
     }
 
-    /// Get available (unlocked) balance
-    pub fn get_available_balance(env: Env, participant: Address, asset: Address) -> i128 {
-        let escrow = Self::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
-        let locked = Self::get_locked_balance(env, participant, asset);
-        escrow - locked
+    /// Set the oracle used to check FX conversion rates
+    pub fn set_fx_oracle(env: Env, admin: Address, oracle: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&FX_ORACLE_KEY, &oracle);
+        Ok(())
     }
 
-    /// Get all settlement records
-    pub fn get_settlements(env: Env) -> Vec<SettlementRecord> {
-        env.storage()
+    /// Set how old an asset's oracle price may be before settlement stops
+    ///
+    /// While the price is stale, settlements touching the asset fail with
+    /// `OracleStale`; deposits and withdrawals are unaffected.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `asset_address` - Asset whose oracle price is checked
+    /// * `max_age` - Maximum age in seconds, or `None` to stop checking
+    pub fn set_oracle_max_age(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        max_age: Option<u64>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut ages: Map<Address, u64> = env
+            .storage()
             .instance()
-            .get(&SETTLEMENTS_KEY)
-            .unwrap_or(vec![&env])
+            .get(&ORACLE_AGE_KEY)
+            .unwrap_or(Map::new(&env));
+        match max_age {
+            Some(max_age) => ages.set(asset_address, max_age),
+            None => {
+                ages.remove(asset_address);
+            }
+        }
+        env.storage().instance().set(&ORACLE_AGE_KEY, &ages);
+        Ok(())
     }
 
-    /// Get settlement by match ID
-    pub fn get_settlement(env: Env, match_id: BytesN<32>) -> Option<SettlementRecord> {
-        let settlements: Vec<SettlementRecord> = env
+    /// Get the maximum oracle price age for an asset
+    pub fn get_oracle_max_age(env: Env, asset_address: Address) -> Option<u64> {
+        let ages: Map<Address, u64> = env
             .storage()
             .instance()
-            .get(&SETTLEMENTS_KEY)
-            .unwrap_or(vec![&env]);
+            .get(&ORACLE_AGE_KEY)
+            .unwrap_or(Map::new(&env));
+        ages.get(asset_address)
+    }
+
+    /// Check whether settlements for an asset are throttled by a stale oracle
+    pub fn is_oracle_stale(env: Env, asset_address: Address) -> bool {
+        Self::require_fresh_oracle(&env, &asset_address).is_err()
+    }
 
-        for s in settlements.iter() {
-            if s.match_id == match_id {
-                return Some(s);
+    /// Check if a nullifier has been used
+    pub fn is_nullifier_used(env: Env, nullifier: BytesN<32>) -> bool {
+        env.storage().persistent().has(&(NULLIFIERS_KEY, nullifier))
+    }
+
+    /// Get escrow balance for a participant and asset
+    pub fn get_escrow_balance(env: Env, participant: Address, asset: Address) -> i128 {
+        Self::read_balance(&env, &ESCROW_KEY, &EscrowKey::main(&participant, &asset))
+    }
+
+    /// Get locked balance for a participant and asset
+    pub fn get_locked_balance(env: Env, participant: Address, asset: Address) -> i128 {
+        Self::read_balance(&env, &LOCKED_KEY, &EscrowKey::main(&participant, &asset))
+    }
+
+    /// Get escrow balance held in a named sub-account
+    pub fn get_subaccount_balance(
+        env: Env,
+        participant: Address,
+        sub_account: Symbol,
+        asset: Address,
+    ) -> i128 {
+        Self::read_balance(&env, &ESCROW_KEY, &EscrowKey::new(&participant, &sub_account, &asset))
+    }
+
+    /// Get locked balance held in a named sub-account
+    pub fn get_subaccount_locked(
+        env: Env,
+        participant: Address,
+        sub_account: Symbol,
+        asset: Address,
+    ) -> i128 {
+        Self::read_balance(&env, &LOCKED_KEY, &EscrowKey::new(&participant, &sub_account, &asset))
+    }
+
+    /// Get available (unlocked) balance
+    pub fn get_available_balance(env: Env, participant: Address, asset: Address) -> i128 {
+        let escrow = Self::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
+        let locked = Self::get_locked_balance(env, participant, asset);
+        escrow - locked
+    }
+
+    /// Get the most recent settlement records visible to the viewer
+    ///
+    /// Only the last `MAX_PAGE_SIZE` settlements are considered; use
+    /// `get_settlements_page` to walk the full history.
+    pub fn get_settlements(env: Env, viewer: Option<Address>) -> Result<Vec<SettlementRecord>, SettlementError> {
+        let count = Self::get_settlement_count(env.clone());
+        Self::get_settlements_page(env, viewer, count.saturating_sub(MAX_PAGE_SIZE), MAX_PAGE_SIZE)
+    }
+
+    /// Get a page of settlement records visible to the viewer
+    ///
+    /// With record privacy on, the viewer must authenticate; the admin and
+    /// auditors see every record and other viewers only their own trades.
+    /// The page covers settlements `start..start + limit` in settlement
+    /// order, so it can hold fewer than `limit` records when some are not
+    /// visible to the viewer.
+    ///
+    /// # Arguments
+    /// * `viewer` - Address reading the records
+    /// * `start` - Index of the first settlement, in settlement order
+    /// * `limit` - Maximum settlements to scan, capped at `MAX_PAGE_SIZE`
+    pub fn get_settlements_page(
+        env: Env,
+        viewer: Option<Address>,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<SettlementRecord>, SettlementError> {
+        let see_all = Self::require_record_viewer(&env, &viewer)?;
+
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(Self::get_settlement_count(env.clone()));
+        let mut settlements = vec![&env];
+        for index in start..end {
+            if let Some(record) = Self::load_settlement_at(&env, index) {
+                let own = viewer.as_ref().is_some_and(|v| *v == record.buyer || *v == record.seller);
+                if see_all || own {
+                    settlements.push_back(record);
+                }
             }
         }
-        None
+        Ok(settlements)
+    }
+
+    /// Get the number of settlements recorded, for paging
+    pub fn get_settlement_count(env: Env) -> u32 {
+        env.storage().persistent().get(&SETTLEMENT_COUNT_KEY).unwrap_or(0)
+    }
+
+    /// Get settlement by match ID
+    ///
+    /// With record privacy on, only the buyer, seller, admin or an auditor
+    /// may read it, and must authenticate.
+    pub fn get_settlement(
+        env: Env,
+        viewer: Option<Address>,
+        match_id: BytesN<32>,
+    ) -> Result<Option<SettlementRecord>, SettlementError> {
+        let see_all = Self::require_record_viewer(&env, &viewer)?;
+        let record = match Self::load_settlement(&env, &match_id) {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        if !see_all && !viewer.is_some_and(|v| v == record.buyer || v == record.seller) {
+            return Err(SettlementError::RecordAccessDenied);
+        }
+        Ok(Some(record))
     }
 
     /// Get admin address
@@ -413,6 +1545,22 @@ impl DarkPoolSettlement {
         env.storage().instance().get(&ADMIN_KEY).unwrap()
     }
 
+    /// Name of a `SettlementError` code for display, or `unknown`
+    ///
+    /// Lets frontends explain a failed call without shipping their own copy
+    /// of the code table; see `get_error_map_version`.
+    pub fn error_description(env: Env, code: u32) -> Symbol {
+        match SettlementError::try_from(soroban_sdk::Error::from_contract_error(code)) {
+            Ok(error) => Symbol::new(&env, error.name()),
+            Err(_) => symbol_short!("unknown"),
+        }
+    }
+
+    /// Version of the error code map, see `ERROR_MAP_VERSION`
+    pub fn get_error_map_version(_env: Env) -> u32 {
+        ERROR_MAP_VERSION
+    }
+
     /// Get registry address
     pub fn get_registry(env: Env) -> Address {
         env.storage().instance().get(&REGISTRY_KEY).unwrap()
@@ -425,65 +1573,432 @@ impl DarkPoolSettlement {
 
     // Internal helper functions
 
-    fn add_escrow_balance(env: &Env, participant: &Address, asset: &Address, amount: i128) -> i128 {
-        let key = EscrowKey {
-            participant: participant.clone(),
-            asset: asset.clone(),
-        };
-        let mut escrow: Map<EscrowKey, i128> = env
+    fn set_asset_paused(env: &Env, asset_address: Address, paused: bool) {
+        let mut assets: Map<Address, bool> = env
             .storage()
             .instance()
-            .get(&ESCROW_KEY)
-            .unwrap_or(Map::new(&env));
+            .get(&PAUSED_KEY)
+            .unwrap_or(Map::new(env));
+        if paused {
+            assets.set(asset_address, true);
+        } else {
+            assets.remove(asset_address);
+        }
+        env.storage().instance().set(&PAUSED_KEY, &assets);
+    }
 
-        let current = escrow.get(key.clone()).unwrap_or(0);
-        let new_balance = current + amount;
-        escrow.set(key, new_balance);
-        env.storage().instance().set(&ESCROW_KEY, &escrow);
-        new_balance
+    fn total_key(ledger: &Symbol) -> Symbol {
+        if *ledger == ESCROW_KEY {
+            ESCROW_TOTAL_KEY
+        } else {
+            LOCKED_TOTAL_KEY
+        }
     }
 
-    fn subtract_escrow_balance(
-        env: &Env,
-        participant: &Address,
-        asset: &Address,
-        amount: i128,
-    ) -> Result<i128, SettlementError> {
-        let key = EscrowKey {
-            participant: participant.clone(),
-            asset: asset.clone(),
+    /// Running total of an asset's balances in the escrow or locked ledger
+    fn read_total(env: &Env, ledger: &Symbol, asset: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(Self::total_key(ledger), asset.clone()))
+            .unwrap_or(0)
+    }
+
+    fn write_total(env: &Env, ledger: &Symbol, asset: &Address, total: i128) {
+        let entry = (Self::total_key(ledger), asset.clone());
+        env.storage().persistent().set(&entry, &total);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    /// Fail if the asset has been paused by the admin
+    fn require_asset_active(env: &Env, asset_address: &Address) -> Result<(), SettlementError> {
+        if Self::is_asset_paused(env.clone(), asset_address.clone()) {
+            return Err(SettlementError::AssetPaused);
+        }
+        Ok(())
+    }
+
+    /// Fail if the asset's oracle price is older than its configured max age
+    fn require_fresh_oracle(env: &Env, asset_address: &Address) -> Result<(), SettlementError> {
+        let max_age = match Self::get_oracle_max_age(env.clone(), asset_address.clone()) {
+            Some(max_age) => max_age,
+            None => return Ok(()),
         };
-        let mut escrow: Map<EscrowKey, i128> = env
+        let oracle_address: Address = env
             .storage()
             .instance()
-            .get(&ESCROW_KEY)
-            .unwrap_or(Map::new(&env));
+            .get(&FX_ORACLE_KEY)
+            .ok_or(SettlementError::FxOracleNotSet)?;
+        let updated = FxOracleClient::new(env, &oracle_address).last_updated(asset_address);
+        if env.ledger().timestamp().saturating_sub(updated) > max_age {
+            return Err(SettlementError::OracleStale);
+        }
+        Ok(())
+    }
 
-        let current = escrow.get(key.clone()).unwrap_or(0);
-        if current < amount {
-            return Err(SettlementError::InsufficientEscrow);
+    /// Transfer adapter the registry lists for an asset
+    fn asset_adapter(env: &Env, asset_address: &Address) -> AssetAdapter {
+        AssetAdapter::load(env, &Self::get_registry(env.clone()), asset_address)
+    }
+
+    /// Fail unless the asset is whitelisted for payment
+    fn require_payment_asset(env: &Env, payment_asset: &Address) -> Result<(), SettlementError> {
+        if !Self::is_payment_asset(env.clone(), payment_asset.clone()) {
+            return Err(SettlementError::PaymentAssetNotAllowed);
         }
+        Ok(())
+    }
 
-        let new_balance = current - amount;
-        escrow.set(key, new_balance);
-        env.storage().instance().set(&ESCROW_KEY, &escrow);
-        Ok(new_balance)
+    /// Verify caller is admin
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), SettlementError> {
+        let admin: Address = env.storage().instance().get(&ADMIN_KEY).unwrap();
+        if *caller != admin {
+            return Err(SettlementError::OnlyAdmin);
+        }
+        env.storage().instance().set(&HEARTBEAT_KEY, &env.ledger().timestamp());
+        Ok(())
     }
 
-    fn add_locked_balance(env: &Env, participant: &Address, asset: &Address, amount: i128) {
-        let key = EscrowKey {
-            participant: participant.clone(),
-            asset: asset.clone(),
+    /// Verify a settlement proof and swap the legs between escrow sub-accounts
+    ///
+    /// `relayer` is the submitting relayer, if any, that earns the relayer
+    /// share of the fees. `auth_mode` is the party authorization the caller
+    /// requires: `ProofOnly` once both parties' signed intents were verified,
+    /// standing in for live authorization. A party settling through a
+    /// sub-account other than its default one must authorize regardless, so
+    /// a relayer cannot choose which of its sub-accounts is debited.
+    fn execute_settlement(
+        env: &Env,
+        relayer: Option<&Address>,
+        auth_mode: SettlementAuthMode,
+        match_id: &BytesN<32>,
+        buyer: &Address,
+        buyer_account: &Symbol,
+        seller: &Address,
+        seller_account: &Symbol,
+        asset_address: &Address,
+        payment_asset: &Address,
+        quantity: i128,
+        price: i128,
+        proof_bytes: &Bytes,
+        pub_signals_bytes: &Bytes,
+    ) -> Result<SettlementRecord, SettlementError> {
+        // NOTE: party auth is not required in the default ProofOnly mode because:
+        // 1. ZK proof cryptographically proves both parties agreed to the trade
+        // 2. Funds are already in escrow (deposited with proper auth)
+        // 3. Nullifier prevents replay attacks
+        // 4. Multi-party auth is complex to implement in frontend
+        //
+        // BothParties mode re-enables it, accepting a settle-scoped broker's
+        // approval or a signed intent in place of the party.
+        for (party, account) in [(buyer, buyer_account), (seller, seller_account)] {
+            if auth_mode == SettlementAuthMode::BothParties || *account != DEFAULT_SUB_ACCOUNT {
+                Self::require_party_auth(env, party, match_id);
+            }
+        }
+
+        // Cheap local checks run first and the proof last, so a doomed
+        // submission (replayed, paused, expired or underfunded) fails before
+        // paying for cross-contract calls and the pairing check
+        Self::require_current_storage(env)?;
+        Self::mark_in_flight(env, match_id, relayer)?;
+        Self::check_trade_amounts(quantity, price)?;
+        Self::require_payment_asset(env, payment_asset)?;
+        Self::require_asset_active(env, asset_address)?;
+        Self::require_asset_active(env, payment_asset)?;
+        Self::check_counterparties(env, buyer, seller)?;
+
+        // Parse public signals - format from settlement_proof.circom
+        // snarkjs outputs signals in order: [output, ...public_inputs]
+        // [0] nullifierHash (output)
+        // [1] buyCommitment
+        // [2] sellCommitment
+        // [3] assetHash
+        // [4] matchedQuantity
+        // [5] executionPrice
+        // [6] whitelistRoot
+        // [7] poolDomain (domain-bound circuit only)
+        let pub_signals = Self::parse_public_signals(env, pub_signals_bytes)?;
+        #[cfg(feature = "debug-events")]
+        debug::signals(env, match_id, &pub_signals);
+
+        let domain_bound = Self::is_domain_bound(env.clone());
+        if pub_signals.len() != if domain_bound { 8 } else { 7 } {
+            return Err(SettlementError::InvalidProof);
+        }
+        if domain_bound && pub_signals.get(7).unwrap() != Self::get_domain_separator(env.clone()) {
+            return Err(SettlementError::DomainMismatch);
+        }
+
+        // Check nullifier not used (signal index 0 - it's the output)
+        let nullifier = pub_signals.get(0).unwrap();
+        if Self::is_nullifier_used(env.clone(), nullifier.clone()) {
+            return Err(SettlementError::NullifierUsed);
+        }
+
+        Self::take_confirmed_match(env, match_id, buyer, seller)?;
+        #[cfg(feature = "schedules")]
+        let (twap_fills, iceberg_fills) = {
+            let (buy_commitment, sell_commitment) = (pub_signals.get(1).unwrap(), pub_signals.get(2).unwrap());
+            (
+                Self::check_twap_slices(env, buyer, seller, &buy_commitment, &sell_commitment, quantity)?,
+                Self::check_iceberg_slices(env, buyer, seller, &buy_commitment, &sell_commitment, quantity)?,
+            )
         };
-        let mut locked: Map<EscrowKey, i128> = env
-            .storage()
-            .instance()
-            .get(&LOCKED_KEY)
-            .unwrap_or(Map::new(&env));
 
-        let current = locked.get(key.clone()).unwrap_or(0);
-        locked.set(key, current + amount);
-        env.storage().instance().set(&LOCKED_KEY, &locked);
+        #[cfg(feature = "order-controls")]
+        if let Some(ticks) = Self::get_tick_size(env.clone(), asset_address.clone()) {
+            // The proven size and price must conform as well as the submitted ones
+            Self::check_tick(&ticks, quantity, price)?;
+            Self::check_tick(
+                &ticks,
+                Self::signal_to_i128(&pub_signals.get(4).unwrap())?,
+                Self::signal_to_i128(&pub_signals.get(5).unwrap())?,
+            )?;
+        }
+        #[cfg(not(feature = "order-controls"))]
+        Self::require_no_tick_size(env, asset_address)?;
+
+        let delivery = Self::leg_delivery(env, match_id, asset_address, quantity, &pub_signals)?;
+
+        // Convert the payment leg if paying in a currency other than the quote
+        #[cfg(feature = "fx")]
+        let payment_amount = Self::convert_payment(env, asset_address, payment_asset, price)?;
+        #[cfg(not(feature = "fx"))]
+        let payment_amount = price;
+        Self::check_notional(env, payment_asset, payment_amount)?;
+
+        // Both legs must be locked before the proof is worth verifying; a
+        // forward's asset leg is only delivered later, against the seller's
+        // bond. Pledged collateral may make up a short payment lock.
+        #[cfg(feature = "margin")]
+        let collateral_draws =
+            Self::plan_collateral_draws(env, &EscrowKey::new(buyer, buyer_account, payment_asset), payment_amount)?;
+        #[cfg(feature = "margin")]
+        let draws_collateral = !collateral_draws.is_empty();
+        #[cfg(not(feature = "margin"))]
+        let draws_collateral = false;
+        if !draws_collateral {
+            Self::check_transfer(env, &EscrowKey::new(buyer, buyer_account, payment_asset), payment_amount)?;
+        }
+        #[cfg(feature = "forwards")]
+        let seller_bond = match delivery {
+            LegDelivery::Forward(_) => {
+                let bond = payment_amount
+                    .checked_mul(Self::get_forward_bond(env.clone()) as i128)
+                    .ok_or(SettlementError::NotionalOverflow)?
+                    / BPS_DENOMINATOR;
+                Self::check_transfer(env, &EscrowKey::new(seller, seller_account, payment_asset), bond)?;
+                bond
+            }
+            _ => {
+                Self::check_transfer(env, &EscrowKey::new(seller, seller_account, asset_address), quantity)?;
+                0
+            }
+        };
+        #[cfg(not(feature = "forwards"))]
+        Self::check_transfer(env, &EscrowKey::new(seller, seller_account, asset_address), quantity)?;
+
+        Self::require_fresh_oracle(env, asset_address)?;
+
+        // Whitelist check is disabled by default for testnet testing
+        // because on-chain registry uses different Poseidon computation
+        if Self::is_whitelist_check_enabled(env.clone()) {
+            Self::check_whitelist_root(env, &pub_signals.get(6).unwrap())?;
+        }
+
+        // Verify ZK proof
+        let scheme_version = Self::verify_settlement_proof(env, proof_bytes, pub_signals_bytes)?;
+        #[cfg(feature = "margin")]
+        Self::convert_collateral(env, match_id, &EscrowKey::new(buyer, buyer_account, payment_asset), &collateral_draws);
+
+        match delivery {
+            // Residual: hold both legs until the next netting round
+            #[cfg(feature = "netting")]
+            LegDelivery::Residual => Self::queue_residual(
+                env,
+                relayer,
+                match_id,
+                &EscrowKey::new(seller, seller_account, asset_address),
+                &EscrowKey::new(buyer, buyer_account, asset_address),
+                quantity,
+                &EscrowKey::new(buyer, buyer_account, payment_asset),
+                &EscrowKey::new(seller, seller_account, payment_asset),
+                payment_amount,
+            )?,
+            // Forward: hold the payment now, deliver the asset later
+            #[cfg(feature = "forwards")]
+            LegDelivery::Forward(delivery_after) => {
+                let buyer_payment = EscrowKey::new(buyer, buyer_account, payment_asset);
+                Self::check_transfer(env, &buyer_payment, payment_amount)?;
+                Self::commit_debit(env, &buyer_payment, payment_amount);
+                Self::commit_debit(env, &EscrowKey::new(seller, seller_account, payment_asset), seller_bond);
+                Self::store_forward(
+                    env,
+                    match_id,
+                    &ForwardSettlement {
+                        buyer: EscrowKey::new(buyer, buyer_account, asset_address),
+                        seller: EscrowKey::new(seller, seller_account, asset_address),
+                        quantity,
+                        payment_asset: payment_asset.clone(),
+                        payment_amount,
+                        seller_bond,
+                        delivery_after,
+                        status: ForwardStatus::Pending,
+                    },
+                );
+            }
+            LegDelivery::Spot => Self::settle_spot(
+                env,
+                relayer,
+                match_id,
+                &EscrowKey::new(seller, seller_account, asset_address),
+                &EscrowKey::new(buyer, buyer_account, asset_address),
+                quantity,
+                &EscrowKey::new(buyer, buyer_account, payment_asset),
+                &EscrowKey::new(seller, seller_account, payment_asset),
+                payment_amount,
+            )?,
+        }
+
+        // Mark nullifier as used
+        Self::mark_nullifier_used(env, &nullifier);
+        #[cfg(feature = "schedules")]
+        {
+            for (parent, order) in twap_fills.iter() {
+                Self::write_twap(env, &parent, &order);
+            }
+            for (parent, order) in iceberg_fills.iter() {
+                Self::write_iceberg(env, &parent, &order);
+            }
+        }
+
+        // Create settlement record
+        let record = SettlementRecord {
+            match_id: match_id.clone(),
+            buyer: buyer.clone(),
+            seller: seller.clone(),
+            asset_address: asset_address.clone(),
+            quantity,
+            price,
+            payment_asset: payment_asset.clone(),
+            payment_amount,
+            timestamp: env.ledger().timestamp(),
+            nullifier: nullifier.clone(),
+            scheme_version,
+        };
+
+        // Without records there is no match sequence to refuse a repeated match
+        #[cfg(not(feature = "records"))]
+        if env.storage().persistent().has(&(SETTLEMENTS_KEY, match_id.clone())) {
+            return Err(SettlementError::AlreadySettled);
+        }
+
+        // Store settlement record
+        Self::store_settlement(env, &record);
+        #[cfg(feature = "records")]
+        if Self::get_record_format(env.clone()) == RecordFormat::CommitmentOnly {
+            Self::store_commitment(env, &record, &pub_signals.get(3).unwrap());
+        }
+        #[cfg(feature = "records")]
+        Self::assign_sequence(env, match_id)?;
+        Self::bump_instance(env);
+
+        // Commit to the receipt hash so it can be verified later
+        #[cfg(feature = "records")]
+        {
+            let receipt = Self::store_receipt(env, match_id, &record.clone().to_xdr(env));
+            Self::append_to_log(env, &receipt);
+            Self::record_compliance_evidence(env, match_id, &pub_signals.get(6).unwrap());
+        }
+        #[cfg(feature = "delivery")]
+        Self::report_high_value(env, &record);
+        #[cfg(feature = "ops")]
+        Self::tally_activity(env, &record.payment_asset, record.payment_amount, relayer);
+
+        Ok(record)
+    }
+
+    /// Decide how a match's legs move once its proof checks out
+    ///
+    /// A size below the asset's bucket base unit is netted later as a
+    /// residual, a registered forward delivers its asset leg later, and
+    /// anything else swaps at once.
+    #[cfg_attr(not(all(feature = "forwards", feature = "netting")), allow(unused_variables))]
+    fn leg_delivery(
+        env: &Env,
+        match_id: &BytesN<32>,
+        asset_address: &Address,
+        quantity: i128,
+        pub_signals: &Vec<BytesN<32>>,
+    ) -> Result<LegDelivery, SettlementError> {
+        #[cfg(feature = "netting")]
+        if let Some(buckets) = Self::get_size_buckets(env.clone(), asset_address.clone()) {
+            if Self::signal_to_i128(&pub_signals.get(4).unwrap())? != quantity {
+                return Err(SettlementError::InvalidProof);
+            }
+            Self::check_bucket(&buckets, quantity)?;
+            if quantity < buckets.base_unit {
+                return Ok(LegDelivery::Residual);
+            }
+        }
+        #[cfg(feature = "forwards")]
+        if let Some(delivery_after) = Self::take_forward_registration(env, match_id) {
+            return Ok(LegDelivery::Forward(delivery_after));
+        }
+        Ok(LegDelivery::Spot)
+    }
+
+    /// Require settlement authorization from a party, or use up its broker's approval
+    ///
+    /// An approval only counts while the approving broker still holds settle
+    /// scope; otherwise the party itself must authorize.
+    #[cfg_attr(not(feature = "delegation"), allow(unused_variables))]
+    fn require_party_auth(env: &Env, party: &Address, match_id: &BytesN<32>) {
+        #[cfg(feature = "delegation")]
+        {
+            let entry = (BROKER_APPROVALS_KEY, match_id.clone(), party.clone());
+            let approved_by: Option<Address> = env.storage().temporary().get(&entry);
+            if approved_by.is_some_and(|broker| Self::require_broker_scope(env, party, &broker, true).is_ok()) {
+                env.storage().temporary().remove(&entry);
+                return;
+            }
+        }
+        party.require_auth();
+    }
+
+    /// Compute `a * b / denominator` without wrapping
+    #[cfg(any(feature = "corporate-actions", feature = "fees", feature = "fx", feature = "margin"))]
+    fn mul_div(a: i128, b: i128, denominator: i128) -> Result<i128, SettlementError> {
+        a.checked_mul(b)
+            .and_then(|n| n.checked_div(denominator))
+            .ok_or(SettlementError::NotionalOverflow)
+    }
+
+    /// Reject non-positive quantities and prices supplied by the relayer
+    fn check_trade_amounts(quantity: i128, price: i128) -> Result<(), SettlementError> {
+        if quantity <= 0 || price <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        Ok(())
+    }
+
+    fn add_escrow_balance(env: &Env, participant: &Address, asset: &Address, amount: i128) -> i128 {
+        Self::credit_escrow(env, &EscrowKey::main(participant, asset), amount)
+    }
+
+    fn subtract_escrow_balance(
+        env: &Env,
+        participant: &Address,
+        asset: &Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        Self::debit_escrow(env, &EscrowKey::main(participant, asset), amount)
+    }
+
+    fn add_locked_balance(env: &Env, participant: &Address, asset: &Address, amount: i128) {
+        Self::credit_locked(env, &EscrowKey::main(participant, asset), amount)
     }
 
     fn subtract_locked_balance(
@@ -492,51 +2007,665 @@ impl DarkPoolSettlement {
         asset: &Address,
         amount: i128,
     ) -> Result<(), SettlementError> {
-        let key = EscrowKey {
-            participant: participant.clone(),
-            asset: asset.clone(),
-        };
-        let mut locked: Map<EscrowKey, i128> = env
+        Self::debit_locked(env, &EscrowKey::main(participant, asset), amount)
+    }
+
+    /// Read a balance from the escrow or locked ledger
+    ///
+    /// Each balance lives in its own persistent entry keyed by
+    /// `(ledger, EscrowKey)`, so touching one account never deserializes
+    /// the balances of every other participant.
+    fn read_balance(env: &Env, ledger: &Symbol, key: &EscrowKey) -> i128 {
+        let balance: i128 = env
             .storage()
-            .instance()
-            .get(&LOCKED_KEY)
-            .unwrap_or(Map::new(&env));
+            .persistent()
+            .get(&(ledger.clone(), key.clone()))
+            .unwrap_or(0);
+        if balance == 0 {
+            return 0;
+        }
 
-        let current = locked.get(key.clone()).unwrap_or(0);
+        // Catch up on splits applied since the balance was written
+        #[cfg(feature = "corporate-actions")]
+        {
+            let splits = Self::get_splits(env.clone(), key.asset.clone());
+            if !splits.is_empty() {
+                let seen: u32 = env
+                    .storage()
+                    .persistent()
+                    .get(&(Self::splits_ledger(ledger), key.clone()))
+                    .unwrap_or(0);
+                return splits
+                    .iter()
+                    .skip(seen as usize)
+                    .fold(balance, |balance, ratio| Self::apply_ratio(balance, &ratio));
+            }
+        }
+        balance
+    }
+
+    /// Overwrite a balance in the escrow or locked ledger
+    ///
+    /// Keeps the ledger's per-asset running total in step.
+    fn write_balance(env: &Env, ledger: &Symbol, key: &EscrowKey, balance: i128) {
+        let delta = balance - Self::read_balance(env, ledger, key);
+        #[cfg(feature = "debug-events")]
+        debug::balance(env, ledger, key, delta, balance);
+
+        #[cfg(feature = "corporate-actions")]
+        if *ledger == ESCROW_KEY {
+            Self::checkpoint_holding(env, key, delta);
+        }
+        if delta != 0 {
+            Self::write_total(env, ledger, &key.asset, Self::read_total(env, ledger, &key.asset) + delta);
+        }
+
+        let entry = (ledger.clone(), key.clone());
+        if balance == 0 {
+            env.storage().persistent().remove(&entry);
+        } else {
+            env.storage().persistent().set(&entry, &balance);
+            env.storage()
+                .persistent()
+                .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        }
+        #[cfg(feature = "corporate-actions")]
+        Self::write_splits_seen(env, ledger, key, balance);
+    }
+
+    fn credit_escrow(env: &Env, key: &EscrowKey, amount: i128) -> i128 {
+        let new_balance = Self::read_balance(env, &ESCROW_KEY, key) + amount;
+        Self::write_balance(env, &ESCROW_KEY, key, new_balance);
+        new_balance
+    }
+
+    fn debit_escrow(env: &Env, key: &EscrowKey, amount: i128) -> Result<i128, SettlementError> {
+        let current = Self::read_balance(env, &ESCROW_KEY, key);
+        if current < amount {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+
+        let new_balance = current - amount;
+        Self::write_balance(env, &ESCROW_KEY, key, new_balance);
+        Ok(new_balance)
+    }
+
+    fn credit_locked(env: &Env, key: &EscrowKey, amount: i128) {
+        let current = Self::read_balance(env, &LOCKED_KEY, key);
+        Self::write_balance(env, &LOCKED_KEY, key, current + amount);
+    }
+
+    fn debit_locked(env: &Env, key: &EscrowKey, amount: i128) -> Result<(), SettlementError> {
+        let current = Self::read_balance(env, &LOCKED_KEY, key);
         if current < amount {
             return Err(SettlementError::InsufficientLockedFunds);
         }
 
-        locked.set(key, current - amount);
-        env.storage().instance().set(&LOCKED_KEY, &locked);
+        Self::write_balance(env, &LOCKED_KEY, key, current - amount);
         Ok(())
     }
 
-    fn transfer_from_escrow(
+    /// Available (unlocked) balance of an escrow account
+    fn available_balance(env: &Env, key: &EscrowKey) -> i128 {
+        Self::read_balance(env, &ESCROW_KEY, key) - Self::read_balance(env, &LOCKED_KEY, key)
+    }
+
+    /// Check phase of a transfer: the sender holds `amount` locked and in escrow
+    ///
+    /// Writes nothing, so every leg of a settlement can be checked before any
+    /// balance moves.
+    fn check_transfer(env: &Env, from: &EscrowKey, amount: i128) -> Result<(), SettlementError> {
+        if Self::read_balance(env, &LOCKED_KEY, from) < amount {
+            return Err(SettlementError::InsufficientLockedFunds);
+        }
+        if Self::read_balance(env, &ESCROW_KEY, from) < amount {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+        Ok(())
+    }
+
+    /// Commit phase of a transfer; the sender must already have passed `check_transfer`
+    fn commit_transfer(env: &Env, from: &EscrowKey, to: &EscrowKey, amount: i128) {
+        Self::commit_debit(env, from, amount);
+        Self::credit_proceeds(env, to, amount);
+    }
+
+    /// Credit settlement proceeds, keeping them locked if the recipient asked to
+    fn credit_proceeds(env: &Env, to: &EscrowKey, amount: i128) {
+        Self::credit_escrow(env, to, amount);
+        #[cfg(feature = "corporate-actions")]
+        Self::open_lot(env, &to.participant, &to.asset, amount, LotSource::Settlement);
+        #[cfg(feature = "accounts")]
+        if Self::is_auto_relock(env.clone(), to.participant.clone(), to.asset.clone()) {
+            Self::credit_locked(env, to, amount);
+        }
+    }
+
+    /// Remove checked funds from the sender's escrow and locked balances
+    fn commit_debit(env: &Env, from: &EscrowKey, amount: i128) {
+        let locked = Self::read_balance(env, &LOCKED_KEY, from);
+        Self::write_balance(env, &LOCKED_KEY, from, locked - amount);
+        let escrow = Self::read_balance(env, &ESCROW_KEY, from);
+        Self::write_balance(env, &ESCROW_KEY, from, escrow - amount);
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(env, &from.participant, &from.asset, amount);
+    }
+
+    /// Send withdrawn tokens, or queue them if the asset has a redemption delay
+    fn pay_out(env: &Env, withdrawer: &Address, asset_address: &Address, amount: i128) -> Result<(), SettlementError> {
+        #[cfg(feature = "withdrawal-queue")]
+        if let Some(delay) = Self::get_redemption_delay(env.clone(), asset_address.clone()) {
+            Self::queue_withdrawal(env, withdrawer, asset_address, amount, delay);
+            return Ok(());
+        }
+        #[cfg(not(feature = "withdrawal-queue"))]
+        Self::require_no_redemption_delay(env, asset_address)?;
+        Self::asset_adapter(env, asset_address).transfer(env, &env.current_contract_address(), withdrawer, amount);
+        Ok(())
+    }
+
+    /// Check both legs of a spot settlement, then swap them
+    ///
+    /// All fallible checks run before the first write, so a failing payment
+    /// leg can never leave the asset leg half-applied.
+    fn settle_spot(
         env: &Env,
-        from: &Address,
-        to: &Address,
-        asset: &Address,
-        amount: i128,
+        relayer: Option<&Address>,
+        match_id: &BytesN<32>,
+        seller_asset: &EscrowKey,
+        buyer_asset: &EscrowKey,
+        quantity: i128,
+        buyer_payment: &EscrowKey,
+        seller_payment: &EscrowKey,
+        payment_amount: i128,
     ) -> Result<(), SettlementError> {
-        // Subtract from sender's escrow and locked
-        Self::subtract_locked_balance(env, from, asset, amount)?;
-        Self::subtract_escrow_balance(env, from, asset, amount)?;
+        Self::check_transfer(env, seller_asset, quantity)?;
+        Self::check_inventory(env, buyer_asset, quantity)?;
+        let mut fees = Self::check_payment(env, buyer_payment, seller_payment, payment_amount)?;
+        fees.relayer = relayer.cloned();
 
-        // Add to receiver's escrow
-        Self::add_escrow_balance(env, to, asset, amount);
+        // Execute atomic swap - seller sends asset to buyer
+        Self::deliver_leg(env, match_id, seller_asset, buyer_asset, quantity);
 
+        // Buyer sends payment to seller, net of fees
+        Self::commit_payment(env, match_id, buyer_payment, seller_payment, payment_amount, fees);
         Ok(())
     }
 
+    /// Move one checked settlement leg, queueing it if the asset has a finality delay
+    #[cfg_attr(not(feature = "delivery"), allow(unused_variables))]
+    fn deliver_leg(env: &Env, match_id: &BytesN<32>, from: &EscrowKey, to: &EscrowKey, amount: i128) {
+        #[cfg(feature = "delivery")]
+        {
+            let delay = Self::get_settlement_delay(env.clone(), to.asset.clone());
+            if delay > 0 {
+                Self::queue_delivery(env, match_id, from, to, amount, delay);
+                return;
+            }
+        }
+        Self::commit_transfer(env, from, to, amount);
+    }
+
+    /// Number of IDs in a persistent index
+    #[cfg(any(feature = "bridge", feature = "delivery", feature = "forwards"))]
+    fn index_len(env: &Env, index: &Symbol) -> u32 {
+        env.storage().persistent().get(&(INDEX_LEN_KEY, index.clone())).unwrap_or(0)
+    }
+
+    /// ID at a position of a persistent index
+    #[cfg(any(feature = "bridge", feature = "delivery", feature = "forwards"))]
+    fn index_get(env: &Env, index: &Symbol, position: u32) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(index.clone(), position))
+    }
+
+    /// Add an ID to a persistent index, if not already present
+    ///
+    /// An index keeps `(index, position) -> id` and `(index, id) -> position`
+    /// entries under a length, so adding or removing an ID touches a fixed
+    /// number of entries however many the index holds.
+    #[cfg(any(feature = "bridge", feature = "delivery", feature = "forwards"))]
+    fn index_add(env: &Env, index: &Symbol, id: &BytesN<32>) {
+        let slot = (index.clone(), id.clone());
+        if env.storage().persistent().has(&slot) {
+            return;
+        }
+        let len_entry = (INDEX_LEN_KEY, index.clone());
+        let position = Self::index_len(env, index);
+        Self::write_index_entry(env, &(index.clone(), position), id);
+        Self::write_index_entry(env, &slot, &position);
+        Self::write_index_entry(env, &len_entry, &(position + 1));
+    }
+
+    /// Remove an ID from a persistent index, moving the last ID into its place
+    #[cfg(any(feature = "bridge", feature = "delivery", feature = "forwards"))]
+    fn index_remove(env: &Env, index: &Symbol, id: &BytesN<32>) {
+        let slot = (index.clone(), id.clone());
+        let Some(position) = env.storage().persistent().get::<_, u32>(&slot) else {
+            return;
+        };
+        let last = Self::index_len(env, index) - 1;
+        if position != last {
+            let moved = Self::index_get(env, index, last).unwrap();
+            Self::write_index_entry(env, &(index.clone(), position), &moved);
+            Self::write_index_entry(env, &(index.clone(), moved), &position);
+        }
+        env.storage().persistent().remove(&(index.clone(), last));
+        env.storage().persistent().remove(&slot);
+        Self::write_index_entry(env, &(INDEX_LEN_KEY, index.clone()), &last);
+    }
+
+    #[cfg(any(feature = "bridge", feature = "delivery", feature = "forwards"))]
+    fn write_index_entry<K, V>(env: &Env, entry: &K, value: &V)
+    where
+        K: IntoVal<Env, Val>,
+        V: IntoVal<Env, Val>,
+    {
+        env.storage().persistent().set(entry, value);
+        env.storage()
+            .persistent()
+            .extend_ttl(entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    /// Check phase of the payment leg, returning the fees owed
+    ///
+    /// The seller's fee comes out of the locked payment unless a fee payer
+    /// covers it; every other fee is taken from the unlocked escrow of the
+    /// account listed by `fee_debits`.
+    #[cfg_attr(not(feature = "fees"), allow(unused_variables))]
+    fn check_payment(
+        env: &Env,
+        buyer: &EscrowKey,
+        seller: &EscrowKey,
+        payment_amount: i128,
+    ) -> Result<SettlementFees, SettlementError> {
+        #[cfg(feature = "fees")]
+        let fees = Self::compute_fees(env, &buyer.participant, &seller.participant, &buyer.asset, payment_amount)?;
+        #[cfg(not(feature = "fees"))]
+        let fees = SettlementFees::default();
+        Self::check_transfer(env, buyer, payment_amount)?;
+
+        // A broker paying for both sides owes both fees from one account
+        #[cfg(feature = "fees")]
+        {
+            let mut owed: Map<EscrowKey, i128> = Map::new(env);
+            for (key, fee) in Self::fee_debits(env, buyer, seller, &fees) {
+                owed.set(key.clone(), owed.get(key).unwrap_or(0) + fee);
+            }
+            for (key, fee) in owed.iter() {
+                if Self::available_balance(env, &key) < fee {
+                    return Err(SettlementError::InsufficientEscrow);
+                }
+            }
+        }
+        Ok(fees)
+    }
+
+    /// Commit phase of the payment leg: pay the seller and collect fees
+    ///
+    /// Fees are credited at once, even when the payment asset has a
+    /// settlement delay.
+    fn commit_payment(
+        env: &Env,
+        match_id: &BytesN<32>,
+        buyer: &EscrowKey,
+        seller: &EscrowKey,
+        payment_amount: i128,
+        fees: SettlementFees,
+    ) {
+        let withheld = fees.seller_fee_from_payment();
+        Self::deliver_leg(env, match_id, buyer, seller, payment_amount - withheld);
+        if withheld > 0 {
+            Self::commit_debit(env, buyer, withheld);
+        }
+        #[cfg(feature = "fees")]
+        Self::collect_fees(env, buyer, seller, &fees);
+    }
+
+    /// Check a proof's whitelist root is the registry's current root or a recent one
+    fn check_whitelist_root(env: &Env, proof_root: &BytesN<32>) -> Result<(), SettlementError> {
+        let registry_address: Address = env.storage().instance().get(&REGISTRY_KEY).unwrap();
+        let registry_client = registry_wasm::Client::new(env, &registry_address);
+
+        if registry_client.get_whitelist_root() == *proof_root {
+            return Ok(());
+        }
+
+        let set_at = registry_client
+            .get_root_timestamp(proof_root)
+            .ok_or(SettlementError::WhitelistRootMismatch)?;
+        let max_age = Self::get_max_root_age(env.clone());
+        if env.ledger().timestamp().saturating_sub(set_at) > max_age {
+            return Err(SettlementError::WhitelistRootStale);
+        }
+        Ok(())
+    }
+
+    /// Count a verification in the cumulative stats
+    fn record_verification(env: &Env, proof_type: &Symbol, route: VerificationRoute) {
+        let mut stats = Self::get_verification_stats(env.clone());
+        stats.total += 1;
+        match route {
+            VerificationRoute::Contract => stats.contract_calls += 1,
+            VerificationRoute::Native => stats.native_calls += 1,
+        }
+        let count = stats.by_proof_type.get(proof_type.clone()).unwrap_or(0);
+        stats.by_proof_type.set(proof_type.clone(), count + 1);
+        env.storage().instance().set(&VERIFY_STATS_KEY, &stats);
+    }
+
+    /// Verify a proof along the route configured for its type
+    fn verify_proof(
+        env: &Env,
+        proof_type: &Symbol,
+        vk_bytes: &Bytes,
+        proof_bytes: &Bytes,
+        pub_signals_bytes: &Bytes,
+    ) -> Result<bool, SettlementError> {
+        #[cfg(feature = "proofs")]
+        {
+            let revoked = Self::revoked_vks(env).get(proof_type.clone());
+            if revoked.is_some_and(|hash| BytesN::<32>::from(env.crypto().sha256(vk_bytes)) == hash) {
+                return Err(SettlementError::VkRevoked);
+            }
+        }
+
+        // Without the verification-routes and input-modes features every proof
+        // goes to the verifier contract with its full public inputs
+        #[cfg(feature = "verification-routes")]
+        let route = Self::get_verification_route(env.clone(), proof_type.clone());
+        #[cfg(not(feature = "verification-routes"))]
+        let route = VerificationRoute::Contract;
+        Self::record_verification(env, proof_type, route);
+
+        #[cfg(feature = "input-modes")]
+        let hashed;
+        #[cfg(feature = "input-modes")]
+        let pub_signals_bytes = match Self::get_input_mode(env.clone(), proof_type.clone()) {
+            PublicInputMode::Full => pub_signals_bytes,
+            PublicInputMode::Hashed => {
+                hashed = zk_bn254::hash_public_signals_bytes(env, pub_signals_bytes)
+                    .map_err(|_| SettlementError::InvalidProof)?;
+                &hashed
+            }
+        };
+
+        let valid = match route {
+            VerificationRoute::Contract => {
+                let verifier_address: Address = env.storage().instance().get(&VERIFIER_KEY).unwrap();
+                let verifier_client = verifier_wasm::Client::new(env, &verifier_address);
+                match verifier_client.try_verify_proof_bytes(vk_bytes, proof_bytes, pub_signals_bytes) {
+                    Ok(Ok(valid)) => valid,
+                    _ => return Err(SettlementError::InvalidProof),
+                }
+            }
+            VerificationRoute::Native => {
+                zk_bn254::verify_groth16_bytes(env, vk_bytes, proof_bytes, pub_signals_bytes)
+                    .map_err(|_| SettlementError::InvalidProof)?
+            }
+        };
+
+        #[cfg(feature = "debug-events")]
+        debug::verification(env, proof_type, route, valid);
+        Ok(valid)
+    }
+
+    /// Verify a settlement proof, returning the commitment scheme it was made under
+    ///
+    /// The current scheme's key is tried first, then the keys of superseded
+    /// schemes still in their upgrade window, newest first. A superseded key
+    /// that fails to verify, or rejects the proof as malformed, is no match.
+    fn verify_settlement_proof(
+        env: &Env,
+        proof_bytes: &Bytes,
+        pub_signals_bytes: &Bytes,
+    ) -> Result<u32, SettlementError> {
+        let vk_bytes: Bytes = env.storage().instance().get(&SETTLEMENT_VK_KEY).unwrap();
+        if Self::verify_proof(env, &SETTLEMENT_PROOF, &vk_bytes, proof_bytes, pub_signals_bytes)? {
+            #[cfg(feature = "proofs")]
+            return Ok(Self::get_commitment_scheme(env.clone()));
+            #[cfg(not(feature = "proofs"))]
+            return Ok(COMMITMENT_SCHEME_V1);
+        }
+
+        #[cfg(feature = "proofs")]
+        {
+            let open: Map<u32, Bytes> = env
+                .storage()
+                .instance()
+                .get(&SCHEME_VKS_KEY)
+                .unwrap_or(Map::new(env));
+            for (version, vk_bytes) in open.iter().rev() {
+                match Self::verify_proof(env, &SETTLEMENT_PROOF, &vk_bytes, proof_bytes, pub_signals_bytes) {
+                    Ok(true) => return Ok(version),
+                    Err(SettlementError::VkRevoked) => return Err(SettlementError::VkRevoked),
+                    _ => {}
+                }
+            }
+        }
+        Err(SettlementError::InvalidProof)
+    }
+
+    /// Structurally validate a verification key before installing it
+    ///
+    /// Checks the key parses with canonical on-curve points, that its IC
+    /// length fits the proof type's public signals, and that no point is
+    /// the identity or outside its subgroup.
+    #[cfg(any(feature = "baskets", feature = "cancellation", feature = "proofs"))]
+    fn validate_vk(env: &Env, proof_type: &Symbol, vk_bytes: &Bytes) -> Result<(), SettlementError> {
+        let vk = zk_bn254::VerificationKeyBN254::from_bytes(env, vk_bytes)
+            .map_err(|_| SettlementError::MalformedVerificationKey)?;
+
+        let signals = vk.ic.len().saturating_sub(1);
+        let expected = if *proof_type == BASKET_PROOF {
+            // Four shared signals, then asset hash, quantity and price per
+            // leg; the domain-bound circuit appends the pool domain
+            signals >= 7 && (signals - 4) % 3 != 2 && signals <= MAX_PUBLIC_SIGNALS
+        } else if *proof_type == CANCEL_PROOF {
+            signals == 4
+        } else {
+            // The domain-bound circuit appends the pool domain
+            signals == 7 || signals == 8
+        };
+        if !expected {
+            return Err(SettlementError::VkSignalCountMismatch);
+        }
+
+        zk_bn254::validate_verification_key(env, &vk).map_err(|_| SettlementError::MalformedVerificationKey)
+    }
+
     fn mark_nullifier_used(env: &Env, nullifier: &BytesN<32>) {
+        let entry = (NULLIFIERS_KEY, nullifier.clone());
+        env.storage().persistent().set(&entry, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+
+        let count: u64 = env.storage().instance().get(&NULLIFIER_COUNT_KEY).unwrap_or(0);
+        env.storage().instance().set(&NULLIFIER_COUNT_KEY, &(count + 1));
+    }
+
+    /// Extend instance storage to its full TTL and record when it expires
+    fn bump_instance(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_TTL_EXTEND_TO, INSTANCE_TTL_EXTEND_TO);
+        env.storage()
+            .instance()
+            .set(&INSTANCE_LIVE_KEY, &(env.ledger().sequence() + INSTANCE_TTL_EXTEND_TO));
+    }
+
+    fn load_settlement(env: &Env, match_id: &BytesN<32>) -> Option<SettlementRecord> {
+        let stored: Val = env.storage().persistent().get(&(SETTLEMENTS_KEY, match_id.clone()))?;
+        Some(Self::decode_settlement(env, stored))
+    }
+
+    /// Load the settlement at an index in settlement order
+    fn load_settlement_at(env: &Env, index: u32) -> Option<SettlementRecord> {
+        let settlement_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&(SETTLEMENT_IDS_KEY, index / SETTLEMENT_ID_CHUNK))?;
+        Self::load_settlement(env, &settlement_ids.get(index % SETTLEMENT_ID_CHUNK)?)
+    }
+
+    /// Decode a stored settlement record in either record layout
+    fn decode_settlement(env: &Env, stored: Val) -> SettlementRecord {
+        let fields = Map::<Symbol, Val>::from_val(env, &stored);
+        if fields.contains_key(Symbol::new(env, "scheme_version")) {
+            SettlementRecord::from_val(env, &stored)
+        } else {
+            SettlementRecordV2::from_val(env, &stored).into()
+        }
+    }
+
+    /// Claim a match for this settlement attempt
+    ///
+    /// The marker lives in temporary storage and expires on its own. A
+    /// failed attempt rolls its marker back with everything else, so only a
+    /// landed settlement holds the match; a relayer racing it on the same
+    /// match then fails here, before any verification cost, instead of
+    /// later on the nullifier or sequence check.
+    fn mark_in_flight(env: &Env, match_id: &BytesN<32>, relayer: Option<&Address>) -> Result<(), SettlementError> {
+        let entry = (IN_FLIGHT_KEY, match_id.clone());
+        if env.storage().temporary().has(&entry) {
+            return Err(SettlementError::SettlementInFlight);
+        }
+        let attempt = InFlightSettlement { relayer: relayer.cloned(), ledger: env.ledger().sequence() };
+        env.storage().temporary().set(&entry, &attempt);
+        env.storage()
+            .temporary()
+            .extend_ttl(&entry, IN_FLIGHT_TTL_LEDGERS, IN_FLIGHT_TTL_LEDGERS);
+        Ok(())
+    }
+
+    /// Store a settlement record under its match ID and index it
+    fn store_settlement(env: &Env, record: &SettlementRecord) {
+        let entry = (SETTLEMENTS_KEY, record.match_id.clone());
+        env.storage().persistent().set(&entry, record);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+
+        let index = Self::get_settlement_count(env.clone());
+        let index_entry = (SETTLEMENT_IDS_KEY, index / SETTLEMENT_ID_CHUNK);
+        let mut settlement_ids: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&index_entry)
+            .unwrap_or(vec![env]);
+        settlement_ids.push_back(record.match_id.clone());
+        env.storage().persistent().set(&index_entry, &settlement_ids);
+        env.storage()
+            .persistent()
+            .extend_ttl(&index_entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        env.storage().persistent().set(&SETTLEMENT_COUNT_KEY, &(index + 1));
+        env.storage()
+            .persistent()
+            .extend_ttl(&SETTLEMENT_COUNT_KEY, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    /// Check a service role in the registry
+    #[cfg(any(feature = "margin", feature = "records", feature = "relayers"))]
+    fn has_service_role(env: &Env, service: &Address, role: registry_wasm::ServiceRole) -> bool {
+        registry_wasm::Client::new(env, &Self::get_registry(env.clone())).has_service_role(service, &role)
+    }
+
+    /// Fail until storage has been migrated to the layout this build expects
+    fn require_current_storage(env: &Env) -> Result<(), SettlementError> {
+        if Self::get_storage_version(env.clone()) != STORAGE_VERSION {
+            return Err(SettlementError::StorageMigrationRequired);
+        }
+        Ok(())
+    }
+
+    /// Move a batch of version 1 instance storage into persistent entries
+    ///
+    /// Nullifiers, then escrow and locked balances (added to the main
+    /// sub-account), then settlement records in settlement order. Each item
+    /// leaves the instance vector or map as it moves, so the remainder is
+    /// the cursor. Returns whether everything has moved.
+    fn migrate_v1_to_v2(env: &Env, payment_asset: Option<&Address>) -> Result<bool, SettlementError> {
+        let mut budget = MIGRATION_BATCH;
+        let mut finished = true;
+
         let mut nullifiers: Vec<BytesN<32>> = env
             .storage()
             .instance()
             .get(&NULLIFIERS_KEY)
-            .unwrap_or(vec![&env]);
-        nullifiers.push_back(nullifier.clone());
-        env.storage().instance().set(&NULLIFIERS_KEY, &nullifiers);
+            .unwrap_or(vec![env]);
+        while budget > 0 {
+            let Some(nullifier) = nullifiers.pop_front() else { break };
+            Self::mark_nullifier_used(env, &nullifier);
+            budget -= 1;
+        }
+        finished &= Self::store_legacy(env, &NULLIFIERS_KEY, &nullifiers, nullifiers.is_empty());
+
+        for ledger in [ESCROW_KEY, LOCKED_KEY] {
+            let mut balances: Map<EscrowKeyV1, i128> = env
+                .storage()
+                .instance()
+                .get(&ledger)
+                .unwrap_or(Map::new(env));
+            while budget > 0 {
+                let Some((key, balance)) = balances.iter().next() else { break };
+                let main = EscrowKey::main(&key.participant, &key.asset);
+                Self::write_balance(env, &ledger, &main, Self::read_balance(env, &ledger, &main) + balance);
+                balances.remove(key);
+                budget -= 1;
+            }
+            finished &= Self::store_legacy(env, &ledger, &balances, balances.is_empty());
+        }
+
+        let mut settlements: Vec<SettlementRecordV1> = env
+            .storage()
+            .instance()
+            .get(&SETTLEMENTS_KEY)
+            .unwrap_or(vec![env]);
+        if !settlements.is_empty() && budget > 0 {
+            let payment_asset = payment_asset.ok_or(SettlementError::LegacyPaymentAssetRequired)?;
+            while budget > 0 {
+                let Some(record) = settlements.pop_front() else { break };
+                Self::store_settlement(env, &record.upgrade(payment_asset));
+                budget -= 1;
+            }
+        }
+        finished &= Self::store_legacy(env, &SETTLEMENTS_KEY, &settlements, settlements.is_empty());
+
+        Ok(finished)
+    }
+
+    /// Write back what remains of a version 1 instance collection
+    ///
+    /// Returns `empty`, having removed the collection's key if so.
+    fn store_legacy<V: IntoVal<Env, Val>>(env: &Env, key: &Symbol, remaining: &V, empty: bool) -> bool {
+        if empty {
+            env.storage().instance().remove(key);
+        } else {
+            env.storage().instance().set(key, remaining);
+        }
+        empty
+    }
+
+    /// Decode a field element signal into an amount
+    ///
+    /// Fails with `NotionalOverflow` if the value does not fit a non-negative i128
+    #[cfg(any(feature = "baskets", feature = "cancellation", feature = "netting", feature = "order-controls"))]
+    fn signal_to_i128(signal: &BytesN<32>) -> Result<i128, SettlementError> {
+        let arr = signal.to_array();
+        if arr[..16].iter().any(|b| *b != 0) || arr[16] & 0x80 != 0 {
+            return Err(SettlementError::NotionalOverflow);
+        }
+        let mut low = [0u8; 16];
+        low.copy_from_slice(&arr[16..]);
+        Ok(i128::from_be_bytes(low))
+    }
+
+    /// An address as a BN254 field element: SHA-256 of its XDR with the top byte cleared
+    #[cfg(any(feature = "baskets", feature = "cancellation"))]
+    fn address_field_hash(env: &Env, address: &Address) -> BytesN<32> {
+        let mut hash = env.crypto().sha256(&address.clone().to_xdr(env)).to_array();
+        hash[0] = 0;
+        BytesN::from_array(env, &hash)
     }
 
     fn parse_public_signals(env: &Env, bytes: &Bytes) -> Result<Vec<BytesN<32>>, SettlementError> {
@@ -549,7 +2678,10 @@ impl DarkPoolSettlement {
         let mut len_bytes = [0u8; 4];
         bytes.slice(0..4).copy_into_slice(&mut len_bytes);
         pos += 4;
-        let len = u32::from_be_bytes(len_bytes) as usize;
+        let len = u32::from_be_bytes(len_bytes);
+        if len > MAX_PUBLIC_SIGNALS {
+            return Err(SettlementError::TooManySignals);
+        }
 
         let mut signals = Vec::new(env);
         for _ in 0..len {
@@ -559,8 +2691,15 @@ impl DarkPoolSettlement {
             let mut arr = [0u8; 32];
             bytes.slice(pos as u32..(pos + 32) as u32).copy_into_slice(&mut arr);
             pos += 32;
+            // A signal of s + r would pass verification as s under a different nullifier
+            if !zk_bn254::is_canonical_scalar(&arr) {
+                return Err(SettlementError::InvalidProof);
+            }
             signals.push_back(BytesN::from_array(env, &arr));
         }
+        if pos != bytes.len() as usize {
+            return Err(SettlementError::InvalidProof);
+        }
 
         Ok(signals)
     }
@@ -0,0 +1,797 @@
+//! Collateral pledges, margin locks and liquidations
+//!
+//! Only compiled with the `margin` feature.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env, Map, Symbol, Vec};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, FxOracleClient, SettlementError,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, BPS_DENOMINATOR, DEFAULT_SUB_ACCOUNT, ESCROW_KEY, FX_ORACLE_KEY,
+    FX_RATE_SCALE, LOCKED_KEY, PLEDGED_KEY, registry_wasm,
+};
+#[cfg(feature = "corporate-actions")]
+use crate::LotSource;
+
+const HAIRCUTS_KEY: Symbol = symbol_short!("haircuts");
+
+const PLEDGES_KEY: Symbol = symbol_short!("pledges");
+
+const CONVERTER_KEY: Symbol = symbol_short!("coll_conv");
+
+const MARGIN_KEY: Symbol = symbol_short!("margin");
+
+const LIQ_CONFIG_KEY: Symbol = symbol_short!("liq_cfg");
+
+const LIQUIDATIONS_KEY: Symbol = symbol_short!("liq");
+
+/// Event emitted when pledged collateral is converted to cover a buyer's payment
+///
+/// `covered` is the haircut value of `units` in the payment asset, paid
+/// into the buyer's locked payment by the collateral converter.
+#[contractevent]
+#[derive(Clone)]
+pub struct CollateralConverted {
+    #[topic]
+    pub match_id: BytesN<32>,
+    #[topic]
+    pub buyer: Address,
+    pub collateral_asset: Address,
+    pub units: i128,
+    pub covered: i128,
+}
+
+/// Liquidation parameters for cross-margin positions
+///
+/// A position is the payment a trader has locked on margin, backed by its
+/// pledged collateral. It becomes liquidatable once the collateral's
+/// haircut value falls below `maintenance_bps` of the margin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct LiquidationConfig {
+    /// Required collateral value as a share of the margin, e.g. 11_000 for 110%
+    pub maintenance_bps: u32,
+    /// Largest discount to the oracle price the auction reaches
+    pub max_slippage_bps: u32,
+    /// Seconds over which the discount grows to `max_slippage_bps`
+    pub duration: u64,
+    /// Share of each sale paid to the keeper that started the liquidation
+    pub incentive_bps: u32,
+}
+
+/// Collateral auction for an under-collateralized position
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct Liquidation {
+    pub keeper: Address,
+    pub started_at: u64,
+    /// Total paid by bidders so far, in the payment asset
+    pub proceeds: i128,
+}
+
+/// Event emitted when a keeper starts liquidating a position
+#[contractevent]
+#[derive(Clone)]
+pub struct LiquidationStarted {
+    #[topic]
+    pub trader: Address,
+    #[topic]
+    pub payment_asset: Address,
+    pub keeper: Address,
+    pub margin: i128,
+    pub collateral_value: i128,
+}
+
+/// Event emitted for each collateral sale in a liquidation auction
+#[contractevent]
+#[derive(Clone)]
+pub struct LiquidationFill {
+    #[topic]
+    pub trader: Address,
+    #[topic]
+    pub bidder: Address,
+    pub collateral_asset: Address,
+    pub units: i128,
+    pub paid: i128,
+    pub keeper_reward: i128,
+}
+
+/// Event emitted when a liquidation auction ends
+#[contractevent]
+#[derive(Clone)]
+pub struct LiquidationClosed {
+    #[topic]
+    pub trader: Address,
+    #[topic]
+    pub payment_asset: Address,
+    pub proceeds: i128,
+    pub remaining_margin: i128,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Approve an asset as collateral for payment locks, or withdraw approval
+    ///
+    /// Pledged collateral counts towards a buyer's locked payment at its
+    /// oracle value less the haircut. Withdrawing approval leaves existing
+    /// pledges releasable but stops them covering new settlements.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `asset_address` - Collateral asset
+    /// * `haircut_bps` - Discount applied to the oracle value, or `None` to withdraw approval
+    pub fn set_collateral_haircut(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        haircut_bps: Option<u32>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut haircuts: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&HAIRCUTS_KEY)
+            .unwrap_or(Map::new(&env));
+        match haircut_bps {
+            Some(bps) if bps as i128 >= BPS_DENOMINATOR => return Err(SettlementError::InvalidHaircut),
+            Some(bps) => haircuts.set(asset_address, bps),
+            None => {
+                haircuts.remove(asset_address);
+            }
+        }
+        env.storage().instance().set(&HAIRCUTS_KEY, &haircuts);
+        Ok(())
+    }
+
+    /// Get the haircut of an approved collateral asset
+    pub fn get_collateral_haircut(env: Env, asset_address: Address) -> Option<u32> {
+        let haircuts: Map<Address, u32> = env
+            .storage()
+            .instance()
+            .get(&HAIRCUTS_KEY)
+            .unwrap_or(Map::new(&env));
+        haircuts.get(asset_address)
+    }
+
+    /// Set the account that converts pledged collateral at settlement
+    ///
+    /// The converter buys a buyer's collateral at its haircut value, paying
+    /// from its own unlocked payment-asset escrow, so it must agree to the
+    /// role.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `converter` - Converting account (must authenticate), or `None` to disable conversion
+    pub fn set_collateral_converter(
+        env: Env,
+        admin: Address,
+        converter: Option<Address>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        match converter {
+            Some(converter) => {
+                converter.require_auth();
+                env.storage().instance().set(&CONVERTER_KEY, &converter);
+            }
+            None => env.storage().instance().remove(&CONVERTER_KEY),
+        }
+        Ok(())
+    }
+
+    /// Get the collateral converter, if set
+    pub fn get_collateral_converter(env: Env) -> Option<Address> {
+        env.storage().instance().get(&CONVERTER_KEY)
+    }
+
+    /// Pledge collateral towards payment locks in another asset
+    ///
+    /// Locks `amount` of the collateral in the trader's main account. When a
+    /// settlement finds the trader's locked payment short, pledged
+    /// collateral is sold to the converter to cover the difference. Pledged
+    /// units stay locked until `release_collateral`: the other unlock calls
+    /// refuse to free them.
+    ///
+    /// # Arguments
+    /// * `trader` - Pledging trader (must authenticate)
+    /// * `payment_asset` - Payment asset the pledge backs
+    /// * `collateral_asset` - Approved collateral asset
+    /// * `amount` - Collateral units to pledge
+    pub fn pledge_collateral(
+        env: Env,
+        trader: Address,
+        payment_asset: Address,
+        collateral_asset: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        trader.require_auth();
+        if amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        if collateral_asset == payment_asset
+            || Self::get_collateral_haircut(env.clone(), collateral_asset.clone()).is_none()
+        {
+            return Err(SettlementError::CollateralNotApproved);
+        }
+        let key = EscrowKey::main(&trader, &collateral_asset);
+        if Self::available_balance(&env, &key) < amount {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+
+        Self::credit_locked(&env, &key, amount);
+        let mut pledges = Self::get_pledged_collateral(env.clone(), trader.clone(), payment_asset.clone());
+        pledges.set(collateral_asset.clone(), pledges.get(collateral_asset).unwrap_or(0) + amount);
+        Self::write_pledges(&env, &trader, &payment_asset, &pledges);
+        Ok(())
+    }
+
+    /// Release pledged collateral back to the trader's available balance
+    ///
+    /// # Arguments
+    /// * `trader` - Pledging trader (must authenticate)
+    /// * `payment_asset` - Payment asset the pledge backs
+    /// * `collateral_asset` - Pledged collateral asset
+    /// * `amount` - Collateral units to release
+    pub fn release_collateral(
+        env: Env,
+        trader: Address,
+        payment_asset: Address,
+        collateral_asset: Address,
+        amount: i128,
+    ) -> Result<(), SettlementError> {
+        trader.require_auth();
+        if amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        Self::require_no_liquidation(&env, &trader, &payment_asset)?;
+        let mut pledges = Self::get_pledged_collateral(env.clone(), trader.clone(), payment_asset.clone());
+        let pledged = pledges.get(collateral_asset.clone()).unwrap_or(0);
+        if pledged < amount {
+            return Err(SettlementError::InsufficientCollateral);
+        }
+        if pledged == amount {
+            pledges.remove(collateral_asset.clone());
+        } else {
+            pledges.set(collateral_asset.clone(), pledged - amount);
+        }
+
+        // What stays pledged must still cover the margin lock
+        let margin = Self::get_margin_lock(env.clone(), trader.clone(), payment_asset.clone());
+        if margin > 0 && Self::pledges_value(&env, &payment_asset, &pledges)? < margin {
+            return Err(SettlementError::InsufficientCollateral);
+        }
+
+        Self::debit_locked(&env, &EscrowKey::main(&trader, &collateral_asset), amount)?;
+        Self::write_pledges(&env, &trader, &payment_asset, &pledges);
+        Ok(())
+    }
+
+    /// Get a trader's collateral pledged towards a payment asset, in collateral units
+    pub fn get_pledged_collateral(env: Env, trader: Address, payment_asset: Address) -> Map<Address, i128> {
+        env.storage()
+            .persistent()
+            .get(&(PLEDGES_KEY, trader, payment_asset))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Get a trader's units of a collateral asset pledged across all payment assets
+    pub fn get_pledged_units(env: Env, trader: Address, collateral_asset: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(PLEDGED_KEY, trader, collateral_asset))
+            .unwrap_or(0)
+    }
+
+    /// Haircut value of a trader's pledged collateral in the payment asset
+    ///
+    /// Pledges in assets no longer approved count as zero, and each pledge
+    /// counts only as far as the collateral is still locked.
+    pub fn get_collateral_value(env: Env, trader: Address, payment_asset: Address) -> Result<i128, SettlementError> {
+        let pledges = Self::held_pledges(&env, &trader, &payment_asset);
+        Self::pledges_value(&env, &payment_asset, &pledges)
+    }
+
+    /// Lock payment on margin, backed by pledged collateral
+    ///
+    /// Meets an order's payment lock requirement without holding the payment
+    /// asset. The pledged collateral's haircut value must cover the whole
+    /// margin lock; settlement converts collateral as the lock is used, and
+    /// a keeper may liquidate the position if the value later falls below
+    /// the maintenance margin.
+    ///
+    /// # Arguments
+    /// * `trader` - Trader locking on margin (must authenticate)
+    /// * `payment_asset` - Payment asset of the lock
+    /// * `amount` - Payment to lock
+    pub fn lock_on_margin(
+        env: Env,
+        trader: Address,
+        payment_asset: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        trader.require_auth();
+        if amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+        Self::require_no_liquidation(&env, &trader, &payment_asset)?;
+
+        let margin = Self::get_margin_lock(env.clone(), trader.clone(), payment_asset.clone())
+            .checked_add(amount)
+            .ok_or(SettlementError::NotionalOverflow)?;
+        if Self::get_collateral_value(env.clone(), trader.clone(), payment_asset.clone())? < margin {
+            return Err(SettlementError::InsufficientCollateral);
+        }
+        Self::write_margin(&env, &trader, &payment_asset, margin);
+        Ok(margin)
+    }
+
+    /// Reduce a margin lock when its order is cancelled
+    pub fn unlock_margin(
+        env: Env,
+        trader: Address,
+        payment_asset: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        trader.require_auth();
+        let margin = Self::get_margin_lock(env.clone(), trader.clone(), payment_asset.clone());
+        if amount <= 0 || amount > margin {
+            return Err(SettlementError::InsufficientLockedFunds);
+        }
+        Self::write_margin(&env, &trader, &payment_asset, margin - amount);
+        Ok(margin - amount)
+    }
+
+    /// Get the payment a trader has locked on margin
+    pub fn get_margin_lock(env: Env, trader: Address, payment_asset: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(MARGIN_KEY, trader, payment_asset))
+            .unwrap_or(0)
+    }
+
+    /// Set the liquidation parameters, or `None` to disable liquidation
+    pub fn set_liquidation_config(
+        env: Env,
+        admin: Address,
+        config: Option<LiquidationConfig>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        match config {
+            Some(config) => {
+                if (config.maintenance_bps as i128) < BPS_DENOMINATOR
+                    || config.max_slippage_bps as i128 >= BPS_DENOMINATOR
+                    || config.incentive_bps as i128 >= BPS_DENOMINATOR
+                    || config.duration == 0
+                {
+                    return Err(SettlementError::InvalidLiquidationConfig);
+                }
+                env.storage().instance().set(&LIQ_CONFIG_KEY, &config);
+            }
+            None => env.storage().instance().remove(&LIQ_CONFIG_KEY),
+        }
+        Ok(())
+    }
+
+    /// Get the liquidation parameters
+    pub fn get_liquidation_config(env: Env) -> Option<LiquidationConfig> {
+        env.storage().instance().get(&LIQ_CONFIG_KEY)
+    }
+
+    /// Whether a margin position is below its maintenance margin
+    pub fn is_liquidatable(env: Env, trader: Address, payment_asset: Address) -> Result<bool, SettlementError> {
+        let config = Self::get_liquidation_config(env.clone()).ok_or(SettlementError::LiquidationDisabled)?;
+        let margin = Self::get_margin_lock(env.clone(), trader.clone(), payment_asset.clone());
+        let value = Self::get_collateral_value(env, trader, payment_asset)?;
+        Self::below_maintenance(&config, margin, value)
+    }
+
+    /// Start liquidating an under-collateralized margin position
+    ///
+    /// Opens a descending-price auction of the position's pledged
+    /// collateral. The price starts at the oracle price and falls to the
+    /// configured maximum slippage over the auction's duration, never
+    /// lower. Pledges are frozen until the auction closes.
+    ///
+    /// # Arguments
+    /// * `keeper` - Keeper registered in the registry (must authenticate)
+    /// * `trader` - Owner of the position
+    /// * `payment_asset` - Payment asset of the margin lock
+    pub fn start_liquidation(
+        env: Env,
+        keeper: Address,
+        trader: Address,
+        payment_asset: Address,
+    ) -> Result<(), SettlementError> {
+        keeper.require_auth();
+        if !Self::has_service_role(&env, &keeper, registry_wasm::ServiceRole::Keeper) {
+            return Err(SettlementError::KeeperNotRegistered);
+        }
+        Self::require_no_liquidation(&env, &trader, &payment_asset)?;
+
+        let config = Self::get_liquidation_config(env.clone()).ok_or(SettlementError::LiquidationDisabled)?;
+        let margin = Self::get_margin_lock(env.clone(), trader.clone(), payment_asset.clone());
+        let collateral_value = Self::get_collateral_value(env.clone(), trader.clone(), payment_asset.clone())?;
+        if !Self::below_maintenance(&config, margin, collateral_value)? {
+            return Err(SettlementError::PositionHealthy);
+        }
+
+        let liquidation = Liquidation { keeper: keeper.clone(), started_at: env.ledger().timestamp(), proceeds: 0 };
+        Self::write_liquidation(&env, &trader, &payment_asset, Some(&liquidation));
+        LiquidationStarted { trader, payment_asset, keeper, margin, collateral_value }.publish(&env);
+        Ok(())
+    }
+
+    /// Buy collateral from a liquidation auction at the current price
+    ///
+    /// The bidder pays from its unlocked payment-asset escrow. The keeper's
+    /// incentive comes out of the payment; the rest is locked in the
+    /// trader's escrow in place of the margin it covers. The auction closes
+    /// once the position is back above maintenance or out of collateral.
+    ///
+    /// # Arguments
+    /// * `bidder` - Buying participant (must authenticate)
+    /// * `trader` - Owner of the liquidated position
+    /// * `payment_asset` - Payment asset of the margin lock
+    /// * `collateral_asset` - Pledged collateral to buy
+    /// * `units` - Collateral units to buy, capped at what is pledged
+    /// * `max_payment` - Most the bidder will pay
+    ///
+    /// # Returns
+    /// The payment taken from the bidder
+    pub fn bid_liquidation(
+        env: Env,
+        bidder: Address,
+        trader: Address,
+        payment_asset: Address,
+        collateral_asset: Address,
+        units: i128,
+        max_payment: i128,
+    ) -> Result<i128, SettlementError> {
+        bidder.require_auth();
+        let mut liquidation = Self::get_liquidation(env.clone(), trader.clone(), payment_asset.clone())
+            .ok_or(SettlementError::LiquidationNotFound)?;
+        let config = Self::get_liquidation_config(env.clone()).ok_or(SettlementError::LiquidationDisabled)?;
+        let mut pledges = Self::held_pledges(&env, &trader, &payment_asset);
+        let pledged = pledges.get(collateral_asset.clone()).unwrap_or(0);
+        let units = units.min(pledged);
+        if units <= 0 {
+            return Err(SettlementError::InsufficientCollateral);
+        }
+
+        // Discount to the oracle price grows linearly, capped at the maximum slippage
+        let elapsed = env.ledger().timestamp().saturating_sub(liquidation.started_at).min(config.duration);
+        let discount = Self::mul_div(config.max_slippage_bps as i128, elapsed as i128, config.duration as i128)?;
+        let rate = Self::oracle_rate(&env, &collateral_asset, &payment_asset)?;
+        let paid = Self::mul_div(
+            units,
+            rate.checked_mul(BPS_DENOMINATOR - discount).ok_or(SettlementError::NotionalOverflow)?,
+            FX_RATE_SCALE * BPS_DENOMINATOR,
+        )?;
+        if paid <= 0 || paid > max_payment {
+            return Err(SettlementError::PaymentAboveMaximum);
+        }
+        let bidder_payment = EscrowKey::main(&bidder, &payment_asset);
+        if Self::available_balance(&env, &bidder_payment) < paid {
+            return Err(SettlementError::InsufficientEscrow);
+        }
+        let collateral = EscrowKey::main(&trader, &collateral_asset);
+        Self::check_transfer(&env, &collateral, units)?;
+
+        let keeper_reward = Self::mul_div(paid, config.incentive_bps as i128, BPS_DENOMINATOR)?;
+        let net = paid - keeper_reward;
+        Self::debit_escrow(&env, &bidder_payment, paid)?;
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(&env, &bidder, &payment_asset, paid);
+        Self::credit_proceeds(&env, &EscrowKey::main(&liquidation.keeper, &payment_asset), keeper_reward);
+        Self::commit_transfer(&env, &collateral, &EscrowKey::main(&bidder, &collateral_asset), units);
+
+        // Proceeds replace the margin they cover with real locked payment
+        let trader_payment = EscrowKey::main(&trader, &payment_asset);
+        let margin = Self::get_margin_lock(env.clone(), trader.clone(), payment_asset.clone());
+        Self::credit_escrow(&env, &trader_payment, net);
+        #[cfg(feature = "corporate-actions")]
+        Self::open_lot(&env, &trader, &payment_asset, net, LotSource::Settlement);
+        Self::credit_locked(&env, &trader_payment, net.min(margin));
+        let margin = margin - net.min(margin);
+        Self::write_margin(&env, &trader, &payment_asset, margin);
+        if pledged == units {
+            pledges.remove(collateral_asset.clone());
+        } else {
+            pledges.set(collateral_asset.clone(), pledged - units);
+        }
+        Self::write_pledges(&env, &trader, &payment_asset, &pledges);
+
+        LiquidationFill {
+            trader: trader.clone(),
+            bidder,
+            collateral_asset,
+            units,
+            paid,
+            keeper_reward,
+        }
+        .publish(&env);
+
+        liquidation.proceeds += paid;
+        let value = Self::pledges_value(&env, &payment_asset, &pledges)?;
+        if pledges.is_empty() || !Self::below_maintenance(&config, margin, value)? {
+            Self::write_liquidation(&env, &trader, &payment_asset, None);
+            LiquidationClosed {
+                trader,
+                payment_asset,
+                proceeds: liquidation.proceeds,
+                remaining_margin: margin,
+            }
+            .publish(&env);
+        } else {
+            Self::write_liquidation(&env, &trader, &payment_asset, Some(&liquidation));
+        }
+        Ok(paid)
+    }
+
+    /// Get the open liquidation auction for a margin position
+    pub fn get_liquidation(env: Env, trader: Address, payment_asset: Address) -> Option<Liquidation> {
+        env.storage().persistent().get(&(LIQUIDATIONS_KEY, trader, payment_asset))
+    }
+
+    /// Plan which pledged collateral covers a shortfall in the buyer's locked payment
+    ///
+    /// Returns `(collateral, units, covered)` draws in asset order; the
+    /// covered amounts sum to the shortfall. Nothing is written, so the
+    /// plan can be checked before the proof and applied after it.
+    pub(crate) fn plan_collateral_draws(
+        env: &Env,
+        buyer_payment: &EscrowKey,
+        payment_amount: i128,
+    ) -> Result<Vec<(Address, i128, i128)>, SettlementError> {
+        let mut draws = vec![env];
+        let held = Self::read_balance(env, &LOCKED_KEY, buyer_payment)
+            .min(Self::read_balance(env, &ESCROW_KEY, buyer_payment));
+        let mut shortfall = payment_amount - held;
+        if shortfall <= 0 {
+            return Ok(draws);
+        }
+
+        Self::require_no_liquidation(env, &buyer_payment.participant, &buyer_payment.asset)?;
+        let pledges = Self::held_pledges(env, &buyer_payment.participant, &buyer_payment.asset);
+        for (asset, pledged) in pledges.iter() {
+            if shortfall == 0 {
+                break;
+            }
+            let Some(rate) = Self::collateral_rate(env, &asset, &buyer_payment.asset)? else {
+                continue;
+            };
+            let value = Self::mul_div(pledged, rate, FX_RATE_SCALE * BPS_DENOMINATOR)?;
+            if value == 0 {
+                continue;
+            }
+            let covered = value.min(shortfall);
+            // Round units up so the converter never pays more than the haircut value
+            let units = if covered == value {
+                pledged
+            } else {
+                let scaled = covered
+                    .checked_mul(FX_RATE_SCALE * BPS_DENOMINATOR)
+                    .ok_or(SettlementError::NotionalOverflow)?;
+                (scaled + rate - 1) / rate
+            };
+            Self::check_transfer(env, &EscrowKey::main(&buyer_payment.participant, &asset), units)?;
+            draws.push_back((asset, units, covered));
+            shortfall -= covered;
+        }
+        if shortfall > 0 {
+            // Nothing to convert: report the payment leg as underfunded
+            return Err(SettlementError::InsufficientLockedFunds);
+        }
+
+        let converter = Self::get_collateral_converter(env.clone()).ok_or(SettlementError::CollateralConverterNotSet)?;
+        let total = payment_amount - held;
+        if Self::available_balance(env, &EscrowKey::main(&converter, &buyer_payment.asset)) < total {
+            return Err(SettlementError::ConverterLiquidityInsufficient);
+        }
+        Ok(draws)
+    }
+
+    /// Sell planned collateral draws to the converter, topping up the buyer's locked payment
+    pub(crate) fn convert_collateral(
+        env: &Env,
+        match_id: &BytesN<32>,
+        buyer_payment: &EscrowKey,
+        draws: &Vec<(Address, i128, i128)>,
+    ) {
+        if draws.is_empty() {
+            return;
+        }
+        let buyer = &buyer_payment.participant;
+        let converter = Self::get_collateral_converter(env.clone()).unwrap();
+        let converter_payment = EscrowKey::main(&converter, &buyer_payment.asset);
+        let mut pledges = Self::get_pledged_collateral(env.clone(), buyer.clone(), buyer_payment.asset.clone());
+        let mut total = 0i128;
+        for (asset, units, covered) in draws.iter() {
+            let pledged = pledges.get(asset.clone()).unwrap_or(0);
+            if pledged == units {
+                pledges.remove(asset.clone());
+            } else {
+                pledges.set(asset.clone(), pledged - units);
+            }
+            Self::commit_transfer(env, &EscrowKey::main(buyer, &asset), &EscrowKey::main(&converter, &asset), units);
+            total += covered;
+            CollateralConverted {
+                match_id: match_id.clone(),
+                buyer: buyer.clone(),
+                collateral_asset: asset,
+                units,
+                covered,
+            }
+            .publish(env);
+        }
+        Self::write_pledges(env, buyer, &buyer_payment.asset, &pledges);
+        let margin = Self::get_margin_lock(env.clone(), buyer.clone(), buyer_payment.asset.clone());
+        Self::write_margin(env, buyer, &buyer_payment.asset, margin - total.min(margin));
+
+        let escrow = Self::read_balance(env, &ESCROW_KEY, &converter_payment);
+        Self::write_balance(env, &ESCROW_KEY, &converter_payment, escrow - total);
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(env, &converter, &buyer_payment.asset, total);
+        Self::credit_escrow(env, buyer_payment, total);
+        Self::credit_locked(env, buyer_payment, total);
+    }
+
+    /// Oracle rate of an approved collateral asset in the payment asset, net of its haircut
+    ///
+    /// Scaled by `FX_RATE_SCALE * BPS_DENOMINATOR`; `None` when the asset is
+    /// no longer approved.
+    fn collateral_rate(env: &Env, asset: &Address, payment_asset: &Address) -> Result<Option<i128>, SettlementError> {
+        let Some(haircut) = Self::get_collateral_haircut(env.clone(), asset.clone()) else {
+            return Ok(None);
+        };
+        Self::oracle_rate(env, asset, payment_asset)?
+            .checked_mul(BPS_DENOMINATOR - haircut as i128)
+            .map(Some)
+            .ok_or(SettlementError::NotionalOverflow)
+    }
+
+    /// Fresh oracle rate of `asset` in `payment_asset`, scaled by `FX_RATE_SCALE`
+    pub(crate) fn oracle_rate(env: &Env, asset: &Address, payment_asset: &Address) -> Result<i128, SettlementError> {
+        Self::require_fresh_oracle(env, asset)?;
+        let oracle_address: Address = env
+            .storage()
+            .instance()
+            .get(&FX_ORACLE_KEY)
+            .ok_or(SettlementError::FxOracleNotSet)?;
+        let rate = FxOracleClient::new(env, &oracle_address).get_rate(asset, payment_asset);
+        if rate <= 0 {
+            return Err(SettlementError::InvalidFxRate);
+        }
+        Ok(rate)
+    }
+
+    /// A trader's pledges towards a payment asset, capped at the collateral still locked
+    ///
+    /// Settlements can spend locked collateral after it was pledged, so the
+    /// recorded pledges may exceed what the trader's main account holds.
+    fn held_pledges(env: &Env, trader: &Address, payment_asset: &Address) -> Map<Address, i128> {
+        let pledges = Self::get_pledged_collateral(env.clone(), trader.clone(), payment_asset.clone());
+        let mut held = Map::new(env);
+        for (asset, units) in pledges.iter() {
+            let key = EscrowKey::main(trader, &asset);
+            let units = units
+                .min(Self::read_balance(env, &LOCKED_KEY, &key))
+                .min(Self::read_balance(env, &ESCROW_KEY, &key));
+            if units > 0 {
+                held.set(asset, units);
+            }
+        }
+        held
+    }
+
+    /// Haircut value of a set of pledges in the payment asset
+    fn pledges_value(env: &Env, payment_asset: &Address, pledges: &Map<Address, i128>) -> Result<i128, SettlementError> {
+        let mut value = 0i128;
+        for (asset, units) in pledges.iter() {
+            if let Some(rate) = Self::collateral_rate(env, &asset, payment_asset)? {
+                value = value
+                    .checked_add(Self::mul_div(units, rate, FX_RATE_SCALE * BPS_DENOMINATOR)?)
+                    .ok_or(SettlementError::NotionalOverflow)?;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Whether collateral worth `value` leaves `margin` below maintenance
+    fn below_maintenance(config: &LiquidationConfig, margin: i128, value: i128) -> Result<bool, SettlementError> {
+        let required = Self::mul_div(margin, config.maintenance_bps as i128, BPS_DENOMINATOR)?;
+        Ok(margin > 0 && value < required)
+    }
+
+    /// Fail if unlocking `amount` from an account would free pledged collateral
+    ///
+    /// Pledges lock collateral in the main account, so other sub-accounts
+    /// are never affected.
+    pub(crate) fn require_unpledged(env: &Env, key: &EscrowKey, amount: i128) -> Result<(), SettlementError> {
+        if key.sub_account != DEFAULT_SUB_ACCOUNT {
+            return Ok(());
+        }
+        let pledged = Self::get_pledged_units(env.clone(), key.participant.clone(), key.asset.clone());
+        if pledged > 0 && Self::read_balance(env, &LOCKED_KEY, key) - amount < pledged {
+            return Err(SettlementError::CollateralPledged);
+        }
+        Ok(())
+    }
+
+    fn require_no_liquidation(env: &Env, trader: &Address, payment_asset: &Address) -> Result<(), SettlementError> {
+        if Self::get_liquidation(env.clone(), trader.clone(), payment_asset.clone()).is_some() {
+            return Err(SettlementError::LiquidationInProgress);
+        }
+        Ok(())
+    }
+
+    fn write_margin(env: &Env, trader: &Address, payment_asset: &Address, margin: i128) {
+        let entry = (MARGIN_KEY, trader.clone(), payment_asset.clone());
+        if margin == 0 {
+            env.storage().persistent().remove(&entry);
+            return;
+        }
+        env.storage().persistent().set(&entry, &margin);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    fn write_liquidation(env: &Env, trader: &Address, payment_asset: &Address, liquidation: Option<&Liquidation>) {
+        let entry = (LIQUIDATIONS_KEY, trader.clone(), payment_asset.clone());
+        match liquidation {
+            Some(liquidation) => {
+                env.storage().persistent().set(&entry, liquidation);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+            }
+            None => env.storage().persistent().remove(&entry),
+        }
+    }
+
+    /// Store a trader's pledges towards a payment asset
+    ///
+    /// Keeps each collateral asset's total across payment assets in step.
+    fn write_pledges(env: &Env, trader: &Address, payment_asset: &Address, pledges: &Map<Address, i128>) {
+        let previous = Self::get_pledged_collateral(env.clone(), trader.clone(), payment_asset.clone());
+        for (asset, units) in previous.iter() {
+            Self::add_pledged_units(env, trader, &asset, pledges.get(asset.clone()).unwrap_or(0) - units);
+        }
+        for (asset, units) in pledges.iter() {
+            if !previous.contains_key(asset.clone()) {
+                Self::add_pledged_units(env, trader, &asset, units);
+            }
+        }
+
+        let entry = (PLEDGES_KEY, trader.clone(), payment_asset.clone());
+        if pledges.is_empty() {
+            env.storage().persistent().remove(&entry);
+            return;
+        }
+        env.storage().persistent().set(&entry, pledges);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    fn add_pledged_units(env: &Env, trader: &Address, collateral_asset: &Address, delta: i128) {
+        if delta == 0 {
+            return;
+        }
+        let entry = (PLEDGED_KEY, trader.clone(), collateral_asset.clone());
+        let units = Self::get_pledged_units(env.clone(), trader.clone(), collateral_asset.clone()) + delta;
+        if units == 0 {
+            env.storage().persistent().remove(&entry);
+            return;
+        }
+        env.storage().persistent().set(&entry, &units);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+}
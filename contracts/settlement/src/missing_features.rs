@@ -0,0 +1,144 @@
+//! Refusals for settings left behind by features this build leaves out
+//!
+//! A setting written by a build with a feature stays in storage after an
+//! upgrade to a build without it, where the feature's checks no longer run.
+//! Rather than silently dropping a rate limit, an allowlist, a cap, record
+//! privacy, a pledge or a redemption delay, the entry points that relied on
+//! the check fail with `FeatureNotBuilt` until a build with the feature
+//! clears the setting.
+//!
+//! Only compiled when at least one of those features is left out; each
+//! check takes the place of its feature's own under the same name.
+
+use soroban_sdk::Env;
+#[cfg(any(
+    not(feature = "order-controls"),
+    not(feature = "records"),
+    not(feature = "withdrawal-queue")
+))]
+use soroban_sdk::Address;
+#[cfg(not(feature = "order-controls"))]
+use soroban_sdk::{BytesN, Val};
+#[cfg(any(not(feature = "order-controls"), not(feature = "withdrawal-queue")))]
+use soroban_sdk::Map;
+
+use crate::{DarkPoolSettlement, SettlementError};
+#[cfg(any(not(feature = "order-controls"), not(feature = "margin")))]
+use crate::EscrowKey;
+#[cfg(not(feature = "margin"))]
+use crate::{DEFAULT_SUB_ACCOUNT, PLEDGED_KEY};
+#[cfg(not(feature = "order-controls"))]
+use crate::{COUNTERPARTY_KEY, INVENTORY_KEY, LAST_LOOK_KEY, MAX_NOTIONAL_KEY, TICKS_KEY};
+#[cfg(not(feature = "records"))]
+use crate::{RECORD_FORMAT_KEY, RECORD_PRIVACY_KEY};
+#[cfg(not(feature = "withdrawal-queue"))]
+use crate::REDEEM_DELAY_KEY;
+#[cfg(not(feature = "relayers"))]
+use crate::{RATE_LIMIT_KEY, REGISTERED_RELAYERS_KEY};
+
+impl DarkPoolSettlement {
+    /// Refuse direct settlements once a relayer rate limit or registration is set
+    #[cfg(not(feature = "relayers"))]
+    pub(crate) fn require_unmetered(env: &Env) -> Result<(), SettlementError> {
+        let registered: bool = env.storage().instance().get(&REGISTERED_RELAYERS_KEY).unwrap_or(false);
+        if registered || env.storage().instance().has(&RATE_LIMIT_KEY) {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(())
+    }
+
+    /// Refuse settlements between parties either of which set a counterparty policy
+    #[cfg(not(feature = "order-controls"))]
+    pub(crate) fn check_counterparties(env: &Env, buyer: &Address, seller: &Address) -> Result<(), SettlementError> {
+        for party in [buyer, seller] {
+            if env.storage().persistent().has(&(COUNTERPARTY_KEY, party.clone())) {
+                return Err(SettlementError::FeatureNotBuilt);
+            }
+        }
+        Ok(())
+    }
+
+    /// Refuse settlements once a last-look window is set
+    #[cfg(not(feature = "order-controls"))]
+    pub(crate) fn take_confirmed_match(
+        env: &Env,
+        _match_id: &BytesN<32>,
+        _buyer: &Address,
+        _seller: &Address,
+    ) -> Result<(), SettlementError> {
+        let window: u32 = env.storage().instance().get(&LAST_LOOK_KEY).unwrap_or(0);
+        if window != 0 {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(())
+    }
+
+    /// Refuse settlements of an asset with a tick size
+    #[cfg(not(feature = "order-controls"))]
+    pub(crate) fn require_no_tick_size(env: &Env, asset_address: &Address) -> Result<(), SettlementError> {
+        let ticks: Map<Address, Val> = env.storage().instance().get(&TICKS_KEY).unwrap_or(Map::new(env));
+        if ticks.contains_key(asset_address.clone()) {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(())
+    }
+
+    /// Refuse settlements paid in an asset with a notional cap
+    #[cfg(not(feature = "order-controls"))]
+    pub(crate) fn check_notional(env: &Env, payment_asset: &Address, _amount: i128) -> Result<(), SettlementError> {
+        let caps: Map<Address, i128> = env.storage().instance().get(&MAX_NOTIONAL_KEY).unwrap_or(Map::new(env));
+        if caps.contains_key(payment_asset.clone()) {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(())
+    }
+
+    /// Refuse deliveries to an account whose owner set an inventory limit for the asset
+    #[cfg(not(feature = "order-controls"))]
+    pub(crate) fn check_inventory(env: &Env, to: &EscrowKey, _amount: i128) -> Result<(), SettlementError> {
+        let entry = (INVENTORY_KEY, to.participant.clone(), to.asset.clone());
+        if env.storage().persistent().has(&entry) {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(())
+    }
+
+    /// Let everyone read records, unless record privacy or commitment-only records are set
+    #[cfg(not(feature = "records"))]
+    pub(crate) fn require_record_viewer(env: &Env, _viewer: &Option<Address>) -> Result<bool, SettlementError> {
+        let private: bool = env.storage().instance().get(&RECORD_PRIVACY_KEY).unwrap_or(false);
+        // `RecordFormat::Full` is stored as 0
+        let format: u32 = env.storage().instance().get(&RECORD_FORMAT_KEY).unwrap_or(0);
+        if private || format != 0 {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(true)
+    }
+
+    /// Refuse unlocking main-account funds of an asset the trader has pledged
+    #[cfg(not(feature = "margin"))]
+    pub(crate) fn require_unpledged(env: &Env, key: &EscrowKey, _amount: i128) -> Result<(), SettlementError> {
+        if key.sub_account != DEFAULT_SUB_ACCOUNT {
+            return Ok(());
+        }
+        let pledged: i128 = env
+            .storage()
+            .persistent()
+            .get(&(PLEDGED_KEY, key.participant.clone(), key.asset.clone()))
+            .unwrap_or(0);
+        if pledged > 0 {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(())
+    }
+
+    /// Refuse withdrawals of an asset with a redemption delay
+    #[cfg(not(feature = "withdrawal-queue"))]
+    pub(crate) fn require_no_redemption_delay(env: &Env, asset_address: &Address) -> Result<(), SettlementError> {
+        let delays: Map<Address, u64> = env.storage().instance().get(&REDEEM_DELAY_KEY).unwrap_or(Map::new(env));
+        if delays.contains_key(asset_address.clone()) {
+            return Err(SettlementError::FeatureNotBuilt);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+//! Size buckets and netting of residual fills
+//!
+//! Only compiled with the `netting` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, Symbol};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, MAX_PAGE_SIZE,
+};
+
+const BUCKETS_KEY: Symbol = symbol_short!("buckets");
+
+const RESIDUALS_KEY: Symbol = symbol_short!("residuals");
+
+const RES_QUEUES_KEY: Symbol = symbol_short!("res_queue");
+
+const NETTING_KEY: Symbol = symbol_short!("netting");
+
+/// Privacy mode where executed sizes only reveal a bucket
+///
+/// Valid quantities are `base_unit * 2^k`. Quantities below `base_unit` are
+/// residuals: they are held by the contract and paid out in a netting round
+/// at most once every `netting_ledgers` ledgers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SizeBuckets {
+    pub base_unit: i128,
+    pub netting_ledgers: u32,
+}
+
+/// A residual fill awaiting the next netting round
+///
+/// Both legs are debited when the fill is queued, so the amounts below are
+/// already held by the contract.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ResidualFill {
+    pub match_id: BytesN<32>,
+    /// Buyer's account receiving the asset
+    pub buyer: EscrowKey,
+    /// Seller's account receiving the payment
+    pub seller: EscrowKey,
+    pub quantity: i128,
+    /// Payment owed to the seller, net of the seller's fee
+    pub seller_proceeds: i128,
+}
+
+/// Bounds of an asset's residual queue; ids in `head..tail` are unpaid
+///
+/// A netting round pays out ids up to `round_end`; fills queued while it
+/// runs wait for the next round.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct ResidualQueue {
+    pub head: u64,
+    pub tail: u64,
+    pub round_end: u64,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Enable or disable bucketed order sizes for an asset
+    ///
+    /// In bucket mode the proof's quantity signal must match the settled
+    /// quantity, and that quantity must be a bucket or a residual.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `asset_address` - The RWA token
+    /// * `buckets` - Bucket configuration, or `None` to settle exact sizes
+    pub fn set_size_buckets(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        buckets: Option<SizeBuckets>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut all: Map<Address, SizeBuckets> = env
+            .storage()
+            .instance()
+            .get(&BUCKETS_KEY)
+            .unwrap_or(Map::new(&env));
+        match buckets {
+            Some(buckets) => {
+                if buckets.base_unit <= 0 {
+                    return Err(SettlementError::InvalidAmount);
+                }
+                all.set(asset_address, buckets);
+            }
+            None => {
+                all.remove(asset_address);
+            }
+        }
+        env.storage().instance().set(&BUCKETS_KEY, &all);
+        Ok(())
+    }
+
+    /// Get the bucket configuration for an asset
+    pub fn get_size_buckets(env: Env, asset_address: Address) -> Option<SizeBuckets> {
+        let all: Map<Address, SizeBuckets> = env
+            .storage()
+            .instance()
+            .get(&BUCKETS_KEY)
+            .unwrap_or(Map::new(&env));
+        all.get(asset_address)
+    }
+
+    /// Get the bounds of an asset's residual queue
+    pub fn get_residual_queue(env: Env, asset_address: Address) -> ResidualQueue {
+        let queues: Map<Address, ResidualQueue> = env
+            .storage()
+            .instance()
+            .get(&RES_QUEUES_KEY)
+            .unwrap_or(Map::new(&env));
+        queues.get(asset_address).unwrap_or_default()
+    }
+
+    /// Get an unpaid residual fill
+    pub fn get_residual(env: Env, asset_address: Address, id: u64) -> Option<ResidualFill> {
+        env.storage().persistent().get(&(RESIDUALS_KEY, asset_address, id))
+    }
+
+    /// Pay out a batch of an asset's queued residual fills
+    ///
+    /// Permissionless. Once the netting interval has passed, a call starts a
+    /// round covering every fill queued so far; further calls finish the
+    /// round's remaining fills without waiting. Fills in a batch are
+    /// aggregated so each recipient account receives a single net credit per
+    /// batch. Netted legs are credited immediately, ignoring any finality
+    /// delay on the asset.
+    ///
+    /// # Arguments
+    /// * `asset_address` - Asset whose residuals to net
+    /// * `limit` - Maximum fills to pay, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * Number of residual fills paid out
+    pub fn run_netting(env: Env, asset_address: Address, limit: u32) -> Result<u32, SettlementError> {
+        let mut queue = Self::get_residual_queue(env.clone(), asset_address.clone());
+        if queue.head >= queue.round_end {
+            let buckets = Self::get_size_buckets(env.clone(), asset_address.clone())
+                .ok_or(SettlementError::NettingNotDue)?;
+            let mut last_rounds: Map<Address, u32> = env
+                .storage()
+                .instance()
+                .get(&NETTING_KEY)
+                .unwrap_or(Map::new(&env));
+            let now = env.ledger().sequence();
+            if now < last_rounds.get(asset_address.clone()).unwrap_or(0) + buckets.netting_ledgers {
+                return Err(SettlementError::NettingNotDue);
+            }
+            last_rounds.set(asset_address.clone(), now);
+            env.storage().instance().set(&NETTING_KEY, &last_rounds);
+            queue.round_end = queue.tail;
+        }
+
+        let mut credits: Map<EscrowKey, i128> = Map::new(&env);
+        let mut paid = 0u32;
+        while paid < limit.min(MAX_PAGE_SIZE) && queue.head < queue.round_end {
+            let entry = (RESIDUALS_KEY, asset_address.clone(), queue.head);
+            let fill: ResidualFill = env.storage().persistent().get(&entry).unwrap();
+            env.storage().persistent().remove(&entry);
+            credits.set(fill.buyer.clone(), credits.get(fill.buyer.clone()).unwrap_or(0) + fill.quantity);
+            credits.set(
+                fill.seller.clone(),
+                credits.get(fill.seller.clone()).unwrap_or(0) + fill.seller_proceeds,
+            );
+            queue.head += 1;
+            paid += 1;
+        }
+        for (key, amount) in credits.iter() {
+            Self::credit_proceeds(&env, &key, amount);
+        }
+
+        Self::store_residual_queue(&env, &asset_address, &queue);
+        Ok(paid)
+    }
+
+    /// Check a quantity is `base_unit * 2^k` or a residual below `base_unit`
+    pub(crate) fn check_bucket(buckets: &SizeBuckets, quantity: i128) -> Result<(), SettlementError> {
+        if quantity < buckets.base_unit {
+            return Ok(());
+        }
+        if quantity % buckets.base_unit != 0 || !((quantity / buckets.base_unit) as u128).is_power_of_two() {
+            return Err(SettlementError::QuantityNotBucketed);
+        }
+        Ok(())
+    }
+
+    /// Debit both legs of a residual fill and queue it for netting
+    ///
+    /// Fees are collected now; the seller's proceeds are recorded net of its fee.
+    pub(crate) fn queue_residual(
+        env: &Env,
+        relayer: Option<&Address>,
+        match_id: &BytesN<32>,
+        seller_asset: &EscrowKey,
+        buyer_asset: &EscrowKey,
+        quantity: i128,
+        buyer_payment: &EscrowKey,
+        seller_payment: &EscrowKey,
+        payment_amount: i128,
+    ) -> Result<(), SettlementError> {
+        Self::check_transfer(env, seller_asset, quantity)?;
+        Self::check_inventory(env, buyer_asset, quantity)?;
+        let mut fees = Self::check_payment(env, buyer_payment, seller_payment, payment_amount)?;
+        fees.relayer = relayer.cloned();
+
+        Self::commit_debit(env, seller_asset, quantity);
+        Self::commit_debit(env, buyer_payment, payment_amount);
+        #[cfg(feature = "fees")]
+        Self::collect_fees(env, buyer_payment, seller_payment, &fees);
+
+        let mut queue = Self::get_residual_queue(env.clone(), seller_asset.asset.clone());
+        let entry = (RESIDUALS_KEY, seller_asset.asset.clone(), queue.tail);
+        env.storage().persistent().set(&entry, &ResidualFill {
+            match_id: match_id.clone(),
+            buyer: buyer_asset.clone(),
+            seller: seller_payment.clone(),
+            quantity,
+            seller_proceeds: payment_amount - fees.seller_fee_from_payment(),
+        });
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        queue.tail += 1;
+        Self::store_residual_queue(env, &seller_asset.asset, &queue);
+        Ok(())
+    }
+
+    fn store_residual_queue(env: &Env, asset_address: &Address, queue: &ResidualQueue) {
+        let mut queues: Map<Address, ResidualQueue> = env
+            .storage()
+            .instance()
+            .get(&RES_QUEUES_KEY)
+            .unwrap_or(Map::new(env));
+        queues.set(asset_address.clone(), queue.clone());
+        env.storage().instance().set(&RES_QUEUES_KEY, &queues);
+    }
+}
@@ -0,0 +1,551 @@
+//! Operator views, guardians, admin recovery, clawbacks and solvency audits
+//!
+//! Only compiled with the `ops` feature.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, symbol_short, token, vec, xdr::ToXdr, Address, Bytes, BytesN, Env, Map,
+    Symbol, Vec,
+};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError, ADMIN_KEY,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, BASKET_VK_KEY, CANCEL_VK_KEY, DUST_KEY, ESCROW_KEY, HEARTBEAT_KEY,
+    INSTANCE_LIVE_KEY, LOCKED_KEY, MAX_PAGE_SIZE, NULLIFIER_COUNT_KEY, PAUSED_KEY, SETTLEMENT_VK_KEY, STORAGE_VERSION,
+    registry_wasm, verifier_wasm,
+};
+#[cfg(feature = "bridge")]
+use crate::OPEN_LOCKS_KEY;
+#[cfg(feature = "delivery")]
+use crate::{FROZEN_KEY, PENDING_IDX_KEY};
+#[cfg(feature = "fees")]
+use crate::FEES_COLLECTED_KEY;
+
+const GUARDIANS_KEY: Symbol = symbol_short!("guardians");
+
+const VOLUME_KEY: Symbol = symbol_short!("volume");
+
+const RELAYER_COUNTS_KEY: Symbol = symbol_short!("rly_count");
+
+/// Length of the epochs settled payment volume is tallied over (one day)
+pub const VOLUME_EPOCH_SECONDS: u64 = 86_400;
+
+/// Solvency check of recorded escrow against the contract's token balance
+///
+/// `discrepancy` is `token_balance - recorded_total`; a negative value means
+/// the recorded escrow balances are not fully backed by tokens held.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct BalanceAudit {
+    pub asset: Address,
+    pub recorded_total: i128,
+    pub token_balance: i128,
+    pub discrepancy: i128,
+}
+
+/// Event emitted by `audit_balances`
+#[contractevent]
+#[derive(Clone)]
+pub struct DiscrepancyReport {
+    #[topic]
+    pub asset: Address,
+    pub recorded_total: i128,
+    pub token_balance: i128,
+    pub discrepancy: i128,
+}
+
+/// Event emitted when an issuer clawback is written off against escrow
+#[contractevent]
+#[derive(Clone)]
+pub struct ClawbackRecorded {
+    #[topic]
+    pub participant: Address,
+    #[topic]
+    pub asset: Address,
+    pub amount: i128,
+    pub escrow_balance: i128,
+}
+
+/// Event emitted when guardians rotate an unresponsive admin
+#[contractevent]
+#[derive(Clone)]
+pub struct AdminRecovered {
+    #[topic]
+    pub old_admin: Address,
+    pub new_admin: Address,
+}
+
+/// Guardians able to replace the admin after a missed heartbeat
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct GuardianSet {
+    pub guardians: Vec<Address>,
+    /// Distinct guardian approvals needed to rotate the admin
+    pub threshold: u32,
+    /// Seconds without an admin heartbeat before recovery opens
+    pub recovery_period: u64,
+}
+
+/// Snapshot of the settlement contract's operational state for monitoring
+#[derive(Clone)]
+#[contracttype]
+pub struct HealthReport {
+    pub paused_assets: Vec<Address>,
+    /// The verifier contract answered a probe call
+    pub verifier_reachable: bool,
+    /// The registry contract answered a probe call
+    pub registry_reachable: bool,
+    pub settlement_vk_set: bool,
+    pub basket_vk_set: bool,
+    pub nullifier_count: u64,
+    /// Stored layout is older than this build and needs `migrate`
+    pub migration_required: bool,
+    /// Ledgers until instance storage is archived, if it has been extended
+    /// since the contract started tracking it
+    pub instance_ttl_remaining: Option<u32>,
+}
+
+/// Operations dashboard state, bundled so it takes one simulation call
+#[derive(Clone)]
+#[contracttype]
+pub struct OperatorSnapshot {
+    /// Fees collected per asset
+    pub fees_collected: Map<Address, i128>,
+    /// Rounding dust per asset, awaiting `claim_dust`
+    pub dust: Map<Address, i128>,
+    pub paused_assets: Vec<Address>,
+    /// Matches in the scanned page whose delayed legs a watchtower has frozen
+    pub pending_disputes: u32,
+    /// Open bridge locks in the scanned page past their timelock, awaiting refund
+    pub expired_locks: u32,
+    /// `start` for the next page, if either index goes past this one
+    pub next_start: Option<u32>,
+    /// Index of the last complete volume epoch
+    pub epoch: u64,
+    /// Payment volume settled during `epoch`, per payment asset
+    pub epoch_volume: Map<Address, i128>,
+}
+
+/// Payment volume settled during one epoch of `VOLUME_EPOCH_SECONDS`
+#[derive(Clone)]
+#[contracttype]
+pub struct EpochVolume {
+    pub epoch: u64,
+    pub volume: Map<Address, i128>,
+}
+
+/// Event emitted when `verify_solvency` finds a deficit and pauses the asset
+#[contractevent]
+#[derive(Clone)]
+pub struct SolvencyBreach {
+    #[topic]
+    pub asset: Address,
+    pub tracked_escrow: i128,
+    pub token_balance: i128,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Report the contract's operational state
+    ///
+    /// Intended for monitoring via simulation: the verifier and registry are
+    /// probed with `try_` calls, so an archived or missing dependency shows up
+    /// as unreachable instead of failing the whole report.
+    pub fn health(env: Env) -> HealthReport {
+        let paused: Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&PAUSED_KEY)
+            .unwrap_or(Map::new(&env));
+
+        // A contract error still proves the verifier is deployed and live
+        let verifier_client = verifier_wasm::Client::new(&env, &Self::get_verifier(env.clone()));
+        let empty = Bytes::new(&env);
+        let verifier_reachable = !matches!(
+            verifier_client.try_verify_proof_bytes(&empty, &empty, &empty),
+            Err(Err(_))
+        );
+        let registry_client = registry_wasm::Client::new(&env, &Self::get_registry(env.clone()));
+        let registry_reachable = registry_client.try_get_whitelist_root().is_ok();
+
+        let settlement_vk: Option<Bytes> = env.storage().instance().get(&SETTLEMENT_VK_KEY);
+        let instance_live_until: Option<u32> = env.storage().instance().get(&INSTANCE_LIVE_KEY);
+
+        HealthReport {
+            paused_assets: paused.keys(),
+            verifier_reachable,
+            registry_reachable,
+            settlement_vk_set: settlement_vk.is_some_and(|vk| !vk.is_empty()),
+            basket_vk_set: env.storage().instance().has(&BASKET_VK_KEY),
+            nullifier_count: env.storage().instance().get(&NULLIFIER_COUNT_KEY).unwrap_or(0),
+            migration_required: Self::get_storage_version(env.clone()) < STORAGE_VERSION,
+            instance_ttl_remaining: instance_live_until
+                .map(|live_until| live_until.saturating_sub(env.ledger().sequence())),
+        }
+    }
+
+    /// Report the state the operations dashboard polls, in one call
+    ///
+    /// Intended for simulation. Volume covers the last complete epoch, so
+    /// it does not move while the current one fills. Disputes and expired
+    /// locks are counted over positions `start..start + limit` of the
+    /// pending-delivery and open-lock indexes; page on with `next_start`
+    /// and add the counts up.
+    ///
+    /// # Arguments
+    /// * `start` - First index position to scan
+    /// * `limit` - Maximum positions to scan, capped at `MAX_PAGE_SIZE`
+    pub fn get_operator_snapshot(env: Env, start: u32, limit: u32) -> OperatorSnapshot {
+        let instance = env.storage().instance();
+        let paused: Map<Address, bool> = instance.get(&PAUSED_KEY).unwrap_or(Map::new(&env));
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE));
+        let indexed = 0;
+
+        #[cfg(feature = "delivery")]
+        let indexed = indexed.max(Self::index_len(&env, &PENDING_IDX_KEY));
+        #[cfg(feature = "delivery")]
+        let pending_disputes = (start..end.min(Self::index_len(&env, &PENDING_IDX_KEY)))
+            .filter_map(|position| Self::index_get(&env, &PENDING_IDX_KEY, position))
+            .filter(|match_id| env.storage().persistent().has(&(FROZEN_KEY, match_id.clone())))
+            .count() as u32;
+        #[cfg(not(feature = "delivery"))]
+        let pending_disputes = 0;
+
+        #[cfg(feature = "bridge")]
+        let indexed = indexed.max(Self::index_len(&env, &OPEN_LOCKS_KEY));
+        #[cfg(feature = "bridge")]
+        let expired_locks = (start..end.min(Self::index_len(&env, &OPEN_LOCKS_KEY)))
+            .filter_map(|position| Self::index_get(&env, &OPEN_LOCKS_KEY, position))
+            .filter(|hashlock| {
+                Self::get_bridge_lock(env.clone(), hashlock.clone())
+                    .is_some_and(|lock| env.ledger().timestamp() >= lock.timelock)
+            })
+            .count() as u32;
+        #[cfg(not(feature = "bridge"))]
+        let expired_locks = 0;
+
+        let epoch = (env.ledger().timestamp() / VOLUME_EPOCH_SECONDS).saturating_sub(1);
+        OperatorSnapshot {
+            #[cfg(feature = "fees")]
+            fees_collected: instance.get(&FEES_COLLECTED_KEY).unwrap_or(Map::new(&env)),
+            #[cfg(not(feature = "fees"))]
+            fees_collected: Map::new(&env),
+            dust: instance.get(&DUST_KEY).unwrap_or(Map::new(&env)),
+            paused_assets: paused.keys(),
+            pending_disputes,
+            expired_locks,
+            next_start: (indexed > end).then_some(end),
+            epoch,
+            epoch_volume: Self::get_epoch_volume(env.clone(), epoch),
+        }
+    }
+
+    /// Get the payment volume settled during an epoch, per payment asset
+    ///
+    /// Only the current and previous epochs are kept; older ones read as
+    /// empty.
+    pub fn get_epoch_volume(env: Env, epoch: u64) -> Map<Address, i128> {
+        let tallies: Vec<EpochVolume> = env.storage().instance().get(&VOLUME_KEY).unwrap_or(vec![&env]);
+        tallies
+            .iter()
+            .find(|tally| tally.epoch == epoch)
+            .map(|tally| tally.volume)
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Get the number of settlements a relayer has submitted
+    pub fn get_relayer_settlements(env: Env, relayer: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(RELAYER_COUNTS_KEY, relayer))
+            .unwrap_or(0)
+    }
+
+    /// Configure the guardians that can recover the admin role
+    ///
+    /// Also records a heartbeat, so the recovery period starts from now.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `guardian_set` - Guardians, approval threshold and recovery period
+    pub fn set_guardians(env: Env, admin: Address, guardian_set: GuardianSet) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if guardian_set.threshold == 0 || guardian_set.threshold > guardian_set.guardians.len() {
+            return Err(SettlementError::GuardianQuorumNotMet);
+        }
+        env.storage().instance().set(&GUARDIANS_KEY, &guardian_set);
+        Ok(())
+    }
+
+    /// Get the configured guardian set
+    pub fn get_guardians(env: Env) -> Option<GuardianSet> {
+        env.storage().instance().get(&GUARDIANS_KEY)
+    }
+
+    /// Prove the admin key is still in use
+    ///
+    /// Every successful admin-authorized call also counts as a heartbeat.
+    pub fn heartbeat(env: Env, admin: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)
+    }
+
+    /// Timestamp of the admin's last heartbeat
+    pub fn get_last_heartbeat(env: Env) -> u64 {
+        env.storage().instance().get(&HEARTBEAT_KEY).unwrap_or(0)
+    }
+
+    /// Rotate the admin once it has missed its heartbeat for the recovery period
+    ///
+    /// # Arguments
+    /// * `approvers` - Guardians approving the rotation (each must authenticate)
+    /// * `new_admin` - Address taking over the admin role
+    pub fn recover_admin(env: Env, approvers: Vec<Address>, new_admin: Address) -> Result<(), SettlementError> {
+        let guardian_set = Self::get_guardians(env.clone()).ok_or(SettlementError::RecoveryNotAvailable)?;
+        let deadline = Self::get_last_heartbeat(env.clone()).saturating_add(guardian_set.recovery_period);
+        if env.ledger().timestamp() < deadline {
+            return Err(SettlementError::RecoveryNotAvailable);
+        }
+
+        let mut approved: Vec<Address> = vec![&env];
+        for approver in approvers.iter() {
+            if guardian_set.guardians.contains(&approver) && !approved.contains(&approver) {
+                approver.require_auth();
+                approved.push_back(approver);
+            }
+        }
+        if approved.len() < guardian_set.threshold {
+            return Err(SettlementError::GuardianQuorumNotMet);
+        }
+
+        let old_admin = Self::get_admin(env.clone());
+        env.storage().instance().set(&ADMIN_KEY, &new_admin);
+        env.storage().instance().set(&HEARTBEAT_KEY, &env.ledger().timestamp());
+        AdminRecovered { old_admin, new_admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Hash over the pool's security-relevant configuration
+    ///
+    /// SHA-256 over the XDR of the admin, verifier and registry addresses,
+    /// the hash of each installed verification key, revoked keys, the fee
+    /// schedule, the paused assets, the relayer policy and the auth mode.
+    /// Monitoring can compare it against a known-good value instead of
+    /// reading each field; balances, heartbeats and other routine state are
+    /// left out. Settings of features left out of the build hash as unset.
+    pub fn state_digest(env: Env) -> BytesN<32> {
+        let vk_hash = |key: Symbol| {
+            let vk: Option<Bytes> = env.storage().instance().get(&key);
+            vk.map(|vk| BytesN::<32>::from(env.crypto().sha256(&vk)))
+        };
+        let paused: Map<Address, bool> = env
+            .storage()
+            .instance()
+            .get(&PAUSED_KEY)
+            .unwrap_or(Map::new(&env));
+        #[cfg(feature = "proofs")]
+        let revoked_vks = Self::revoked_vks(&env);
+        #[cfg(not(feature = "proofs"))]
+        let revoked_vks: Map<Symbol, BytesN<32>> = Map::new(&env);
+        #[cfg(feature = "fees")]
+        let fee_schedule = Self::get_fee_schedule(env.clone());
+        #[cfg(not(feature = "fees"))]
+        let fee_schedule: Option<()> = None;
+        #[cfg(feature = "relayers")]
+        let relayer_policy = (
+            Self::requires_registered_relayers(env.clone()),
+            Self::get_relayer_rate_limit(env.clone()),
+        );
+        #[cfg(not(feature = "relayers"))]
+        let relayer_policy = (false, None::<()>);
+        let config = (
+            Self::get_admin(env.clone()),
+            Self::get_verifier(env.clone()),
+            Self::get_registry(env.clone()),
+            (vk_hash(SETTLEMENT_VK_KEY), vk_hash(BASKET_VK_KEY), vk_hash(CANCEL_VK_KEY)),
+            revoked_vks,
+            fee_schedule,
+            paused,
+            relayer_policy,
+            Self::get_auth_mode(env.clone()),
+        );
+        env.crypto().sha256(&config.to_xdr(&env)).into()
+    }
+
+    /**
+     * Reconcile escrow after an issuer clawed back tokens held by the pool
+     *
+     * A clawback removes tokens from the contract without touching escrow
+     * records, leaving the pool insolvent for that asset (visible as a
+     * negative discrepancy in `audit_balances`). This writes the clawed-back
+     * amount off the affected participant's escrow and oldest tax lots,
+     * releasing any lock that is no longer backed, and emits a
+     * `clawback_recorded` event.
+     *
+     * # Arguments
+     * * `admin` - Admin address
+     * * `participant` - Participant whose tokens were clawed back
+     * * `sub_account` - Sub-account holding the affected balance
+     * * `asset` - Clawback-enabled token contract address
+     * * `amount` - Amount clawed back
+     *
+     * # Returns
+     * * The participant's remaining escrow balance
+     */
+    pub fn record_clawback(
+        env: Env,
+        admin: Address,
+        participant: Address,
+        sub_account: Symbol,
+        asset: Address,
+        amount: i128,
+    ) -> Result<i128, SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if amount <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+
+        let key = EscrowKey::new(&participant, &sub_account, &asset);
+        let escrow_balance = Self::read_balance(&env, &ESCROW_KEY, &key);
+        if amount > escrow_balance {
+            return Err(SettlementError::InsufficientBalance);
+        }
+
+        let remaining = escrow_balance - amount;
+        if Self::read_balance(&env, &LOCKED_KEY, &key) > remaining {
+            Self::write_balance(&env, &LOCKED_KEY, &key, remaining);
+        }
+        Self::write_balance(&env, &ESCROW_KEY, &key, remaining);
+        #[cfg(feature = "corporate-actions")]
+        Self::consume_lots(&env, &participant, &asset, amount);
+
+        ClawbackRecorded {
+            participant,
+            asset,
+            amount,
+            escrow_balance: remaining,
+        }
+        .publish(&env);
+
+        Ok(remaining)
+    }
+
+    /**
+     * Cross-check recorded escrow for an asset against the tokens held
+     *
+     * Compares the asset's running escrow total, across every participant
+     * and sub-account (see `get_total_escrow`), to the contract's token
+     * balance and emits the result as a `discrepancy_report` event. Payments
+     * held for pending or forward deliveries are not counted, so they show
+     * up as a surplus.
+     *
+     * # Arguments
+     * * `admin` - Admin address
+     * * `asset` - Token contract address to audit
+     */
+    pub fn audit_balances(env: Env, admin: Address, asset: Address) -> Result<BalanceAudit, SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let recorded_total = Self::get_total_escrow(env.clone(), asset.clone());
+        let token_balance = token::Client::new(&env, &asset).balance(&env.current_contract_address());
+
+        let audit = BalanceAudit {
+            asset,
+            recorded_total,
+            token_balance,
+            discrepancy: token_balance - recorded_total,
+        };
+        DiscrepancyReport {
+            asset: audit.asset.clone(),
+            recorded_total,
+            token_balance,
+            discrepancy: audit.discrepancy,
+        }
+        .publish(&env);
+
+        Ok(audit)
+    }
+
+    /// Total escrow held for all participants and sub-accounts in an asset
+    ///
+    /// Maintained incrementally on every balance change. Balances written
+    /// before totals were tracked are not included.
+    pub fn get_total_escrow(env: Env, asset: Address) -> i128 {
+        Self::read_total(&env, &ESCROW_KEY, &asset)
+    }
+
+    /// Total escrow locked for orders in an asset
+    ///
+    /// Maintained like `get_total_escrow`.
+    pub fn get_total_locked(env: Env, asset: Address) -> i128 {
+        Self::read_total(&env, &LOCKED_KEY, &asset)
+    }
+
+    /**
+     * Check that the tokens held cover all tracked escrow for an asset
+     *
+     * Compares the contract's token balance with the running total of escrow
+     * balances and pauses the asset if it falls short, so an accounting bug
+     * stops settlements before it can be exploited further. Anyone may call
+     * this. Tokens held outside escrow (pending deliveries, unclaimed fees)
+     * only add to the surplus. The running total rounds each split once
+     * rather than per account, so it may overstate escrow by a few units
+     * after a split.
+     *
+     * # Returns
+     * * The comparison; a negative `discrepancy` is a deficit
+     */
+    pub fn verify_solvency(env: Env, asset: Address) -> BalanceAudit {
+        let tracked_escrow = Self::get_total_escrow(env.clone(), asset.clone());
+        let token_balance = token::Client::new(&env, &asset).balance(&env.current_contract_address());
+
+        if token_balance < tracked_escrow {
+            Self::set_asset_paused(&env, asset.clone(), true);
+            SolvencyBreach {
+                asset: asset.clone(),
+                tracked_escrow,
+                token_balance,
+            }
+            .publish(&env);
+        }
+        BalanceAudit {
+            asset,
+            recorded_total: tracked_escrow,
+            token_balance,
+            discrepancy: token_balance - tracked_escrow,
+        }
+    }
+
+    /// Add a settlement's payment to the current epoch's volume and its relayer's count
+    pub(crate) fn tally_activity(env: &Env, payment_asset: &Address, payment_amount: i128, relayer: Option<&Address>) {
+        let epoch = env.ledger().timestamp() / VOLUME_EPOCH_SECONDS;
+        let tallies: Vec<EpochVolume> = env.storage().instance().get(&VOLUME_KEY).unwrap_or(vec![env]);
+        let mut current = match tallies.last() {
+            Some(tally) if tally.epoch == epoch => tally,
+            _ => EpochVolume { epoch, volume: Map::new(env) },
+        };
+        let volume = current.volume.get(payment_asset.clone()).unwrap_or(0);
+        current.volume.set(payment_asset.clone(), volume.saturating_add(payment_amount));
+
+        // Keep the previous epoch alongside the one filling up
+        let mut kept = vec![env];
+        if let Some(previous) = tallies.iter().find(|tally| tally.epoch + 1 == epoch) {
+            kept.push_back(previous);
+        }
+        kept.push_back(current);
+        env.storage().instance().set(&VOLUME_KEY, &kept);
+
+        if let Some(relayer) = relayer {
+            let entry = (RELAYER_COUNTS_KEY, relayer.clone());
+            let count = Self::get_relayer_settlements(env.clone(), relayer.clone());
+            env.storage().persistent().set(&entry, &count.saturating_add(1));
+            env.storage()
+                .persistent()
+                .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        }
+    }
+}
@@ -0,0 +1,356 @@
+//! Counterparty policies, inventory and notional limits, tick sizes and last look
+//!
+//! Only compiled with the `order-controls` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, BytesN, Env, Map, Symbol, Vec};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, EscrowKey, SettlementError,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, COUNTERPARTY_KEY, ESCROW_KEY, INVENTORY_KEY, LAST_LOOK_KEY,
+    MAX_NOTIONAL_KEY, PROPOSAL_TTL_LEDGERS, TICKS_KEY,
+};
+
+const PROPOSALS_KEY: Symbol = symbol_short!("proposals");
+
+/// Whether a counterparty policy lists permitted or forbidden addresses
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[contracttype]
+#[repr(u32)]
+pub enum CounterpartyMode {
+    /// Only the listed counterparties may settle against the participant
+    Allowlist = 0,
+    /// The listed counterparties may not settle against the participant
+    Blocklist = 1,
+}
+
+/// A participant's restrictions on who it may settle against
+///
+/// Kept off the matching layer entirely; it is only consulted at settlement.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct CounterpartyPolicy {
+    pub mode: CounterpartyMode,
+    pub counterparties: Vec<Address>,
+}
+
+/// Market conventions for an asset's settled sizes and prices
+///
+/// Quantities must be a multiple of `lot_size` and prices a multiple of
+/// `tick_size`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TickSize {
+    pub lot_size: i128,
+    pub tick_size: i128,
+}
+
+/// A match awaiting the maker's last-look confirmation
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MatchProposal {
+    pub relayer: Address,
+    pub maker: Address,
+    /// Latest ledger timestamp at which the maker may confirm
+    pub deadline: u64,
+    pub confirmed: bool,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Set or clear the participant's counterparty policy
+    ///
+    /// # Arguments
+    /// * `participant` - Participant setting the policy (must authenticate)
+    /// * `policy` - Allowlist or blocklist of counterparties, or `None` to clear
+    pub fn set_counterparty_policy(env: Env, participant: Address, policy: Option<CounterpartyPolicy>) {
+        participant.require_auth();
+
+        let entry = (COUNTERPARTY_KEY, participant);
+        match policy {
+            Some(policy) => {
+                env.storage().persistent().set(&entry, &policy);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+            }
+            None => env.storage().persistent().remove(&entry),
+        }
+    }
+
+    /// Get the participant's counterparty policy
+    pub fn get_counterparty_policy(env: Env, participant: Address) -> Option<CounterpartyPolicy> {
+        env.storage().persistent().get(&(COUNTERPARTY_KEY, participant))
+    }
+
+    /// Cap the inventory a maker may accumulate in an asset
+    ///
+    /// Fills that would take the receiving escrow account above the limit
+    /// are rejected, as a backstop against a runaway automated strategy.
+    ///
+    /// # Arguments
+    /// * `maker` - Maker setting the limit (must authenticate)
+    /// * `asset_address` - Asset the limit applies to
+    /// * `max_inventory` - Maximum escrowed balance, or `None` to remove it
+    pub fn set_inventory_limit(
+        env: Env,
+        maker: Address,
+        asset_address: Address,
+        max_inventory: Option<i128>,
+    ) -> Result<(), SettlementError> {
+        maker.require_auth();
+
+        let entry = (INVENTORY_KEY, maker, asset_address);
+        match max_inventory {
+            Some(max_inventory) if max_inventory < 0 => return Err(SettlementError::InvalidAmount),
+            Some(max_inventory) => {
+                env.storage().persistent().set(&entry, &max_inventory);
+                env.storage()
+                    .persistent()
+                    .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+            }
+            None => env.storage().persistent().remove(&entry),
+        }
+        Ok(())
+    }
+
+    /// Get a maker's inventory limit for an asset
+    pub fn get_inventory_limit(env: Env, maker: Address, asset_address: Address) -> Option<i128> {
+        env.storage().persistent().get(&(INVENTORY_KEY, maker, asset_address))
+    }
+
+    /// Cap the payment amount a single settlement may move in a payment asset
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `payment_asset` - Payment token the cap applies to
+    /// * `max_notional` - Largest payment amount per settlement
+    pub fn set_max_notional(
+        env: Env,
+        admin: Address,
+        payment_asset: Address,
+        max_notional: i128,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if max_notional <= 0 {
+            return Err(SettlementError::InvalidAmount);
+        }
+
+        let mut caps: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&MAX_NOTIONAL_KEY)
+            .unwrap_or(Map::new(&env));
+        caps.set(payment_asset, max_notional);
+        env.storage().instance().set(&MAX_NOTIONAL_KEY, &caps);
+        Ok(())
+    }
+
+    /// Get the per-settlement notional cap for a payment asset, if any
+    pub fn get_max_notional(env: Env, payment_asset: Address) -> Option<i128> {
+        let caps: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&MAX_NOTIONAL_KEY)
+            .unwrap_or(Map::new(&env));
+        caps.get(payment_asset)
+    }
+
+    /// Set the lot and tick size settlements of an asset must conform to
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `asset_address` - The RWA token
+    /// * `ticks` - Lot and tick size, or `None` to accept any size and price
+    pub fn set_tick_size(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        ticks: Option<TickSize>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut all: Map<Address, TickSize> = env
+            .storage()
+            .instance()
+            .get(&TICKS_KEY)
+            .unwrap_or(Map::new(&env));
+        match ticks {
+            Some(ticks) => {
+                if ticks.lot_size <= 0 || ticks.tick_size <= 0 {
+                    return Err(SettlementError::InvalidAmount);
+                }
+                all.set(asset_address, ticks);
+            }
+            None => {
+                all.remove(asset_address);
+            }
+        }
+        env.storage().instance().set(&TICKS_KEY, &all);
+        Ok(())
+    }
+
+    /// Get the lot and tick size for an asset
+    pub fn get_tick_size(env: Env, asset_address: Address) -> Option<TickSize> {
+        let all: Map<Address, TickSize> = env
+            .storage()
+            .instance()
+            .get(&TICKS_KEY)
+            .unwrap_or(Map::new(&env));
+        all.get(asset_address)
+    }
+
+    /// Require makers to confirm matches before they settle
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `window` - Seconds a maker has to confirm a proposed match; zero
+    ///   disables last-look
+    pub fn set_last_look_window(env: Env, admin: Address, window: u64) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&LAST_LOOK_KEY, &window);
+        Ok(())
+    }
+
+    /// Get the last-look window in seconds (zero when disabled)
+    pub fn get_last_look_window(env: Env) -> u64 {
+        env.storage().instance().get(&LAST_LOOK_KEY).unwrap_or(0)
+    }
+
+    /// Propose a match for the maker's last look
+    ///
+    /// # Arguments
+    /// * `relayer` - Relayer proposing the match (must authenticate)
+    /// * `match_id` - Match to be settled
+    /// * `maker` - Resting-order party that must confirm
+    pub fn propose_match(
+        env: Env,
+        relayer: Address,
+        match_id: BytesN<32>,
+        maker: Address,
+    ) -> Result<MatchProposal, SettlementError> {
+        relayer.require_auth();
+        #[cfg(feature = "relayers")]
+        Self::require_registered_relayer(&env, &relayer)?;
+
+        let entry = (PROPOSALS_KEY, match_id);
+        if env.storage().temporary().has(&entry) {
+            return Err(SettlementError::MatchAlreadyProposed);
+        }
+        let proposal = MatchProposal {
+            relayer,
+            maker,
+            deadline: env.ledger().timestamp() + Self::get_last_look_window(env.clone()),
+            confirmed: false,
+        };
+        env.storage().temporary().set(&entry, &proposal);
+        env.storage()
+            .temporary()
+            .extend_ttl(&entry, PROPOSAL_TTL_LEDGERS, PROPOSAL_TTL_LEDGERS);
+        Ok(proposal)
+    }
+
+    /// Confirm a proposed match as its maker, before the deadline
+    pub fn confirm_match(env: Env, maker: Address, match_id: BytesN<32>) -> Result<(), SettlementError> {
+        maker.require_auth();
+
+        let entry = (PROPOSALS_KEY, match_id);
+        let mut proposal: MatchProposal = env
+            .storage()
+            .temporary()
+            .get(&entry)
+            .ok_or(SettlementError::ProposalNotFound)?;
+        if proposal.maker != maker {
+            return Err(SettlementError::ProposalNotFound);
+        }
+        if env.ledger().timestamp() > proposal.deadline {
+            return Err(SettlementError::ProposalExpired);
+        }
+        proposal.confirmed = true;
+        env.storage().temporary().set(&entry, &proposal);
+        Ok(())
+    }
+
+    /// Get a match proposal, if it has not settled or expired from storage
+    pub fn get_match_proposal(env: Env, match_id: BytesN<32>) -> Option<MatchProposal> {
+        env.storage().temporary().get(&(PROPOSALS_KEY, match_id))
+    }
+
+    /// Check that neither party's counterparty policy excludes the other
+    pub(crate) fn check_counterparties(env: &Env, buyer: &Address, seller: &Address) -> Result<(), SettlementError> {
+        for (party, counterparty) in [(buyer, seller), (seller, buyer)] {
+            let Some(policy) = Self::get_counterparty_policy(env.clone(), party.clone()) else {
+                continue;
+            };
+            let listed = policy.counterparties.contains(counterparty);
+            let allowed = match policy.mode {
+                CounterpartyMode::Allowlist => listed,
+                CounterpartyMode::Blocklist => !listed,
+            };
+            if !allowed {
+                return Err(SettlementError::CounterpartyBlocked);
+            }
+        }
+        Ok(())
+    }
+
+    /// With last-look enabled, consume the match's confirmed proposal
+    ///
+    /// The confirming maker must be one of the settling parties.
+    pub(crate) fn take_confirmed_match(
+        env: &Env,
+        match_id: &BytesN<32>,
+        buyer: &Address,
+        seller: &Address,
+    ) -> Result<(), SettlementError> {
+        if Self::get_last_look_window(env.clone()) == 0 {
+            return Ok(());
+        }
+
+        let entry = (PROPOSALS_KEY, match_id.clone());
+        let proposal: MatchProposal = env
+            .storage()
+            .temporary()
+            .get(&entry)
+            .ok_or(SettlementError::MatchNotConfirmed)?;
+        if !proposal.confirmed || (proposal.maker != *buyer && proposal.maker != *seller) {
+            return Err(SettlementError::MatchNotConfirmed);
+        }
+        env.storage().temporary().remove(&entry);
+        Ok(())
+    }
+
+    /// Enforce the configured notional cap for the payment asset
+    pub(crate) fn check_notional(env: &Env, payment_asset: &Address, amount: i128) -> Result<(), SettlementError> {
+        match Self::get_max_notional(env.clone(), payment_asset.clone()) {
+            Some(max_notional) if amount > max_notional => Err(SettlementError::NotionalTooLarge),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fail if receiving `amount` would take the account past its owner's inventory limit
+    pub(crate) fn check_inventory(env: &Env, to: &EscrowKey, amount: i128) -> Result<(), SettlementError> {
+        let limit = match Self::get_inventory_limit(env.clone(), to.participant.clone(), to.asset.clone()) {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+        let after = Self::read_balance(env, &ESCROW_KEY, to)
+            .checked_add(amount)
+            .ok_or(SettlementError::NotionalOverflow)?;
+        if after > limit {
+            return Err(SettlementError::InventoryLimitExceeded);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn check_tick(ticks: &TickSize, quantity: i128, price: i128) -> Result<(), SettlementError> {
+        if quantity % ticks.lot_size != 0 || price % ticks.tick_size != 0 {
+            return Err(SettlementError::InvalidTick);
+        }
+        Ok(())
+    }
+}
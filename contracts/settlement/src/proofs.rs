@@ -0,0 +1,280 @@
+//! Commitment schemes and verification key management
+//!
+//! Only compiled with the `proofs` feature.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Map, Symbol, Vec,
+};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, SettlementError, BASKET_PROOF, BASKET_VK_KEY,
+    CANCEL_PROOF, CANCEL_VK_KEY, COMMITMENT_SCHEME_V1, SETTLEMENT_PROOF, SETTLEMENT_VK_KEY,
+};
+
+const VK_UPLOAD_KEY: Symbol = symbol_short!("vk_upload");
+
+const SCHEME_KEY: Symbol = symbol_short!("cmt_schm");
+
+pub(crate) const SCHEME_VKS_KEY: Symbol = symbol_short!("schm_vks");
+
+const REVOKED_VKS_KEY: Symbol = symbol_short!("vk_revoke");
+
+/// Verification key being streamed in over several transactions
+#[derive(Clone)]
+#[contracttype]
+pub struct VkUpload {
+    pub proof_type: Symbol,
+    /// SHA-256 of the complete key bytes
+    pub vk_hash: BytesN<32>,
+    pub total_len: u32,
+    pub received: Bytes,
+}
+
+/// Event emitted when a proof type's verification key is revoked or restored
+#[contractevent]
+#[derive(Clone)]
+pub struct VkRevocation {
+    #[topic]
+    pub proof_type: Symbol,
+    pub vk_hash: BytesN<32>,
+    pub revoked: bool,
+    pub by: Address,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Move settlement proofs to a new order commitment scheme
+    ///
+    /// `vk_bytes` becomes the settlement verification key. The key it
+    /// replaces stays accepted for the old scheme until
+    /// `close_commitment_scheme`, so orders committed before the upgrade can
+    /// still settle. Settlement records note which scheme's key verified.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `version` - New scheme version, above the current one
+    /// * `vk_bytes` - Settlement verification key for circuits of the new scheme
+    pub fn upgrade_commitment_scheme(
+        env: Env,
+        admin: Address,
+        version: u32,
+        vk_bytes: Bytes,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let current = Self::get_commitment_scheme(env.clone());
+        if version <= current {
+            return Err(SettlementError::InvalidCommitmentScheme);
+        }
+        Self::validate_vk(&env, &SETTLEMENT_PROOF, &vk_bytes)?;
+
+        let mut open: Map<u32, Bytes> = env
+            .storage()
+            .instance()
+            .get(&SCHEME_VKS_KEY)
+            .unwrap_or(Map::new(&env));
+        let current_vk: Bytes = env.storage().instance().get(&SETTLEMENT_VK_KEY).unwrap();
+        open.set(current, current_vk);
+        env.storage().instance().set(&SCHEME_VKS_KEY, &open);
+        env.storage().instance().set(&SETTLEMENT_VK_KEY, &vk_bytes);
+        env.storage().instance().set(&SCHEME_KEY, &version);
+        Ok(())
+    }
+
+    /// End the upgrade window of a superseded commitment scheme
+    ///
+    /// Settlement proofs for orders committed under `version` are rejected
+    /// from then on.
+    pub fn close_commitment_scheme(env: Env, admin: Address, version: u32) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut open: Map<u32, Bytes> = env
+            .storage()
+            .instance()
+            .get(&SCHEME_VKS_KEY)
+            .unwrap_or(Map::new(&env));
+        if !open.contains_key(version) {
+            return Err(SettlementError::InvalidCommitmentScheme);
+        }
+        open.remove(version);
+        env.storage().instance().set(&SCHEME_VKS_KEY, &open);
+        Ok(())
+    }
+
+    /// Get the commitment scheme new orders should use
+    pub fn get_commitment_scheme(env: Env) -> u32 {
+        env.storage().instance().get(&SCHEME_KEY).unwrap_or(COMMITMENT_SCHEME_V1)
+    }
+
+    /// Get superseded commitment schemes still accepted for settlement
+    pub fn get_open_commitment_schemes(env: Env) -> Vec<u32> {
+        let open: Map<u32, Bytes> = env
+            .storage()
+            .instance()
+            .get(&SCHEME_VKS_KEY)
+            .unwrap_or(Map::new(&env));
+        open.keys()
+    }
+
+    /// Start streaming a verification key too large for one transaction
+    ///
+    /// Replaces any upload already in progress. The key is only installed by
+    /// `finalize_vk_upload` once all bytes have arrived and match `vk_hash`.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `proof_type` - Proof type the key is for (`SETTLEMENT_PROOF`, `BASKET_PROOF` or `CANCEL_PROOF`)
+    /// * `vk_hash` - SHA-256 of the complete serialized key
+    /// * `total_len` - Length of the complete serialized key in bytes
+    pub fn begin_vk_upload(
+        env: Env,
+        admin: Address,
+        proof_type: Symbol,
+        vk_hash: BytesN<32>,
+        total_len: u32,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        Self::vk_storage_key(&proof_type)?;
+
+        let upload = VkUpload {
+            proof_type,
+            vk_hash,
+            total_len,
+            received: Bytes::new(&env),
+        };
+        env.storage().persistent().set(&VK_UPLOAD_KEY, &upload);
+        Ok(())
+    }
+
+    /// Append the next chunk of the key being uploaded
+    ///
+    /// # Returns
+    /// * Number of bytes received so far
+    pub fn append_vk_chunk(env: Env, admin: Address, chunk: Bytes) -> Result<u32, SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut upload: VkUpload = env
+            .storage()
+            .persistent()
+            .get(&VK_UPLOAD_KEY)
+            .ok_or(SettlementError::VkUploadNotStarted)?;
+        if upload.received.len() + chunk.len() > upload.total_len {
+            return Err(SettlementError::VkUploadTooLarge);
+        }
+
+        upload.received.append(&chunk);
+        env.storage().persistent().set(&VK_UPLOAD_KEY, &upload);
+        Ok(upload.received.len())
+    }
+
+    /// Check the uploaded key against its declared length and hash, then install it
+    ///
+    /// The key is also validated as for `set_basket_vk`, so a key that
+    /// could never verify is rejected here rather than at the first proof.
+    pub fn finalize_vk_upload(env: Env, admin: Address) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let upload: VkUpload = env
+            .storage()
+            .persistent()
+            .get(&VK_UPLOAD_KEY)
+            .ok_or(SettlementError::VkUploadNotStarted)?;
+        if upload.received.len() != upload.total_len {
+            return Err(SettlementError::VkUploadIncomplete);
+        }
+        let hash: BytesN<32> = env.crypto().sha256(&upload.received).into();
+        if hash != upload.vk_hash {
+            return Err(SettlementError::VkHashMismatch);
+        }
+
+        let key = Self::vk_storage_key(&upload.proof_type)?;
+        Self::validate_vk(&env, &upload.proof_type, &upload.received)?;
+        env.storage().instance().set(&key, &upload.received);
+        env.storage().persistent().remove(&VK_UPLOAD_KEY);
+        Ok(())
+    }
+
+    /// Get the verification key upload in progress, if any
+    pub fn get_vk_upload(env: Env) -> Option<VkUpload> {
+        env.storage().persistent().get(&VK_UPLOAD_KEY)
+    }
+
+    /// Get the installed verification key for a proof type
+    pub fn get_vk(env: Env, proof_type: Symbol) -> Option<Bytes> {
+        let key = Self::vk_storage_key(&proof_type).ok()?;
+        env.storage().instance().get(&key)
+    }
+
+    /// Revoke a proof type's installed verification key
+    ///
+    /// Emergency kill switch for a compromised circuit: every proof checked
+    /// against the key fails with `VkRevoked`, while other proof types and
+    /// asset pauses are unaffected. Installing a new key for the proof type
+    /// lifts the block. The admin or any guardian may revoke.
+    ///
+    /// # Arguments
+    /// * `caller` - Admin or guardian (must authenticate)
+    /// * `proof_type` - Proof type whose key is revoked
+    pub fn revoke_vk(env: Env, caller: Address, proof_type: Symbol) -> Result<(), SettlementError> {
+        caller.require_auth();
+        #[cfg(feature = "ops")]
+        let is_guardian = Self::get_guardians(env.clone()).is_some_and(|set| set.guardians.contains(&caller));
+        #[cfg(not(feature = "ops"))]
+        let is_guardian = false;
+        if !is_guardian {
+            Self::require_admin(&env, &caller)?;
+        }
+
+        Self::vk_storage_key(&proof_type)?;
+        let vk = Self::get_vk(env.clone(), proof_type.clone()).ok_or(SettlementError::VkNotSet)?;
+        let vk_hash: BytesN<32> = env.crypto().sha256(&vk).into();
+        let mut revoked = Self::revoked_vks(&env);
+        revoked.set(proof_type.clone(), vk_hash.clone());
+        env.storage().instance().set(&REVOKED_VKS_KEY, &revoked);
+        VkRevocation { proof_type, vk_hash, revoked: true, by: caller }.publish(&env);
+        Ok(())
+    }
+
+    /// Lift a revocation without replacing the key, e.g. after a false alarm
+    pub fn restore_vk(env: Env, admin: Address, proof_type: Symbol) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut revoked = Self::revoked_vks(&env);
+        let vk_hash = revoked.get(proof_type.clone()).ok_or(SettlementError::VkNotRevoked)?;
+        revoked.remove(proof_type.clone());
+        env.storage().instance().set(&REVOKED_VKS_KEY, &revoked);
+        VkRevocation { proof_type, vk_hash, revoked: false, by: admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Hash of a proof type's revoked verification key, if one is revoked
+    pub fn get_revoked_vk(env: Env, proof_type: Symbol) -> Option<BytesN<32>> {
+        Self::revoked_vks(&env).get(proof_type)
+    }
+
+    pub(crate) fn revoked_vks(env: &Env) -> Map<Symbol, BytesN<32>> {
+        env.storage()
+            .instance()
+            .get(&REVOKED_VKS_KEY)
+            .unwrap_or(Map::new(env))
+    }
+
+    /// Map a proof type to the instance storage key holding its verification key
+    fn vk_storage_key(proof_type: &Symbol) -> Result<Symbol, SettlementError> {
+        if *proof_type == SETTLEMENT_PROOF {
+            Ok(SETTLEMENT_VK_KEY)
+        } else if *proof_type == BASKET_PROOF {
+            Ok(BASKET_VK_KEY)
+        } else if *proof_type == CANCEL_PROOF {
+            Ok(CANCEL_VK_KEY)
+        } else {
+            Err(SettlementError::UnknownProofType)
+        }
+    }
+}
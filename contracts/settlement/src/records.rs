@@ -0,0 +1,461 @@
+//! Record privacy and formats, receipts, the settlement log, exports and compliance evidence
+//!
+//! Only compiled with the `records` feature.
+
+use soroban_sdk::{
+    contractevent, contractimpl, contracttype, symbol_short, vec, xdr::{FromXdr, ToXdr}, Address, Bytes, BytesN, Env,
+    Symbol, Vec,
+};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, SettlementError, SettlementRecord,
+    BALANCE_TTL_EXTEND_TO, BALANCE_TTL_THRESHOLD, RECORD_FORMAT_KEY, RECORD_PRIVACY_KEY, registry_wasm,
+};
+
+const RECEIPTS_KEY: Symbol = symbol_short!("receipts");
+
+const ATTEST_KEY: Symbol = symbol_short!("attest");
+
+const EVIDENCE_KEY: Symbol = symbol_short!("evidence");
+
+const SEQUENCE_KEY: Symbol = symbol_short!("seq");
+
+const MATCH_SEQ_KEY: Symbol = symbol_short!("match_seq");
+
+const AUDITORS_KEY: Symbol = symbol_short!("auditors");
+
+const COMMITMENTS_KEY: Symbol = symbol_short!("rec_cmt");
+
+const LOG_HEAD_KEY: Symbol = symbol_short!("log_head");
+
+/// Encoding version written by `export_settlements`
+///
+/// 1: records without a commitment scheme version
+/// 2: records carry `scheme_version`
+pub const EXPORT_FORMAT_VERSION: u32 = 2;
+
+/// Maximum records returned by one `export_settlements` call
+pub const MAX_EXPORT_RECORDS: u32 = 100;
+
+/// How settled trades are recorded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+#[repr(u32)]
+pub enum RecordFormat {
+    /// Full records, public unless record privacy is enabled
+    Full = 0,
+    /// Public commitment-only records; full records are always auth-gated
+    CommitmentOnly = 1,
+}
+
+/// Public form of a settlement under `RecordFormat::CommitmentOnly`
+///
+/// `asset_hash` is the asset commitment from the settlement proof and
+/// `amount_bucket` is `floor(log2(quantity))`, revealing only the order of
+/// magnitude of the fill.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SettlementRecordPrivate {
+    pub match_id: BytesN<32>,
+    pub nullifier: BytesN<32>,
+    pub asset_hash: BytesN<32>,
+    pub amount_bucket: u32,
+}
+
+/// Verifiable proof of execution for a settled trade
+///
+/// `record_xdr` is the canonical XDR encoding of the `SettlementRecord` and
+/// `hash` is its SHA-256, committed to contract storage at settlement time.
+#[derive(Clone)]
+#[contracttype]
+pub struct SettlementReceipt {
+    pub record_xdr: Bytes,
+    pub hash: BytesN<32>,
+}
+
+/// Page of settlement records exported for archiving
+///
+/// Exported as the canonical XDR encoding of this struct. `start` is the
+/// index of the first record in settlement order and `total` the number of
+/// settlements at export time, so archivers can detect gaps.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SettlementExport {
+    pub version: u32,
+    pub start: u32,
+    pub total: u32,
+    pub records: Vec<SettlementRecord>,
+}
+
+/// Head of the hash-chained settlement log
+///
+/// `hash` starts at 32 zero bytes and becomes
+/// `sha256(hash || receipt_hash)` for each settlement recorded, where
+/// `receipt_hash` is the SHA-256 of the record's XDR. `length` counts the
+/// records chained, which are the last `length` in settlement order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct SettlementLogHead {
+    pub hash: BytesN<32>,
+    pub length: u32,
+}
+
+/// Event emitted for every settled match with its position in settlement order
+///
+/// Sequence numbers start at 1 and increase by exactly one, so a consumer
+/// that sees a jump has missed an event.
+#[contractevent]
+#[derive(Clone)]
+pub struct SettlementSequenced {
+    #[topic]
+    pub sequence: u64,
+    #[topic]
+    pub match_id: BytesN<32>,
+}
+
+/// Off-chain compliance check result submitted ahead of a settlement
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ComplianceAttestation {
+    /// Root or hash of the KYC attestations covering both parties
+    pub attestation_root: BytesN<32>,
+    /// Outcome of the jurisdiction eligibility check
+    pub jurisdiction_cleared: bool,
+}
+
+/// Compliance evidence captured when a match settles
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct ComplianceEvidence {
+    /// Whitelist root the settlement proof was generated against
+    pub whitelist_root: BytesN<32>,
+    /// Attestation root submitted for the match, if any
+    pub attestation_root: Option<BytesN<32>>,
+    /// Jurisdiction check result; false when no attestation was submitted
+    pub jurisdiction_cleared: bool,
+    pub recorded_at: u64,
+}
+
+/// Decode a blob produced by `export_settlements`
+///
+/// Returns `None` if the blob does not decode to a settlement export or was
+/// written in an unknown format version.
+pub fn decode_settlement_export(env: &Env, bytes: &Bytes) -> Option<SettlementExport> {
+    let export = SettlementExport::from_xdr(env, bytes).ok()?;
+    if export.version != EXPORT_FORMAT_VERSION {
+        return None;
+    }
+    Some(export)
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Get the sequence number assigned to a settled match
+    ///
+    /// Matches settled before sequencing was introduced have none.
+    pub fn get_settlement_sequence(env: Env, match_id: BytesN<32>) -> Option<u64> {
+        env.storage().persistent().get(&(MATCH_SEQ_KEY, match_id))
+    }
+
+    /// Get the most recently assigned settlement sequence number
+    pub fn get_last_sequence(env: Env) -> u64 {
+        env.storage().instance().get(&SEQUENCE_KEY).unwrap_or(0)
+    }
+
+    /// Attach a compliance attestation to a match before it settles
+    ///
+    /// The attestation is captured with the whitelist root into the match's
+    /// compliance evidence at settlement time.
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `match_id` - Match the attestation covers
+    /// * `attestation` - KYC attestation root and jurisdiction check result
+    pub fn submit_compliance_attestation(
+        env: Env,
+        admin: Address,
+        match_id: BytesN<32>,
+        attestation: ComplianceAttestation,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let entry = (ATTEST_KEY, match_id);
+        env.storage().persistent().set(&entry, &attestation);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Get the compliance evidence recorded when a match settled
+    pub fn get_compliance_evidence(env: Env, match_id: BytesN<32>) -> Option<ComplianceEvidence> {
+        env.storage().persistent().get(&(EVIDENCE_KEY, match_id))
+    }
+
+    /// Restrict settlement record reads to parties, the admin and auditors
+    ///
+    /// Events only ever carry match ids and sequence numbers, so with
+    /// privacy on nothing about counterparties or sizes is public.
+    pub fn set_record_privacy(env: Env, admin: Address, enabled: bool) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        env.storage().instance().set(&RECORD_PRIVACY_KEY, &enabled);
+        Ok(())
+    }
+
+    /// Check whether settlement record reads are restricted
+    pub fn is_record_privacy(env: Env) -> bool {
+        env.storage().instance().get(&RECORD_PRIVACY_KEY).unwrap_or(false)
+    }
+
+    /// Choose how settlements are recorded
+    ///
+    /// Part of deployment: the format can only change before the first
+    /// settlement, so every record of a pool has the same shape. Contract
+    /// storage stays readable by anyone operating a node; the format only
+    /// controls what the contract serves without authentication.
+    pub fn set_record_format(env: Env, admin: Address, format: RecordFormat) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        if Self::get_settlement_count(env.clone()) != 0 {
+            return Err(SettlementError::RecordFormatLocked);
+        }
+        env.storage().instance().set(&RECORD_FORMAT_KEY, &format);
+        Ok(())
+    }
+
+    /// Get the record format
+    pub fn get_record_format(env: Env) -> RecordFormat {
+        env.storage().instance().get(&RECORD_FORMAT_KEY).unwrap_or(RecordFormat::Full)
+    }
+
+    /// Get the public commitment-only record of a settlement
+    pub fn get_settlement_commitment(env: Env, match_id: BytesN<32>) -> Option<SettlementRecordPrivate> {
+        env.storage().persistent().get(&(COMMITMENTS_KEY, match_id))
+    }
+
+    /// Grant or revoke the auditor role, which may read every record
+    pub fn set_auditor(env: Env, admin: Address, auditor: Address, enabled: bool) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let entry = (AUDITORS_KEY, auditor);
+        if enabled {
+            env.storage().persistent().set(&entry, &true);
+            env.storage()
+                .persistent()
+                .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        } else {
+            env.storage().persistent().remove(&entry);
+        }
+        Ok(())
+    }
+
+    /// Check whether an address holds the auditor role
+    ///
+    /// Auditors registered in the registry are accepted alongside those
+    /// granted locally with `set_auditor`.
+    pub fn is_auditor(env: Env, auditor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(AUDITORS_KEY, auditor.clone()))
+            .unwrap_or(false)
+            || Self::has_service_role(&env, &auditor, registry_wasm::ServiceRole::Auditor)
+    }
+
+    /// Export settled records as a versioned XDR blob
+    ///
+    /// Decode with `decode_settlement_export`.
+    ///
+    /// # Arguments
+    /// * `viewer` - Admin or auditor, required when record privacy is on
+    /// * `start` - Index of the first record, in settlement order
+    /// * `limit` - Maximum records to include, capped at `MAX_EXPORT_RECORDS`
+    pub fn export_settlements(
+        env: Env,
+        viewer: Option<Address>,
+        start: u32,
+        limit: u32,
+    ) -> Result<Bytes, SettlementError> {
+        if !Self::require_record_viewer(&env, &viewer)? {
+            return Err(SettlementError::RecordAccessDenied);
+        }
+        let total = Self::get_settlement_count(env.clone());
+
+        let end = start.saturating_add(limit.min(MAX_EXPORT_RECORDS)).min(total);
+        let mut records = vec![&env];
+        for index in start..end {
+            if let Some(record) = Self::load_settlement_at(&env, index) {
+                records.push_back(record);
+            }
+        }
+
+        Ok(SettlementExport {
+            version: EXPORT_FORMAT_VERSION,
+            start,
+            total,
+            records,
+        }
+        .to_xdr(&env))
+    }
+
+    /// Get a verifiable receipt for a settled match
+    ///
+    /// Returns `None` if the match has not been settled. Gated like
+    /// `get_settlement`, since the receipt carries the full record. For a
+    /// basket match `record_xdr` encodes its `BasketRecord`.
+    pub fn get_settlement_receipt(
+        env: Env,
+        viewer: Option<Address>,
+        match_id: BytesN<32>,
+    ) -> Result<Option<SettlementReceipt>, SettlementError> {
+        let hash = match Self::load_receipt(&env, &match_id) {
+            Some(hash) => hash,
+            None => return Ok(None),
+        };
+        let record_xdr = match Self::get_settlement(env.clone(), viewer.clone(), match_id.clone())? {
+            Some(record) => record.to_xdr(&env),
+            #[cfg(feature = "baskets")]
+            None => match Self::get_basket(env.clone(), viewer, match_id)? {
+                Some(record) => record.to_xdr(&env),
+                None => return Ok(None),
+            },
+            #[cfg(not(feature = "baskets"))]
+            None => return Ok(None),
+        };
+
+        Ok(Some(SettlementReceipt { record_xdr, hash }))
+    }
+
+    /// Get the head of the hash-chained settlement log
+    ///
+    /// Auditors replay exported records through the chain and compare the
+    /// result, so any altered, dropped or reordered record is detected.
+    pub fn get_log_head(env: Env) -> SettlementLogHead {
+        env.storage().instance().get(&LOG_HEAD_KEY).unwrap_or(SettlementLogHead {
+            hash: BytesN::from_array(&env, &[0u8; 32]),
+            length: 0,
+        })
+    }
+
+    /// Check a presented receipt encoding against the committed hash
+    pub fn verify_settlement_receipt(env: Env, match_id: BytesN<32>, record_xdr: Bytes) -> bool {
+        match Self::load_receipt(&env, &match_id) {
+            Some(hash) => BytesN::from(env.crypto().sha256(&record_xdr)) == hash,
+            None => false,
+        }
+    }
+
+    /// Store the whitelist root and any pending attestation for a settled match
+    pub(crate) fn record_compliance_evidence(env: &Env, match_id: &BytesN<32>, whitelist_root: &BytesN<32>) {
+        let pending = (ATTEST_KEY, match_id.clone());
+        let attestation: Option<ComplianceAttestation> = env.storage().persistent().get(&pending);
+        if attestation.is_some() {
+            env.storage().persistent().remove(&pending);
+        }
+
+        let entry = (EVIDENCE_KEY, match_id.clone());
+        let evidence = ComplianceEvidence {
+            whitelist_root: whitelist_root.clone(),
+            attestation_root: attestation.as_ref().map(|a| a.attestation_root.clone()),
+            jurisdiction_cleared: attestation.map(|a| a.jurisdiction_cleared).unwrap_or(false),
+            recorded_at: env.ledger().timestamp(),
+        };
+        env.storage().persistent().set(&entry, &evidence);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    /// Hash the canonical encoding of a settled match's record and commit to it
+    ///
+    /// `record_xdr` is a `SettlementRecord` or, for baskets, a `BasketRecord`.
+    pub(crate) fn store_receipt(env: &Env, match_id: &BytesN<32>, record_xdr: &Bytes) -> BytesN<32> {
+        let hash: BytesN<32> = env.crypto().sha256(record_xdr).into();
+
+        let entry = (RECEIPTS_KEY, match_id.clone());
+        env.storage().persistent().set(&entry, &hash);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        hash
+    }
+
+    /// Chain a settlement's receipt hash into the settlement log
+    ///
+    /// Only the head is kept, in instance storage. Baskets are not chained,
+    /// since the log is replayed against `export_settlements`.
+    pub(crate) fn append_to_log(env: &Env, hash: &BytesN<32>) {
+        let head = Self::get_log_head(env.clone());
+        let mut preimage = Bytes::from(head.hash);
+        preimage.append(&hash.clone().into());
+        let head = SettlementLogHead {
+            hash: env.crypto().sha256(&preimage).into(),
+            length: head.length + 1,
+        };
+        env.storage().instance().set(&LOG_HEAD_KEY, &head);
+    }
+
+    fn load_receipt(env: &Env, match_id: &BytesN<32>) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(RECEIPTS_KEY, match_id.clone()))
+    }
+
+    /// Authenticate a record viewer, returning whether it may read every record
+    ///
+    /// Without record privacy or commitment-only records everyone may.
+    /// Otherwise the viewer must be present and authenticate; only the admin
+    /// and auditors see everything.
+    pub(crate) fn require_record_viewer(env: &Env, viewer: &Option<Address>) -> Result<bool, SettlementError> {
+        let gated = Self::is_record_privacy(env.clone())
+            || Self::get_record_format(env.clone()) == RecordFormat::CommitmentOnly;
+        if !gated {
+            return Ok(true);
+        }
+        let viewer = viewer.as_ref().ok_or(SettlementError::RecordAccessDenied)?;
+        viewer.require_auth();
+        Ok(*viewer == Self::get_admin(env.clone()) || Self::is_auditor(env.clone(), viewer.clone()))
+    }
+
+    /// Give a newly settled match the next sequence number
+    ///
+    /// Each match is sequenced exactly once, so a match can never reappear
+    /// later in the order.
+    pub(crate) fn assign_sequence(env: &Env, match_id: &BytesN<32>) -> Result<u64, SettlementError> {
+        let entry = (MATCH_SEQ_KEY, match_id.clone());
+        if env.storage().persistent().has(&entry) {
+            return Err(SettlementError::AlreadySettled);
+        }
+
+        let sequence = Self::get_last_sequence(env.clone()) + 1;
+        env.storage().instance().set(&SEQUENCE_KEY, &sequence);
+        env.storage().persistent().set(&entry, &sequence);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+
+        SettlementSequenced {
+            sequence,
+            match_id: match_id.clone(),
+        }
+        .publish(env);
+        Ok(sequence)
+    }
+
+    /// Store the public commitment-only form of a settlement
+    pub(crate) fn store_commitment(env: &Env, record: &SettlementRecord, asset_hash: &BytesN<32>) {
+        let entry = (COMMITMENTS_KEY, record.match_id.clone());
+        let commitment = SettlementRecordPrivate {
+            match_id: record.match_id.clone(),
+            nullifier: record.nullifier.clone(),
+            asset_hash: asset_hash.clone(),
+            amount_bucket: record.quantity.max(1).ilog2(),
+        };
+        env.storage().persistent().set(&entry, &commitment);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+}
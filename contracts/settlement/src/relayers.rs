@@ -0,0 +1,264 @@
+//! Relayed settlements, relayer rate limits and registration
+//!
+//! Only compiled with the `relayers` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, Symbol};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, SettlementError, SettlementRecord,
+    DEFAULT_SUB_ACCOUNT, RATE_LIMIT_KEY, REGISTERED_RELAYERS_KEY, registry_wasm,
+};
+
+const RELAY_WINDOW_KEY: Symbol = symbol_short!("relay_win");
+
+const IDEMPOTENCY_KEY: Symbol = symbol_short!("idem_key");
+
+// Relayer idempotency keys are remembered for about a day as well
+const IDEMPOTENCY_TTL_LEDGERS: u32 = 17_280;
+
+/// Cap on settlements each relayer may submit per ledger window
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct RelayerRateLimit {
+    pub max_settlements: u32,
+    pub window_ledgers: u32,
+}
+
+/// Settlements a relayer has submitted in its current window
+#[derive(Clone)]
+#[contracttype]
+pub struct RelayerWindow {
+    pub window_start: u32,
+    pub count: u32,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Settle a matched trade submitted by an identified relayer
+    ///
+    /// Identical to `settle_trade`, but counts the settlement against the
+    /// relayer's rate limit and accrues the relayer share of the fees to it.
+    /// Once a rate limit is configured, this is the only way to settle trades.
+    ///
+    /// # Arguments
+    /// * `relayer` - Relayer submitting the settlement (must authenticate)
+    pub fn settle_trade_relayed(
+        env: Env,
+        relayer: Address,
+        match_id: BytesN<32>,
+        buyer: Address,
+        seller: Address,
+        asset_address: Address,
+        payment_asset: Address,
+        quantity: i128,
+        price: i128,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<SettlementRecord, SettlementError> {
+        relayer.require_auth();
+        Self::require_registered_relayer(&env, &relayer)?;
+        Self::consume_relayer_quota(&env, &relayer)?;
+
+        Self::execute_settlement(
+            &env,
+            Some(&relayer),
+            Self::get_auth_mode(env.clone()),
+            &match_id,
+            &buyer,
+            &DEFAULT_SUB_ACCOUNT,
+            &seller,
+            &DEFAULT_SUB_ACCOUNT,
+            &asset_address,
+            &payment_asset,
+            quantity,
+            price,
+            &proof_bytes,
+            &pub_signals_bytes,
+        )
+    }
+
+    /// Settle a matched trade under a relayer-chosen idempotency key
+    ///
+    /// Identical to `settle_trade_relayed`, except that resubmitting with a
+    /// key the relayer already used for the same match returns the original
+    /// record instead of failing, so a relayer can retry after an RPC
+    /// timeout without checking whether the first attempt landed. Keys are
+    /// scoped to the relayer and remembered for about a day.
+    ///
+    /// # Arguments
+    /// * `relayer` - Relayer submitting the settlement (must authenticate)
+    /// * `idempotency_key` - Key identifying this submission
+    pub fn settle_trade_idempotent(
+        env: Env,
+        relayer: Address,
+        idempotency_key: BytesN<32>,
+        match_id: BytesN<32>,
+        buyer: Address,
+        seller: Address,
+        asset_address: Address,
+        payment_asset: Address,
+        quantity: i128,
+        price: i128,
+        proof_bytes: Bytes,
+        pub_signals_bytes: Bytes,
+    ) -> Result<SettlementRecord, SettlementError> {
+        relayer.require_auth();
+
+        let entry = (IDEMPOTENCY_KEY, relayer.clone(), idempotency_key);
+        let settled: Option<BytesN<32>> = env.storage().temporary().get(&entry);
+        if let Some(settled) = settled {
+            if settled != match_id {
+                return Err(SettlementError::IdempotencyKeyReused);
+            }
+            return Self::load_settlement(&env, &match_id).ok_or(SettlementError::MatchNotFound);
+        }
+
+        Self::require_registered_relayer(&env, &relayer)?;
+        Self::consume_relayer_quota(&env, &relayer)?;
+        let record = Self::execute_settlement(
+            &env,
+            Some(&relayer),
+            Self::get_auth_mode(env.clone()),
+            &match_id,
+            &buyer,
+            &DEFAULT_SUB_ACCOUNT,
+            &seller,
+            &DEFAULT_SUB_ACCOUNT,
+            &asset_address,
+            &payment_asset,
+            quantity,
+            price,
+            &proof_bytes,
+            &pub_signals_bytes,
+        )?;
+
+        env.storage().temporary().set(&entry, &match_id);
+        env.storage()
+            .temporary()
+            .extend_ttl(&entry, IDEMPOTENCY_TTL_LEDGERS, IDEMPOTENCY_TTL_LEDGERS);
+        Ok(record)
+    }
+
+    /// Limit how many settlements each relayer may submit per ledger window
+    ///
+    /// Quotas are kept per relayer address, so a limit is only enforceable
+    /// while relayers must be registered (see
+    /// `set_require_registered_relayers`); otherwise a relayer could rotate
+    /// to fresh addresses. Setting a limit fails with
+    /// `RelayerRegistrationRequired` until registration is required.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `limit` - Per-relayer cap, or `None` to remove rate limiting
+    pub fn set_relayer_rate_limit(
+        env: Env,
+        admin: Address,
+        limit: Option<RelayerRateLimit>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        match limit {
+            Some(limit) => {
+                if limit.window_ledgers == 0 {
+                    return Err(SettlementError::InvalidAmount);
+                }
+                if !Self::requires_registered_relayers(env.clone()) {
+                    return Err(SettlementError::RelayerRegistrationRequired);
+                }
+                env.storage().instance().set(&RATE_LIMIT_KEY, &limit);
+            }
+            None => env.storage().instance().remove(&RATE_LIMIT_KEY),
+        }
+        Ok(())
+    }
+
+    /// Get the per-relayer rate limit, if configured
+    pub fn get_relayer_rate_limit(env: Env) -> Option<RelayerRateLimit> {
+        env.storage().instance().get(&RATE_LIMIT_KEY)
+    }
+
+    /// Only accept relayers registered in the registry
+    ///
+    /// Once enabled, `settle_trade_relayed`, `settle_trade_idempotent` and
+    /// `propose_match` reject relayers without the registry's `Relayer`
+    /// role, and settlements that do not name a relayer are refused. Cannot
+    /// be disabled while a relayer rate limit is set.
+    pub fn set_require_registered_relayers(env: Env, admin: Address, required: bool) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if !required && Self::get_relayer_rate_limit(env.clone()).is_some() {
+            return Err(SettlementError::RelayerRegistrationRequired);
+        }
+
+        env.storage().instance().set(&REGISTERED_RELAYERS_KEY, &required);
+        Ok(())
+    }
+
+    /// Check whether relayers must be registered in the registry
+    pub fn requires_registered_relayers(env: Env) -> bool {
+        env.storage().instance().get(&REGISTERED_RELAYERS_KEY).unwrap_or(false)
+    }
+
+    /// Get how many settlements a relayer has left in its current window
+    pub fn get_relayer_remaining(env: Env, relayer: Address) -> Option<u32> {
+        let limit = Self::get_relayer_rate_limit(env.clone())?;
+        let used = Self::relayer_window(&env, &relayer, &limit).count;
+        Some(limit.max_settlements.saturating_sub(used))
+    }
+
+    /// Reject settlements that bypass relayer rate limiting or registration once enabled
+    pub(crate) fn require_unmetered(env: &Env) -> Result<(), SettlementError> {
+        if Self::get_relayer_rate_limit(env.clone()).is_some() || Self::requires_registered_relayers(env.clone()) {
+            return Err(SettlementError::RelayerRequired);
+        }
+        Ok(())
+    }
+
+    /// Reject relayers the registry does not list, when registration is required
+    pub(crate) fn require_registered_relayer(env: &Env, relayer: &Address) -> Result<(), SettlementError> {
+        if Self::requires_registered_relayers(env.clone())
+            && !Self::has_service_role(env, relayer, registry_wasm::ServiceRole::Relayer)
+        {
+            return Err(SettlementError::RelayerNotRegistered);
+        }
+        Ok(())
+    }
+
+    /// The relayer's window, reset if the previous one has elapsed
+    fn relayer_window(env: &Env, relayer: &Address, limit: &RelayerRateLimit) -> RelayerWindow {
+        let current = env.ledger().sequence();
+        let window: Option<RelayerWindow> = env
+            .storage()
+            .temporary()
+            .get(&(RELAY_WINDOW_KEY, relayer.clone()));
+        match window {
+            Some(window) if current < window.window_start + limit.window_ledgers => window,
+            _ => RelayerWindow {
+                window_start: current,
+                count: 0,
+            },
+        }
+    }
+
+    /// Count a settlement against the relayer's quota for the current window
+    pub(crate) fn consume_relayer_quota(env: &Env, relayer: &Address) -> Result<(), SettlementError> {
+        let limit = match Self::get_relayer_rate_limit(env.clone()) {
+            Some(limit) => limit,
+            None => return Ok(()),
+        };
+
+        let mut window = Self::relayer_window(env, relayer, &limit);
+        if window.count >= limit.max_settlements {
+            return Err(SettlementError::RateLimited);
+        }
+        window.count += 1;
+
+        let entry = (RELAY_WINDOW_KEY, relayer.clone());
+        env.storage().temporary().set(&entry, &window);
+        env.storage()
+            .temporary()
+            .extend_ttl(&entry, limit.window_ledgers, limit.window_ledgers);
+        Ok(())
+    }
+}
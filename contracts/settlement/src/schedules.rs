@@ -0,0 +1,334 @@
+//! TWAP and iceberg order schedules
+//!
+//! Only compiled with the `schedules` feature.
+
+use soroban_sdk::{contractimpl, contracttype, symbol_short, vec, Address, Bytes, BytesN, Env, Symbol, Vec};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, SettlementError, BALANCE_TTL_EXTEND_TO,
+    BALANCE_TTL_THRESHOLD,
+};
+
+const TWAP_KEY: Symbol = symbol_short!("twap");
+
+const TWAP_SLICE_KEY: Symbol = symbol_short!("twap_slc");
+
+const ICEBERG_KEY: Symbol = symbol_short!("iceberg");
+
+const ICEBERG_SLICE_KEY: Symbol = symbol_short!("ice_slc");
+
+/// Limits a TWAP parent order's slices must keep to
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TwapSchedule {
+    /// Total quantity the parent order may fill across all slices
+    pub total_quantity: i128,
+    /// Largest quantity a single slice may fill
+    pub max_slice: i128,
+    /// Minimum seconds between two slice fills
+    pub min_spacing: u64,
+    /// Earliest ledger timestamp of the first fill
+    pub start: u64,
+}
+
+/// A TWAP parent order and its progress
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TwapOrder {
+    pub owner: Address,
+    pub schedule: TwapSchedule,
+    pub filled: i128,
+    pub slices: u32,
+    /// Timestamp of the latest slice fill, if any
+    pub last_fill: Option<u64>,
+}
+
+/// An iceberg parent order, tracked without revealing its total size
+///
+/// The parent is the head of a hash chain over its child order
+/// commitments: `head = sha256(child || next)`, ending in the all-zero
+/// hash. Children are revealed one at a time, so the chain bounds the
+/// total fill while only the displayed child is ever public.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct IcebergOrder {
+    pub owner: Address,
+    /// Commitment to the children not yet revealed
+    pub head: BytesN<32>,
+    /// Revealed child waiting to fill, if any
+    pub displayed: Option<BytesN<32>>,
+    pub filled: i128,
+    pub fills: u32,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Open a TWAP parent order
+    ///
+    /// The parent commitment identifies an order worked over time. Each
+    /// slice is its own order commitment, linked to the parent with
+    /// `attach_twap_slice`, and settles through the normal proof path; the
+    /// contract then enforces the schedule's spacing and size limits.
+    ///
+    /// # Arguments
+    /// * `owner` - Trader working the order (must authenticate)
+    /// * `parent` - Parent order commitment
+    /// * `schedule` - Slice limits for the parent
+    pub fn create_twap(
+        env: Env,
+        owner: Address,
+        parent: BytesN<32>,
+        schedule: TwapSchedule,
+    ) -> Result<(), SettlementError> {
+        owner.require_auth();
+
+        if schedule.max_slice <= 0 || schedule.total_quantity < schedule.max_slice {
+            return Err(SettlementError::InvalidAmount);
+        }
+        if Self::get_twap(env.clone(), parent.clone()).is_some() {
+            return Err(SettlementError::TwapAlreadyExists);
+        }
+        Self::write_twap(
+            &env,
+            &parent,
+            &TwapOrder {
+                owner,
+                schedule,
+                filled: 0,
+                slices: 0,
+                last_fill: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Link a slice's order commitment to its TWAP parent
+    ///
+    /// Links are scoped to the owner, so only settlements where the owner
+    /// is the party behind `child` count against the parent.
+    pub fn attach_twap_slice(
+        env: Env,
+        owner: Address,
+        parent: BytesN<32>,
+        child: BytesN<32>,
+    ) -> Result<(), SettlementError> {
+        owner.require_auth();
+
+        let order = Self::get_twap(env.clone(), parent.clone()).ok_or(SettlementError::TwapNotFound)?;
+        if order.owner != owner {
+            return Err(SettlementError::TwapNotFound);
+        }
+        let entry = (TWAP_SLICE_KEY, owner, child);
+        env.storage().persistent().set(&entry, &parent);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        Ok(())
+    }
+
+    /// Close a TWAP parent order
+    ///
+    /// Slices still linked to it can no longer settle.
+    pub fn cancel_twap(env: Env, owner: Address, parent: BytesN<32>) -> Result<(), SettlementError> {
+        owner.require_auth();
+
+        let order = Self::get_twap(env.clone(), parent.clone()).ok_or(SettlementError::TwapNotFound)?;
+        if order.owner != owner {
+            return Err(SettlementError::TwapNotFound);
+        }
+        env.storage().persistent().remove(&(TWAP_KEY, parent));
+        Ok(())
+    }
+
+    /// Get a TWAP parent order and its progress
+    pub fn get_twap(env: Env, parent: BytesN<32>) -> Option<TwapOrder> {
+        env.storage().persistent().get(&(TWAP_KEY, parent))
+    }
+
+    /// Get the TWAP parent a party's order commitment is linked to, if any
+    pub fn get_twap_parent(env: Env, owner: Address, child: BytesN<32>) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&(TWAP_SLICE_KEY, owner, child))
+    }
+
+    /// Open an iceberg parent order
+    ///
+    /// # Arguments
+    /// * `owner` - Trader working the order (must authenticate)
+    /// * `parent` - Head of the hash chain over the child commitments
+    pub fn create_iceberg(env: Env, owner: Address, parent: BytesN<32>) -> Result<(), SettlementError> {
+        owner.require_auth();
+
+        if Self::get_iceberg(env.clone(), parent.clone()).is_some() {
+            return Err(SettlementError::IcebergAlreadyExists);
+        }
+        Self::write_iceberg(
+            &env,
+            &parent,
+            &IcebergOrder {
+                owner,
+                head: parent.clone(),
+                displayed: None,
+                filled: 0,
+                fills: 0,
+            },
+        );
+        Ok(())
+    }
+
+    /// Reveal the next child of an iceberg order
+    ///
+    /// `sha256(child || next)` must open the current head; `next` becomes
+    /// the head. Only one child is displayed at a time, and once the head
+    /// is the all-zero hash the order is exhausted.
+    ///
+    /// # Arguments
+    /// * `owner` - Owner of the iceberg (must authenticate)
+    /// * `parent` - Parent order commitment
+    /// * `child` - Order commitment of the next slice
+    /// * `next` - Commitment to the remaining children
+    pub fn reveal_iceberg_slice(
+        env: Env,
+        owner: Address,
+        parent: BytesN<32>,
+        child: BytesN<32>,
+        next: BytesN<32>,
+    ) -> Result<(), SettlementError> {
+        owner.require_auth();
+
+        let mut order = Self::get_iceberg(env.clone(), parent.clone()).ok_or(SettlementError::IcebergNotFound)?;
+        if order.owner != owner {
+            return Err(SettlementError::IcebergNotFound);
+        }
+        if order.displayed.is_some() {
+            return Err(SettlementError::IcebergSlicePending);
+        }
+        if order.head == BytesN::from_array(&env, &[0u8; 32]) {
+            return Err(SettlementError::IcebergExhausted);
+        }
+        let mut opening = Bytes::from_array(&env, &child.to_array());
+        opening.extend_from_array(&next.to_array());
+        if BytesN::<32>::from(env.crypto().sha256(&opening)) != order.head {
+            return Err(SettlementError::IcebergSliceMismatch);
+        }
+
+        let entry = (ICEBERG_SLICE_KEY, owner, child.clone());
+        env.storage().persistent().set(&entry, &parent);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+
+        order.head = next;
+        order.displayed = Some(child);
+        Self::write_iceberg(&env, &parent, &order);
+        Ok(())
+    }
+
+    /// Close an iceberg parent order
+    ///
+    /// Children already revealed can no longer settle.
+    pub fn cancel_iceberg(env: Env, owner: Address, parent: BytesN<32>) -> Result<(), SettlementError> {
+        owner.require_auth();
+
+        let order = Self::get_iceberg(env.clone(), parent.clone()).ok_or(SettlementError::IcebergNotFound)?;
+        if order.owner != owner {
+            return Err(SettlementError::IcebergNotFound);
+        }
+        env.storage().persistent().remove(&(ICEBERG_KEY, parent));
+        Ok(())
+    }
+
+    /// Get an iceberg parent order and its progress
+    pub fn get_iceberg(env: Env, parent: BytesN<32>) -> Option<IcebergOrder> {
+        env.storage().persistent().get(&(ICEBERG_KEY, parent))
+    }
+
+    /// Check TWAP slices among the matched commitments against their schedules
+    ///
+    /// Returns each affected parent with the fill applied, to be written
+    /// once the proof has verified.
+    pub(crate) fn check_twap_slices(
+        env: &Env,
+        buyer: &Address,
+        seller: &Address,
+        buy_commitment: &BytesN<32>,
+        sell_commitment: &BytesN<32>,
+        quantity: i128,
+    ) -> Result<Vec<(BytesN<32>, TwapOrder)>, SettlementError> {
+        let now = env.ledger().timestamp();
+        let mut fills = vec![env];
+        for (party, commitment) in [(buyer, buy_commitment), (seller, sell_commitment)] {
+            let Some(parent) = Self::get_twap_parent(env.clone(), party.clone(), commitment.clone()) else {
+                continue;
+            };
+            let mut order = Self::get_twap(env.clone(), parent.clone()).ok_or(SettlementError::TwapNotFound)?;
+            if quantity > order.schedule.max_slice {
+                return Err(SettlementError::TwapSliceTooLarge);
+            }
+            let earliest = match order.last_fill {
+                Some(last) => last.saturating_add(order.schedule.min_spacing),
+                None => order.schedule.start,
+            };
+            if now < earliest {
+                return Err(SettlementError::TwapSliceTooEarly);
+            }
+            if order.filled + quantity > order.schedule.total_quantity {
+                return Err(SettlementError::TwapOverfilled);
+            }
+            order.filled += quantity;
+            order.slices += 1;
+            order.last_fill = Some(now);
+            fills.push_back((parent, order));
+        }
+        Ok(fills)
+    }
+
+    /// Check revealed iceberg children among the matched commitments
+    ///
+    /// A child fills once, while it is the displayed slice; settling it
+    /// again would fill the parent beyond its chain. Returns each affected
+    /// parent with the fill applied, to be written once the proof verifies.
+    pub(crate) fn check_iceberg_slices(
+        env: &Env,
+        buyer: &Address,
+        seller: &Address,
+        buy_commitment: &BytesN<32>,
+        sell_commitment: &BytesN<32>,
+        quantity: i128,
+    ) -> Result<Vec<(BytesN<32>, IcebergOrder)>, SettlementError> {
+        let mut fills = vec![env];
+        for (party, child) in [(buyer, buy_commitment), (seller, sell_commitment)] {
+            let Some(parent) = env
+                .storage()
+                .persistent()
+                .get::<_, BytesN<32>>(&(ICEBERG_SLICE_KEY, party.clone(), child.clone()))
+            else {
+                continue;
+            };
+            let mut order = Self::get_iceberg(env.clone(), parent.clone()).ok_or(SettlementError::IcebergNotFound)?;
+            if order.displayed.as_ref() != Some(child) {
+                return Err(SettlementError::IcebergOverfilled);
+            }
+            order.displayed = None;
+            order.filled += quantity;
+            order.fills += 1;
+            fills.push_back((parent, order));
+        }
+        Ok(fills)
+    }
+
+    pub(crate) fn write_iceberg(env: &Env, parent: &BytesN<32>, order: &IcebergOrder) {
+        let entry = (ICEBERG_KEY, parent.clone());
+        env.storage().persistent().set(&entry, order);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+
+    pub(crate) fn write_twap(env: &Env, parent: &BytesN<32>, order: &TwapOrder) {
+        let entry = (TWAP_KEY, parent.clone());
+        env.storage().persistent().set(&entry, order);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+    }
+}
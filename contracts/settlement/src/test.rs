@@ -1,97 +1,5426 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Bytes, BytesN, Env};
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::{self, StellarAssetClient},
+    BytesN, Env,
+};
+
+/// The settlement contract as first released, storing everything in instance storage
+#[cfg(feature = "ops")]
+mod settlement_v1_wasm {
+    soroban_sdk::contractimport!(file = "testdata/darkpool_settlement_v1.wasm");
+}
 
 // Note: Full integration tests require deploying the verifier and registry contracts first.
 // These are basic unit tests for escrow functionality.
 
+/// Register the settlement contract with placeholder dependencies so that
+/// internal helpers can be exercised inside its storage context.
+fn register_settlement(env: &Env) -> Address {
+    let admin = Address::generate(env);
+    register_settlement_with_admin(env, &admin)
+}
+
+fn register_settlement_with_admin(env: &Env, admin: &Address) -> Address {
+    let verifier = Address::generate(env);
+    let vk_bytes = Bytes::from_slice(env, &[0u8; 100]);
+    // Token transfers look up the asset's adapter in the registry
+    let registry = env.register(registry_wasm::WASM, (admin, &verifier, &vk_bytes));
+    env.register(DarkPoolSettlement, (admin, &registry, &verifier, &vk_bytes))
+}
+
 #[test]
 fn test_escrow_balance_tracking() {
     let env = Env::default();
+    let contract_id = register_settlement(&env);
 
-    let participant = Address::generate(&env);
-    let asset = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let participant = Address::generate(&env);
+        let asset = Address::generate(&env);
 
-    // Initially zero
-    let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(balance, 0);
+        // Initially zero
+        let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(balance, 0);
 
-    // Add balance
-    DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
-    let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(balance, 1000);
+        // Add balance
+        DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
+        let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(balance, 1000);
 
-    // Add more
-    DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 500);
-    let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(balance, 1500);
+        // Add more
+        DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 500);
+        let balance = DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(balance, 1500);
+    });
 }
 
 #[test]
 fn test_locked_balance_tracking() {
     let env = Env::default();
+    let contract_id = register_settlement(&env);
+
+    env.as_contract(&contract_id, || {
+        let participant = Address::generate(&env);
+        let asset = Address::generate(&env);
+
+        // Add escrow first
+        DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
+
+        // Lock some
+        DarkPoolSettlement::add_locked_balance(&env, &participant, &asset, 400);
+        let locked = DarkPoolSettlement::get_locked_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(locked, 400);
+
+        // Available should be escrow - locked
+        let available = DarkPoolSettlement::get_available_balance(env.clone(), participant.clone(), asset.clone());
+        assert_eq!(available, 600);
+    });
+}
+
+#[test]
+fn test_nullifier_tracking() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+
+    env.as_contract(&contract_id, || {
+        let nullifier = BytesN::from_array(&env, &[1u8; 32]);
+
+        // Should not be used initially
+        assert!(!DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
+
+        // Mark as used
+        DarkPoolSettlement::mark_nullifier_used(&env, &nullifier);
+
+        // Should be used now
+        assert!(DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
+    });
+}
+
+#[cfg(feature = "forwards")]
+#[test]
+fn test_escrow_transfer() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+
+    env.as_contract(&contract_id, || {
+        let alice = Address::generate(&env);
+        let bob = Address::generate(&env);
+        let asset = Address::generate(&env);
+
+        // Give Alice some balance and lock it
+        DarkPoolSettlement::add_escrow_balance(&env, &alice, &asset, 1000);
+        DarkPoolSettlement::add_locked_balance(&env, &alice, &asset, 1000);
+
+        // Transfer from Alice to Bob
+        let result = DarkPoolSettlement::transfer_between(
+            &env,
+            &EscrowKey::main(&alice, &asset),
+            &EscrowKey::main(&bob, &asset),
+            500,
+        );
+        assert!(result.is_ok());
+
+        // Check balances
+        let alice_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), alice.clone(), asset.clone());
+        let bob_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), bob.clone(), asset.clone());
+
+        assert_eq!(alice_balance, 500);
+        assert_eq!(bob_balance, 500);
+
+        // Alice's locked balance should also decrease
+        let alice_locked = DarkPoolSettlement::get_locked_balance(env.clone(), alice.clone(), asset.clone());
+        assert_eq!(alice_locked, 500);
+    });
+}
+
+/// Oracle returning a fixed FX rate set at construction
+#[contract]
+struct MockFxOracle;
+
+#[contractimpl]
+impl MockFxOracle {
+    pub fn __constructor(env: Env, rate: i128) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+
+    pub fn get_rate(env: Env, _base: Address, _quote: Address) -> i128 {
+        env.storage().instance().get(&symbol_short!("rate")).unwrap()
+    }
+
+    pub fn set_rate(env: Env, rate: i128) {
+        env.storage().instance().set(&symbol_short!("rate"), &rate);
+    }
+
+    pub fn last_updated(env: Env, _asset: Address) -> u64 {
+        env.storage().instance().get(&symbol_short!("updated")).unwrap_or(0)
+    }
+
+    pub fn set_updated(env: Env, timestamp: u64) {
+        env.storage().instance().set(&symbol_short!("updated"), &timestamp);
+    }
+}
+
+#[cfg(feature = "fx")]
+#[test]
+fn test_fx_conversion_within_tolerance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let eurc = Address::generate(&env);
+
+    // Oracle says 1 USDC = 0.92 EURC; the configured pair rate is 0.921
+    let oracle = env.register(MockFxOracle, (9_200_000i128,));
+    client.set_quote_asset(&admin, &asset, &usdc);
+    client.set_fx_oracle(&admin, &oracle);
+    client.set_fx_rate(&admin, &usdc, &eurc, &9_210_000);
+    client.set_fx_tolerance(&admin, &50);
+
+    env.as_contract(&contract_id, || {
+        // Paying in the quote currency needs no conversion
+        let same = DarkPoolSettlement::convert_payment(&env, &asset, &usdc, 1_000_000);
+        assert_eq!(same, Ok(1_000_000));
+
+        let converted = DarkPoolSettlement::convert_payment(&env, &asset, &eurc, 1_000_000);
+        assert_eq!(converted, Ok(921_000));
+    });
+
+    // Tighten tolerance below the ~10.9 bps drift
+    client.set_fx_tolerance(&admin, &10);
+    env.as_contract(&contract_id, || {
+        let result = DarkPoolSettlement::convert_payment(&env, &asset, &eurc, 1_000_000);
+        assert_eq!(result, Err(SettlementError::FxRateOutOfTolerance));
+    });
+}
+
+#[cfg(feature = "fx")]
+#[test]
+fn test_fx_conversion_requires_pair_rate() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = Address::generate(&env);
+    let usdc = Address::generate(&env);
+    let eurc = Address::generate(&env);
+    client.set_quote_asset(&admin, &asset, &usdc);
+
+    env.as_contract(&contract_id, || {
+        let result = DarkPoolSettlement::convert_payment(&env, &asset, &eurc, 1_000_000);
+        assert_eq!(result, Err(SettlementError::FxRateNotSet));
+    });
+
+    let outsider = Address::generate(&env);
+    let result = client.try_set_fx_tolerance(&outsider, &50);
+    assert_eq!(result, Err(Ok(SettlementError::OnlyAdmin)));
+}
+
+#[cfg(feature = "delivery")]
+#[test]
+fn test_delayed_delivery_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[7u8; 32]);
+    let later_id = BytesN::from_array(&env, &[8u8; 32]);
+
+    client.set_settlement_delay(&admin, &asset, &10);
+
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::add_escrow_balance(&env, &seller, &asset, 1000);
+        DarkPoolSettlement::add_locked_balance(&env, &seller, &asset, 1000);
+        let from = EscrowKey::main(&seller, &asset);
+        let to = EscrowKey::main(&buyer, &asset);
+        DarkPoolSettlement::deliver_leg(&env, &match_id, &from, &to, 400);
+    });
+    env.ledger().with_mut(|li| li.sequence_number += 5);
+    env.as_contract(&contract_id, || {
+        let from = EscrowKey::main(&seller, &asset);
+        let to = EscrowKey::main(&buyer, &asset);
+        DarkPoolSettlement::deliver_leg(&env, &later_id, &from, &to, 100);
+    });
+    env.ledger().with_mut(|li| li.sequence_number -= 5);
+
+    // Seller is debited immediately, buyer is not yet credited
+    assert_eq!(client.get_escrow_balance(&seller, &asset), 500);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 0);
+    assert_eq!(client.get_pending_deliveries(&match_id).len(), 1);
+
+    let early = client.try_claim_settled(&match_id);
+    assert_eq!(early, Err(Ok(SettlementError::DeliveryNotReady)));
+    assert!(client.get_unfinalized_settlements(&10).is_empty());
+
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    assert_eq!(client.get_unfinalized_settlements(&10), vec![&env, match_id.clone()]);
+    assert_eq!(client.claim_settled(&match_id), 1);
+    assert!(client.get_unfinalized_settlements(&10).is_empty());
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 400);
+    assert!(client.get_pending_deliveries(&match_id).is_empty());
+
+    let again = client.try_claim_settled(&match_id);
+    assert_eq!(again, Err(Ok(SettlementError::DeliveryNotFound)));
+
+    // Claiming the first match leaves the later one indexed
+    env.ledger().with_mut(|li| li.sequence_number += 5);
+    assert_eq!(client.get_unfinalized_settlements(&10), vec![&env, later_id.clone()]);
+    assert_eq!(client.claim_settled(&later_id), 1);
+    assert!(client.get_unfinalized_settlements(&10).is_empty());
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 500);
+}
+
+#[cfg(feature = "delivery")]
+#[test]
+fn test_watchtower_freezes_delayed_delivery() {
+    use soroban_sdk::{testutils::Events, Event};
+
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (seller, buyer, watcher) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    let match_id = BytesN::from_array(&env, &[8u8; 32]);
+    client.set_settlement_delay(&admin, &asset, &10);
+    client.set_watch_threshold(&admin, &usdc, &Some(50_000));
+    assert_eq!(client.try_set_watch_threshold(&admin, &usdc, &Some(0)), Err(Ok(SettlementError::InvalidAmount)));
+
+    // Only settlements at or above the threshold are reported
+    let record = |payment_amount: i128| SettlementRecord {
+        match_id: match_id.clone(),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        asset_address: asset.clone(),
+        quantity: 400,
+        price: payment_amount,
+        payment_asset: usdc.clone(),
+        payment_amount,
+        timestamp: 0,
+        nullifier: BytesN::from_array(&env, &[9u8; 32]),
+        scheme_version: COMMITMENT_SCHEME_V1,
+    };
+    env.as_contract(&contract_id, || DarkPoolSettlement::report_high_value(&env, &record(49_999)));
+    assert!(env.events().all().filter_by_contract(&contract_id).events().is_empty());
+    env.as_contract(&contract_id, || DarkPoolSettlement::report_high_value(&env, &record(50_000)));
+    let reported = HighValueSettlement {
+        match_id: match_id.clone(),
+        payment_asset: usdc.clone(),
+        payment_amount: 50_000,
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+    };
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [reported.to_xdr(&env, &contract_id)]
+    );
+
+    env.as_contract(&contract_id, || {
+        let from = EscrowKey::main(&seller, &asset);
+        DarkPoolSettlement::credit_escrow(&env, &from, 400);
+        DarkPoolSettlement::credit_locked(&env, &from, 400);
+        DarkPoolSettlement::deliver_leg(&env, &match_id, &from, &EscrowKey::main(&buyer, &asset), 400);
+    });
+
+    let unknown = client.try_freeze_delivery(&watcher, &match_id);
+    assert_eq!(unknown, Err(Ok(SettlementError::WatcherNotSubscribed)));
+    client.subscribe_watcher(&admin, &watcher);
+    assert_eq!(client.get_watchers(), vec![&env, watcher.clone()]);
+    let settled = client.try_freeze_delivery(&watcher, &BytesN::from_array(&env, &[1u8; 32]));
+    assert_eq!(settled, Err(Ok(SettlementError::DeliveryNotFound)));
+
+    client.freeze_delivery(&watcher, &match_id);
+    assert_eq!(client.get_frozen_by(&match_id), Some(watcher.clone()));
+
+    // The freeze outlasts the challenge window until the admin resolves it
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    assert_eq!(client.try_claim_settled(&match_id), Err(Ok(SettlementError::DeliveryFrozen)));
+    client.unsubscribe_watcher(&admin, &watcher);
+    assert_eq!(client.try_claim_settled(&match_id), Err(Ok(SettlementError::DeliveryFrozen)));
+
+    client.unfreeze_delivery(&admin, &match_id);
+    assert_eq!(client.claim_settled(&match_id), 1);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 400);
+}
+
+#[cfg(all(feature = "accounts", feature = "delivery"))]
+#[test]
+fn test_auto_relock_keeps_proceeds_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let maker = Address::generate(&env);
+    let taker = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let delayed_asset = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[34u8; 32]);
+    client.set_settlement_delay(&admin, &delayed_asset, &5);
+
+    client.set_auto_relock(&maker, &asset, &true);
+    client.set_auto_relock(&maker, &delayed_asset, &true);
+    assert!(client.is_auto_relock(&maker, &asset));
+    assert!(!client.is_auto_relock(&taker, &asset));
+
+    env.as_contract(&contract_id, || {
+        for a in [&asset, &delayed_asset] {
+            let taker_key = EscrowKey::main(&taker, a);
+            DarkPoolSettlement::credit_escrow(&env, &taker_key, 1_000);
+            DarkPoolSettlement::credit_locked(&env, &taker_key, 1_000);
+            DarkPoolSettlement::deliver_leg(&env, &match_id, &taker_key, &EscrowKey::main(&maker, a), 400);
+        }
+    });
+
+    assert_eq!(client.get_escrow_balance(&maker, &asset), 400);
+    assert_eq!(client.get_locked_balance(&maker, &asset), 400);
+
+    // Delayed legs are relocked when they are claimed
+    env.ledger().with_mut(|li| li.sequence_number += 5);
+    client.claim_settled(&match_id);
+    assert_eq!(client.get_locked_balance(&maker, &delayed_asset), 400);
+
+    client.set_auto_relock(&maker, &asset, &false);
+    assert!(!client.is_auto_relock(&maker, &asset));
+}
+
+#[cfg(all(feature = "accounts", feature = "corporate-actions"))]
+#[test]
+fn test_tax_lots_consumed_fifo() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(issuer).address();
+    let seller = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&seller, &1_000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 10);
+    client.deposit(&seller, &asset, &300);
+    client.deposit(&seller, &asset, &100);
+    env.ledger().with_mut(|li| li.sequence_number = 20);
+    client.deposit_to(&seller, &symbol_short!("fund"), &asset, &200);
+
+    // Deposits in the same ledger share a lot
+    let lot = |amount, ledger, source| TaxLot { amount, ledger, source };
+    assert_eq!(
+        client.get_lots(&seller, &asset),
+        vec![&env, lot(400, 10, LotSource::Deposit), lot(200, 20, LotSource::Deposit)]
+    );
+
+    // Withdrawals and settlement debits draw down the oldest lot first
+    client.withdraw(&seller, &asset, &150);
+    env.ledger().with_mut(|li| li.sequence_number = 30);
+    env.as_contract(&contract_id, || {
+        let seller_key = EscrowKey::main(&seller, &asset);
+        DarkPoolSettlement::credit_locked(&env, &seller_key, 200);
+        let match_id = BytesN::from_array(&env, &[35u8; 32]);
+        DarkPoolSettlement::deliver_leg(&env, &match_id, &seller_key, &EscrowKey::main(&buyer, &asset), 200);
+    });
+    assert_eq!(
+        client.get_lots(&seller, &asset),
+        vec![&env, lot(50, 10, LotSource::Deposit), lot(200, 20, LotSource::Deposit)]
+    );
+    assert_eq!(client.get_lots(&buyer, &asset), vec![&env, lot(200, 30, LotSource::Settlement)]);
+
+    // Lots span sub-accounts, so moving escrow between them changes nothing
+    client.transfer_between_subaccounts(&seller, &asset, &symbol_short!("fund"), &symbol_short!("main"), &100);
+    client.withdraw(&seller, &asset, &150);
+    assert_eq!(client.get_lots(&seller, &asset), vec![&env, lot(100, 20, LotSource::Deposit)]);
+}
+
+#[cfg(feature = "order-controls")]
+#[test]
+fn test_inventory_limit_rejects_runaway_fills() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let maker = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let payment_asset = Address::generate(&env);
+
+    client.set_inventory_limit(&maker, &asset, &Some(500));
+    assert_eq!(client.get_inventory_limit(&maker, &asset), Some(500));
+
+    let fill = |byte: u8, quantity: i128| {
+        env.as_contract(&contract_id, || {
+            let seller_asset = EscrowKey::main(&seller, &asset);
+            let buyer_payment = EscrowKey::main(&maker, &payment_asset);
+            DarkPoolSettlement::credit_escrow(&env, &seller_asset, quantity);
+            DarkPoolSettlement::credit_locked(&env, &seller_asset, quantity);
+            DarkPoolSettlement::credit_escrow(&env, &buyer_payment, 100);
+            DarkPoolSettlement::credit_locked(&env, &buyer_payment, 100);
+            DarkPoolSettlement::settle_spot(
+                &env,
+                None,
+                &BytesN::from_array(&env, &[byte; 32]),
+                &seller_asset,
+                &EscrowKey::main(&maker, &asset),
+                quantity,
+                &buyer_payment,
+                &EscrowKey::main(&seller, &payment_asset),
+                100,
+            )
+        })
+    };
+
+    assert_eq!(fill(80, 400), Ok(()));
+    assert_eq!(fill(81, 200), Err(SettlementError::InventoryLimitExceeded));
+    assert_eq!(client.get_escrow_balance(&maker, &asset), 400);
+    assert_eq!(fill(82, 100), Ok(()));
+
+    client.set_inventory_limit(&maker, &asset, &None);
+    assert_eq!(fill(83, 200), Ok(()));
+    assert_eq!(client.get_escrow_balance(&maker, &asset), 700);
+}
+
+#[cfg(feature = "forwards")]
+#[test]
+fn test_subaccount_segregation() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+
+    env.as_contract(&contract_id, || {
+        let participant = Address::generate(&env);
+        let asset = Address::generate(&env);
+        let client_a = Symbol::new(&env, "client_a");
+        let main = EscrowKey::main(&participant, &asset);
+        let sub = EscrowKey::new(&participant, &client_a, &asset);
+
+        DarkPoolSettlement::credit_escrow(&env, &sub, 1000);
+        DarkPoolSettlement::credit_locked(&env, &sub, 300);
+
+        // Sub-account funds are invisible to the default account
+        assert_eq!(DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone()), 0);
+        assert_eq!(DarkPoolSettlement::available_balance(&env, &sub), 700);
+
+        // Locked funds settle out of the sub-account they were locked in
+        assert!(DarkPoolSettlement::transfer_between(&env, &sub, &main, 300).is_ok());
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &ESCROW_KEY, &sub), 700);
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &LOCKED_KEY, &sub), 0);
+        assert_eq!(DarkPoolSettlement::get_escrow_balance(env.clone(), participant.clone(), asset.clone()), 300);
+    });
+}
+
+#[cfg(feature = "accounts")]
+#[test]
+fn test_transfer_between_subaccounts_respects_locks() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
 
     let participant = Address::generate(&env);
     let asset = Address::generate(&env);
+    let growth = Symbol::new(&env, "growth");
+
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
+        DarkPoolSettlement::add_locked_balance(&env, &participant, &asset, 600);
+    });
 
-    // Add escrow first
-    DarkPoolSettlement::add_escrow_balance(&env, &participant, &asset, 1000);
+    let too_much = client.try_transfer_between_subaccounts(
+        &participant,
+        &asset,
+        &DEFAULT_SUB_ACCOUNT,
+        &growth,
+        &500,
+    );
+    assert_eq!(too_much, Err(Ok(SettlementError::InsufficientBalance)));
 
-    // Lock some
-    DarkPoolSettlement::add_locked_balance(&env, &participant, &asset, 400);
-    let locked = DarkPoolSettlement::get_locked_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(locked, 400);
+    client.transfer_between_subaccounts(&participant, &asset, &DEFAULT_SUB_ACCOUNT, &growth, &400);
+    assert_eq!(client.get_escrow_balance(&participant, &asset), 600);
+    assert_eq!(client.get_subaccount_balance(&participant, &growth, &asset), 400);
 
-    // Available should be escrow - locked
-    let available = DarkPoolSettlement::get_available_balance(env.clone(), participant.clone(), asset.clone());
-    assert_eq!(available, 600);
+    client.lock_escrow_in(&participant, &growth, &asset, &400);
+    assert_eq!(client.get_subaccount_locked(&participant, &growth, &asset), 400);
 }
 
+#[cfg(feature = "accounts")]
 #[test]
-fn test_nullifier_tracking() {
+fn test_memo_deposits_route_to_client_subaccounts() {
+    use soroban_sdk::{testutils::Events, Event};
+
     let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
 
-    let nullifier = BytesN::from_array(&env, &[1u8; 32]);
+    let custodian = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    StellarAssetClient::new(&env, &asset).mint(&custodian, &1_000);
+    let (alice, bob) = (BytesN::from_array(&env, &[0xa1; 32]), BytesN::from_array(&env, &[0xb0; 32]));
+    let alice_account = client.get_memo_sub_account(&alice);
+    assert_eq!(alice_account, Symbol::new(&env, "ma1a1a1a1a1a1a1a1a1"));
 
-    // Initialize nullifiers storage
-    let nullifiers: Vec<BytesN<32>> = vec![&env];
-    env.storage().instance().set(&symbol_short!("nulls"), &nullifiers);
+    client.deposit_with_memo(&custodian, &alice, &asset, &600);
+    let routed = MemoDeposit {
+        depositor: custodian.clone(),
+        memo: alice.clone(),
+        sub_account: alice_account.clone(),
+        asset: asset.clone(),
+        amount: 600,
+    };
+    assert!(env.events().all().filter_by_contract(&contract_id).events().contains(&routed.to_xdr(&env, &contract_id)));
+    client.deposit_with_memo(&custodian, &bob, &asset, &400);
 
-    // Should not be used initially
-    assert!(!DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
+    // Each memo is its own account under the custodian's address
+    let bob_account = client.get_memo_sub_account(&bob);
+    assert_eq!(client.get_escrow_balance(&custodian, &asset), 0);
+    assert_eq!(client.get_subaccount_balance(&custodian, &alice_account, &asset), 600);
+    assert_eq!(client.get_subaccount_balance(&custodian, &bob_account, &asset), 400);
 
-    // Mark as used
-    DarkPoolSettlement::mark_nullifier_used(&env, &nullifier);
+    client.lock_escrow_in(&custodian, &bob_account, &asset, &300);
+    let locked = client.try_withdraw_from(&custodian, &bob_account, &asset, &200);
+    assert_eq!(locked, Err(Ok(SettlementError::InsufficientBalance)));
+    client.withdraw_from(&custodian, &alice_account, &asset, &600);
+    assert_eq!(token::TokenClient::new(&env, &asset).balance(&custodian), 600);
+}
+
+/// Router delivering a set share of the amount sold, whatever `min_out` asks
+#[cfg(feature = "accounts")]
+#[contract]
+struct MockPathRouter;
 
-    // Should be used now
-    assert!(DarkPoolSettlement::is_nullifier_used(env.clone(), nullifier.clone()));
+#[cfg(feature = "accounts")]
+#[contractimpl]
+impl MockPathRouter {
+    pub fn set_delivery_bps(env: Env, bps: i128) {
+        env.storage().instance().set(&symbol_short!("bps"), &bps);
+    }
+
+    pub fn swap_exact_in(env: Env, from: Address, path: Vec<Address>, amount_in: i128, min_out: i128, to: Address) -> i128 {
+        from.require_auth();
+        token::TokenClient::new(&env, &path.first_unchecked()).transfer(&from, env.current_contract_address(), &amount_in);
+        let bps: i128 = env.storage().instance().get(&symbol_short!("bps")).unwrap_or(10_000);
+        let out = amount_in * bps / 10_000;
+        StellarAssetClient::new(&env, &path.last_unchecked()).mint(&to, &out);
+        out.max(min_out)
+    }
 }
 
+#[cfg(feature = "accounts")]
 #[test]
-fn test_escrow_transfer() {
+fn test_path_payment_deposit_credits_received_amount() {
+    use soroban_sdk::{testutils::Events, Event};
+
     let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
 
-    let alice = Address::generate(&env);
-    let bob = Address::generate(&env);
+    let router = env.register(MockPathRouter, ());
+    let xlm = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let usdc = env.register_stellar_asset_contract_v2(router.clone()).address();
+    let depositor = Address::generate(&env);
+    StellarAssetClient::new(&env, &xlm).mint(&depositor, &3_000);
+    let path = vec![&env, xlm.clone(), usdc.clone()];
+
+    let unset = client.try_deposit_via_path(&depositor, &path, &1_000, &990);
+    assert_eq!(unset, Err(Ok(SettlementError::PathRouterNotSet)));
+    client.set_path_router(&admin, &Some(router.clone()));
+    let short = client.try_deposit_via_path(&depositor, &vec![&env, usdc.clone()], &1_000, &990);
+    assert_eq!(short, Err(Ok(SettlementError::InvalidPath)));
+
+    // Slightly under the quote but above the bound: the actual amount is credited
+    let router_client = MockPathRouterClient::new(&env, &router);
+    router_client.set_delivery_bps(&9_950);
+    assert_eq!(client.deposit_via_path(&depositor, &path, &1_000, &990), 995);
+    let deposited = PathDeposit {
+        depositor: depositor.clone(),
+        asset: usdc.clone(),
+        send_asset: xlm.clone(),
+        send_amount: 1_000,
+        received: 995,
+    };
+    assert!(env.events().all().filter_by_contract(&contract_id).events().contains(&deposited.to_xdr(&env, &contract_id)));
+    assert_eq!(client.get_escrow_balance(&depositor, &usdc), 995);
+    assert_eq!(token::TokenClient::new(&env, &usdc).balance(&contract_id), 995);
+
+    // The bound is enforced on what arrived, not on what the router reports
+    router_client.set_delivery_bps(&9_800);
+    let short_fill = client.try_deposit_via_path(&depositor, &path, &1_000, &990);
+    assert_eq!(short_fill, Err(Ok(SettlementError::ReceivedBelowMinimum)));
+    assert_eq!(token::TokenClient::new(&env, &xlm).balance(&depositor), 2_000);
+    assert_eq!(client.get_escrow_balance(&depositor, &usdc), 995);
+}
+
+#[cfg(feature = "delegation")]
+#[test]
+fn test_broker_scoped_locking() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let trader = Address::generate(&env);
+    let broker = Address::generate(&env);
+    let asset = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::add_escrow_balance(&env, &trader, &asset, 1000);
+    });
+
+    // No delegation yet
+    let result = client.try_broker_lock_escrow(&broker, &trader, &DEFAULT_SUB_ACCOUNT, &asset, &100);
+    assert_eq!(result, Err(Ok(SettlementError::BrokerNotAuthorized)));
+
+    // Settle-only delegation does not allow locking
+    client.set_broker(&trader, &broker, &false, &true);
+    let result = client.try_broker_lock_escrow(&broker, &trader, &DEFAULT_SUB_ACCOUNT, &asset, &100);
+    assert_eq!(result, Err(Ok(SettlementError::BrokerNotAuthorized)));
+
+    client.set_broker(&trader, &broker, &true, &true);
+    client.broker_lock_escrow(&broker, &trader, &DEFAULT_SUB_ACCOUNT, &asset, &400);
+    assert_eq!(client.get_locked_balance(&trader, &asset), 400);
+
+    client.broker_unlock_escrow(&broker, &trader, &DEFAULT_SUB_ACCOUNT, &asset, &150);
+    assert_eq!(client.get_locked_balance(&trader, &asset), 250);
+
+    client.remove_broker(&trader);
+    assert!(client.get_broker(&trader).is_none());
+}
+
+#[cfg(feature = "delegation")]
+#[test]
+fn test_broker_approval_or_party_auth() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64| {
+        generate(42, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller, broker) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    client.set_auth_mode(&admin, &SettlementAuthMode::BothParties);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+    let settle = |nullifier: u64| {
+        let proof = fixture(nullifier);
+        client.settle_trade(
+            &BytesN::from_array(&env, &[nullifier as u8; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &proof.proof),
+            &Bytes::from_slice(&env, &proof.signals),
+        );
+        env.auths().iter().any(|(address, _)| *address == buyer)
+    };
+
+    // A lock-only broker cannot approve settlements
+    client.set_broker(&buyer, &broker, &true, &false);
+    let match_id = BytesN::from_array(&env, &[2u8; 32]);
+    assert_eq!(
+        client.try_broker_approve_settlement(&broker, &buyer, &match_id),
+        Err(Ok(SettlementError::BrokerNotAuthorized))
+    );
+
+    // With a settle-scoped broker, the buyer may still authorize directly
+    client.set_broker(&buyer, &broker, &false, &true);
+    assert!(settle(1));
+
+    // The broker's approval replaces the buyer's authorization for that match only
+    client.broker_approve_settlement(&broker, &buyer, &match_id);
+    assert!(!settle(2));
+    assert!(settle(3));
+}
+
+#[cfg(feature = "accounts")]
+#[test]
+fn test_subaccount_settlement_requires_party_auth() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64| {
+        generate(42, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    let growth = symbol_short!("growth");
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::new(&seller, &growth, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+    let settle = |nullifier: u64| {
+        let proof = fixture(nullifier);
+        client.try_settle_trade_subaccounts(
+            &BytesN::from_array(&env, &[nullifier as u8; 32]),
+            &buyer,
+            &DEFAULT_SUB_ACCOUNT,
+            &seller,
+            &growth,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &proof.proof),
+            &Bytes::from_slice(&env, &proof.signals),
+        )
+    };
+
+    // Without the seller's consent a relayer cannot pick its sub-account
+    env.set_auths(&[]);
+    assert!(matches!(settle(1), Err(Err(_))));
+
+    env.mock_all_auths();
+    assert!(settle(1).is_ok());
+    let auths = env.auths();
+    assert!(auths.iter().any(|(address, _)| *address == seller));
+    assert!(!auths.iter().any(|(address, _)| *address == buyer));
+    assert_eq!(client.get_subaccount_balance(&seller, &growth, &usdc), 5_000);
+}
+
+#[cfg(feature = "records")]
+#[test]
+fn test_settlement_receipt_roundtrip() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let match_id = BytesN::from_array(&env, &[9u8; 32]);
+    let payment_asset = Address::generate(&env);
+    let record = SettlementRecord {
+        match_id: match_id.clone(),
+        buyer: Address::generate(&env),
+        seller: Address::generate(&env),
+        asset_address: Address::generate(&env),
+        quantity: 1000,
+        price: 50000,
+        payment_asset,
+        payment_amount: 50000,
+        timestamp: 0,
+        nullifier: BytesN::from_array(&env, &[4u8; 32]),
+        scheme_version: COMMITMENT_SCHEME_V1,
+    };
+
+    assert!(client.get_settlement_receipt(&None, &match_id).is_none());
+
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::store_settlement(&env, &record);
+        let receipt = DarkPoolSettlement::store_receipt(&env, &record.match_id, &record.clone().to_xdr(&env));
+        DarkPoolSettlement::append_to_log(&env, &receipt);
+    });
+
+    let receipt = client.get_settlement_receipt(&None, &match_id).unwrap();
+    assert_eq!(receipt.record_xdr, record.clone().to_xdr(&env));
+    assert!(client.verify_settlement_receipt(&match_id, &receipt.record_xdr));
+
+    // A tampered encoding does not match the committed hash
+    let mut tampered = receipt.record_xdr.clone();
+    tampered.set(tampered.len() - 1, 0xff);
+    assert!(!client.verify_settlement_receipt(&match_id, &tampered));
+}
+
+#[test]
+fn test_settlement_pages_are_bounded() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let total = MAX_PAGE_SIZE + 5;
+    let trader = Address::generate(&env);
+    // One record per frame keeps each write under the footprint limit
+    for i in 0..total {
+        let mut match_id = [0u8; 32];
+        match_id[..4].copy_from_slice(&i.to_be_bytes());
+        let record = SettlementRecord {
+            match_id: BytesN::from_array(&env, &match_id),
+            buyer: trader.clone(),
+            seller: Address::generate(&env),
+            asset_address: Address::generate(&env),
+            quantity: i as i128 + 1,
+            price: 1_000,
+            payment_asset: Address::generate(&env),
+            payment_amount: 1_000,
+            timestamp: i as u64,
+            nullifier: BytesN::from_array(&env, &match_id),
+            scheme_version: COMMITMENT_SCHEME_V1,
+        };
+        env.as_contract(&contract_id, || DarkPoolSettlement::store_settlement(&env, &record));
+    }
+    assert_eq!(client.get_settlement_count(), total);
+
+    // Oversized pages are clamped to MAX_PAGE_SIZE
+    let page = client.get_settlements_page(&None, &0, &(total * 2));
+    assert_eq!(page.len(), MAX_PAGE_SIZE);
+    assert_eq!(page.get(0).unwrap().quantity, 1);
+    let rest = client.get_settlements_page(&None, &MAX_PAGE_SIZE, &MAX_PAGE_SIZE);
+    assert_eq!(rest.len(), 5);
+    assert!(client.get_settlements_page(&None, &total, &10).is_empty());
+
+    // The unpaged getter only returns the most recent page
+    let recent = client.get_settlements(&Some(trader));
+    assert_eq!(recent.len(), MAX_PAGE_SIZE);
+    assert_eq!(recent.last().unwrap().quantity, total as i128);
+}
+
+#[cfg(feature = "records")]
+#[test]
+fn test_settlement_log_chains_exported_records() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    assert_eq!(client.get_log_head().length, 0);
+
+    env.as_contract(&contract_id, || {
+        for i in 0..3u8 {
+            let record = SettlementRecord {
+                match_id: BytesN::from_array(&env, &[70 + i; 32]),
+                buyer: Address::generate(&env),
+                seller: Address::generate(&env),
+                asset_address: Address::generate(&env),
+                quantity: 10,
+                price: 1_000 + i as i128,
+                payment_asset: Address::generate(&env),
+                payment_amount: 1_000 + i as i128,
+                timestamp: i as u64,
+                nullifier: BytesN::from_array(&env, &[80 + i; 32]),
+                scheme_version: COMMITMENT_SCHEME_V1,
+            };
+            DarkPoolSettlement::store_settlement(&env, &record);
+            let receipt = DarkPoolSettlement::store_receipt(&env, &record.match_id, &record.clone().to_xdr(&env));
+            DarkPoolSettlement::append_to_log(&env, &receipt);
+        }
+    });
+
+    // Replay the export the way an off-chain auditor would
+    let chain = |records: &Vec<SettlementRecord>| {
+        let mut hash = [0u8; 32];
+        for record in records.iter() {
+            let mut preimage = Bytes::from_array(&env, &hash);
+            preimage.append(&env.crypto().sha256(&record.to_xdr(&env)).into());
+            hash = env.crypto().sha256(&preimage).to_array();
+        }
+        BytesN::from_array(&env, &hash)
+    };
+    let mut records = decode_settlement_export(&env, &client.export_settlements(&None, &0, &10))
+        .unwrap()
+        .records;
+    let head = client.get_log_head();
+    assert_eq!(head.length, 3);
+    assert_eq!(chain(&records), head.hash);
+
+    // Tampering with or reordering a record breaks the chain
+    let mut altered = records.get(1).unwrap();
+    altered.payment_amount += 1;
+    records.set(1, altered);
+    assert_ne!(chain(&records), head.hash);
+    let first = records.pop_front().unwrap();
+    records.push_back(first);
+    assert_ne!(chain(&records), head.hash);
+}
+
+#[cfg(feature = "records")]
+#[test]
+fn test_export_settlements_roundtrip() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let mut records = vec![&env];
+    for i in 0..3u8 {
+        records.push_back(SettlementRecord {
+            match_id: BytesN::from_array(&env, &[50 + i; 32]),
+            buyer: Address::generate(&env),
+            seller: Address::generate(&env),
+            asset_address: Address::generate(&env),
+            quantity: 10 * (i as i128 + 1),
+            price: 1_000,
+            payment_asset: Address::generate(&env),
+            payment_amount: 1_000,
+            timestamp: i as u64,
+            nullifier: BytesN::from_array(&env, &[60 + i; 32]),
+            scheme_version: COMMITMENT_SCHEME_V1,
+        });
+    }
+    env.as_contract(&contract_id, || {
+        for record in records.iter() {
+            DarkPoolSettlement::store_settlement(&env, &record);
+        }
+    });
+
+    let export = decode_settlement_export(&env, &client.export_settlements(&None, &1, &5)).unwrap();
+    assert_eq!(export.version, EXPORT_FORMAT_VERSION);
+    assert_eq!(export.start, 1);
+    assert_eq!(export.total, 3);
+    assert_eq!(export.records, records.slice(1..3));
+
+    // The encoding is canonical: exporting the same page twice is byte-identical
+    assert_eq!(client.export_settlements(&None, &0, &2), client.export_settlements(&None, &0, &2));
+    assert!(decode_settlement_export(&env, &client.export_settlements(&None, &3, &5)).unwrap().records.is_empty());
+
+    // Blobs from an unknown format version are rejected
+    let mut future = export.clone();
+    future.version = EXPORT_FORMAT_VERSION + 1;
+    assert!(decode_settlement_export(&env, &future.to_xdr(&env)).is_none());
+}
+
+#[cfg(feature = "records")]
+#[test]
+fn test_settlement_sequence_numbers() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let first = BytesN::from_array(&env, &[70u8; 32]);
+    let second = BytesN::from_array(&env, &[71u8; 32]);
+    assert_eq!(client.get_last_sequence(), 0);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(DarkPoolSettlement::assign_sequence(&env, &first), Ok(1));
+        assert_eq!(DarkPoolSettlement::assign_sequence(&env, &second), Ok(2));
+        // A match keeps its place in the order
+        assert_eq!(
+            DarkPoolSettlement::assign_sequence(&env, &first),
+            Err(SettlementError::AlreadySettled)
+        );
+    });
+
+    assert_eq!(client.get_settlement_sequence(&first), Some(1));
+    assert_eq!(client.get_settlement_sequence(&second), Some(2));
+    assert_eq!(client.get_settlement_sequence(&BytesN::from_array(&env, &[72u8; 32])), None);
+    assert_eq!(client.get_last_sequence(), 2);
+}
+
+#[cfg(feature = "records")]
+#[test]
+fn test_record_privacy_gates_reads() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let auditor = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[73u8; 32]);
+    let record = SettlementRecord {
+        match_id: match_id.clone(),
+        buyer: buyer.clone(),
+        seller: Address::generate(&env),
+        asset_address: Address::generate(&env),
+        quantity: 10,
+        price: 1_000,
+        payment_asset: Address::generate(&env),
+        payment_amount: 1_000,
+        timestamp: 0,
+        nullifier: BytesN::from_array(&env, &[74u8; 32]),
+        scheme_version: COMMITMENT_SCHEME_V1,
+    };
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::store_settlement(&env, &record);
+        let receipt = DarkPoolSettlement::store_receipt(&env, &record.match_id, &record.clone().to_xdr(&env));
+        DarkPoolSettlement::append_to_log(&env, &receipt);
+    });
+
+    // Public by default
+    assert_eq!(client.get_settlement(&None, &match_id), Some(record.clone()));
+
+    client.set_record_privacy(&admin, &true);
+    client.set_auditor(&admin, &auditor, &true);
+    assert!(client.is_auditor(&auditor));
+
+    let anonymous = client.try_get_settlement(&None, &match_id);
+    assert_eq!(anonymous, Err(Ok(SettlementError::RecordAccessDenied)));
+    let snooping = client.try_get_settlement(&Some(outsider.clone()), &match_id);
+    assert_eq!(snooping, Err(Ok(SettlementError::RecordAccessDenied)));
+    assert!(client.try_get_settlement_receipt(&Some(outsider.clone()), &match_id).is_err());
+
+    assert_eq!(client.get_settlement(&Some(buyer.clone()), &match_id), Some(record.clone()));
+    assert_eq!(client.get_settlement(&Some(auditor.clone()), &match_id), Some(record.clone()));
+    assert_eq!(client.get_settlement(&Some(admin.clone()), &match_id), Some(record));
+
+    // Listing only shows a participant its own trades; exports need a privileged viewer
+    assert_eq!(client.get_settlements(&Some(outsider.clone())).len(), 0);
+    assert_eq!(client.get_settlements(&Some(buyer)).len(), 1);
+    assert_eq!(client.get_settlements(&Some(auditor.clone())).len(), 1);
+    let export = client.try_export_settlements(&Some(outsider), &0, &10);
+    assert_eq!(export.err(), Some(Ok(SettlementError::RecordAccessDenied)));
+    client.export_settlements(&Some(auditor), &0, &10);
+}
+
+#[cfg(feature = "records")]
+#[test]
+fn test_commitment_only_records() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[75u8; 32]);
+    let asset_hash = BytesN::from_array(&env, &[76u8; 32]);
+    let record = SettlementRecord {
+        match_id: match_id.clone(),
+        buyer: buyer.clone(),
+        seller: Address::generate(&env),
+        asset_address: Address::generate(&env),
+        quantity: 1_500,
+        price: 1_000,
+        payment_asset: Address::generate(&env),
+        payment_amount: 1_000,
+        timestamp: 0,
+        nullifier: BytesN::from_array(&env, &[77u8; 32]),
+        scheme_version: COMMITMENT_SCHEME_V1,
+    };
+
+    assert_eq!(client.get_record_format(), RecordFormat::Full);
+    client.set_record_format(&admin, &RecordFormat::CommitmentOnly);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::store_settlement(&env, &record);
+        DarkPoolSettlement::store_commitment(&env, &record, &asset_hash);
+    });
+
+    let commitment = client.get_settlement_commitment(&match_id).unwrap();
+    assert_eq!(commitment.nullifier, record.nullifier);
+    assert_eq!(commitment.asset_hash, asset_hash);
+    assert_eq!(commitment.amount_bucket, 10);
+
+    // The full record needs an authenticated party even without record privacy
+    assert!(!client.is_record_privacy());
+    let anonymous = client.try_get_settlement(&None, &match_id);
+    assert_eq!(anonymous, Err(Ok(SettlementError::RecordAccessDenied)));
+    assert_eq!(client.get_settlement(&Some(buyer), &match_id), Some(record));
+
+    let locked = client.try_set_record_format(&admin, &RecordFormat::Full);
+    assert_eq!(locked, Err(Ok(SettlementError::RecordFormatLocked)));
+}
+
+#[test]
+fn test_balance_update_cost_vs_map_storage() {
+    let env = Env::default();
     let asset = Address::generate(&env);
+    let target = Address::generate(&env);
+    let legacy_key = symbol_short!("legacy");
+
+    // Two pools with the same 100 participants: one using per-entry balances,
+    // one using the previous single instance map
+    let entry_pool = register_settlement(&env);
+    let map_pool = register_settlement(&env);
+    let mut legacy: Map<EscrowKey, i128> = Map::new(&env);
+    for _ in 0..4 {
+        env.as_contract(&entry_pool, || {
+            for _ in 0..25 {
+                let key = EscrowKey::main(&Address::generate(&env), &asset);
+                DarkPoolSettlement::credit_escrow(&env, &key, 1000);
+                legacy.set(key, 1000);
+            }
+        });
+    }
+    env.as_contract(&map_pool, || {
+        env.storage().instance().set(&legacy_key, &legacy);
+    });
+
+    env.cost_estimate().budget().reset_default();
+    env.as_contract(&entry_pool, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&target, &asset), 500);
+    });
+    let entry_cost = env.cost_estimate().budget().cpu_instruction_cost();
+
+    env.cost_estimate().budget().reset_default();
+    env.as_contract(&map_pool, || {
+        let mut escrow: Map<EscrowKey, i128> = env.storage().instance().get(&legacy_key).unwrap();
+        let key = EscrowKey::main(&target, &asset);
+        let current = escrow.get(key.clone()).unwrap_or(0);
+        escrow.set(key, current + 500);
+        env.storage().instance().set(&legacy_key, &escrow);
+    });
+    let map_cost = env.cost_estimate().budget().cpu_instruction_cost();
+
+    assert!(
+        entry_cost < map_cost,
+        "per-entry update ({entry_cost}) should be cheaper than map update ({map_cost})"
+    );
+}
+
+#[cfg(feature = "verification-routes")]
+#[test]
+fn test_verification_routing() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_verification_route(&SETTLEMENT_PROOF), VerificationRoute::Contract);
+
+    client.set_verification_route(&admin, &SETTLEMENT_PROOF, &VerificationRoute::Native);
+    assert_eq!(client.get_verification_route(&SETTLEMENT_PROOF), VerificationRoute::Native);
+
+    // The native path rejects malformed inputs without calling the verifier contract
+    env.as_contract(&contract_id, || {
+        let junk = Bytes::from_slice(&env, &[0u8; 16]);
+        let result = DarkPoolSettlement::verify_proof(&env, &SETTLEMENT_PROOF, &junk, &junk, &junk);
+        assert_eq!(result, Err(SettlementError::InvalidProof));
+    });
+}
+
+#[test]
+fn test_whitelist_root_freshness() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let admin = Address::generate(&env);
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry_id = env.register(
+        registry_wasm::WASM,
+        (&admin, &Address::generate(&env), &vk_bytes),
+    );
+    let registry = registry_wasm::Client::new(&env, &registry_id);
+    let contract_id = env.register(
+        DarkPoolSettlement,
+        (&admin, &registry_id, &Address::generate(&env), &vk_bytes),
+    );
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    client.set_whitelist_check(&admin, &true);
+    client.set_max_root_age(&admin, &3_600);
+
+    let old_root = registry.get_whitelist_root();
+    env.ledger().with_mut(|li| li.timestamp = 2_000);
+    registry.register_participant(
+        &admin,
+        &registry_wasm::Participant {
+            id_hash: BytesN::from_array(&env, &[1u8; 32]),
+            trading_address: Address::generate(&env),
+            category: registry_wasm::ParticipantCategory::BrokerDealer,
+            kyc_expiry: 1_000_000,
+            is_active: true,
+            tree_index: 0,
+        },
+    );
+    let current_root = registry.get_whitelist_root();
+
+    env.as_contract(&contract_id, || {
+        assert!(DarkPoolSettlement::check_whitelist_root(&env, &current_root).is_ok());
+        assert!(DarkPoolSettlement::check_whitelist_root(&env, &old_root).is_ok());
+
+        let unknown = BytesN::from_array(&env, &[5u8; 32]);
+        let result = DarkPoolSettlement::check_whitelist_root(&env, &unknown);
+        assert_eq!(result, Err(SettlementError::WhitelistRootMismatch));
+    });
+
+    // The superseded root ages out; the current root never does
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    env.as_contract(&contract_id, || {
+        let result = DarkPoolSettlement::check_whitelist_root(&env, &old_root);
+        assert_eq!(result, Err(SettlementError::WhitelistRootStale));
+        assert!(DarkPoolSettlement::check_whitelist_root(&env, &current_root).is_ok());
+    });
+}
+
+#[cfg(feature = "forwards")]
+fn open_test_forward(
+    env: &Env,
+    contract_id: &Address,
+    match_id: &BytesN<32>,
+    buyer: &Address,
+    seller: &Address,
+    asset: &Address,
+    payment_asset: &Address,
+) {
+    env.as_contract(contract_id, || {
+        // Seller keeps the asset locked; the buyer's payment and the
+        // seller's bond are already held
+        DarkPoolSettlement::add_escrow_balance(env, seller, asset, 100);
+        DarkPoolSettlement::add_locked_balance(env, seller, asset, 100);
+        DarkPoolSettlement::store_forward(
+            env,
+            match_id,
+            &ForwardSettlement {
+                buyer: EscrowKey::main(buyer, asset),
+                seller: EscrowKey::main(seller, asset),
+                quantity: 100,
+                payment_asset: payment_asset.clone(),
+                payment_amount: 5000,
+                seller_bond: 500,
+                delivery_after: 1_000,
+                status: ForwardStatus::Pending,
+            },
+        );
+    });
+}
+
+#[cfg(all(feature = "delivery", feature = "forwards"))]
+#[test]
+fn test_forward_delivery() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    let match_id = BytesN::from_array(&env, &[11u8; 32]);
+    open_test_forward(&env, &contract_id, &match_id, &buyer, &seller, &asset, &usdc);
+
+    let early = client.try_deliver_forward(&match_id);
+    assert_eq!(early, Err(Ok(SettlementError::ForwardNotDue)));
+    assert!(client.get_unfinalized_settlements(&10).is_empty());
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(client.get_unfinalized_settlements(&10), vec![&env, match_id.clone()]);
+    assert_eq!(client.get_unfinalized_settlements(&0).len(), 0);
+    assert_eq!(client.deliver_forward(&match_id), ForwardStatus::Delivered);
+    assert!(client.get_unfinalized_settlements(&10).is_empty());
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 100);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 5500);
+    assert_eq!(client.get_escrow_balance(&seller, &asset), 0);
+
+    let again = client.try_deliver_forward(&match_id);
+    assert_eq!(again, Err(Ok(SettlementError::ForwardClosed)));
+}
+
+#[cfg(feature = "forwards")]
+#[test]
+fn test_forward_default_refunds_buyer() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    client.set_forward_grace(&admin, &500);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    let match_id = BytesN::from_array(&env, &[12u8; 32]);
+    open_test_forward(&env, &contract_id, &match_id, &buyer, &seller, &asset, &usdc);
+
+    // Seller pulls the asset out of the lock before delivery
+    client.unlock_escrow(&seller, &asset, &100);
+
+    env.ledger().with_mut(|li| li.timestamp = 1_200);
+    let within_grace = client.try_deliver_forward(&match_id);
+    assert_eq!(within_grace, Err(Ok(SettlementError::InsufficientLockedFunds)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_500);
+    assert_eq!(client.deliver_forward(&match_id), ForwardStatus::Defaulted);
+    assert_eq!(client.get_escrow_balance(&buyer, &usdc), 5500);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 0);
+    assert_eq!(client.get_forward(&match_id).unwrap().status, ForwardStatus::Defaulted);
+}
+
+#[cfg(feature = "forwards")]
+#[test]
+fn test_forward_settlement_takes_seller_bond() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = generate(42, &[scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)]);
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    let match_id = BytesN::from_array(&env, &[13u8; 32]);
+    client.add_payment_asset(&admin, &usdc);
+    client.register_forward(&admin, &match_id, &1_000);
+    env.as_contract(&contract_id, || {
+        let key = EscrowKey::main(&buyer, &usdc);
+        DarkPoolSettlement::credit_escrow(&env, &key, 5_000);
+        DarkPoolSettlement::credit_locked(&env, &key, 5_000);
+    });
+    let settle = || {
+        client.try_settle_trade(
+            &match_id,
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, &fixture.signals),
+        )
+    };
+
+    // Until lowered, the bond is the full payment
+    assert_eq!(client.get_forward_bond(), 10_000);
+    assert_eq!(client.try_set_forward_bond(&admin, &10_001), Err(Ok(SettlementError::InvalidFee)));
+    assert_eq!(settle().err(), Some(Ok(SettlementError::InsufficientLockedFunds)));
+
+    // The issuer need not hold the asset yet, only the bond
+    client.set_forward_bond(&admin, &1_000);
+    env.as_contract(&contract_id, || {
+        let key = EscrowKey::main(&seller, &usdc);
+        DarkPoolSettlement::credit_escrow(&env, &key, 500);
+        DarkPoolSettlement::credit_locked(&env, &key, 500);
+    });
+    settle().unwrap().unwrap();
+    assert_eq!(client.get_forward(&match_id).unwrap().seller_bond, 500);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 0);
+    assert_eq!(client.get_escrow_balance(&buyer, &usdc), 0);
+
+    // Failing to deliver forfeits the bond to the buyer
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(client.deliver_forward(&match_id), ForwardStatus::Defaulted);
+    assert_eq!(client.get_escrow_balance(&buyer, &usdc), 5_500);
+}
+
+/// Encode an amount as a big-endian field element signal
+#[cfg(any(feature = "baskets", feature = "cancellation", feature = "order-controls"))]
+fn amount_signal(env: &Env, value: i128) -> BytesN<32> {
+    let mut arr = [0u8; 32];
+    arr[16..].copy_from_slice(&value.to_be_bytes());
+    BytesN::from_array(env, &arr)
+}
+
+#[cfg(feature = "baskets")]
+#[test]
+fn test_basket_signals_bind_legs() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let leg = |quantity: i128, price: i128| SettlementLeg {
+        asset_address: Address::generate(&env),
+        quantity,
+        price,
+    };
+    let legs = vec![&env, leg(100, 5_000), leg(250, 9_000)];
+
+    env.as_contract(&contract_id, || {
+        let mut signals: Vec<BytesN<32>> = vec![&env];
+        for byte in 1..=4u8 {
+            signals.push_back(BytesN::from_array(&env, &[byte; 32]));
+        }
+        for l in legs.iter() {
+            signals.push_back(DarkPoolSettlement::address_field_hash(&env, &l.asset_address));
+            signals.push_back(amount_signal(&env, l.quantity));
+            signals.push_back(amount_signal(&env, l.price));
+        }
+        assert!(DarkPoolSettlement::check_basket_signals(&env, &legs, &signals).is_ok());
+
+        // A leg that differs from what the proof committed to is rejected
+        let mut altered = legs.clone();
+        let mut resized = legs.get(1).unwrap();
+        resized.quantity = 251;
+        altered.set(1, resized);
+        let result = DarkPoolSettlement::check_basket_signals(&env, &altered, &signals);
+        assert_eq!(result, Err(SettlementError::InvalidProof));
+
+        // So is a leg in an asset other than the one proven
+        let mut swapped = legs.clone();
+        swapped.set(1, leg(250, 9_000));
+        let result = DarkPoolSettlement::check_basket_signals(&env, &swapped, &signals);
+        assert_eq!(result, Err(SettlementError::InvalidProof));
+    });
+
+    let empty = client.try_settle_basket(
+        &BytesN::from_array(&env, &[13u8; 32]),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &vec![&env],
+        &Bytes::new(&env),
+        &Bytes::new(&env),
+    );
+    assert_eq!(empty.err(), Some(Ok(SettlementError::EmptyBasket)));
+}
+
+#[cfg(all(feature = "baskets", feature = "ops", feature = "records", feature = "schedules"))]
+#[test]
+fn test_settle_basket_with_generated_proof() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let settle_vk = Bytes::from_slice(&env, &generate(42, &[scalar(1); 7]).vk);
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &settle_vk));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &settle_vk));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (gold, silver, usdc) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &gold), EscrowKey::main(&seller, &silver), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+
+    let legs = vec![
+        &env,
+        SettlementLeg { asset_address: gold.clone(), quantity: 100, price: 5_000 },
+        SettlementLeg { asset_address: silver.clone(), quantity: 50, price: 2_000 },
+    ];
+    let fixture = |nullifier: u64, legs: &Vec<SettlementLeg>| {
+        // Two legs: four shared signals, then three per leg
+        let mut signals = [[0u8; 32]; 10];
+        signals[..4].copy_from_slice(&[scalar(nullifier), scalar(14), scalar(11), scalar(12)]);
+        for (i, leg) in legs.iter().enumerate() {
+            let hash = env.as_contract(&contract_id, || DarkPoolSettlement::address_field_hash(&env, &leg.asset_address));
+            signals[4 + 3 * i..7 + 3 * i].copy_from_slice(&[
+                hash.to_array(),
+                scalar(leg.quantity as u64),
+                scalar(leg.price as u64),
+            ]);
+        }
+        generate(7, &signals)
+    };
+    client.set_basket_vk(&admin, &Bytes::from_slice(&env, &fixture(0, &legs).vk));
+    let settle = |match_byte: u8, proof: &darkpool_testdata::ProofFixture| {
+        client.try_settle_basket(
+            &BytesN::from_array(&env, &[match_byte; 32]),
+            &buyer,
+            &seller,
+            &usdc,
+            &legs,
+            &Bytes::from_slice(&env, &proof.proof),
+            &Bytes::from_slice(&env, &proof.signals),
+        )
+    };
+
+    // A proof over another asset cannot settle these legs
+    let mut other = legs.clone();
+    other.set(1, SettlementLeg { asset_address: usdc.clone(), quantity: 50, price: 2_000 });
+    assert_eq!(settle(70, &fixture(1, &other)).err(), Some(Ok(SettlementError::InvalidProof)));
+
+    // The seller's commitment is a TWAP slice: the basket fills it in full
+    let parent = BytesN::from_array(&env, &[91u8; 32]);
+    let schedule = TwapSchedule { total_quantity: 300, max_slice: 150, min_spacing: 60, start: 0 };
+    client.create_twap(&seller, &parent, &schedule);
+    client.attach_twap_slice(&seller, &parent, &BytesN::from_array(&env, &scalar(12)));
+
+    let record = settle(71, &fixture(1, &legs)).unwrap().unwrap();
+    assert_eq!(record.payment_amount, 7_000);
+    assert_eq!(client.get_escrow_balance(&buyer, &gold), 100);
+    assert_eq!(client.get_escrow_balance(&buyer, &silver), 50);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 7_000);
+    assert_eq!(client.get_twap(&parent).unwrap().filled, 150);
+    let match_id = BytesN::from_array(&env, &[71u8; 32]);
+    assert_eq!(client.get_in_flight(&match_id), Some(InFlightSettlement { relayer: None, ledger: env.ledger().sequence() }));
+    assert_eq!(settle(71, &fixture(3, &legs)).err(), Some(Ok(SettlementError::SettlementInFlight)));
+
+    // The basket's receipt commits to its record; it stays out of the settlement log
+    let receipt = client.get_settlement_receipt(&None, &match_id).unwrap();
+    assert_eq!(receipt.record_xdr, record.clone().to_xdr(&env));
+    assert!(client.verify_settlement_receipt(&match_id, &receipt.record_xdr));
+    assert_eq!(client.get_log_head().length, 0);
+
+    // Its payment counts toward the epoch's volume
+    let epoch = env.ledger().timestamp() / VOLUME_EPOCH_SECONDS;
+    assert_eq!(client.get_epoch_volume(&epoch).get(usdc.clone()), Some(7_000));
+
+    // The schedule's spacing applies to the next basket as to any slice
+    assert_eq!(settle(72, &fixture(2, &legs)).err(), Some(Ok(SettlementError::TwapSliceTooEarly)));
+}
+
+#[cfg(feature = "order-controls")]
+#[test]
+fn test_notional_overflow_and_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let payment_asset = Address::generate(&env);
+    assert_eq!(client.get_max_notional(&payment_asset), None);
+    client.set_max_notional(&admin, &payment_asset, &1_000_000);
+    assert_eq!(client.get_max_notional(&payment_asset), Some(1_000_000));
+
+    env.as_contract(&contract_id, || {
+        #[cfg(any(feature = "corporate-actions", feature = "fees", feature = "fx", feature = "margin"))]
+        {
+            assert_eq!(
+                DarkPoolSettlement::mul_div(i128::MAX, 2, FX_RATE_SCALE),
+                Err(SettlementError::NotionalOverflow)
+            );
+            assert_eq!(DarkPoolSettlement::mul_div(500, FX_RATE_SCALE * 2, FX_RATE_SCALE), Ok(1_000));
+        }
+
+        assert_eq!(
+            DarkPoolSettlement::check_trade_amounts(0, 100),
+            Err(SettlementError::InvalidAmount)
+        );
+        assert_eq!(
+            DarkPoolSettlement::check_trade_amounts(10, -1),
+            Err(SettlementError::InvalidAmount)
+        );
+
+        assert!(DarkPoolSettlement::check_notional(&env, &payment_asset, 1_000_000).is_ok());
+        assert_eq!(
+            DarkPoolSettlement::check_notional(&env, &payment_asset, 1_000_001),
+            Err(SettlementError::NotionalTooLarge)
+        );
+
+        // Signals wider than an i128 are rejected rather than truncated
+        let wide = BytesN::from_array(&env, &[0xffu8; 32]);
+        assert_eq!(
+            DarkPoolSettlement::signal_to_i128(&wide),
+            Err(SettlementError::NotionalOverflow)
+        );
+        assert_eq!(DarkPoolSettlement::signal_to_i128(&amount_signal(&env, 42)), Ok(42));
+    });
+}
+
+#[cfg(all(feature = "accounts", feature = "ops"))]
+#[test]
+fn test_audit_balances_reports_discrepancy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(issuer).address();
+    let asset_admin = StellarAssetClient::new(&env, &asset);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    asset_admin.mint(&alice, &1_000);
+    asset_admin.mint(&bob, &500);
+    client.deposit(&alice, &asset, &1_000);
+    client.deposit(&bob, &asset, &500);
+
+    let audit = client.audit_balances(&admin, &asset);
+    assert_eq!(audit.recorded_total, 1_500);
+    assert_eq!(audit.token_balance, 1_500);
+    assert_eq!(audit.discrepancy, 0);
+
+    // Sub-account balances count towards the recorded total
+    let growth = Symbol::new(&env, "growth");
+    client.transfer_between_subaccounts(&alice, &asset, &DEFAULT_SUB_ACCOUNT, &growth, &400);
+    let moved = client.audit_balances(&admin, &asset);
+    assert_eq!((moved.recorded_total, moved.discrepancy), (1_500, 0));
+
+    // Tokens sent straight to the pool show up as a surplus
+    asset_admin.mint(&contract_id, &25);
+    assert_eq!(client.audit_balances(&admin, &asset).discrepancy, 25);
+
+    let outsider = Address::generate(&env);
+    let denied = client.try_audit_balances(&outsider, &asset);
+    assert_eq!(denied.err(), Some(Ok(SettlementError::OnlyAdmin)));
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn test_verify_solvency_pauses_on_deficit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    StellarAssetClient::new(&env, &asset).mint(&alice, &1_000);
+    StellarAssetClient::new(&env, &asset).mint(&bob, &1_000);
+
+    client.deposit(&alice, &asset, &600);
+    client.deposit(&bob, &asset, &300);
+    client.withdraw(&alice, &asset, &100);
+    // Tokens sent straight to the contract are a harmless surplus
+    StellarAssetClient::new(&env, &asset).mint(&contract_id, &50);
+
+    let audit = client.verify_solvency(&asset);
+    assert_eq!(audit.recorded_total, 800);
+    assert_eq!(audit.discrepancy, 50);
+    assert!(!client.is_asset_paused(&asset));
+
+    // An accounting bug credits escrow no tokens back
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&bob, &asset), 100);
+    });
+    let audit = client.verify_solvency(&asset);
+    assert_eq!(audit.discrepancy, -50);
+    assert!(client.is_asset_paused(&asset));
+}
+
+#[cfg(feature = "accounts")]
+#[test]
+fn test_membership_badge_on_first_deposit() {
+    use soroban_sdk::String;
+
+    use soroban_sdk::{testutils::Events, Event};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    StellarAssetClient::new(&env, &asset).mint(&alice, &1_000);
+    StellarAssetClient::new(&env, &asset).mint(&bob, &1_000);
+
+    let uri = String::from_str(&env, "ipfs://badges/pool");
+    client.set_badge_uri(&admin, &uri);
+    assert_eq!(client.get_badge(&alice), None);
+
+    env.ledger().set_timestamp(100);
+    client.deposit(&alice, &asset, &600);
+    assert_eq!(
+        env.events().all().filter_by_contract(&contract_id),
+        [BadgeIssued { holder: alice.clone(), number: 1 }.to_xdr(&env, &contract_id)]
+    );
+    let badge = MembershipBadge { number: 1, issued_at: 100, first_asset: asset.clone(), metadata_uri: uri };
+    assert_eq!(client.get_badge(&alice), Some(badge.clone()));
+
+    // Later deposits keep the original badge
+    env.ledger().set_timestamp(200);
+    client.deposit_to(&alice, &symbol_short!("desk"), &asset, &100);
+    assert_eq!(client.get_badge(&alice), Some(badge));
+
+    client.deposit_to(&bob, &symbol_short!("desk"), &asset, &100);
+    assert_eq!(client.get_badge(&bob).unwrap().number, 2);
+    assert_eq!(client.get_badge_count(), 2);
+}
+
+#[cfg(feature = "delivery")]
+#[test]
+fn test_claimable_delivery_to_custodian() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let (buyer, custodian) = (Address::generate(&env), Address::generate(&env));
+    let settled = |id: u8| {
+        let record = SettlementRecord {
+            match_id: BytesN::from_array(&env, &[id; 32]),
+            buyer: buyer.clone(),
+            seller: Address::generate(&env),
+            asset_address: asset.clone(),
+            quantity: 40,
+            price: 1_000,
+            payment_asset: Address::generate(&env),
+            payment_amount: 1_000,
+            timestamp: 0,
+            nullifier: BytesN::from_array(&env, &[id; 32]),
+            scheme_version: COMMITMENT_SCHEME_V1,
+        };
+        env.as_contract(&contract_id, || {
+            DarkPoolSettlement::store_settlement(&env, &record);
+            DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&buyer, &asset), 40);
+        });
+        record.match_id
+    };
+    StellarAssetClient::new(&env, &asset).mint(&contract_id, &80);
+    let (first, second) = (settled(1), settled(2));
+
+    let outsider = Address::generate(&env);
+    let not_buyer = client.try_send_claimable(&outsider, &first, &custodian, &100);
+    assert_eq!(not_buyer, Err(Ok(SettlementError::MatchNotFound)));
+
+    let delivery = client.send_claimable(&buyer, &first, &custodian, &100);
+    assert_eq!(delivery.amount, 40);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 40);
+    assert_eq!(
+        client.try_send_claimable(&buyer, &first, &custodian, &100),
+        Err(Ok(SettlementError::ClaimableExists))
+    );
+
+    // The custodian claims straight to its wallet
+    assert_eq!(client.try_reclaim_delivery(&buyer, &first), Err(Ok(SettlementError::ClaimableNotExpired)));
+    assert_eq!(client.claim_delivery(&custodian, &first), 40);
+    assert_eq!(token::TokenClient::new(&env, &asset).balance(&custodian), 40);
+    assert_eq!(client.get_claimable(&first).unwrap().status, ClaimableStatus::Claimed);
+    assert_eq!(client.try_claim_delivery(&custodian, &first), Err(Ok(SettlementError::ClaimableNotFound)));
+
+    // An unclaimed delivery returns to the buyer after expiry
+    client.send_claimable(&buyer, &second, &custodian, &100);
+    env.ledger().set_timestamp(101);
+    assert_eq!(client.try_claim_delivery(&custodian, &second), Err(Ok(SettlementError::ClaimableExpired)));
+    assert_eq!(client.reclaim_delivery(&buyer, &second), 40);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 40);
+}
+
+#[cfg(all(feature = "corporate-actions", feature = "ops"))]
+#[test]
+fn test_aggregate_escrow_totals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    StellarAssetClient::new(&env, &asset).mint(&alice, &1_000);
+    StellarAssetClient::new(&env, &asset).mint(&bob, &1_000);
+
+    client.deposit(&alice, &asset, &600);
+    client.deposit(&bob, &asset, &400);
+    client.lock_escrow(&alice, &asset, &500);
+    client.lock_escrow(&bob, &asset, &100);
+    client.unlock_escrow(&alice, &asset, &200);
+    client.withdraw(&bob, &asset, &300);
+    assert_eq!(client.get_total_escrow(&asset), 700);
+    assert_eq!(client.get_total_locked(&asset), 400);
+
+    // Moving locked funds between accounts leaves the escrow total unchanged
+    env.as_contract(&contract_id, || {
+        let from = EscrowKey::main(&alice, &asset);
+        DarkPoolSettlement::commit_transfer(&env, &from, &EscrowKey::main(&bob, &asset), 300);
+    });
+    assert_eq!(client.get_total_escrow(&asset), 700);
+    assert_eq!(client.get_total_locked(&asset), 100);
+
+    client.apply_split(&admin, &asset, &2, &1);
+    assert_eq!(client.get_total_escrow(&asset), 1_400);
+    assert_eq!(client.get_total_locked(&asset), 200);
+    assert_eq!(
+        client.get_escrow_balance(&alice, &asset) + client.get_escrow_balance(&bob, &asset),
+        1_400
+    );
+}
+
+#[cfg(feature = "withdrawal-queue")]
+#[test]
+fn test_withdrawal_queue_for_redemption_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let token = token::TokenClient::new(&env, &asset);
+    let (alice, bob) = (Address::generate(&env), Address::generate(&env));
+    StellarAssetClient::new(&env, &asset).mint(&alice, &1_000);
+    StellarAssetClient::new(&env, &asset).mint(&bob, &1_000);
+    client.deposit(&alice, &asset, &500);
+    client.deposit(&bob, &asset, &500);
+
+    env.ledger().set_timestamp(1_000);
+    client.set_redemption_delay(&admin, &asset, &Some(86_400));
+    assert_eq!(client.withdraw(&alice, &asset, &200), 300);
+    env.ledger().set_timestamp(2_000);
+    client.withdraw(&bob, &asset, &100);
+
+    // Escrow is debited at once; tokens wait in the queue
+    assert_eq!(token.balance(&alice), 500);
+    assert_eq!(client.get_withdrawal_queue(&asset), WithdrawalQueue { head: 0, tail: 2 });
+    assert_eq!(client.get_withdrawal_position(&asset, &1), Some(1));
+    let request = client.get_withdrawal_request(&asset, &0).unwrap();
+    assert_eq!(request.ready_at, 87_400);
+    assert_eq!(client.process_withdrawals(&asset, &10), 0);
+
+    env.ledger().set_timestamp(87_400);
+    assert_eq!(client.process_withdrawals(&asset, &10), 1);
+    assert_eq!(token.balance(&alice), 700);
+    assert_eq!(client.get_withdrawal_position(&asset, &0), None);
+    assert_eq!(client.get_withdrawal_position(&asset, &1), Some(0));
+
+    env.ledger().set_timestamp(88_400);
+    assert_eq!(client.process_withdrawals(&asset, &10), 1);
+    assert_eq!(token.balance(&bob), 600);
+    assert_eq!(client.get_withdrawal_request(&asset, &1), None);
+
+    // Without a delay withdrawals pay out directly again
+    client.set_redemption_delay(&admin, &asset, &None);
+    client.withdraw(&alice, &asset, &100);
+    assert_eq!(token.balance(&alice), 800);
+    assert_eq!(client.get_withdrawal_queue(&asset).tail, 2);
+}
+
+#[cfg(feature = "withdrawal-queue")]
+#[test]
+fn test_unlock_and_withdraw_waits_for_redemption_delay() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    let token = token::TokenClient::new(&env, &asset);
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&trader, &1_000);
+    client.deposit(&trader, &asset, &500);
+    client.lock_escrow(&trader, &asset, &300);
+
+    env.ledger().set_timestamp(1_000);
+    client.set_redemption_delay(&admin, &asset, &Some(86_400));
+    assert_eq!(client.unlock_and_withdraw(&trader, &asset, &200), 300);
+
+    // The unlocked funds join the queue like any other withdrawal
+    assert_eq!(token.balance(&trader), 500);
+    assert_eq!(client.get_locked_balance(&trader, &asset), 100);
+    let request = client.get_withdrawal_request(&asset, &0).unwrap();
+    assert_eq!((request.withdrawer, request.amount, request.ready_at), (trader.clone(), 200, 87_400));
+
+    env.ledger().set_timestamp(87_400);
+    assert_eq!(client.process_withdrawals(&asset, &10), 1);
+    assert_eq!(token.balance(&trader), 700);
+}
+
+#[test]
+fn test_pause_asset_blocks_deposits_and_locks() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let paused_asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let other_asset = env.register_stellar_asset_contract_v2(issuer).address();
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &paused_asset).mint(&trader, &1_000);
+    StellarAssetClient::new(&env, &other_asset).mint(&trader, &1_000);
+
+    client.deposit(&trader, &paused_asset, &600);
+    client.lock_escrow(&trader, &paused_asset, &200);
+
+    client.pause_asset(&admin, &paused_asset);
+    assert!(client.is_asset_paused(&paused_asset));
+    assert!(!client.is_asset_paused(&other_asset));
+
+    let deposit = client.try_deposit(&trader, &paused_asset, &100);
+    assert_eq!(deposit.err(), Some(Ok(SettlementError::AssetPaused)));
+    let lock = client.try_lock_escrow(&trader, &paused_asset, &100);
+    assert_eq!(lock.err(), Some(Ok(SettlementError::AssetPaused)));
+
+    // Other assets are unaffected and holders can still exit the paused one
+    client.deposit(&trader, &other_asset, &100);
+    client.unlock_escrow(&trader, &paused_asset, &200);
+    client.withdraw(&trader, &paused_asset, &600);
+
+    client.add_payment_asset(&admin, &other_asset);
+    env.as_contract(&contract_id, || {
+        let result = DarkPoolSettlement::execute_settlement(
+            &env,
+            None,
+            SettlementAuthMode::ProofOnly,
+            &BytesN::from_array(&env, &[20u8; 32]),
+            &trader,
+            &DEFAULT_SUB_ACCOUNT,
+            &Address::generate(&env),
+            &DEFAULT_SUB_ACCOUNT,
+            &paused_asset,
+            &other_asset,
+            10,
+            100,
+            &Bytes::new(&env),
+            &Bytes::new(&env),
+        );
+        assert_eq!(result.err(), Some(SettlementError::AssetPaused));
+    });
+
+    client.unpause_asset(&admin, &paused_asset);
+    client.deposit(&trader, &paused_asset, &100);
+}
+
+#[test]
+fn test_stale_oracle_throttles_settlement() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.timestamp = 10_000);
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let payment_asset = env.register_stellar_asset_contract_v2(issuer).address();
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&trader, &1_000);
+
+    let oracle = env.register(MockFxOracle, (FX_RATE_SCALE,));
+    let oracle_client = MockFxOracleClient::new(&env, &oracle);
+    oracle_client.set_updated(&9_000);
+    client.set_fx_oracle(&admin, &oracle);
+    client.add_payment_asset(&admin, &payment_asset);
+
+    assert!(!client.is_oracle_stale(&asset));
+    client.set_oracle_max_age(&admin, &asset, &Some(600));
+    assert_eq!(client.get_oracle_max_age(&asset), Some(600));
+    assert!(client.is_oracle_stale(&asset));
+
+    // Deposits and withdrawals still work while the price is stale
+    client.deposit(&trader, &asset, &500);
+    client.withdraw(&trader, &asset, &200);
+
+    // The freshness check runs once the legs are known to be funded
+    let seller = Address::generate(&env);
+    let mut pub_signals = Bytes::from_slice(&env, &7u32.to_be_bytes());
+    pub_signals.append(&Bytes::from_slice(&env, &[0u8; 7 * 32]));
+    env.as_contract(&contract_id, || {
+        for (key, amount) in [(EscrowKey::main(&trader, &payment_asset), 100), (EscrowKey::main(&seller, &asset), 10)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, amount);
+            DarkPoolSettlement::credit_locked(&env, &key, amount);
+        }
+    });
+
+    let settle = |match_byte: u8| {
+        env.as_contract(&contract_id, || {
+            DarkPoolSettlement::execute_settlement(
+                &env,
+                None,
+                SettlementAuthMode::ProofOnly,
+                &BytesN::from_array(&env, &[match_byte; 32]),
+                &trader,
+                &DEFAULT_SUB_ACCOUNT,
+                &seller,
+                &DEFAULT_SUB_ACCOUNT,
+                &asset,
+                &payment_asset,
+                10,
+                100,
+                &Bytes::new(&env),
+                &pub_signals,
+            )
+            .err()
+        })
+    };
+    assert_eq!(settle(21), Some(SettlementError::OracleStale));
+
+    // A fresh price lifts the throttle; settlement proceeds to the whitelist check
+    client.set_whitelist_check(&admin, &true);
+    oracle_client.set_updated(&9_500);
+    assert!(!client.is_oracle_stale(&asset));
+    assert_eq!(settle(22), Some(SettlementError::WhitelistRootMismatch));
+}
+
+#[cfg(feature = "corporate-actions")]
+#[test]
+fn test_balance_checkpoints() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let participant = Address::generate(&env);
+    let early = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let client_a = Symbol::new(&env, "client_a");
+
+    let credit = |key: EscrowKey, amount: i128| {
+        env.as_contract(&contract_id, || {
+            DarkPoolSettlement::credit_escrow(&env, &key, amount);
+        })
+    };
+    env.ledger().with_mut(|li| li.sequence_number = 500);
+    credit(EscrowKey::main(&early, &asset), 250);
+
+    assert_eq!(client.try_set_checkpoint_interval(&admin, &asset, &Some(0)), Err(Ok(SettlementError::InvalidAmount)));
+    client.set_checkpoint_interval(&admin, &asset, &Some(100));
+    assert_eq!(client.get_checkpoint_interval(&asset), Some(100));
+
+    // Holdings from before checkpoints were enabled count until they move
+    assert_eq!(client.get_balance_at(&early, &asset, &499), 0);
+    assert_eq!(client.get_balance_at(&early, &asset, &900), 250);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_050);
+    credit(EscrowKey::main(&participant, &asset), 500);
+    credit(EscrowKey::new(&participant, &client_a, &asset), 200);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1_230);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::debit_escrow(&env, &EscrowKey::main(&participant, &asset), 300).unwrap();
+    });
+
+    // Holdings sum sub-accounts and resolve to the enclosing interval
+    assert_eq!(client.get_balance_at(&participant, &asset, &999), 0);
+    assert_eq!(client.get_balance_at(&participant, &asset, &1_000), 700);
+    assert_eq!(client.get_balance_at(&participant, &asset, &1_199), 700);
+    assert_eq!(client.get_balance_at(&participant, &asset, &1_200), 400);
+    assert_eq!(client.get_balance_at(&participant, &asset, &5_000), 400);
+    assert_eq!(client.get_holding(&participant, &asset), 400);
+
+    // The first change seeds the holder's and the pool's checkpoints
+    credit(EscrowKey::main(&early, &asset), 50);
+    assert_eq!(client.get_balance_at(&early, &asset, &700), 250);
+    assert_eq!(client.get_balance_at(&early, &asset, &1_200), 300);
+    assert_eq!(client.get_total_holding_at(&asset, &600), 250);
+    assert_eq!(client.get_total_holding_at(&asset, &1_000), 950);
+    assert_eq!(client.get_total_holding_at(&asset, &1_200), 700);
+
+    // Lookups stop after a bounded number of checkpoints
+    for interval in 0..64 {
+        env.ledger().with_mut(|li| li.sequence_number = 2_000 + interval * 100);
+        credit(EscrowKey::main(&participant, &asset), 1);
+    }
+    assert_eq!(client.get_balance_at(&participant, &asset, &8_300), 464);
+    assert_eq!(client.get_balance_at(&participant, &asset, &2_000), 401);
+    assert_eq!(
+        client.try_get_balance_at(&participant, &asset, &1_999),
+        Err(Ok(SettlementError::RecordLedgerTooOld))
+    );
+
+    // Assets without checkpoints record nothing
+    let other = Address::generate(&env);
+    credit(EscrowKey::main(&participant, &other), 100);
+    assert_eq!(client.get_balance_at(&participant, &other, &1_230), 0);
+}
+
+#[cfg(feature = "corporate-actions")]
+#[test]
+fn test_distribution_pro_rated_by_checkpoint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let coupon_asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    StellarAssetClient::new(&env, &coupon_asset).mint(&issuer, &1_000);
+    client.set_checkpoint_interval(&admin, &asset, &Some(1));
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&alice, &asset), 300);
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&bob, &asset), 100);
+    });
+
+    // The record ledger has to be closed before it can be used
+    let open = client.try_distribute(&issuer, &asset, &coupon_asset, &1_000, &100);
+    assert_eq!(open, Err(Ok(SettlementError::RecordLedgerNotFinal)));
+
+    // Moves after the record ledger don't change entitlements
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&bob, &asset), 600);
+    });
+    let empty = client.try_distribute(&issuer, &asset, &coupon_asset, &1_000, &99);
+    assert_eq!(empty, Err(Ok(SettlementError::NoHoldingsAtRecord)));
+
+    let id = client.distribute(&issuer, &asset, &coupon_asset, &1_000, &100);
+    assert_eq!(client.get_total_holding_at(&asset, &100), 400);
+    assert_eq!(client.get_distribution_claimable(&id, &alice), 750);
+    assert_eq!(client.get_distribution_claimable(&id, &bob), 250);
+
+    let coupon = token::TokenClient::new(&env, &coupon_asset);
+    assert_eq!(client.claim_distribution(&alice, &id), 750);
+    assert_eq!(coupon.balance(&alice), 750);
+    assert_eq!(
+        client.try_claim_distribution(&alice, &id),
+        Err(Ok(SettlementError::DistributionClaimed))
+    );
+    assert_eq!(client.get_distribution_claimable(&id, &alice), 0);
+    assert_eq!(client.claim_distribution(&bob, &id), 250);
+    assert_eq!(client.get_distribution(&id).unwrap().claimed_amount, 1_000);
+    assert_eq!(
+        client.try_claim_distribution(&bob, &7),
+        Err(Ok(SettlementError::DistributionNotFound))
+    );
+}
+
+#[cfg(feature = "corporate-actions")]
+#[test]
+fn test_distribution_counts_holdings_from_before_checkpoints() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let coupon_asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    StellarAssetClient::new(&env, &coupon_asset).mint(&issuer, &1_000);
+    let credit = |key: EscrowKey, amount: i128| {
+        env.as_contract(&contract_id, || {
+            DarkPoolSettlement::credit_escrow(&env, &key, amount);
+        })
+    };
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    credit(EscrowKey::main(&alice, &asset), 300);
+    credit(EscrowKey::new(&alice, &Symbol::new(&env, "client_a"), &asset), 100);
+    env.ledger().with_mut(|li| li.sequence_number = 110);
+    client.set_checkpoint_interval(&admin, &asset, &Some(10));
+    env.ledger().with_mut(|li| li.sequence_number = 120);
+    credit(EscrowKey::main(&bob, &asset), 100);
+
+    env.ledger().with_mut(|li| li.sequence_number = 140);
+    let id = client.distribute(&issuer, &asset, &coupon_asset, &1_000, &130);
+    assert_eq!(client.get_distribution(&id).unwrap().total_holding, 500);
+
+    // Alice's first move after the record ledger doesn't change her share
+    env.ledger().with_mut(|li| li.sequence_number = 150);
+    credit(EscrowKey::main(&alice, &asset), 50);
+    assert_eq!(client.get_balance_at(&alice, &asset, &130), 400);
+    assert_eq!(client.claim_distribution(&alice, &id), 800);
+    assert_eq!(client.claim_distribution(&bob, &id), 200);
+}
+
+#[cfg(feature = "corporate-actions")]
+#[test]
+fn test_distribution_across_splits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (alice, bob, carol) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let issuer = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let coupon_asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    StellarAssetClient::new(&env, &coupon_asset).mint(&issuer, &1_000);
+    let credit = |holder: &Address, amount: i128| {
+        env.as_contract(&contract_id, || {
+            DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(holder, &asset), amount);
+        })
+    };
+
+    env.ledger().with_mut(|li| li.sequence_number = 10);
+    credit(&alice, 300);
+    env.ledger().with_mut(|li| li.sequence_number = 20);
+    client.set_checkpoint_interval(&admin, &asset, &Some(10));
+    env.ledger().with_mut(|li| li.sequence_number = 30);
+    credit(&bob, 100);
+
+    // Carol's holding is recorded after the split, alice's and bob's before it
+    env.ledger().with_mut(|li| li.sequence_number = 40);
+    client.apply_split(&admin, &asset, &2, &1);
+    env.ledger().with_mut(|li| li.sequence_number = 45);
+    credit(&carol, 200);
+
+    // The record ledger's whole checkpoint interval has to be closed
+    env.ledger().with_mut(|li| li.sequence_number = 55);
+    let open = client.try_distribute(&issuer, &asset, &coupon_asset, &1_000, &50);
+    assert_eq!(open, Err(Ok(SettlementError::RecordLedgerNotFinal)));
+    env.ledger().with_mut(|li| li.sequence_number = 60);
+    let id = client.distribute(&issuer, &asset, &coupon_asset, &1_000, &50);
+    assert_eq!(client.get_distribution(&id).unwrap().total_holding, 1_000);
+
+    // A later split and move leave the shares as they were
+    env.ledger().with_mut(|li| li.sequence_number = 70);
+    client.apply_split(&admin, &asset, &3, &1);
+    env.ledger().with_mut(|li| li.sequence_number = 80);
+    credit(&alice, 1);
+    assert_eq!(client.get_balance_at(&alice, &asset, &50), 1_800);
+    assert_eq!(client.get_total_holding_at(&asset, &50), 3_000);
+
+    assert_eq!(client.claim_distribution(&alice, &id), 600);
+    assert_eq!(client.claim_distribution(&bob, &id), 200);
+    assert_eq!(client.claim_distribution(&carol, &id), 200);
+}
+
+#[cfg(all(feature = "corporate-actions", feature = "fees"))]
+#[test]
+fn test_distribution_dust_claimed_by_treasury() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let holders = [Address::generate(&env), Address::generate(&env), Address::generate(&env)];
+    let issuer = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let coupon_asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    StellarAssetClient::new(&env, &coupon_asset).mint(&issuer, &100);
+    client.set_checkpoint_interval(&admin, &asset, &Some(1));
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    env.as_contract(&contract_id, || {
+        for holder in holders.iter() {
+            DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(holder, &asset), 1);
+        }
+    });
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    let id = client.distribute(&issuer, &asset, &coupon_asset, &100, &100);
+
+    // Each third rounds down; the fractions only add up to a unit at the last claim
+    for (claimed, holder) in holders.iter().enumerate() {
+        assert_eq!(client.get_dust(&coupon_asset), 0, "after {claimed} claims");
+        assert_eq!(client.claim_distribution(holder, &id), 33);
+    }
+    assert_eq!(client.get_dust(&coupon_asset), 1);
+
+    assert_eq!(client.try_claim_dust(&treasury, &coupon_asset), Err(Ok(SettlementError::OnlyTreasury)));
+    client.set_treasury(&admin, &treasury);
+    assert_eq!(client.claim_dust(&treasury, &coupon_asset), 1);
+    assert_eq!(client.get_dust(&coupon_asset), 0);
+
+    let coupon = token::TokenClient::new(&env, &coupon_asset);
+    let paid_out: i128 = holders.iter().map(|holder| coupon.balance(holder)).sum::<i128>() + coupon.balance(&treasury);
+    assert_eq!(paid_out, 100);
+    assert_eq!(coupon.balance(&contract_id), 0);
+}
+
+#[cfg(all(feature = "corporate-actions", feature = "delivery"))]
+#[test]
+fn test_split_rescales_balances() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let other = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[33u8; 32]);
+    client.set_settlement_delay(&admin, &asset, &10);
+
+    env.as_contract(&contract_id, || {
+        let alice_key = EscrowKey::main(&alice, &asset);
+        DarkPoolSettlement::credit_escrow(&env, &alice_key, 1_000);
+        DarkPoolSettlement::credit_locked(&env, &alice_key, 700);
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&alice, &other), 1_000);
+        DarkPoolSettlement::deliver_leg(&env, &match_id, &alice_key, &EscrowKey::main(&bob, &asset), 400);
+    });
+
+    assert_eq!(client.try_apply_split(&admin, &asset, &3, &0), Err(Ok(SettlementError::InvalidAmount)));
+    client.apply_split(&admin, &asset, &3, &2);
+    assert_eq!(client.get_splits(&asset).len(), 1);
+
+    assert_eq!(client.get_escrow_balance(&alice, &asset), 900);
+    assert_eq!(client.get_locked_balance(&alice, &asset), 450);
+    assert_eq!(client.get_escrow_balance(&alice, &other), 1_000);
+    assert_eq!(client.get_pending_deliveries(&match_id).get(0).unwrap().amount, 600);
+
+    // Balances written after the split are already in post-split units
+    env.ledger().with_mut(|li| li.sequence_number += 10);
+    client.claim_settled(&match_id);
+    assert_eq!(client.get_escrow_balance(&bob, &asset), 600);
+
+    // A later reverse split applies on top of both
+    client.apply_split(&admin, &asset, &1, &3);
+    assert_eq!(client.get_escrow_balance(&alice, &asset), 300);
+    assert_eq!(client.get_escrow_balance(&bob, &asset), 200);
+}
+
+#[cfg(feature = "corporate-actions")]
+#[test]
+fn test_migrate_reissued_asset() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let old_asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let new_asset = env.register_stellar_asset_contract_v2(issuer.clone()).address();
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &old_asset).mint(&trader, &1_000);
+    StellarAssetClient::new(&env, &new_asset).mint(&issuer, &3_000);
+
+    client.deposit(&trader, &old_asset, &1_000);
+    client.lock_escrow(&trader, &old_asset, &400);
+
+    // Two new tokens per old token, with only enough reserve for half the pool
+    client.migrate_asset(&admin, &issuer, &old_asset, &new_asset, &(2 * FX_RATE_SCALE), &1_000);
+    assert!(client.is_asset_paused(&old_asset));
+    assert_eq!(
+        client.try_migrate_asset(&admin, &issuer, &old_asset, &new_asset, &FX_RATE_SCALE, &0),
+        Err(Ok(SettlementError::AssetAlreadyMigrated))
+    );
+
+    assert_eq!(
+        client.try_convert_migrated_asset(&trader, &DEFAULT_SUB_ACCOUNT, &old_asset),
+        Err(Ok(SettlementError::MigrationReserveInsufficient))
+    );
+    client.fund_migration_reserve(&issuer, &old_asset, &1_000);
+    assert_eq!(client.convert_migrated_asset(&trader, &DEFAULT_SUB_ACCOUNT, &old_asset), 1_200);
+    assert_eq!(client.get_escrow_balance(&trader, &new_asset), 1_200);
+    assert_eq!(client.get_escrow_balance(&trader, &old_asset), 400);
+
+    // Locked funds follow once released
+    client.unlock_escrow(&trader, &old_asset, &400);
+    assert_eq!(client.convert_migrated_asset(&trader, &DEFAULT_SUB_ACCOUNT, &old_asset), 800);
+    let migration = client.get_asset_migration(&old_asset).unwrap();
+    assert_eq!(migration.reserve, 0);
+    assert_eq!(migration.converted, 1_000);
+
+    assert_eq!(client.reclaim_migrated_asset(&old_asset), 1_000);
+    assert_eq!(token::TokenClient::new(&env, &old_asset).balance(&issuer), 1_000);
+    client.withdraw(&trader, &new_asset, &2_000);
+    assert_eq!(
+        client.try_convert_migrated_asset(&trader, &DEFAULT_SUB_ACCOUNT, &new_asset),
+        Err(Ok(SettlementError::AssetNotMigrated))
+    );
+}
+
+#[test]
+fn test_settle_trade_with_generated_proof() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    // Settlement signals: nullifier, commitments, asset hash, quantity, price, root
+    let signals = |nullifier: u64| {
+        [scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)]
+    };
+    let fixture = generate(42, &signals(1));
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    let settle = |match_byte: u8, fixture: &darkpool_testdata::ProofFixture| {
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[match_byte; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, &fixture.signals),
+        )
+    };
+
+    // A proof for other signals fails the pairing check in the verifier
+    let mut forged = fixture.clone();
+    forged.signals = generate(42, &signals(2)).signals;
+    assert_eq!(settle(60, &forged).err(), Some(Ok(SettlementError::InvalidProof)));
+
+    settle(61, &fixture).unwrap().unwrap();
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 100);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 5_000);
+    assert!(client.is_nullifier_used(&BytesN::from_array(&env, &scalar(1))));
+
+    // The key keeps accepting proofs generated from the same seed
+    settle(62, &generate(42, &signals(2))).unwrap().unwrap();
+    assert_eq!(settle(63, &fixture).err(), Some(Ok(SettlementError::NullifierUsed)));
+}
+
+#[cfg(feature = "relayers")]
+#[test]
+fn test_idempotent_settlement_retries() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signals = [scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)];
+    let fixture = generate(42, &signals);
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    let relayer = Address::generate(&env);
+    let key = BytesN::from_array(&env, &[7u8; 32]);
+    let settle = |match_byte: u8| {
+        client.try_settle_trade_idempotent(
+            &relayer,
+            &key,
+            &BytesN::from_array(&env, &[match_byte; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, &fixture.signals),
+        )
+    };
+
+    let record = settle(1).unwrap().unwrap();
+    // A retry returns the original record instead of tripping the nullifier
+    assert_eq!(settle(1).unwrap().unwrap(), record);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 100);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 5_000);
+
+    assert_eq!(settle(2).err(), Some(Ok(SettlementError::IdempotencyKeyReused)));
+}
+
+#[cfg(feature = "fx")]
+#[test]
+fn test_unit_priced_settlement_rounding() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let fixture = |nullifier: u64| {
+        let signals = [scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(3), scalar(5), scalar(14)];
+        generate(42, &signals)
+    };
+    let fixtures = [fixture(1), fixture(2)];
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixtures[0].vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    // 3 units at 1.6666667 each come to 5.0000001 payment units
+    let unit_price = 16_666_667;
+    let settle = |id: u8, rounding: RoundingMode| {
+        let fixture = &fixtures[id as usize - 1];
+        client.settle_trade_unit_priced(
+            &BytesN::from_array(&env, &[id; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &3,
+            &unit_price,
+            &rounding,
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, &fixture.signals),
+        )
+    };
+
+    assert_eq!(settle(1, RoundingMode::FloorToSeller).price, 5);
+    assert_eq!(settle(2, RoundingMode::CeilToBuyer).price, 6);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 11);
+
+    let quote = client.get_unit_quote(&None, &BytesN::from_array(&env, &[2u8; 32])).unwrap();
+    assert_eq!(quote, UnitQuote { unit_price, rounding: RoundingMode::CeilToBuyer });
+    assert_eq!(client.get_unit_quote(&None, &BytesN::from_array(&env, &[3u8; 32])), None);
+}
+
+#[test]
+fn test_rejected_duplicate_is_cheap() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signals = [scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)];
+    let fixture = generate(42, &signals);
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    let proof = Bytes::from_slice(&env, &fixture.proof);
+    let pub_signals = Bytes::from_slice(&env, &fixture.signals);
+    let settle = |match_byte: u8, quantity: i128| {
+        env.cost_estimate().budget().reset_default();
+        let result = client.try_settle_trade(
+            &BytesN::from_array(&env, &[match_byte; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &quantity,
+            &5_000,
+            &proof,
+            &pub_signals,
+        );
+        (result.err(), env.cost_estimate().budget().cpu_instruction_cost())
+    };
+
+    let (error, success_cost) = settle(1, 100);
+    assert_eq!(error, None);
+
+    // A replayed proof and an underfunded fill both fail before verification
+    let (error, duplicate_cost) = settle(2, 100);
+    assert_eq!(error, Some(Ok(SettlementError::NullifierUsed)));
+    assert!(
+        duplicate_cost * 10 < success_cost,
+        "duplicate ({duplicate_cost}) should cost a fraction of a settlement ({success_cost})"
+    );
+    env.as_contract(&contract_id, || {
+        let record = DarkPoolSettlement::load_settlement(&env, &BytesN::from_array(&env, &[1u8; 32])).unwrap();
+        env.storage().persistent().remove(&(NULLIFIERS_KEY, record.nullifier));
+    });
+    let (error, underfunded_cost) = settle(3, 1_000_000);
+    assert_eq!(error, Some(Ok(SettlementError::InsufficientLockedFunds)));
+    assert!(underfunded_cost * 10 < success_cost);
+}
+
+#[cfg(feature = "delegation")]
+#[test]
+fn test_signed_intents_replace_live_party_auth() {
+    use darkpool_testdata::{generate, scalar};
+    use ed25519_dalek::{Signer, SigningKey};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signals = [scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)];
+    let fixture = generate(42, &signals);
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    client.set_auth_mode(&admin, &SettlementAuthMode::BothParties);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    let buyer_key = SigningKey::from_bytes(&[1u8; 32]);
+    let seller_key = SigningKey::from_bytes(&[2u8; 32]);
+    client.set_intent_key(&buyer, &Some(BytesN::from_array(&env, &buyer_key.verifying_key().to_bytes())));
+
+    env.ledger().set_timestamp(1_000);
+    let intent = SettlementIntent {
+        match_id: BytesN::from_array(&env, &[1u8; 32]),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        asset_address: asset.clone(),
+        payment_asset: usdc.clone(),
+        quantity: 100,
+        price: 5_000,
+        expires_at: 2_000,
+    };
+    let sign = |key: &SigningKey, intent: &SettlementIntent| {
+        let hash = client.intent_hash(intent).to_array();
+        BytesN::from_array(&env, &key.sign(&hash).to_bytes())
+    };
+    let proof = Bytes::from_slice(&env, &fixture.proof);
+    let pub_signals = Bytes::from_slice(&env, &fixture.signals);
+
+    let no_key = client.try_settle_trade_signed(
+        &intent,
+        &sign(&buyer_key, &intent),
+        &sign(&seller_key, &intent),
+        &proof,
+        &pub_signals,
+    );
+    assert_eq!(no_key, Err(Ok(SettlementError::IntentKeyNotSet)));
+    client.set_intent_key(&seller, &Some(BytesN::from_array(&env, &seller_key.verifying_key().to_bytes())));
+
+    // Neither party is around to authorize live
+    env.set_auths(&[]);
+    let live = client.try_settle_trade(
+        &intent.match_id,
+        &buyer,
+        &seller,
+        &asset,
+        &usdc,
+        &100,
+        &5_000,
+        &proof,
+        &pub_signals,
+    );
+    assert!(live.is_err());
+
+    // A signature over different terms does not verify
+    let tampered = SettlementIntent { price: 4_000, ..intent.clone() };
+    let forged = client.try_settle_trade_signed(
+        &intent,
+        &sign(&buyer_key, &intent),
+        &sign(&seller_key, &tampered),
+        &proof,
+        &pub_signals,
+    );
+    assert!(forged.is_err());
+
+    let record = client.settle_trade_signed(
+        &intent,
+        &sign(&buyer_key, &intent),
+        &sign(&seller_key, &intent),
+        &proof,
+        &pub_signals,
+    );
+    assert_eq!(record.payment_amount, 5_000);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 100);
+
+    env.ledger().set_timestamp(2_001);
+    let expired = SettlementIntent { match_id: BytesN::from_array(&env, &[2u8; 32]), ..intent };
+    let late = client.try_settle_trade_signed(
+        &expired,
+        &sign(&buyer_key, &expired),
+        &sign(&seller_key, &expired),
+        &proof,
+        &pub_signals,
+    );
+    assert_eq!(late, Err(Ok(SettlementError::IntentExpired)));
+}
+
+#[cfg(feature = "fees")]
+#[test]
+fn test_fee_rounding_conserves_value() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signals = [scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_001), scalar(14)];
+    let fixture = generate(42, &signals);
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (recipient, referrer) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+        // Unlocked escrow the buyer's fee is taken from
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&buyer, &usdc), 100);
+    });
+
+    // Odd rates so every fee and the referral share have a fraction to drop
+    client.set_fee_schedule(
+        &admin,
+        &FeeSchedule {
+            buyer_fee_bps: 33,
+            seller_fee_bps: 17,
+            recipient: recipient.clone(),
+        },
+    );
+    client.set_referral_share(&admin, &3_333);
+    client.set_referrer(&buyer, &referrer);
+
+    client.settle_trade(
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &usdc,
+        &100,
+        &5_001,
+        &Bytes::from_slice(&env, &fixture.proof),
+        &Bytes::from_slice(&env, &fixture.signals),
+    );
+
+    // 5_001 * 0.33% = 16.5033 and 5_001 * 0.17% = 8.5017, both rounded down
+    assert_eq!(client.get_escrow_balance(&buyer, &usdc), 10_100 - 5_001 - 16);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 5_001 - 8);
+    // A third of the buyer's 16 goes to the referrer; the fraction stays with the recipient
+    assert_eq!(client.get_referral_fees(&referrer, &usdc), 5);
+    assert_eq!(client.get_escrow_balance(&recipient, &usdc), 16 + 8 - 5);
+
+    let total = [&buyer, &seller, &recipient]
+        .iter()
+        .map(|party| client.get_escrow_balance(party, &usdc))
+        .sum::<i128>()
+        + client.get_referral_fees(&referrer, &usdc)
+        + client.get_dust(&usdc);
+    assert_eq!(total, 10_100);
+}
+
+#[cfg(feature = "order-controls")]
+#[test]
+fn test_tick_size_enforced_on_trade_and_signals() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signals = [scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)];
+    let fixture = generate(42, &signals);
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    let invalid = TickSize { lot_size: 0, tick_size: 10 };
+    assert_eq!(
+        client.try_set_tick_size(&admin, &asset, &Some(invalid)),
+        Err(Ok(SettlementError::InvalidAmount))
+    );
+
+    let settle = |ticks: TickSize, quantity: i128, price: i128| {
+        client.set_tick_size(&admin, &asset, &Some(ticks));
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &quantity,
+            &price,
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, &fixture.signals),
+        )
+    };
+
+    // Off-lot quantity and off-tick price
+    let off_lot = settle(TickSize { lot_size: 30, tick_size: 1_000 }, 100, 5_000);
+    assert_eq!(off_lot.err(), Some(Ok(SettlementError::InvalidTick)));
+    let off_tick = settle(TickSize { lot_size: 50, tick_size: 3 }, 100, 5_000);
+    assert_eq!(off_tick.err(), Some(Ok(SettlementError::InvalidTick)));
+
+    // The submitted quantity conforms but the proven one does not
+    let off_signal = settle(TickSize { lot_size: 40, tick_size: 1_000 }, 120, 5_000);
+    assert_eq!(off_signal.err(), Some(Ok(SettlementError::InvalidTick)));
+
+    settle(TickSize { lot_size: 50, tick_size: 1_000 }, 100, 5_000).unwrap().unwrap();
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 100);
+
+    client.set_tick_size(&admin, &asset, &None);
+    assert_eq!(client.get_tick_size(&asset), None);
+}
+
+#[cfg(feature = "proofs")]
+#[test]
+fn test_commitment_scheme_upgrade_window() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    // Same circuit shape, separate setups standing in for the two schemes
+    let fixture = |seed: u64, nullifier: u64| {
+        let signals = [scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)];
+        generate(seed, &signals)
+    };
+    let (old_order, new_order, late_order) = (fixture(1, 1), fixture(2, 2), fixture(1, 3));
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &old_order.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+
+    assert_eq!(client.get_commitment_scheme(), COMMITMENT_SCHEME_V1);
+    let new_vk = Bytes::from_slice(&env, &new_order.vk);
+    client.upgrade_commitment_scheme(&admin, &2, &new_vk);
+    assert_eq!(client.get_commitment_scheme(), 2);
+    assert_eq!(client.get_open_commitment_schemes(), vec![&env, COMMITMENT_SCHEME_V1]);
+    assert_eq!(
+        client.try_upgrade_commitment_scheme(&admin, &2, &new_vk),
+        Err(Ok(SettlementError::InvalidCommitmentScheme))
+    );
+
+    let settle = |id: u8, fixture: &darkpool_testdata::ProofFixture| {
+        let match_id = BytesN::from_array(&env, &[id; 32]);
+        client
+            .try_settle_trade(
+                &match_id,
+                &buyer,
+                &seller,
+                &asset,
+                &usdc,
+                &100,
+                &5_000,
+                &Bytes::from_slice(&env, &fixture.proof),
+                &Bytes::from_slice(&env, &fixture.signals),
+            )
+            .map(|_| client.get_settlement(&None, &match_id).unwrap().scheme_version)
+    };
+
+    // Orders from before the upgrade settle under the old scheme's key
+    assert_eq!(settle(1, &old_order), Ok(COMMITMENT_SCHEME_V1));
+    assert_eq!(settle(2, &new_order), Ok(2));
+
+    client.close_commitment_scheme(&admin, &COMMITMENT_SCHEME_V1);
+    assert_eq!(client.get_open_commitment_schemes().len(), 0);
+    assert_eq!(settle(3, &late_order).err(), Some(Ok(SettlementError::InvalidProof)));
+    assert_eq!(
+        client.try_close_commitment_scheme(&admin, &COMMITMENT_SCHEME_V1),
+        Err(Ok(SettlementError::InvalidCommitmentScheme))
+    );
+}
+
+#[cfg(all(feature = "baskets", feature = "proofs"))]
+#[test]
+fn test_domain_bound_proofs() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let unbound = generate(1, &[scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)]);
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &unbound.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+
+    // The separator is tied to this instance
+    let domain = client.get_domain_separator();
+    assert_eq!(domain.to_array()[0], 0);
+    let other = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    assert_ne!(DarkPoolSettlementClient::new(&env, &other).get_domain_separator(), domain);
+
+    let bound = |nullifier: u64, pool_domain: [u8; 32]| {
+        let signals =
+            [scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14), pool_domain];
+        generate(2, &signals)
+    };
+    let for_this_pool = bound(2, domain.to_array());
+    client.upgrade_commitment_scheme(&admin, &2, &Bytes::from_slice(&env, &for_this_pool.vk));
+    client.set_domain_binding(&admin, &true);
+    assert!(client.is_domain_bound());
+
+    let settle = |id: u8, fixture: &darkpool_testdata::ProofFixture| {
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[id; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, &fixture.signals),
+        )
+    };
+
+    // Unbound proofs and proofs for another pool are refused
+    assert_eq!(settle(1, &unbound).err(), Some(Ok(SettlementError::InvalidProof)));
+    let replayed = bound(3, DarkPoolSettlementClient::new(&env, &other).get_domain_separator().to_array());
+    assert_eq!(settle(2, &replayed).err(), Some(Ok(SettlementError::DomainMismatch)));
+
+    // A proof from another setup falls back to the open unbound key, whose
+    // signal count does not fit; that is no match rather than a trap
+    let signals = [scalar(4), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14), domain.to_array()];
+    let forged = generate(5, &signals);
+    assert_eq!(settle(4, &forged).err(), Some(Ok(SettlementError::InvalidProof)));
+
+    assert!(settle(3, &for_this_pool).is_ok());
+
+    // Basket proofs carry the separator after their legs
+    let asset_hash = env.as_contract(&contract_id, || DarkPoolSettlement::address_field_hash(&env, &asset)).to_array();
+    let basket = |nullifier: u64, pool_domain: Option<[u8; 32]>| {
+        let legs = [scalar(nullifier), scalar(14), scalar(11), scalar(12), asset_hash, scalar(100), scalar(5_000)];
+        match pool_domain {
+            Some(pool_domain) => {
+                let mut signals = [pool_domain; 8];
+                signals[..7].copy_from_slice(&legs);
+                generate(6, &signals)
+            }
+            None => generate(6, &legs),
+        }
+    };
+    client.set_basket_vk(&admin, &Bytes::from_slice(&env, &basket(0, Some(domain.to_array())).vk));
+    let settle_basket = |id: u8, fixture: &darkpool_testdata::ProofFixture| {
+        client.try_settle_basket(
+            &BytesN::from_array(&env, &[id; 32]),
+            &buyer,
+            &seller,
+            &usdc,
+            &vec![&env, SettlementLeg { asset_address: asset.clone(), quantity: 100, price: 5_000 }],
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, &fixture.signals),
+        )
+    };
+    assert_eq!(settle_basket(5, &basket(20, None)).err(), Some(Ok(SettlementError::InvalidProof)));
+    let replayed = basket(21, Some(DarkPoolSettlementClient::new(&env, &other).get_domain_separator().to_array()));
+    assert_eq!(settle_basket(6, &replayed).err(), Some(Ok(SettlementError::DomainMismatch)));
+    assert!(settle_basket(7, &basket(22, Some(domain.to_array()))).is_ok());
+}
+
+#[cfg(feature = "schedules")]
+#[test]
+fn test_twap_slices_keep_to_schedule() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64| {
+        generate(42, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+
+    let parent = BytesN::from_array(&env, &[90u8; 32]);
+    let schedule = TwapSchedule { total_quantity: 250, max_slice: 100, min_spacing: 60, start: 1_000 };
+    client.create_twap(&seller, &parent, &schedule);
+    assert_eq!(client.try_create_twap(&buyer, &parent, &schedule), Err(Ok(SettlementError::TwapAlreadyExists)));
+
+    // Only the owner links slices, and only to its own parent
+    let child = BytesN::from_array(&env, &scalar(12));
+    let foreign = client.try_attach_twap_slice(&buyer, &parent, &child);
+    assert_eq!(foreign, Err(Ok(SettlementError::TwapNotFound)));
+    client.attach_twap_slice(&seller, &parent, &child);
+    assert_eq!(client.get_twap_parent(&seller, &child), Some(parent.clone()));
+
+    let settle = |nullifier: u64, quantity: i128| {
+        let proof = fixture(nullifier);
+        client
+            .try_settle_trade(
+                &BytesN::from_array(&env, &[nullifier as u8; 32]),
+                &buyer,
+                &seller,
+                &asset,
+                &usdc,
+                &quantity,
+                &5_000,
+                &Bytes::from_slice(&env, &proof.proof),
+                &Bytes::from_slice(&env, &proof.signals),
+            )
+            .map(|_| ())
+    };
+
+    env.ledger().set_timestamp(500);
+    assert_eq!(settle(1, 100), Err(Ok(SettlementError::TwapSliceTooEarly)));
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(settle(1, 150), Err(Ok(SettlementError::TwapSliceTooLarge)));
+    assert_eq!(settle(1, 100), Ok(()));
+
+    env.ledger().set_timestamp(1_030);
+    assert_eq!(settle(2, 100), Err(Ok(SettlementError::TwapSliceTooEarly)));
+    env.ledger().set_timestamp(1_060);
+    assert_eq!(settle(2, 100), Ok(()));
+
+    env.ledger().set_timestamp(1_200);
+    assert_eq!(settle(3, 100), Err(Ok(SettlementError::TwapOverfilled)));
+    let order = client.get_twap(&parent).unwrap();
+    assert_eq!((order.filled, order.slices, order.last_fill), (200, 2, Some(1_060)));
+
+    // Slices of a closed parent cannot settle
+    client.cancel_twap(&seller, &parent);
+    assert_eq!(settle(3, 50), Err(Ok(SettlementError::TwapNotFound)));
+}
+
+#[cfg(feature = "margin")]
+#[test]
+fn test_pledged_collateral_covers_payment_lock() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64| {
+        generate(42, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    // One unit of gold is worth two of the payment asset
+    let oracle = env.register(MockFxOracle, (2 * FX_RATE_SCALE,));
+    client.set_fx_oracle(&admin, &oracle);
+    let (buyer, seller, converter) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let (asset, usdc, gold) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&seller, &asset), 100);
+        DarkPoolSettlement::credit_locked(&env, &EscrowKey::main(&seller, &asset), 100);
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&buyer, &usdc), 2_000);
+        DarkPoolSettlement::credit_locked(&env, &EscrowKey::main(&buyer, &usdc), 2_000);
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&buyer, &gold), 5_000);
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&converter, &usdc), 10_000);
+    });
+
+    let unapproved = client.try_pledge_collateral(&buyer, &usdc, &gold, &2_000);
+    assert_eq!(unapproved, Err(Ok(SettlementError::CollateralNotApproved)));
+    let bad = client.try_set_collateral_haircut(&admin, &gold, &Some(10_000));
+    assert_eq!(bad, Err(Ok(SettlementError::InvalidHaircut)));
+    client.set_collateral_haircut(&admin, &gold, &Some(2_000));
+
+    client.pledge_collateral(&buyer, &usdc, &gold, &2_000);
+    assert_eq!(client.get_locked_balance(&buyer, &gold), 2_000);
+    assert_eq!(client.get_collateral_value(&buyer, &usdc), 3_200);
+
+    let settle = |nullifier: u64| {
+        let proof = fixture(nullifier);
+        client
+            .try_settle_trade(
+                &BytesN::from_array(&env, &[nullifier as u8; 32]),
+                &buyer,
+                &seller,
+                &asset,
+                &usdc,
+                &100,
+                &5_000,
+                &Bytes::from_slice(&env, &proof.proof),
+                &Bytes::from_slice(&env, &proof.signals),
+            )
+            .map(|_| ())
+    };
+    assert_eq!(settle(1), Err(Ok(SettlementError::CollateralConverterNotSet)));
+    client.set_collateral_converter(&admin, &Some(converter.clone()));
+    assert_eq!(settle(1), Ok(()));
+
+    // The 3,000 shortfall took 1,875 gold at its haircut value of 1.6
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 5_000);
+    assert_eq!(client.get_escrow_balance(&buyer, &usdc), 0);
+    assert_eq!(client.get_escrow_balance(&buyer, &gold), 3_125);
+    assert_eq!(client.get_escrow_balance(&converter, &gold), 1_875);
+    assert_eq!(client.get_escrow_balance(&converter, &usdc), 7_000);
+    assert_eq!(client.get_pledged_collateral(&buyer, &usdc).get(gold.clone()), Some(125));
+
+    let over = client.try_release_collateral(&buyer, &usdc, &gold, &200);
+    assert_eq!(over, Err(Ok(SettlementError::InsufficientCollateral)));
+    client.release_collateral(&buyer, &usdc, &gold, &125);
+    assert_eq!(client.get_locked_balance(&buyer, &gold), 0);
+    assert!(client.get_pledged_collateral(&buyer, &usdc).is_empty());
+}
+
+#[cfg(all(feature = "accounts", feature = "delegation", feature = "margin"))]
+#[test]
+fn test_unlocks_cannot_free_pledged_collateral() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let oracle = env.register(MockFxOracle, (2 * FX_RATE_SCALE,));
+    client.set_fx_oracle(&admin, &oracle);
+    let (trader, broker) = (Address::generate(&env), Address::generate(&env));
+    let (usdc, eurc, gold) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&trader, &gold), 1_000);
+    });
+    client.set_collateral_haircut(&admin, &gold, &Some(2_000));
+    client.lock_escrow(&trader, &gold, &300);
+    client.pledge_collateral(&trader, &usdc, &gold, &400);
+    client.pledge_collateral(&trader, &eurc, &gold, &100);
+    assert_eq!(client.get_pledged_units(&trader, &gold), 500);
+    assert_eq!(client.get_locked_balance(&trader, &gold), 800);
+
+    // The order lock can be released, the pledged units cannot
+    client.unlock_escrow(&trader, &gold, &300);
+    let pledged = SettlementError::CollateralPledged;
+    assert_eq!(client.try_unlock_escrow(&trader, &gold, &1), Err(Ok(pledged)));
+    assert_eq!(client.try_unlock_and_withdraw(&trader, &gold, &1), Err(Ok(pledged)));
+    assert_eq!(client.try_unlock_escrow_in(&trader, &DEFAULT_SUB_ACCOUNT, &gold, &1), Err(Ok(pledged)));
+    client.set_broker(&trader, &broker, &true, &false);
+    let by_broker = client.try_broker_unlock_escrow(&broker, &trader, &DEFAULT_SUB_ACCOUNT, &gold, &1);
+    assert_eq!(by_broker, Err(Ok(pledged)));
+    assert_eq!(client.get_locked_balance(&trader, &gold), 500);
+
+    client.release_collateral(&trader, &usdc, &gold, &400);
+    assert_eq!(client.get_pledged_units(&trader, &gold), 100);
+    client.release_collateral(&trader, &eurc, &gold, &100);
+    assert_eq!(client.get_pledged_units(&trader, &gold), 0);
+    assert_eq!(client.get_locked_balance(&trader, &gold), 0);
+}
+
+#[cfg(feature = "margin")]
+#[test]
+fn test_keeper_liquidates_undercollateralized_margin() {
+    use soroban_sdk::{testutils::Events, Event};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let registry = registry_wasm::Client::new(&env, &client.get_registry());
+
+    let oracle = env.register(MockFxOracle, (2 * FX_RATE_SCALE,));
+    client.set_fx_oracle(&admin, &oracle);
+    let (trader, keeper, bidder) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let (usdc, gold) = (Address::generate(&env), Address::generate(&env));
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&trader, &gold), 1_000);
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&bidder, &usdc), 5_000);
+    });
+    client.set_collateral_haircut(&admin, &gold, &Some(2_000));
+    client.pledge_collateral(&trader, &usdc, &gold, &1_000);
+
+    // Collateral worth 1,600 after the haircut backs at most 1,600 of margin
+    let over = client.try_lock_on_margin(&trader, &usdc, &1_700);
+    assert_eq!(over, Err(Ok(SettlementError::InsufficientCollateral)));
+    assert_eq!(client.lock_on_margin(&trader, &usdc, &1_400), 1_400);
+    let uncovered = client.try_release_collateral(&trader, &usdc, &gold, &200);
+    assert_eq!(uncovered, Err(Ok(SettlementError::InsufficientCollateral)));
+
+    assert_eq!(client.try_is_liquidatable(&trader, &usdc), Err(Ok(SettlementError::LiquidationDisabled)));
+    let config = LiquidationConfig { maintenance_bps: 11_000, max_slippage_bps: 1_000, duration: 100, incentive_bps: 500 };
+    let bad = LiquidationConfig { maintenance_bps: 9_000, ..config.clone() };
+    assert_eq!(
+        client.try_set_liquidation_config(&admin, &Some(bad)),
+        Err(Ok(SettlementError::InvalidLiquidationConfig))
+    );
+    client.set_liquidation_config(&admin, &Some(config));
+    assert!(!client.is_liquidatable(&trader, &usdc));
+
+    let unregistered = client.try_start_liquidation(&keeper, &trader, &usdc);
+    assert_eq!(unregistered, Err(Ok(SettlementError::KeeperNotRegistered)));
+    let stake_ref = BytesN::from_array(&env, &[60u8; 32]);
+    registry.register_service(&admin, &keeper, &registry_wasm::ServiceRole::Keeper, &stake_ref);
+    let healthy = client.try_start_liquidation(&keeper, &trader, &usdc);
+    assert_eq!(healthy, Err(Ok(SettlementError::PositionHealthy)));
+
+    // Gold falls to 1.5: the collateral is now worth 1,200 against 1,540 required
+    MockFxOracleClient::new(&env, &oracle).set_rate(&15_000_000);
+    assert!(client.is_liquidatable(&trader, &usdc));
+    client.start_liquidation(&keeper, &trader, &usdc);
+    let frozen = client.try_release_collateral(&trader, &usdc, &gold, &1);
+    assert_eq!(frozen, Err(Ok(SettlementError::LiquidationInProgress)));
+
+    // Halfway through the auction the price is 5% under the oracle
+    env.ledger().set_timestamp(1_050);
+    let capped = client.try_bid_liquidation(&bidder, &trader, &usdc, &gold, &400, &500);
+    assert_eq!(capped, Err(Ok(SettlementError::PaymentAboveMaximum)));
+    assert_eq!(client.bid_liquidation(&bidder, &trader, &usdc, &gold, &400, &600), 570);
+    assert_eq!(client.get_margin_lock(&trader, &usdc), 858);
+    assert!(client.get_liquidation(&trader, &usdc).is_some());
+
+    // The discount stops at the maximum slippage
+    env.ledger().set_timestamp(5_000);
+    assert_eq!(client.bid_liquidation(&bidder, &trader, &usdc, &gold, &1_000, &1_000), 810);
+    let closed = LiquidationClosed { trader: trader.clone(), payment_asset: usdc.clone(), proceeds: 1_380, remaining_margin: 88 };
+    assert!(env.events().all().filter_by_contract(&contract_id).events().contains(&closed.to_xdr(&env, &contract_id)));
+    assert!(client.get_liquidation(&trader, &usdc).is_none());
+
+    assert_eq!(client.get_escrow_balance(&trader, &usdc), 1_312);
+    assert_eq!(client.get_locked_balance(&trader, &usdc), 1_312);
+    assert_eq!(client.get_escrow_balance(&keeper, &usdc), 68);
+    assert_eq!(client.get_escrow_balance(&bidder, &gold), 1_000);
+    assert_eq!(client.get_escrow_balance(&bidder, &usdc), 3_620);
+    assert_eq!(client.get_escrow_balance(&trader, &gold), 0);
+}
+
+#[cfg(feature = "margin")]
+#[test]
+fn test_liquidation_values_only_collateral_still_locked() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(1_000);
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let registry = registry_wasm::Client::new(&env, &client.get_registry());
+
+    let oracle = env.register(MockFxOracle, (2 * FX_RATE_SCALE,));
+    client.set_fx_oracle(&admin, &oracle);
+    let (trader, keeper, bidder) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let (usdc, gold) = (Address::generate(&env), Address::generate(&env));
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&trader, &gold), 1_000);
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&bidder, &usdc), 5_000);
+    });
+    client.set_collateral_haircut(&admin, &gold, &Some(2_000));
+    client.pledge_collateral(&trader, &usdc, &gold, &1_000);
+    client.lock_on_margin(&trader, &usdc, &1_400);
+    let config = LiquidationConfig { maintenance_bps: 11_000, max_slippage_bps: 1_000, duration: 100, incentive_bps: 500 };
+    client.set_liquidation_config(&admin, &Some(config));
+    let stake_ref = BytesN::from_array(&env, &[61u8; 32]);
+    registry.register_service(&admin, &keeper, &registry_wasm::ServiceRole::Keeper, &stake_ref);
+    assert!(!client.is_liquidatable(&trader, &usdc));
+
+    // A settlement spends half of the locked gold the pledge still records
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::commit_debit(&env, &EscrowKey::main(&trader, &gold), 500);
+    });
+    assert_eq!(client.get_pledged_collateral(&trader, &usdc).get(gold.clone()), Some(1_000));
+    assert_eq!(client.get_collateral_value(&trader, &usdc), 800);
+    assert!(client.is_liquidatable(&trader, &usdc));
+    client.start_liquidation(&keeper, &trader, &usdc);
+
+    // Bids are capped at the gold still held, which closes the auction
+    assert_eq!(client.bid_liquidation(&bidder, &trader, &usdc, &gold, &1_000, &2_000), 1_000);
+    assert!(client.get_liquidation(&trader, &usdc).is_none());
+    assert!(client.get_pledged_collateral(&trader, &usdc).is_empty());
+    assert_eq!(client.get_pledged_units(&trader, &gold), 0);
+    assert_eq!(client.get_escrow_balance(&bidder, &gold), 500);
+    assert_eq!(client.get_margin_lock(&trader, &usdc), 450);
+}
+
+#[cfg(feature = "fees")]
+#[test]
+fn test_settle_trade_v2_options() {
+    use darkpool_testdata::{generate, scalar};
+    use soroban_sdk::{testutils::Events, Event};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64| {
+        generate(42, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    let memo = BytesN::from_array(&env, &[7u8; 32]);
+    let memo_account = client.get_memo_sub_account(&memo);
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [
+            EscrowKey::main(&seller, &asset),
+            EscrowKey::main(&buyer, &usdc),
+            EscrowKey::new(&buyer, &memo_account, &usdc),
+        ] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 50_000);
+        }
+    });
+
+    // The old entry point still settles, and flags itself as deprecated
+    let proof = fixture(1);
+    client.settle_trade(
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &usdc,
+        &100,
+        &5_000,
+        &Bytes::from_slice(&env, &proof.proof),
+        &Bytes::from_slice(&env, &proof.signals),
+    );
+    let deprecated = EntryPointDeprecated {
+        entry_point: Symbol::new(&env, "settle_trade"),
+        replacement: Symbol::new(&env, "settle_trade_v2"),
+    };
+    assert!(env.events().all().filter_by_contract(&contract_id).events().contains(&deprecated.to_xdr(&env, &contract_id)));
+
+    client.set_fee_schedule(&admin, &FeeSchedule { buyer_fee_bps: 30, seller_fee_bps: 20, recipient: admin.clone() });
+    let proof = fixture(2);
+    let request = SettleTradeRequest {
+        match_id: BytesN::from_array(&env, &[2u8; 32]),
+        buyer: buyer.clone(),
+        seller: seller.clone(),
+        asset_address: asset.clone(),
+        payment_asset: usdc.clone(),
+        quantity: 100,
+        price: 5_000,
+        buy_commitment: Some(BytesN::from_array(&env, &scalar(11))),
+        sell_commitment: Some(BytesN::from_array(&env, &scalar(99))),
+        max_fee_bps: Some(30),
+        buyer_memo: Some(memo.clone()),
+        seller_memo: None,
+        auth_mode: SettlementAuthMode::BothParties,
+    };
+    let settle = |request: &SettleTradeRequest| {
+        client.try_settle_trade_v2(
+            request,
+            &Bytes::from_slice(&env, &proof.proof),
+            &Bytes::from_slice(&env, &proof.signals),
+        )
+    };
+    assert_eq!(settle(&request).err(), Some(Ok(SettlementError::CommitmentMismatch)));
+    let request = SettleTradeRequest { sell_commitment: Some(BytesN::from_array(&env, &scalar(12))), ..request };
+    let capped = SettleTradeRequest { max_fee_bps: Some(25), ..request.clone() };
+    assert_eq!(settle(&capped).err(), Some(Ok(SettlementError::FeeAboveMaximum)));
+
+    // Routing to a memo sub-account needs the buyer even without BothParties
+    let proof_only = SettleTradeRequest { auth_mode: SettlementAuthMode::ProofOnly, ..request.clone() };
+    env.set_auths(&[]);
+    assert!(matches!(settle(&proof_only), Err(Err(_))));
+    env.mock_all_auths();
+
+    assert!(settle(&request).is_ok());
+    let auths = env.auths();
+    assert!([&buyer, &seller].iter().all(|party| auths.iter().any(|(address, _)| address == *party)));
+
+    // The buyer's side settled out of and into the memo sub-account
+    assert_eq!(client.get_subaccount_balance(&buyer, &memo_account, &usdc), 100_000 - 5_000 - 15);
+    assert_eq!(client.get_subaccount_balance(&buyer, &memo_account, &asset), 100);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 100);
+}
+
+#[cfg(all(feature = "ops", feature = "proofs"))]
+#[test]
+fn test_revoked_vk_blocks_its_proof_type() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = |seed: u64, nullifier: u64| {
+        generate(seed, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(42, 0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+    let settle = |seed: u64, nullifier: u64| {
+        let proof = fixture(seed, nullifier);
+        client
+            .try_settle_trade(
+                &BytesN::from_array(&env, &[nullifier as u8; 32]),
+                &buyer,
+                &seller,
+                &asset,
+                &usdc,
+                &100,
+                &5_000,
+                &Bytes::from_slice(&env, &proof.proof),
+                &Bytes::from_slice(&env, &proof.signals),
+            )
+            .map(|_| ())
+    };
+
+    let guardian = Address::generate(&env);
+    client.set_guardians(&admin, &GuardianSet { guardians: vec![&env, guardian.clone()], threshold: 1, recovery_period: 0 });
+    let stranger = client.try_revoke_vk(&Address::generate(&env), &SETTLEMENT_PROOF);
+    assert_eq!(stranger, Err(Ok(SettlementError::OnlyAdmin)));
+    assert_eq!(client.try_revoke_vk(&admin, &BASKET_PROOF), Err(Ok(SettlementError::VkNotSet)));
+
+    // A guardian can pull the key; only the admin can put it back
+    client.revoke_vk(&guardian, &SETTLEMENT_PROOF);
+    assert_eq!(client.get_revoked_vk(&SETTLEMENT_PROOF), Some(env.crypto().sha256(&vk_bytes).into()));
+    assert_eq!(settle(42, 1), Err(Ok(SettlementError::VkRevoked)));
+    assert_eq!(client.try_restore_vk(&guardian, &SETTLEMENT_PROOF), Err(Ok(SettlementError::OnlyAdmin)));
+    client.restore_vk(&admin, &SETTLEMENT_PROOF);
+    assert_eq!(client.try_restore_vk(&admin, &SETTLEMENT_PROOF), Err(Ok(SettlementError::VkNotRevoked)));
+    assert_eq!(settle(42, 1), Ok(()));
+
+    // Installing a replacement key lifts the block for new proofs only
+    client.revoke_vk(&admin, &SETTLEMENT_PROOF);
+    client.upgrade_commitment_scheme(&admin, &2, &Bytes::from_slice(&env, &fixture(43, 0).vk));
+    assert_eq!(settle(43, 2), Ok(()));
+    assert_eq!(settle(42, 3), Err(Ok(SettlementError::VkRevoked)));
+}
+
+#[cfg(feature = "relayers")]
+#[test]
+fn test_in_flight_marker_stops_racing_relayers() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_sequence_number(100);
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64| {
+        generate(42, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+    let match_id = BytesN::from_array(&env, &[1u8; 32]);
+    let settle = |relayer: &Address, nullifier: u64| {
+        let proof = fixture(nullifier);
+        client
+            .try_settle_trade_relayed(
+                relayer,
+                &match_id,
+                &buyer,
+                &seller,
+                &asset,
+                &usdc,
+                &100,
+                &5_000,
+                &Bytes::from_slice(&env, &proof.proof),
+                &Bytes::from_slice(&env, &proof.signals),
+            )
+            .map(|_| ())
+    };
+
+    // A failed attempt leaves no marker behind
+    let (first, second) = (Address::generate(&env), Address::generate(&env));
+    let bogus = client.try_settle_trade_relayed(
+        &second,
+        &match_id,
+        &buyer,
+        &seller,
+        &asset,
+        &usdc,
+        &100,
+        &5_000,
+        &Bytes::new(&env),
+        &Bytes::new(&env),
+    );
+    assert!(bogus.is_err());
+    assert_eq!(client.get_in_flight(&match_id), None);
+
+    assert_eq!(settle(&first, 1), Ok(()));
+    assert_eq!(client.get_in_flight(&match_id), Some(InFlightSettlement { relayer: Some(first.clone()), ledger: 100 }));
+    assert_eq!(settle(&second, 2), Err(Ok(SettlementError::SettlementInFlight)));
+
+    // Once the marker expires the sequence check still refuses the match
+    env.ledger().set_sequence_number(120);
+    assert_eq!(client.get_in_flight(&match_id), None);
+    assert_eq!(settle(&second, 2), Err(Ok(SettlementError::AlreadySettled)));
+}
+
+#[cfg(all(feature = "fees", feature = "ops", feature = "relayers"))]
+#[test]
+fn test_operator_snapshot_bundles_dashboard_state() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().set_timestamp(VOLUME_EPOCH_SECONDS + 100);
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64| {
+        generate(42, &[scalar(nullifier), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)])
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000_000);
+        }
+    });
+    let relayer = Address::generate(&env);
+    let settle = |id: u8, nullifier: u64| {
+        let proof = fixture(nullifier);
+        client.settle_trade_relayed(
+            &relayer,
+            &BytesN::from_array(&env, &[id; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &proof.proof),
+            &Bytes::from_slice(&env, &proof.signals),
+        )
+    };
+
+    let empty = client.get_operator_snapshot(&0, &10);
+    assert_eq!(empty.epoch, 0);
+    assert!(empty.epoch_volume.is_empty());
+    assert_eq!(client.get_relayer_settlements(&relayer), 0);
+
+    // Two settlements in epoch 1, one in epoch 2
+    let record = settle(1, 1);
+    settle(2, 2);
+    env.ledger().set_timestamp(2 * VOLUME_EPOCH_SECONDS + 100);
+    settle(3, 3);
+    client.pause_asset(&admin, &asset);
+
+    let snapshot = client.get_operator_snapshot(&0, &10);
+    assert_eq!(snapshot.epoch, 1);
+    assert_eq!(snapshot.epoch_volume.get(usdc.clone()), Some(2 * record.payment_amount));
+    assert_eq!(client.get_epoch_volume(&2).get(usdc.clone()), Some(record.payment_amount));
+    assert_eq!(client.get_relayer_settlements(&relayer), 3);
+    assert_eq!(snapshot.paused_assets, vec![&env, asset.clone()]);
+    assert_eq!((snapshot.pending_disputes, snapshot.expired_locks, snapshot.next_start), (0, 0, None));
+    assert_eq!(snapshot.fees_collected.get(usdc.clone()).unwrap_or(0), client.get_fees_collected(&usdc));
+
+    // Only the previous epoch is kept once a new one starts
+    env.ledger().set_timestamp(3 * VOLUME_EPOCH_SECONDS);
+    client.unpause_asset(&admin, &asset);
+    settle(4, 4);
+    assert_eq!(client.get_operator_snapshot(&0, &10).epoch_volume.get(usdc.clone()), Some(record.payment_amount));
+    assert!(client.get_epoch_volume(&1).is_empty());
+}
+
+#[cfg(feature = "schedules")]
+#[test]
+fn test_iceberg_reveals_one_child_at_a_time() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    let fixture = |nullifier: u64, sell_commitment: u64| {
+        let signals =
+            [scalar(nullifier), scalar(11), scalar(sell_commitment), scalar(13), scalar(100), scalar(5_000), scalar(14)];
+        generate(42, &signals)
+    };
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture(0, 0).vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 100_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 100_000);
+        }
+    });
+
+    // Two children chained behind the parent; the total is never submitted
+    let link = |child: &BytesN<32>, next: &BytesN<32>| -> BytesN<32> {
+        let mut opening = Bytes::from_array(&env, &child.to_array());
+        opening.extend_from_array(&next.to_array());
+        env.crypto().sha256(&opening).into()
+    };
+    let end = BytesN::from_array(&env, &[0u8; 32]);
+    let (first, second) = (BytesN::from_array(&env, &scalar(12)), BytesN::from_array(&env, &scalar(22)));
+    let tail = link(&second, &end);
+    let parent = link(&first, &tail);
+    client.create_iceberg(&seller, &parent);
+
+    let settle = |nullifier: u64, sell_commitment: u64| {
+        let proof = fixture(nullifier, sell_commitment);
+        client
+            .try_settle_trade(
+                &BytesN::from_array(&env, &[nullifier as u8; 32]),
+                &buyer,
+                &seller,
+                &asset,
+                &usdc,
+                &100,
+                &5_000,
+                &Bytes::from_slice(&env, &proof.proof),
+                &Bytes::from_slice(&env, &proof.signals),
+            )
+            .map(|_| ())
+    };
+
+    let wrong = client.try_reveal_iceberg_slice(&seller, &parent, &second, &tail);
+    assert_eq!(wrong, Err(Ok(SettlementError::IcebergSliceMismatch)));
+    client.reveal_iceberg_slice(&seller, &parent, &first, &tail);
+    let early = client.try_reveal_iceberg_slice(&seller, &parent, &second, &end);
+    assert_eq!(early, Err(Ok(SettlementError::IcebergSlicePending)));
+
+    assert_eq!(settle(1, 12), Ok(()));
+    // The displayed child cannot fill again against another counterparty order
+    assert_eq!(settle(2, 12), Err(Ok(SettlementError::IcebergOverfilled)));
+
+    client.reveal_iceberg_slice(&seller, &parent, &second, &end);
+    assert_eq!(settle(3, 22), Ok(()));
+    let exhausted = client.try_reveal_iceberg_slice(&seller, &parent, &first, &end);
+    assert_eq!(exhausted, Err(Ok(SettlementError::IcebergExhausted)));
+
+    let order = client.get_iceberg(&parent).unwrap();
+    assert_eq!((order.filled, order.fills, order.displayed), (200, 2, None));
+}
+
+#[cfg(feature = "input-modes")]
+#[test]
+fn test_hashed_public_inputs() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    // The circuit proves only the hash; callers still submit every signal
+    let signals = |quantity: u64| {
+        let full = [scalar(1), scalar(11), scalar(12), scalar(13), scalar(quantity), scalar(5_000), scalar(14)];
+        generate(0, &full).signals
+    };
+    let hash = zk_bn254::hash_public_signals_bytes(&env, &Bytes::from_slice(&env, &signals(100))).unwrap();
+    let mut hash_signal = [0u8; 32];
+    hash.slice(4..36).copy_into_slice(&mut hash_signal);
+    let fixture = generate(42, &[hash_signal]);
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    assert_eq!(client.get_input_mode(&SETTLEMENT_PROOF), PublicInputMode::Full);
+    client.set_input_mode(&admin, &SETTLEMENT_PROOF, &PublicInputMode::Hashed);
+
+    let settle = |signals: &[u8]| {
+        client.try_settle_trade(
+            &BytesN::from_array(&env, &[1u8; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &fixture.proof),
+            &Bytes::from_slice(&env, signals),
+        )
+    };
+
+    // Any change to a submitted signal changes the hash the proof is bound to
+    assert_eq!(settle(&signals(101)).err(), Some(Ok(SettlementError::InvalidProof)));
+
+    settle(&signals(100)).unwrap().unwrap();
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 100);
+}
+
+#[cfg(feature = "debug-events")]
+#[test]
+fn test_debug_events_trace_settlement() {
+    use darkpool_testdata::{generate, scalar};
+    use soroban_sdk::{
+        testutils::Events,
+        xdr::{ContractEventBody, ScVal},
+    };
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let signals = [scalar(1), scalar(11), scalar(12), scalar(13), scalar(100), scalar(5_000), scalar(14)];
+    let fixture = generate(42, &signals);
+
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &fixture.vk);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let contract_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (asset, usdc) = (Address::generate(&env), Address::generate(&env));
+    client.add_payment_asset(&admin, &usdc);
+    env.as_contract(&contract_id, || {
+        for key in [EscrowKey::main(&seller, &asset), EscrowKey::main(&buyer, &usdc)] {
+            DarkPoolSettlement::credit_escrow(&env, &key, 10_000);
+            DarkPoolSettlement::credit_locked(&env, &key, 10_000);
+        }
+    });
+
+    client.settle_trade(
+        &BytesN::from_array(&env, &[1u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &usdc,
+        &100,
+        &5_000,
+        &Bytes::from_slice(&env, &fixture.proof),
+        &Bytes::from_slice(&env, &fixture.signals),
+    );
+
+    let events = env.events().all().filter_by_contract(&contract_id);
+    let count = |name: &str| {
+        events
+            .events()
+            .iter()
+            .filter(|event| {
+                let ContractEventBody::V0(body) = &event.body;
+                matches!(body.topics.first(), Some(ScVal::Symbol(topic)) if topic.to_utf8_string_lossy() == name)
+            })
+            .count()
+    };
+    assert_eq!(count("debug_signals"), 1);
+    assert_eq!(count("debug_verification"), 1);
+    // Both legs debit a locked and an escrow balance and credit an escrow balance
+    assert_eq!(count("debug_balance"), 6);
+}
+
+#[cfg(feature = "records")]
+#[test]
+fn test_compliance_evidence_recorded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let attested = BytesN::from_array(&env, &[21u8; 32]);
+    let unattested = BytesN::from_array(&env, &[22u8; 32]);
+    let root = BytesN::from_array(&env, &[7u8; 32]);
+    let attestation = ComplianceAttestation {
+        attestation_root: BytesN::from_array(&env, &[8u8; 32]),
+        jurisdiction_cleared: true,
+    };
+    client.submit_compliance_attestation(&admin, &attested, &attestation);
+    assert_eq!(client.get_compliance_evidence(&attested), None);
+
+    env.ledger().with_mut(|li| li.timestamp = 5_000);
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::record_compliance_evidence(&env, &attested, &root);
+        DarkPoolSettlement::record_compliance_evidence(&env, &unattested, &root);
+    });
+
+    let evidence = client.get_compliance_evidence(&attested).unwrap();
+    assert_eq!(evidence.whitelist_root, root);
+    assert_eq!(evidence.attestation_root, Some(attestation.attestation_root));
+    assert!(evidence.jurisdiction_cleared);
+    assert_eq!(evidence.recorded_at, 5_000);
+
+    // Without an attestation the proof's whitelist root is still kept
+    let evidence = client.get_compliance_evidence(&unattested).unwrap();
+    assert_eq!(evidence.whitelist_root, root);
+    assert_eq!(evidence.attestation_root, None);
+    assert!(!evidence.jurisdiction_cleared);
+}
+
+#[cfg(feature = "proofs")]
+#[test]
+fn test_chunked_vk_upload() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let basket = darkpool_testdata::generate(5, &[darkpool_testdata::scalar(1); 7]);
+    let vk = Bytes::from_slice(&env, &basket.vk);
+    let vk_hash: BytesN<32> = env.crypto().sha256(&vk).into();
+
+    let unknown = client.try_begin_vk_upload(&admin, &symbol_short!("other"), &vk_hash, &vk.len());
+    assert_eq!(unknown, Err(Ok(SettlementError::UnknownProofType)));
+
+    client.begin_vk_upload(&admin, &BASKET_PROOF, &vk_hash, &vk.len());
+    assert_eq!(client.append_vk_chunk(&admin, &vk.slice(0..400)), 400);
+
+    let early = client.try_finalize_vk_upload(&admin);
+    assert_eq!(early, Err(Ok(SettlementError::VkUploadIncomplete)));
+
+    let oversized = client.try_append_vk_chunk(&admin, &vk);
+    assert_eq!(oversized, Err(Ok(SettlementError::VkUploadTooLarge)));
+
+    assert_eq!(client.append_vk_chunk(&admin, &vk.slice(400..)), vk.len());
+    client.finalize_vk_upload(&admin);
+    assert_eq!(client.get_vk(&BASKET_PROOF), Some(vk.clone()));
+    assert!(client.get_vk_upload().is_none());
+
+    // A key that does not match its declared hash is never installed
+    let tampered = Bytes::from_slice(&env, &[6u8; 1_000]);
+    client.begin_vk_upload(&admin, &SETTLEMENT_PROOF, &vk_hash, &tampered.len());
+    client.append_vk_chunk(&admin, &tampered);
+    let mismatch = client.try_finalize_vk_upload(&admin);
+    assert_eq!(mismatch, Err(Ok(SettlementError::VkHashMismatch)));
+    assert_ne!(client.get_vk(&SETTLEMENT_PROOF), Some(tampered));
+}
+
+#[cfg(all(feature = "baskets", feature = "cancellation", feature = "proofs"))]
+#[test]
+fn test_vk_guard_rejects_malformed_keys() {
+    use darkpool_testdata::{generate, off_subgroup_g2, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let settle_vk = generate(42, &[scalar(1); 7]).vk;
+    let cancel_vk = generate(43, &[scalar(1); 4]).vk;
+
+    let garbage = Bytes::from_slice(&env, &[7u8; 300]);
+    assert_eq!(client.try_set_cancel_vk(&admin, &garbage), Err(Ok(SettlementError::MalformedVerificationKey)));
+
+    // A settlement-shaped key cannot become the cancellation key
+    let wrong_shape = client.try_set_cancel_vk(&admin, &Bytes::from_slice(&env, &settle_vk));
+    assert_eq!(wrong_shape, Err(Ok(SettlementError::VkSignalCountMismatch)));
+    let odd_basket = client.try_set_basket_vk(&admin, &Bytes::from_slice(&env, &generate(44, &[scalar(1); 5]).vk));
+    assert_eq!(odd_basket, Err(Ok(SettlementError::VkSignalCountMismatch)));
+
+    // An identity delta would accept forged proofs
+    let (delta_start, delta_end) = (64 + 2 * 128, 64 + 3 * 128);
+    let mut identity_delta = cancel_vk.clone();
+    identity_delta[delta_start..delta_end].fill(0);
+    let identity = client.try_set_cancel_vk(&admin, &Bytes::from_slice(&env, &identity_delta));
+    assert_eq!(identity, Err(Ok(SettlementError::MalformedVerificationKey)));
+
+    // On the twist but outside the subgroup, caught by the host
+    let mut off_subgroup = cancel_vk.clone();
+    off_subgroup[delta_start..delta_end].copy_from_slice(&off_subgroup_g2());
+    assert!(client.try_set_cancel_vk(&admin, &Bytes::from_slice(&env, &off_subgroup)).is_err());
+    assert_eq!(client.get_vk(&CANCEL_PROOF), None);
+
+    client.set_cancel_vk(&admin, &Bytes::from_slice(&env, &cancel_vk));
+    assert_eq!(client.get_vk(&CANCEL_PROOF), Some(Bytes::from_slice(&env, &cancel_vk)));
+
+    // Scheme upgrades validate the incoming settlement key too
+    let upgrade = client.try_upgrade_commitment_scheme(&admin, &2, &Bytes::from_slice(&env, &cancel_vk));
+    assert_eq!(upgrade, Err(Ok(SettlementError::VkSignalCountMismatch)));
+    client.upgrade_commitment_scheme(&admin, &2, &Bytes::from_slice(&env, &settle_vk));
+}
+
+#[test]
+fn test_unlock_and_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(issuer).address();
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&trader, &1_000);
+
+    client.deposit(&trader, &asset, &1_000);
+    client.lock_escrow(&trader, &asset, &800);
+
+    let remaining = client.unlock_and_withdraw(&trader, &asset, &500);
+    assert_eq!(remaining, 500);
+    assert_eq!(client.get_locked_balance(&trader, &asset), 300);
+    assert_eq!(token::TokenClient::new(&env, &asset).balance(&trader), 500);
+
+    // Cannot release more than is locked
+    let result = client.try_unlock_and_withdraw(&trader, &asset, &400);
+    assert_eq!(result, Err(Ok(SettlementError::InsufficientLockedFunds)));
+}
+
+#[cfg(feature = "fees")]
+#[test]
+fn test_quote_and_fees_with_rebate() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let payment_asset = Address::generate(&env);
+
+    // No schedule: quote is fee-free
+    let quote = client.quote_settlement(&10, &10_000, &asset, &payment_asset, &buyer, &seller);
+    assert_eq!(quote.buyer_pays, 10_000);
+    assert_eq!(quote.seller_receives, 10_000);
+
+    let schedule = FeeSchedule {
+        buyer_fee_bps: 30,
+        seller_fee_bps: 20,
+        recipient: treasury.clone(),
+    };
+    client.set_fee_schedule(&admin, &schedule);
+    client.set_fee_tier(&admin, &seller, &5);
+
+    let quote = client.quote_settlement(&10, &10_000, &asset, &payment_asset, &buyer, &seller);
+    assert_eq!(quote.buyer_fee, 30);
+    assert_eq!(quote.seller_fee, 15);
+    assert_eq!(quote.buyer_pays, 10_030);
+    assert_eq!(quote.seller_receives, 9_985);
+
+    env.as_contract(&contract_id, || {
+        let buyer_key = EscrowKey::main(&buyer, &payment_asset);
+        let seller_key = EscrowKey::main(&seller, &payment_asset);
+        DarkPoolSettlement::credit_escrow(&env, &buyer_key, 10_030);
+        DarkPoolSettlement::credit_locked(&env, &buyer_key, 10_000);
+
+        let match_id = BytesN::from_array(&env, &[30u8; 32]);
+        let fees = DarkPoolSettlement::check_payment(&env, &buyer_key, &seller_key, 10_000).unwrap();
+        DarkPoolSettlement::commit_payment(&env, &match_id, &buyer_key, &seller_key, 10_000, fees);
+    });
+
+    // Settlement moves exactly what the quote promised
+    assert_eq!(client.get_escrow_balance(&buyer, &payment_asset), 0);
+    assert_eq!(client.get_escrow_balance(&seller, &payment_asset), quote.seller_receives);
+    assert_eq!(client.get_escrow_balance(&treasury, &payment_asset), 45);
+
+    let invalid = FeeSchedule {
+        buyer_fee_bps: 10_001,
+        seller_fee_bps: 0,
+        recipient: treasury,
+    };
+    assert_eq!(client.try_set_fee_schedule(&admin, &invalid), Err(Ok(SettlementError::InvalidFee)));
+}
+
+#[cfg(feature = "fees")]
+#[test]
+fn test_fees_paid_in_protocol_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let payment_asset = Address::generate(&env);
+    let dusk = Address::generate(&env);
+
+    client.set_fee_schedule(&admin, &FeeSchedule {
+        buyer_fee_bps: 30,
+        seller_fee_bps: 20,
+        recipient: treasury.clone(),
+    });
+    // 1 payment unit = 2 DUSK, fees paid in DUSK are 20% off
+    let oracle = env.register(MockFxOracle, (20_000_000i128,));
+    client.set_fx_oracle(&admin, &oracle);
+    client.set_protocol_fee_token(&admin, &Some(ProtocolFeeToken {
+        token: dusk.clone(),
+        discount_bps: 2_000,
+    }));
+    client.set_fee_token_opt_in(&buyer, &true);
+    assert!(client.is_fee_token_opted_in(&buyer));
+    assert!(!client.is_fee_token_opted_in(&seller));
+
+    let quote = client.quote_settlement(&10, &10_000, &asset, &payment_asset, &buyer, &seller);
+    assert_eq!(quote.buyer_fee, 0);
+    assert_eq!(quote.buyer_token_fee, 48);
+    assert_eq!(quote.seller_fee, 20);
+    assert_eq!(quote.seller_token_fee, 0);
+    assert_eq!(quote.buyer_pays, 10_000);
+
+    env.as_contract(&contract_id, || {
+        let buyer_key = EscrowKey::main(&buyer, &payment_asset);
+        let seller_key = EscrowKey::main(&seller, &payment_asset);
+        DarkPoolSettlement::credit_escrow(&env, &buyer_key, 10_000);
+        DarkPoolSettlement::credit_locked(&env, &buyer_key, 10_000);
+
+        // Without DUSK in escrow the buyer cannot cover the fee
+        assert_eq!(
+            DarkPoolSettlement::check_payment(&env, &buyer_key, &seller_key, 10_000),
+            Err(SettlementError::InsufficientEscrow)
+        );
+
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&buyer, &dusk), 100);
+        let match_id = BytesN::from_array(&env, &[31u8; 32]);
+        let fees = DarkPoolSettlement::check_payment(&env, &buyer_key, &seller_key, 10_000).unwrap();
+        DarkPoolSettlement::commit_payment(&env, &match_id, &buyer_key, &seller_key, 10_000, fees);
+    });
+
+    assert_eq!(client.get_escrow_balance(&buyer, &payment_asset), 0);
+    assert_eq!(client.get_escrow_balance(&buyer, &dusk), 52);
+    assert_eq!(client.get_escrow_balance(&seller, &payment_asset), 9_980);
+    assert_eq!(client.get_escrow_balance(&treasury, &payment_asset), 20);
+    assert_eq!(client.get_escrow_balance(&treasury, &dusk), 48);
+    assert_eq!(client.get_fees_collected(&payment_asset), 20);
+    assert_eq!(client.get_fees_collected(&dusk), 48);
+
+    let invalid = ProtocolFeeToken { token: dusk, discount_bps: 10_001 };
+    assert_eq!(
+        client.try_set_protocol_fee_token(&admin, &Some(invalid)),
+        Err(Ok(SettlementError::InvalidFee))
+    );
+}
+
+#[cfg(all(feature = "delegation", feature = "fees"))]
+#[test]
+fn test_broker_absorbs_client_fees() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let broker = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let payment_asset = Address::generate(&env);
+
+    client.set_fee_schedule(&admin, &FeeSchedule {
+        buyer_fee_bps: 30,
+        seller_fee_bps: 20,
+        recipient: treasury.clone(),
+    });
+
+    // Only the client's own broker can take over its fees
+    assert_eq!(
+        client.try_set_fee_payer(&seller, &broker),
+        Err(Ok(SettlementError::BrokerNotAuthorized))
+    );
+    for party in [&buyer, &seller] {
+        client.set_broker(party, &broker, &true, &false);
+        client.set_fee_payer(party, &broker);
+    }
+    assert_eq!(client.get_fee_payer(&seller), Some(broker.clone()));
+
+    let quote = client.quote_settlement(&10, &10_000, &asset, &payment_asset, &buyer, &seller);
+    assert_eq!(quote.buyer_fee, 30);
+    assert_eq!(quote.seller_fee, 20);
+    assert_eq!(quote.buyer_pays, 10_000);
+    assert_eq!(quote.seller_receives, 10_000);
+
+    env.as_contract(&contract_id, || {
+        let buyer_key = EscrowKey::main(&buyer, &payment_asset);
+        let seller_key = EscrowKey::main(&seller, &payment_asset);
+        DarkPoolSettlement::credit_escrow(&env, &buyer_key, 10_000);
+        DarkPoolSettlement::credit_locked(&env, &buyer_key, 10_000);
+
+        // The broker must cover both clients' fees at once
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&broker, &payment_asset), 40);
+        assert_eq!(
+            DarkPoolSettlement::check_payment(&env, &buyer_key, &seller_key, 10_000),
+            Err(SettlementError::InsufficientEscrow)
+        );
+
+        DarkPoolSettlement::credit_escrow(&env, &EscrowKey::main(&broker, &payment_asset), 60);
+        let match_id = BytesN::from_array(&env, &[36u8; 32]);
+        let fees = DarkPoolSettlement::check_payment(&env, &buyer_key, &seller_key, 10_000).unwrap();
+        DarkPoolSettlement::commit_payment(&env, &match_id, &buyer_key, &seller_key, 10_000, fees);
+    });
+
+    assert_eq!(client.get_escrow_balance(&buyer, &payment_asset), 0);
+    assert_eq!(client.get_escrow_balance(&seller, &payment_asset), 10_000);
+    assert_eq!(client.get_escrow_balance(&broker, &payment_asset), 50);
+    assert_eq!(client.get_escrow_balance(&treasury, &payment_asset), 50);
+
+    // Revoking the delegation ends the arrangement
+    client.remove_broker(&seller);
+    assert_eq!(client.get_fee_payer(&seller), None);
+    client.remove_fee_payer(&buyer, &broker);
+    assert_eq!(client.get_fee_payer(&buyer), None);
+    let quote = client.quote_settlement(&10, &10_000, &asset, &payment_asset, &buyer, &seller);
+    assert_eq!(quote.buyer_pays, 10_030);
+    assert_eq!(quote.seller_receives, 9_980);
+}
+
+#[cfg(feature = "fees")]
+#[test]
+fn test_referral_fee_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let partner = Address::generate(&env);
+    let issuer = Address::generate(&env);
+    let payment_asset = env.register_stellar_asset_contract_v2(issuer).address();
+    StellarAssetClient::new(&env, &payment_asset).mint(&contract_id, &10_030);
+
+    client.set_fee_schedule(&admin, &FeeSchedule {
+        buyer_fee_bps: 30,
+        seller_fee_bps: 20,
+        recipient: treasury.clone(),
+    });
+    client.set_referral_share(&admin, &5_000);
+
+    assert_eq!(client.try_set_referrer(&buyer, &buyer), Err(Ok(SettlementError::InvalidReferrer)));
+    client.set_referrer(&buyer, &partner);
+    assert_eq!(client.get_referrer(&buyer), Some(partner.clone()));
+    assert_eq!(client.try_set_referrer(&buyer, &seller), Err(Ok(SettlementError::ReferrerAlreadySet)));
+
+    env.as_contract(&contract_id, || {
+        let buyer_key = EscrowKey::main(&buyer, &payment_asset);
+        let seller_key = EscrowKey::main(&seller, &payment_asset);
+        DarkPoolSettlement::credit_escrow(&env, &buyer_key, 10_030);
+        DarkPoolSettlement::credit_locked(&env, &buyer_key, 10_000);
+
+        let match_id = BytesN::from_array(&env, &[32u8; 32]);
+        let fees = DarkPoolSettlement::check_payment(&env, &buyer_key, &seller_key, 10_000).unwrap();
+        DarkPoolSettlement::commit_payment(&env, &match_id, &buyer_key, &seller_key, 10_000, fees);
+    });
+
+    // Half the referred buyer's fee goes to the partner; the seller was not referred
+    assert_eq!(client.get_referral_fees(&partner, &payment_asset), 15);
+    assert_eq!(client.get_escrow_balance(&treasury, &payment_asset), 35);
+    assert_eq!(client.get_fees_collected(&payment_asset), 50);
+
+    assert_eq!(client.claim_referral_fees(&partner, &payment_asset), 15);
+    assert_eq!(token::TokenClient::new(&env, &payment_asset).balance(&partner), 15);
+    assert_eq!(client.get_referral_fees(&partner, &payment_asset), 0);
+    assert_eq!(client.claim_referral_fees(&partner, &payment_asset), 0);
+}
+
+#[cfg(feature = "fees")]
+#[test]
+fn test_relayer_fee_share() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    let (treasury, partner, relayer) = (Address::generate(&env), Address::generate(&env), Address::generate(&env));
+    let asset = Address::generate(&env);
+    let payment_asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+    StellarAssetClient::new(&env, &payment_asset).mint(&contract_id, &10_030);
+
+    client.set_fee_schedule(&admin, &FeeSchedule {
+        buyer_fee_bps: 30,
+        seller_fee_bps: 20,
+        recipient: treasury.clone(),
+    });
+    client.set_referral_share(&admin, &5_000);
+    client.set_referrer(&buyer, &partner);
+    assert_eq!(client.try_set_relayer_share(&admin, &5_001), Err(Ok(SettlementError::InvalidFee)));
+    client.set_relayer_share(&admin, &2_500);
+    assert_eq!(client.get_relayer_share(), 2_500);
+    assert_eq!(client.try_set_referral_share(&admin, &7_501), Err(Ok(SettlementError::InvalidFee)));
+
+    env.as_contract(&contract_id, || {
+        let buyer_key = EscrowKey::main(&buyer, &payment_asset);
+        let seller_key = EscrowKey::main(&seller, &asset);
+        DarkPoolSettlement::credit_escrow(&env, &buyer_key, 10_030);
+        DarkPoolSettlement::credit_locked(&env, &buyer_key, 10_000);
+        DarkPoolSettlement::credit_escrow(&env, &seller_key, 10);
+        DarkPoolSettlement::credit_locked(&env, &seller_key, 10);
+
+        DarkPoolSettlement::settle_spot(
+            &env,
+            Some(&relayer),
+            &BytesN::from_array(&env, &[33u8; 32]),
+            &seller_key,
+            &EscrowKey::main(&buyer, &asset),
+            10,
+            &buyer_key,
+            &EscrowKey::main(&seller, &payment_asset),
+            10_000,
+        )
+        .unwrap();
+    });
+
+    // A quarter of each fee goes to the relayer, on top of the referral share
+    assert_eq!(client.get_relayer_fees(&relayer, &payment_asset), 7 + 5);
+    assert_eq!(client.get_referral_fees(&partner, &payment_asset), 15);
+    assert_eq!(client.get_escrow_balance(&treasury, &payment_asset), 50 - 12 - 15);
+    assert_eq!(client.get_fees_collected(&payment_asset), 50);
+
+    assert_eq!(client.claim_relayer_fees(&relayer, &payment_asset), 12);
+    assert_eq!(token::TokenClient::new(&env, &payment_asset).balance(&relayer), 12);
+    assert_eq!(client.claim_relayer_fees(&relayer, &payment_asset), 0);
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn test_migrate_v1_storage() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+
+    // A pool deployed from the first release, with its balances, nullifiers
+    // and records in instance storage
+    let vk = Bytes::from_slice(&env, &generate(7, &[scalar(1); 7]).vk);
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk));
+    let contract_id = env.register(settlement_v1_wasm::WASM, (&admin, &registry, &verifier, &vk));
+    let legacy = settlement_v1_wasm::Client::new(&env, &contract_id);
+
+    let asset = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+    let (buyer, seller) = (Address::generate(&env), Address::generate(&env));
+    StellarAssetClient::new(&env, &asset).mint(&seller, &1_000);
+    StellarAssetClient::new(&env, &usdc).mint(&buyer, &50_000);
+    legacy.deposit(&seller, &asset, &1_000);
+    legacy.deposit(&buyer, &usdc, &50_000);
+    legacy.lock_escrow(&seller, &asset, &600);
+    legacy.lock_escrow(&buyer, &usdc, &50_000);
+    let holders = [(); 10].map(|_| Address::generate(&env));
+    for holder in holders.iter() {
+        StellarAssetClient::new(&env, &asset).mint(holder, &10);
+        legacy.deposit(holder, &asset, &10);
+    }
+    for i in 0..3u8 {
+        let proof = generate(7, &[scalar(50 + i as u64), scalar(1), scalar(2), scalar(3), scalar(4), scalar(5), scalar(6)]);
+        legacy.settle_trade(
+            &BytesN::from_array(&env, &[60 + i; 32]),
+            &buyer,
+            &seller,
+            &asset,
+            &usdc,
+            &100,
+            &5_000,
+            &Bytes::from_slice(&env, &proof.proof),
+            &Bytes::from_slice(&env, &proof.signals),
+        );
+    }
+
+    // Upgrade in place. Re-registering runs the constructor, which a real
+    // code upgrade does not, so drop the version it stamps
+    env.register_at(&contract_id, DarkPoolSettlement, (&admin, &registry, &verifier, &vk));
+    env.as_contract(&contract_id, || env.storage().instance().remove(&STORAGE_VERSION_KEY));
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    assert_eq!(client.get_storage_version(), 1);
+    let proof = generate(7, &[scalar(53), scalar(1), scalar(2), scalar(3), scalar(4), scalar(5), scalar(6)]);
+    let blocked = client.try_settle_trade(
+        &BytesN::from_array(&env, &[63u8; 32]),
+        &buyer,
+        &seller,
+        &asset,
+        &usdc,
+        &100,
+        &5_000,
+        &Bytes::from_slice(&env, &proof.proof),
+        &Bytes::from_slice(&env, &proof.signals),
+    );
+    assert_eq!(blocked.err(), Some(Ok(SettlementError::StorageMigrationRequired)));
+
+    // A deposit landing before migration adds to the balance moved over
+    StellarAssetClient::new(&env, &asset).mint(&holders[9], &5);
+    client.deposit(&holders[9], &asset, &5);
+
+    let wrong = client.try_migrate(&admin, &2, &None);
+    assert_eq!(wrong, Err(Ok(SettlementError::MigrationVersionMismatch)));
+
+    // 3 nullifiers, 14 escrow and 2 locked balances, and 3 records take two
+    // batches; records need the payment asset the first release never stored
+    assert_eq!(client.migrate(&admin, &1, &None), 1);
+    assert_eq!(
+        client.try_migrate(&admin, &1, &None),
+        Err(Ok(SettlementError::LegacyPaymentAssetRequired))
+    );
+    assert_eq!(client.migrate(&admin, &1, &Some(usdc.clone())), STORAGE_VERSION);
+
+    for i in 0..3u8 {
+        assert!(client.is_nullifier_used(&BytesN::from_array(&env, &scalar(50 + i as u64))));
+    }
+    assert_eq!(client.get_escrow_balance(&seller, &asset), 700);
+    assert_eq!(client.get_locked_balance(&seller, &asset), 300);
+    assert_eq!(client.get_escrow_balance(&seller, &usdc), 15_000);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 300);
+    assert_eq!(client.get_locked_balance(&buyer, &usdc), 35_000);
+    assert_eq!(client.get_escrow_balance(&holders[9], &asset), 15);
+    assert_eq!(client.get_total_escrow(&asset), 1_105);
+
+    let record = client.get_settlement(&None, &BytesN::from_array(&env, &[61u8; 32])).unwrap();
+    assert_eq!((record.buyer, record.seller), (buyer.clone(), seller.clone()));
+    assert_eq!((record.quantity, record.price), (100, 5_000));
+    assert_eq!((record.payment_asset, record.payment_amount), (usdc.clone(), 5_000));
+    assert_eq!(record.nullifier, BytesN::from_array(&env, &scalar(51)));
+    assert_eq!(client.get_settlement_count(), 3);
+    assert_eq!(client.get_settlements_page(&None, &0, &3).get(0).unwrap().match_id, BytesN::from_array(&env, &[60u8; 32]));
+
+    // Migrated balances are live
+    client.withdraw(&seller, &usdc, &15_000);
+    assert_eq!(token::TokenClient::new(&env, &usdc).balance(&seller), 15_000);
+
+    // Migrations cannot be replayed
+    let replay = client.try_migrate(&admin, &1, &Some(usdc.clone()));
+    assert_eq!(replay, Err(Ok(SettlementError::MigrationVersionMismatch)));
+}
+
+#[cfg(feature = "relayers")]
+#[test]
+fn test_relayer_rate_limit() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let relayer = Address::generate(&env);
+    let other = Address::generate(&env);
+    assert_eq!(client.get_relayer_remaining(&relayer), None);
+
+    // Per-address quotas only hold while relayers can't mint fresh addresses
+    let limit = RelayerRateLimit {
+        max_settlements: 2,
+        window_ledgers: 100,
+    };
+    let unregistered = client.try_set_relayer_rate_limit(&admin, &Some(limit.clone()));
+    assert_eq!(unregistered, Err(Ok(SettlementError::RelayerRegistrationRequired)));
+    client.set_require_registered_relayers(&admin, &true);
+    client.set_relayer_rate_limit(&admin, &Some(limit));
+    let relaxed = client.try_set_require_registered_relayers(&admin, &false);
+    assert_eq!(relaxed, Err(Ok(SettlementError::RelayerRegistrationRequired)));
+
+    // Unattributed settlement is refused once limits apply
+    let unmetered = client.try_settle_trade(
+        &BytesN::from_array(&env, &[50u8; 32]),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &10,
+        &100,
+        &Bytes::new(&env),
+        &Bytes::new(&env),
+    );
+    assert_eq!(unmetered.err(), Some(Ok(SettlementError::RelayerRequired)));
+
+    env.as_contract(&contract_id, || {
+        assert!(DarkPoolSettlement::consume_relayer_quota(&env, &relayer).is_ok());
+        assert!(DarkPoolSettlement::consume_relayer_quota(&env, &relayer).is_ok());
+        assert_eq!(
+            DarkPoolSettlement::consume_relayer_quota(&env, &relayer),
+            Err(SettlementError::RateLimited)
+        );
+        // Quotas are tracked per relayer
+        assert!(DarkPoolSettlement::consume_relayer_quota(&env, &other).is_ok());
+    });
+    assert_eq!(client.get_relayer_remaining(&relayer), Some(0));
+    assert_eq!(client.get_relayer_remaining(&other), Some(1));
+
+    // The quota refills once the window has passed
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+    assert_eq!(client.get_relayer_remaining(&relayer), Some(2));
+
+    client.set_relayer_rate_limit(&admin, &None);
+    assert_eq!(client.get_relayer_rate_limit(), None);
+    client.set_require_registered_relayers(&admin, &false);
+}
+
+#[cfg(all(feature = "order-controls", feature = "records", feature = "relayers"))]
+#[test]
+fn test_service_roles_come_from_registry() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let registry = registry_wasm::Client::new(&env, &client.get_registry());
+
+    let relayer = Address::generate(&env);
+    let auditor = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[51u8; 32]);
+    let stake_ref = BytesN::from_array(&env, &[52u8; 32]);
+
+    // Unregistered relayers may operate until registration is required
+    client.propose_match(&relayer, &match_id, &Address::generate(&env));
+    client.set_require_registered_relayers(&admin, &true);
+    assert!(client.requires_registered_relayers());
+
+    let other_match = BytesN::from_array(&env, &[53u8; 32]);
+    let unregistered = client.try_propose_match(&relayer, &other_match, &Address::generate(&env));
+    assert_eq!(unregistered, Err(Ok(SettlementError::RelayerNotRegistered)));
+    let unattributed = client.try_settle_trade(
+        &other_match,
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &10,
+        &100,
+        &Bytes::new(&env),
+        &Bytes::new(&env),
+    );
+    assert_eq!(unattributed.err(), Some(Ok(SettlementError::RelayerRequired)));
+
+    registry.register_service(&admin, &relayer, &registry_wasm::ServiceRole::Relayer, &stake_ref);
+    client.propose_match(&relayer, &other_match, &Address::generate(&env));
+
+    // A registry auditor reads records without a local grant
+    assert!(!client.is_auditor(&auditor));
+    registry.register_service(&admin, &auditor, &registry_wasm::ServiceRole::Auditor, &stake_ref);
+    assert!(client.is_auditor(&auditor));
+    registry.revoke_service(&admin, &auditor, &registry_wasm::ServiceRole::Auditor);
+    assert!(!client.is_auditor(&auditor));
+}
+
+#[cfg(all(feature = "cancellation", feature = "fees", feature = "ops", feature = "relayers"))]
+#[test]
+fn test_state_digest_tracks_configuration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let asset = Address::generate(&env);
+
+    let baseline = client.state_digest();
+    client.heartbeat(&admin);
+    client.set_fee_tier(&admin, &Address::generate(&env), &5);
+    assert_eq!(client.state_digest(), baseline);
+
+    client.pause_asset(&admin, &asset);
+    let paused = client.state_digest();
+    assert_ne!(paused, baseline);
+    client.unpause_asset(&admin, &asset);
+    assert_eq!(client.state_digest(), baseline);
+
+    client.set_fee_schedule(&admin, &FeeSchedule { buyer_fee_bps: 10, seller_fee_bps: 10, recipient: admin.clone() });
+    let with_fees = client.state_digest();
+    assert_ne!(with_fees, baseline);
+    client.set_require_registered_relayers(&admin, &true);
+    assert_ne!(client.state_digest(), with_fees);
+    client.set_require_registered_relayers(&admin, &false);
+    assert_eq!(client.state_digest(), with_fees);
+
+    client.set_cancel_vk(&admin, &Bytes::from_slice(&env, &darkpool_testdata::generate(7, &[[1u8; 32]; 4]).vk));
+    assert_ne!(client.state_digest(), with_fees);
+}
+
+#[test]
+fn test_error_description() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_error_map_version(), ERROR_MAP_VERSION);
+    assert_eq!(client.error_description(&4), Symbol::new(&env, "nullifier_used"));
+    assert_eq!(client.error_description(&(SettlementError::VkNotSet as u32)), Symbol::new(&env, "vk_not_set"));
+    assert_eq!(client.error_description(&0), Symbol::new(&env, "unknown"));
+    assert_eq!(client.error_description(&10_000), Symbol::new(&env, "unknown"));
+
+    // Codes are contiguous, so every one up to the newest has a name
+    let newest = SettlementError::FeatureNotBuilt as u32;
+    for code in 1..=newest {
+        assert_ne!(client.error_description(&code), Symbol::new(&env, "unknown"));
+    }
+    assert_eq!(client.error_description(&(newest + 1)), Symbol::new(&env, "unknown"));
+}
+
+#[cfg(feature = "verification-routes")]
+#[test]
+fn test_verification_stats() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    assert_eq!(client.get_verification_stats().total, 0);
+
+    client.set_verification_route(&admin, &SETTLEMENT_PROOF, &VerificationRoute::Native);
+    env.as_contract(&contract_id, || {
+        let junk = Bytes::from_slice(&env, &[0u8; 16]);
+        let _ = DarkPoolSettlement::verify_proof(&env, &SETTLEMENT_PROOF, &junk, &junk, &junk);
+        let _ = DarkPoolSettlement::verify_proof(&env, &SETTLEMENT_PROOF, &junk, &junk, &junk);
+        DarkPoolSettlement::record_verification(&env, &BASKET_PROOF, VerificationRoute::Contract);
+    });
+
+    let stats = client.get_verification_stats();
+    assert_eq!(stats.total, 3);
+    assert_eq!(stats.native_calls, 2);
+    assert_eq!(stats.contract_calls, 1);
+    assert_eq!(stats.by_proof_type.get(SETTLEMENT_PROOF), Some(2));
+    assert_eq!(stats.by_proof_type.get(BASKET_PROOF), Some(1));
+}
+
+#[cfg(all(feature = "corporate-actions", feature = "ops"))]
+#[test]
+fn test_record_clawback_restores_solvency() {
+    use soroban_sdk::testutils::IssuerFlags;
+
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let issuer = Address::generate(&env);
+    let sac = env.register_stellar_asset_contract_v2(issuer);
+    sac.issuer().set_flag(IssuerFlags::ClawbackEnabledFlag);
+    let asset = sac.address();
+    let asset_admin = StellarAssetClient::new(&env, &asset);
+    let trader = Address::generate(&env);
+    asset_admin.mint(&trader, &1_000);
+    client.deposit(&trader, &asset, &1_000);
+    client.lock_escrow(&trader, &asset, &900);
+
+    // The issuer claws back tokens straight out of the pool
+    asset_admin.clawback(&contract_id, &300);
+    let audit = client.audit_balances(&admin, &asset);
+    assert_eq!(audit.discrepancy, -300);
+
+    let remaining = client.record_clawback(&admin, &trader, &DEFAULT_SUB_ACCOUNT, &asset, &300);
+    assert_eq!(remaining, 700);
+    assert_eq!(client.get_locked_balance(&trader, &asset), 700);
+    assert_eq!(client.get_lots(&trader, &asset).get(0).unwrap().amount, 700);
+
+    let audit = client.audit_balances(&admin, &asset);
+    assert_eq!(audit.discrepancy, 0);
+
+    let excessive = client.try_record_clawback(&admin, &trader, &DEFAULT_SUB_ACCOUNT, &asset, &701);
+    assert_eq!(excessive.err(), Some(Ok(SettlementError::InsufficientBalance)));
+}
+
+#[cfg(feature = "order-controls")]
+#[test]
+fn test_counterparty_policy() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(DarkPoolSettlement::check_counterparties(&env, &buyer, &seller), Ok(()));
+    });
+
+    client.set_counterparty_policy(
+        &buyer,
+        &Some(CounterpartyPolicy {
+            mode: CounterpartyMode::Allowlist,
+            counterparties: vec![&env, other.clone()],
+        }),
+    );
+    client.set_counterparty_policy(
+        &other,
+        &Some(CounterpartyPolicy {
+            mode: CounterpartyMode::Blocklist,
+            counterparties: vec![&env, seller.clone()],
+        }),
+    );
+
+    env.as_contract(&contract_id, || {
+        // Buyer only trades with `other`
+        assert_eq!(
+            DarkPoolSettlement::check_counterparties(&env, &buyer, &seller),
+            Err(SettlementError::CounterpartyBlocked)
+        );
+        assert_eq!(DarkPoolSettlement::check_counterparties(&env, &buyer, &other), Ok(()));
+        // `other` blocks the seller regardless of side
+        assert_eq!(
+            DarkPoolSettlement::check_counterparties(&env, &seller, &other),
+            Err(SettlementError::CounterpartyBlocked)
+        );
+    });
+
+    client.set_counterparty_policy(&buyer, &None);
+    assert!(client.get_counterparty_policy(&buyer).is_none());
+    env.as_contract(&contract_id, || {
+        assert_eq!(DarkPoolSettlement::check_counterparties(&env, &buyer, &seller), Ok(()));
+    });
+}
+
+#[test]
+fn test_failed_payment_leg_leaves_no_partial_state() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let payment_asset = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[36u8; 32]);
+
+    env.as_contract(&contract_id, || {
+        let seller_asset = EscrowKey::main(&seller, &asset);
+        let buyer_asset = EscrowKey::main(&buyer, &asset);
+        let buyer_payment = EscrowKey::main(&buyer, &payment_asset);
+        let seller_payment = EscrowKey::main(&seller, &payment_asset);
+
+        // Asset leg is fully funded; the buyer locked less than the payment
+        DarkPoolSettlement::credit_escrow(&env, &seller_asset, 100);
+        DarkPoolSettlement::credit_locked(&env, &seller_asset, 100);
+        DarkPoolSettlement::credit_escrow(&env, &buyer_payment, 5_000);
+        DarkPoolSettlement::credit_locked(&env, &buyer_payment, 4_000);
+
+        // Storage writes made inside as_contract are not rolled back on error,
+        // so any write before the failing check would be visible below.
+        let result = DarkPoolSettlement::settle_spot(
+            &env,
+            None,
+            &match_id,
+            &seller_asset,
+            &buyer_asset,
+            100,
+            &buyer_payment,
+            &seller_payment,
+            5_000,
+        );
+        assert_eq!(result, Err(SettlementError::InsufficientLockedFunds));
+
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &ESCROW_KEY, &seller_asset), 100);
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &LOCKED_KEY, &seller_asset), 100);
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &ESCROW_KEY, &buyer_asset), 0);
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &ESCROW_KEY, &buyer_payment), 5_000);
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &LOCKED_KEY, &buyer_payment), 4_000);
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &ESCROW_KEY, &seller_payment), 0);
+
+        // Once the lock covers the payment both legs move together
+        DarkPoolSettlement::credit_locked(&env, &buyer_payment, 1_000);
+        DarkPoolSettlement::settle_spot(
+            &env,
+            None,
+            &match_id,
+            &seller_asset,
+            &buyer_asset,
+            100,
+            &buyer_payment,
+            &seller_payment,
+            5_000,
+        )
+        .unwrap();
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &ESCROW_KEY, &buyer_asset), 100);
+        assert_eq!(DarkPoolSettlement::read_balance(&env, &ESCROW_KEY, &seller_payment), 5_000);
+    });
+}
+
+/// Token whose transfer calls back into the settlement contract
+#[contract]
+struct ReentrantToken;
+
+#[contractimpl]
+impl ReentrantToken {
+    pub fn transfer(env: Env, from: Address, _to: Address, amount: i128) {
+        let settlement: Address = env.storage().instance().get(&symbol_short!("target")).unwrap();
+        DarkPoolSettlementClient::new(&env, &settlement).withdraw(&from, &env.current_contract_address(), &amount);
+    }
+
+    pub fn set_target(env: Env, settlement: Address) {
+        env.storage().instance().set(&symbol_short!("target"), &settlement);
+    }
+}
+
+#[test]
+fn test_reentrant_token_cannot_observe_partial_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let token_id = env.register(ReentrantToken, ());
+    ReentrantTokenClient::new(&env, &token_id).set_target(&contract_id);
+
+    let trader = Address::generate(&env);
+    assert!(client.try_deposit(&trader, &token_id, &1_000).is_err());
+
+    // The re-entrant call aborts the whole deposit
+    assert_eq!(client.get_escrow_balance(&trader, &token_id), 0);
+    assert_eq!(client.get_locked_balance(&trader, &token_id), 0);
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn test_health_report() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    // The verifier is a placeholder, not a deployed contract
+    let report = client.health();
+    assert!(!report.verifier_reachable);
+    assert!(report.registry_reachable);
+    assert!(report.settlement_vk_set);
+    assert!(!report.basket_vk_set);
+    assert!(!report.migration_required);
+    assert_eq!(report.nullifier_count, 0);
+    assert_eq!(report.instance_ttl_remaining, None);
+
+    let asset = Address::generate(&env);
+    client.pause_asset(&admin, &asset);
+    client.extend_instance_ttl();
+    env.as_contract(&contract_id, || {
+        DarkPoolSettlement::mark_nullifier_used(&env, &BytesN::from_array(&env, &[37u8; 32]));
+    });
+    env.ledger().with_mut(|li| li.sequence_number += 100);
+
+    let report = client.health();
+    assert_eq!(report.paused_assets, vec![&env, asset]);
+    assert_eq!(report.nullifier_count, 1);
+    assert_eq!(report.instance_ttl_remaining, Some(INSTANCE_TTL_EXTEND_TO - 100));
+
+    // Live dependencies answer the probes
+    let verifier = env.register(verifier_wasm::WASM, ());
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 100]);
+    let registry = env.register(registry_wasm::WASM, (&admin, &verifier, &vk_bytes));
+    let live_id = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk_bytes));
+    let report = DarkPoolSettlementClient::new(&env, &live_id).health();
+    assert!(report.verifier_reachable);
+    assert!(report.registry_reachable);
+}
+
+#[test]
+fn test_payment_asset_whitelist() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let usdc = Address::generate(&env);
+    let attacker_token = Address::generate(&env);
+    client.add_payment_asset(&admin, &usdc);
+    assert!(client.is_payment_asset(&usdc));
+    assert!(!client.is_payment_asset(&attacker_token));
+
+    // Rejected before the proof is even parsed
+    let empty = Bytes::new(&env);
+    let result = client.try_settle_trade(
+        &BytesN::from_array(&env, &[38u8; 32]),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &Address::generate(&env),
+        &attacker_token,
+        &10,
+        &100,
+        &empty,
+        &empty,
+    );
+    assert_eq!(result.err(), Some(Ok(SettlementError::PaymentAssetNotAllowed)));
+
+    client.remove_payment_asset(&admin, &usdc);
+    assert!(!client.is_payment_asset(&usdc));
+}
+
+#[cfg(feature = "netting")]
+#[test]
+fn test_size_buckets_and_residual_netting() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let payment_asset = Address::generate(&env);
+
+    let buckets = SizeBuckets {
+        base_unit: 100,
+        netting_ledgers: 50,
+    };
+    client.set_size_buckets(&admin, &asset, &Some(buckets.clone()));
+    assert_eq!(client.get_size_buckets(&asset), Some(buckets.clone()));
+
+    env.as_contract(&contract_id, || {
+        assert!(DarkPoolSettlement::check_bucket(&buckets, 100).is_ok());
+        assert!(DarkPoolSettlement::check_bucket(&buckets, 800).is_ok());
+        assert!(DarkPoolSettlement::check_bucket(&buckets, 37).is_ok());
+        assert_eq!(DarkPoolSettlement::check_bucket(&buckets, 300), Err(SettlementError::QuantityNotBucketed));
+        assert_eq!(DarkPoolSettlement::check_bucket(&buckets, 150), Err(SettlementError::QuantityNotBucketed));
+    });
+
+    env.ledger().with_mut(|li| li.sequence_number = 10);
+    env.as_contract(&contract_id, || {
+        let seller_asset = EscrowKey::main(&seller, &asset);
+        let buyer_payment = EscrowKey::main(&buyer, &payment_asset);
+        DarkPoolSettlement::credit_escrow(&env, &seller_asset, 60);
+        DarkPoolSettlement::credit_locked(&env, &seller_asset, 60);
+        DarkPoolSettlement::credit_escrow(&env, &buyer_payment, 900);
+        DarkPoolSettlement::credit_locked(&env, &buyer_payment, 900);
+
+        // Two residual fills between the same accounts
+        for (i, (quantity, payment)) in [(40, 600), (20, 300)].into_iter().enumerate() {
+            DarkPoolSettlement::queue_residual(
+                &env,
+                None,
+                &BytesN::from_array(&env, &[40 + i as u8; 32]),
+                &seller_asset,
+                &EscrowKey::main(&buyer, &asset),
+                quantity,
+                &buyer_payment,
+                &EscrowKey::main(&seller, &payment_asset),
+                payment,
+            )
+            .unwrap();
+        }
+    });
+
+    // Both legs are held by the contract until the round runs
+    assert_eq!(client.get_residual_queue(&asset), ResidualQueue { head: 0, tail: 2, round_end: 0 });
+    assert_eq!(client.get_residual(&asset, &1).unwrap().seller_proceeds, 300);
+    assert_eq!(client.get_escrow_balance(&seller, &asset), 0);
+    assert_eq!(client.get_escrow_balance(&buyer, &payment_asset), 0);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 0);
+
+    assert_eq!(client.try_run_netting(&asset, &10).err(), Some(Ok(SettlementError::NettingNotDue)));
+    env.ledger().with_mut(|li| li.sequence_number = 50);
+    assert_eq!(client.run_netting(&asset, &1), 1);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 40);
+    assert_eq!(client.get_escrow_balance(&seller, &payment_asset), 600);
+
+    // The rest of a started round doesn't wait for the interval
+    assert_eq!(client.run_netting(&asset, &10), 1);
+    assert_eq!(client.get_escrow_balance(&buyer, &asset), 60);
+    assert_eq!(client.get_escrow_balance(&seller, &payment_asset), 900);
+    assert_eq!(client.get_residual_queue(&asset), ResidualQueue { head: 2, tail: 2, round_end: 2 });
+    assert_eq!(client.get_residual(&asset, &1), None);
+
+    // Next round is not due until the interval passes again
+    assert_eq!(client.try_run_netting(&asset, &10).err(), Some(Ok(SettlementError::NettingNotDue)));
+}
+
+#[cfg(feature = "ops")]
+#[test]
+fn test_guardian_admin_recovery() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let guardians = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let new_admin = Address::generate(&env);
+
+    // No guardians configured yet
+    let result = client.try_recover_admin(&guardians, &new_admin);
+    assert_eq!(result.err(), Some(Ok(SettlementError::RecoveryNotAvailable)));
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    client.set_guardians(
+        &admin,
+        &GuardianSet {
+            guardians: guardians.clone(),
+            threshold: 2,
+            recovery_period: 86_400,
+        },
+    );
+
+    // The admin keeps the role alive with heartbeats
+    env.ledger().with_mut(|li| li.timestamp = 80_000);
+    client.heartbeat(&admin);
+    env.ledger().with_mut(|li| li.timestamp = 90_000);
+    let result = client.try_recover_admin(&guardians, &new_admin);
+    assert_eq!(result.err(), Some(Ok(SettlementError::RecoveryNotAvailable)));
+
+    env.ledger().with_mut(|li| li.timestamp = 80_000 + 86_400);
+
+    // One guardian (even listed twice) plus an outsider is not a quorum
+    let outsider = Address::generate(&env);
+    let short = vec![&env, guardians.get(0).unwrap(), guardians.get(0).unwrap(), outsider];
+    let result = client.try_recover_admin(&short, &new_admin);
+    assert_eq!(result.err(), Some(Ok(SettlementError::GuardianQuorumNotMet)));
+
+    let quorum = vec![&env, guardians.get(1).unwrap(), guardians.get(2).unwrap()];
+    client.recover_admin(&quorum, &new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.try_heartbeat(&admin).err(), Some(Ok(SettlementError::OnlyAdmin)));
+    client.heartbeat(&new_admin);
+}
+
+#[test]
+fn test_public_signal_count_is_capped() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+
+    env.as_contract(&contract_id, || {
+        let at_cap = Bytes::from_slice(&env, &MAX_PUBLIC_SIGNALS.to_be_bytes());
+        // Within the cap, a short blob fails on its missing signals instead
+        let result = DarkPoolSettlement::parse_public_signals(&env, &at_cap);
+        assert_eq!(result, Err(SettlementError::InvalidProof));
+
+        let oversized = Bytes::from_slice(&env, &u32::MAX.to_be_bytes());
+        let result = DarkPoolSettlement::parse_public_signals(&env, &oversized);
+        assert_eq!(result, Err(SettlementError::TooManySignals));
+    });
+}
+
+/// Issuer hook that refuses transfers above a fixed size
+#[contract]
+struct CappedHook;
+
+#[contractimpl]
+impl CappedHook {
+    pub fn on_transfer(_env: Env, _token: Address, _from: Address, _to: Address, amount: i128) {
+        assert!(amount <= 500, "transfer exceeds issuer cap");
+    }
+}
+
+#[test]
+fn test_hooked_token_adapter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+    let registry = registry_wasm::Client::new(&env, &client.get_registry());
+
+    let issuer = Address::generate(&env);
+    let asset = env.register_stellar_asset_contract_v2(issuer).address();
+    let trader = Address::generate(&env);
+    StellarAssetClient::new(&env, &asset).mint(&trader, &1_000);
+    assert_eq!(registry.get_token_adapter(&asset), registry_wasm::TokenAdapter::Sep41);
+
+    let hook = env.register(CappedHook, ());
+    registry.set_token_adapter(&admin, &asset, &registry_wasm::TokenAdapter::Hooked(hook));
+
+    client.deposit(&trader, &asset, &400);
+    assert!(client.try_deposit(&trader, &asset, &600).is_err());
+    assert_eq!(client.get_escrow_balance(&trader, &asset), 400);
+
+    // Withdrawals go through the hook as well
+    client.withdraw(&trader, &asset, &400);
+    assert_eq!(client.get_escrow_balance(&trader, &asset), 0);
+}
+
+#[cfg(feature = "order-controls")]
+#[test]
+fn test_maker_last_look() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let relayer = Address::generate(&env);
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let match_id = BytesN::from_array(&env, &[43u8; 32]);
+
+    // Disabled by default
+    env.as_contract(&contract_id, || {
+        assert_eq!(DarkPoolSettlement::take_confirmed_match(&env, &match_id, &buyer, &seller), Ok(()));
+    });
+
+    client.set_last_look_window(&admin, &60);
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let proposal = client.propose_match(&relayer, &match_id, &seller);
+    assert_eq!(proposal.deadline, 1_060);
+    let result = client.try_propose_match(&relayer, &match_id, &seller);
+    assert_eq!(result.err(), Some(Ok(SettlementError::MatchAlreadyProposed)));
+
+    env.as_contract(&contract_id, || {
+        let result = DarkPoolSettlement::take_confirmed_match(&env, &match_id, &buyer, &seller);
+        assert_eq!(result, Err(SettlementError::MatchNotConfirmed));
+    });
+
+    // Only the named maker can confirm, and only before the deadline
+    let result = client.try_confirm_match(&buyer, &match_id);
+    assert_eq!(result.err(), Some(Ok(SettlementError::ProposalNotFound)));
+    client.confirm_match(&seller, &match_id);
+    assert!(client.get_match_proposal(&match_id).unwrap().confirmed);
+
+    env.as_contract(&contract_id, || {
+        assert_eq!(DarkPoolSettlement::take_confirmed_match(&env, &match_id, &buyer, &seller), Ok(()));
+    });
+    assert!(client.get_match_proposal(&match_id).is_none());
+
+    let late_id = BytesN::from_array(&env, &[44u8; 32]);
+    client.propose_match(&relayer, &late_id, &seller);
+    env.ledger().with_mut(|li| li.timestamp = 1_061);
+    let result = client.try_confirm_match(&seller, &late_id);
+    assert_eq!(result.err(), Some(Ok(SettlementError::ProposalExpired)));
+}
+
+#[cfg(feature = "cancellation")]
+#[test]
+fn test_cancel_with_proof_checks_signals() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let trader = Address::generate(&env);
+    let asset = Address::generate(&env);
+    let root = BytesN::from_array(&env, &[45u8; 32]);
+    let nullifier = BytesN::from_array(&env, &[46u8; 32]);
+    client.set_order_root(&admin, &root);
+
+    let encode = |signals: &[BytesN<32>]| {
+        let mut bytes = Bytes::from_slice(&env, &(signals.len() as u32).to_be_bytes());
+        for signal in signals {
+            bytes.append(&Bytes::from_slice(&env, &signal.to_array()));
+        }
+        bytes
+    };
+    let trader_hash = env.as_contract(&contract_id, || DarkPoolSettlement::address_field_hash(&env, &trader));
+    assert_eq!(trader_hash.to_array()[0], 0);
+    let proof = Bytes::new(&env);
+
+    // A proof generated for another trader is rejected
+    let other_hash = env.as_contract(&contract_id, || {
+        DarkPoolSettlement::address_field_hash(&env, &Address::generate(&env))
+    });
+    let signals = encode(&[nullifier.clone(), root.clone(), amount_signal(&env, 100), other_hash]);
+    let result = client.try_cancel_with_proof(&trader, &asset, &proof, &signals);
+    assert_eq!(result.err(), Some(Ok(SettlementError::InvalidProof)));
+
+    let stale = BytesN::from_array(&env, &[47u8; 32]);
+    let signals = encode(&[nullifier.clone(), stale, amount_signal(&env, 100), trader_hash.clone()]);
+    let result = client.try_cancel_with_proof(&trader, &asset, &proof, &signals);
+    assert_eq!(result.err(), Some(Ok(SettlementError::OrderRootMismatch)));
+
+    let signals = encode(&[nullifier.clone(), root, amount_signal(&env, 100), trader_hash]);
+    let result = client.try_cancel_with_proof(&trader, &asset, &proof, &signals);
+    assert_eq!(result.err(), Some(Ok(SettlementError::CancelVkNotSet)));
+
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&(CANCEL_NULLS_KEY, nullifier.clone()), &true);
+    });
+    assert!(client.is_cancel_nullifier_used(&nullifier));
+    let result = client.try_cancel_with_proof(&trader, &asset, &proof, &signals);
+    assert_eq!(result.err(), Some(Ok(SettlementError::NullifierUsed)));
+}
+
+#[cfg(feature = "bridge")]
+#[test]
+fn test_cross_pool_bridge() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let pool_a = register_settlement_with_admin(&env, &admin);
+    let pool_b = register_settlement_with_admin(&env, &admin);
+    let client_a = DarkPoolSettlementClient::new(&env, &pool_a);
+    let client_b = DarkPoolSettlementClient::new(&env, &pool_b);
+
+    let buyer = Address::generate(&env);
+    let seller = Address::generate(&env);
+    let bond = Address::generate(&env);
+    let usdc = Address::generate(&env);
+
+    env.as_contract(&pool_a, || {
+        let key = EscrowKey::main(&seller, &bond);
+        DarkPoolSettlement::credit_escrow(&env, &key, 100);
+        DarkPoolSettlement::credit_locked(&env, &key, 100);
+    });
+    env.as_contract(&pool_b, || {
+        let key = EscrowKey::main(&buyer, &usdc);
+        DarkPoolSettlement::credit_escrow(&env, &key, 5_000);
+        DarkPoolSettlement::credit_locked(&env, &key, 5_000);
+    });
+
+    env.ledger().with_mut(|li| li.timestamp = 1_000);
+    let preimage = Bytes::from_slice(&env, b"cross-pool secret");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+
+    // Seller delivers the bond in pool A, buyer pays in pool B
+    client_a.open_bridge_lock(&seller, &bond, &100, &buyer, &hashlock, &3_000);
+    client_b.open_bridge_lock(&buyer, &usdc, &5_000, &seller, &hashlock, &2_000);
+    assert_eq!(client_a.get_escrow_balance(&seller, &bond), 0);
+    assert_eq!(
+        client_a.try_open_bridge_lock(&seller, &bond, &1, &buyer, &hashlock, &3_000).err(),
+        Some(Ok(SettlementError::BridgeLockExists))
+    );
+
+    let wrong = Bytes::from_slice(&env, b"guess");
+    assert_eq!(
+        client_a.try_claim_bridge_lock(&hashlock, &wrong).err(),
+        Some(Ok(SettlementError::HashlockMismatch))
+    );
+    assert_eq!(client_b.try_refund_bridge_lock(&hashlock).err(), Some(Ok(SettlementError::BridgeLockActive)));
+
+    client_a.claim_cross_pool(&hashlock, &preimage, &pool_b);
+    assert_eq!(client_a.get_escrow_balance(&buyer, &bond), 100);
+    assert_eq!(client_b.get_escrow_balance(&seller, &usdc), 5_000);
+    assert_eq!(client_a.get_bridge_lock(&hashlock).unwrap().status, BridgeLockStatus::Claimed);
+    assert_eq!(
+        client_b.try_claim_bridge_lock(&hashlock, &preimage).err(),
+        Some(Ok(SettlementError::BridgeLockClosed))
+    );
+}
+
+#[cfg(all(feature = "bridge", feature = "ops"))]
+#[test]
+fn test_bridge_lock_refund_after_timelock() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = register_settlement(&env);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let sender = Address::generate(&env);
+    let asset = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let key = EscrowKey::main(&sender, &asset);
+        DarkPoolSettlement::credit_escrow(&env, &key, 150);
+        DarkPoolSettlement::credit_locked(&env, &key, 150);
+    });
+
+    let preimage = Bytes::from_slice(&env, b"never revealed");
+    let hashlock: BytesN<32> = env.crypto().sha256(&preimage).into();
+    client.open_bridge_lock(&sender, &asset, &100, &Address::generate(&env), &hashlock, &500);
+    let later = BytesN::from_array(&env, &[9u8; 32]);
+    client.open_bridge_lock(&sender, &asset, &50, &Address::generate(&env), &later, &900);
+    assert!(client.get_expired_locks(&10).is_empty());
+
+    env.ledger().with_mut(|li| li.timestamp = 500);
+    assert_eq!(client.get_expired_locks(&10), vec![&env, hashlock.clone()]);
+
+    // The operator snapshot counts expired locks a page at a time
+    let first = client.get_operator_snapshot(&0, &1);
+    assert_eq!((first.expired_locks, first.next_start), (1, Some(1)));
+    let second = client.get_operator_snapshot(&1, &1);
+    assert_eq!((second.expired_locks, second.next_start), (0, None));
+    assert_eq!(
+        client.try_claim_bridge_lock(&hashlock, &preimage).err(),
+        Some(Ok(SettlementError::BridgeLockExpired))
+    );
+    client.refund_bridge_lock(&hashlock);
+
+    // Refunded funds come back unlocked
+    assert_eq!(client.get_available_balance(&sender, &asset), 100);
+    assert_eq!(client.get_bridge_lock(&hashlock).unwrap().status, BridgeLockStatus::Refunded);
+    assert!(client.get_expired_locks(&10).is_empty());
+}
+
+#[cfg(feature = "bridge")]
+#[test]
+fn test_stale_lock_penalty_funds_insurance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = register_settlement_with_admin(&env, &admin);
+    let client = DarkPoolSettlementClient::new(&env, &contract_id);
+
+    let sender = Address::generate(&env);
+    let asset = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        let key = EscrowKey::main(&sender, &asset);
+        DarkPoolSettlement::credit_escrow(&env, &key, 1_000);
+        DarkPoolSettlement::credit_locked(&env, &key, 1_000);
+    });
+
+    assert_eq!(
+        client.try_set_stale_lock_penalty(&admin, &asset, &10_001),
+        Err(Ok(SettlementError::InvalidFee))
+    );
+    client.set_stale_lock_penalty(&admin, &asset, &250);
+    assert_eq!(client.get_stale_lock_penalty(&asset), 250);
+
+    let hashlock: BytesN<32> = env.crypto().sha256(&Bytes::from_slice(&env, b"ghost")).into();
+    client.open_bridge_lock(&sender, &asset, &1_000, &Address::generate(&env), &hashlock, &500);
+    env.ledger().with_mut(|li| li.timestamp = 500);
+
+    // A keeper force-expires the lock; 2.5% goes to the insurance fund
+    client.refund_bridge_lock(&hashlock);
+    assert_eq!(client.get_available_balance(&sender, &asset), 975);
+    assert_eq!(client.get_insurance_fund(&asset), 25);
+
+    client.set_stale_lock_penalty(&admin, &asset, &0);
+    assert_eq!(client.get_stale_lock_penalty(&asset), 0);
+}
+
+#[cfg(not(all(
+    feature = "margin",
+    feature = "order-controls",
+    feature = "records",
+    feature = "relayers",
+    feature = "withdrawal-queue"
+)))]
+#[test]
+fn test_settings_of_missing_features_are_refused() {
+    let env = Env::default();
+    let contract_id = register_settlement(&env);
+    let (trader, asset) = (Address::generate(&env), Address::generate(&env));
+
+    // Settings written by an earlier build that had the feature
+    env.as_contract(&contract_id, || {
+        #[cfg(not(feature = "relayers"))]
+        {
+            assert_eq!(DarkPoolSettlement::require_unmetered(&env), Ok(()));
+            env.storage().instance().set(&REGISTERED_RELAYERS_KEY, &true);
+            assert_eq!(DarkPoolSettlement::require_unmetered(&env), Err(SettlementError::FeatureNotBuilt));
+        }
 
-    // Give Alice some balance and lock it
-    DarkPoolSettlement::add_escrow_balance(&env, &alice, &asset, 1000);
-    DarkPoolSettlement::add_locked_balance(&env, &alice, &asset, 1000);
+        #[cfg(not(feature = "order-controls"))]
+        {
+            let counterparty = Address::generate(&env);
+            let match_id = BytesN::from_array(&env, &[1u8; 32]);
+            let key = EscrowKey::main(&trader, &asset);
+            assert_eq!(DarkPoolSettlement::check_counterparties(&env, &trader, &counterparty), Ok(()));
+            assert_eq!(DarkPoolSettlement::take_confirmed_match(&env, &match_id, &trader, &counterparty), Ok(()));
+            assert_eq!(DarkPoolSettlement::require_no_tick_size(&env, &asset), Ok(()));
+            assert_eq!(DarkPoolSettlement::check_notional(&env, &asset, 100), Ok(()));
+            assert_eq!(DarkPoolSettlement::check_inventory(&env, &key, 100), Ok(()));
 
-    // Transfer from Alice to Bob
-    let result = DarkPoolSettlement::transfer_from_escrow(&env, &alice, &bob, &asset, 500);
-    assert!(result.is_ok());
+            env.storage().persistent().set(&(COUNTERPARTY_KEY, counterparty.clone()), &true);
+            env.storage().instance().set(&LAST_LOOK_KEY, &10u32);
+            let capped: Map<Address, i128> = Map::from_array(&env, [(asset.clone(), 1)]);
+            env.storage().instance().set(&TICKS_KEY, &capped);
+            env.storage().instance().set(&MAX_NOTIONAL_KEY, &capped);
+            env.storage().persistent().set(&(INVENTORY_KEY, trader.clone(), asset.clone()), &1i128);
+            let refused = Err(SettlementError::FeatureNotBuilt);
+            assert_eq!(DarkPoolSettlement::check_counterparties(&env, &trader, &counterparty), refused);
+            assert_eq!(DarkPoolSettlement::take_confirmed_match(&env, &match_id, &trader, &counterparty), refused);
+            assert_eq!(DarkPoolSettlement::require_no_tick_size(&env, &asset), refused);
+            assert_eq!(DarkPoolSettlement::check_notional(&env, &asset, 100), refused);
+            assert_eq!(DarkPoolSettlement::check_inventory(&env, &key, 100), refused);
+        }
 
-    // Check balances
-    let alice_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), alice.clone(), asset.clone());
-    let bob_balance = DarkPoolSettlement::get_escrow_balance(env.clone(), bob.clone(), asset.clone());
+        #[cfg(not(feature = "records"))]
+        {
+            assert_eq!(DarkPoolSettlement::require_record_viewer(&env, &None), Ok(true));
+            // `RecordFormat::CommitmentOnly`
+            env.storage().instance().set(&RECORD_FORMAT_KEY, &1u32);
+            assert_eq!(
+                DarkPoolSettlement::require_record_viewer(&env, &None),
+                Err(SettlementError::FeatureNotBuilt)
+            );
+        }
 
-    assert_eq!(alice_balance, 500);
-    assert_eq!(bob_balance, 500);
+        #[cfg(not(feature = "margin"))]
+        {
+            let key = EscrowKey::main(&trader, &asset);
+            assert_eq!(DarkPoolSettlement::require_unpledged(&env, &key, 100), Ok(()));
+            env.storage().persistent().set(&(PLEDGED_KEY, trader.clone(), asset.clone()), &50i128);
+            assert_eq!(DarkPoolSettlement::require_unpledged(&env, &key, 100), Err(SettlementError::FeatureNotBuilt));
+            // Pledges only ever lock the main account
+            let sub = EscrowKey::new(&trader, &symbol_short!("fund"), &asset);
+            assert_eq!(DarkPoolSettlement::require_unpledged(&env, &sub, 100), Ok(()));
+        }
 
-    // Alice's locked balance should also decrease
-    let alice_locked = DarkPoolSettlement::get_locked_balance(env.clone(), alice.clone(), asset.clone());
-    assert_eq!(alice_locked, 500);
+        #[cfg(not(feature = "withdrawal-queue"))]
+        {
+            assert_eq!(DarkPoolSettlement::require_no_redemption_delay(&env, &asset), Ok(()));
+            let delays: Map<Address, u64> = Map::from_array(&env, [(asset.clone(), 60)]);
+            env.storage().instance().set(&REDEEM_DELAY_KEY, &delays);
+            assert_eq!(
+                DarkPoolSettlement::require_no_redemption_delay(&env, &asset),
+                Err(SettlementError::FeatureNotBuilt)
+            );
+        }
+    });
 }
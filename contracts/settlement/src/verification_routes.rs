@@ -0,0 +1,47 @@
+//! Per proof type choice between the verifier contract and native host verification
+//!
+//! Only compiled with the `verification-routes` feature.
+
+use soroban_sdk::{contractimpl, symbol_short, Address, Env, Map, Symbol};
+
+use crate::{DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, SettlementError, VerificationRoute};
+
+const ROUTES_KEY: Symbol = symbol_short!("routes");
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Choose where proofs of a given type are verified
+    ///
+    /// # Arguments
+    /// * `admin` - Must be the admin address
+    /// * `proof_type` - Proof type identifier (e.g., `SETTLEMENT_PROOF`)
+    /// * `route` - Verifier contract or native host verification
+    pub fn set_verification_route(
+        env: Env,
+        admin: Address,
+        proof_type: Symbol,
+        route: VerificationRoute,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut routes: Map<Symbol, VerificationRoute> = env
+            .storage()
+            .instance()
+            .get(&ROUTES_KEY)
+            .unwrap_or(Map::new(&env));
+        routes.set(proof_type, route);
+        env.storage().instance().set(&ROUTES_KEY, &routes);
+        Ok(())
+    }
+
+    /// Get the verification route for a proof type
+    pub fn get_verification_route(env: Env, proof_type: Symbol) -> VerificationRoute {
+        let routes: Map<Symbol, VerificationRoute> = env
+            .storage()
+            .instance()
+            .get(&ROUTES_KEY)
+            .unwrap_or(Map::new(&env));
+        routes.get(proof_type).unwrap_or(VerificationRoute::Contract)
+    }
+}
@@ -0,0 +1,189 @@
+//! Issuer redemption delays and the withdrawal queue
+//!
+//! Only compiled with the `withdrawal-queue` feature.
+
+use soroban_sdk::{contractevent, contractimpl, contracttype, symbol_short, Address, Env, Map, Symbol};
+
+use crate::{
+    DarkPoolSettlement, DarkPoolSettlementArgs, DarkPoolSettlementClient, SettlementError, BALANCE_TTL_EXTEND_TO,
+    BALANCE_TTL_THRESHOLD, MAX_PAGE_SIZE, REDEEM_DELAY_KEY,
+};
+
+const WD_QUEUES_KEY: Symbol = symbol_short!("wd_queues");
+
+const WD_REQUEST_KEY: Symbol = symbol_short!("wd_req");
+
+/// Event emitted when a withdrawal is queued behind a redemption delay
+#[contractevent]
+#[derive(Clone)]
+pub struct WithdrawalQueued {
+    #[topic]
+    pub asset: Address,
+    #[topic]
+    pub withdrawer: Address,
+    pub id: u64,
+    pub amount: i128,
+    pub ready_at: u64,
+}
+
+/// A withdrawal waiting out its asset's issuer redemption delay
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct WithdrawalRequest {
+    pub withdrawer: Address,
+    pub amount: i128,
+    /// Ledger timestamp from which a keeper may pay it out
+    pub ready_at: u64,
+}
+
+/// Bounds of an asset's withdrawal queue; ids in `head..tail` are unpaid
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[contracttype]
+pub struct WithdrawalQueue {
+    pub head: u64,
+    pub tail: u64,
+}
+
+#[contractimpl]
+impl DarkPoolSettlement {
+    /// Queue withdrawals of an asset behind its issuer's redemption delay
+    ///
+    /// While set, `withdraw`, `withdraw_from` and `unlock_and_withdraw`
+    /// debit escrow at once but only enqueue the payout; a keeper pays
+    /// requests out in order with `process_withdrawals` once `delay` seconds
+    /// have passed.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address
+    /// * `asset_address` - Asset redeemed through the issuer
+    /// * `delay` - Redemption delay in seconds, or `None` to pay out directly
+    pub fn set_redemption_delay(
+        env: Env,
+        admin: Address,
+        asset_address: Address,
+        delay: Option<u64>,
+    ) -> Result<(), SettlementError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+
+        let mut delays: Map<Address, u64> = env
+            .storage()
+            .instance()
+            .get(&REDEEM_DELAY_KEY)
+            .unwrap_or(Map::new(&env));
+        match delay {
+            Some(delay) => delays.set(asset_address, delay),
+            None => {
+                delays.remove(asset_address);
+            }
+        }
+        env.storage().instance().set(&REDEEM_DELAY_KEY, &delays);
+        Ok(())
+    }
+
+    /// Get the redemption delay (in seconds) queueing an asset's withdrawals
+    pub fn get_redemption_delay(env: Env, asset_address: Address) -> Option<u64> {
+        let delays: Map<Address, u64> = env
+            .storage()
+            .instance()
+            .get(&REDEEM_DELAY_KEY)
+            .unwrap_or(Map::new(&env));
+        delays.get(asset_address)
+    }
+
+    /// Pay out queued withdrawals whose redemption delay has elapsed
+    ///
+    /// Requests are paid strictly in queue order, stopping at the first one
+    /// not yet due. Anyone may act as keeper, since funds only go to the
+    /// recorded withdrawer.
+    ///
+    /// # Arguments
+    /// * `asset_address` - Asset whose queue to process
+    /// * `limit` - Maximum requests to pay, capped at `MAX_PAGE_SIZE`
+    ///
+    /// # Returns
+    /// * The number of requests paid
+    pub fn process_withdrawals(env: Env, asset_address: Address, limit: u32) -> u32 {
+        let mut queue = Self::get_withdrawal_queue(env.clone(), asset_address.clone());
+        let now = env.ledger().timestamp();
+        let mut paid = 0u32;
+
+        while paid < limit.min(MAX_PAGE_SIZE) && queue.head < queue.tail {
+            let entry = (WD_REQUEST_KEY, asset_address.clone(), queue.head);
+            let request: WithdrawalRequest = env.storage().persistent().get(&entry).unwrap();
+            if request.ready_at > now {
+                break;
+            }
+            env.storage().persistent().remove(&entry);
+            Self::asset_adapter(&env, &asset_address).transfer(
+                &env,
+                &env.current_contract_address(),
+                &request.withdrawer,
+                request.amount,
+            );
+            queue.head += 1;
+            paid += 1;
+        }
+
+        if paid > 0 {
+            Self::store_withdrawal_queue(&env, &asset_address, &queue);
+        }
+        paid
+    }
+
+    /// Get the bounds of an asset's withdrawal queue
+    pub fn get_withdrawal_queue(env: Env, asset_address: Address) -> WithdrawalQueue {
+        let queues: Map<Address, WithdrawalQueue> = env
+            .storage()
+            .instance()
+            .get(&WD_QUEUES_KEY)
+            .unwrap_or(Map::new(&env));
+        queues.get(asset_address).unwrap_or_default()
+    }
+
+    /// Get an unpaid withdrawal request; `ready_at` is its earliest payout time
+    pub fn get_withdrawal_request(env: Env, asset_address: Address, id: u64) -> Option<WithdrawalRequest> {
+        env.storage().persistent().get(&(WD_REQUEST_KEY, asset_address, id))
+    }
+
+    /// Get how many unpaid requests are ahead of a withdrawal request
+    pub fn get_withdrawal_position(env: Env, asset_address: Address, id: u64) -> Option<u64> {
+        let queue = Self::get_withdrawal_queue(env.clone(), asset_address);
+        (queue.head..queue.tail).contains(&id).then(|| id - queue.head)
+    }
+
+    /// Queue a withdrawal until the asset's redemption delay has elapsed
+    pub(crate) fn queue_withdrawal(env: &Env, withdrawer: &Address, asset_address: &Address, amount: i128, delay: u64) {
+        let mut queue = Self::get_withdrawal_queue(env.clone(), asset_address.clone());
+        let entry = (WD_REQUEST_KEY, asset_address.clone(), queue.tail);
+        let request = WithdrawalRequest {
+            withdrawer: withdrawer.clone(),
+            amount,
+            ready_at: env.ledger().timestamp() + delay,
+        };
+        env.storage().persistent().set(&entry, &request);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, BALANCE_TTL_THRESHOLD, BALANCE_TTL_EXTEND_TO);
+        WithdrawalQueued {
+            asset: asset_address.clone(),
+            withdrawer: withdrawer.clone(),
+            id: queue.tail,
+            amount,
+            ready_at: request.ready_at,
+        }
+        .publish(env);
+        queue.tail += 1;
+        Self::store_withdrawal_queue(env, asset_address, &queue);
+    }
+
+    fn store_withdrawal_queue(env: &Env, asset_address: &Address, queue: &WithdrawalQueue) {
+        let mut queues: Map<Address, WithdrawalQueue> = env
+            .storage()
+            .instance()
+            .get(&WD_QUEUES_KEY)
+            .unwrap_or(Map::new(env));
+        queues.set(asset_address.clone(), queue.clone());
+        env.storage().instance().set(&WD_QUEUES_KEY, &queues);
+    }
+}
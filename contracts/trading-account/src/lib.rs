@@ -0,0 +1,205 @@
+#![no_std]
+
+use soroban_sdk::{
+    auth::{Context, ContractContext, CustomAccountInterface},
+    contract, contracterror, contractimpl, contracttype,
+    crypto::Hash,
+    symbol_short, Address, BytesN, Env, Map, Symbol, TryFromVal, Vec,
+};
+
+#[cfg(test)]
+mod test;
+
+// Storage keys
+const TRADING_KEY: Symbol = symbol_short!("trading");
+const HARDWARE_KEY: Symbol = symbol_short!("hardware");
+const POOL_KEY: Symbol = symbol_short!("pool");
+const LIMITS_KEY: Symbol = symbol_short!("limits");
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AccountError {
+    UnknownSigner = 1,
+    MissingSignature = 2,
+    HardwareKeyRequired = 3,
+    LimitExceeded = 4,
+    InvalidLimit = 5,
+}
+
+/// Ed25519 signature over the authorization payload
+#[derive(Clone)]
+#[contracttype]
+pub struct AccountSignature {
+    pub public_key: BytesN<32>,
+    pub signature: BytesN<64>,
+}
+
+/// Trader account with split signing authority
+///
+/// The trading key may authorize lock, unlock and settle calls on the
+/// configured pool, within per-asset limits. Everything else, including
+/// withdrawals and changes to this account's own policy, needs the hardware
+/// key.
+#[contract]
+pub struct TradingAccount;
+
+#[contractimpl]
+impl TradingAccount {
+    /// Initialize the account
+    ///
+    /// # Arguments
+    /// * `trading_key` - Ed25519 key used for day-to-day trading
+    /// * `hardware_key` - Ed25519 key required for withdrawals and policy changes
+    /// * `pool` - Settlement contract the trading key may act on
+    pub fn __constructor(env: Env, trading_key: BytesN<32>, hardware_key: BytesN<32>, pool: Address) {
+        env.storage().instance().set(&TRADING_KEY, &trading_key);
+        env.storage().instance().set(&HARDWARE_KEY, &hardware_key);
+        env.storage().instance().set(&POOL_KEY, &pool);
+    }
+
+    /// Set the largest amount of an asset the trading key may lock or settle
+    /// in one call
+    ///
+    /// Assets without a limit can only be locked or settled with the
+    /// hardware key.
+    ///
+    /// # Arguments
+    /// * `asset_address` - Asset the limit applies to
+    /// * `limit` - Maximum amount per call, or `None` to remove the limit
+    pub fn set_lock_limit(env: Env, asset_address: Address, limit: Option<i128>) -> Result<(), AccountError> {
+        env.current_contract_address().require_auth();
+
+        let mut limits: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&LIMITS_KEY)
+            .unwrap_or(Map::new(&env));
+        match limit {
+            Some(limit) if limit <= 0 => return Err(AccountError::InvalidLimit),
+            Some(limit) => limits.set(asset_address, limit),
+            None => {
+                limits.remove(asset_address);
+            }
+        }
+        env.storage().instance().set(&LIMITS_KEY, &limits);
+        Ok(())
+    }
+
+    /// Get the trading key's per-call limit for an asset
+    pub fn get_lock_limit(env: Env, asset_address: Address) -> Option<i128> {
+        let limits: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&LIMITS_KEY)
+            .unwrap_or(Map::new(&env));
+        limits.get(asset_address)
+    }
+
+    /// Replace the trading key
+    pub fn set_trading_key(env: Env, trading_key: BytesN<32>) {
+        env.current_contract_address().require_auth();
+        env.storage().instance().set(&TRADING_KEY, &trading_key);
+    }
+
+    /// Get the trading key
+    pub fn get_trading_key(env: Env) -> BytesN<32> {
+        env.storage().instance().get(&TRADING_KEY).unwrap()
+    }
+
+    /// Get the hardware key
+    pub fn get_hardware_key(env: Env) -> BytesN<32> {
+        env.storage().instance().get(&HARDWARE_KEY).unwrap()
+    }
+
+    /// Get the settlement contract the trading key may act on
+    pub fn get_pool(env: Env) -> Address {
+        env.storage().instance().get(&POOL_KEY).unwrap()
+    }
+
+    /// Check one authorized call against the trading key's policy
+    fn check_trading_context(env: &Env, context: &Context) -> Result<(), AccountError> {
+        let Context::Contract(ContractContext { contract, fn_name, args }) = context else {
+            return Err(AccountError::HardwareKeyRequired);
+        };
+        if *contract != Self::get_pool(env.clone()) {
+            return Err(AccountError::HardwareKeyRequired);
+        }
+
+        // Position of the (asset, amount) arguments for calls that move funds
+        // into a lock or a trade
+        let (asset_arg, amount_arg) = if *fn_name == Symbol::new(env, "lock_escrow") {
+            (1, 2)
+        } else if *fn_name == Symbol::new(env, "lock_escrow_in") {
+            (2, 3)
+        } else if *fn_name == Symbol::new(env, "settle_trade") {
+            (3, 5)
+        } else if *fn_name == Symbol::new(env, "settle_trade_subaccounts") {
+            (5, 7)
+        } else if *fn_name == Symbol::new(env, "unlock_escrow")
+            || *fn_name == Symbol::new(env, "unlock_escrow_in")
+            || *fn_name == Symbol::new(env, "confirm_match")
+        {
+            return Ok(());
+        } else {
+            return Err(AccountError::HardwareKeyRequired);
+        };
+
+        let asset = args
+            .get(asset_arg)
+            .and_then(|v| Address::try_from_val(env, &v).ok())
+            .ok_or(AccountError::HardwareKeyRequired)?;
+        let amount = args
+            .get(amount_arg)
+            .and_then(|v| i128::try_from_val(env, &v).ok())
+            .ok_or(AccountError::HardwareKeyRequired)?;
+        let limit = Self::get_lock_limit(env.clone(), asset).ok_or(AccountError::HardwareKeyRequired)?;
+        if amount > limit {
+            return Err(AccountError::LimitExceeded);
+        }
+        Ok(())
+    }
+}
+
+#[contractimpl]
+impl CustomAccountInterface for TradingAccount {
+    type Signature = Vec<AccountSignature>;
+    type Error = AccountError;
+
+    /// Verify signatures and apply the signing policy
+    ///
+    /// A hardware key signature authorizes any call. With only the trading
+    /// key, every call in the authorization tree must pass the trading policy.
+    #[allow(non_snake_case)]
+    fn __check_auth(
+        env: Env,
+        signature_payload: Hash<32>,
+        signatures: Vec<AccountSignature>,
+        auth_contexts: Vec<Context>,
+    ) -> Result<(), AccountError> {
+        if signatures.is_empty() {
+            return Err(AccountError::MissingSignature);
+        }
+
+        let trading_key = Self::get_trading_key(env.clone());
+        let hardware_key = Self::get_hardware_key(env.clone());
+        let mut hardware_signed = false;
+        for sig in signatures.iter() {
+            if sig.public_key == hardware_key {
+                hardware_signed = true;
+            } else if sig.public_key != trading_key {
+                return Err(AccountError::UnknownSigner);
+            }
+            env.crypto()
+                .ed25519_verify(&sig.public_key, &signature_payload.clone().into(), &sig.signature);
+        }
+
+        if hardware_signed {
+            return Ok(());
+        }
+        for context in auth_contexts.iter() {
+            Self::check_trading_context(&env, &context)?;
+        }
+        Ok(())
+    }
+}
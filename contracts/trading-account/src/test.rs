@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use super::*;
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, BytesN as _},
+    vec, IntoVal, Val,
+};
+
+struct Setup {
+    env: Env,
+    account: Address,
+    pool: Address,
+    trading: SigningKey,
+    hardware: SigningKey,
+}
+
+fn setup() -> Setup {
+    let env = Env::default();
+    let trading = SigningKey::from_bytes(&[1u8; 32]);
+    let hardware = SigningKey::from_bytes(&[2u8; 32]);
+    let pool = Address::generate(&env);
+    let account = env.register(
+        TradingAccount,
+        (
+            BytesN::from_array(&env, &trading.verifying_key().to_bytes()),
+            BytesN::from_array(&env, &hardware.verifying_key().to_bytes()),
+            &pool,
+        ),
+    );
+    Setup { env, account, pool, trading, hardware }
+}
+
+fn sign(env: &Env, payload: &BytesN<32>, keys: &[&SigningKey]) -> Vec<AccountSignature> {
+    let mut sigs = Vec::new(env);
+    for key in keys {
+        sigs.push_back(AccountSignature {
+            public_key: BytesN::from_array(env, &key.verifying_key().to_bytes()),
+            signature: BytesN::from_array(env, &key.sign(&payload.to_array()).to_bytes()),
+        });
+    }
+    sigs
+}
+
+fn call(s: &Setup, contract: &Address, fn_name: &str, args: Vec<Val>) -> Context {
+    Context::Contract(ContractContext {
+        contract: contract.clone(),
+        fn_name: Symbol::new(&s.env, fn_name),
+        args,
+    })
+}
+
+fn check(s: &Setup, keys: &[&SigningKey], contexts: Vec<Context>) -> Result<(), AccountError> {
+    let payload = BytesN::random(&s.env);
+    s.env
+        .try_invoke_contract_check_auth::<AccountError>(
+            &s.account,
+            &payload,
+            sign(&s.env, &payload, keys).into_val(&s.env),
+            &contexts,
+        )
+        .map_err(|e| e.unwrap())
+}
+
+#[test]
+fn test_trading_key_locks_within_limit() {
+    let s = setup();
+    let client = TradingAccountClient::new(&s.env, &s.account);
+    let asset = Address::generate(&s.env);
+
+    s.env.mock_all_auths();
+    client.set_lock_limit(&asset, &Some(1_000));
+    assert_eq!(client.get_lock_limit(&asset), Some(1_000));
+
+    let lock = |amount: i128| {
+        call(&s, &s.pool, "lock_escrow", vec![&s.env, s.account.into_val(&s.env), asset.into_val(&s.env), amount.into_val(&s.env)])
+    };
+    assert_eq!(check(&s, &[&s.trading], vec![&s.env, lock(1_000)]), Ok(()));
+    assert_eq!(
+        check(&s, &[&s.trading], vec![&s.env, lock(1_001)]),
+        Err(AccountError::LimitExceeded)
+    );
+
+    // Unlocking and confirming matches need no limit
+    let unlock = call(&s, &s.pool, "unlock_escrow", vec![&s.env, s.account.into_val(&s.env), asset.into_val(&s.env), 5_000i128.into_val(&s.env)]);
+    assert_eq!(check(&s, &[&s.trading], vec![&s.env, unlock]), Ok(()));
+
+    // Assets without a limit are refused to the trading key
+    let other = Address::generate(&s.env);
+    let unlisted = call(&s, &s.pool, "lock_escrow", vec![&s.env, s.account.into_val(&s.env), other.into_val(&s.env), 1i128.into_val(&s.env)]);
+    assert_eq!(
+        check(&s, &[&s.trading], vec![&s.env, unlisted]),
+        Err(AccountError::HardwareKeyRequired)
+    );
+
+    client.set_lock_limit(&asset, &None);
+    assert_eq!(client.get_lock_limit(&asset), None);
+    assert_eq!(client.try_set_lock_limit(&asset, &Some(0)), Err(Ok(AccountError::InvalidLimit)));
+}
+
+#[test]
+fn test_withdrawals_require_hardware_key() {
+    let s = setup();
+    let asset = Address::generate(&s.env);
+    let withdraw = call(&s, &s.pool, "withdraw", vec![&s.env, s.account.into_val(&s.env), asset.into_val(&s.env), 10i128.into_val(&s.env)]);
+
+    assert_eq!(
+        check(&s, &[&s.trading], vec![&s.env, withdraw.clone()]),
+        Err(AccountError::HardwareKeyRequired)
+    );
+    assert_eq!(check(&s, &[&s.hardware], vec![&s.env, withdraw.clone()]), Ok(()));
+
+    // A policy-compliant call does not carry a withdrawal through with it
+    let confirm = call(&s, &s.pool, "confirm_match", vec![&s.env, s.account.into_val(&s.env), BytesN::<32>::random(&s.env).into_val(&s.env)]);
+    assert_eq!(
+        check(&s, &[&s.trading], vec![&s.env, confirm, withdraw]),
+        Err(AccountError::HardwareKeyRequired)
+    );
+
+    // Pool calls are only recognised on the configured pool
+    let elsewhere = Address::generate(&s.env);
+    let unlock = call(&s, &elsewhere, "unlock_escrow", vec![&s.env, s.account.into_val(&s.env), asset.into_val(&s.env), 1i128.into_val(&s.env)]);
+    assert_eq!(
+        check(&s, &[&s.trading], vec![&s.env, unlock]),
+        Err(AccountError::HardwareKeyRequired)
+    );
+
+    // Policy changes on the account itself also need the hardware key
+    let rotate = call(&s, &s.account, "set_trading_key", vec![&s.env, BytesN::<32>::random(&s.env).into_val(&s.env)]);
+    assert_eq!(
+        check(&s, &[&s.trading], vec![&s.env, rotate]),
+        Err(AccountError::HardwareKeyRequired)
+    );
+}
+
+#[test]
+fn test_rejects_unknown_signers() {
+    let s = setup();
+    let unlock = call(&s, &s.pool, "unlock_escrow", vec![&s.env]);
+
+    assert_eq!(check(&s, &[], vec![&s.env, unlock.clone()]), Err(AccountError::MissingSignature));
+
+    let stranger = SigningKey::from_bytes(&[3u8; 32]);
+    assert_eq!(check(&s, &[&stranger], vec![&s.env, unlock]), Err(AccountError::UnknownSigner));
+}
+
+#[test]
+fn test_settle_limited_by_quantity() {
+    let s = setup();
+    let client = TradingAccountClient::new(&s.env, &s.account);
+    let (asset, usdc) = (Address::generate(&s.env), Address::generate(&s.env));
+    s.env.mock_all_auths();
+    client.set_lock_limit(&asset, &Some(100));
+
+    let settle = |quantity: i128| {
+        call(
+            &s,
+            &s.pool,
+            "settle_trade",
+            vec![
+                &s.env,
+                BytesN::<32>::random(&s.env).into_val(&s.env),
+                s.account.into_val(&s.env),
+                Address::generate(&s.env).into_val(&s.env),
+                asset.into_val(&s.env),
+                usdc.into_val(&s.env),
+                quantity.into_val(&s.env),
+                5_000i128.into_val(&s.env),
+            ],
+        )
+    };
+    assert_eq!(check(&s, &[&s.trading], vec![&s.env, settle(100)]), Ok(()));
+    assert_eq!(
+        check(&s, &[&s.trading], vec![&s.env, settle(101)]),
+        Err(AccountError::LimitExceeded)
+    );
+}
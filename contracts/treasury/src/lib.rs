@@ -0,0 +1,461 @@
+#![no_std]
+
+use soroban_sdk::{
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, Map,
+    Symbol,
+};
+
+#[cfg(test)]
+mod test;
+
+// Storage keys
+const ADMIN_KEY: Symbol = symbol_short!("admin");
+const GOVERNANCE_KEY: Symbol = symbol_short!("gov");
+const SETTLEMENT_KEY: Symbol = symbol_short!("settl");
+const INS_POLICY_KEY: Symbol = symbol_short!("ins_pol");
+const INS_RESERVE_KEY: Symbol = symbol_short!("ins_res");
+const COMMITTED_KEY: Symbol = symbol_short!("committed");
+const STREAM_COUNT_KEY: Symbol = symbol_short!("strm_cnt");
+const STREAMS_KEY: Symbol = symbol_short!("streams");
+const TRANSFER_COUNT_KEY: Symbol = symbol_short!("xfer_cnt");
+const TRANSFERS_KEY: Symbol = symbol_short!("xfers");
+
+/// Stream and transfer entries are bumped to ~30 days whenever they drop
+/// below ~15 days (at 5s ledgers)
+const ENTRY_TTL_THRESHOLD: u32 = 259_200;
+const ENTRY_TTL_EXTEND_TO: u32 = 518_400;
+
+/// Basis points denominator for the insurance share
+pub const BPS_DENOMINATOR: i128 = 10_000;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TreasuryError {
+    OnlyAdmin = 1,
+    OnlyGovernance = 2,
+    InvalidAmount = 3,
+    InvalidPolicy = 4,
+    InvalidSchedule = 5,
+    StreamNotFound = 6,
+    TransferNotFound = 7,
+    TransferAlreadyExecuted = 8,
+    InsufficientFreeBalance = 9,
+    InsufficientReserve = 10,
+}
+
+/// Rule for ring-fencing collected fees as an insurance reserve
+///
+/// `share_bps` of every collection is set aside until the reserve reaches
+/// `target`. Only governance can release the reserve.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct InsurancePolicy {
+    pub share_bps: u32,
+    pub target: i128,
+}
+
+/// Budget paid out linearly between `start` and `end`
+///
+/// The full `amount` is committed when the stream is created, so spending
+/// elsewhere can never leave a stream unfunded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Stream {
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub start: u64,
+    pub end: u64,
+    pub withdrawn: i128,
+}
+
+/// One-off payment proposed by the admin, paid once governance approves it
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TransferProposal {
+    pub recipient: Address,
+    pub asset: Address,
+    pub amount: i128,
+    pub proposed_at: u64,
+    pub executed: bool,
+}
+
+/// Settlement entry points the treasury collects fees and dust through
+#[contractclient(name = "SettlementFeesClient")]
+pub trait SettlementFees {
+    fn get_available_balance(env: Env, participant: Address, asset: Address) -> i128;
+    fn withdraw(env: Env, withdrawer: Address, asset_address: Address, amount: i128) -> i128;
+    fn get_treasury(env: Env) -> Option<Address>;
+    fn claim_dust(env: Env, treasury: Address, asset: Address) -> i128;
+}
+
+/// Protocol treasury
+///
+/// Receives settlement fees and dust, and only pays them out under its
+/// spending policy: budgeted streams and one-off transfers approved by
+/// governance, never touching the insurance reserve.
+#[contract]
+pub struct DarkPoolTreasury;
+
+#[contractimpl]
+impl DarkPoolTreasury {
+    /// Initialize the treasury
+    ///
+    /// # Arguments
+    /// * `admin` - Proposes one-off transfers
+    /// * `governance` - Approves spending and sets the insurance policy
+    /// * `settlement` - Settlement contract whose fees are collected
+    pub fn __constructor(env: Env, admin: Address, governance: Address, settlement: Address) {
+        env.storage().instance().set(&ADMIN_KEY, &admin);
+        env.storage().instance().set(&GOVERNANCE_KEY, &governance);
+        env.storage().instance().set(&SETTLEMENT_KEY, &settlement);
+    }
+
+    /// Pull fees and dust accrued in settlement into the treasury
+    ///
+    /// Anyone may call this. Withdraws the treasury's unlocked escrow in
+    /// `asset`, claims its dust if settlement names this contract as its
+    /// treasury, and tops up the insurance reserve from the proceeds.
+    ///
+    /// # Returns
+    /// * Amount collected
+    pub fn collect(env: Env, asset: Address) -> i128 {
+        let settlement = SettlementFeesClient::new(&env, &Self::get_settlement(env.clone()));
+        let treasury = env.current_contract_address();
+
+        let mut collected = settlement.get_available_balance(&treasury, &asset);
+        if collected > 0 {
+            settlement.withdraw(&treasury, &asset, &collected);
+        }
+        if settlement.get_treasury() == Some(treasury.clone()) {
+            collected += settlement.claim_dust(&treasury, &asset);
+        }
+
+        if let Some(policy) = Self::get_insurance_policy(env.clone(), asset.clone()) {
+            let reserve = Self::get_insurance_reserve(env.clone(), asset.clone());
+            let top_up = (collected * policy.share_bps as i128 / BPS_DENOMINATOR).min(policy.target - reserve);
+            if top_up > 0 {
+                Self::adjust(&env, &INS_RESERVE_KEY, &asset, top_up);
+            }
+        }
+        collected
+    }
+
+    /// Set (or clear) the insurance top-up rule for an asset
+    ///
+    /// # Arguments
+    /// * `governance` - Must be governance
+    /// * `asset` - Asset the rule applies to
+    /// * `policy` - Share and target, or `None` to stop topping up
+    pub fn set_insurance_policy(
+        env: Env,
+        governance: Address,
+        asset: Address,
+        policy: Option<InsurancePolicy>,
+    ) -> Result<(), TreasuryError> {
+        governance.require_auth();
+        Self::require_governance(&env, &governance)?;
+
+        let mut policies: Map<Address, InsurancePolicy> = env
+            .storage()
+            .instance()
+            .get(&INS_POLICY_KEY)
+            .unwrap_or(Map::new(&env));
+        match policy {
+            Some(policy) => {
+                if policy.share_bps as i128 > BPS_DENOMINATOR || policy.target < 0 {
+                    return Err(TreasuryError::InvalidPolicy);
+                }
+                policies.set(asset, policy);
+            }
+            None => {
+                policies.remove(asset);
+            }
+        }
+        env.storage().instance().set(&INS_POLICY_KEY, &policies);
+        Ok(())
+    }
+
+    /// Get the insurance top-up rule for an asset, if any
+    pub fn get_insurance_policy(env: Env, asset: Address) -> Option<InsurancePolicy> {
+        let policies: Map<Address, InsurancePolicy> = env
+            .storage()
+            .instance()
+            .get(&INS_POLICY_KEY)
+            .unwrap_or(Map::new(&env));
+        policies.get(asset)
+    }
+
+    /// Get the insurance reserve held in an asset
+    pub fn get_insurance_reserve(env: Env, asset: Address) -> i128 {
+        Self::read(&env, &INS_RESERVE_KEY, &asset)
+    }
+
+    /// Pay out of the insurance reserve
+    ///
+    /// # Arguments
+    /// * `governance` - Must be governance
+    /// * `asset` - Asset the reserve is held in
+    /// * `recipient` - Receives the payment
+    /// * `amount` - Amount to release
+    pub fn release_insurance(
+        env: Env,
+        governance: Address,
+        asset: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), TreasuryError> {
+        governance.require_auth();
+        Self::require_governance(&env, &governance)?;
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        if Self::get_insurance_reserve(env.clone(), asset.clone()) < amount {
+            return Err(TreasuryError::InsufficientReserve);
+        }
+
+        Self::adjust(&env, &INS_RESERVE_KEY, &asset, -amount);
+        token::Client::new(&env, &asset).transfer(&env.current_contract_address(), &recipient, &amount);
+        Ok(())
+    }
+
+    /// Open a budgeted stream
+    ///
+    /// # Arguments
+    /// * `governance` - Must be governance
+    /// * `recipient` - Address the stream pays
+    /// * `asset` - Asset paid
+    /// * `amount` - Total budget, committed from the free balance
+    /// * `start` - Timestamp payments start accruing
+    /// * `end` - Timestamp the full budget has accrued
+    ///
+    /// # Returns
+    /// * Stream id
+    pub fn create_stream(
+        env: Env,
+        governance: Address,
+        recipient: Address,
+        asset: Address,
+        amount: i128,
+        start: u64,
+        end: u64,
+    ) -> Result<u32, TreasuryError> {
+        governance.require_auth();
+        Self::require_governance(&env, &governance)?;
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+        if end <= start {
+            return Err(TreasuryError::InvalidSchedule);
+        }
+        if Self::get_free_balance(env.clone(), asset.clone()) < amount {
+            return Err(TreasuryError::InsufficientFreeBalance);
+        }
+
+        Self::adjust(&env, &COMMITTED_KEY, &asset, amount);
+        let id: u32 = env.storage().instance().get(&STREAM_COUNT_KEY).unwrap_or(0);
+        env.storage().instance().set(&STREAM_COUNT_KEY, &(id + 1));
+        Self::store_stream(
+            &env,
+            id,
+            &Stream {
+                recipient,
+                asset,
+                amount,
+                start,
+                end,
+                withdrawn: 0,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Withdraw what a stream has accrued so far
+    ///
+    /// # Arguments
+    /// * `recipient` - Stream recipient (must authenticate)
+    /// * `id` - Stream id
+    ///
+    /// # Returns
+    /// * Amount paid
+    pub fn withdraw_stream(env: Env, recipient: Address, id: u32) -> Result<i128, TreasuryError> {
+        recipient.require_auth();
+
+        let mut stream = Self::get_stream(env.clone(), id).ok_or(TreasuryError::StreamNotFound)?;
+        if stream.recipient != recipient {
+            return Err(TreasuryError::StreamNotFound);
+        }
+
+        let amount = Self::accrued(&env, &stream) - stream.withdrawn;
+        if amount > 0 {
+            stream.withdrawn += amount;
+            Self::store_stream(&env, id, &stream);
+            Self::adjust(&env, &COMMITTED_KEY, &stream.asset, -amount);
+            token::Client::new(&env, &stream.asset).transfer(&env.current_contract_address(), &recipient, &amount);
+        }
+        Ok(amount)
+    }
+
+    /// Stop a stream, returning its unaccrued budget to the free balance
+    ///
+    /// What has accrued so far stays withdrawable.
+    pub fn cancel_stream(env: Env, governance: Address, id: u32) -> Result<(), TreasuryError> {
+        governance.require_auth();
+        Self::require_governance(&env, &governance)?;
+
+        let mut stream = Self::get_stream(env.clone(), id).ok_or(TreasuryError::StreamNotFound)?;
+        let accrued = Self::accrued(&env, &stream);
+        Self::adjust(&env, &COMMITTED_KEY, &stream.asset, accrued - stream.amount);
+        stream.amount = accrued;
+        stream.end = env.ledger().timestamp().clamp(stream.start, stream.end);
+        Self::store_stream(&env, id, &stream);
+        Ok(())
+    }
+
+    /// Get a stream by id
+    pub fn get_stream(env: Env, id: u32) -> Option<Stream> {
+        env.storage().persistent().get(&(STREAMS_KEY, id))
+    }
+
+    /// Propose a one-off transfer for governance to approve
+    ///
+    /// # Returns
+    /// * Proposal id
+    pub fn propose_transfer(
+        env: Env,
+        admin: Address,
+        recipient: Address,
+        asset: Address,
+        amount: i128,
+    ) -> Result<u32, TreasuryError> {
+        admin.require_auth();
+        Self::require_admin(&env, &admin)?;
+        if amount <= 0 {
+            return Err(TreasuryError::InvalidAmount);
+        }
+
+        let id: u32 = env.storage().instance().get(&TRANSFER_COUNT_KEY).unwrap_or(0);
+        env.storage().instance().set(&TRANSFER_COUNT_KEY, &(id + 1));
+        Self::store_transfer(
+            &env,
+            id,
+            &TransferProposal {
+                recipient,
+                asset,
+                amount,
+                proposed_at: env.ledger().timestamp(),
+                executed: false,
+            },
+        );
+        Ok(id)
+    }
+
+    /// Approve and pay a proposed transfer out of the free balance
+    pub fn approve_transfer(env: Env, governance: Address, id: u32) -> Result<(), TreasuryError> {
+        governance.require_auth();
+        Self::require_governance(&env, &governance)?;
+
+        let mut proposal = Self::get_transfer(env.clone(), id).ok_or(TreasuryError::TransferNotFound)?;
+        if proposal.executed {
+            return Err(TreasuryError::TransferAlreadyExecuted);
+        }
+        if Self::get_free_balance(env.clone(), proposal.asset.clone()) < proposal.amount {
+            return Err(TreasuryError::InsufficientFreeBalance);
+        }
+
+        proposal.executed = true;
+        Self::store_transfer(&env, id, &proposal);
+        token::Client::new(&env, &proposal.asset).transfer(
+            &env.current_contract_address(),
+            &proposal.recipient,
+            &proposal.amount,
+        );
+        Ok(())
+    }
+
+    /// Get a transfer proposal by id
+    pub fn get_transfer(env: Env, id: u32) -> Option<TransferProposal> {
+        env.storage().persistent().get(&(TRANSFERS_KEY, id))
+    }
+
+    /// Balance available for new spending
+    ///
+    /// Excludes the insurance reserve and budget committed to streams.
+    pub fn get_free_balance(env: Env, asset: Address) -> i128 {
+        let held = token::Client::new(&env, &asset).balance(&env.current_contract_address());
+        held - Self::get_insurance_reserve(env.clone(), asset.clone()) - Self::read(&env, &COMMITTED_KEY, &asset)
+    }
+
+    /// Get the admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage().instance().get(&ADMIN_KEY).unwrap()
+    }
+
+    /// Get the governance address
+    pub fn get_governance(env: Env) -> Address {
+        env.storage().instance().get(&GOVERNANCE_KEY).unwrap()
+    }
+
+    /// Get the settlement contract address
+    pub fn get_settlement(env: Env) -> Address {
+        env.storage().instance().get(&SETTLEMENT_KEY).unwrap()
+    }
+
+    // Internal helpers
+
+    fn require_admin(env: &Env, caller: &Address) -> Result<(), TreasuryError> {
+        if *caller != Self::get_admin(env.clone()) {
+            return Err(TreasuryError::OnlyAdmin);
+        }
+        Ok(())
+    }
+
+    fn require_governance(env: &Env, caller: &Address) -> Result<(), TreasuryError> {
+        if *caller != Self::get_governance(env.clone()) {
+            return Err(TreasuryError::OnlyGovernance);
+        }
+        Ok(())
+    }
+
+    /// Amount a stream has accrued by the current ledger time
+    fn accrued(env: &Env, stream: &Stream) -> i128 {
+        let now = env.ledger().timestamp();
+        if now <= stream.start {
+            0
+        } else if now >= stream.end {
+            stream.amount
+        } else {
+            stream.amount * (now - stream.start) as i128 / (stream.end - stream.start) as i128
+        }
+    }
+
+    /// Read a per-asset amount from an instance map
+    fn read(env: &Env, key: &Symbol, asset: &Address) -> i128 {
+        let amounts: Map<Address, i128> = env.storage().instance().get(key).unwrap_or(Map::new(env));
+        amounts.get(asset.clone()).unwrap_or(0)
+    }
+
+    /// Add to a per-asset amount in an instance map
+    fn adjust(env: &Env, key: &Symbol, asset: &Address, delta: i128) {
+        let mut amounts: Map<Address, i128> = env.storage().instance().get(key).unwrap_or(Map::new(env));
+        amounts.set(asset.clone(), amounts.get(asset.clone()).unwrap_or(0) + delta);
+        env.storage().instance().set(key, &amounts);
+    }
+
+    fn store_stream(env: &Env, id: u32, stream: &Stream) {
+        let entry = (STREAMS_KEY, id);
+        env.storage().persistent().set(&entry, stream);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, ENTRY_TTL_THRESHOLD, ENTRY_TTL_EXTEND_TO);
+    }
+
+    fn store_transfer(env: &Env, id: u32, proposal: &TransferProposal) {
+        let entry = (TRANSFERS_KEY, id);
+        env.storage().persistent().set(&entry, proposal);
+        env.storage()
+            .persistent()
+            .extend_ttl(&entry, ENTRY_TTL_THRESHOLD, ENTRY_TTL_EXTEND_TO);
+    }
+}
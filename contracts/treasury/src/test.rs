@@ -0,0 +1,184 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+};
+
+#[contract]
+struct MockSettlement;
+
+#[contractimpl]
+impl MockSettlement {
+    pub fn set_available(env: Env, asset: Address, amount: i128) {
+        env.storage().instance().set(&(symbol_short!("avail"), asset), &amount);
+    }
+
+    pub fn set_dust(env: Env, treasury: Address, asset: Address, amount: i128) {
+        env.storage().instance().set(&symbol_short!("treasury"), &treasury);
+        env.storage().instance().set(&(symbol_short!("dust"), asset), &amount);
+    }
+
+    pub fn get_available_balance(env: Env, _participant: Address, asset: Address) -> i128 {
+        env.storage().instance().get(&(symbol_short!("avail"), asset)).unwrap_or(0)
+    }
+
+    pub fn withdraw(env: Env, withdrawer: Address, asset_address: Address, amount: i128) -> i128 {
+        withdrawer.require_auth();
+        env.storage().instance().set(&(symbol_short!("avail"), asset_address.clone()), &0i128);
+        TokenClient::new(&env, &asset_address).transfer(&env.current_contract_address(), &withdrawer, &amount);
+        amount
+    }
+
+    pub fn get_treasury(env: Env) -> Option<Address> {
+        env.storage().instance().get(&symbol_short!("treasury"))
+    }
+
+    pub fn claim_dust(env: Env, treasury: Address, asset: Address) -> i128 {
+        treasury.require_auth();
+        let amount: i128 = env.storage().instance().get(&(symbol_short!("dust"), asset.clone())).unwrap_or(0);
+        env.storage().instance().set(&(symbol_short!("dust"), asset.clone()), &0i128);
+        TokenClient::new(&env, &asset).transfer(&env.current_contract_address(), &treasury, &amount);
+        amount
+    }
+}
+
+struct Setup<'a> {
+    env: Env,
+    client: DarkPoolTreasuryClient<'a>,
+    treasury: Address,
+    settlement: Address,
+    admin: Address,
+    governance: Address,
+    asset: Address,
+}
+
+fn setup<'a>() -> Setup<'a> {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let governance = Address::generate(&env);
+    let settlement = env.register(MockSettlement, ());
+    let treasury = env.register(DarkPoolTreasury, (&admin, &governance, &settlement));
+    let client = DarkPoolTreasuryClient::new(&env, &treasury);
+    let asset = env.register_stellar_asset_contract_v2(Address::generate(&env)).address();
+
+    Setup { env, client, treasury, settlement, admin, governance, asset }
+}
+
+/// Fund the treasury through a settlement collection
+fn fund(s: &Setup, amount: i128) -> i128 {
+    StellarAssetClient::new(&s.env, &s.asset).mint(&s.settlement, &amount);
+    MockSettlementClient::new(&s.env, &s.settlement).set_available(&s.asset, &amount);
+    s.client.collect(&s.asset)
+}
+
+#[test]
+fn test_collect_fees_and_dust_into_insurance() {
+    let s = setup();
+    let mock = MockSettlementClient::new(&s.env, &s.settlement);
+
+    s.client.set_insurance_policy(
+        &s.governance,
+        &s.asset,
+        &Some(InsurancePolicy { share_bps: 2_000, target: 300 }),
+    );
+
+    // Dust is only claimed once settlement names this contract as treasury
+    StellarAssetClient::new(&s.env, &s.asset).mint(&s.settlement, &5);
+    mock.set_dust(&s.treasury, &s.asset, &5);
+    assert_eq!(fund(&s, 1_000), 1_005);
+    assert_eq!(s.client.get_insurance_reserve(&s.asset), 201);
+    assert_eq!(s.client.get_free_balance(&s.asset), 804);
+
+    // Top-ups stop at the target
+    assert_eq!(fund(&s, 1_000), 1_000);
+    assert_eq!(s.client.get_insurance_reserve(&s.asset), 300);
+    assert_eq!(s.client.get_free_balance(&s.asset), 1_705);
+
+    let bad = s.client.try_set_insurance_policy(
+        &s.governance,
+        &s.asset,
+        &Some(InsurancePolicy { share_bps: 10_001, target: 300 }),
+    );
+    assert_eq!(bad, Err(Ok(TreasuryError::InvalidPolicy)));
+    let not_gov = s.client.try_release_insurance(&s.admin, &s.asset, &s.admin, &100);
+    assert_eq!(not_gov, Err(Ok(TreasuryError::OnlyGovernance)));
+    let too_much = s.client.try_release_insurance(&s.governance, &s.asset, &s.admin, &301);
+    assert_eq!(too_much, Err(Ok(TreasuryError::InsufficientReserve)));
+
+    let claimant = Address::generate(&s.env);
+    s.client.release_insurance(&s.governance, &s.asset, &claimant, &300);
+    assert_eq!(TokenClient::new(&s.env, &s.asset).balance(&claimant), 300);
+    assert_eq!(s.client.get_insurance_reserve(&s.asset), 0);
+    assert_eq!(s.client.get_free_balance(&s.asset), 1_705);
+}
+
+#[test]
+fn test_stream_vests_linearly_and_cancels() {
+    let s = setup();
+    fund(&s, 1_000);
+    let recipient = Address::generate(&s.env);
+    let token = TokenClient::new(&s.env, &s.asset);
+
+    let bad = s.client.try_create_stream(&s.governance, &recipient, &s.asset, &100, &200, &100);
+    assert_eq!(bad, Err(Ok(TreasuryError::InvalidSchedule)));
+    let over = s.client.try_create_stream(&s.governance, &recipient, &s.asset, &1_001, &100, &200);
+    assert_eq!(over, Err(Ok(TreasuryError::InsufficientFreeBalance)));
+
+    s.env.ledger().set_timestamp(100);
+    let id = s.client.create_stream(&s.governance, &recipient, &s.asset, &800, &100, &500);
+    assert_eq!(s.client.get_free_balance(&s.asset), 200);
+
+    // A quarter of the schedule has elapsed
+    s.env.ledger().set_timestamp(200);
+    assert_eq!(s.client.withdraw_stream(&recipient, &id), 200);
+    assert_eq!(s.client.withdraw_stream(&recipient, &id), 0);
+    assert_eq!(token.balance(&recipient), 200);
+
+    let stranger = Address::generate(&s.env);
+    assert_eq!(s.client.try_withdraw_stream(&stranger, &id), Err(Ok(TreasuryError::StreamNotFound)));
+
+    // Cancelling at the halfway mark keeps the accrued half withdrawable
+    s.env.ledger().set_timestamp(300);
+    s.client.cancel_stream(&s.governance, &id);
+    assert_eq!(s.client.get_free_balance(&s.asset), 600);
+    s.env.ledger().set_timestamp(1_000);
+    assert_eq!(s.client.withdraw_stream(&recipient, &id), 200);
+    assert_eq!(token.balance(&recipient), 400);
+    assert_eq!(s.client.get_free_balance(&s.asset), 600);
+}
+
+#[test]
+fn test_transfer_needs_governance_approval() {
+    let s = setup();
+    fund(&s, 500);
+    s.client.set_insurance_policy(&s.governance, &s.asset, &Some(InsurancePolicy { share_bps: 10_000, target: 200 }));
+    fund(&s, 100);
+    let recipient = Address::generate(&s.env);
+
+    let not_admin = s.client.try_propose_transfer(&s.governance, &recipient, &s.asset, &100);
+    assert_eq!(not_admin, Err(Ok(TreasuryError::OnlyAdmin)));
+
+    // The reserve is ring-fenced from spending
+    let big = s.client.propose_transfer(&s.admin, &recipient, &s.asset, &550);
+    assert_eq!(
+        s.client.try_approve_transfer(&s.governance, &big),
+        Err(Ok(TreasuryError::InsufficientFreeBalance))
+    );
+
+    let id = s.client.propose_transfer(&s.admin, &recipient, &s.asset, &500);
+    assert_eq!(s.client.try_approve_transfer(&s.admin, &id), Err(Ok(TreasuryError::OnlyGovernance)));
+    assert!(!s.client.get_transfer(&id).unwrap().executed);
+
+    s.client.approve_transfer(&s.governance, &id);
+    assert!(s.client.get_transfer(&id).unwrap().executed);
+    assert_eq!(TokenClient::new(&s.env, &s.asset).balance(&recipient), 500);
+    assert_eq!(
+        s.client.try_approve_transfer(&s.governance, &id),
+        Err(Ok(TreasuryError::TransferAlreadyExecuted))
+    );
+    assert_eq!(s.client.try_approve_transfer(&s.governance, &9), Err(Ok(TreasuryError::TransferNotFound)));
+}
@@ -1,7 +1,7 @@
 #![cfg(test)]
 
 use super::*;
-use soroban_sdk::Env;
+use soroban_sdk::{Bytes, Env};
 
 #[test]
 fn test_verifier_contract_deploys() {
@@ -11,3 +11,166 @@ fn test_verifier_contract_deploys() {
     // Contract should deploy successfully
     assert!(!contract_id.to_string().is_empty());
 }
+
+#[test]
+fn test_stateless_verify_rejects_malformed_inputs() {
+    let env = Env::default();
+
+    let vk_bytes = Bytes::from_slice(&env, &[0u8; 10]);
+    let proof_bytes = Bytes::from_slice(&env, &[0u8; G1_SIZE + G2_SIZE + G1_SIZE]);
+    let signals_bytes = Bytes::from_slice(&env, &[0u8; 4]);
+
+    // No contract is registered; the free function runs in the caller's frame
+    assert_eq!(
+        verify_groth16(&vk_bytes, &proof_bytes, &signals_bytes),
+        Err(VerifierError::MalformedVerificationKey)
+    );
+}
+
+#[test]
+fn test_signal_count_is_capped() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    let fixture = generate(5, &[scalar(1)]);
+    let vk = Bytes::from_slice(&env, &fixture.vk);
+    let proof = Bytes::from_slice(&env, &fixture.proof);
+
+    let mut signals_bytes = Bytes::from_slice(&env, &(MAX_PUBLIC_SIGNALS + 1).to_be_bytes());
+    signals_bytes.append(&Bytes::from_slice(&env, &[0u8; FR_SIZE]));
+    assert_eq!(verify_groth16(&vk, &proof, &signals_bytes), Err(VerifierError::TooManySignals));
+
+    let signals_bytes = Bytes::from_slice(&env, &u32::MAX.to_be_bytes());
+    assert_eq!(verify_groth16(&vk, &proof, &signals_bytes), Err(VerifierError::TooManySignals));
+}
+
+#[test]
+fn test_signal_bytes_must_be_fully_consumed() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    let fixture = generate(5, &[scalar(1)]);
+    let vk = Bytes::from_slice(&env, &fixture.vk);
+    let proof = Bytes::from_slice(&env, &fixture.proof);
+
+    let mut signals_bytes = Bytes::from_slice(&env, &fixture.signals);
+    assert_eq!(verify_groth16(&vk, &proof, &signals_bytes), Ok(true));
+
+    // Trailing bytes after the declared signals are rejected
+    signals_bytes.push_back(0);
+    assert_eq!(verify_groth16(&vk, &proof, &signals_bytes), Err(VerifierError::InvalidPublicSignals));
+}
+
+#[test]
+fn test_verifies_compressed_encodings() {
+    use darkpool_testdata::{generate, scalar};
+
+    let env = Env::default();
+    let contract_id = env.register(Groth16VerifierBN254, ());
+    let client = Groth16VerifierBN254Client::new(&env, &contract_id);
+
+    let fixture = generate(5, &[scalar(1), scalar(2), scalar(3)]);
+    let vk = Bytes::from_slice(&env, &fixture.vk);
+    let proof = Bytes::from_slice(&env, &fixture.proof);
+    let signals = Bytes::from_slice(&env, &fixture.signals);
+    let compressed_vk = zk_bn254::compress_vk_bytes(&env, &vk).unwrap();
+    let compressed_proof = zk_bn254::compress_proof_bytes(&env, &proof).unwrap();
+    assert_eq!(compressed_proof.len(), 1 + 33 + 65 + 33);
+
+    assert!(client.verify_proof_bytes(&vk, &proof, &signals));
+    assert!(client.verify_proof_bytes(&compressed_vk, &compressed_proof, &signals));
+    assert!(client.verify_proof_bytes(&vk, &compressed_proof, &signals));
+
+    // An explicit uncompressed prefix is accepted too
+    let mut prefixed = Bytes::from_array(&env, &[zk_bn254::ENCODING_UNCOMPRESSED]);
+    prefixed.append(&proof);
+    assert!(client.verify_proof_bytes(&vk, &prefixed, &signals));
+
+    // Flipping the y sign of A yields a different, invalid proof
+    let mut flipped = compressed_proof.clone();
+    flipped.set(1, flipped.get(1).unwrap() ^ 1);
+    assert!(!client.verify_proof_bytes(&vk, &flipped, &signals));
+
+    // Truncated and unknown-tag points are malformed
+    let truncated = compressed_proof.slice(0..compressed_proof.len() - 1);
+    assert_eq!(
+        client.try_verify_proof_bytes(&vk, &truncated, &signals),
+        Err(Ok(VerifierError::MalformedProof))
+    );
+    let mut bad_tag = compressed_proof.clone();
+    bad_tag.set(1, 0x07);
+    assert_eq!(
+        client.try_verify_proof_bytes(&vk, &bad_tag, &signals),
+        Err(Ok(VerifierError::MalformedProof))
+    );
+}
+
+/// Add two big-endian 256-bit integers, wrapping on overflow
+fn add_be(a: &[u8], b: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let mut carry = 0u16;
+    for i in (0..32).rev() {
+        let sum = a[i] as u16 + b[i] as u16 + carry;
+        out[i] = sum as u8;
+        carry = sum >> 8;
+    }
+    out
+}
+
+#[test]
+fn test_rejects_malleated_proofs() {
+    use darkpool_testdata::{generate, scalar};
+    use zk_bn254::compressed::{negate_g1, negate_g2};
+
+    let env = Env::default();
+    let contract_id = env.register(Groth16VerifierBN254, ());
+    let client = Groth16VerifierBN254Client::new(&env, &contract_id);
+
+    let fixture = generate(9, &[scalar(1), scalar(2)]);
+    let vk = Bytes::from_slice(&env, &fixture.vk);
+    let proof = Bytes::from_slice(&env, &fixture.proof);
+    let signals = Bytes::from_slice(&env, &fixture.signals);
+
+    // (-A, -B, C) is the same proof and normalizes to the same bytes
+    let mut a: [u8; G1_SIZE] = fixture.proof[..64].try_into().unwrap();
+    let mut b: [u8; G2_SIZE] = fixture.proof[64..192].try_into().unwrap();
+    negate_g1(&mut a);
+    negate_g2(&mut b);
+    let mut negated = Bytes::from_array(&env, &a);
+    negated.extend_from_array(&b);
+    negated.append(&proof.slice(192..));
+    assert_ne!(negated, proof);
+    assert_eq!(
+        zk_bn254::normalize_proof_bytes(&env, &negated).unwrap(),
+        zk_bn254::normalize_proof_bytes(&env, &proof).unwrap()
+    );
+    assert!(client.verify_proof_bytes(&vk, &negated, &signals));
+
+    // A signal shifted by the scalar field modulus is rejected, not reduced
+    let r = [
+        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+        0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+    ];
+    let mut shifted = fixture.signals.clone();
+    shifted[4..36].copy_from_slice(&add_be(&fixture.signals[4..36], &r));
+    assert_eq!(
+        client.try_verify_proof_bytes(&vk, &proof, &Bytes::from_slice(&env, &shifted)),
+        Err(Ok(VerifierError::InvalidPublicSignals))
+    );
+
+    // Coordinates shifted by the base field modulus, or moved off the curve
+    let p = [
+        0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+        0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+    ];
+    let mut wide = fixture.proof.clone();
+    wide[32..64].copy_from_slice(&add_be(&fixture.proof[32..64], &p));
+    let mut off_curve = fixture.proof.clone();
+    off_curve[63] ^= 1;
+    for malformed in [wide, off_curve] {
+        assert_eq!(
+            client.try_verify_proof_bytes(&vk, &Bytes::from_slice(&env, &malformed), &signals),
+            Err(Ok(VerifierError::MalformedProof))
+        );
+    }
+}
@@ -57,7 +57,7 @@ impl LeanIMTBN254 {
 
     /// Inserts a new leaf into the tree
     pub fn insert(&mut self, leaf: BytesN<32>) -> Result<(), &'static str> {
-        let current_count = self.leaves.len() as u32;
+        let current_count = self.leaves.len();
 
         if current_count >= self.capacity {
             return Err("Tree is at capacity: cannot insert more leaves");
@@ -85,7 +85,7 @@ impl LeanIMTBN254 {
 
     /// Gets the number of leaves that have been explicitly inserted
     pub fn get_leaf_count(&self) -> u32 {
-        self.leaves.len() as u32
+        self.leaves.len()
     }
 
     /// Gets the maximum capacity of the tree (2^depth)
@@ -100,7 +100,7 @@ impl LeanIMTBN254 {
 
     /// Generates a merkle proof for a given leaf index
     pub fn generate_proof(&self, leaf_index: u32) -> Option<(Vec<Bn254Scalar>, u32)> {
-        if leaf_index >= self.leaves.len() as u32 {
+        if leaf_index >= self.leaves.len() {
             return None;
         }
 
@@ -119,14 +119,14 @@ impl LeanIMTBN254 {
             let mut current_depth = 0;
 
             while current_depth < self.depth {
-                let sibling_index = if current_index % 2 == 0 {
+                let sibling_index = if current_index.is_multiple_of(2) {
                     current_index + 1
                 } else {
                     current_index - 1
                 };
 
                 let sibling_scalar = if current_depth == 0 {
-                    if sibling_index < self.leaves.len() as u32 {
+                    if sibling_index < self.leaves.len() {
                         let sibling_bytes = self.leaves.get(sibling_index).unwrap();
                         bytes_to_bn254_scalar(&sibling_bytes)
                     } else {
@@ -137,7 +137,7 @@ impl LeanIMTBN254 {
                 };
 
                 siblings.push_back(sibling_scalar);
-                current_index = current_index / 2;
+                current_index /= 2;
                 current_depth += 1;
             }
         }
@@ -145,6 +145,46 @@ impl LeanIMTBN254 {
         Some((siblings, self.depth))
     }
 
+    /// Returns the sibling path for a leaf, bottom level first
+    ///
+    /// Builds each level from the stored leaves, padding with empty-subtree
+    /// hashes, so the cost grows with the leaf count rather than the capacity.
+    pub fn generate_path(&self, leaf_index: u32) -> Option<Vec<BytesN<32>>> {
+        if leaf_index >= self.leaves.len() {
+            return None;
+        }
+
+        let mut layer: Vec<Bn254Scalar> = vec![&self.env];
+        for leaf in self.leaves.iter() {
+            layer.push_back(bytes_to_bn254_scalar(&leaf));
+        }
+
+        let mut sponge = Poseidon2Sponge::<3, Bn254Scalar>::new(&self.env);
+        let mut zero = Bn254Scalar::from_u256(U256::from_u32(&self.env, 0));
+        let mut path = vec![&self.env];
+        let mut index = leaf_index;
+
+        for _ in 0..self.depth {
+            let sibling = layer.get(index ^ 1).unwrap_or(zero.clone());
+            path.push_back(bn254_scalar_to_bytes(&sibling));
+
+            let mut parents: Vec<Bn254Scalar> = vec![&self.env];
+            let mut i = 0;
+            while i < layer.len() {
+                let left = layer.get(i).unwrap();
+                let right = layer.get(i + 1).unwrap_or(zero.clone());
+                parents.push_back(self.hash_pair_with_sponge(&mut sponge, left, right));
+                i += 2;
+            }
+
+            layer = parents;
+            zero = self.hash_pair_with_sponge(&mut sponge, zero.clone(), zero);
+            index /= 2;
+        }
+
+        Some(path)
+    }
+
     /// Computes the value of an internal node at a specific level
     fn compute_node_at_level_scalar(&self, node_index: u32, target_level: u32) -> Bn254Scalar {
         if target_level > self.depth {
@@ -156,7 +196,7 @@ impl LeanIMTBN254 {
         }
 
         if target_level == 0 {
-            if node_index < self.leaves.len() as u32 {
+            if node_index < self.leaves.len() {
                 let leaf_bytes = self.leaves.get(node_index).unwrap();
                 bytes_to_bn254_scalar(&leaf_bytes)
             } else {
@@ -176,7 +216,7 @@ impl LeanIMTBN254 {
 
     /// Incremental update using path recomputation
     fn incremental_update(&mut self) {
-        let leaf_index = (self.leaves.len() - 1) as u32;
+        let leaf_index = self.leaves.len() - 1;
 
         let leaf_bytes = self.leaves.get(leaf_index).unwrap();
         let leaf_scalar = bytes_to_bn254_scalar(&leaf_bytes);
@@ -197,14 +237,14 @@ impl LeanIMTBN254 {
         let mut current_scalar = leaf_scalar;
 
         while current_level < self.depth {
-            let sibling_index = if current_index % 2 == 0 {
+            let sibling_index = if current_index.is_multiple_of(2) {
                 current_index + 1
             } else {
                 current_index - 1
             };
 
             let sibling_scalar = if current_level == 0 {
-                if sibling_index < self.leaves.len() as u32 {
+                if sibling_index < self.leaves.len() {
                     let sibling_bytes = self.leaves.get(sibling_index).unwrap();
                     bytes_to_bn254_scalar(&sibling_bytes)
                 } else {
@@ -218,7 +258,7 @@ impl LeanIMTBN254 {
                 }
             };
 
-            let parent_scalar = if current_index % 2 == 0 {
+            let parent_scalar = if current_index.is_multiple_of(2) {
                 self.hash_pair_with_sponge(&mut sponge, current_scalar, sibling_scalar)
             } else {
                 self.hash_pair_with_sponge(&mut sponge, sibling_scalar, current_scalar)
@@ -228,7 +268,7 @@ impl LeanIMTBN254 {
             let parent_level = current_level + 1;
             self.cache_sparse_node(parent_level, parent_index, parent_scalar.clone());
 
-            current_index = current_index / 2;
+            current_index /= 2;
             current_level = parent_level;
             current_scalar = parent_scalar;
         }
@@ -248,7 +288,11 @@ impl LeanIMTBN254 {
         if let Some(cached_value) = self.sparse_cache.get((level, node_index)) {
             return Some(cached_value);
         }
-        self.get_cached_subtree_level(level)
+        // Only subtrees past the last leaf are known to be empty
+        if ((node_index as u64) << level) >= self.leaves.len() as u64 {
+            return self.get_cached_subtree_level(level);
+        }
+        None
     }
 
     fn cache_sparse_node(&mut self, level: u32, node_index: u32, hash: Bn254Scalar) {
@@ -256,6 +300,9 @@ impl LeanIMTBN254 {
     }
 
     /// Rebuilds the cache from the current leaves
+    ///
+    /// Empty-subtree hashes are always cached so that an insert after loading
+    /// from storage does not rehash the empty part of the tree.
     fn rebuild_cache_from_leaves(&mut self) {
         if self.leaves.is_empty() {
             self.recompute_tree();
@@ -263,6 +310,9 @@ impl LeanIMTBN254 {
         }
         self.subtree_cache = Map::new(&self.env);
         self.sparse_cache = Map::new(&self.env);
+        if self.depth > 0 {
+            self.cache_empty_subtrees();
+        }
     }
 
     /// Recomputes the entire tree using dynamic programming for empty trees
@@ -272,6 +322,12 @@ impl LeanIMTBN254 {
             return;
         }
 
+        let root = self.cache_empty_subtrees();
+        self.root = bn254_scalar_to_bytes(&root);
+    }
+
+    /// Caches the hash of an empty subtree at every level, returning the empty root
+    fn cache_empty_subtrees(&mut self) -> Bn254Scalar {
         let mut sponge = Poseidon2Sponge::<3, Bn254Scalar>::new(&self.env);
 
         let zero_scalar = Bn254Scalar::from_u256(U256::from_u32(&self.env, 0));
@@ -290,7 +346,7 @@ impl LeanIMTBN254 {
             self.cache_subtree_level(level, current_level_hash.clone());
         }
 
-        self.root = bn254_scalar_to_bytes(&current_level_hash);
+        current_level_hash
     }
 
     /// Hashes two Bn254Scalar values using Poseidon2 hash function
@@ -350,10 +406,7 @@ impl LeanIMTBN254 {
 
     /// Gets a leaf at a specific index
     pub fn get_leaf(&self, index: usize) -> Option<BytesN<32>> {
-        match self.leaves.get(index.try_into().unwrap()) {
-            Some(leaf) => Some(leaf.clone()),
-            None => None,
-        }
+        self.leaves.get(index.try_into().unwrap())
     }
 
     /// Gets a leaf as Bn254Scalar at a specific index
@@ -414,6 +467,52 @@ mod tests {
 
         let (siblings, depth) = proof.unwrap();
         assert_eq!(depth, 3);
-        assert_eq!(siblings.len() as u32, 3);
+        assert_eq!(siblings.len(), 3);
+    }
+
+    #[test]
+    fn test_generate_path_rebuilds_root() {
+        let env = Env::default();
+        let mut tree = LeanIMTBN254::new(&env, 3);
+        for i in 1..=3u8 {
+            tree.insert(BytesN::from_array(&env, &[i; 32])).unwrap();
+        }
+
+        let leaf_index = 2u32;
+        let path = tree.generate_path(leaf_index).unwrap();
+        assert_eq!(path.len(), 3);
+
+        let mut node = tree.get_leaf_scalar(leaf_index as usize).unwrap();
+        let mut index = leaf_index;
+        for sibling in path.iter() {
+            let sibling = bytes_to_bn254_scalar(&sibling);
+            node = if index.is_multiple_of(2) {
+                tree.hash_pair(node, sibling)
+            } else {
+                tree.hash_pair(sibling, node)
+            };
+            index /= 2;
+        }
+        assert_eq!(bn254_scalar_to_bytes(&node), tree.get_root());
+        assert!(tree.generate_path(3).is_none());
+    }
+
+    #[test]
+    fn test_insert_after_reload_matches_continuous_tree() {
+        let env = Env::default();
+        let mut tree = LeanIMTBN254::new(&env, 20);
+        let mut reloaded = LeanIMTBN254::new(&env, 20);
+        for i in 1..=5u8 {
+            let leaf = BytesN::from_array(&env, &[i; 32]);
+            tree.insert(leaf.clone()).unwrap();
+
+            // Each reload and insert must fit in a single invocation's budget
+            env.cost_estimate().budget().reset_default();
+
+            let (leaves, depth, root) = reloaded.to_storage();
+            reloaded = LeanIMTBN254::from_storage(&env, leaves, depth, root);
+            reloaded.insert(leaf).unwrap();
+            assert_eq!(reloaded.get_root(), tree.get_root());
+        }
     }
 }
@@ -0,0 +1,182 @@
+//! Deterministic Groth16 fixtures for contract tests
+//!
+//! Generates a verification key, proof and public signals from a seed, in
+//! the byte formats read by the verifier contract and `zk-bn254`. The circuit
+//! only squares each public signal, so any signal values can be proven and
+//! tests can pick signals that satisfy the contract's own checks.
+
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, Proof, VerifyingKey};
+use ark_relations::{
+    lc,
+    r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError},
+};
+use ark_std::rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+/// Serialized proof fixture
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProofFixture {
+    /// alpha, beta, gamma, delta, IC count (u32 big-endian), IC points
+    pub vk: Vec<u8>,
+    /// A, B, C points
+    pub proof: Vec<u8>,
+    /// Signal count (u32 big-endian) followed by 32-byte scalars
+    pub signals: Vec<u8>,
+}
+
+/// Circuit with one public input per signal, constrained as `x * x = sq`
+#[derive(Clone)]
+struct SignalCircuit {
+    signals: Vec<Fr>,
+}
+
+impl ConstraintSynthesizer<Fr> for SignalCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        for x in self.signals {
+            let input = cs.new_input_variable(|| Ok(x))?;
+            let square = cs.new_witness_variable(|| Ok(x * x))?;
+            cs.enforce_constraint(lc!() + input, lc!() + input, lc!() + square)?;
+        }
+        Ok(())
+    }
+}
+
+/// Generate a valid proof over the given public signals
+///
+/// The same seed and signal count always give the same verification key, so
+/// a key stored in a contract keeps accepting proofs generated later for
+/// other signals. Signals are reduced modulo the BN254 scalar field.
+///
+/// # Arguments
+/// * `seed` - Seed for the trusted setup and prover randomness
+/// * `signals` - Big-endian public signals, in the order the contract reads them
+pub fn generate(seed: u64, signals: &[[u8; 32]]) -> ProofFixture {
+    let signals: Vec<Fr> = signals.iter().map(|s| Fr::from_be_bytes_mod_order(s)).collect();
+    let circuit = SignalCircuit { signals: signals.clone() };
+
+    let mut rng = ChaCha20Rng::seed_from_u64(seed);
+    let pk = Groth16::<Bn254>::generate_random_parameters_with_reduction(circuit.clone(), &mut rng)
+        .expect("setup failed");
+    let proof = Groth16::<Bn254>::create_random_proof_with_reduction(circuit, &pk, &mut rng)
+        .expect("proving failed");
+
+    ProofFixture {
+        vk: encode_vk(&pk.vk),
+        proof: encode_proof(&proof),
+        signals: encode_signals(&signals),
+    }
+}
+
+/// Encode a small integer as a public signal
+pub fn scalar(value: u64) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[24..].copy_from_slice(&value.to_be_bytes());
+    out
+}
+
+/// Encode a G2 point that is on the twist but outside the prime-order subgroup
+///
+/// Such a point passes the on-curve check, so it exercises subgroup
+/// validation of verification keys.
+pub fn off_subgroup_g2() -> [u8; 128] {
+    let point = (1u64..)
+        .filter_map(|x| G2Affine::get_point_from_x_unchecked(Fq2::from(x), false))
+        .find(|point| !point.is_in_correct_subgroup_assuming_on_curve())
+        .unwrap();
+    let mut out = Vec::new();
+    push_g2(&mut out, &point);
+    out.try_into().unwrap()
+}
+
+fn encode_vk(vk: &VerifyingKey<Bn254>) -> Vec<u8> {
+    let mut out = Vec::new();
+    push_g1(&mut out, &vk.alpha_g1);
+    push_g2(&mut out, &vk.beta_g2);
+    push_g2(&mut out, &vk.gamma_g2);
+    push_g2(&mut out, &vk.delta_g2);
+    out.extend_from_slice(&(vk.gamma_abc_g1.len() as u32).to_be_bytes());
+    for point in &vk.gamma_abc_g1 {
+        push_g1(&mut out, point);
+    }
+    out
+}
+
+fn encode_proof(proof: &Proof<Bn254>) -> Vec<u8> {
+    let mut out = Vec::new();
+    push_g1(&mut out, &proof.a);
+    push_g2(&mut out, &proof.b);
+    push_g1(&mut out, &proof.c);
+    out
+}
+
+fn encode_signals(signals: &[Fr]) -> Vec<u8> {
+    let mut out = Vec::new();
+    out.extend_from_slice(&(signals.len() as u32).to_be_bytes());
+    for s in signals {
+        out.extend_from_slice(&s.into_bigint().to_bytes_be());
+    }
+    out
+}
+
+fn push_fq(out: &mut Vec<u8>, f: &Fq) {
+    out.extend_from_slice(&f.into_bigint().to_bytes_be());
+}
+
+/// Fp2 elements are written imaginary part first: `c1 || c0`
+fn push_fq2(out: &mut Vec<u8>, f: &Fq2) {
+    push_fq(out, &f.c1);
+    push_fq(out, &f.c0);
+}
+
+fn push_g1(out: &mut Vec<u8>, p: &G1Affine) {
+    push_fq(out, &p.x);
+    push_fq(out, &p.y);
+}
+
+fn push_g2(out: &mut Vec<u8>, p: &G2Affine) {
+    push_fq2(out, &p.x);
+    push_fq2(out, &p.y);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{Bytes, Env};
+
+    fn verify(env: &Env, fixture: &ProofFixture) -> bool {
+        zk_bn254::verify_groth16_bytes(
+            env,
+            &Bytes::from_slice(env, &fixture.vk),
+            &Bytes::from_slice(env, &fixture.proof),
+            &Bytes::from_slice(env, &fixture.signals),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_fixture_verifies_on_host() {
+        let env = Env::default();
+        let fixture = generate(7, &[scalar(1), scalar(42), [9u8; 32]]);
+        assert!(verify(&env, &fixture));
+    }
+
+    #[test]
+    fn test_fixture_is_deterministic() {
+        let signals = [scalar(3), scalar(5)];
+        assert_eq!(generate(1, &signals), generate(1, &signals));
+        assert_ne!(generate(1, &signals).vk, generate(2, &signals).vk);
+
+        // The key depends only on the seed and signal count
+        assert_eq!(generate(1, &signals).vk, generate(1, &[scalar(4), scalar(6)]).vk);
+    }
+
+    #[test]
+    fn test_proof_bound_to_signals() {
+        let env = Env::default();
+        let mut fixture = generate(3, &[scalar(10), scalar(20)]);
+        fixture.signals = generate(3, &[scalar(10), scalar(21)]).signals;
+        assert!(!verify(&env, &fixture));
+    }
+}
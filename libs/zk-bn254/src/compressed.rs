@@ -0,0 +1,428 @@
+//! Compressed BN254 point encodings and point validation
+//!
+//! A compressed point is a tag byte followed by its x coordinate, 33 bytes
+//! for G1 and 65 for G2. The tag is `0x02` or `0x03` for the sign of y, or
+//! `0x00` with a zero x for the point at infinity; y is recovered from the
+//! curve equation. Fp2 coordinates keep the host's `c1 || c0` order, and the
+//! sign of an Fp2 element is the parity of `c0`, or of `c1` when `c0` is zero.
+//!
+//! The host only accepts uncompressed points, so decompression runs in the
+//! contract. The square roots it needs are computed with the small Montgomery
+//! field implementation below, which also backs the on-curve checks used to
+//! reject malformed points before they reach the host.
+
+use crate::{BN254_G1_SERIALIZED_SIZE, BN254_G2_SERIALIZED_SIZE};
+
+/// Size of a compressed G1 point (tag byte and x)
+pub const BN254_G1_COMPRESSED_SIZE: usize = 33;
+
+/// Size of a compressed G2 point (tag byte and x as `c1 || c0`)
+pub const BN254_G2_COMPRESSED_SIZE: usize = 65;
+
+const TAG_INFINITY: u8 = 0x00;
+const TAG_EVEN: u8 = 0x02;
+const TAG_ODD: u8 = 0x03;
+
+// Base field modulus, little-endian limbs
+const MODULUS: [u64; 4] = [0x3c208c16d87cfd47, 0x97816a916871ca8d, 0xb85045b68181585d, 0x30644e72e131a029];
+// -p^-1 mod 2^64
+const INV: u64 = 0x87d20782e4866389;
+// 2^512 mod p, for converting into Montgomery form
+const R2: [u64; 4] = [0xf32cfc5b538afa89, 0xb5e71911d44501fb, 0x47ab1eff0a417ff6, 0x06d89f71cab8351f];
+// (p + 1) / 4, (p - 3) / 4 and (p - 1) / 2 for square roots (p = 3 mod 4)
+const EXP_SQRT: [u64; 4] = [0x4f082305b61f3f52, 0x65e05aa45a1c72a3, 0x6e14116da0605617, 0x0c19139cb84c680a];
+const EXP_SQRT_FP2: [u64; 4] = [0x4f082305b61f3f51, 0x65e05aa45a1c72a3, 0x6e14116da0605617, 0x0c19139cb84c680a];
+const EXP_HALF: [u64; 4] = [0x9e10460b6c3e7ea3, 0xcbc0b548b438e546, 0xdc2822db40c0ac2e, 0x183227397098d014];
+// G2 curve coefficient 3 / (9 + u)
+const B2_C0: [u64; 4] = [0x3267e6dc24a138e5, 0xb5b4c5e559dbefa3, 0x81be18991be06ac3, 0x2b149d40ceb8aaae];
+const B2_C1: [u64; 4] = [0xe4a2bd0685c315d2, 0xa74fa084e52d1852, 0xcd2cafadeed8fdf4, 0x009713b03af0fed4];
+
+/// Compress an uncompressed G1 point
+///
+/// The input is not checked to be on the curve.
+pub fn compress_g1(point: &[u8; BN254_G1_SERIALIZED_SIZE]) -> [u8; BN254_G1_COMPRESSED_SIZE] {
+    let mut out = [0u8; BN254_G1_COMPRESSED_SIZE];
+    if point.iter().all(|b| *b == 0) {
+        return out;
+    }
+    out[0] = if point[63] & 1 == 1 { TAG_ODD } else { TAG_EVEN };
+    out[1..].copy_from_slice(&point[..32]);
+    out
+}
+
+/// Compress an uncompressed G2 point
+///
+/// The input is not checked to be on the curve.
+pub fn compress_g2(point: &[u8; BN254_G2_SERIALIZED_SIZE]) -> [u8; BN254_G2_COMPRESSED_SIZE] {
+    let mut out = [0u8; BN254_G2_COMPRESSED_SIZE];
+    if point.iter().all(|b| *b == 0) {
+        return out;
+    }
+    // y is `c1 || c0`; its sign is c0's parity unless c0 is zero
+    let c0_zero = point[96..].iter().all(|b| *b == 0);
+    let odd = if c0_zero { point[95] & 1 == 1 } else { point[127] & 1 == 1 };
+    out[0] = if odd { TAG_ODD } else { TAG_EVEN };
+    out[1..].copy_from_slice(&point[..64]);
+    out
+}
+
+/// Recover an uncompressed G1 point, or `None` if x is not on the curve
+pub fn decompress_g1(bytes: &[u8; BN254_G1_COMPRESSED_SIZE]) -> Option<[u8; BN254_G1_SERIALIZED_SIZE]> {
+    let mut out = [0u8; BN254_G1_SERIALIZED_SIZE];
+    let x_bytes: &[u8; 32] = bytes[1..].try_into().unwrap();
+    if bytes[0] == TAG_INFINITY {
+        return x_bytes.iter().all(|b| *b == 0).then_some(out);
+    }
+    let odd = match bytes[0] {
+        TAG_EVEN => false,
+        TAG_ODD => true,
+        _ => return None,
+    };
+
+    let x = Fp::from_be_bytes(x_bytes)?;
+    let mut y = x.square().mul(&x).add(&Fp::from_u64(3)).sqrt()?;
+    if y.is_odd() != odd {
+        y = y.neg();
+    }
+    out[..32].copy_from_slice(x_bytes);
+    out[32..].copy_from_slice(&y.to_be_bytes());
+    Some(out)
+}
+
+/// Recover an uncompressed G2 point, or `None` if x is not on the curve
+///
+/// Subgroup membership is left to the host, which checks it on use.
+pub fn decompress_g2(bytes: &[u8; BN254_G2_COMPRESSED_SIZE]) -> Option<[u8; BN254_G2_SERIALIZED_SIZE]> {
+    let mut out = [0u8; BN254_G2_SERIALIZED_SIZE];
+    if bytes[0] == TAG_INFINITY {
+        return bytes[1..].iter().all(|b| *b == 0).then_some(out);
+    }
+    let odd = match bytes[0] {
+        TAG_EVEN => false,
+        TAG_ODD => true,
+        _ => return None,
+    };
+
+    let x = Fp2::from_be_bytes(bytes[1..].try_into().unwrap())?;
+    let b = Fp2 {
+        c0: Fp::from_raw(B2_C0),
+        c1: Fp::from_raw(B2_C1),
+    };
+    let mut y = x.square().mul(&x).add(&b).sqrt()?;
+    if y.sign() != odd {
+        y = y.neg();
+    }
+    out[..64].copy_from_slice(&bytes[1..]);
+    out[64..].copy_from_slice(&y.to_be_bytes());
+    Some(out)
+}
+
+/// Check an uncompressed G1 point is canonical and on the curve
+///
+/// Coordinates must be below the field modulus, which also rules out the
+/// host's flag bits. G1 has cofactor 1, so this is also the subgroup check.
+pub fn is_valid_g1(point: &[u8; BN254_G1_SERIALIZED_SIZE]) -> bool {
+    if point.iter().all(|b| *b == 0) {
+        return true;
+    }
+    let (Some(x), Some(y)) = (
+        Fp::from_be_bytes(point[..32].try_into().unwrap()),
+        Fp::from_be_bytes(point[32..].try_into().unwrap()),
+    ) else {
+        return false;
+    };
+    y.square() == x.square().mul(&x).add(&Fp::from_u64(3))
+}
+
+/// Check an uncompressed G2 point is canonical and on the twist
+///
+/// Subgroup membership is left to the host, which checks it on use.
+pub fn is_valid_g2(point: &[u8; BN254_G2_SERIALIZED_SIZE]) -> bool {
+    if point.iter().all(|b| *b == 0) {
+        return true;
+    }
+    let (Some(x), Some(y)) = (
+        Fp2::from_be_bytes(point[..64].try_into().unwrap()),
+        Fp2::from_be_bytes(point[64..].try_into().unwrap()),
+    ) else {
+        return false;
+    };
+    let b = Fp2 {
+        c0: Fp::from_raw(B2_C0),
+        c1: Fp::from_raw(B2_C1),
+    };
+    y.square() == x.square().mul(&x).add(&b)
+}
+
+/// Negate a valid uncompressed G1 point in place
+pub fn negate_g1(point: &mut [u8; BN254_G1_SERIALIZED_SIZE]) {
+    if let Some(y) = Fp::from_be_bytes(point[32..].try_into().unwrap()) {
+        point[32..].copy_from_slice(&y.neg().to_be_bytes());
+    }
+}
+
+/// Negate a valid uncompressed G2 point in place
+pub fn negate_g2(point: &mut [u8; BN254_G2_SERIALIZED_SIZE]) {
+    if let Some(y) = Fp2::from_be_bytes(point[64..].try_into().unwrap()) {
+        point[64..].copy_from_slice(&y.neg().to_be_bytes());
+    }
+}
+
+/// Base field element in Montgomery form, little-endian limbs
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fp([u64; 4]);
+
+impl Fp {
+    const ZERO: Fp = Fp([0; 4]);
+
+    fn from_raw(limbs: [u64; 4]) -> Fp {
+        Fp(limbs).mul(&Fp(R2))
+    }
+
+    fn from_u64(value: u64) -> Fp {
+        Fp::from_raw([value, 0, 0, 0])
+    }
+
+    /// Parse a big-endian element, rejecting values not below the modulus
+    fn from_be_bytes(bytes: &[u8; 32]) -> Option<Fp> {
+        let mut limbs = [0u64; 4];
+        for (i, limb) in limbs.iter_mut().enumerate() {
+            let start = 32 - 8 * (i + 1);
+            *limb = u64::from_be_bytes(bytes[start..start + 8].try_into().unwrap());
+        }
+        if !lt(&limbs, &MODULUS) {
+            return None;
+        }
+        Some(Fp::from_raw(limbs))
+    }
+
+    fn to_be_bytes(self) -> [u8; 32] {
+        let limbs = self.mul(&Fp([1, 0, 0, 0])).0;
+        let mut out = [0u8; 32];
+        for (i, limb) in limbs.iter().enumerate() {
+            let start = 32 - 8 * (i + 1);
+            out[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+        }
+        out
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Fp::ZERO
+    }
+
+    fn is_odd(&self) -> bool {
+        self.mul(&Fp([1, 0, 0, 0])).0[0] & 1 == 1
+    }
+
+    fn add(&self, rhs: &Fp) -> Fp {
+        let mut out = [0u64; 4];
+        let mut carry = 0u128;
+        for (i, limb) in out.iter_mut().enumerate() {
+            let sum = self.0[i] as u128 + rhs.0[i] as u128 + carry;
+            *limb = sum as u64;
+            carry = sum >> 64;
+        }
+        // p < 2^254, so the sum never carries out of the top limb
+        if !lt(&out, &MODULUS) {
+            out = sub_limbs(&out, &MODULUS);
+        }
+        Fp(out)
+    }
+
+    fn neg(&self) -> Fp {
+        if self.is_zero() {
+            return *self;
+        }
+        Fp(sub_limbs(&MODULUS, &self.0))
+    }
+
+    fn sub(&self, rhs: &Fp) -> Fp {
+        self.add(&rhs.neg())
+    }
+
+    /// Montgomery multiplication (CIOS)
+    fn mul(&self, rhs: &Fp) -> Fp {
+        let mut t = [0u64; 6];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for (tj, aj) in t.iter_mut().zip(self.0) {
+                let v = *tj as u128 + aj as u128 * rhs.0[i] as u128 + carry;
+                *tj = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[4] as u128 + carry;
+            t[4] = v as u64;
+            t[5] = (v >> 64) as u64;
+
+            let m = t[0].wrapping_mul(INV);
+            let mut carry = (t[0] as u128 + m as u128 * MODULUS[0] as u128) >> 64;
+            for j in 1..4 {
+                let v = t[j] as u128 + m as u128 * MODULUS[j] as u128 + carry;
+                t[j - 1] = v as u64;
+                carry = v >> 64;
+            }
+            let v = t[4] as u128 + carry;
+            t[3] = v as u64;
+            t[4] = t[5] + (v >> 64) as u64;
+        }
+
+        let mut out = [t[0], t[1], t[2], t[3]];
+        if t[4] != 0 || !lt(&out, &MODULUS) {
+            out = sub_limbs(&out, &MODULUS);
+        }
+        Fp(out)
+    }
+
+    fn square(&self) -> Fp {
+        self.mul(self)
+    }
+
+    fn pow(&self, exp: &[u64; 4]) -> Fp {
+        let mut acc = Fp::from_u64(1);
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> bit) & 1 == 1 {
+                    acc = acc.mul(self);
+                }
+            }
+        }
+        acc
+    }
+
+    fn sqrt(&self) -> Option<Fp> {
+        let root = self.pow(&EXP_SQRT);
+        (root.square() == *self).then_some(root)
+    }
+}
+
+/// Quadratic extension element `c0 + c1 * u` with `u^2 = -1`
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fp2 {
+    c0: Fp,
+    c1: Fp,
+}
+
+impl Fp2 {
+    /// Parse a `c1 || c0` big-endian element
+    fn from_be_bytes(bytes: &[u8; 64]) -> Option<Fp2> {
+        Some(Fp2 {
+            c1: Fp::from_be_bytes(bytes[..32].try_into().unwrap())?,
+            c0: Fp::from_be_bytes(bytes[32..].try_into().unwrap())?,
+        })
+    }
+
+    fn to_be_bytes(self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(&self.c1.to_be_bytes());
+        out[32..].copy_from_slice(&self.c0.to_be_bytes());
+        out
+    }
+
+    fn one() -> Fp2 {
+        Fp2 {
+            c0: Fp::from_u64(1),
+            c1: Fp::ZERO,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn sign(&self) -> bool {
+        if self.c0.is_zero() { self.c1.is_odd() } else { self.c0.is_odd() }
+    }
+
+    fn add(&self, rhs: &Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.c0.add(&rhs.c0),
+            c1: self.c1.add(&rhs.c1),
+        }
+    }
+
+    fn neg(&self) -> Fp2 {
+        Fp2 {
+            c0: self.c0.neg(),
+            c1: self.c1.neg(),
+        }
+    }
+
+    fn conjugate(&self) -> Fp2 {
+        Fp2 {
+            c0: self.c0,
+            c1: self.c1.neg(),
+        }
+    }
+
+    fn mul(&self, rhs: &Fp2) -> Fp2 {
+        Fp2 {
+            c0: self.c0.mul(&rhs.c0).sub(&self.c1.mul(&rhs.c1)),
+            c1: self.c0.mul(&rhs.c1).add(&self.c1.mul(&rhs.c0)),
+        }
+    }
+
+    fn square(&self) -> Fp2 {
+        self.mul(self)
+    }
+
+    fn pow(&self, exp: &[u64; 4]) -> Fp2 {
+        let mut acc = Fp2::one();
+        for limb in exp.iter().rev() {
+            for bit in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> bit) & 1 == 1 {
+                    acc = acc.mul(self);
+                }
+            }
+        }
+        acc
+    }
+
+    /// Square root for p = 3 mod 4 (Adj and Rodríguez-Henríquez, algorithm 9)
+    fn sqrt(&self) -> Option<Fp2> {
+        if self.is_zero() {
+            return Some(*self);
+        }
+        let minus_one = Fp2::one().neg();
+
+        let a1 = self.pow(&EXP_SQRT_FP2);
+        let alpha = a1.square().mul(self);
+        // alpha^(p+1) is its norm, since the Frobenius map is conjugation
+        if alpha.conjugate().mul(&alpha) == minus_one {
+            return None;
+        }
+        let x0 = a1.mul(self);
+        let root = if alpha == minus_one {
+            Fp2 {
+                c0: x0.c1.neg(),
+                c1: x0.c0,
+            }
+        } else {
+            alpha.add(&Fp2::one()).pow(&EXP_HALF).mul(&x0)
+        };
+        (root.square() == *self).then_some(root)
+    }
+}
+
+fn lt(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// `a - b` for `a >= b`
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut out = [0u64; 4];
+    let mut borrow = 0u64;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        out[i] = d;
+        borrow = (b1 || b2) as u64;
+    }
+    out
+}
@@ -1,7 +1,18 @@
 #![no_std]
 
+use soroban_poseidon::PoseidonSponge;
 use soroban_sdk::{
-    contracterror, contracttype, Bytes, BytesN, Env, Vec, U256,
+    contracterror, contracttype,
+    crypto::bn254::{Bn254G1Affine, Bn254G2Affine, Fr},
+    symbol_short, vec, Bytes, BytesN, Env, Vec, U256,
+};
+
+pub mod compressed;
+mod poseidon6;
+
+use compressed::{
+    compress_g1, compress_g2, decompress_g1, decompress_g2, is_valid_g1, is_valid_g2, negate_g1,
+    negate_g2, BN254_G1_COMPRESSED_SIZE, BN254_G2_COMPRESSED_SIZE,
 };
 
 /// Size of serialized BN254 G1 affine point (32 bytes x + 32 bytes y)
@@ -13,6 +24,33 @@ pub const BN254_G2_SERIALIZED_SIZE: usize = 128;
 /// Size of BN254 scalar field element (Fr)
 pub const BN254_FR_SIZE: usize = 32;
 
+/// Maximum public signals accepted in one proof, checked before parsing
+pub const MAX_PUBLIC_SIGNALS: u32 = 32;
+
+/// BN254 scalar field modulus r, big-endian
+const BN254_FR_MODULUS: [u8; BN254_FR_SIZE] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// Check a big-endian public signal is below the scalar field modulus
+///
+/// The pairing check reduces signals modulo r, so `s` and `s + r` verify
+/// against the same proof. Contracts that key state on signal bytes, such as
+/// nullifiers, must reject the non-canonical form.
+pub fn is_canonical_scalar(bytes: &[u8; BN254_FR_SIZE]) -> bool {
+    *bytes < BN254_FR_MODULUS
+}
+
+/// Format byte for a proof or key blob with uncompressed points
+///
+/// Format bytes have the top bit set, which no serialized field element can
+/// start with, so blobs without one are read as uncompressed.
+pub const ENCODING_UNCOMPRESSED: u8 = 0x80;
+
+/// Format byte for a proof or key blob with compressed points
+pub const ENCODING_COMPRESSED: u8 = 0x81;
+
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
 #[repr(u32)]
@@ -21,6 +59,7 @@ pub enum ZkError {
     MalformedProof = 2,
     MalformedPublicSignals = 3,
     InvalidInputLength = 4,
+    TooManySignals = 5,
 }
 
 /// BN254 Groth16 Verification Key
@@ -48,7 +87,7 @@ impl VerificationKeyBN254 {
         bytes.append(&Bytes::from_slice(env, self.gamma.to_array().as_slice()));
         bytes.append(&Bytes::from_slice(env, self.delta.to_array().as_slice()));
         // Serialize ic length as u32 (big endian)
-        let ic_len = self.ic.len() as u32;
+        let ic_len = self.ic.len();
         let ic_len_bytes = ic_len.to_be_bytes();
         bytes.append(&Bytes::from_slice(env, &ic_len_bytes));
         for g1 in self.ic.iter() {
@@ -57,8 +96,9 @@ impl VerificationKeyBN254 {
         bytes
     }
 
-    /// Deserialize verification key from bytes
+    /// Deserialize verification key from bytes, in either point encoding
     pub fn from_bytes(env: &Env, bytes: &Bytes) -> Result<Self, ZkError> {
+        let bytes = &normalize_vk_bytes(env, bytes)?;
         let mut pos = 0usize;
         
         fn take<const N: usize>(bytes: &Bytes, pos: &mut usize) -> Result<[u8; N], ZkError> {
@@ -120,8 +160,9 @@ impl ProofBN254 {
         bytes
     }
 
-    /// Deserialize proof from bytes
+    /// Deserialize proof from bytes, in either point encoding
     pub fn from_bytes(env: &Env, bytes: &Bytes) -> Result<Self, ZkError> {
+        let bytes = &normalize_proof_bytes(env, bytes)?;
         let mut pos = 0usize;
         
         fn take<const N: usize>(bytes: &Bytes, pos: &mut usize) -> Result<[u8; N], ZkError> {
@@ -162,7 +203,7 @@ impl PublicSignalsBN254 {
     /// Serialize public signals to bytes
     pub fn to_bytes(&self, env: &Env) -> Bytes {
         let mut bytes = Bytes::new(env);
-        let len = self.signals.len() as u32;
+        let len = self.signals.len();
         let len_bytes = len.to_be_bytes();
         bytes.append(&Bytes::from_slice(env, &len_bytes));
         for signal in self.signals.iter() {
@@ -188,11 +229,20 @@ impl PublicSignalsBN254 {
         }
 
         let len_bytes = take::<4>(bytes, &mut pos)?;
-        let len = u32::from_be_bytes(len_bytes) as usize;
+        let len = u32::from_be_bytes(len_bytes);
+        if len > MAX_PUBLIC_SIGNALS {
+            return Err(ZkError::TooManySignals);
+        }
         let mut signals = Vec::new(env);
         for _ in 0..len {
-            let signal = BytesN::from_array(env, &take::<32>(bytes, &mut pos)?);
-            signals.push_back(signal);
+            let signal = take::<32>(bytes, &mut pos)?;
+            if !is_canonical_scalar(&signal) {
+                return Err(ZkError::MalformedPublicSignals);
+            }
+            signals.push_back(BytesN::from_array(env, &signal));
+        }
+        if pos != bytes.len() as usize {
+            return Err(ZkError::MalformedPublicSignals);
         }
         
         Ok(PublicSignalsBN254 { signals })
@@ -214,6 +264,390 @@ impl PublicSignalsBN254 {
     }
 }
 
+/// Point layout of a serialized proof: A, B, C
+const PROOF_LAYOUT: [bool; 3] = [false, true, false];
+
+/// Point layout of a serialized key before the IC count: alpha, beta, gamma, delta
+const VK_LAYOUT: [bool; 4] = [false, true, true, true];
+
+/// Convert a proof blob to its canonical unprefixed uncompressed encoding
+///
+/// Accepts unprefixed blobs and blobs starting with `ENCODING_UNCOMPRESSED`
+/// or `ENCODING_COMPRESSED`. Every point must have canonical coordinates and
+/// lie on the curve. Since `(-A, -B, C)` verifies exactly when `(A, B, C)`
+/// does, the pair is flipped when needed so A always has an even y, giving
+/// each proof a single encoding.
+pub fn normalize_proof_bytes(env: &Env, bytes: &Bytes) -> Result<Bytes, ZkError> {
+    let uncompressed = match PointReader::new(bytes) {
+        Some(mut reader) => {
+            let mut out = Bytes::new(env);
+            for is_g2 in PROOF_LAYOUT {
+                reader.point(&mut out, is_g2).ok_or(ZkError::MalformedProof)?;
+            }
+            reader.finish().then_some(out).ok_or(ZkError::MalformedProof)?
+        }
+        None => bytes.clone(),
+    };
+    if uncompressed.len() as usize != 2 * BN254_G1_SERIALIZED_SIZE + BN254_G2_SERIALIZED_SIZE {
+        return Err(ZkError::MalformedProof);
+    }
+
+    let mut a = [0u8; BN254_G1_SERIALIZED_SIZE];
+    let mut b = [0u8; BN254_G2_SERIALIZED_SIZE];
+    let mut c = [0u8; BN254_G1_SERIALIZED_SIZE];
+    uncompressed.slice(0..64).copy_into_slice(&mut a);
+    uncompressed.slice(64..192).copy_into_slice(&mut b);
+    uncompressed.slice(192..256).copy_into_slice(&mut c);
+    if !is_valid_g1(&a) || !is_valid_g2(&b) || !is_valid_g1(&c) {
+        return Err(ZkError::MalformedProof);
+    }
+    if a[63] & 1 == 1 {
+        negate_g1(&mut a);
+        negate_g2(&mut b);
+    }
+
+    let mut out = Bytes::from_array(env, &a);
+    out.extend_from_array(&b);
+    out.extend_from_array(&c);
+    Ok(out)
+}
+
+/// Convert a verification key blob to the unprefixed uncompressed encoding
+///
+/// Accepts the same format bytes as `normalize_proof_bytes`. Points are
+/// checked to be canonical and on the curve.
+pub fn normalize_vk_bytes(env: &Env, bytes: &Bytes) -> Result<Bytes, ZkError> {
+    let mut reader = match PointReader::new(bytes) {
+        Some(reader) => reader,
+        None => PointReader::unprefixed(bytes),
+    };
+    let mut out = Bytes::new(env);
+    let err = ZkError::MalformedVerificationKey;
+    for is_g2 in VK_LAYOUT {
+        reader.point(&mut out, is_g2).ok_or(err)?;
+    }
+    let ic_len = reader.raw::<4>().ok_or(err)?;
+    if u32::from_be_bytes(ic_len) > MAX_PUBLIC_SIGNALS + 1 {
+        return Err(err);
+    }
+    out.extend_from_array(&ic_len);
+    for _ in 0..u32::from_be_bytes(ic_len) {
+        reader.point(&mut out, false).ok_or(err)?;
+    }
+    reader.finish().then_some(out).ok_or(err)
+}
+
+/// Re-encode an unprefixed uncompressed proof with compressed points
+pub fn compress_proof_bytes(env: &Env, bytes: &Bytes) -> Result<Bytes, ZkError> {
+    let mut out = Bytes::from_array(env, &[ENCODING_COMPRESSED]);
+    let mut pos = 0u32;
+    for is_g2 in PROOF_LAYOUT {
+        compress_point(bytes, &mut pos, &mut out, is_g2).ok_or(ZkError::MalformedProof)?;
+    }
+    (pos == bytes.len()).then_some(out).ok_or(ZkError::MalformedProof)
+}
+
+/// Re-encode an unprefixed uncompressed verification key with compressed points
+pub fn compress_vk_bytes(env: &Env, bytes: &Bytes) -> Result<Bytes, ZkError> {
+    let err = ZkError::MalformedVerificationKey;
+    let mut out = Bytes::from_array(env, &[ENCODING_COMPRESSED]);
+    let mut pos = 0u32;
+    for is_g2 in VK_LAYOUT {
+        compress_point(bytes, &mut pos, &mut out, is_g2).ok_or(err)?;
+    }
+    if pos + 4 > bytes.len() {
+        return Err(err);
+    }
+    let mut ic_len = [0u8; 4];
+    bytes.slice(pos..pos + 4).copy_into_slice(&mut ic_len);
+    out.extend_from_array(&ic_len);
+    pos += 4;
+    for _ in 0..u32::from_be_bytes(ic_len) {
+        compress_point(bytes, &mut pos, &mut out, false).ok_or(err)?;
+    }
+    (pos == bytes.len()).then_some(out).ok_or(err)
+}
+
+fn compress_point(bytes: &Bytes, pos: &mut u32, out: &mut Bytes, is_g2: bool) -> Option<()> {
+    let size = if is_g2 { BN254_G2_SERIALIZED_SIZE } else { BN254_G1_SERIALIZED_SIZE } as u32;
+    if *pos + size > bytes.len() {
+        return None;
+    }
+    let point = bytes.slice(*pos..*pos + size);
+    *pos += size;
+    if is_g2 {
+        let mut arr = [0u8; BN254_G2_SERIALIZED_SIZE];
+        point.copy_into_slice(&mut arr);
+        out.extend_from_array(&compress_g2(&arr));
+    } else {
+        let mut arr = [0u8; BN254_G1_SERIALIZED_SIZE];
+        point.copy_into_slice(&mut arr);
+        out.extend_from_array(&compress_g1(&arr));
+    }
+    Some(())
+}
+
+/// Reads the points of a blob that starts with a format byte
+struct PointReader<'a> {
+    bytes: &'a Bytes,
+    pos: u32,
+    compressed: bool,
+}
+
+impl<'a> PointReader<'a> {
+    /// `None` for an unprefixed blob
+    fn new(bytes: &'a Bytes) -> Option<Self> {
+        let compressed = match bytes.first()? {
+            ENCODING_UNCOMPRESSED => false,
+            ENCODING_COMPRESSED => true,
+            _ => return None,
+        };
+        Some(PointReader { bytes, pos: 1, compressed })
+    }
+
+    /// Reader for a legacy blob without a format byte
+    fn unprefixed(bytes: &'a Bytes) -> Self {
+        PointReader {
+            bytes,
+            pos: 0,
+            compressed: false,
+        }
+    }
+
+    fn raw<const N: usize>(&mut self) -> Option<[u8; N]> {
+        if self.pos + N as u32 > self.bytes.len() {
+            return None;
+        }
+        let mut arr = [0u8; N];
+        self.bytes.slice(self.pos..self.pos + N as u32).copy_into_slice(&mut arr);
+        self.pos += N as u32;
+        Some(arr)
+    }
+
+    /// Append the next point to `out`, uncompressed
+    ///
+    /// Uncompressed points are checked here; decompressed ones are on the
+    /// curve by construction.
+    fn point(&mut self, out: &mut Bytes, is_g2: bool) -> Option<()> {
+        match (self.compressed, is_g2) {
+            (false, false) => {
+                let point = self.raw::<BN254_G1_SERIALIZED_SIZE>()?;
+                is_valid_g1(&point).then(|| out.extend_from_array(&point))
+            }
+            (false, true) => {
+                let point = self.raw::<BN254_G2_SERIALIZED_SIZE>()?;
+                is_valid_g2(&point).then(|| out.extend_from_array(&point))
+            }
+            (true, false) => {
+                out.extend_from_array(&decompress_g1(&self.raw::<BN254_G1_COMPRESSED_SIZE>()?)?);
+                Some(())
+            }
+            (true, true) => {
+                out.extend_from_array(&decompress_g2(&self.raw::<BN254_G2_COMPRESSED_SIZE>()?)?);
+                Some(())
+            }
+        }
+    }
+
+    /// Whether the whole blob was consumed
+    fn finish(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+}
+
+/// Fold public signals into the single input of a hashed-input circuit
+///
+/// Computes `h = Poseidon(h, s)` over the signals in order, starting from
+/// `h = 0`, with circom's two-input Poseidon, so a circuit chaining
+/// `Poseidon(2)` templates the same way exposes `h` as its only public
+/// input. Signals must be canonical scalars.
+pub fn hash_public_signals(env: &Env, signals: &PublicSignalsBN254) -> BytesN<32> {
+    let mut sponge = PoseidonSponge::<3, Fr>::new(env);
+    let mut acc = U256::from_u32(env, 0);
+    for signal in signals.signals.iter() {
+        let inputs = vec![env, acc, bytes32_to_u256(env, &signal)];
+        acc = sponge.compute_hash(&inputs);
+    }
+    u256_to_bytes32(env, &acc)
+}
+
+/// Nullifier output by the settlement circuit
+///
+/// `Poseidon(buyCommitment, sellCommitment, matchedQuantity, combinedSecret)`
+/// with circom's four-input Poseidon, where `combinedSecret` is the sum of
+/// the two order secrets in the scalar field. Every input must be a
+/// canonical scalar.
+pub fn settlement_nullifier(
+    env: &Env,
+    buy_commitment: &BytesN<32>,
+    sell_commitment: &BytesN<32>,
+    quantity: &BytesN<32>,
+    combined_secret: &BytesN<32>,
+) -> Result<BytesN<32>, ZkError> {
+    let inputs = [buy_commitment, sell_commitment, quantity, combined_secret];
+    if inputs.iter().any(|input| !is_canonical_scalar(&input.to_array())) {
+        return Err(ZkError::MalformedPublicSignals);
+    }
+    let mut values = Vec::new(env);
+    for input in inputs {
+        values.push_back(bytes32_to_u256(env, input));
+    }
+    Ok(u256_to_bytes32(env, &PoseidonSponge::<5, Fr>::new(env).compute_hash(&values)))
+}
+
+/// Nullifier output by the domain-bound settlement circuit
+///
+/// `Poseidon(nullifier, poolDomain)`, folding the pool's domain separator
+/// into the unbound nullifier.
+pub fn bind_nullifier(env: &Env, nullifier: &BytesN<32>, pool_domain: &BytesN<32>) -> Result<BytesN<32>, ZkError> {
+    if !is_canonical_scalar(&nullifier.to_array()) || !is_canonical_scalar(&pool_domain.to_array()) {
+        return Err(ZkError::MalformedPublicSignals);
+    }
+    let values = vec![env, bytes32_to_u256(env, nullifier), bytes32_to_u256(env, pool_domain)];
+    Ok(u256_to_bytes32(env, &PoseidonSponge::<3, Fr>::new(env).compute_hash(&values)))
+}
+
+/// Order commitment opened by the settlement and cancellation circuits
+///
+/// `Poseidon(assetHash, side, quantity, price, nonce, secret)` with circom's
+/// six-input Poseidon, where `side` is 0 for a buy and 1 for a sell. Every
+/// other input must be a canonical scalar.
+pub fn order_commitment(
+    env: &Env,
+    asset_hash: &BytesN<32>,
+    side: u32,
+    quantity: &BytesN<32>,
+    price: &BytesN<32>,
+    nonce: &BytesN<32>,
+    secret: &BytesN<32>,
+) -> Result<BytesN<32>, ZkError> {
+    let inputs = [asset_hash, quantity, price, nonce, secret];
+    if side > 1 || inputs.iter().any(|input| !is_canonical_scalar(&input.to_array())) {
+        return Err(ZkError::MalformedPublicSignals);
+    }
+    let mut values = vec![env, bytes32_to_u256(env, asset_hash), U256::from_u32(env, side)];
+    for input in &inputs[1..] {
+        values.push_back(bytes32_to_u256(env, input));
+    }
+    Ok(u256_to_bytes32(env, &poseidon6_hash(env, &values)))
+}
+
+/// circom's six-input Poseidon, run on the host permutation
+fn poseidon6_hash(env: &Env, inputs: &Vec<U256>) -> U256 {
+    let mut state = vec![env, U256::from_u32(env, 0)];
+    state.append(inputs);
+    let state = env.crypto_hazmat().poseidon_permutation(
+        &state,
+        symbol_short!("BN254"),
+        poseidon6::WIDTH,
+        poseidon6::SBOX_D,
+        poseidon6::ROUNDS_F,
+        poseidon6::ROUNDS_P,
+        &poseidon6::mds(env),
+        &poseidon6::rc(env),
+    );
+    state.get_unchecked(0)
+}
+
+/// Replace serialized public signals with their serialized hash
+///
+/// The result is a one-signal blob in the same format, ready to verify
+/// against the verification key of a hashed-input circuit.
+pub fn hash_public_signals_bytes(env: &Env, bytes: &Bytes) -> Result<Bytes, ZkError> {
+    let signals = PublicSignalsBN254::from_bytes(env, bytes)?;
+    let hash = hash_public_signals(env, &signals);
+    Ok(PublicSignalsBN254::new(vec![env, hash]).to_bytes(env))
+}
+
+/// Verify a Groth16 proof directly with the host BN254 primitives
+///
+/// This is the pairing check behind the verifier contract, callable without
+/// a cross-contract call:
+/// e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+pub fn verify_groth16(
+    env: &Env,
+    vk: &VerificationKeyBN254,
+    proof: &ProofBN254,
+    pub_signals: &PublicSignalsBN254,
+) -> Result<bool, ZkError> {
+    if pub_signals.len() + 1 != vk.ic.len() {
+        return Err(ZkError::MalformedVerificationKey);
+    }
+
+    let bn254 = env.crypto().bn254();
+
+    // Compute vk_x = ic[0] + sum(pub_signals[i] * ic[i+1])
+    let mut vk_x = Bn254G1Affine::from_bytes(vk.ic.get(0).unwrap());
+    for (s, v) in pub_signals.signals.iter().zip(vk.ic.iter().skip(1)) {
+        let prod = bn254.g1_mul(&Bn254G1Affine::from_bytes(v), &Fr::from_bytes(s));
+        vk_x = bn254.g1_add(&vk_x, &prod);
+    }
+
+    let neg_a = -Bn254G1Affine::from_bytes(proof.a.clone());
+    let g1_points = vec![
+        env,
+        neg_a,
+        Bn254G1Affine::from_bytes(vk.alpha.clone()),
+        vk_x,
+        Bn254G1Affine::from_bytes(proof.c.clone()),
+    ];
+    let g2_points = vec![
+        env,
+        Bn254G2Affine::from_bytes(proof.b.clone()),
+        Bn254G2Affine::from_bytes(vk.beta.clone()),
+        Bn254G2Affine::from_bytes(vk.gamma.clone()),
+        Bn254G2Affine::from_bytes(vk.delta.clone()),
+    ];
+
+    Ok(bn254.pairing_check(g1_points, g2_points))
+}
+
+/// Reject a verification key that could never verify, or would verify anything
+///
+/// Parsing already checks every point is canonical and on its curve, which
+/// for G1 includes the subgroup. This also rejects identity points among
+/// alpha, beta, gamma and delta, an empty IC, and G2 points outside the
+/// prime-order subgroup. The host only checks G2 subgroup membership when
+/// the points are used, so they are passed through one pairing check whose
+/// result is ignored; a bad point aborts the call.
+pub fn validate_verification_key(env: &Env, vk: &VerificationKeyBN254) -> Result<(), ZkError> {
+    let err = ZkError::MalformedVerificationKey;
+    if vk.ic.is_empty() || vk.alpha.to_array().iter().all(|b| *b == 0) {
+        return Err(err);
+    }
+    for point in [&vk.beta, &vk.gamma, &vk.delta] {
+        if point.to_array().iter().all(|b| *b == 0) {
+            return Err(err);
+        }
+    }
+
+    let alpha = Bn254G1Affine::from_bytes(vk.alpha.clone());
+    let g1_points = vec![env, alpha.clone(), alpha.clone(), alpha];
+    let g2_points = vec![
+        env,
+        Bn254G2Affine::from_bytes(vk.beta.clone()),
+        Bn254G2Affine::from_bytes(vk.gamma.clone()),
+        Bn254G2Affine::from_bytes(vk.delta.clone()),
+    ];
+    env.crypto().bn254().pairing_check(g1_points, g2_points);
+    Ok(())
+}
+
+/// Verify a Groth16 proof from the serialized formats used by the verifier contract
+pub fn verify_groth16_bytes(
+    env: &Env,
+    vk_bytes: &Bytes,
+    proof_bytes: &Bytes,
+    pub_signals_bytes: &Bytes,
+) -> Result<bool, ZkError> {
+    let vk = VerificationKeyBN254::from_bytes(env, vk_bytes)
+        .map_err(|_| ZkError::MalformedVerificationKey)?;
+    let proof = ProofBN254::from_bytes(env, proof_bytes)?;
+    let pub_signals = PublicSignalsBN254::from_bytes(env, pub_signals_bytes)?;
+
+    verify_groth16(env, &vk, &proof, &pub_signals)
+}
+
 /// Convert U256 to BytesN<32> in big-endian format
 pub fn u256_to_bytes32(env: &Env, value: &U256) -> BytesN<32> {
     let bytes = value.to_be_bytes();
@@ -255,10 +689,14 @@ mod tests {
     fn test_proof_roundtrip() {
         let env = Env::default();
         
+        // G1 generator (1, 2), and the point at infinity for B
+        let mut generator = [0u8; 64];
+        generator[31] = 1;
+        generator[63] = 2;
         let proof = ProofBN254 {
-            a: BytesN::from_array(&env, &[1u8; 64]),
-            b: BytesN::from_array(&env, &[2u8; 128]),
-            c: BytesN::from_array(&env, &[3u8; 64]),
+            a: BytesN::from_array(&env, &generator),
+            b: BytesN::from_array(&env, &[0u8; 128]),
+            c: BytesN::from_array(&env, &generator),
         };
         
         let bytes = proof.to_bytes(&env);
@@ -268,4 +706,113 @@ mod tests {
         assert_eq!(decoded.b, proof.b);
         assert_eq!(decoded.c, proof.c);
     }
+
+    #[test]
+    fn test_g1_generator_compression_roundtrip() {
+        use compressed::*;
+
+        // G1 generator (1, 2)
+        let mut point = [0u8; 64];
+        point[31] = 1;
+        point[63] = 2;
+        let compressed = compress_g1(&point);
+        assert_eq!(compressed[0], 0x02);
+        assert_eq!(decompress_g1(&compressed), Some(point));
+
+        // x = 0 gives y^2 = 3, which has no root in Fp
+        let mut off_curve = [0u8; 33];
+        off_curve[0] = 0x02;
+        assert_eq!(decompress_g1(&off_curve), None);
+        assert_eq!(decompress_g1(&[0u8; 33]), Some([0u8; 64]));
+
+        // Coordinates at or above the modulus are rejected
+        let mut wide = [0xffu8; 33];
+        wide[0] = 0x03;
+        assert_eq!(decompress_g1(&wide), None);
+    }
+
+    #[test]
+    fn test_settlement_nullifier_matches_circomlib() {
+        let env = Env::default();
+        let scalar = |v: u8| {
+            let mut out = [0u8; 32];
+            out[31] = v;
+            BytesN::from_array(&env, &out)
+        };
+
+        // circomlibjs poseidon([1, 2, 3, 4])
+        let expected = BytesN::from_array(
+            &env,
+            &[
+                0x29, 0x9c, 0x86, 0x7d, 0xb6, 0xc1, 0xfd, 0xd7, 0x9d, 0xce, 0xfa, 0x40, 0xe4, 0x51, 0x0b, 0x98,
+                0x37, 0xe6, 0x0e, 0xbb, 0x1c, 0xe0, 0x66, 0x3d, 0xba, 0xa5, 0x25, 0xdf, 0x65, 0x25, 0x04, 0x65,
+            ],
+        );
+        let nullifier = settlement_nullifier(&env, &scalar(1), &scalar(2), &scalar(3), &scalar(4)).unwrap();
+        assert_eq!(nullifier, expected);
+
+        let wide = BytesN::from_array(&env, &[0xffu8; 32]);
+        assert_eq!(bind_nullifier(&env, &nullifier, &wide), Err(ZkError::MalformedPublicSignals));
+    }
+
+    #[test]
+    fn test_order_commitment_matches_circomlib() {
+        let env = Env::default();
+        let scalar = |v: u8| {
+            let mut out = [0u8; 32];
+            out[31] = v;
+            BytesN::from_array(&env, &out)
+        };
+
+        // circomlibjs poseidon([1, 2, 3, 4, 5, 6])
+        let expected = BytesN::from_array(
+            &env,
+            &[
+                0x2d, 0x1a, 0x03, 0x85, 0x00, 0x84, 0x44, 0x28, 0x13, 0xc8, 0xeb, 0xf0, 0x94, 0xde, 0xa4, 0x75,
+                0x38, 0x49, 0x0a, 0x68, 0xb0, 0x5f, 0x22, 0x39, 0x13, 0x4a, 0x4c, 0xca, 0x2f, 0x63, 0x02, 0xe1,
+            ],
+        );
+        let mut values = Vec::new(&env);
+        for v in 1..=6 {
+            values.push_back(U256::from_u32(&env, v));
+        }
+        assert_eq!(u256_to_bytes32(&env, &poseidon6_hash(&env, &values)), expected);
+
+        let sell = order_commitment(&env, &scalar(1), 1, &scalar(3), &scalar(4), &scalar(5), &scalar(6)).unwrap();
+        assert_ne!(sell, order_commitment(&env, &scalar(1), 0, &scalar(3), &scalar(4), &scalar(5), &scalar(6)).unwrap());
+        assert_eq!(
+            order_commitment(&env, &scalar(1), 2, &scalar(3), &scalar(4), &scalar(5), &scalar(6)),
+            Err(ZkError::MalformedPublicSignals)
+        );
+        let wide = BytesN::from_array(&env, &[0xffu8; 32]);
+        assert_eq!(
+            order_commitment(&env, &scalar(1), 0, &scalar(3), &scalar(4), &wide, &scalar(6)),
+            Err(ZkError::MalformedPublicSignals)
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_ic_length_mismatch() {
+        let env = Env::default();
+
+        let mut ic = Vec::new(&env);
+        ic.push_back(BytesN::from_array(&env, &[0u8; 64]));
+        let vk = VerificationKeyBN254 {
+            alpha: BytesN::from_array(&env, &[0u8; 64]),
+            beta: BytesN::from_array(&env, &[0u8; 128]),
+            gamma: BytesN::from_array(&env, &[0u8; 128]),
+            delta: BytesN::from_array(&env, &[0u8; 128]),
+            ic,
+        };
+        let proof = ProofBN254 {
+            a: BytesN::from_array(&env, &[0u8; 64]),
+            b: BytesN::from_array(&env, &[0u8; 128]),
+            c: BytesN::from_array(&env, &[0u8; 64]),
+        };
+        let mut signals = Vec::new(&env);
+        signals.push_back(BytesN::from_array(&env, &[1u8; 32]));
+
+        let result = verify_groth16(&env, &vk, &proof, &PublicSignalsBN254::new(signals));
+        assert_eq!(result, Err(ZkError::MalformedVerificationKey));
+    }
 }
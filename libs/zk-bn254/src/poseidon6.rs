@@ -0,0 +1,2236 @@
+//! Poseidon parameters for circom's six-input hash over BN254
+//!
+//! soroban-poseidon only ships BN254 parameters up to state width 6, but an
+//! order commitment hashes six inputs and needs width 7. These are generated
+//! with the reference Grain LFSR for 8 full and 63 partial rounds, as
+//! circomlib's `poseidon_constants.js` is, and are fed to the host's
+//! Poseidon permutation the same way soroban-poseidon feeds its own.
+
+use soroban_sdk::{Bytes, Env, Vec, U256};
+
+/// State width: six inputs plus one capacity element
+pub(crate) const WIDTH: u32 = 7;
+
+/// S-box exponent
+pub(crate) const SBOX_D: u32 = 5;
+
+pub(crate) const ROUNDS_F: u32 = 8;
+pub(crate) const ROUNDS_P: u32 = 63;
+
+/// MDS matrix, row-major
+pub(crate) fn mds(e: &Env) -> Vec<Vec<U256>> {
+    rows(e, &MDS)
+}
+
+/// Round constants, one row per round
+pub(crate) fn rc(e: &Env) -> Vec<Vec<U256>> {
+    rows(e, &RC)
+}
+
+/// Split big-endian constants into rows of `WIDTH` field elements
+///
+/// The constants are kept as plain bytes rather than one host call each in
+/// code, which would cost several times their size in Wasm.
+fn rows(e: &Env, constants: &[[u8; 32]]) -> Vec<Vec<U256>> {
+    let mut rows = Vec::new(e);
+    for chunk in constants.chunks(WIDTH as usize) {
+        let mut row = Vec::new(e);
+        for constant in chunk {
+            row.push_back(U256::from_be_bytes(e, &Bytes::from_array(e, constant)));
+        }
+        rows.push_back(row);
+    }
+    rows
+}
+
+/// MDS matrix entries, row-major
+const MDS: [[u8; 32]; 49] = [
+    [
+        0x2a, 0xbd, 0x9e, 0x16, 0xb7, 0xb4, 0x82, 0x89, 0xaa, 0x19, 0xcd, 0xfe, 0xc7, 0x26, 0xfa, 0x3f,
+        0xa1, 0x5c, 0xc1, 0x62, 0x51, 0x02, 0xae, 0x51, 0x1b, 0xca, 0x45, 0xf3, 0x9d, 0x33, 0x7e, 0x9d,
+    ],
+    [
+        0x1b, 0x4b, 0xc5, 0x88, 0x5d, 0x1c, 0xfc, 0x8e, 0xb6, 0x91, 0x87, 0x53, 0x00, 0xdb, 0x5a, 0x0c,
+        0x73, 0x62, 0xae, 0x38, 0x18, 0x98, 0xac, 0xaf, 0x8b, 0x63, 0xca, 0xd0, 0x4e, 0xcb, 0x36, 0xc6,
+    ],
+    [
+        0x08, 0xa7, 0x2f, 0xbb, 0x55, 0xdd, 0xe3, 0xe9, 0x3c, 0x58, 0x18, 0x57, 0x1a, 0x42, 0x4a, 0x54,
+        0x95, 0x36, 0x95, 0x02, 0x9e, 0xed, 0xbc, 0xf9, 0x1e, 0xae, 0xb0, 0x12, 0x75, 0x1c, 0x7f, 0x83,
+    ],
+    [
+        0x0f, 0x95, 0x35, 0x9a, 0x1f, 0x68, 0x45, 0xd4, 0x92, 0x18, 0x92, 0xf8, 0x3e, 0x50, 0x0b, 0xbb,
+        0x1b, 0x3d, 0x37, 0x63, 0xd8, 0xfc, 0x76, 0x24, 0x00, 0xaf, 0x03, 0x9b, 0x0a, 0xbb, 0x83, 0xf5,
+    ],
+    [
+        0x16, 0xf0, 0x17, 0x51, 0xce, 0x98, 0x53, 0x28, 0xaf, 0xde, 0x34, 0xd8, 0x16, 0x19, 0xde, 0xfb,
+        0x43, 0xe6, 0xc7, 0xb5, 0xda, 0xdf, 0x52, 0xd0, 0xf8, 0x24, 0x72, 0xfb, 0xce, 0x77, 0x70, 0xd9,
+    ],
+    [
+        0x1c, 0x39, 0x16, 0x26, 0x32, 0x84, 0x57, 0xfc, 0xaf, 0xa6, 0x29, 0xf3, 0xe6, 0xd3, 0x3e, 0x25,
+        0x55, 0xe8, 0xd8, 0x85, 0xe3, 0xc0, 0x7c, 0xec, 0xc8, 0xaf, 0x1f, 0x4d, 0x56, 0x59, 0x64, 0x17,
+    ],
+    [
+        0x00, 0x97, 0x0f, 0x67, 0x1b, 0x9b, 0xa3, 0x5e, 0x3e, 0x3c, 0x64, 0x2d, 0x1e, 0x0b, 0x80, 0x32,
+        0x02, 0x3e, 0xd5, 0xba, 0xc9, 0x1c, 0x2c, 0x10, 0x56, 0xf0, 0x39, 0x65, 0xa4, 0x2c, 0x52, 0x97,
+    ],
+    [
+        0x1f, 0xe9, 0xfe, 0xb5, 0xc7, 0xf7, 0xc7, 0xa4, 0x26, 0xbf, 0xf0, 0x32, 0xcd, 0x6a, 0x31, 0x58,
+        0x27, 0x8b, 0x6c, 0x6b, 0x2c, 0xc5, 0x7a, 0x87, 0x12, 0x13, 0xfc, 0xaf, 0xd6, 0x42, 0x79, 0x89,
+    ],
+    [
+        0x0c, 0xc7, 0xea, 0xbb, 0x15, 0x4a, 0xa7, 0x95, 0x5e, 0xac, 0x47, 0xa1, 0x9f, 0x64, 0x37, 0xa2,
+        0xb8, 0xde, 0xc0, 0xfe, 0x9e, 0x56, 0x86, 0x15, 0x0f, 0xf6, 0xe8, 0x8b, 0x1a, 0x7b, 0x4b, 0xc4,
+    ],
+    [
+        0x03, 0x93, 0xef, 0xd9, 0x63, 0x99, 0xaa, 0x07, 0x26, 0xef, 0x9a, 0xf7, 0x9b, 0xe8, 0xb6, 0xc7,
+        0x15, 0xe4, 0x29, 0x90, 0x6c, 0xa0, 0xd1, 0x50, 0x76, 0xb5, 0xa9, 0x90, 0xfe, 0xa7, 0x5a, 0xce,
+    ],
+    [
+        0x08, 0x71, 0x23, 0x38, 0x35, 0xb7, 0x52, 0xea, 0x03, 0x0c, 0xf8, 0xa4, 0xf4, 0x37, 0x71, 0x29,
+        0x6e, 0xeb, 0x33, 0xf6, 0x97, 0xd1, 0xb0, 0x82, 0x5d, 0xb4, 0x7d, 0x39, 0xac, 0x9e, 0x59, 0x55,
+    ],
+    [
+        0x1a, 0x02, 0x79, 0x8f, 0x04, 0x7a, 0x51, 0xba, 0xae, 0xa3, 0x4f, 0xcf, 0xb2, 0x6c, 0xa6, 0xaf,
+        0xe9, 0x6a, 0x5f, 0xad, 0x1d, 0x2d, 0xb4, 0x27, 0x04, 0x97, 0xb6, 0xe6, 0x50, 0x48, 0x91, 0xcb,
+    ],
+    [
+        0x05, 0x56, 0x65, 0xd7, 0x6d, 0xf3, 0xcd, 0x0f, 0xcb, 0x71, 0x3c, 0x23, 0x4e, 0xa7, 0x9d, 0x06,
+        0xa3, 0x1d, 0x6e, 0x3e, 0x4c, 0x3b, 0xcc, 0x65, 0x7d, 0xba, 0x4c, 0x66, 0x4d, 0x5a, 0x0d, 0xa0,
+    ],
+    [
+        0x26, 0xee, 0x96, 0x91, 0xa6, 0x38, 0xed, 0xc7, 0xc9, 0x18, 0x87, 0xe3, 0x02, 0xfc, 0x55, 0xe8,
+        0xe7, 0x75, 0x82, 0x86, 0xa4, 0x48, 0x45, 0xad, 0x88, 0xfd, 0x7d, 0xe3, 0xbb, 0x10, 0x52, 0xc1,
+    ],
+    [
+        0x00, 0xcd, 0xb2, 0xdb, 0x9e, 0x4f, 0x9d, 0x73, 0x2d, 0x21, 0x7c, 0x5e, 0x31, 0x14, 0x82, 0x11,
+        0x65, 0xd4, 0x9e, 0x5a, 0x2d, 0x41, 0x55, 0x6b, 0xcd, 0x53, 0xd0, 0xc4, 0xd4, 0x33, 0x22, 0x4c,
+    ],
+    [
+        0x01, 0x2f, 0x3b, 0xb2, 0x9c, 0x97, 0x27, 0x93, 0x18, 0x21, 0x23, 0xf5, 0xc4, 0x93, 0x06, 0xe1,
+        0x6a, 0x3a, 0x78, 0x7c, 0x4b, 0xcd, 0x9c, 0x79, 0x8a, 0x13, 0x1b, 0x70, 0x85, 0x6d, 0x1c, 0xbe,
+    ],
+    [
+        0x0b, 0xba, 0x90, 0x06, 0x40, 0x4c, 0x5e, 0xde, 0x82, 0xb5, 0x27, 0x67, 0x7d, 0xaf, 0x44, 0x4e,
+        0xd4, 0x3f, 0xd1, 0xe0, 0xdb, 0xdc, 0x55, 0x28, 0xdd, 0x2c, 0xd1, 0xf1, 0x7a, 0xd7, 0x34, 0x81,
+    ],
+    [
+        0x17, 0xe4, 0xe7, 0x3b, 0xf7, 0x01, 0xf8, 0x8d, 0x21, 0x9a, 0xcf, 0x1c, 0xa6, 0x39, 0x0c, 0xc1,
+        0xe1, 0x7c, 0x9f, 0x84, 0xb1, 0x71, 0x38, 0x20, 0x83, 0x7c, 0xa0, 0x70, 0xbe, 0x84, 0xa2, 0xdc,
+    ],
+    [
+        0x23, 0x4b, 0x8a, 0x07, 0x7e, 0xf5, 0x2b, 0x53, 0xa3, 0x43, 0xb8, 0xda, 0x5b, 0x20, 0xcc, 0x35,
+        0x8a, 0xd6, 0x07, 0x60, 0xdd, 0x60, 0x27, 0x6d, 0x48, 0x50, 0x15, 0xf7, 0x3d, 0x53, 0xd2, 0x07,
+    ],
+    [
+        0x2d, 0xc3, 0xfd, 0x77, 0x39, 0x4b, 0x7b, 0x5a, 0xc1, 0xcb, 0xd1, 0xb4, 0x6e, 0x6d, 0xed, 0x5b,
+        0x83, 0x5d, 0x72, 0x17, 0xa4, 0xc5, 0x0a, 0x48, 0x7a, 0xe9, 0xa3, 0x5a, 0x7f, 0xd4, 0x5d, 0x9d,
+    ],
+    [
+        0x16, 0x68, 0x47, 0x76, 0x40, 0xfa, 0xb1, 0x4c, 0x72, 0xb1, 0xc1, 0x6f, 0xf0, 0x19, 0x2e, 0x48,
+        0xcf, 0x88, 0x2f, 0x12, 0x27, 0x6b, 0xd1, 0x2f, 0x97, 0xf8, 0x2c, 0x6e, 0xe2, 0xe6, 0x1e, 0x48,
+    ],
+    [
+        0x09, 0x66, 0x42, 0x16, 0xca, 0x56, 0x5c, 0x1a, 0x44, 0xfc, 0x2b, 0x98, 0x34, 0x7e, 0xa8, 0xb3,
+        0x69, 0x04, 0xb3, 0x87, 0x7f, 0x21, 0x59, 0xdc, 0xc9, 0x99, 0x89, 0x5a, 0xde, 0xb4, 0xd7, 0x0b,
+    ],
+    [
+        0x11, 0x1f, 0xd8, 0xdf, 0xce, 0x2b, 0xd3, 0xb7, 0xba, 0x0c, 0xfa, 0x93, 0xe2, 0x0f, 0xb6, 0x85,
+        0xab, 0xf7, 0x6d, 0x77, 0x30, 0x58, 0x57, 0xb8, 0xdf, 0x30, 0x91, 0x4e, 0x48, 0x49, 0xec, 0xef,
+    ],
+    [
+        0x19, 0xf5, 0x3c, 0x0a, 0x59, 0x2b, 0x3e, 0xa9, 0x34, 0x97, 0xa5, 0x11, 0xe5, 0x6f, 0x66, 0x75,
+        0x35, 0x98, 0x26, 0x5b, 0x1a, 0xb6, 0x77, 0x4d, 0xa4, 0x8e, 0xec, 0x04, 0xe4, 0xd9, 0x34, 0x26,
+    ],
+    [
+        0x19, 0xb6, 0x83, 0xc3, 0x9d, 0x3b, 0x00, 0x2f, 0xc3, 0x52, 0x3b, 0x7b, 0xee, 0x5a, 0x60, 0x14,
+        0x8b, 0x1d, 0xbd, 0xf1, 0x8d, 0xd1, 0xc3, 0x18, 0xe6, 0xa5, 0x41, 0xe8, 0xcf, 0x66, 0xa1, 0x74,
+    ],
+    [
+        0x25, 0x47, 0x11, 0x3a, 0x81, 0x87, 0x79, 0xf1, 0x3e, 0x34, 0x48, 0x71, 0x9c, 0x7e, 0xf1, 0x00,
+        0xb8, 0x03, 0x6b, 0xb3, 0xe8, 0x82, 0xdd, 0x5e, 0x64, 0x56, 0xc3, 0x53, 0x11, 0xc1, 0xbd, 0x5b,
+    ],
+    [
+        0x00, 0xcf, 0x14, 0x8f, 0x80, 0x45, 0xcd, 0xbe, 0x7b, 0xb1, 0x01, 0x3f, 0x72, 0xeb, 0xd4, 0x12,
+        0x6a, 0x3e, 0x35, 0xcb, 0xa9, 0xa9, 0x70, 0x44, 0x1b, 0xa8, 0xd5, 0xbc, 0x76, 0x94, 0x3b, 0x0b,
+    ],
+    [
+        0x30, 0x08, 0x42, 0x1e, 0xed, 0x76, 0x7a, 0x09, 0x33, 0x22, 0xa0, 0x9c, 0x37, 0x33, 0x97, 0x3a,
+        0x03, 0x81, 0x68, 0xcb, 0x28, 0xcd, 0x2e, 0x1f, 0x70, 0x90, 0x76, 0xc0, 0x0b, 0x86, 0x95, 0x99,
+    ],
+    [
+        0x14, 0x63, 0xf5, 0x1d, 0x4a, 0x4c, 0xb1, 0xeb, 0xa1, 0x6d, 0xbc, 0x11, 0x55, 0x66, 0xaa, 0x48,
+        0xfe, 0x77, 0xb4, 0x3c, 0x24, 0x4c, 0x99, 0x88, 0xa3, 0xc6, 0x58, 0xd2, 0xf5, 0x64, 0x93, 0xd6,
+    ],
+    [
+        0x16, 0xaf, 0xd8, 0xf4, 0xc3, 0x90, 0x38, 0xb8, 0x00, 0x6a, 0x59, 0xd2, 0x92, 0xae, 0xa8, 0x47,
+        0x31, 0xc0, 0xf8, 0x38, 0x32, 0xa6, 0x1b, 0x82, 0x25, 0x0e, 0xd9, 0x01, 0x18, 0xe8, 0xfe, 0xc2,
+    ],
+    [
+        0x22, 0x96, 0x36, 0x1f, 0x3b, 0xf1, 0x54, 0xef, 0xba, 0xb9, 0x96, 0x92, 0x19, 0x36, 0xe9, 0xb1,
+        0xdf, 0xf8, 0x35, 0x27, 0x11, 0xf9, 0x58, 0x63, 0x81, 0x72, 0x05, 0x53, 0x33, 0xe0, 0x75, 0x79,
+    ],
+    [
+        0x1c, 0x31, 0x60, 0xeb, 0xae, 0xb1, 0x0f, 0x80, 0x9f, 0x69, 0x84, 0x3c, 0xf8, 0xd5, 0xbb, 0xac,
+        0xa1, 0x98, 0xd1, 0xf8, 0xe3, 0x43, 0x9b, 0xe2, 0x9b, 0xeb, 0x92, 0xd9, 0x09, 0xec, 0x89, 0x1c,
+    ],
+    [
+        0x09, 0x4c, 0x66, 0xbd, 0xcf, 0xf5, 0x43, 0x82, 0xcd, 0x92, 0x13, 0xab, 0xe4, 0x33, 0x12, 0xed,
+        0xb5, 0xb4, 0xd4, 0x14, 0xc6, 0xe0, 0x9a, 0xcc, 0xa2, 0x49, 0x12, 0xa7, 0x63, 0x54, 0x04, 0xc6,
+    ],
+    [
+        0x2e, 0x88, 0x3b, 0xd4, 0x08, 0xc4, 0xc8, 0x26, 0xd0, 0x04, 0xff, 0x77, 0x7d, 0x7c, 0x09, 0x38,
+        0x43, 0x4a, 0xb5, 0xbb, 0x84, 0xf3, 0x42, 0x0e, 0x5d, 0x94, 0xb8, 0x53, 0xb1, 0x74, 0x6f, 0x89,
+    ],
+    [
+        0x22, 0x8a, 0xf6, 0xd4, 0x25, 0xe9, 0x1a, 0x78, 0x3d, 0xd1, 0x8f, 0xd8, 0x9a, 0x6b, 0x38, 0xbd,
+        0xa0, 0x53, 0xf2, 0xf2, 0x3c, 0x09, 0x4b, 0x87, 0x5f, 0x27, 0xcb, 0x3a, 0xbb, 0xa2, 0x03, 0x0b,
+    ],
+    [
+        0x21, 0x4e, 0x41, 0x78, 0x8b, 0xd2, 0xb9, 0x90, 0x34, 0x7c, 0x7f, 0x2a, 0x8f, 0x47, 0x61, 0x2d,
+        0x4d, 0xfc, 0xeb, 0x1a, 0x1e, 0x6d, 0xa3, 0x81, 0x65, 0x87, 0x3d, 0xf6, 0x6c, 0xe5, 0x1f, 0x59,
+    ],
+    [
+        0x03, 0xfb, 0xa8, 0x2d, 0x3f, 0x78, 0x36, 0x20, 0x87, 0x3d, 0xa9, 0x4e, 0x9f, 0x4e, 0x84, 0xc8,
+        0xb5, 0x29, 0xd7, 0x6f, 0x54, 0xe4, 0xb5, 0xd7, 0xd4, 0x80, 0x52, 0x72, 0xaf, 0x9b, 0x97, 0x75,
+    ],
+    [
+        0x06, 0x67, 0x82, 0xfe, 0x3e, 0x86, 0x9d, 0xe2, 0xce, 0xbd, 0x7e, 0xbb, 0xf6, 0x0f, 0xb4, 0x35,
+        0x78, 0x53, 0x29, 0xdf, 0xb1, 0x2a, 0xd9, 0xbd, 0x1b, 0x4e, 0x46, 0xed, 0x36, 0x28, 0x95, 0x11,
+    ],
+    [
+        0x1c, 0x5c, 0xd6, 0xb2, 0x3e, 0xf3, 0x36, 0xbe, 0x01, 0xef, 0xa3, 0x7a, 0x27, 0xe6, 0xb7, 0xaa,
+        0xe5, 0xd1, 0x64, 0x3f, 0xce, 0xeb, 0x21, 0x2c, 0xdf, 0xf5, 0x15, 0x88, 0x83, 0x5f, 0xb5, 0x37,
+    ],
+    [
+        0x2f, 0x3b, 0x4b, 0xf0, 0xf1, 0x05, 0xf8, 0x8e, 0x27, 0x24, 0x8a, 0xf0, 0x94, 0xe2, 0x9f, 0xee,
+        0xd9, 0xff, 0x3f, 0xad, 0x9a, 0x79, 0x4e, 0x15, 0x83, 0x0b, 0xd2, 0xb3, 0x11, 0x53, 0x94, 0x53,
+    ],
+    [
+        0x2f, 0x40, 0x85, 0xea, 0xa9, 0xf8, 0x0a, 0x69, 0xb7, 0x39, 0xfc, 0x23, 0xf2, 0xfe, 0x40, 0x57,
+        0xf6, 0x47, 0x29, 0xb3, 0x11, 0x78, 0x3f, 0xcf, 0xab, 0x3a, 0x0a, 0x36, 0xfc, 0x1f, 0xf4, 0x32,
+    ],
+    [
+        0x23, 0x63, 0x60, 0x33, 0x49, 0xe9, 0x4f, 0x52, 0x0f, 0xa1, 0xf9, 0x8f, 0xe2, 0x50, 0x7f, 0x6a,
+        0xae, 0x36, 0x19, 0x34, 0x07, 0x8a, 0xa5, 0x9a, 0x16, 0x21, 0x4c, 0x08, 0x81, 0x85, 0x63, 0x8b,
+    ],
+    [
+        0x05, 0x48, 0x54, 0x17, 0x24, 0xf6, 0x4e, 0x20, 0x12, 0x82, 0x60, 0x89, 0x9a, 0xbe, 0x8f, 0xbc,
+        0xdf, 0xf1, 0x84, 0xa1, 0x95, 0x7a, 0x93, 0x85, 0xfb, 0x71, 0x59, 0x23, 0xbf, 0x0f, 0xc7, 0x9d,
+    ],
+    [
+        0x21, 0xc5, 0xe2, 0x5a, 0x69, 0x91, 0x36, 0x4e, 0x59, 0xa2, 0x05, 0xe5, 0xb0, 0x1c, 0x81, 0x1d,
+        0xee, 0xb0, 0x95, 0x68, 0x0a, 0x72, 0x92, 0x83, 0x69, 0xcf, 0xd2, 0xde, 0x71, 0xb1, 0x4f, 0x98,
+    ],
+    [
+        0x11, 0xc5, 0xcc, 0x53, 0xf6, 0x33, 0x8c, 0x82, 0x58, 0x17, 0xaf, 0xf7, 0x79, 0x2a, 0x2e, 0xfb,
+        0xce, 0x8f, 0xff, 0x17, 0x13, 0x00, 0xd4, 0x14, 0x55, 0xa2, 0xed, 0xac, 0x37, 0x66, 0x3d, 0x02,
+    ],
+    [
+        0x27, 0xa0, 0x94, 0x9f, 0xdc, 0x9f, 0x10, 0x49, 0x5c, 0x4e, 0xe7, 0x21, 0x4a, 0xa0, 0xed, 0xd2,
+        0xad, 0x8e, 0xef, 0x68, 0xf5, 0x77, 0xda, 0x8b, 0x96, 0xf9, 0xd8, 0x7a, 0x39, 0x50, 0x4b, 0xf9,
+    ],
+    [
+        0x1c, 0x7f, 0xc6, 0x8f, 0x3f, 0xbd, 0xe4, 0xa2, 0x3c, 0x45, 0x5e, 0xf0, 0xd0, 0xdb, 0xce, 0x0c,
+        0xe5, 0x56, 0xa0, 0x19, 0x4e, 0x6d, 0x55, 0x2c, 0x04, 0x55, 0xcb, 0xb5, 0xb0, 0xc2, 0x30, 0xe2,
+    ],
+    [
+        0x11, 0xd0, 0xf5, 0x6f, 0xc0, 0x0e, 0x7d, 0x85, 0xb3, 0xa2, 0x93, 0x9f, 0xd3, 0x39, 0x91, 0xdc,
+        0x8a, 0xbc, 0x1c, 0xed, 0x0c, 0x3f, 0x3c, 0x53, 0x0c, 0xbf, 0xb4, 0x50, 0x0d, 0x63, 0xde, 0x9c,
+    ],
+    [
+        0x01, 0xb2, 0xe9, 0xd2, 0x0d, 0x9b, 0x55, 0xde, 0xb0, 0x48, 0xca, 0xbe, 0x16, 0x36, 0xf0, 0xe9,
+        0x63, 0x63, 0xbe, 0xc8, 0x86, 0x67, 0x9c, 0xbd, 0x91, 0xf3, 0x32, 0xfb, 0x2b, 0xad, 0xe4, 0x4f,
+    ],
+];
+
+/// Round constants, `WIDTH` per round
+const RC: [[u8; 32]; 497] = [
+    [
+        0x21, 0x97, 0x70, 0x3f, 0xce, 0xb4, 0xcb, 0xf0, 0x7c, 0x6d, 0xbf, 0x46, 0xc4, 0xad, 0x93, 0xe7,
+        0xd1, 0x4e, 0x55, 0x4d, 0xb6, 0x6d, 0x09, 0x10, 0x2f, 0xf8, 0x48, 0x24, 0x74, 0x3f, 0xe4, 0xe7,
+    ],
+    [
+        0x13, 0x22, 0xf0, 0xff, 0xfc, 0x7e, 0x48, 0x54, 0xbd, 0x8b, 0x5f, 0xc2, 0x3e, 0x71, 0x06, 0xb5,
+        0x1f, 0x59, 0xbd, 0x50, 0x61, 0xd8, 0xb5, 0x65, 0xac, 0xa2, 0xfd, 0x3e, 0xfe, 0x51, 0x2f, 0x79,
+    ],
+    [
+        0x19, 0xe4, 0x14, 0xf8, 0x03, 0x80, 0xaa, 0x2d, 0x22, 0x21, 0x33, 0x11, 0x8c, 0x33, 0x7d, 0x82,
+        0xa8, 0xc5, 0x89, 0x4c, 0x2f, 0x88, 0x86, 0x1e, 0xe4, 0x6f, 0x44, 0x8f, 0xa9, 0xd6, 0x13, 0x59,
+    ],
+    [
+        0x22, 0x24, 0x11, 0x99, 0xa4, 0xdf, 0x9c, 0xb7, 0xd6, 0x52, 0x49, 0xd9, 0x56, 0x2c, 0xad, 0x6c,
+        0x65, 0xf1, 0x64, 0x60, 0xe7, 0x9a, 0x79, 0x39, 0xcb, 0xfd, 0xd6, 0xd7, 0xb9, 0x20, 0x9d, 0x9a,
+    ],
+    [
+        0x03, 0x01, 0x06, 0x17, 0x35, 0x38, 0x4d, 0xfc, 0xec, 0xf9, 0xa3, 0x11, 0xc2, 0x2a, 0xde, 0x32,
+        0x7b, 0x51, 0xc7, 0x8b, 0x21, 0x16, 0x15, 0xf4, 0x1c, 0x77, 0xf6, 0xd8, 0x5e, 0x8a, 0x41, 0xb6,
+    ],
+    [
+        0x22, 0x9e, 0x1d, 0x86, 0xc1, 0xf2, 0x81, 0x0f, 0x4a, 0x26, 0x33, 0xb7, 0xb4, 0xb5, 0xbb, 0x61,
+        0xb7, 0xdc, 0x5d, 0x19, 0xac, 0x95, 0xfd, 0x8d, 0x37, 0x95, 0x48, 0xff, 0x2b, 0x68, 0x50, 0xee,
+    ],
+    [
+        0x0e, 0x31, 0xdc, 0xf9, 0xc5, 0x32, 0x09, 0xa8, 0x17, 0xee, 0xb2, 0x92, 0xe9, 0x89, 0x72, 0x7c,
+        0x3f, 0x56, 0xbf, 0xf6, 0x58, 0x83, 0xaf, 0xe5, 0xfd, 0xc2, 0x8a, 0xc3, 0xdf, 0xbe, 0xa6, 0x75,
+    ],
+    [
+        0x22, 0x2c, 0x73, 0x18, 0xeb, 0xe9, 0x63, 0x06, 0xc9, 0xd7, 0x9a, 0xc1, 0x12, 0x37, 0x26, 0x3c,
+        0x6b, 0x8e, 0xb2, 0x52, 0x72, 0xeb, 0x88, 0x40, 0x7e, 0x8c, 0x29, 0xa3, 0x58, 0xb4, 0x0f, 0x2a,
+    ],
+    [
+        0x0c, 0xb4, 0x16, 0xb4, 0x2c, 0x53, 0xa8, 0x29, 0xcf, 0x4f, 0xc8, 0x23, 0xbe, 0x8f, 0x4f, 0xed,
+        0xd1, 0x25, 0x93, 0x39, 0x4c, 0xd5, 0x29, 0x05, 0x3f, 0xf4, 0xe3, 0x60, 0x49, 0x74, 0xa2, 0xc6,
+    ],
+    [
+        0x08, 0x92, 0x72, 0x9d, 0x09, 0x59, 0x4a, 0x8d, 0x8b, 0x1a, 0xdf, 0xbf, 0x2e, 0x2e, 0xd7, 0x53,
+        0x99, 0xde, 0xe6, 0x12, 0x84, 0x2b, 0x6d, 0xb3, 0xd2, 0xd2, 0xc2, 0xaa, 0xdd, 0xb6, 0xc0, 0xaa,
+    ],
+    [
+        0x18, 0xe3, 0x9e, 0x9a, 0xe4, 0x5f, 0x14, 0xba, 0x97, 0xa1, 0x5b, 0xd6, 0x72, 0x71, 0x1a, 0xe1,
+        0x24, 0x37, 0x5d, 0x9f, 0x48, 0x2a, 0x44, 0x87, 0xa0, 0xe3, 0x34, 0xde, 0xe2, 0xd2, 0x7c, 0x71,
+    ],
+    [
+        0x25, 0x60, 0xc9, 0xff, 0x67, 0x2e, 0x6e, 0xa6, 0x9d, 0xbb, 0x33, 0x84, 0x4d, 0x0d, 0x22, 0x87,
+        0x04, 0x61, 0xd1, 0x8e, 0xaf, 0x0f, 0x89, 0x92, 0xd4, 0xd7, 0xac, 0x64, 0x9e, 0xdd, 0x66, 0x10,
+    ],
+    [
+        0x21, 0xbe, 0xe2, 0xb8, 0xed, 0x54, 0xb7, 0x26, 0xef, 0x17, 0xf7, 0x73, 0x66, 0xb1, 0xdc, 0xfb,
+        0xda, 0x7c, 0xcf, 0x1e, 0x48, 0x7f, 0x99, 0x16, 0xe5, 0xf9, 0x63, 0xe8, 0x14, 0xc5, 0x73, 0x12,
+    ],
+    [
+        0x30, 0x0e, 0x1b, 0x28, 0xda, 0xbe, 0xbd, 0xc0, 0xd4, 0xbd, 0xbc, 0x19, 0xbe, 0x29, 0xa3, 0xde,
+        0xe4, 0xed, 0xa8, 0x42, 0x5d, 0xd0, 0xc7, 0xc6, 0xe8, 0x2f, 0xec, 0x14, 0x7c, 0xb0, 0x70, 0xba,
+    ],
+    [
+        0x2c, 0x44, 0xa5, 0xff, 0x45, 0xde, 0xea, 0x69, 0xdd, 0x29, 0x61, 0xd6, 0x70, 0x11, 0xaf, 0x49,
+        0x82, 0xb9, 0x40, 0x5f, 0x9f, 0xe3, 0x8d, 0xe4, 0xa7, 0x32, 0xd2, 0xae, 0xae, 0x88, 0xcc, 0x23,
+    ],
+    [
+        0x0c, 0x8d, 0x59, 0xfe, 0x1b, 0x7f, 0x55, 0x09, 0x5e, 0x6a, 0xaa, 0xd9, 0xe3, 0xb4, 0xe7, 0xb1,
+        0xc9, 0x3d, 0x87, 0x6b, 0xa3, 0x23, 0x4d, 0xd1, 0xac, 0x5e, 0xc6, 0x41, 0x08, 0x29, 0x1b, 0xfd,
+    ],
+    [
+        0x06, 0xcf, 0x82, 0xbc, 0x5f, 0xd1, 0x15, 0xd3, 0x48, 0xa3, 0x0b, 0x0c, 0x45, 0x37, 0x8d, 0x90,
+        0xcc, 0xa1, 0x66, 0x04, 0x46, 0xba, 0x04, 0x97, 0x59, 0xfa, 0x53, 0x10, 0x3f, 0xcd, 0x78, 0xb5,
+    ],
+    [
+        0x2f, 0x80, 0xed, 0x01, 0xa2, 0x11, 0x8d, 0x02, 0xe2, 0x14, 0x51, 0x11, 0xc3, 0xfe, 0x6c, 0xa7,
+        0x0e, 0xfd, 0xf9, 0x5d, 0x8a, 0xbe, 0xae, 0xe8, 0x82, 0x92, 0x93, 0x34, 0x7d, 0xc9, 0x06, 0x1c,
+    ],
+    [
+        0x0b, 0xdf, 0xe7, 0xf6, 0x80, 0x3e, 0x4d, 0xee, 0x7b, 0x02, 0x6e, 0x07, 0x90, 0x75, 0x90, 0x8c,
+        0xb8, 0x41, 0x4e, 0x3d, 0x65, 0x1d, 0xd1, 0x2f, 0xda, 0x67, 0xdf, 0x08, 0xdd, 0xed, 0x9e, 0x42,
+    ],
+    [
+        0x06, 0xcc, 0x48, 0xed, 0x88, 0x24, 0x05, 0x59, 0x5b, 0x34, 0xd3, 0x91, 0x38, 0xf5, 0x0b, 0xb5,
+        0xb3, 0x1a, 0x96, 0x3a, 0x0d, 0xba, 0x66, 0xae, 0xf1, 0x7c, 0x19, 0xad, 0x62, 0x2e, 0x29, 0xb1,
+    ],
+    [
+        0x2a, 0x67, 0xf3, 0xba, 0x6d, 0x15, 0x94, 0x99, 0x32, 0xac, 0x56, 0x22, 0xcf, 0x9e, 0x2b, 0x49,
+        0x38, 0x01, 0xbf, 0x16, 0xa5, 0x03, 0x36, 0xa4, 0x51, 0x82, 0x58, 0xbc, 0xc6, 0x86, 0x7c, 0x95,
+    ],
+    [
+        0x25, 0x72, 0x72, 0x5a, 0x67, 0xc6, 0x07, 0x18, 0xdf, 0x65, 0x69, 0x34, 0xaa, 0x78, 0xfb, 0xeb,
+        0x80, 0xc8, 0xf3, 0x95, 0xae, 0xb6, 0xa2, 0x0c, 0xd8, 0x33, 0xc4, 0xb1, 0x4f, 0xef, 0x41, 0x2a,
+    ],
+    [
+        0x03, 0xbb, 0x7f, 0x6c, 0xa4, 0x35, 0x45, 0xb1, 0x1f, 0x19, 0x72, 0x12, 0x65, 0xb4, 0x16, 0x16,
+        0x65, 0x79, 0xcb, 0x75, 0x33, 0x30, 0xb1, 0x87, 0x9b, 0x79, 0x68, 0x5d, 0x1d, 0xf0, 0xbf, 0xa7,
+    ],
+    [
+        0x24, 0x12, 0x81, 0x5e, 0x86, 0x3f, 0x8f, 0x3b, 0xc2, 0x8b, 0xfc, 0xbb, 0x95, 0x7f, 0xaa, 0x5f,
+        0x39, 0x33, 0x00, 0xb2, 0x51, 0x33, 0xe9, 0x22, 0x1b, 0x8e, 0xb3, 0x15, 0x6e, 0xd5, 0xc1, 0x2b,
+    ],
+    [
+        0x0d, 0xbf, 0x64, 0xd5, 0x8d, 0xf2, 0x12, 0xe4, 0x30, 0x78, 0x71, 0x96, 0x6d, 0xe0, 0x97, 0xa5,
+        0xdb, 0x09, 0xa9, 0x3e, 0x5b, 0xc3, 0x05, 0xce, 0xbd, 0xcd, 0x06, 0x30, 0xf4, 0x33, 0xaf, 0x45,
+    ],
+    [
+        0x27, 0x4c, 0x06, 0xc0, 0x11, 0x58, 0xe0, 0x47, 0xff, 0x4a, 0x79, 0x1e, 0x25, 0xb7, 0xbb, 0x3d,
+        0xb1, 0x90, 0x52, 0x9d, 0xf8, 0xf5, 0x0b, 0x63, 0x86, 0x27, 0x3f, 0x81, 0x0f, 0x15, 0xc6, 0xab,
+    ],
+    [
+        0x23, 0xcf, 0x40, 0x69, 0x3f, 0x52, 0xab, 0xf0, 0xed, 0x3d, 0x84, 0x2f, 0x7b, 0xc8, 0x5d, 0x1a,
+        0x21, 0x11, 0xd0, 0xab, 0xec, 0xfc, 0x18, 0xac, 0xd0, 0xfe, 0x39, 0x91, 0xee, 0x5f, 0xd3, 0x3d,
+    ],
+    [
+        0x1d, 0xaa, 0xa9, 0x26, 0x52, 0x8f, 0x33, 0x68, 0xf4, 0xe4, 0xcf, 0x92, 0x41, 0x66, 0xb8, 0xbd,
+        0x3f, 0xf8, 0xda, 0xef, 0xbc, 0xf9, 0x00, 0xe6, 0xdc, 0xc4, 0xee, 0x63, 0xc4, 0x09, 0x96, 0x98,
+    ],
+    [
+        0x10, 0x10, 0x37, 0x89, 0xdc, 0x94, 0x71, 0xad, 0x71, 0x86, 0x57, 0xbe, 0xbb, 0x67, 0x81, 0xe3,
+        0x9b, 0xd9, 0x2f, 0xd2, 0x0e, 0xe1, 0xa3, 0x2d, 0x0d, 0xe3, 0xea, 0x28, 0x17, 0xa0, 0x15, 0x55,
+    ],
+    [
+        0x00, 0x09, 0xbc, 0x25, 0x06, 0x50, 0x80, 0xe6, 0xc6, 0xe9, 0x93, 0x2e, 0x34, 0xbf, 0xde, 0x78,
+        0xd1, 0x08, 0x5f, 0x19, 0xe9, 0xff, 0xc8, 0x96, 0x5a, 0x46, 0x60, 0xa4, 0xd9, 0xb6, 0xd6, 0x07,
+    ],
+    [
+        0x13, 0x12, 0xc7, 0x27, 0xcb, 0x54, 0x89, 0xbb, 0x17, 0x4e, 0x1d, 0xcb, 0x82, 0x58, 0x8e, 0xdf,
+        0xab, 0x31, 0xa2, 0x99, 0x9b, 0x37, 0x7d, 0xab, 0xec, 0xe4, 0x66, 0xe9, 0xdc, 0xd6, 0xaf, 0xee,
+    ],
+    [
+        0x20, 0x29, 0x4a, 0x46, 0x2c, 0x7d, 0x0c, 0x25, 0xae, 0xb5, 0x37, 0x46, 0x18, 0xe5, 0xe7, 0x73,
+        0x16, 0x68, 0x2a, 0xe3, 0xd7, 0x26, 0x82, 0x4f, 0x15, 0xf0, 0x0f, 0x7c, 0xb9, 0xa7, 0x00, 0x9f,
+    ],
+    [
+        0x25, 0xe0, 0xc6, 0xbe, 0x89, 0xbb, 0xb0, 0x4b, 0xd8, 0x39, 0x1c, 0x90, 0xcf, 0x35, 0x58, 0x8f,
+        0x0e, 0x9d, 0x73, 0xfd, 0xb0, 0x62, 0x59, 0xf4, 0x7a, 0x05, 0x51, 0x3a, 0xd7, 0xa2, 0x3e, 0x91,
+    ],
+    [
+        0x00, 0x84, 0xa0, 0xb8, 0x7e, 0x0b, 0x33, 0x6c, 0x9a, 0x91, 0x10, 0x69, 0xf8, 0x9d, 0x87, 0x5a,
+        0x38, 0xbe, 0x31, 0x3e, 0xfc, 0x42, 0x0d, 0x28, 0xb9, 0xec, 0x28, 0x7f, 0x28, 0x81, 0x0f, 0xd3,
+    ],
+    [
+        0x28, 0xa0, 0x3e, 0xe2, 0x9f, 0x61, 0x2a, 0xab, 0x26, 0x8f, 0x76, 0x24, 0x1d, 0x54, 0xd4, 0x77,
+        0xa1, 0xd9, 0x7f, 0x58, 0x07, 0xba, 0x2e, 0x18, 0x12, 0xf4, 0xd3, 0x50, 0xd7, 0xc6, 0xf7, 0xda,
+    ],
+    [
+        0x0b, 0x67, 0x94, 0x18, 0x2b, 0x72, 0x3a, 0x12, 0xe0, 0xb3, 0x5f, 0x76, 0xbd, 0x55, 0xa7, 0xd1,
+        0x06, 0xee, 0x6a, 0x40, 0x35, 0x52, 0x7c, 0x4d, 0x32, 0x9a, 0x6f, 0x33, 0x06, 0x8d, 0x7e, 0xda,
+    ],
+    [
+        0x01, 0x5b, 0x9a, 0x69, 0x84, 0x65, 0x42, 0x8f, 0xc6, 0x86, 0xb7, 0x9d, 0xb4, 0x95, 0x9c, 0x84,
+        0x95, 0xb9, 0x2b, 0x85, 0x12, 0x2f, 0x6a, 0x69, 0x32, 0x94, 0x08, 0x36, 0xfa, 0xce, 0xa8, 0x6d,
+    ],
+    [
+        0x1b, 0xfe, 0x52, 0x28, 0x9f, 0x81, 0x1f, 0x5c, 0x36, 0x19, 0xf1, 0x48, 0x66, 0x13, 0x8a, 0xe9,
+        0xa1, 0x27, 0x9b, 0xb7, 0x6e, 0x8c, 0xec, 0x9a, 0x89, 0x36, 0xfd, 0xc3, 0x6f, 0x59, 0xdf, 0x7b,
+    ],
+    [
+        0x1c, 0xd5, 0x74, 0xfe, 0x8d, 0x5e, 0x4c, 0x42, 0x8b, 0x92, 0xb2, 0x3a, 0x97, 0x17, 0x34, 0x21,
+        0x76, 0xe1, 0xd3, 0x09, 0xa7, 0xe2, 0x59, 0x05, 0x2c, 0x31, 0xbd, 0xc9, 0xaf, 0x09, 0x1f, 0xa3,
+    ],
+    [
+        0x21, 0x2d, 0x1b, 0xbd, 0xfa, 0xaf, 0x02, 0x00, 0x73, 0x5b, 0xc5, 0x85, 0x1a, 0x15, 0x6a, 0xdc,
+        0xbf, 0xa4, 0xf6, 0x07, 0xf5, 0xda, 0x12, 0x2e, 0x1f, 0x63, 0xaf, 0xae, 0xcd, 0x38, 0xca, 0xb7,
+    ],
+    [
+        0x0b, 0x04, 0x79, 0xe2, 0x88, 0x20, 0xe8, 0x83, 0xd9, 0x03, 0xa9, 0x51, 0xf9, 0x5b, 0x13, 0x09,
+        0x37, 0x88, 0xe9, 0xe1, 0xca, 0x64, 0xdd, 0xf2, 0xab, 0xb9, 0x4b, 0x13, 0xfe, 0xe9, 0xed, 0xbc,
+    ],
+    [
+        0x01, 0x10, 0xf5, 0x25, 0x7c, 0x21, 0xc9, 0x37, 0x9d, 0x73, 0xb7, 0x6e, 0x9c, 0xa9, 0x5c, 0x51,
+        0x3c, 0x77, 0xa1, 0x5c, 0xc8, 0xee, 0x0e, 0xf7, 0x26, 0xd8, 0x46, 0x0d, 0x4f, 0xbb, 0xda, 0xaa,
+    ],
+    [
+        0x08, 0xc5, 0x2e, 0x94, 0x01, 0xa9, 0x76, 0x4f, 0x0c, 0x24, 0x87, 0xdf, 0xed, 0x34, 0xe2, 0x15,
+        0xc1, 0x86, 0xb6, 0xc2, 0x83, 0x8e, 0x38, 0xeb, 0x5a, 0x50, 0xcc, 0xa4, 0xf0, 0xbc, 0x69, 0x94,
+    ],
+    [
+        0x2e, 0x42, 0x2b, 0x43, 0x23, 0xcb, 0x4c, 0x0a, 0xf4, 0x6f, 0x4b, 0x0e, 0x50, 0x52, 0xf7, 0x57,
+        0x44, 0xc5, 0x38, 0xab, 0xcc, 0xc7, 0x64, 0x3f, 0x33, 0x94, 0x40, 0xdc, 0x3a, 0xae, 0xec, 0xa3,
+    ],
+    [
+        0x2e, 0x12, 0x48, 0x2b, 0xa1, 0x60, 0x47, 0x2d, 0x45, 0x54, 0x9f, 0xf7, 0x9c, 0xd0, 0x4a, 0xeb,
+        0xc4, 0xa3, 0x27, 0xc4, 0xbb, 0x9d, 0x78, 0xc2, 0x57, 0x3b, 0x83, 0x5b, 0x05, 0xc5, 0x5f, 0xe6,
+    ],
+    [
+        0x12, 0x4f, 0xa5, 0xf9, 0xc5, 0xa9, 0x6d, 0x6c, 0xce, 0x92, 0xcf, 0xc7, 0x3e, 0xa2, 0x44, 0x62,
+        0x02, 0xf0, 0x91, 0x00, 0xee, 0xd2, 0x03, 0xae, 0x42, 0x67, 0xaa, 0x63, 0x7a, 0x03, 0x2d, 0x36,
+    ],
+    [
+        0x0b, 0xf6, 0x51, 0xf8, 0x2f, 0x83, 0xca, 0x1f, 0xa0, 0x37, 0xa9, 0x41, 0xd4, 0xe2, 0xa4, 0xb3,
+        0xe0, 0x8f, 0x5e, 0x16, 0x03, 0xf9, 0x33, 0xa1, 0x72, 0x3a, 0xdf, 0x41, 0x35, 0x79, 0x6d, 0xe7,
+    ],
+    [
+        0x21, 0xc6, 0x74, 0xea, 0x7b, 0x83, 0x47, 0x41, 0xcb, 0xad, 0x39, 0xb2, 0x1b, 0x24, 0x2a, 0xb9,
+        0xa5, 0x55, 0x4c, 0x6c, 0x9f, 0x92, 0xe8, 0x01, 0xcb, 0x1f, 0xe4, 0x38, 0x2b, 0x61, 0x94, 0x63,
+    ],
+    [
+        0x21, 0x4f, 0x91, 0xd6, 0xc4, 0xae, 0xf5, 0x7e, 0x83, 0x18, 0x6b, 0x57, 0xfd, 0x9f, 0xa4, 0x8a,
+        0x19, 0x3e, 0x1c, 0xaf, 0x16, 0xd7, 0xf6, 0xdc, 0x15, 0x59, 0xd1, 0x44, 0xa2, 0x71, 0xb3, 0x41,
+    ],
+    [
+        0x20, 0x3e, 0x22, 0x18, 0x38, 0x97, 0x29, 0xc2, 0x6c, 0x6f, 0x66, 0x21, 0x3f, 0x22, 0x69, 0xc2,
+        0x62, 0x78, 0x5e, 0xc6, 0x8c, 0x75, 0x95, 0x99, 0xc2, 0xd8, 0xe3, 0xf6, 0x80, 0x35, 0x05, 0x5b,
+    ],
+    [
+        0x1a, 0xa9, 0x34, 0x19, 0x87, 0x13, 0xce, 0x17, 0x65, 0x8f, 0x51, 0x29, 0x3f, 0xad, 0x44, 0xb1,
+        0xc2, 0x2f, 0xc8, 0x03, 0x91, 0x40, 0x5c, 0xe7, 0x6c, 0x92, 0xb2, 0x34, 0x9b, 0x7e, 0xb3, 0xa2,
+    ],
+    [
+        0x10, 0x14, 0xd4, 0xe5, 0xdd, 0xd9, 0xc1, 0x7b, 0x3c, 0x2b, 0x29, 0x68, 0x44, 0x76, 0xaf, 0xdb,
+        0xaf, 0x7f, 0xc5, 0x6e, 0x8b, 0x79, 0x96, 0x16, 0xa6, 0x7b, 0xbc, 0x66, 0x78, 0xe4, 0x53, 0x51,
+    ],
+    [
+        0x03, 0x3c, 0x13, 0xed, 0x45, 0xb4, 0x76, 0x2a, 0x47, 0xe1, 0xd2, 0xd4, 0x5d, 0x7f, 0xc8, 0xfb,
+        0xf9, 0xd4, 0xe2, 0x45, 0x46, 0xe2, 0x0d, 0xc5, 0x96, 0x91, 0x1f, 0xb9, 0x91, 0x71, 0x03, 0x58,
+    ],
+    [
+        0x09, 0xbc, 0x1c, 0x32, 0x74, 0xc5, 0x69, 0xe7, 0xe5, 0xc5, 0xd7, 0x7c, 0x51, 0xfe, 0x69, 0xb2,
+        0x3b, 0x7e, 0x43, 0x51, 0x38, 0xf4, 0xf3, 0x21, 0x70, 0x16, 0xa2, 0xd7, 0x0c, 0xe7, 0x1d, 0x5e,
+    ],
+    [
+        0x18, 0x03, 0x17, 0x1c, 0x9d, 0xbc, 0x06, 0x10, 0xe3, 0x8d, 0x83, 0x08, 0xdb, 0x10, 0x1d, 0x6a,
+        0x99, 0xca, 0x90, 0x13, 0x7f, 0x6a, 0xb8, 0xcd, 0xab, 0x2a, 0x91, 0xb3, 0x6b, 0x63, 0x32, 0x2a,
+    ],
+    [
+        0x0b, 0xa6, 0x07, 0x1a, 0xa9, 0x8a, 0xfe, 0xc3, 0xfb, 0xde, 0xb4, 0x6a, 0x88, 0xe4, 0xa5, 0xd1,
+        0x7b, 0xd4, 0x45, 0x4c, 0x0e, 0x7d, 0xa8, 0xc7, 0x4b, 0x15, 0xb0, 0x84, 0x48, 0x8c, 0xfd, 0xcf,
+    ],
+    [
+        0x17, 0x9e, 0x90, 0xab, 0x4b, 0x08, 0xa4, 0xea, 0xd2, 0x81, 0xa8, 0x70, 0x90, 0x57, 0x48, 0x7e,
+        0x07, 0x7b, 0x71, 0x08, 0x79, 0x91, 0x1f, 0x21, 0x5f, 0xbd, 0x6e, 0x8e, 0xbf, 0x83, 0x8e, 0x97,
+    ],
+    [
+        0x1c, 0xbc, 0xa8, 0x8b, 0x8a, 0xb3, 0xf0, 0x58, 0x8f, 0xf8, 0x7d, 0xee, 0xa9, 0x78, 0xd0, 0x00,
+        0x08, 0xad, 0xba, 0xd8, 0x08, 0xe6, 0x51, 0x08, 0x97, 0x23, 0x8e, 0xa0, 0x07, 0xf4, 0x91, 0xa4,
+    ],
+    [
+        0x2f, 0x50, 0xb2, 0x0b, 0x33, 0x69, 0x2b, 0xb0, 0xf6, 0x27, 0x77, 0xde, 0x5e, 0x31, 0x98, 0x37,
+        0x7d, 0x79, 0x23, 0xef, 0x2f, 0xa8, 0xed, 0xe0, 0x88, 0x31, 0xe1, 0x0f, 0x7e, 0x9b, 0x9f, 0x5a,
+    ],
+    [
+        0x15, 0xba, 0x03, 0x0e, 0x8f, 0x1e, 0xf6, 0xf2, 0x60, 0x56, 0x6f, 0xd6, 0xfc, 0xff, 0xfc, 0xd2,
+        0x1a, 0x1c, 0xfc, 0x12, 0x32, 0xf7, 0xec, 0x71, 0x48, 0xff, 0x07, 0x6d, 0x1b, 0xa6, 0x87, 0x07,
+    ],
+    [
+        0x0d, 0xbe, 0x05, 0x3d, 0xa0, 0x1a, 0xa4, 0xb7, 0xc0, 0xfa, 0xd8, 0x3a, 0x3c, 0xc9, 0x66, 0xf0,
+        0xb8, 0x28, 0x1b, 0xb4, 0x94, 0x0f, 0x97, 0xa5, 0x51, 0x6c, 0xfb, 0x9d, 0xcd, 0x54, 0x3a, 0x6f,
+    ],
+    [
+        0x22, 0x2e, 0xca, 0x5d, 0x75, 0x4a, 0xfe, 0xca, 0x89, 0x97, 0xf3, 0x1d, 0x6f, 0xcb, 0x94, 0xa5,
+        0xd7, 0x99, 0xaf, 0xb7, 0x95, 0xe6, 0x8d, 0x4d, 0x8b, 0x0c, 0xe6, 0xd8, 0x39, 0xf6, 0xdd, 0x7e,
+    ],
+    [
+        0x2a, 0xbf, 0xfa, 0x43, 0xd7, 0x56, 0xef, 0x24, 0x2d, 0xb7, 0xf5, 0x84, 0xbd, 0x62, 0x40, 0x59,
+        0x91, 0x11, 0x26, 0x18, 0x36, 0x2f, 0x1b, 0x29, 0xa3, 0x3e, 0xe2, 0x13, 0x1b, 0x46, 0xa4, 0x46,
+    ],
+    [
+        0x03, 0xc9, 0x25, 0x24, 0xeb, 0xe6, 0x73, 0x00, 0xd0, 0xdd, 0x0a, 0xac, 0x46, 0x4f, 0x0e, 0x6e,
+        0xcf, 0x96, 0x2d, 0x6a, 0xd4, 0x1b, 0xf2, 0x68, 0x5f, 0x07, 0x7b, 0xfd, 0x78, 0x8e, 0xb3, 0xfd,
+    ],
+    [
+        0x28, 0x22, 0x41, 0x75, 0x8a, 0xde, 0x4e, 0xf8, 0xff, 0x38, 0x58, 0x30, 0x52, 0x88, 0xd3, 0xb4,
+        0xa5, 0x22, 0x09, 0x29, 0x5d, 0x1a, 0x4b, 0xc8, 0x9d, 0x88, 0x5b, 0x2f, 0x5d, 0xc3, 0x34, 0x1a,
+    ],
+    [
+        0x02, 0x63, 0x5c, 0xb9, 0x73, 0xd7, 0x1b, 0xb3, 0xd2, 0x13, 0x7b, 0xd8, 0x16, 0x80, 0xdd, 0x3b,
+        0xc6, 0x07, 0xfc, 0x54, 0x87, 0xe8, 0x98, 0xc4, 0xc2, 0xd8, 0x3c, 0x1d, 0xc2, 0x81, 0x76, 0x87,
+    ],
+    [
+        0x13, 0xc8, 0x66, 0xaa, 0x66, 0xe8, 0xd5, 0x06, 0x07, 0x9d, 0xc2, 0x4c, 0x2d, 0xf7, 0xb1, 0xe6,
+        0x91, 0x99, 0x48, 0x87, 0xd3, 0x56, 0x22, 0xf4, 0xcf, 0xcf, 0x01, 0x4b, 0xbe, 0xa2, 0x23, 0xe9,
+    ],
+    [
+        0x15, 0x1f, 0x00, 0x07, 0xcc, 0xf4, 0x2a, 0x7e, 0xf9, 0x69, 0xf1, 0xb6, 0xf6, 0x31, 0x59, 0x92,
+        0xe6, 0x59, 0x4b, 0xb5, 0x34, 0x4d, 0x28, 0x39, 0xfe, 0xe6, 0x62, 0x0b, 0x60, 0xe2, 0xc0, 0x23,
+    ],
+    [
+        0x1c, 0x1c, 0x6a, 0x5e, 0xbc, 0xce, 0xe8, 0xca, 0xa4, 0xdc, 0x7e, 0xf1, 0x8e, 0x19, 0x42, 0x74,
+        0xf6, 0xe1, 0x98, 0xd2, 0xb1, 0x40, 0xdd, 0xcc, 0x97, 0xcb, 0x15, 0xc9, 0x2b, 0xa1, 0x40, 0x9c,
+    ],
+    [
+        0x18, 0x33, 0x0c, 0x9e, 0x67, 0xd6, 0x2f, 0x78, 0x61, 0xa9, 0x96, 0xd7, 0xed, 0x69, 0xbb, 0xa9,
+        0x04, 0xb0, 0xdd, 0x45, 0x9f, 0x2b, 0x25, 0x4f, 0xc7, 0xcc, 0x53, 0xdd, 0x28, 0xe4, 0x5a, 0x65,
+    ],
+    [
+        0x26, 0xb7, 0x47, 0x74, 0x0c, 0x95, 0xbf, 0x13, 0x9c, 0xb1, 0x1b, 0xdf, 0x02, 0xfa, 0x63, 0xa4,
+        0xbd, 0xb2, 0x9a, 0x90, 0xf1, 0x58, 0x93, 0x3a, 0x18, 0x22, 0xcd, 0xde, 0x53, 0x87, 0x29, 0x0d,
+    ],
+    [
+        0x14, 0x75, 0x67, 0x6f, 0xb2, 0x05, 0x08, 0x42, 0x46, 0xef, 0x9b, 0xc7, 0x23, 0x4f, 0x31, 0x2c,
+        0x21, 0x4f, 0x42, 0x68, 0x52, 0xdc, 0xb2, 0xec, 0x7d, 0xc3, 0x77, 0x2f, 0x57, 0x9a, 0xf8, 0xb1,
+    ],
+    [
+        0x1b, 0x03, 0xad, 0x90, 0x80, 0xd2, 0x9b, 0x70, 0xaa, 0xf3, 0x0b, 0xbc, 0x32, 0x00, 0xef, 0xff,
+        0xe7, 0x3e, 0xe2, 0x69, 0xdc, 0x03, 0x2d, 0x2e, 0x32, 0xbd, 0x19, 0x07, 0x67, 0xbc, 0x38, 0x0d,
+    ],
+    [
+        0x21, 0xc1, 0x75, 0xf9, 0x37, 0xc2, 0xf8, 0x2e, 0x99, 0x73, 0x5b, 0xc7, 0xc2, 0x01, 0x28, 0x4f,
+        0x89, 0x57, 0x7b, 0x27, 0xa1, 0xf8, 0x4f, 0xa2, 0x46, 0x42, 0xec, 0xeb, 0x55, 0x2f, 0x44, 0x55,
+    ],
+    [
+        0x11, 0x92, 0xe0, 0x26, 0x9a, 0x6b, 0x66, 0xa8, 0xc5, 0x88, 0x75, 0x27, 0xaa, 0x44, 0x74, 0xc2,
+        0xde, 0x91, 0xcf, 0x8c, 0x71, 0xeb, 0xda, 0x83, 0x5d, 0xf2, 0xcd, 0xcc, 0xdd, 0x4a, 0x4e, 0x4b,
+    ],
+    [
+        0x0f, 0x3a, 0x12, 0xc0, 0xee, 0x52, 0xf0, 0xd3, 0x4f, 0x2b, 0x4a, 0x0d, 0xcd, 0x13, 0x54, 0x1b,
+        0x48, 0xae, 0xb3, 0xd5, 0x12, 0x1d, 0x31, 0x11, 0x13, 0xbb, 0xa9, 0x79, 0x2a, 0x45, 0xa5, 0x8d,
+    ],
+    [
+        0x07, 0xfb, 0x52, 0x18, 0x83, 0xbd, 0xf7, 0x8b, 0x33, 0x68, 0x92, 0x05, 0xb4, 0x5e, 0x52, 0x26,
+        0x94, 0x58, 0xa0, 0x61, 0xbc, 0xb8, 0x7e, 0xeb, 0x3b, 0x1e, 0x18, 0x3c, 0x6d, 0x7e, 0xb5, 0xb7,
+    ],
+    [
+        0x24, 0x8d, 0x0e, 0x80, 0x51, 0xc1, 0xa9, 0x65, 0x64, 0x0c, 0x96, 0xc6, 0xf0, 0x10, 0xc4, 0x93,
+        0x1f, 0xe6, 0x48, 0xb9, 0x6f, 0x95, 0xef, 0x6f, 0x5b, 0x8e, 0x47, 0x4f, 0x64, 0xcf, 0x31, 0x69,
+    ],
+    [
+        0x2a, 0xb5, 0x53, 0xdb, 0x9e, 0xad, 0x2c, 0x82, 0xce, 0x91, 0xfe, 0xbe, 0x18, 0x05, 0x29, 0xef,
+        0xf3, 0x52, 0x18, 0x75, 0xf7, 0xe9, 0x0a, 0x9b, 0x8b, 0x7a, 0x3c, 0x4d, 0xe4, 0x87, 0x7c, 0xd6,
+    ],
+    [
+        0x21, 0x1e, 0xe3, 0xbd, 0xa3, 0xc9, 0x5f, 0x19, 0x54, 0xee, 0xf2, 0x2a, 0x0a, 0x82, 0xc2, 0xed,
+        0xe8, 0x44, 0x4e, 0x42, 0xa7, 0x2a, 0x82, 0x00, 0xba, 0xb6, 0xa1, 0x33, 0x84, 0x6d, 0x5b, 0x5c,
+    ],
+    [
+        0x11, 0x1d, 0xb3, 0xb5, 0x18, 0x4f, 0xf7, 0x41, 0x2f, 0x79, 0xc1, 0x29, 0x90, 0x4a, 0x9c, 0xac,
+        0x4e, 0xe6, 0x5a, 0x51, 0xc2, 0x06, 0x2d, 0x66, 0x73, 0x2f, 0x54, 0xbc, 0xdf, 0x76, 0x7b, 0xe4,
+    ],
+    [
+        0x1a, 0x73, 0x84, 0x32, 0x64, 0xcf, 0x9a, 0x3a, 0x1b, 0xb6, 0xfb, 0x68, 0xef, 0x76, 0x03, 0x7c,
+        0x4f, 0xa9, 0x83, 0x37, 0xe5, 0xc1, 0x00, 0x96, 0xc6, 0x30, 0xb4, 0x49, 0xec, 0x4a, 0x5f, 0x67,
+    ],
+    [
+        0x1c, 0x2e, 0x5a, 0x2d, 0x93, 0x32, 0xf6, 0x51, 0x28, 0xc8, 0x53, 0x58, 0x66, 0x2d, 0x6a, 0x7c,
+        0x44, 0x35, 0x3a, 0xdd, 0x09, 0xc2, 0x7c, 0xd0, 0x7d, 0xa0, 0x2f, 0x91, 0x44, 0xda, 0x85, 0x5e,
+    ],
+    [
+        0x0e, 0x2b, 0x35, 0xfe, 0x10, 0x21, 0xb8, 0x79, 0xde, 0x5b, 0x67, 0xa7, 0x45, 0x8f, 0xa6, 0x18,
+        0x8b, 0xbd, 0xea, 0x39, 0xe2, 0xbf, 0x46, 0x8a, 0x1a, 0x24, 0xe3, 0x62, 0x5c, 0xaf, 0xff, 0x98,
+    ],
+    [
+        0x2e, 0x56, 0x85, 0x94, 0xe5, 0xdf, 0xbf, 0xad, 0xf2, 0x72, 0xb8, 0xd6, 0xe8, 0xc9, 0x9b, 0xd7,
+        0x85, 0xc4, 0xfa, 0x94, 0xce, 0x9e, 0x87, 0xfa, 0x8a, 0x9a, 0x37, 0xe3, 0x2c, 0x7a, 0x28, 0xde,
+    ],
+    [
+        0x07, 0x04, 0x3c, 0x75, 0x71, 0xcb, 0x7d, 0xc5, 0x20, 0x5b, 0xfd, 0xa4, 0x2d, 0x69, 0x35, 0x27,
+        0xa4, 0xbc, 0xb5, 0x82, 0x38, 0xed, 0x6a, 0x6a, 0x24, 0x8e, 0x79, 0x17, 0xdd, 0xaf, 0x0c, 0x3a,
+    ],
+    [
+        0x23, 0x9e, 0x18, 0xa0, 0xc8, 0x5b, 0x84, 0x5f, 0xfd, 0x6b, 0x85, 0xe9, 0x63, 0x43, 0xf1, 0xa8,
+        0x37, 0x8f, 0x3c, 0x69, 0x1d, 0xad, 0xfb, 0x22, 0x8d, 0xa6, 0xff, 0x7a, 0xa7, 0x9e, 0xfe, 0x1f,
+    ],
+    [
+        0x25, 0xe2, 0x47, 0xaf, 0x25, 0x0c, 0x56, 0x4c, 0x00, 0xf5, 0xff, 0x1c, 0xf1, 0xf2, 0xbc, 0x86,
+        0x06, 0x60, 0x0f, 0x17, 0x3c, 0xb6, 0x5e, 0xf0, 0x78, 0x0a, 0xf9, 0xf5, 0xa1, 0xb6, 0x76, 0xc8,
+    ],
+    [
+        0x03, 0x1b, 0xa3, 0x38, 0x56, 0x64, 0x54, 0x4e, 0x3e, 0x08, 0x01, 0xf1, 0x69, 0x8b, 0x15, 0x03,
+        0x3a, 0x60, 0x49, 0xb2, 0x50, 0x4e, 0x9c, 0xdc, 0x2c, 0x6e, 0x3e, 0xb2, 0xfd, 0x12, 0x10, 0x0d,
+    ],
+    [
+        0x2a, 0x33, 0x77, 0x77, 0x39, 0xe2, 0xab, 0x0a, 0xdf, 0xb1, 0xca, 0x56, 0x87, 0xdb, 0x1e, 0x05,
+        0x9b, 0x20, 0x37, 0x0c, 0xbe, 0xec, 0xca, 0xd3, 0x2d, 0xa5, 0xc3, 0x60, 0x94, 0x31, 0xff, 0x65,
+    ],
+    [
+        0x2b, 0xfd, 0x6f, 0x0e, 0x9e, 0x99, 0x6b, 0xa1, 0x37, 0x49, 0x32, 0x7b, 0x9c, 0x5c, 0xec, 0x69,
+        0x90, 0x3d, 0x41, 0x3b, 0x2d, 0xf2, 0x9b, 0x77, 0x7d, 0x05, 0x38, 0x4b, 0x3e, 0x7a, 0xf9, 0x52,
+    ],
+    [
+        0x2d, 0x45, 0x3d, 0x93, 0xfb, 0x36, 0xfb, 0xf8, 0xe3, 0x67, 0x47, 0xee, 0x92, 0xd9, 0x0c, 0xad,
+        0x9e, 0x02, 0xe6, 0x92, 0xd0, 0x74, 0x8c, 0x07, 0xfd, 0x43, 0xea, 0xfc, 0x2c, 0xc5, 0xe8, 0x23,
+    ],
+    [
+        0x18, 0x7b, 0xd8, 0x04, 0x3f, 0x86, 0x42, 0xcd, 0xa2, 0xb6, 0xba, 0xd8, 0x29, 0x58, 0xd1, 0x15,
+        0x81, 0x88, 0x80, 0xeb, 0x80, 0xf6, 0xb7, 0x30, 0x38, 0x1d, 0x97, 0x4a, 0xc5, 0xd5, 0x03, 0x7e,
+    ],
+    [
+        0x21, 0xac, 0xa6, 0x05, 0xf9, 0x2a, 0x48, 0xac, 0x35, 0x70, 0x8f, 0xe5, 0x14, 0x07, 0x29, 0xfb,
+        0x35, 0xc3, 0xe4, 0x8c, 0xce, 0xc4, 0x41, 0x21, 0x77, 0x13, 0xb0, 0x86, 0x49, 0x44, 0x39, 0x30,
+    ],
+    [
+        0x17, 0x3f, 0xd1, 0x64, 0xf5, 0x22, 0x4a, 0xc0, 0x6c, 0x2e, 0x99, 0xf3, 0xe2, 0xea, 0xa6, 0x37,
+        0x9d, 0xf1, 0x13, 0xde, 0x30, 0xaa, 0x48, 0x5e, 0x8b, 0xbc, 0xc7, 0xf0, 0xfd, 0x2d, 0x1e, 0x03,
+    ],
+    [
+        0x07, 0x1c, 0x66, 0xa6, 0x46, 0x3c, 0x3f, 0xd4, 0x29, 0x2b, 0xf1, 0xe5, 0xac, 0xb6, 0xde, 0x61,
+        0x11, 0x42, 0xd7, 0x3c, 0xd1, 0xdb, 0x59, 0x0e, 0x89, 0xb6, 0x1b, 0x57, 0xd4, 0x4d, 0x69, 0xbd,
+    ],
+    [
+        0x04, 0x4a, 0x40, 0xe3, 0x96, 0x85, 0x5b, 0xe5, 0x50, 0xd8, 0xcc, 0xce, 0x7b, 0x82, 0xee, 0x2c,
+        0xaa, 0xef, 0x66, 0x75, 0xbe, 0xbe, 0x92, 0x26, 0x6c, 0x66, 0x69, 0x82, 0xff, 0x72, 0xbb, 0xf8,
+    ],
+    [
+        0x11, 0x76, 0xd9, 0xf5, 0x2e, 0x12, 0xdf, 0x67, 0xf0, 0x87, 0x11, 0x0a, 0x15, 0xaa, 0xf9, 0x8d,
+        0x1d, 0xed, 0x29, 0x3f, 0xfd, 0xf9, 0x95, 0x99, 0xfe, 0xde, 0xf2, 0xe5, 0x15, 0x69, 0xe4, 0xf6,
+    ],
+    [
+        0x06, 0xe8, 0xeb, 0x7d, 0xd0, 0x26, 0xd2, 0x57, 0xb1, 0x5c, 0x66, 0x24, 0xbe, 0xe2, 0xf3, 0x7d,
+        0x5b, 0x67, 0xb4, 0xa6, 0x71, 0xcf, 0xcd, 0xfe, 0xe5, 0x79, 0x7e, 0x84, 0x57, 0xb6, 0x39, 0x16,
+    ],
+    [
+        0x06, 0x96, 0xaa, 0x48, 0xc1, 0xd5, 0xfe, 0x84, 0xca, 0x5c, 0xf5, 0x90, 0x09, 0xab, 0x0b, 0xaa,
+        0xef, 0xbb, 0x61, 0x2e, 0x32, 0xc9, 0x1d, 0x5e, 0xf3, 0x06, 0xde, 0xf3, 0x8d, 0xc5, 0x67, 0x3a,
+    ],
+    [
+        0x08, 0x53, 0xc3, 0x3a, 0x9c, 0x7a, 0x7c, 0x3b, 0x35, 0x5c, 0x92, 0xc7, 0x5d, 0xe4, 0x44, 0x36,
+        0x5d, 0xb8, 0x2d, 0x24, 0x1e, 0xef, 0x9f, 0x3f, 0xac, 0x41, 0x70, 0x04, 0xa3, 0xa1, 0xac, 0xcc,
+    ],
+    [
+        0x30, 0x16, 0xc0, 0xba, 0x34, 0x67, 0x8e, 0x0a, 0x67, 0xf5, 0x9c, 0xf9, 0x6c, 0xe1, 0x6e, 0x90,
+        0x81, 0x54, 0xf5, 0xf9, 0x80, 0x25, 0x91, 0x71, 0x7e, 0x66, 0xe9, 0x5b, 0xa2, 0x5a, 0xb7, 0x7f,
+    ],
+    [
+        0x0a, 0xf0, 0x08, 0x60, 0x1d, 0xc9, 0x55, 0x25, 0x17, 0xf3, 0x03, 0xff, 0x9e, 0x22, 0xc7, 0x93,
+        0xa6, 0x82, 0xca, 0xa9, 0x32, 0x56, 0xf2, 0xf0, 0x9a, 0x4f, 0x74, 0x65, 0x42, 0x60, 0x3b, 0x0e,
+    ],
+    [
+        0x04, 0xcb, 0x6d, 0x76, 0xb5, 0xa2, 0x7a, 0x5a, 0xae, 0x38, 0x7c, 0xb6, 0x47, 0x79, 0x03, 0x18,
+        0xc2, 0x5f, 0xc1, 0x6f, 0x8d, 0x7d, 0x18, 0x55, 0xa3, 0x87, 0x65, 0xf8, 0x47, 0xf7, 0x31, 0xb0,
+    ],
+    [
+        0x2e, 0xcf, 0x70, 0xc3, 0x72, 0xd8, 0xe5, 0xed, 0xca, 0xd4, 0x9e, 0x58, 0xd4, 0x78, 0x75, 0xf2,
+        0x69, 0x33, 0xd6, 0x26, 0x14, 0xf2, 0x66, 0xe6, 0xb4, 0xf5, 0x86, 0x17, 0x71, 0x48, 0x56, 0x5f,
+    ],
+    [
+        0x01, 0xf3, 0x93, 0xa6, 0xee, 0x74, 0xdd, 0x6c, 0x4d, 0x52, 0xb0, 0xe7, 0x82, 0x0a, 0x71, 0x32,
+        0xf8, 0xc4, 0x88, 0x97, 0x42, 0x31, 0x3c, 0x06, 0xca, 0xa6, 0xec, 0xc5, 0x5f, 0x3d, 0xd4, 0x13,
+    ],
+    [
+        0x2e, 0x4d, 0x4c, 0xe3, 0xf9, 0x7b, 0xf9, 0x6f, 0xf8, 0x6b, 0x88, 0xe9, 0x69, 0xd1, 0x3e, 0x41,
+        0x5e, 0x14, 0x72, 0x12, 0x82, 0x76, 0x9f, 0x7c, 0xf8, 0xc3, 0xa7, 0x37, 0x23, 0xf0, 0x94, 0xdb,
+    ],
+    [
+        0x30, 0x60, 0x00, 0xef, 0x24, 0xa7, 0xf5, 0xa9, 0xc6, 0x52, 0xe3, 0xa9, 0x36, 0x75, 0x78, 0x55,
+        0x4e, 0xa5, 0x9b, 0xf8, 0xef, 0xa0, 0xbd, 0x8b, 0xc5, 0x77, 0x64, 0x60, 0xc1, 0x8c, 0x1a, 0xdd,
+    ],
+    [
+        0x26, 0xe5, 0x8d, 0x62, 0x87, 0x67, 0x8f, 0x3d, 0xb9, 0xc2, 0x25, 0xc1, 0xd3, 0xb9, 0x3a, 0xe7,
+        0xc4, 0x3a, 0x69, 0x02, 0x4e, 0xbf, 0x24, 0x9c, 0x8b, 0xde, 0x40, 0x61, 0x7c, 0x2b, 0x3d, 0x49,
+    ],
+    [
+        0x09, 0xee, 0xe0, 0x9f, 0x87, 0x3d, 0x1b, 0xf6, 0x83, 0x97, 0x1d, 0xf4, 0xd7, 0xce, 0x56, 0xc8,
+        0xf0, 0xb8, 0xb9, 0x8d, 0xdc, 0x05, 0xb9, 0x61, 0x2c, 0x0a, 0x0d, 0x31, 0x73, 0x10, 0x65, 0x5a,
+    ],
+    [
+        0x0f, 0x08, 0x87, 0x9d, 0xfd, 0x4f, 0xa0, 0xdc, 0x11, 0xde, 0xd5, 0x56, 0xef, 0x01, 0xc6, 0xf9,
+        0x4c, 0xc6, 0xf4, 0xd1, 0x85, 0x53, 0xb0, 0x8b, 0xcb, 0x1d, 0xd6, 0x04, 0x39, 0x2e, 0xc0, 0x0c,
+    ],
+    [
+        0x11, 0x45, 0x9f, 0x7e, 0x9f, 0x0e, 0x3c, 0x72, 0x77, 0x77, 0xe3, 0x4d, 0x6d, 0x93, 0xec, 0x28,
+        0xa2, 0x5b, 0xd8, 0x67, 0x17, 0x15, 0x23, 0xba, 0xd5, 0xbe, 0x0d, 0x5a, 0x20, 0x02, 0x9e, 0xb7,
+    ],
+    [
+        0x04, 0xe9, 0xcb, 0xb6, 0xf6, 0xe8, 0xe8, 0x05, 0xa2, 0x0f, 0xbd, 0x80, 0x1a, 0x53, 0xb7, 0x23,
+        0xf7, 0x8c, 0x1f, 0x5a, 0xcd, 0x22, 0x78, 0x80, 0xf7, 0xf3, 0x7f, 0x4e, 0x5b, 0x05, 0x28, 0xad,
+    ],
+    [
+        0x2d, 0x2f, 0xf3, 0x2e, 0xb7, 0xa0, 0xc0, 0x26, 0x6d, 0xd4, 0x6b, 0xd5, 0x93, 0x9c, 0x9a, 0x9a,
+        0xae, 0x88, 0xb3, 0xe9, 0xaf, 0x5d, 0xad, 0x01, 0xa7, 0x30, 0x4f, 0x98, 0x68, 0xf7, 0xc7, 0xc6,
+    ],
+    [
+        0x23, 0xb7, 0x7e, 0xb0, 0xf2, 0x7e, 0x95, 0x02, 0x00, 0xa5, 0x90, 0x44, 0x7d, 0x64, 0x5a, 0x72,
+        0x3d, 0x9b, 0x40, 0x5f, 0xa2, 0x53, 0x8a, 0x5c, 0xb7, 0x6f, 0xc5, 0xfd, 0xae, 0x16, 0xd8, 0x39,
+    ],
+    [
+        0x2c, 0xd6, 0xd2, 0xc5, 0x65, 0x8e, 0xc0, 0x79, 0x2d, 0xae, 0xe2, 0x06, 0x7e, 0x2f, 0x95, 0x8d,
+        0x68, 0x49, 0x88, 0x63, 0x18, 0xa6, 0xc1, 0xc2, 0x72, 0x33, 0xa4, 0x69, 0xe9, 0x99, 0x8c, 0x2f,
+    ],
+    [
+        0x0a, 0xe1, 0xda, 0xa4, 0x20, 0xba, 0xc9, 0x07, 0x80, 0xd0, 0xc8, 0x30, 0x26, 0xbf, 0xcc, 0x84,
+        0xaf, 0x83, 0x95, 0x3e, 0x26, 0xec, 0xa8, 0x39, 0xda, 0x76, 0x86, 0x1e, 0x73, 0x98, 0x55, 0xe7,
+    ],
+    [
+        0x2b, 0x49, 0x5b, 0x85, 0x45, 0x53, 0xd0, 0x2a, 0x75, 0xb6, 0x87, 0x63, 0xbd, 0xa6, 0xdd, 0x8e,
+        0xf0, 0xe8, 0x1f, 0xe3, 0x2b, 0x5c, 0xb0, 0x72, 0xd3, 0xf5, 0x80, 0xa9, 0x14, 0x96, 0x7b, 0xf0,
+    ],
+    [
+        0x05, 0x6f, 0xa5, 0xd7, 0xce, 0x2c, 0x32, 0xbc, 0x57, 0x35, 0x35, 0x1d, 0x2d, 0x48, 0xaf, 0x5d,
+        0x6c, 0x92, 0x56, 0xa4, 0xb7, 0x2e, 0x12, 0xa3, 0x56, 0x40, 0xb5, 0x3e, 0xa9, 0x59, 0xe2, 0x96,
+    ],
+    [
+        0x2e, 0xcb, 0xe1, 0xa0, 0x6b, 0x28, 0xec, 0x78, 0xea, 0x7d, 0x22, 0xdf, 0x11, 0xcd, 0x22, 0x4a,
+        0x0e, 0x1a, 0x0a, 0xc7, 0xa3, 0x47, 0x6c, 0x5c, 0xc3, 0xc4, 0xe5, 0x0d, 0xa9, 0x61, 0xa8, 0x7b,
+    ],
+    [
+        0x14, 0x30, 0x53, 0xc1, 0xc3, 0x87, 0xf9, 0x35, 0x3b, 0xd6, 0x77, 0xe5, 0xdb, 0x6e, 0x99, 0x75,
+        0x79, 0x2b, 0xff, 0xee, 0xcf, 0x02, 0xd6, 0xaa, 0x0f, 0xe8, 0xf6, 0x27, 0x57, 0x9f, 0xe6, 0xcf,
+    ],
+    [
+        0x04, 0x06, 0x4b, 0x25, 0x58, 0x44, 0x99, 0xa0, 0x11, 0xea, 0x74, 0xac, 0x04, 0x7a, 0x8b, 0x37,
+        0x49, 0x41, 0x89, 0xbc, 0x63, 0x34, 0x2b, 0xb1, 0x18, 0x2e, 0x23, 0x42, 0x12, 0xd4, 0x2b, 0xdb,
+    ],
+    [
+        0x10, 0x0f, 0x61, 0xf6, 0xb8, 0x15, 0x42, 0x15, 0x67, 0x60, 0xa6, 0xd2, 0x9a, 0xf5, 0xdd, 0x86,
+        0x44, 0xc8, 0x2d, 0xea, 0xb5, 0xb9, 0x0e, 0x6a, 0xc6, 0x6d, 0xe8, 0xb4, 0xa0, 0x69, 0x56, 0x4a,
+    ],
+    [
+        0x18, 0xd3, 0x31, 0xb2, 0xe8, 0x18, 0x0b, 0x7a, 0xa7, 0x05, 0x5c, 0x02, 0x9f, 0xa7, 0x7a, 0xff,
+        0x4f, 0x02, 0xb0, 0xe2, 0xa2, 0x07, 0x8b, 0xff, 0x67, 0x68, 0x8e, 0xd6, 0x5b, 0x51, 0xd6, 0xdd,
+    ],
+    [
+        0x0f, 0xcf, 0x9b, 0x89, 0xe1, 0x9b, 0x1a, 0x55, 0xf9, 0xb0, 0x32, 0x55, 0xcf, 0x80, 0xcc, 0xb9,
+        0xfb, 0x6a, 0xaf, 0xde, 0x2b, 0x46, 0x20, 0x7d, 0x87, 0x3f, 0xac, 0x80, 0xcc, 0xef, 0x08, 0x5c,
+    ],
+    [
+        0x25, 0x4f, 0x80, 0x1f, 0x3c, 0x89, 0xc6, 0x24, 0x36, 0xef, 0x63, 0xdf, 0x67, 0xd9, 0x12, 0xc1,
+        0xf2, 0x31, 0xbb, 0x1f, 0x76, 0xc6, 0xc7, 0xb4, 0x97, 0xf7, 0x70, 0x8d, 0xbc, 0xfd, 0xc1, 0x7b,
+    ],
+    [
+        0x16, 0xd8, 0xe8, 0x5e, 0x57, 0x81, 0x9a, 0x10, 0xa6, 0x14, 0x61, 0xbc, 0xd8, 0xd3, 0xd3, 0xc9,
+        0x63, 0xc4, 0xf9, 0x5a, 0xfb, 0xa3, 0xe4, 0xd8, 0xfd, 0x0d, 0x6e, 0xc8, 0x82, 0xb9, 0xd1, 0x61,
+    ],
+    [
+        0x29, 0xc9, 0x56, 0x5a, 0xa7, 0x02, 0x08, 0x8b, 0xd4, 0x43, 0xe4, 0x62, 0x0d, 0x84, 0xbb, 0x2b,
+        0xfb, 0x93, 0x25, 0x3a, 0x8f, 0x0f, 0x04, 0xe5, 0xcf, 0x40, 0x8c, 0x92, 0x82, 0x0a, 0xda, 0x84,
+    ],
+    [
+        0x29, 0x4f, 0xb6, 0x80, 0x64, 0x64, 0x3d, 0x8b, 0xeb, 0x23, 0x7b, 0xd7, 0x05, 0xa5, 0x57, 0x87,
+        0x1b, 0x5f, 0x14, 0x08, 0x62, 0x4e, 0xda, 0x46, 0xd4, 0x7b, 0x58, 0x3a, 0x60, 0xd2, 0x9c, 0xf9,
+    ],
+    [
+        0x2c, 0x26, 0xf3, 0x08, 0x31, 0x40, 0xc1, 0x04, 0xba, 0xf2, 0x4c, 0x3d, 0xf9, 0x22, 0x31, 0x75,
+        0x84, 0xaa, 0x11, 0x77, 0x04, 0x51, 0xe6, 0xa8, 0x46, 0xde, 0x7d, 0xc7, 0x95, 0x19, 0x73, 0x13,
+    ],
+    [
+        0x23, 0x18, 0x11, 0x27, 0x98, 0x54, 0x70, 0x7d, 0xba, 0xa5, 0x37, 0xcb, 0x27, 0x24, 0x1e, 0x70,
+        0x21, 0xa9, 0x49, 0xa1, 0xd9, 0x67, 0x13, 0x80, 0xa7, 0xd6, 0x94, 0x4c, 0x83, 0x89, 0x04, 0xcb,
+    ],
+    [
+        0x14, 0x19, 0x36, 0x5a, 0xdb, 0xb9, 0x1f, 0x1f, 0x7d, 0x1b, 0xb0, 0xaa, 0x8a, 0x4b, 0x5d, 0x11,
+        0xc7, 0xad, 0x05, 0x70, 0xcc, 0x6c, 0xf3, 0x63, 0x8b, 0xe9, 0xc5, 0xa2, 0xa2, 0x75, 0xb4, 0x95,
+    ],
+    [
+        0x16, 0x74, 0x42, 0x5f, 0x2e, 0x9f, 0xca, 0xa6, 0x6e, 0xb0, 0x01, 0xfe, 0xca, 0x48, 0xdd, 0xd4,
+        0xb2, 0xa2, 0x9e, 0x86, 0xd4, 0x51, 0xf0, 0x0d, 0x17, 0x6e, 0xa3, 0x5b, 0x4e, 0x02, 0xfb, 0x84,
+    ],
+    [
+        0x29, 0x1f, 0x95, 0x6d, 0xef, 0x59, 0xb8, 0x78, 0x02, 0x80, 0x00, 0xce, 0xf0, 0x3d, 0x7b, 0x54,
+        0xdc, 0xc2, 0xfe, 0x1d, 0xd5, 0xa7, 0x5d, 0xc1, 0x80, 0xd6, 0xd5, 0xbe, 0xd8, 0x98, 0xcd, 0x30,
+    ],
+    [
+        0x2f, 0x13, 0x02, 0x89, 0x4f, 0xe6, 0xa2, 0x6b, 0x5b, 0xc0, 0x3f, 0xba, 0xa6, 0x9c, 0x4d, 0x95,
+        0x01, 0x23, 0x94, 0xd7, 0x8d, 0xe4, 0x94, 0x54, 0x9c, 0x70, 0x57, 0xb7, 0xef, 0x96, 0x5b, 0xcc,
+    ],
+    [
+        0x06, 0x27, 0xd6, 0xd6, 0xd9, 0x4d, 0x0a, 0x19, 0xa1, 0x7d, 0xb4, 0x5c, 0x07, 0x96, 0xf7, 0xdb,
+        0x8d, 0xe1, 0x3d, 0x27, 0x8a, 0x2b, 0x24, 0xc9, 0x9a, 0xc5, 0xd9, 0x2d, 0x0a, 0x1a, 0x1c, 0xe2,
+    ],
+    [
+        0x2e, 0xe2, 0x97, 0x66, 0x2b, 0xaf, 0xeb, 0xcb, 0x01, 0xdf, 0x8e, 0x62, 0xbd, 0x5f, 0x50, 0xf0,
+        0x30, 0x25, 0x51, 0x5b, 0x10, 0x46, 0x5d, 0x59, 0xf3, 0xa2, 0x94, 0xf0, 0xbf, 0xbb, 0x2f, 0xc5,
+    ],
+    [
+        0x11, 0x80, 0x29, 0x94, 0x5d, 0xf7, 0x73, 0xf5, 0x2f, 0xef, 0x54, 0x29, 0xda, 0x76, 0x6b, 0x90,
+        0x2e, 0xea, 0xe7, 0xd9, 0x67, 0x06, 0x2f, 0xdd, 0x87, 0x9c, 0x60, 0xa1, 0xbf, 0xab, 0xa7, 0x5b,
+    ],
+    [
+        0x30, 0x60, 0x42, 0xd2, 0x62, 0xf6, 0x26, 0x8b, 0xa7, 0x89, 0xeb, 0x43, 0x23, 0xc2, 0x16, 0x92,
+        0x79, 0x75, 0x2f, 0xfe, 0xc6, 0xb8, 0x53, 0x6c, 0xc6, 0x8b, 0x53, 0xbd, 0x4d, 0xf0, 0xe8, 0x58,
+    ],
+    [
+        0x04, 0x72, 0xa1, 0x3a, 0xc7, 0xda, 0x9e, 0xbe, 0x11, 0x32, 0xe0, 0x5f, 0x9e, 0xf6, 0x09, 0x13,
+        0x5c, 0x82, 0xa0, 0xeb, 0xd9, 0x27, 0x8d, 0xef, 0x2b, 0x34, 0xf8, 0xce, 0x70, 0x9a, 0xee, 0x4a,
+    ],
+    [
+        0x2e, 0x8f, 0x76, 0xb2, 0x7b, 0x47, 0x41, 0x80, 0xf0, 0x0c, 0x5a, 0x6c, 0x2d, 0x65, 0x85, 0x0b,
+        0xbf, 0x96, 0x01, 0x9b, 0xf0, 0xd7, 0x6d, 0xbb, 0x47, 0x43, 0xa9, 0x70, 0xf4, 0x2a, 0x13, 0x0f,
+    ],
+    [
+        0x1e, 0x33, 0x98, 0x41, 0x65, 0xf4, 0x7c, 0x20, 0x4a, 0x33, 0xfe, 0xe3, 0x1f, 0x61, 0x35, 0x44,
+        0xb7, 0xd8, 0x46, 0xa7, 0xd5, 0xea, 0x45, 0x28, 0x0e, 0xde, 0x4b, 0x36, 0x2c, 0x32, 0xaa, 0xa4,
+    ],
+    [
+        0x17, 0xf2, 0x2e, 0x27, 0x25, 0x2b, 0xdc, 0xf9, 0x1a, 0x72, 0xb5, 0x9e, 0xb2, 0x76, 0xa6, 0x66,
+        0xce, 0x4f, 0xea, 0x7a, 0xdf, 0xba, 0xcb, 0x2e, 0xa9, 0xfd, 0xf2, 0x85, 0x24, 0xb7, 0xb4, 0x3d,
+    ],
+    [
+        0x00, 0x54, 0x7e, 0x7c, 0x70, 0xee, 0x4e, 0x3f, 0xb4, 0xa4, 0xd3, 0x86, 0xc8, 0xba, 0xa5, 0x5d,
+        0x82, 0x3a, 0x0f, 0x02, 0xbc, 0x96, 0x3b, 0x43, 0x03, 0x9b, 0x88, 0x47, 0xcf, 0xf6, 0xe6, 0xc9,
+    ],
+    [
+        0x08, 0xea, 0x4c, 0xcb, 0xf8, 0x15, 0xbe, 0x32, 0x5c, 0xcd, 0x15, 0x49, 0x4f, 0x38, 0xf3, 0xf9,
+        0x71, 0x53, 0xab, 0x09, 0xc5, 0x4a, 0x87, 0xa8, 0x52, 0xb2, 0xa9, 0x94, 0x80, 0x72, 0x4e, 0x25,
+    ],
+    [
+        0x27, 0x7c, 0xb4, 0x5f, 0x3e, 0x63, 0x27, 0x18, 0x67, 0x3b, 0xa0, 0x5d, 0x48, 0xc7, 0x12, 0xfe,
+        0x74, 0x04, 0xdf, 0x9e, 0xff, 0x54, 0x01, 0xce, 0xbe, 0x45, 0xd1, 0x94, 0xc6, 0x7f, 0xa8, 0xdb,
+    ],
+    [
+        0x01, 0x59, 0xec, 0x7e, 0xa4, 0x9a, 0xad, 0xb3, 0xa8, 0xb0, 0xa2, 0x3c, 0x1d, 0xba, 0x00, 0x23,
+        0x80, 0x7b, 0x0d, 0x09, 0x9d, 0x3c, 0x40, 0xb6, 0x9d, 0xf8, 0x66, 0xa9, 0x2d, 0xc8, 0x7b, 0x37,
+    ],
+    [
+        0x0c, 0x33, 0x48, 0x92, 0x97, 0xe2, 0x44, 0x95, 0x6d, 0xe5, 0x79, 0xc5, 0xc1, 0x12, 0xab, 0x40,
+        0xc7, 0xfb, 0x2d, 0x89, 0x86, 0x6a, 0x28, 0x41, 0x8f, 0x66, 0x0a, 0x86, 0xee, 0x18, 0x52, 0x4e,
+    ],
+    [
+        0x01, 0xbb, 0x4f, 0xcb, 0xae, 0x28, 0xa2, 0xf3, 0x39, 0x95, 0x68, 0xc6, 0xb3, 0x84, 0x9d, 0xa2,
+        0x7d, 0x6d, 0xdf, 0x4f, 0xe6, 0xad, 0xab, 0xb5, 0x91, 0x15, 0x16, 0x79, 0xed, 0x37, 0xd2, 0x1f,
+    ],
+    [
+        0x2a, 0x23, 0x34, 0x3d, 0x24, 0xa9, 0xcf, 0xa3, 0xb8, 0xf4, 0x13, 0xf5, 0x2e, 0xe1, 0xb2, 0x33,
+        0xce, 0x4c, 0x1d, 0x5b, 0x9b, 0xf3, 0x4b, 0x03, 0xdb, 0xc6, 0xd7, 0x33, 0xd5, 0x23, 0x05, 0xc9,
+    ],
+    [
+        0x07, 0x07, 0xa6, 0xd5, 0x15, 0xb3, 0xba, 0xd0, 0x3a, 0x9b, 0x06, 0xd6, 0xbe, 0x04, 0x8d, 0xef,
+        0xa8, 0x50, 0x13, 0x4a, 0xac, 0xd1, 0x20, 0xf3, 0x8f, 0xa9, 0x32, 0x64, 0x01, 0x6b, 0x77, 0x86,
+    ],
+    [
+        0x18, 0x37, 0xdb, 0x54, 0x01, 0xdd, 0x6e, 0xc3, 0x0b, 0xa6, 0xb8, 0x66, 0x18, 0x4d, 0xa5, 0x6e,
+        0x8d, 0xb4, 0x39, 0xff, 0xd0, 0x41, 0x54, 0xec, 0x46, 0x9b, 0x62, 0x12, 0x0c, 0xe3, 0x5c, 0xb4,
+    ],
+    [
+        0x24, 0x48, 0x72, 0xa0, 0xd2, 0x76, 0x6b, 0x85, 0x47, 0xe6, 0xb6, 0xa5, 0xaf, 0xea, 0xbf, 0x19,
+        0x8e, 0x14, 0x09, 0xa0, 0xf0, 0x02, 0x6d, 0xe3, 0x6c, 0x10, 0x17, 0x93, 0x95, 0x2f, 0x6c, 0xfb,
+    ],
+    [
+        0x20, 0x4f, 0xb1, 0x79, 0x27, 0xce, 0x87, 0x3c, 0x76, 0x11, 0x96, 0x39, 0x5f, 0x7d, 0xa4, 0x6f,
+        0x67, 0x9c, 0xa3, 0x31, 0x83, 0x2a, 0x59, 0xfd, 0xdd, 0x2a, 0xe3, 0x1e, 0x84, 0xeb, 0xa1, 0xf5,
+    ],
+    [
+        0x05, 0x6f, 0x53, 0x03, 0x7d, 0x5d, 0x8e, 0xfc, 0x7c, 0xf6, 0xa6, 0xfe, 0xba, 0x18, 0xd1, 0x02,
+        0xbf, 0x81, 0x8b, 0xbb, 0xa7, 0x0b, 0x86, 0xdd, 0x9b, 0x76, 0x3a, 0x12, 0x07, 0x2f, 0x4c, 0x2e,
+    ],
+    [
+        0x1d, 0xf4, 0xbc, 0x5d, 0x73, 0x23, 0x9f, 0x5e, 0xc3, 0xb8, 0x9e, 0xfa, 0xd5, 0x3d, 0xf9, 0xcd,
+        0x8a, 0xd8, 0xfc, 0xa5, 0x43, 0xbe, 0x01, 0xf5, 0xf4, 0x0f, 0xea, 0x29, 0x52, 0x07, 0x1b, 0xd6,
+    ],
+    [
+        0x1f, 0xfb, 0x6c, 0xcb, 0xba, 0x00, 0x11, 0x55, 0xd4, 0x6e, 0x16, 0x7d, 0x1f, 0xad, 0x1c, 0x13,
+        0xbf, 0x28, 0x92, 0xac, 0x87, 0xcd, 0x9c, 0x34, 0x7c, 0x73, 0x4a, 0xb5, 0xef, 0x65, 0x83, 0x67,
+    ],
+    [
+        0x08, 0xee, 0x36, 0xe8, 0x81, 0x49, 0x65, 0x39, 0xb0, 0xab, 0xd6, 0x47, 0xd3, 0x53, 0xe5, 0x1b,
+        0x63, 0xfe, 0xf9, 0x1f, 0xc9, 0x44, 0x88, 0x00, 0x06, 0xf2, 0xc2, 0x6c, 0x2e, 0xcf, 0x2a, 0x24,
+    ],
+    [
+        0x1c, 0x30, 0x80, 0xc2, 0x54, 0x44, 0x33, 0x32, 0xa3, 0xe6, 0x28, 0x3d, 0x5b, 0xab, 0x6f, 0x7f,
+        0xce, 0x8a, 0xaa, 0x68, 0x48, 0x7b, 0x9e, 0xe4, 0xe3, 0x72, 0xcb, 0xc6, 0xcc, 0x17, 0x26, 0x65,
+    ],
+    [
+        0x2d, 0xc6, 0x04, 0x05, 0x00, 0xe7, 0x32, 0x9f, 0x75, 0xa3, 0xce, 0xfa, 0x4d, 0xb4, 0x9f, 0x9b,
+        0x47, 0x08, 0x5b, 0x16, 0x60, 0xf8, 0x87, 0x3b, 0x3e, 0x81, 0xb7, 0xb1, 0xaf, 0x82, 0xaf, 0x53,
+    ],
+    [
+        0x2c, 0x8b, 0xca, 0x9d, 0xd2, 0x93, 0xbb, 0x67, 0xdb, 0x1f, 0x66, 0xff, 0x2e, 0x82, 0x04, 0x7a,
+        0xe9, 0x38, 0x5c, 0xe6, 0x7d, 0x7f, 0x33, 0x56, 0x8c, 0xd4, 0xc2, 0x16, 0xa8, 0x99, 0x94, 0xb7,
+    ],
+    [
+        0x2a, 0x15, 0x7b, 0x97, 0x86, 0xf9, 0x22, 0x4c, 0xf6, 0x36, 0x48, 0xaa, 0xec, 0x1b, 0x9f, 0x61,
+        0xc7, 0xa7, 0x08, 0x7e, 0xd6, 0xeb, 0x8a, 0xb2, 0x0c, 0x6c, 0x54, 0x69, 0xd4, 0x01, 0xb7, 0xdf,
+    ],
+    [
+        0x1e, 0x0f, 0xb0, 0xcd, 0xd8, 0xa5, 0x45, 0x91, 0xc4, 0x2e, 0x7c, 0x26, 0xb9, 0x02, 0x7b, 0x77,
+        0x41, 0x7e, 0x4e, 0x65, 0x22, 0xb4, 0x72, 0xbf, 0xe6, 0x1f, 0xf8, 0x9c, 0x39, 0xbc, 0x40, 0xe9,
+    ],
+    [
+        0x25, 0x01, 0x44, 0xd1, 0xce, 0xa0, 0x33, 0xfd, 0x78, 0xbd, 0x73, 0xee, 0x4e, 0xa4, 0xcf, 0xdc,
+        0xd6, 0xa4, 0x2f, 0x7c, 0x00, 0xe2, 0xd7, 0xee, 0x67, 0x94, 0xd4, 0x64, 0x20, 0x7d, 0x2b, 0x3d,
+    ],
+    [
+        0x0b, 0x6b, 0x3f, 0xe9, 0xa7, 0xa5, 0x25, 0xca, 0x60, 0x44, 0x3c, 0x4b, 0x78, 0xa8, 0xe8, 0x30,
+        0x95, 0x7a, 0xae, 0x04, 0x03, 0x11, 0x6d, 0x22, 0x98, 0x3a, 0x2e, 0x68, 0x0e, 0xed, 0xff, 0x65,
+    ],
+    [
+        0x2f, 0xdf, 0x61, 0x3e, 0x67, 0xd1, 0x76, 0x56, 0x9f, 0x47, 0x43, 0xb4, 0x96, 0x3f, 0x8c, 0x53,
+        0xe5, 0x24, 0x82, 0xfa, 0x32, 0xe7, 0x05, 0x24, 0x7e, 0xb6, 0x50, 0xbc, 0x7b, 0xd6, 0x16, 0xe9,
+    ],
+    [
+        0x04, 0x8f, 0x64, 0xb1, 0x42, 0x75, 0xfb, 0x86, 0xfc, 0x55, 0xb3, 0xc0, 0x46, 0x9c, 0xd8, 0x93,
+        0x06, 0x75, 0x28, 0x27, 0xc3, 0xc0, 0x44, 0x63, 0xbe, 0x5b, 0xea, 0x66, 0xfb, 0xd7, 0xb3, 0x8c,
+    ],
+    [
+        0x0f, 0x20, 0x27, 0xb7, 0x81, 0xc2, 0xc5, 0x24, 0x90, 0xe1, 0x1e, 0xd4, 0xb7, 0xb4, 0x94, 0xd6,
+        0x6c, 0xdc, 0x16, 0xe6, 0x90, 0xb6, 0xa4, 0xb3, 0xc9, 0x24, 0xdc, 0x9d, 0x6e, 0x85, 0x48, 0x32,
+    ],
+    [
+        0x29, 0x65, 0x27, 0x85, 0x16, 0x0b, 0x26, 0x1c, 0x1c, 0x20, 0xc7, 0x41, 0x52, 0x4a, 0x26, 0x14,
+        0x7c, 0xc3, 0x19, 0x1f, 0x33, 0xb7, 0x4e, 0xbf, 0x17, 0xd4, 0x4c, 0xe2, 0xf8, 0x8b, 0x0f, 0x91,
+    ],
+    [
+        0x11, 0x76, 0x90, 0x9e, 0x02, 0xa5, 0xb3, 0x7b, 0xfd, 0x47, 0x81, 0x0f, 0xcb, 0xe7, 0x3a, 0xf8,
+        0x7f, 0xea, 0x13, 0x04, 0x70, 0xaa, 0x44, 0xbd, 0xf6, 0xb0, 0x58, 0xc4, 0xd3, 0x59, 0xe1, 0x79,
+    ],
+    [
+        0x18, 0x0f, 0x26, 0xc6, 0xce, 0x2a, 0x3a, 0x47, 0x7b, 0xaf, 0x0d, 0xa5, 0x8d, 0x06, 0x97, 0x69,
+        0xc2, 0xbf, 0x01, 0x56, 0xd6, 0x36, 0xf2, 0x25, 0x5e, 0x1b, 0xc3, 0x5c, 0x01, 0xe9, 0x89, 0x93,
+    ],
+    [
+        0x2a, 0xfe, 0xed, 0xcc, 0x2c, 0xec, 0x57, 0x28, 0x5a, 0xd2, 0x2e, 0x73, 0x4f, 0xb7, 0xfe, 0x3d,
+        0xd6, 0x5f, 0x62, 0x60, 0x1c, 0x1c, 0xa4, 0x88, 0x4a, 0x86, 0xc7, 0xa6, 0xa0, 0x5f, 0x08, 0x3d,
+    ],
+    [
+        0x0d, 0xd2, 0x1a, 0x87, 0xee, 0x22, 0x62, 0x69, 0x98, 0xdb, 0xcd, 0x73, 0xdd, 0x75, 0x1a, 0xff,
+        0xb4, 0x9d, 0x47, 0xa0, 0x2e, 0x99, 0x5a, 0x41, 0xbc, 0xa2, 0x63, 0x99, 0x10, 0x30, 0x87, 0xd2,
+    ],
+    [
+        0x1d, 0x21, 0xba, 0xa0, 0x9d, 0x7f, 0x50, 0xf0, 0x15, 0x9f, 0xa8, 0xa0, 0xb6, 0xe0, 0xd6, 0x14,
+        0x46, 0xd1, 0xc7, 0xdb, 0x5d, 0x15, 0x66, 0x46, 0x9d, 0xa6, 0xa8, 0x5a, 0xa7, 0x22, 0x49, 0x62,
+    ],
+    [
+        0x2d, 0xc5, 0x9c, 0x4c, 0x9e, 0x27, 0x5c, 0xd8, 0xc2, 0x50, 0x62, 0x82, 0xcc, 0x5b, 0xb8, 0x42,
+        0xc0, 0x14, 0xbe, 0xa4, 0x23, 0x6b, 0x1b, 0xc2, 0x7a, 0x85, 0x63, 0x51, 0xb4, 0x8a, 0xf8, 0x02,
+    ],
+    [
+        0x2c, 0xf6, 0x2d, 0x08, 0x54, 0x1d, 0xff, 0x09, 0x19, 0xba, 0x68, 0xa6, 0x97, 0xf4, 0x4f, 0x47,
+        0x91, 0xc4, 0x2d, 0x14, 0x47, 0xa4, 0x15, 0x13, 0xab, 0x9d, 0x5f, 0x75, 0x5e, 0xb1, 0xf3, 0x8a,
+    ],
+    [
+        0x2f, 0x5b, 0x88, 0x53, 0xe2, 0xcd, 0x2e, 0x47, 0xa9, 0xfe, 0xde, 0xea, 0x40, 0x47, 0xe4, 0x60,
+        0x83, 0xc9, 0x7a, 0xcd, 0x4b, 0x68, 0x7a, 0xeb, 0xd2, 0x26, 0x4e, 0x7a, 0x0e, 0xb3, 0x54, 0xb1,
+    ],
+    [
+        0x05, 0x86, 0x53, 0x28, 0xa9, 0xb2, 0xfd, 0x46, 0x91, 0x70, 0xc3, 0x91, 0xc5, 0xba, 0x8d, 0xb5,
+        0xc6, 0x6e, 0xef, 0x99, 0x38, 0xe4, 0xda, 0x5b, 0xae, 0x33, 0xf2, 0x70, 0x3c, 0x47, 0x07, 0x30,
+    ],
+    [
+        0x29, 0x76, 0x1c, 0x9f, 0xc3, 0xd2, 0xc4, 0xcc, 0x1b, 0x12, 0x6b, 0xe1, 0xdc, 0x04, 0x70, 0xa9,
+        0x7b, 0x8f, 0x27, 0x25, 0xd3, 0x27, 0xc6, 0xd1, 0xca, 0x92, 0x3c, 0x2a, 0xa8, 0x09, 0x2b, 0x1e,
+    ],
+    [
+        0x1d, 0x3f, 0xb1, 0xf8, 0xdc, 0x3c, 0x77, 0xcb, 0x51, 0x5c, 0x37, 0xbc, 0x81, 0x0d, 0x3a, 0x5e,
+        0x3b, 0x96, 0x55, 0x2d, 0x4b, 0xbb, 0x57, 0xfd, 0xb0, 0x1c, 0x2a, 0xee, 0xb1, 0xd9, 0xa0, 0x48,
+    ],
+    [
+        0x05, 0xf2, 0xfb, 0xeb, 0x7c, 0xae, 0x0a, 0x35, 0xee, 0x2f, 0x3a, 0x4d, 0x29, 0x64, 0xd2, 0x41,
+        0xa0, 0xce, 0xeb, 0x47, 0xb3, 0xf7, 0xc4, 0xc8, 0x7b, 0xb3, 0xb0, 0x52, 0xc8, 0x70, 0x5d, 0x90,
+    ],
+    [
+        0x1f, 0x44, 0x50, 0x14, 0xa0, 0xf2, 0x66, 0x08, 0x2a, 0x50, 0x25, 0x71, 0x52, 0x60, 0x9c, 0xc2,
+        0x19, 0x06, 0x21, 0x54, 0xf4, 0xd7, 0xc1, 0x32, 0x88, 0x74, 0x69, 0x54, 0xb9, 0x79, 0x98, 0xf8,
+    ],
+    [
+        0x2d, 0x35, 0x91, 0x7c, 0xb1, 0xd0, 0xca, 0x61, 0x1e, 0xe3, 0x7c, 0xfd, 0x90, 0x78, 0xc9, 0x24,
+        0xfc, 0x84, 0xbd, 0x1a, 0x4b, 0xfd, 0x7d, 0xb3, 0xce, 0x39, 0xe0, 0x5b, 0x38, 0xf6, 0x98, 0xb1,
+    ],
+    [
+        0x05, 0xa8, 0x9a, 0x4e, 0xb4, 0x03, 0x87, 0xb8, 0x51, 0x2f, 0x2f, 0x39, 0x8b, 0x65, 0x26, 0xef,
+        0xe7, 0x67, 0x16, 0x95, 0x1c, 0x50, 0x0f, 0xc8, 0xda, 0xbc, 0x83, 0x57, 0x75, 0x33, 0x5c, 0xed,
+    ],
+    [
+        0x04, 0xe2, 0x6a, 0xa3, 0xf0, 0x26, 0x01, 0x17, 0x4f, 0x46, 0xce, 0xd1, 0x55, 0x94, 0x32, 0x9e,
+        0x4f, 0x4e, 0x5b, 0xd5, 0xd7, 0xd5, 0x3d, 0x5d, 0xf6, 0xc8, 0x63, 0x3a, 0x94, 0xdd, 0x01, 0xde,
+    ],
+    [
+        0x20, 0x4d, 0x58, 0x7d, 0x61, 0x52, 0x6c, 0x1e, 0x04, 0x7e, 0x1d, 0xc6, 0xb6, 0xd4, 0x03, 0xb9,
+        0xeb, 0x87, 0x30, 0xf2, 0xe2, 0xdf, 0xb7, 0xbc, 0x73, 0x50, 0xd9, 0x39, 0xba, 0xc5, 0xb9, 0x0f,
+    ],
+    [
+        0x21, 0xc8, 0xca, 0x21, 0x3d, 0x2f, 0x44, 0x44, 0x6a, 0xe9, 0x0a, 0x6b, 0xda, 0xe5, 0x84, 0x3b,
+        0x7c, 0x2c, 0xb5, 0x3e, 0xd6, 0x99, 0xf3, 0x66, 0x75, 0xea, 0x82, 0x80, 0xa2, 0xa4, 0x20, 0xe5,
+    ],
+    [
+        0x13, 0x03, 0xbd, 0x92, 0x1f, 0x32, 0xfa, 0x4c, 0x4e, 0x73, 0xaf, 0x21, 0x45, 0x7c, 0x46, 0xb6,
+        0xd6, 0x9e, 0x2e, 0x6a, 0x2c, 0x39, 0x67, 0x16, 0x21, 0x77, 0x87, 0xb4, 0x6e, 0x82, 0x2e, 0xbf,
+    ],
+    [
+        0x06, 0x13, 0x82, 0x3c, 0x5b, 0x6a, 0xbe, 0xef, 0x14, 0x38, 0x74, 0x65, 0x31, 0x7e, 0x71, 0x3b,
+        0x45, 0xac, 0xe7, 0x9c, 0x0e, 0x44, 0x33, 0xd7, 0xc4, 0xd3, 0x52, 0x09, 0x7e, 0x5c, 0x83, 0x99,
+    ],
+    [
+        0x0f, 0x27, 0xc5, 0x30, 0x5e, 0xe9, 0x78, 0xf4, 0xf7, 0x75, 0xe2, 0x13, 0xe3, 0xba, 0x12, 0xa9,
+        0x01, 0xf5, 0x11, 0x44, 0xce, 0x1e, 0xa8, 0x9b, 0xb5, 0x04, 0x69, 0x30, 0xf5, 0xec, 0x55, 0xd1,
+    ],
+    [
+        0x2c, 0xad, 0x11, 0xc6, 0x93, 0xc6, 0xa6, 0x27, 0x53, 0x19, 0x50, 0xa3, 0x07, 0xe0, 0x7b, 0x30,
+        0xea, 0x8a, 0x49, 0x51, 0x15, 0xf5, 0xa5, 0x38, 0x88, 0x27, 0x79, 0x21, 0x20, 0xc7, 0xbd, 0xb0,
+    ],
+    [
+        0x16, 0xbb, 0x25, 0x68, 0x83, 0xc4, 0x4c, 0xf3, 0x73, 0x66, 0x61, 0x8e, 0x46, 0xc6, 0xdb, 0x4e,
+        0x3d, 0xa9, 0x7a, 0x9d, 0x4a, 0xe7, 0xd5, 0xc5, 0x85, 0x95, 0x9d, 0xe3, 0x27, 0x2f, 0x82, 0xb1,
+    ],
+    [
+        0x29, 0xaf, 0xe5, 0x0b, 0xd7, 0x8f, 0x72, 0xcc, 0x76, 0x36, 0x95, 0x97, 0x64, 0x10, 0x32, 0x4b,
+        0xfd, 0x67, 0x15, 0x7a, 0x5c, 0xcd, 0xb7, 0xae, 0x45, 0xb0, 0x00, 0x32, 0xe1, 0xd5, 0x7a, 0x51,
+    ],
+    [
+        0x09, 0x32, 0x99, 0x02, 0xe5, 0x9e, 0x52, 0x43, 0xa7, 0x26, 0xae, 0x5c, 0xa3, 0x54, 0x08, 0xa2,
+        0x07, 0x14, 0x86, 0x09, 0xab, 0x36, 0x98, 0x13, 0x97, 0x25, 0xae, 0xfe, 0x29, 0xae, 0xb1, 0x67,
+    ],
+    [
+        0x04, 0xb1, 0x80, 0x0e, 0x60, 0x0c, 0x90, 0xec, 0x47, 0x76, 0xbc, 0x6a, 0xf1, 0x76, 0x4c, 0xcb,
+        0xdc, 0x10, 0xbf, 0x55, 0xc3, 0x31, 0x4f, 0x42, 0xac, 0xd0, 0x53, 0x1a, 0xd5, 0x34, 0x20, 0xfd,
+    ],
+    [
+        0x29, 0x8d, 0x01, 0x10, 0xbb, 0x90, 0xe4, 0xc3, 0x5e, 0x29, 0x3c, 0x7a, 0x7d, 0xac, 0xed, 0xef,
+        0x7e, 0xe9, 0x34, 0x2a, 0x59, 0x1b, 0x43, 0x30, 0xe2, 0x72, 0x76, 0xaa, 0x13, 0xb6, 0x7a, 0xd1,
+    ],
+    [
+        0x02, 0x4e, 0xa6, 0xe6, 0x8c, 0xb1, 0xaa, 0x6b, 0x40, 0x7e, 0x02, 0x26, 0x49, 0xaa, 0x32, 0xd1,
+        0xb7, 0xea, 0x4a, 0x35, 0x0e, 0x51, 0x0c, 0x07, 0xee, 0xe4, 0x39, 0x29, 0x39, 0x5e, 0x4f, 0x82,
+    ],
+    [
+        0x22, 0xae, 0x3a, 0x1a, 0x81, 0xc4, 0x55, 0x0f, 0x5f, 0xa0, 0x11, 0x6e, 0x13, 0x5d, 0x2d, 0xf2,
+        0xbd, 0x9f, 0x68, 0x35, 0xa0, 0xf0, 0xd0, 0x3f, 0x4d, 0xd9, 0x34, 0xe1, 0x32, 0x0a, 0x3d, 0x8d,
+    ],
+    [
+        0x1f, 0x0f, 0x5e, 0xee, 0x9e, 0x9a, 0xbb, 0x2a, 0x2f, 0x4a, 0xe0, 0x4d, 0x6f, 0xe0, 0x93, 0x11,
+        0xfe, 0x01, 0x15, 0xc1, 0xe1, 0x99, 0xc2, 0x8e, 0x22, 0x70, 0x5e, 0x68, 0x14, 0x42, 0xed, 0x37,
+    ],
+    [
+        0x02, 0x3c, 0x86, 0x60, 0x66, 0x16, 0xf3, 0x17, 0xaf, 0x02, 0x24, 0x64, 0xf3, 0x50, 0xb4, 0xbe,
+        0xe8, 0x0c, 0x2f, 0xca, 0x32, 0x06, 0x4b, 0xcb, 0xf8, 0x3e, 0x8c, 0xd3, 0x5d, 0xac, 0x06, 0xc7,
+    ],
+    [
+        0x2a, 0x89, 0xc4, 0xe5, 0xb0, 0x74, 0xfb, 0xe6, 0x7d, 0x04, 0xcb, 0x03, 0xef, 0xe1, 0x8c, 0x4b,
+        0xf9, 0x04, 0xbe, 0xc4, 0x75, 0x9b, 0x33, 0xda, 0xd6, 0xb2, 0xc8, 0x6e, 0xbf, 0xd2, 0xb9, 0xd5,
+    ],
+    [
+        0x04, 0xb0, 0xf4, 0x8c, 0x4f, 0x2e, 0x05, 0xbd, 0xa4, 0xb9, 0xcf, 0x80, 0x60, 0x75, 0xf9, 0xc2,
+        0x75, 0xdf, 0x2c, 0xf6, 0x0b, 0x5f, 0xd1, 0x14, 0x70, 0xea, 0xa7, 0x39, 0x4a, 0x82, 0x72, 0x7e,
+    ],
+    [
+        0x0e, 0xdd, 0xe5, 0xa2, 0xc0, 0x13, 0x27, 0xad, 0x04, 0x2a, 0x68, 0x6f, 0x44, 0x34, 0x17, 0xbd,
+        0x8f, 0x10, 0x7c, 0x99, 0xeb, 0x7c, 0x02, 0x90, 0x0c, 0x93, 0x1a, 0x7b, 0xd8, 0xcb, 0x34, 0x9e,
+    ],
+    [
+        0x2c, 0xac, 0xac, 0xc9, 0xc9, 0xd8, 0xb0, 0x00, 0x4d, 0x89, 0x32, 0x08, 0x0f, 0x93, 0x78, 0xb6,
+        0x06, 0x75, 0xa8, 0xbd, 0x85, 0x3a, 0xad, 0x1d, 0xc5, 0x06, 0x72, 0xf1, 0x37, 0x21, 0x2c, 0xc1,
+    ],
+    [
+        0x08, 0x16, 0xf3, 0x35, 0x08, 0x62, 0x1f, 0x2d, 0x56, 0x37, 0x11, 0x56, 0xb0, 0xa3, 0xf7, 0x73,
+        0x10, 0x30, 0xe4, 0xb2, 0x18, 0xe4, 0x4c, 0x90, 0xa8, 0x8c, 0xec, 0xaf, 0x07, 0x17, 0x0d, 0xec,
+    ],
+    [
+        0x2e, 0xf8, 0xe0, 0xa4, 0x55, 0xd4, 0x72, 0xb4, 0x9b, 0xff, 0xda, 0x93, 0x5c, 0x8d, 0x3b, 0xee,
+        0x1a, 0xe9, 0x24, 0x86, 0x08, 0xe2, 0xa5, 0x2d, 0x53, 0xf4, 0xb7, 0x63, 0x13, 0xc9, 0x79, 0x20,
+    ],
+    [
+        0x17, 0x18, 0x4a, 0x8a, 0xf9, 0x19, 0x9a, 0xba, 0x60, 0x5d, 0xd1, 0xf2, 0xdd, 0x18, 0xeb, 0xc0,
+        0x2e, 0x30, 0x55, 0xce, 0x40, 0x80, 0x68, 0xec, 0xca, 0xaf, 0x3e, 0xa7, 0xc0, 0xa3, 0x34, 0xaf,
+    ],
+    [
+        0x16, 0x39, 0x5e, 0x15, 0x39, 0x37, 0xa8, 0x36, 0x60, 0xdb, 0x6f, 0x78, 0x44, 0x6c, 0xb9, 0x95,
+        0x83, 0x64, 0x36, 0x60, 0x27, 0x77, 0xd8, 0x1f, 0x06, 0x07, 0x13, 0x0b, 0x44, 0x88, 0x14, 0x48,
+    ],
+    [
+        0x0e, 0x17, 0x40, 0x88, 0x22, 0x3c, 0x38, 0xe9, 0x44, 0x0f, 0xc7, 0x73, 0x07, 0xd6, 0x72, 0x0a,
+        0xc3, 0x27, 0x84, 0xc9, 0xd2, 0xf5, 0x91, 0xe2, 0x43, 0x7e, 0xae, 0xa9, 0xb8, 0xf5, 0xe2, 0xbb,
+    ],
+    [
+        0x19, 0xe7, 0x0f, 0xa9, 0x37, 0x78, 0x8f, 0x7a, 0x21, 0x27, 0x57, 0x3d, 0x56, 0xc8, 0x19, 0x7b,
+        0xd0, 0xdd, 0x09, 0x61, 0x40, 0xf3, 0x90, 0x64, 0x86, 0x5c, 0x65, 0xc3, 0x1b, 0x47, 0xd4, 0x02,
+    ],
+    [
+        0x0e, 0x43, 0x4c, 0x32, 0x9d, 0x52, 0x42, 0xad, 0xbd, 0xab, 0x24, 0x78, 0x04, 0x95, 0xff, 0x97,
+        0x23, 0x0b, 0xeb, 0x34, 0x4b, 0x91, 0x8f, 0x6f, 0x8d, 0x6b, 0xd7, 0xd2, 0x1b, 0x03, 0x69, 0x2e,
+    ],
+    [
+        0x2c, 0x88, 0xa8, 0x2e, 0xb8, 0x7c, 0x81, 0xba, 0x42, 0xa2, 0x11, 0xbf, 0x2e, 0xf3, 0xc1, 0x58,
+        0x75, 0xac, 0x15, 0x7c, 0x7f, 0x38, 0x68, 0xff, 0xf3, 0x5a, 0xf9, 0xf5, 0x5a, 0x8c, 0x22, 0xbc,
+    ],
+    [
+        0x11, 0x1d, 0xc2, 0x23, 0xe3, 0xc9, 0xfc, 0x74, 0x52, 0xa9, 0xc2, 0xde, 0x46, 0x93, 0x83, 0x91,
+        0x5f, 0x33, 0xad, 0xf4, 0xcf, 0x5b, 0x49, 0xfd, 0x2e, 0x01, 0xc5, 0x1d, 0x09, 0xcc, 0xf4, 0x36,
+    ],
+    [
+        0x10, 0x64, 0x73, 0x53, 0xde, 0x31, 0x97, 0xb9, 0xcb, 0x48, 0xf4, 0x53, 0x9b, 0x50, 0x7e, 0x45,
+        0x39, 0x3b, 0x00, 0x08, 0x42, 0xe8, 0xba, 0xa1, 0x21, 0x8a, 0xf0, 0x97, 0xf8, 0x71, 0xba, 0xea,
+    ],
+    [
+        0x0e, 0x53, 0xd6, 0x41, 0x21, 0xc0, 0xa8, 0x51, 0xc1, 0xb3, 0xf0, 0x74, 0x23, 0x58, 0x20, 0x34,
+        0xab, 0xce, 0x69, 0x85, 0x25, 0xd0, 0xa4, 0x77, 0x24, 0x35, 0x4c, 0x07, 0x15, 0x2f, 0x70, 0x3b,
+    ],
+    [
+        0x28, 0x91, 0x93, 0x8a, 0xcb, 0x83, 0xee, 0x19, 0x37, 0x54, 0x96, 0x9f, 0x4a, 0xa5, 0xa6, 0x2a,
+        0x53, 0x10, 0x81, 0xdb, 0xca, 0x4d, 0xec, 0x22, 0x62, 0x58, 0xea, 0x92, 0x98, 0x4b, 0x43, 0x48,
+    ],
+    [
+        0x04, 0x10, 0x6f, 0x95, 0xe4, 0xba, 0x3f, 0xb7, 0x4d, 0xbe, 0x4b, 0xc5, 0x3a, 0xbc, 0x17, 0x02,
+        0x62, 0xba, 0x00, 0xc7, 0xc6, 0x3e, 0xd8, 0xc4, 0x57, 0xc3, 0x22, 0x75, 0x32, 0xc1, 0x2c, 0xe3,
+    ],
+    [
+        0x2a, 0x73, 0xe6, 0x1b, 0x8f, 0x1b, 0xab, 0xfb, 0xb3, 0x8b, 0x18, 0x6f, 0x40, 0x1e, 0xed, 0x0d,
+        0x92, 0xc5, 0x61, 0x3b, 0xa2, 0x5d, 0xd2, 0xff, 0x4d, 0x09, 0x9f, 0x7d, 0xd3, 0xc1, 0x9f, 0xe5,
+    ],
+    [
+        0x18, 0xe1, 0x6b, 0x24, 0x64, 0x2f, 0x19, 0x19, 0x3b, 0x79, 0x8b, 0x92, 0x3b, 0x8d, 0xd2, 0x5f,
+        0x6e, 0x48, 0x56, 0xd6, 0xb2, 0x1b, 0x72, 0x9a, 0xed, 0x22, 0x81, 0xab, 0x15, 0x4f, 0x7c, 0x5f,
+    ],
+    [
+        0x0d, 0x5f, 0x84, 0x9b, 0x16, 0x6a, 0xb9, 0xf6, 0xfc, 0x1a, 0x64, 0x46, 0x7c, 0x55, 0x57, 0x02,
+        0x9f, 0x35, 0x9a, 0x2d, 0x0f, 0x19, 0x40, 0x30, 0xc4, 0xdd, 0x87, 0x5e, 0x12, 0xae, 0x34, 0x85,
+    ],
+    [
+        0x0e, 0x25, 0x25, 0x0c, 0x57, 0x36, 0x5a, 0x29, 0xc6, 0x3a, 0x4f, 0x15, 0x31, 0xb8, 0xb0, 0xef,
+        0x52, 0x3e, 0xaa, 0x93, 0xc1, 0xd7, 0x0a, 0xcd, 0x0a, 0xa7, 0xcd, 0x78, 0x44, 0xdd, 0x04, 0x42,
+    ],
+    [
+        0x0f, 0x81, 0x3d, 0x14, 0xaf, 0x0c, 0x85, 0xbc, 0xed, 0xfb, 0xf4, 0x80, 0xb3, 0xd8, 0x0b, 0x9f,
+        0x33, 0x58, 0x53, 0xc6, 0x01, 0x88, 0x52, 0x6d, 0x80, 0x76, 0x12, 0x74, 0x07, 0x4a, 0x59, 0x9a,
+    ],
+    [
+        0x0d, 0x23, 0x56, 0xa9, 0x2d, 0xba, 0xae, 0x8c, 0x24, 0xa8, 0xce, 0xbe, 0xd6, 0x42, 0x80, 0x83,
+        0xef, 0x2f, 0x46, 0x94, 0x60, 0xf1, 0x66, 0xa6, 0xe7, 0x74, 0x37, 0x7e, 0x78, 0x05, 0x54, 0x35,
+    ],
+    [
+        0x1f, 0xb6, 0xdc, 0xeb, 0x49, 0x5b, 0xf9, 0x0f, 0xff, 0x56, 0xc8, 0xaa, 0xd7, 0x0b, 0x40, 0xbf,
+        0x77, 0x3e, 0xa1, 0x00, 0x3c, 0xdd, 0x09, 0x2f, 0xd7, 0x7a, 0x43, 0x96, 0x1e, 0x9d, 0x7d, 0xc7,
+    ],
+    [
+        0x2c, 0x0a, 0x70, 0xce, 0xd7, 0x99, 0x51, 0xa7, 0x5e, 0x23, 0xc5, 0xa3, 0x23, 0xfd, 0x70, 0x6c,
+        0x2a, 0xb8, 0x7c, 0x38, 0x1c, 0xfc, 0x3c, 0x26, 0x90, 0x81, 0x62, 0x77, 0x41, 0xc2, 0xbb, 0xe8,
+    ],
+    [
+        0x13, 0xdc, 0x6b, 0x3b, 0xdb, 0x05, 0x16, 0x7d, 0xce, 0xb4, 0xb1, 0x10, 0x37, 0x06, 0x4a, 0xc5,
+        0xfd, 0x22, 0xdb, 0x5e, 0x22, 0x9e, 0x4c, 0x6b, 0xa5, 0x5e, 0x83, 0x82, 0x32, 0x1d, 0xc5, 0xee,
+    ],
+    [
+        0x21, 0x0f, 0xa8, 0xb7, 0x77, 0x90, 0x7e, 0xc3, 0x0b, 0xb8, 0x8d, 0xbd, 0x7c, 0x0e, 0x6c, 0xa9,
+        0x00, 0x4e, 0xb3, 0x42, 0x5d, 0x49, 0x80, 0x25, 0xda, 0x6c, 0x90, 0xa4, 0x7c, 0x35, 0x02, 0x5c,
+    ],
+    [
+        0x0c, 0x5c, 0xfa, 0x95, 0x71, 0x39, 0xb4, 0x5a, 0x72, 0xfb, 0xbf, 0x80, 0xa4, 0x10, 0xaa, 0xb5,
+        0x45, 0x4a, 0x98, 0x26, 0x68, 0xba, 0xf2, 0x4b, 0x0d, 0xd3, 0xc0, 0x4e, 0x4d, 0x59, 0x9c, 0xd9,
+    ],
+    [
+        0x1f, 0x1f, 0x3b, 0xc4, 0xc2, 0x1d, 0x63, 0x34, 0x00, 0xfc, 0xf8, 0xbd, 0xb7, 0xbe, 0x59, 0xb7,
+        0xf6, 0x5c, 0xed, 0xc0, 0x6b, 0xb2, 0xfc, 0x93, 0xa8, 0x73, 0xb6, 0xef, 0x12, 0xe0, 0xf5, 0x2b,
+    ],
+    [
+        0x18, 0x8f, 0x0e, 0xe9, 0x5a, 0x9d, 0xfb, 0x03, 0xcd, 0x5e, 0x3f, 0xb3, 0x08, 0x27, 0x05, 0x7e,
+        0x93, 0x37, 0x11, 0x64, 0x4e, 0xe7, 0xbd, 0x57, 0x49, 0x03, 0x74, 0xc0, 0xd6, 0x75, 0x32, 0xe1,
+    ],
+    [
+        0x08, 0x23, 0x87, 0x7e, 0xbf, 0x8a, 0x84, 0x2b, 0x77, 0xe8, 0xe0, 0xf3, 0xb4, 0x48, 0x73, 0xe9,
+        0x40, 0x97, 0x27, 0x10, 0x1b, 0x18, 0x6c, 0xae, 0x2b, 0x80, 0xd0, 0xcf, 0xa7, 0x26, 0xec, 0x8c,
+    ],
+    [
+        0x0f, 0xe8, 0x16, 0x03, 0xde, 0x39, 0xcc, 0xf1, 0x48, 0xca, 0x63, 0x90, 0xd6, 0xf8, 0xbd, 0xc9,
+        0x39, 0x5c, 0xa2, 0xf3, 0xf5, 0x08, 0x78, 0x28, 0x3d, 0x98, 0xf1, 0xf0, 0xe6, 0x1b, 0x1a, 0xff,
+    ],
+    [
+        0x2e, 0xf6, 0x1f, 0xe1, 0x92, 0xf5, 0x2a, 0x9d, 0x7c, 0xeb, 0xf4, 0x1c, 0x13, 0x40, 0x98, 0xf2,
+        0xcc, 0xae, 0x35, 0xca, 0xbc, 0xaa, 0x12, 0x96, 0x35, 0x66, 0x76, 0x8f, 0xf1, 0xb8, 0x37, 0x06,
+    ],
+    [
+        0x0d, 0xb6, 0xd0, 0x76, 0xb2, 0xdd, 0x1b, 0x9f, 0x4d, 0x56, 0xd2, 0xf6, 0x6f, 0xab, 0x4d, 0x7b,
+        0xfc, 0x6a, 0x38, 0xfe, 0xf7, 0xf9, 0xae, 0x1d, 0x4c, 0x2f, 0x8c, 0x44, 0xb1, 0x51, 0x0f, 0x51,
+    ],
+    [
+        0x2a, 0x19, 0x4a, 0x4a, 0xe8, 0x58, 0x3f, 0x28, 0x18, 0x9f, 0x9c, 0x01, 0x1d, 0x10, 0x7b, 0x82,
+        0xf2, 0x79, 0xe6, 0xa0, 0xf8, 0xca, 0x8b, 0xc9, 0xcb, 0x0a, 0xa5, 0x81, 0x83, 0x1c, 0x7d, 0x06,
+    ],
+    [
+        0x0a, 0xe5, 0x39, 0xfa, 0x9c, 0xa6, 0x7d, 0x4a, 0xf4, 0x8f, 0xb2, 0x92, 0xf9, 0x18, 0x8c, 0x9e,
+        0x72, 0xf2, 0xee, 0xaa, 0xe2, 0xe5, 0x00, 0xb7, 0xe7, 0x20, 0x17, 0x18, 0xc8, 0xda, 0x8d, 0xe9,
+    ],
+    [
+        0x23, 0xf6, 0x6a, 0x89, 0x4b, 0x1d, 0xf4, 0xd2, 0x6a, 0x8b, 0xaa, 0x6d, 0x05, 0xb1, 0xac, 0xe5,
+        0x11, 0xfa, 0xff, 0xd4, 0x60, 0x5b, 0x36, 0xcf, 0xd1, 0x2b, 0xb4, 0xc6, 0xdc, 0x2b, 0xb4, 0xd7,
+    ],
+    [
+        0x13, 0x7c, 0xef, 0x52, 0x2a, 0xf2, 0x94, 0x2a, 0xee, 0x9a, 0x4f, 0xb8, 0x01, 0x7b, 0xf3, 0x30,
+        0xe6, 0x46, 0x01, 0xf4, 0x66, 0xb5, 0x6d, 0x32, 0x24, 0x56, 0x69, 0xd9, 0xfe, 0x5b, 0xa7, 0x07,
+    ],
+    [
+        0x18, 0x7a, 0xaf, 0xcb, 0x87, 0x84, 0x1b, 0x2d, 0xf9, 0xd6, 0x4c, 0x17, 0x67, 0x78, 0x9a, 0x6b,
+        0x22, 0x17, 0x67, 0xe4, 0x63, 0x4f, 0x0d, 0x55, 0x00, 0x63, 0xa0, 0xbf, 0x92, 0x78, 0x8c, 0x32,
+    ],
+    [
+        0x23, 0x21, 0x2e, 0x75, 0xf4, 0xb8, 0x99, 0x92, 0xa3, 0x64, 0x70, 0x36, 0xee, 0x36, 0xd7, 0x49,
+        0x38, 0xbb, 0x9a, 0x98, 0x51, 0xe1, 0x3d, 0x7b, 0x6e, 0x52, 0xda, 0xf6, 0x6c, 0x4c, 0xbd, 0x25,
+    ],
+    [
+        0x0f, 0x54, 0xcb, 0xe9, 0x67, 0x77, 0x0d, 0xfa, 0x9d, 0x06, 0x8b, 0x2f, 0xc5, 0x3a, 0xbc, 0x96,
+        0x48, 0x9b, 0xb7, 0xd2, 0x66, 0x21, 0x26, 0x54, 0xd3, 0x26, 0xde, 0x6b, 0x9a, 0xf1, 0x6d, 0x63,
+    ],
+    [
+        0x23, 0xd8, 0x3a, 0x60, 0xb9, 0x88, 0x7a, 0xb4, 0x35, 0x9b, 0x85, 0x69, 0xff, 0x3a, 0x21, 0x9b,
+        0xb8, 0x13, 0x67, 0x95, 0x5f, 0xde, 0x72, 0xa6, 0x20, 0x02, 0xd3, 0xcd, 0x51, 0x10, 0x1f, 0xcc,
+    ],
+    [
+        0x2e, 0xa6, 0x76, 0xfe, 0x7f, 0x21, 0xfc, 0x58, 0x3a, 0xb0, 0xb0, 0xe1, 0x89, 0x02, 0x0d, 0x42,
+        0xcb, 0x22, 0xf0, 0x44, 0x60, 0x7e, 0xe1, 0x5b, 0xfa, 0xc7, 0xdd, 0x66, 0xda, 0x5e, 0xbe, 0x76,
+    ],
+    [
+        0x16, 0xf3, 0x7f, 0x35, 0x7a, 0x0c, 0x58, 0x48, 0x21, 0xa3, 0x56, 0xab, 0x56, 0xa1, 0x6d, 0x41,
+        0xf0, 0x45, 0x02, 0x9c, 0xb5, 0x15, 0xf5, 0x8e, 0xde, 0x00, 0x1f, 0x05, 0xa8, 0x07, 0x89, 0x0b,
+    ],
+    [
+        0x13, 0x90, 0x93, 0xb7, 0x8f, 0xc9, 0x60, 0x3a, 0x42, 0x88, 0xae, 0x58, 0x11, 0xed, 0x13, 0x11,
+        0xbe, 0x91, 0x6e, 0x4c, 0x97, 0xd3, 0x5a, 0x5c, 0xf1, 0x8e, 0x4b, 0x7e, 0x47, 0xd1, 0x3b, 0x03,
+    ],
+    [
+        0x0a, 0xab, 0xa5, 0xfc, 0xad, 0x1c, 0x04, 0x70, 0x30, 0xc8, 0xbf, 0x24, 0x6f, 0x41, 0x61, 0x0b,
+        0x68, 0xd6, 0x0b, 0xad, 0x28, 0x5c, 0xc1, 0x5f, 0x55, 0x90, 0x0f, 0x52, 0x77, 0x8a, 0x11, 0xba,
+    ],
+    [
+        0x16, 0x11, 0x82, 0x79, 0x56, 0xf3, 0x43, 0x5c, 0x1f, 0xc6, 0x7d, 0xd3, 0xd3, 0x79, 0x54, 0xa2,
+        0x55, 0xea, 0xa0, 0x19, 0x6c, 0xdd, 0xc5, 0xd5, 0xa6, 0x18, 0x7f, 0xa0, 0xa2, 0x83, 0x9e, 0xfb,
+    ],
+    [
+        0x23, 0xdb, 0x56, 0x36, 0x2f, 0x24, 0x89, 0x12, 0x42, 0x1e, 0x5f, 0x08, 0x7a, 0xd1, 0x6e, 0x0c,
+        0x9e, 0x6d, 0x2b, 0xb5, 0x9d, 0xb3, 0x0a, 0x52, 0x81, 0x8e, 0x41, 0x96, 0x4b, 0x7d, 0x81, 0x26,
+    ],
+    [
+        0x2f, 0x21, 0x5e, 0x64, 0x02, 0x07, 0xa8, 0x8b, 0x02, 0x99, 0x9c, 0x98, 0xf1, 0xaf, 0x8f, 0xa1,
+        0x73, 0x31, 0xd1, 0x9e, 0xa3, 0xde, 0x1d, 0xec, 0xfe, 0x8f, 0x2e, 0x47, 0x82, 0x24, 0x20, 0x9c,
+    ],
+    [
+        0x2b, 0x2a, 0x26, 0x19, 0x17, 0xc0, 0xfe, 0xcf, 0x2b, 0xce, 0x19, 0x02, 0x1f, 0x85, 0x06, 0xe3,
+        0x78, 0xe9, 0xd3, 0xe1, 0xaf, 0xb4, 0x51, 0xe9, 0xd1, 0x62, 0xb3, 0xd9, 0x3e, 0xed, 0x86, 0x12,
+    ],
+    [
+        0x2f, 0x66, 0x2e, 0x4c, 0xdf, 0xef, 0x5f, 0x82, 0x2c, 0xb9, 0x56, 0xdf, 0x75, 0xb1, 0x7b, 0x46,
+        0xf1, 0x7b, 0x08, 0xf9, 0x30, 0xf5, 0x4b, 0xab, 0x43, 0x24, 0xa0, 0x36, 0x3e, 0x23, 0x38, 0x4b,
+    ],
+    [
+        0x08, 0x70, 0xee, 0xab, 0x2e, 0x7b, 0xb1, 0xe8, 0x2c, 0x42, 0x6c, 0x2a, 0xb1, 0xd6, 0x5f, 0xda,
+        0x25, 0xd4, 0x26, 0x74, 0x9a, 0x53, 0x8b, 0x4e, 0xa6, 0x69, 0xea, 0xa3, 0x1f, 0x66, 0x1a, 0x00,
+    ],
+    [
+        0x16, 0x94, 0x18, 0xdf, 0xfc, 0x99, 0xf2, 0xef, 0x5d, 0xc6, 0xb1, 0x01, 0x37, 0x17, 0xc1, 0x34,
+        0x40, 0x93, 0x49, 0xd8, 0x46, 0x45, 0x79, 0x85, 0x72, 0x5b, 0x15, 0xac, 0x6a, 0x15, 0x2f, 0xac,
+    ],
+    [
+        0x02, 0x1d, 0x3c, 0xb2, 0x40, 0xd6, 0x40, 0x03, 0x39, 0xad, 0x5b, 0xc5, 0x35, 0xb4, 0xfb, 0xb4,
+        0x09, 0x19, 0xce, 0x6b, 0x90, 0x16, 0x2e, 0x51, 0xec, 0x68, 0x95, 0xa7, 0xca, 0xbb, 0x43, 0x75,
+    ],
+    [
+        0x12, 0xe4, 0x5c, 0x46, 0xc4, 0x5b, 0x66, 0x0b, 0x6a, 0xdd, 0xc1, 0x49, 0xe4, 0x21, 0x6e, 0xeb,
+        0x6f, 0xb1, 0x94, 0x74, 0xc8, 0xcc, 0xe4, 0xd7, 0x8e, 0x34, 0xc3, 0xcc, 0x18, 0x02, 0x4a, 0xfb,
+    ],
+    [
+        0x0c, 0x7c, 0x86, 0x39, 0x4b, 0x7a, 0xd6, 0x1d, 0x8c, 0x34, 0x8d, 0x18, 0x54, 0x03, 0xdc, 0x77,
+        0x15, 0x2f, 0xd3, 0x10, 0xb5, 0xd0, 0x59, 0x70, 0x37, 0x5a, 0x11, 0xb5, 0xab, 0xd0, 0x35, 0x48,
+    ],
+    [
+        0x30, 0x24, 0xe7, 0x8b, 0x21, 0x7f, 0x85, 0xd8, 0xef, 0xeb, 0x60, 0x00, 0x8f, 0xf7, 0x0d, 0x33,
+        0xc3, 0xe8, 0x4c, 0x23, 0x8d, 0x9c, 0x21, 0xa0, 0x41, 0x21, 0xb4, 0x98, 0xa2, 0xfb, 0xb1, 0x31,
+    ],
+    [
+        0x23, 0x59, 0x77, 0x10, 0x1b, 0x56, 0x07, 0x1c, 0xed, 0x31, 0x63, 0xc1, 0xec, 0x71, 0x87, 0x90,
+        0x9a, 0x3e, 0xd7, 0x67, 0x9b, 0xb5, 0x4a, 0xd4, 0x08, 0x11, 0xba, 0x31, 0x8e, 0x1a, 0xfc, 0x81,
+    ],
+    [
+        0x14, 0xbf, 0x88, 0x5e, 0x66, 0xf7, 0xc0, 0x1f, 0xd9, 0x74, 0xef, 0xdc, 0x0b, 0xc2, 0xd6, 0x28,
+        0xed, 0x79, 0xad, 0x6f, 0xc6, 0xba, 0x51, 0x1c, 0x5a, 0x8e, 0xc3, 0x83, 0xe1, 0xa7, 0x3a, 0x70,
+    ],
+    [
+        0x22, 0x33, 0x25, 0xae, 0x7e, 0x73, 0xcf, 0x09, 0x3e, 0x1d, 0x58, 0x91, 0xaa, 0x92, 0xed, 0x43,
+        0xf8, 0x26, 0x10, 0xf1, 0x28, 0x3f, 0x26, 0x5f, 0x56, 0x54, 0x8b, 0x38, 0x07, 0x2f, 0x38, 0xd3,
+    ],
+    [
+        0x23, 0x9a, 0x5a, 0xe1, 0x38, 0xdc, 0xe6, 0xa0, 0x19, 0xf4, 0x45, 0x60, 0xef, 0x7c, 0x19, 0xd2,
+        0xb8, 0x02, 0x5d, 0xad, 0xf0, 0x40, 0x55, 0x92, 0x26, 0xf5, 0x48, 0x70, 0xd3, 0x18, 0xa5, 0x7e,
+    ],
+    [
+        0x01, 0xdc, 0xc3, 0x51, 0x15, 0xd9, 0x05, 0x7e, 0xfa, 0xf4, 0x68, 0xd7, 0xc8, 0xa7, 0xd4, 0x60,
+        0x48, 0xe0, 0x13, 0x59, 0xc9, 0x74, 0x85, 0x5b, 0xc9, 0x97, 0x38, 0xf0, 0xb2, 0x47, 0x67, 0x2e,
+    ],
+    [
+        0x13, 0x59, 0xa4, 0x00, 0x12, 0xc5, 0x33, 0x25, 0x87, 0xa4, 0xde, 0xb5, 0x60, 0x4a, 0x43, 0x6e,
+        0x26, 0x9c, 0x28, 0x2b, 0xa2, 0x40, 0xcc, 0xa0, 0xa6, 0xd7, 0x7b, 0x34, 0x3e, 0x76, 0xbb, 0xf3,
+    ],
+    [
+        0x0a, 0x9e, 0xe3, 0x97, 0x12, 0x22, 0x3d, 0x4c, 0x0c, 0x37, 0xc2, 0x6a, 0x96, 0xf2, 0x67, 0xc8,
+        0x5f, 0xc8, 0xe3, 0x1a, 0xca, 0xee, 0x0c, 0x07, 0x35, 0x4d, 0x37, 0x98, 0xdc, 0x50, 0x03, 0xe7,
+    ],
+    [
+        0x11, 0x81, 0x2d, 0x1c, 0xa3, 0x67, 0x1a, 0xe6, 0x88, 0xa9, 0x87, 0x72, 0x33, 0xee, 0xb5, 0xec,
+        0x29, 0x86, 0x8f, 0x7c, 0xd7, 0xc5, 0xe1, 0x32, 0x75, 0x39, 0x1a, 0x84, 0x16, 0x7e, 0x02, 0x31,
+    ],
+    [
+        0x24, 0x35, 0xcd, 0x69, 0x9b, 0xc9, 0x49, 0x85, 0x94, 0x5d, 0x87, 0x89, 0x60, 0x77, 0x0f, 0x92,
+        0x98, 0xd4, 0x0c, 0x0e, 0x28, 0xb6, 0xb5, 0x97, 0x90, 0x69, 0xe0, 0x59, 0x09, 0x52, 0xf0, 0xb8,
+    ],
+    [
+        0x2b, 0xf0, 0x20, 0x78, 0x5e, 0x41, 0x17, 0xa1, 0xea, 0x59, 0x30, 0xcd, 0x53, 0x55, 0x30, 0x3b,
+        0xf9, 0xaa, 0xb2, 0xa1, 0xea, 0x0d, 0x66, 0x85, 0x39, 0x1f, 0x40, 0xee, 0xab, 0x2a, 0xf0, 0xa0,
+    ],
+    [
+        0x2c, 0xef, 0x6c, 0x78, 0x57, 0x7c, 0x9e, 0x97, 0xa7, 0x68, 0x55, 0x29, 0x2c, 0xa9, 0x2e, 0x42,
+        0x69, 0xac, 0x53, 0xe9, 0xb7, 0x43, 0x9d, 0xff, 0xba, 0xc2, 0xed, 0xa5, 0xc6, 0x75, 0x80, 0x24,
+    ],
+    [
+        0x09, 0xa3, 0x0e, 0x00, 0xc9, 0xf0, 0xf6, 0xf2, 0x60, 0xf0, 0x5f, 0x02, 0x9c, 0xda, 0xa7, 0xc7,
+        0xaf, 0xca, 0x50, 0x6a, 0x5c, 0xe5, 0x62, 0xc8, 0x77, 0xb3, 0xb8, 0xd5, 0x43, 0x13, 0x54, 0x29,
+    ],
+    [
+        0x1f, 0xd8, 0x59, 0x28, 0xf8, 0xc8, 0x05, 0x3c, 0x9b, 0x26, 0x4a, 0x74, 0xf1, 0x21, 0xb9, 0x0b,
+        0xbf, 0x9a, 0xe8, 0x06, 0x3e, 0x0e, 0x89, 0x67, 0x01, 0x65, 0xb9, 0xba, 0x66, 0xa3, 0xe7, 0xcd,
+    ],
+    [
+        0x24, 0x48, 0x22, 0x79, 0xd9, 0xef, 0xa5, 0x78, 0x62, 0xa6, 0xcd, 0x45, 0x24, 0xaa, 0x28, 0x9e,
+        0x69, 0x50, 0x60, 0x6d, 0xf8, 0xcd, 0xf8, 0xfb, 0xca, 0xed, 0x4b, 0x67, 0x4f, 0xda, 0xde, 0x91,
+    ],
+    [
+        0x10, 0x26, 0x0a, 0x39, 0xcf, 0xb0, 0xd8, 0x2a, 0xd8, 0x18, 0x2a, 0x69, 0x51, 0xf9, 0xc7, 0x80,
+        0xa4, 0x55, 0xfc, 0x1d, 0xe4, 0x8c, 0x41, 0x7d, 0x6c, 0xe6, 0xb2, 0x7f, 0x36, 0x46, 0x21, 0x85,
+    ],
+    [
+        0x25, 0x20, 0x78, 0x27, 0xa7, 0x60, 0x52, 0xd4, 0x89, 0xbb, 0x26, 0x98, 0x73, 0x0d, 0x5c, 0x3f,
+        0xc9, 0x71, 0x92, 0xa4, 0x42, 0x05, 0x78, 0xd2, 0x47, 0x4a, 0x37, 0xae, 0x7d, 0x7e, 0x31, 0x48,
+    ],
+    [
+        0x28, 0xe3, 0x6b, 0x63, 0x4e, 0xcb, 0xdf, 0x7b, 0x8e, 0x4f, 0x9e, 0x1a, 0x34, 0x7d, 0xf9, 0x5d,
+        0x77, 0x1f, 0x15, 0xd9, 0x7f, 0x2d, 0x5c, 0x40, 0x1e, 0xdf, 0x6a, 0x6e, 0x37, 0x58, 0x74, 0xbd,
+    ],
+    [
+        0x07, 0x3f, 0x0d, 0xea, 0x6f, 0x83, 0xd4, 0x43, 0xee, 0x3b, 0xa2, 0x7c, 0x5b, 0x7f, 0x00, 0x64,
+        0xab, 0x7c, 0xc0, 0xd3, 0x4b, 0xab, 0xae, 0x85, 0x9f, 0x6b, 0xf6, 0x6d, 0x46, 0xa1, 0xe1, 0x65,
+    ],
+    [
+        0x14, 0xc6, 0x61, 0x85, 0x69, 0xf7, 0x48, 0xff, 0x65, 0x51, 0x44, 0x37, 0xad, 0xbc, 0xfa, 0x97,
+        0xf8, 0x7b, 0x9b, 0x0d, 0x16, 0x90, 0x48, 0xe8, 0x4c, 0x55, 0x3b, 0xa9, 0x88, 0x8c, 0x45, 0x1e,
+    ],
+    [
+        0x0c, 0x76, 0x75, 0xa0, 0x42, 0xba, 0x6c, 0xc7, 0x4f, 0xa5, 0x88, 0xa0, 0xb2, 0x6f, 0xdc, 0x4f,
+        0x2d, 0x1f, 0x11, 0x9a, 0x0f, 0xe6, 0xef, 0x11, 0x1b, 0xe6, 0xa2, 0xd4, 0x5b, 0x4b, 0xbc, 0xad,
+    ],
+    [
+        0x03, 0xdf, 0xad, 0x7d, 0xb3, 0xf3, 0x2c, 0x6e, 0xd8, 0x13, 0x0c, 0x43, 0xcf, 0xf6, 0x04, 0xdb,
+        0xe1, 0xc2, 0x9d, 0xbf, 0xf2, 0x50, 0xc6, 0x84, 0xc9, 0x55, 0xf0, 0x9e, 0x49, 0x5f, 0xb3, 0x4c,
+    ],
+    [
+        0x20, 0x11, 0x42, 0x81, 0xfb, 0xe3, 0x5c, 0x18, 0x81, 0x64, 0xfc, 0x6d, 0x41, 0x44, 0x20, 0x0f,
+        0xc1, 0xd9, 0xec, 0x43, 0x3e, 0xfc, 0x67, 0x74, 0x80, 0xbd, 0x2c, 0xdc, 0xb3, 0x5b, 0x64, 0xe6,
+    ],
+    [
+        0x29, 0xe4, 0x2f, 0x52, 0x18, 0xb1, 0xea, 0x3f, 0xad, 0x06, 0xa4, 0x8e, 0x22, 0x92, 0xc0, 0xfe,
+        0x3d, 0xf0, 0x8a, 0x2c, 0x4a, 0x82, 0x3a, 0x3b, 0xe4, 0xb9, 0x98, 0x95, 0x5d, 0xa8, 0xa2, 0x1d,
+    ],
+    [
+        0x13, 0xbf, 0x40, 0x39, 0x48, 0x8d, 0x94, 0xb1, 0xb6, 0xa6, 0x7f, 0x25, 0x62, 0x96, 0xda, 0xeb,
+        0x6c, 0x7c, 0x80, 0xfa, 0xd2, 0x72, 0x6d, 0x6e, 0x9b, 0xe1, 0x1e, 0x17, 0xc2, 0x35, 0x28, 0x16,
+    ],
+    [
+        0x18, 0x47, 0xa4, 0xe3, 0x6b, 0x3e, 0xac, 0xe1, 0x3d, 0xe5, 0x8f, 0xa4, 0x66, 0x2a, 0x9f, 0x88,
+        0xc9, 0xc1, 0x7a, 0x46, 0x4e, 0x6d, 0xbd, 0x20, 0x83, 0xc2, 0x93, 0x8c, 0x22, 0x7e, 0xe3, 0xfa,
+    ],
+    [
+        0x0a, 0x7a, 0x20, 0x56, 0xc7, 0xb7, 0x93, 0x84, 0xaf, 0x9f, 0xe3, 0xc6, 0x82, 0x68, 0x30, 0x14,
+        0x5f, 0x51, 0xf6, 0xf8, 0xca, 0x5d, 0x1a, 0x34, 0x4e, 0x29, 0x5c, 0xa7, 0x66, 0x3a, 0xda, 0x27,
+    ],
+    [
+        0x18, 0x12, 0x78, 0xca, 0xa1, 0x5b, 0xdf, 0xb5, 0x94, 0x0c, 0xff, 0x2b, 0xc3, 0x21, 0x6f, 0xf0,
+        0x6a, 0x45, 0x96, 0x2d, 0x49, 0x79, 0xdf, 0xc0, 0xd6, 0xc9, 0x49, 0x93, 0xbb, 0x7d, 0xe8, 0x6d,
+    ],
+    [
+        0x0a, 0x8a, 0x6f, 0x4f, 0xbb, 0xa2, 0xee, 0x09, 0xa3, 0x6d, 0x39, 0x0f, 0xbb, 0x14, 0x85, 0xfb,
+        0x78, 0x9c, 0xf3, 0xf9, 0xf6, 0x41, 0xda, 0xe0, 0x11, 0x52, 0x0c, 0x8f, 0x10, 0xe5, 0x7a, 0x43,
+    ],
+    [
+        0x08, 0xf6, 0xb4, 0xc0, 0xd7, 0xbd, 0x5e, 0x4c, 0x87, 0xfb, 0xc6, 0x87, 0x45, 0x20, 0xe3, 0x32,
+        0x9c, 0x06, 0x7b, 0x68, 0x41, 0xfc, 0x83, 0x3a, 0xa3, 0x75, 0x14, 0x43, 0x5e, 0x98, 0x7c, 0xea,
+    ],
+    [
+        0x22, 0x06, 0xb2, 0xc1, 0x8f, 0x32, 0x92, 0xe7, 0xa9, 0xa9, 0x10, 0x39, 0x68, 0x3a, 0x55, 0xd8,
+        0x13, 0xdd, 0x91, 0x4b, 0x0c, 0x7d, 0x52, 0x32, 0x10, 0x42, 0x4f, 0xf0, 0xf2, 0x81, 0x7d, 0xf7,
+    ],
+    [
+        0x01, 0x86, 0xce, 0xf5, 0xc0, 0x4b, 0x3a, 0x6c, 0x32, 0xda, 0x26, 0x40, 0x5a, 0x16, 0xcd, 0xd3,
+        0x59, 0xdf, 0x57, 0xbe, 0x8e, 0x93, 0x29, 0x1a, 0xcb, 0xeb, 0xa3, 0xf4, 0x87, 0x32, 0x9c, 0xfc,
+    ],
+    [
+        0x07, 0x5a, 0xff, 0x67, 0x57, 0x0e, 0xa1, 0x7c, 0x7c, 0x79, 0xc9, 0x11, 0x4c, 0x7a, 0x10, 0xd5,
+        0x33, 0xc6, 0x08, 0x0b, 0x6d, 0x15, 0xa5, 0xbe, 0xbd, 0x12, 0x04, 0xfd, 0xcd, 0xe7, 0x72, 0xf0,
+    ],
+    [
+        0x2b, 0x06, 0xd8, 0x38, 0x9b, 0xa9, 0x62, 0x1c, 0x38, 0x69, 0xd2, 0xe8, 0xb2, 0x5a, 0xc1, 0x5e,
+        0x2c, 0x97, 0x38, 0x9c, 0xaa, 0xf7, 0xf9, 0x7a, 0x35, 0x70, 0x0b, 0xe6, 0x6c, 0x16, 0x55, 0xa8,
+    ],
+    [
+        0x04, 0xfc, 0x6d, 0x0a, 0x6b, 0x67, 0x74, 0x44, 0x28, 0x16, 0x1c, 0xb5, 0x22, 0x8a, 0x94, 0xe7,
+        0xc0, 0xe9, 0x5d, 0xed, 0xe8, 0x04, 0xb2, 0xb4, 0x7a, 0x18, 0x05, 0x5a, 0xcd, 0x73, 0x7a, 0xeb,
+    ],
+    [
+        0x25, 0xe4, 0xa2, 0x95, 0xb4, 0xe7, 0xa2, 0xdc, 0x71, 0x39, 0x4d, 0x41, 0xec, 0x2a, 0x8f, 0x4b,
+        0x12, 0x4e, 0x58, 0xc2, 0x34, 0x44, 0x8a, 0x02, 0x8a, 0x2c, 0x3b, 0xc6, 0xad, 0x25, 0xe6, 0x9b,
+    ],
+    [
+        0x00, 0x94, 0xc2, 0x4a, 0xb0, 0x1c, 0x6d, 0xb5, 0x94, 0xfd, 0xa9, 0x07, 0xb6, 0x39, 0xa7, 0xb3,
+        0xa5, 0x43, 0x01, 0x43, 0x93, 0x40, 0xe8, 0x89, 0xb6, 0xca, 0x9c, 0xb6, 0xcb, 0xa0, 0x67, 0xb0,
+    ],
+    [
+        0x27, 0x51, 0x82, 0x1a, 0x6e, 0x3b, 0x22, 0x06, 0xcd, 0x6b, 0xc3, 0xcd, 0x5d, 0x98, 0xcb, 0xae,
+        0x88, 0x50, 0x74, 0x5e, 0xc6, 0xcb, 0xbe, 0xa4, 0x13, 0xe7, 0x25, 0x4d, 0x2a, 0x51, 0x45, 0x49,
+    ],
+    [
+        0x14, 0x5a, 0xae, 0xcc, 0x24, 0xe3, 0x87, 0x8d, 0xb9, 0xa8, 0xd7, 0x71, 0x49, 0xb2, 0x41, 0x9e,
+        0xd5, 0xb4, 0x2d, 0xf0, 0x25, 0xec, 0xbd, 0x1a, 0x8e, 0x97, 0xd8, 0xb8, 0x17, 0x90, 0x4b, 0xb9,
+    ],
+    [
+        0x0d, 0x2a, 0xa0, 0x0c, 0xc6, 0xe5, 0x16, 0x7d, 0x84, 0x5e, 0xe1, 0x87, 0x7b, 0x7a, 0xa0, 0x59,
+        0xb5, 0xe5, 0x15, 0x1c, 0x57, 0xe4, 0xc1, 0x63, 0xda, 0x34, 0x03, 0xf9, 0xa9, 0x57, 0x55, 0xf2,
+    ],
+    [
+        0x24, 0x23, 0x3b, 0x69, 0x86, 0x1c, 0x70, 0xc4, 0x49, 0xeb, 0xd3, 0x6e, 0x44, 0x20, 0xdd, 0xfc,
+        0x3c, 0x24, 0x2b, 0x69, 0xff, 0x6e, 0x3d, 0x4c, 0x60, 0x90, 0xc2, 0xbd, 0x08, 0xa7, 0x64, 0xc2,
+    ],
+    [
+        0x2c, 0x55, 0xfe, 0x1a, 0x75, 0x14, 0x7c, 0x9c, 0x82, 0x6f, 0x76, 0x2f, 0x83, 0x6d, 0x56, 0x45,
+        0xac, 0x41, 0x84, 0xba, 0x19, 0x27, 0xdd, 0x56, 0xa7, 0x6d, 0xc0, 0x56, 0xa9, 0x89, 0x56, 0x67,
+    ],
+    [
+        0x19, 0x77, 0x9b, 0x1c, 0xb1, 0x6a, 0x9c, 0x79, 0x65, 0x40, 0x16, 0x67, 0x49, 0x78, 0xb8, 0xb6,
+        0xee, 0x1a, 0x78, 0xb6, 0x45, 0x79, 0xde, 0x93, 0xed, 0x2d, 0x67, 0xb9, 0xe4, 0x9f, 0x09, 0x8e,
+    ],
+    [
+        0x18, 0x07, 0x72, 0x1b, 0xa6, 0x0f, 0xba, 0xe4, 0x76, 0xff, 0x60, 0x12, 0x0c, 0x1f, 0x47, 0xec,
+        0x0b, 0xce, 0x69, 0x2c, 0xfc, 0x00, 0x10, 0x2a, 0xe5, 0x0b, 0xd9, 0x37, 0x6c, 0x5a, 0x22, 0xa1,
+    ],
+    [
+        0x1c, 0x02, 0x08, 0xf5, 0xab, 0xa1, 0x27, 0x50, 0x80, 0x2f, 0xbd, 0xc3, 0xbf, 0x12, 0xea, 0xc9,
+        0xe0, 0x0a, 0xda, 0x70, 0x8f, 0x16, 0x7d, 0x4b, 0x16, 0x7b, 0xd7, 0xd4, 0xb0, 0x3d, 0x76, 0xf1,
+    ],
+    [
+        0x09, 0x0b, 0x6e, 0x28, 0xff, 0x54, 0x1c, 0xc3, 0x88, 0x69, 0xab, 0xe2, 0xf5, 0xdb, 0xfb, 0x9f,
+        0xbe, 0x88, 0x8d, 0x21, 0x02, 0x1f, 0x29, 0xd2, 0xbd, 0xdb, 0x91, 0x27, 0xd3, 0xc2, 0x73, 0xbe,
+    ],
+    [
+        0x19, 0x3d, 0x6c, 0x7e, 0x76, 0xad, 0x94, 0xeb, 0x59, 0xa2, 0x00, 0x10, 0xa7, 0x99, 0xc7, 0xbf,
+        0x96, 0xa8, 0xdd, 0xa8, 0xc9, 0x08, 0x87, 0xd6, 0x52, 0x14, 0xc8, 0x66, 0x5e, 0xcd, 0xa0, 0x1b,
+    ],
+    [
+        0x09, 0xdd, 0xd5, 0xbc, 0x0b, 0x1f, 0x8d, 0x4c, 0x4f, 0x1a, 0x7a, 0x10, 0x2c, 0x1b, 0xa1, 0x2e,
+        0xc7, 0xe7, 0x54, 0x0c, 0xda, 0x28, 0x5a, 0x56, 0x4e, 0xa7, 0xc4, 0x75, 0x3a, 0xf8, 0xa8, 0xd1,
+    ],
+    [
+        0x30, 0x0a, 0x1d, 0xd5, 0xfd, 0xa1, 0x5d, 0x1a, 0x9c, 0xfe, 0x2a, 0x28, 0x78, 0x01, 0x81, 0x71,
+        0x68, 0x72, 0x1d, 0xa2, 0xf9, 0x70, 0x13, 0x95, 0x49, 0xe1, 0x68, 0x11, 0xf5, 0x55, 0xbe, 0x1f,
+    ],
+    [
+        0x15, 0x20, 0x90, 0x8d, 0xf2, 0xee, 0xfb, 0xa0, 0x75, 0xbb, 0x41, 0xcf, 0xe0, 0x74, 0x0e, 0x1e,
+        0x5a, 0x1e, 0xe1, 0x81, 0xce, 0x49, 0x20, 0x16, 0xe1, 0x52, 0x70, 0x6e, 0x49, 0x3c, 0xf4, 0x70,
+    ],
+    [
+        0x0b, 0x3f, 0x21, 0x5d, 0x52, 0xe8, 0x5e, 0xc3, 0xc6, 0x82, 0x69, 0x8e, 0xbc, 0xb6, 0x22, 0x82,
+        0x33, 0xae, 0x95, 0x18, 0xf9, 0x45, 0x0f, 0x48, 0x06, 0x89, 0xe7, 0xcf, 0x30, 0x13, 0x78, 0x46,
+    ],
+    [
+        0x05, 0x09, 0x96, 0xa0, 0x7a, 0xf6, 0x76, 0x5c, 0x56, 0xa8, 0xee, 0x2f, 0x73, 0x66, 0x50, 0xde,
+        0xb4, 0x97, 0x2b, 0xfc, 0xdd, 0xb1, 0x94, 0xa0, 0xef, 0xeb, 0x19, 0x54, 0xda, 0xa8, 0xa4, 0xd7,
+    ],
+    [
+        0x23, 0xa5, 0x93, 0x26, 0xda, 0xf5, 0xf9, 0x4f, 0x3b, 0x51, 0x92, 0xc1, 0x8e, 0xd0, 0x6c, 0xc5,
+        0x8c, 0xd9, 0x60, 0x8e, 0x44, 0x13, 0x86, 0x86, 0x39, 0x9d, 0xc4, 0xf1, 0xee, 0x80, 0x7a, 0xdd,
+    ],
+    [
+        0x1d, 0x22, 0x0b, 0x9a, 0xdd, 0x05, 0x92, 0x86, 0x68, 0x98, 0xce, 0x83, 0x4b, 0x1b, 0xdc, 0x20,
+        0x03, 0x97, 0x6e, 0x81, 0x5c, 0x0c, 0xf7, 0xd3, 0xf6, 0xb8, 0xbf, 0x96, 0x7a, 0xd5, 0x64, 0x96,
+    ],
+    [
+        0x19, 0xb4, 0x9c, 0x4d, 0xa3, 0xd9, 0xe3, 0xa2, 0x6d, 0x36, 0x56, 0x67, 0x38, 0x65, 0x89, 0x23,
+        0x47, 0x7c, 0x50, 0x4e, 0x76, 0xa3, 0x8a, 0x82, 0xb1, 0xb1, 0x24, 0x71, 0x04, 0xf6, 0x81, 0xe1,
+    ],
+    [
+        0x13, 0x9d, 0xec, 0xc5, 0x67, 0xb0, 0x6b, 0x49, 0x5b, 0xd6, 0xf3, 0xcf, 0x00, 0x6c, 0x05, 0xb7,
+        0xe1, 0xf2, 0x5d, 0x5b, 0x7a, 0xc5, 0x54, 0x44, 0x5c, 0x37, 0xdd, 0x28, 0x5b, 0xea, 0xae, 0x7a,
+    ],
+    [
+        0x1a, 0x5a, 0x7d, 0x8b, 0xca, 0x8d, 0x4f, 0x07, 0xd4, 0xcd, 0x6e, 0x9b, 0xcd, 0x00, 0xc0, 0x09,
+        0xe3, 0x09, 0x9e, 0x99, 0xaf, 0x30, 0x80, 0xde, 0xbb, 0xb2, 0x1f, 0x90, 0xf8, 0xe5, 0xb4, 0x25,
+    ],
+    [
+        0x06, 0x21, 0xe3, 0x81, 0x1d, 0x3d, 0xcb, 0x95, 0x84, 0xd0, 0xa8, 0xbe, 0x41, 0xe4, 0x31, 0xd5,
+        0x88, 0xc9, 0x32, 0x74, 0xc8, 0x54, 0x9e, 0x62, 0x5f, 0x98, 0xfd, 0x15, 0x98, 0x3a, 0x80, 0x9e,
+    ],
+    [
+        0x02, 0xa4, 0x1c, 0x2d, 0x2b, 0xcf, 0x14, 0x66, 0x4a, 0x01, 0x30, 0xa0, 0x21, 0xda, 0xa6, 0xef,
+        0xd4, 0xa3, 0xb0, 0xc3, 0xad, 0xed, 0x38, 0x63, 0x5b, 0xff, 0x97, 0xe3, 0x78, 0x5e, 0xd6, 0xbd,
+    ],
+    [
+        0x21, 0xcf, 0xb5, 0x4b, 0xa7, 0x2f, 0xc0, 0x67, 0x94, 0x94, 0x7c, 0xc3, 0xbd, 0xa3, 0x3d, 0x81,
+        0x53, 0x55, 0x41, 0x6e, 0x2a, 0xae, 0x19, 0x08, 0x4d, 0x84, 0x47, 0x1e, 0xed, 0xca, 0x39, 0x9d,
+    ],
+    [
+        0x05, 0x11, 0x50, 0x93, 0x89, 0x40, 0x89, 0xa2, 0x02, 0xa4, 0xcf, 0x8d, 0x0a, 0xea, 0x32, 0x9d,
+        0x83, 0xcb, 0xe0, 0xec, 0xe7, 0x41, 0x16, 0x0f, 0x99, 0x75, 0xcf, 0x01, 0xb7, 0xc8, 0x5d, 0xc6,
+    ],
+    [
+        0x2e, 0x40, 0x54, 0x1c, 0xac, 0xa7, 0x65, 0x32, 0x12, 0x82, 0x71, 0x29, 0x55, 0x9a, 0xb4, 0x22,
+        0xa1, 0xc0, 0x6a, 0x6f, 0x48, 0xc9, 0x74, 0x29, 0x14, 0x70, 0xc6, 0x5f, 0xc0, 0x70, 0xdf, 0xb6,
+    ],
+    [
+        0x07, 0xee, 0xad, 0xd7, 0x30, 0x28, 0x4f, 0x5a, 0xb6, 0x07, 0x78, 0x90, 0x0b, 0x60, 0x30, 0x0d,
+        0x27, 0x97, 0x00, 0x01, 0xaa, 0x63, 0xe4, 0x6e, 0xbb, 0x7f, 0x9c, 0xda, 0x11, 0x57, 0x60, 0x0e,
+    ],
+    [
+        0x0c, 0xf3, 0x3e, 0x49, 0x64, 0xd3, 0x50, 0xad, 0xf2, 0x05, 0x1f, 0x8e, 0x2e, 0x84, 0x48, 0xd3,
+        0x32, 0x4d, 0x24, 0xd3, 0xf4, 0x85, 0x84, 0x07, 0xf4, 0xfd, 0x60, 0xd3, 0x33, 0x3b, 0xd9, 0x8d,
+    ],
+    [
+        0x28, 0x7c, 0x60, 0x48, 0x3e, 0x2d, 0xb3, 0x13, 0xf1, 0xa2, 0xbd, 0xa9, 0x2c, 0x1e, 0x1c, 0x28,
+        0xb6, 0x5e, 0xf0, 0xb9, 0x9d, 0xf4, 0xac, 0xfb, 0x75, 0x70, 0x9e, 0xdf, 0x6a, 0x3b, 0xfc, 0xb9,
+    ],
+    [
+        0x28, 0x94, 0x40, 0xf4, 0xb7, 0xfd, 0xb8, 0xb2, 0xb2, 0x26, 0x61, 0xd0, 0x74, 0x2e, 0x96, 0x95,
+        0x13, 0x9c, 0x1a, 0x1e, 0xce, 0xf5, 0x3c, 0x3f, 0xeb, 0xde, 0x0b, 0x5f, 0x6d, 0x9b, 0xb4, 0xa5,
+    ],
+    [
+        0x29, 0x1b, 0xda, 0x2c, 0x37, 0xfa, 0xc9, 0x62, 0xa2, 0x45, 0x55, 0xa7, 0xab, 0xeb, 0x36, 0xd8,
+        0xe8, 0xb9, 0xeb, 0x25, 0x22, 0x2f, 0x97, 0xf4, 0x81, 0x7e, 0xf2, 0xe5, 0xb0, 0xbc, 0x72, 0x08,
+    ],
+    [
+        0x0e, 0x10, 0x54, 0x49, 0x58, 0x94, 0x12, 0x40, 0x4c, 0xda, 0x7b, 0x82, 0xf1, 0x4d, 0x52, 0x75,
+        0x94, 0xff, 0x99, 0xd0, 0x4c, 0xea, 0x89, 0x4d, 0x6e, 0xcc, 0x31, 0x51, 0x19, 0xf4, 0x2b, 0x73,
+    ],
+    [
+        0x0e, 0x60, 0x97, 0xf0, 0xad, 0x41, 0x8a, 0xc7, 0x12, 0x3a, 0xe7, 0xeb, 0xa6, 0xcd, 0x45, 0x22,
+        0xb7, 0xff, 0xa8, 0xdc, 0x8c, 0xf6, 0x0a, 0x1f, 0x83, 0x6b, 0xe2, 0x3e, 0x41, 0xec, 0x25, 0xe5,
+    ],
+    [
+        0x22, 0xfd, 0xb6, 0x76, 0x72, 0x40, 0x17, 0xaf, 0xa8, 0xa8, 0x06, 0x9c, 0x62, 0xd3, 0x07, 0xaa,
+        0x4f, 0xf5, 0x2c, 0x14, 0x45, 0xad, 0x41, 0xb9, 0x4c, 0x3e, 0x40, 0x6e, 0x33, 0xe0, 0xde, 0xf1,
+    ],
+    [
+        0x02, 0x04, 0xd5, 0x94, 0xad, 0x78, 0x55, 0xad, 0x0f, 0x69, 0x50, 0x74, 0x2b, 0x19, 0xe6, 0x0c,
+        0x2c, 0x53, 0x38, 0x81, 0x7a, 0xab, 0x7e, 0xe7, 0xbf, 0x63, 0x1c, 0x3a, 0xa9, 0x21, 0x60, 0xf1,
+    ],
+    [
+        0x0b, 0x6a, 0x64, 0x3d, 0xad, 0x3f, 0x95, 0x38, 0x77, 0x11, 0x05, 0x17, 0xf7, 0xb3, 0x36, 0x51,
+        0x8c, 0x8f, 0xc1, 0x1d, 0x86, 0x8b, 0xc7, 0x2e, 0x76, 0xcd, 0x76, 0xc3, 0xb2, 0xf7, 0xdc, 0x1b,
+    ],
+    [
+        0x04, 0x18, 0x9e, 0xa6, 0xc2, 0xbd, 0x4b, 0x00, 0xfa, 0x63, 0x49, 0xad, 0xde, 0x17, 0x02, 0x7e,
+        0xb7, 0xd7, 0x63, 0x53, 0xa1, 0x0b, 0xd0, 0x7d, 0x09, 0x42, 0x63, 0x94, 0x47, 0xcb, 0x43, 0xd3,
+    ],
+    [
+        0x23, 0xbc, 0x84, 0x49, 0xd8, 0x98, 0xa7, 0x52, 0x4b, 0x2d, 0xfd, 0xe2, 0x63, 0x03, 0x6c, 0xac,
+        0x2e, 0x14, 0x93, 0x51, 0x34, 0xf8, 0xc3, 0xba, 0xf7, 0xf1, 0xe1, 0x3e, 0xa2, 0xde, 0xec, 0x3c,
+    ],
+    [
+        0x00, 0x17, 0xef, 0xa6, 0x08, 0xd9, 0x94, 0x1b, 0xc6, 0x4d, 0x39, 0xe0, 0xb2, 0x59, 0x3b, 0x74,
+        0x25, 0x76, 0xdb, 0xa2, 0x57, 0xfa, 0xb3, 0x23, 0xd4, 0xc9, 0x9c, 0x7a, 0x42, 0x10, 0x7a, 0x16,
+    ],
+    [
+        0x02, 0xf0, 0x61, 0x47, 0x5e, 0x9d, 0x88, 0x88, 0xb3, 0x87, 0x7f, 0x93, 0x08, 0xe7, 0x75, 0x8a,
+        0x16, 0xf9, 0x1e, 0x3a, 0x34, 0xa5, 0x85, 0x6f, 0x15, 0xad, 0x0b, 0x9f, 0x2f, 0x92, 0xe2, 0xb4,
+    ],
+    [
+        0x1f, 0x25, 0x7d, 0x4d, 0x6f, 0x32, 0x77, 0x67, 0x4a, 0x06, 0x13, 0x30, 0xd7, 0x3d, 0x6b, 0xa1,
+        0x35, 0xda, 0x7c, 0xb0, 0xcb, 0xa3, 0x18, 0x68, 0x68, 0x72, 0xfe, 0x1e, 0xf6, 0xd5, 0x2e, 0x25,
+    ],
+    [
+        0x19, 0x53, 0x40, 0xf6, 0xc1, 0xf7, 0xb5, 0xf6, 0x15, 0x88, 0x84, 0xbd, 0x23, 0x77, 0x15, 0x14,
+        0x9c, 0xea, 0x48, 0x8e, 0xdb, 0xfd, 0x80, 0xfc, 0x9b, 0x9c, 0xc6, 0x1c, 0x38, 0x48, 0x53, 0xab,
+    ],
+    [
+        0x27, 0x3e, 0x20, 0x01, 0x79, 0xc5, 0xc4, 0x5d, 0x86, 0x0b, 0x26, 0xa2, 0x0b, 0x2c, 0x08, 0x53,
+        0xc1, 0x00, 0x8f, 0x94, 0x24, 0x59, 0xd5, 0xcd, 0xbe, 0xd6, 0x33, 0xed, 0xe0, 0x31, 0xaa, 0x1d,
+    ],
+    [
+        0x24, 0xbd, 0x0e, 0xce, 0xa1, 0xe1, 0xdf, 0x97, 0xaf, 0x0f, 0x29, 0x6c, 0x0e, 0x79, 0x2d, 0x67,
+        0x6e, 0x2c, 0x8e, 0x02, 0xe8, 0x08, 0xa1, 0x58, 0xbd, 0xbe, 0x01, 0x43, 0x1c, 0x5e, 0x4a, 0x48,
+    ],
+    [
+        0x0c, 0x6e, 0x6f, 0x0f, 0x7c, 0x20, 0x81, 0x1f, 0x40, 0x45, 0x99, 0xd4, 0x58, 0xaf, 0x5c, 0x6c,
+        0xb4, 0x1a, 0xb6, 0x71, 0x59, 0x9d, 0x63, 0x50, 0xdf, 0x38, 0xe8, 0x21, 0x04, 0xbc, 0x68, 0x35,
+    ],
+    [
+        0x1d, 0xee, 0x13, 0x52, 0x47, 0x02, 0x35, 0xcd, 0x8e, 0x12, 0x9b, 0x56, 0x11, 0xfa, 0x95, 0x37,
+        0x06, 0xbc, 0x08, 0xd5, 0x8f, 0x6a, 0x3e, 0xf3, 0xd8, 0x03, 0x70, 0xd8, 0xd1, 0x96, 0x83, 0xa2,
+    ],
+    [
+        0x1b, 0xe0, 0xcc, 0x30, 0x34, 0x3a, 0x16, 0x8f, 0xda, 0x7c, 0x75, 0x7a, 0xc0, 0xc7, 0xe5, 0xe1,
+        0x6a, 0xfd, 0xdb, 0xfb, 0x72, 0x65, 0x87, 0x80, 0x80, 0x72, 0x5a, 0x12, 0xfa, 0x12, 0xad, 0x4c,
+    ],
+    [
+        0x1a, 0x44, 0x9a, 0xbf, 0x64, 0x4f, 0x68, 0xa6, 0x4c, 0x21, 0x7c, 0x6a, 0x04, 0x2a, 0x23, 0x11,
+        0x07, 0xca, 0x8d, 0x9c, 0x61, 0x92, 0x08, 0x58, 0x0e, 0xb7, 0x6b, 0xbd, 0x92, 0x15, 0xd9, 0x84,
+    ],
+    [
+        0x08, 0x01, 0x17, 0xdd, 0xe5, 0x8f, 0x22, 0x8a, 0x45, 0x9f, 0x59, 0xd9, 0x0e, 0x64, 0xba, 0xbe,
+        0x2d, 0xaf, 0x47, 0x6a, 0x9a, 0x2c, 0xa0, 0x18, 0xfa, 0xa3, 0xc5, 0xfe, 0xca, 0xac, 0x17, 0x89,
+    ],
+    [
+        0x24, 0xab, 0x9a, 0x16, 0x7b, 0x61, 0x85, 0x5e, 0x0f, 0x2c, 0x60, 0xbf, 0xf0, 0x98, 0xd5, 0xeb,
+        0x7d, 0x15, 0x16, 0x6f, 0xab, 0x51, 0xf2, 0xb6, 0x2c, 0x80, 0x9d, 0xe3, 0x18, 0xd6, 0xd1, 0x76,
+    ],
+    [
+        0x2b, 0x56, 0xac, 0xa9, 0xd5, 0x95, 0x46, 0x05, 0x03, 0x36, 0x32, 0x92, 0xbc, 0xcd, 0xc8, 0x0e,
+        0x82, 0x32, 0x01, 0xf7, 0x1b, 0x54, 0xbd, 0xa6, 0x84, 0xc9, 0x3e, 0xb0, 0x89, 0x37, 0x84, 0xf6,
+    ],
+    [
+        0x05, 0x73, 0x61, 0xec, 0x23, 0xc8, 0x7b, 0x30, 0x08, 0x0f, 0xf1, 0xcd, 0xf3, 0x46, 0x7d, 0x57,
+        0xc9, 0x5a, 0x12, 0xaf, 0xbf, 0xc4, 0x8a, 0xb5, 0xe1, 0xe4, 0xde, 0x01, 0x62, 0xa4, 0x1b, 0x5a,
+    ],
+    [
+        0x1b, 0xae, 0xd1, 0x77, 0x17, 0x7a, 0x25, 0xec, 0xbb, 0xfa, 0xf4, 0xf2, 0x04, 0xd9, 0x92, 0x1e,
+        0xad, 0xe3, 0x68, 0x9b, 0xa1, 0x37, 0x8c, 0xd9, 0x36, 0x2c, 0xc1, 0x41, 0x45, 0x08, 0x9f, 0x95,
+    ],
+    [
+        0x00, 0xcc, 0xdb, 0x6d, 0x5f, 0x49, 0x13, 0xd7, 0x65, 0x68, 0x89, 0xc3, 0x12, 0x97, 0x5f, 0xdb,
+        0xe0, 0xe0, 0x30, 0x9a, 0x84, 0x18, 0x49, 0xfd, 0x80, 0x8d, 0x75, 0xf5, 0x93, 0xa7, 0x14, 0x81,
+    ],
+    [
+        0x2c, 0x52, 0xf5, 0xe1, 0xef, 0x57, 0xde, 0x5d, 0xde, 0x52, 0x84, 0x71, 0xcc, 0x1f, 0x0e, 0xe7,
+        0x83, 0x99, 0x29, 0xdf, 0x98, 0xee, 0x74, 0x3d, 0x9e, 0xff, 0x96, 0x25, 0x15, 0x56, 0x19, 0x10,
+    ],
+    [
+        0x02, 0x34, 0xa4, 0xa3, 0x2f, 0x76, 0x7f, 0x2e, 0x1c, 0xa4, 0x8d, 0xad, 0x3a, 0x2f, 0xdd, 0x3f,
+        0xf5, 0x4b, 0x2c, 0xa3, 0xd5, 0x3c, 0xe1, 0x3f, 0xc9, 0x05, 0x24, 0x14, 0xad, 0x7f, 0x75, 0x55,
+    ],
+    [
+        0x18, 0x2b, 0x4c, 0x0c, 0x55, 0x1a, 0xb0, 0xce, 0x19, 0x90, 0xb4, 0x72, 0x27, 0x8f, 0x79, 0x4b,
+        0xa9, 0xcc, 0x62, 0xab, 0xcc, 0x90, 0xa8, 0x9e, 0xfd, 0xd8, 0x07, 0x76, 0x55, 0x8e, 0x34, 0x33,
+    ],
+    [
+        0x00, 0x1a, 0xb2, 0xbb, 0xdb, 0xd0, 0x43, 0xd2, 0xd7, 0xd1, 0xa4, 0xeb, 0x76, 0xdb, 0x4d, 0xe7,
+        0x1f, 0xdd, 0xf1, 0x9e, 0xdf, 0x10, 0xd7, 0xcd, 0xb9, 0x55, 0x0e, 0x18, 0x46, 0xe5, 0x3f, 0xd5,
+    ],
+    [
+        0x20, 0x6e, 0x6c, 0xd1, 0xe3, 0x15, 0x4c, 0x11, 0x3a, 0x76, 0x2a, 0x62, 0x8e, 0x24, 0x94, 0x09,
+        0x07, 0xb4, 0x1e, 0x90, 0xe7, 0xc2, 0xd4, 0x3a, 0xa2, 0x56, 0xea, 0x52, 0x49, 0x97, 0x83, 0x58,
+    ],
+    [
+        0x27, 0x81, 0x6c, 0xab, 0x4b, 0xec, 0x18, 0x57, 0x7b, 0xd3, 0x35, 0xbd, 0xaf, 0x82, 0x98, 0x79,
+        0x8a, 0x26, 0x1b, 0xb1, 0x75, 0x13, 0x71, 0x77, 0xd4, 0x6b, 0xa9, 0x5d, 0xd0, 0x3d, 0xc8, 0xbb,
+    ],
+    [
+        0x16, 0x80, 0xb6, 0x32, 0xa0, 0x4b, 0xe3, 0xeb, 0x1e, 0xd6, 0x4e, 0xf5, 0x89, 0x44, 0x0b, 0x3f,
+        0x95, 0x26, 0xb2, 0xad, 0x82, 0xcf, 0x0b, 0x88, 0x39, 0xd3, 0xe7, 0xb0, 0xf5, 0x85, 0xbc, 0x8c,
+    ],
+    [
+        0x19, 0x6b, 0x2c, 0xc0, 0xe6, 0xe0, 0x76, 0x28, 0xdf, 0x82, 0x34, 0xf5, 0x8c, 0x02, 0x7c, 0x19,
+        0x0c, 0x75, 0xb0, 0x34, 0x25, 0x11, 0xef, 0xea, 0x4c, 0x37, 0x24, 0xc4, 0xfe, 0xdd, 0x6e, 0x75,
+    ],
+    [
+        0x29, 0xab, 0x0b, 0x60, 0x65, 0xeb, 0xbe, 0xfc, 0x68, 0x46, 0x08, 0xac, 0xc6, 0xbf, 0x3b, 0x5f,
+        0xc6, 0x93, 0x77, 0x0c, 0x38, 0xbf, 0x91, 0xb4, 0xbe, 0x38, 0xfe, 0x6f, 0x72, 0x64, 0x74, 0x86,
+    ],
+    [
+        0x22, 0x4c, 0xe8, 0x14, 0xd3, 0x9c, 0x38, 0x2d, 0xa2, 0xd6, 0x5e, 0xcc, 0xd9, 0xde, 0xb4, 0x80,
+        0x56, 0xed, 0xe1, 0x8c, 0x5e, 0xfc, 0xb8, 0xe2, 0x55, 0x15, 0xb7, 0x27, 0xe5, 0x40, 0xa8, 0x59,
+    ],
+    [
+        0x19, 0x5e, 0x1c, 0xb2, 0x8f, 0x38, 0xa9, 0xcb, 0x57, 0xb2, 0xc7, 0x19, 0xb5, 0x04, 0xf5, 0xfd,
+        0xd1, 0xd4, 0x25, 0x59, 0xb6, 0xa3, 0x68, 0x26, 0x4f, 0xd0, 0xfb, 0xfe, 0xfa, 0x66, 0x55, 0x9f,
+    ],
+    [
+        0x0e, 0x9e, 0x5e, 0xb7, 0x0f, 0x44, 0x90, 0xba, 0x6d, 0xbd, 0x1c, 0x0e, 0xe1, 0xd6, 0x72, 0x4c,
+        0xcd, 0xd5, 0x8a, 0xcd, 0xf1, 0x38, 0x78, 0x5c, 0xe9, 0x8e, 0xe3, 0x84, 0x8f, 0xfb, 0x78, 0x40,
+    ],
+    [
+        0x19, 0x95, 0xc9, 0xc8, 0xe5, 0x20, 0x01, 0x4e, 0x44, 0x12, 0xec, 0x65, 0x38, 0x39, 0xd7, 0x81,
+        0x54, 0x8c, 0xf7, 0x1f, 0x46, 0xfb, 0xeb, 0x51, 0x91, 0x95, 0x82, 0x22, 0x6c, 0xbd, 0x5b, 0x21,
+    ],
+    [
+        0x08, 0x86, 0x09, 0x5c, 0xc3, 0x76, 0xc8, 0x7f, 0xa6, 0x53, 0x00, 0x02, 0x83, 0xa6, 0xea, 0xe1,
+        0x10, 0x08, 0x98, 0x2f, 0x27, 0x10, 0x4a, 0xfe, 0xe1, 0x98, 0xfc, 0x37, 0x7f, 0x26, 0xe3, 0x24,
+    ],
+    [
+        0x13, 0x76, 0x1e, 0x20, 0x6b, 0x3e, 0x89, 0xb2, 0x3d, 0xe2, 0x34, 0x2b, 0xfc, 0xa8, 0x60, 0xf9,
+        0xb0, 0xd9, 0xa3, 0x6d, 0x48, 0x6a, 0xa1, 0x15, 0x56, 0x5f, 0x9f, 0x52, 0x36, 0x9c, 0x12, 0x4d,
+    ],
+    [
+        0x22, 0xa8, 0xc6, 0xcf, 0xc0, 0xe3, 0x8a, 0x50, 0x0a, 0x47, 0x51, 0x5c, 0x51, 0x9a, 0xd6, 0xa7,
+        0x25, 0x06, 0xec, 0x0d, 0x50, 0x8d, 0xe2, 0x59, 0x42, 0xc1, 0x0f, 0x51, 0xf0, 0xdf, 0xab, 0x81,
+    ],
+    [
+        0x18, 0x98, 0x5e, 0x9c, 0x7e, 0xa6, 0xf1, 0xa1, 0x69, 0x62, 0xe4, 0xe2, 0x7c, 0xf1, 0xaa, 0x4a,
+        0xa3, 0x08, 0x69, 0x38, 0xb1, 0x3f, 0x8e, 0xe9, 0x46, 0x5a, 0x5e, 0xc7, 0xbe, 0xbd, 0xee, 0x03,
+    ],
+    [
+        0x21, 0x5b, 0x52, 0x3c, 0xe3, 0xf0, 0x55, 0xc9, 0x2e, 0xb1, 0x48, 0xc0, 0x00, 0x27, 0xd1, 0xad,
+        0x40, 0x6a, 0x2a, 0xbd, 0x64, 0x08, 0x10, 0x43, 0xe0, 0xd1, 0x54, 0x92, 0x84, 0x5b, 0x31, 0xa4,
+    ],
+    [
+        0x01, 0x02, 0xf7, 0x8c, 0x75, 0x13, 0xb0, 0x93, 0xb8, 0x85, 0x72, 0x6b, 0x7d, 0xf2, 0xe7, 0x02,
+        0x98, 0x80, 0x1b, 0x3e, 0x40, 0x19, 0xcd, 0xd4, 0x22, 0x39, 0xd7, 0x64, 0x48, 0x58, 0x8c, 0x31,
+    ],
+    [
+        0x0b, 0xce, 0x88, 0x60, 0x2f, 0xd3, 0xa0, 0xf0, 0xab, 0x88, 0x85, 0x89, 0x1a, 0xd4, 0x40, 0x45,
+        0x05, 0x8f, 0x9a, 0xce, 0x11, 0xfd, 0xab, 0xd2, 0x4e, 0x8d, 0xfa, 0x1b, 0x0a, 0x4a, 0xe7, 0x27,
+    ],
+    [
+        0x07, 0x45, 0xf7, 0x60, 0xf8, 0xd4, 0x66, 0xfc, 0x21, 0x04, 0x3e, 0x22, 0x92, 0xe6, 0xcb, 0xb2,
+        0x0b, 0x62, 0x27, 0x51, 0x7f, 0x52, 0x00, 0x25, 0x11, 0x29, 0xc5, 0x48, 0x1f, 0x52, 0xfa, 0x4b,
+    ],
+    [
+        0x04, 0x06, 0xde, 0x38, 0x07, 0x44, 0x5c, 0x6d, 0x3c, 0xbd, 0xf7, 0xb1, 0x74, 0xc4, 0x10, 0xa9,
+        0x98, 0xa1, 0x53, 0x0f, 0xcc, 0xa9, 0x91, 0x53, 0x5a, 0x2a, 0x26, 0x2d, 0xe4, 0xe2, 0xee, 0x56,
+    ],
+    [
+        0x26, 0x77, 0x9e, 0x73, 0x46, 0x34, 0x90, 0x68, 0xdb, 0xba, 0x66, 0xf7, 0x84, 0xf8, 0x7e, 0x24,
+        0x3c, 0xc2, 0x7c, 0xab, 0x59, 0xe8, 0x87, 0xf7, 0xed, 0x7a, 0x01, 0x17, 0x88, 0x7d, 0x1a, 0xcd,
+    ],
+    [
+        0x25, 0x15, 0x06, 0x52, 0xa0, 0x8a, 0xf8, 0xca, 0x57, 0x4c, 0x7c, 0x5c, 0x77, 0xff, 0xd9, 0x8b,
+        0x17, 0x25, 0x9a, 0x1d, 0x91, 0x4a, 0x6e, 0xa4, 0x1d, 0xb7, 0x50, 0x14, 0x26, 0x79, 0x49, 0xd8,
+    ],
+    [
+        0x00, 0xbb, 0x8d, 0x13, 0x43, 0x80, 0x40, 0xf6, 0x74, 0xa7, 0x9e, 0xd4, 0x22, 0x02, 0xc5, 0xbf,
+        0x6e, 0x67, 0xde, 0x6f, 0xc3, 0xf6, 0xb6, 0x5c, 0xa8, 0x37, 0xce, 0xab, 0x79, 0x5f, 0xcc, 0x6f,
+    ],
+    [
+        0x0c, 0x1e, 0xbf, 0x40, 0xee, 0xaa, 0xc3, 0xd4, 0xa1, 0x92, 0xdd, 0x50, 0xee, 0x25, 0xc0, 0x06,
+        0x7f, 0x66, 0xef, 0xf6, 0x22, 0x9d, 0x04, 0x5d, 0x5a, 0x80, 0x10, 0x67, 0x63, 0x9c, 0xcf, 0x2c,
+    ],
+    [
+        0x1a, 0x39, 0x73, 0x1e, 0xb3, 0x2f, 0xdb, 0x51, 0xdd, 0x79, 0x37, 0x54, 0xf7, 0x61, 0x03, 0xb5,
+        0xca, 0xb8, 0x69, 0x47, 0xaa, 0xd1, 0x2d, 0x49, 0x0d, 0x49, 0x51, 0x93, 0x2f, 0xf2, 0x07, 0x27,
+    ],
+    [
+        0x2b, 0xaa, 0xd3, 0xd4, 0x1b, 0x18, 0xae, 0x10, 0xc4, 0x63, 0xaf, 0x16, 0x62, 0xec, 0xb2, 0x8c,
+        0x69, 0x88, 0x52, 0x4e, 0xdd, 0xed, 0x36, 0x94, 0x36, 0x72, 0xa0, 0xe8, 0x57, 0xb9, 0x60, 0xf8,
+    ],
+    [
+        0x2e, 0x77, 0x8d, 0xa1, 0x51, 0x8b, 0x6a, 0x4f, 0xad, 0x2e, 0x83, 0x3e, 0x41, 0x46, 0x6f, 0xb2,
+        0xfe, 0x82, 0xf4, 0xf8, 0xdf, 0x1b, 0x5c, 0x21, 0x8c, 0xdd, 0x3b, 0x7e, 0x49, 0xaf, 0x67, 0xe1,
+    ],
+    [
+        0x15, 0x6d, 0xc7, 0x17, 0x93, 0x0d, 0x64, 0x90, 0x16, 0x77, 0x43, 0x91, 0x1e, 0x9b, 0xf4, 0xd4,
+        0x63, 0x4a, 0xe0, 0x49, 0xb5, 0x3a, 0xda, 0xb2, 0x81, 0x69, 0x86, 0x45, 0xf8, 0xb1, 0x6d, 0xc6,
+    ],
+    [
+        0x11, 0x7f, 0x2e, 0xf1, 0x71, 0x26, 0x92, 0xab, 0x9d, 0xbd, 0xc5, 0x1d, 0xb4, 0x96, 0x8b, 0x45,
+        0xf5, 0xe0, 0x10, 0x4f, 0x9e, 0x52, 0x81, 0x32, 0x93, 0x49, 0xd9, 0x08, 0xb2, 0x3e, 0x23, 0x1c,
+    ],
+    [
+        0x0b, 0x28, 0x1b, 0x5d, 0x9d, 0xd2, 0xca, 0x1a, 0x92, 0xe2, 0xd8, 0x3b, 0x50, 0x87, 0xb6, 0xa0,
+        0x8d, 0x6a, 0xb0, 0x83, 0x30, 0xf4, 0x75, 0x12, 0xaa, 0x04, 0x7b, 0x2f, 0x88, 0xba, 0x68, 0xcc,
+    ],
+    [
+        0x1c, 0x28, 0x03, 0x76, 0x6d, 0xd9, 0x09, 0x65, 0x67, 0xa3, 0x36, 0x60, 0x61, 0x76, 0x4f, 0x8c,
+        0x91, 0x6f, 0x2a, 0x2d, 0xd6, 0x40, 0xb6, 0xee, 0xc0, 0x94, 0x2c, 0xcb, 0x75, 0xd4, 0x07, 0x67,
+    ],
+    [
+        0x12, 0x01, 0x74, 0xd5, 0x3b, 0xa5, 0x7d, 0x1a, 0x14, 0x1b, 0xf4, 0x0e, 0x4c, 0x70, 0x56, 0x83,
+        0x66, 0x8b, 0xd5, 0x58, 0x00, 0x00, 0x0a, 0xab, 0x6f, 0x34, 0x76, 0x6b, 0x7b, 0x65, 0x75, 0xa2,
+    ],
+    [
+        0x0c, 0x37, 0xb2, 0x50, 0x20, 0xf8, 0xc7, 0x6e, 0xa5, 0x53, 0x6d, 0xe5, 0x4c, 0x72, 0x2e, 0x47,
+        0xd0, 0x17, 0x95, 0xd3, 0x1b, 0x5e, 0xd7, 0x34, 0x66, 0xe7, 0xd8, 0xb8, 0xad, 0x37, 0x6d, 0x46,
+    ],
+    [
+        0x0a, 0x50, 0x8f, 0x34, 0x5c, 0xc9, 0xa8, 0xb0, 0xc3, 0x0b, 0xa2, 0x76, 0x51, 0xba, 0x1c, 0x3a,
+        0xd8, 0x39, 0x80, 0x25, 0xa4, 0x00, 0x0a, 0x5c, 0x25, 0x00, 0x22, 0x8c, 0x44, 0xfa, 0x91, 0xb8,
+    ],
+    [
+        0x12, 0xaf, 0xa4, 0x9f, 0xf4, 0xd8, 0xb4, 0xc6, 0xc3, 0x72, 0xf7, 0xe0, 0x65, 0x84, 0x07, 0x83,
+        0xcb, 0x94, 0x97, 0xee, 0xac, 0xc8, 0x47, 0xa3, 0x40, 0xf6, 0x1f, 0x18, 0x23, 0xcb, 0x2a, 0x8d,
+    ],
+    [
+        0x29, 0xfc, 0x37, 0xeb, 0x5e, 0xc7, 0xcd, 0x00, 0x48, 0x09, 0x51, 0x6b, 0x7a, 0xa3, 0xc7, 0x16,
+        0xf4, 0xf7, 0xf6, 0xe7, 0x77, 0x2f, 0x44, 0x3e, 0x07, 0x30, 0xb0, 0x1d, 0xd5, 0x07, 0x1a, 0x55,
+    ],
+    [
+        0x20, 0x48, 0xfb, 0x9c, 0x1f, 0xda, 0xce, 0xd0, 0xfd, 0xc5, 0x15, 0x94, 0x90, 0xdb, 0x29, 0x77,
+        0xe5, 0x7d, 0xbc, 0xbf, 0xf7, 0x51, 0x4a, 0xd1, 0xe5, 0x2a, 0x27, 0x1b, 0xf8, 0x4d, 0xd1, 0xa8,
+    ],
+    [
+        0x00, 0xa6, 0xc4, 0x22, 0x3a, 0xab, 0xe5, 0x83, 0xe8, 0x99, 0xbb, 0xcd, 0x03, 0x14, 0xa4, 0x74,
+        0x31, 0x81, 0xf1, 0xd5, 0xe0, 0x3d, 0xbc, 0x11, 0xc8, 0xa4, 0xd0, 0x25, 0x50, 0xfa, 0xb6, 0x4f,
+    ],
+    [
+        0x15, 0xf4, 0x62, 0xab, 0x5b, 0x21, 0xc3, 0x15, 0x57, 0xe2, 0x5d, 0x9c, 0x22, 0xd8, 0xdc, 0x6a,
+        0x0c, 0xca, 0x93, 0xc8, 0xed, 0x68, 0xa3, 0x56, 0x55, 0xcf, 0xf7, 0x45, 0x68, 0x67, 0x64, 0xd0,
+    ],
+    [
+        0x27, 0xbc, 0x1c, 0x9a, 0xf6, 0x8e, 0xdf, 0x8e, 0x37, 0x30, 0x19, 0x71, 0x24, 0xbe, 0x55, 0x9f,
+        0x14, 0x72, 0xe7, 0xe3, 0xbb, 0xa0, 0xd3, 0x5e, 0x07, 0x9b, 0x2d, 0x1b, 0x9e, 0x42, 0x71, 0xef,
+    ],
+    [
+        0x1a, 0x2e, 0xc1, 0x48, 0xf9, 0x00, 0xee, 0xae, 0x38, 0xc0, 0xd3, 0xd6, 0xef, 0xda, 0x64, 0xe1,
+        0x46, 0x92, 0x9c, 0x71, 0xeb, 0x82, 0xda, 0x31, 0x39, 0xa3, 0xa8, 0x59, 0x68, 0xec, 0x6e, 0xbc,
+    ],
+    [
+        0x04, 0xff, 0x41, 0xcb, 0x55, 0x3c, 0xa6, 0x16, 0xd0, 0x61, 0x65, 0x45, 0x92, 0x2f, 0x20, 0x30,
+        0xb7, 0x1b, 0x23, 0x54, 0xbc, 0xd1, 0xc5, 0x5d, 0x7e, 0xab, 0xca, 0xea, 0x89, 0x04, 0xc2, 0x2e,
+    ],
+    [
+        0x2f, 0x2c, 0x4e, 0x7b, 0x18, 0xa8, 0x45, 0xfc, 0xd6, 0xad, 0xd6, 0x70, 0x09, 0xfc, 0x23, 0x7b,
+        0x14, 0xa9, 0x84, 0x61, 0x92, 0x60, 0xa7, 0xb3, 0xd3, 0x87, 0x12, 0xe0, 0x3a, 0x08, 0x49, 0xb7,
+    ],
+    [
+        0x22, 0x92, 0x5c, 0x60, 0x6a, 0x1f, 0x09, 0x1c, 0x1d, 0x5c, 0x71, 0x22, 0xf8, 0xe1, 0xa8, 0xff,
+        0xea, 0x6a, 0x14, 0x00, 0x31, 0x10, 0x68, 0x26, 0xac, 0x53, 0xa9, 0xc6, 0x9d, 0x84, 0xc0, 0xab,
+    ],
+    [
+        0x10, 0xe2, 0xa8, 0xc7, 0xb1, 0x2b, 0xf7, 0x02, 0x66, 0xc2, 0x57, 0x57, 0x3c, 0xa2, 0x59, 0x4d,
+        0x88, 0x11, 0x63, 0x3f, 0x77, 0x82, 0x2d, 0x7c, 0x23, 0x2b, 0x32, 0x9c, 0x5b, 0x4e, 0x72, 0x79,
+    ],
+    [
+        0x17, 0x12, 0x31, 0x95, 0x95, 0xae, 0x46, 0x49, 0x92, 0x52, 0x4b, 0xf8, 0xe4, 0x88, 0xb7, 0x83,
+        0xfa, 0x45, 0xb3, 0x54, 0x8c, 0xd3, 0xf1, 0x62, 0xd5, 0x86, 0xbc, 0xe3, 0x1e, 0xc7, 0x18, 0x4c,
+    ],
+    [
+        0x2e, 0x96, 0x16, 0x98, 0x7d, 0x33, 0x33, 0x38, 0x8e, 0xa5, 0xa1, 0xcb, 0x56, 0xb4, 0xc1, 0x61,
+        0xf2, 0x79, 0xff, 0x19, 0x45, 0x4a, 0x64, 0xad, 0xe4, 0x3d, 0xae, 0xd4, 0xdc, 0xda, 0x5c, 0x0b,
+    ],
+    [
+        0x2c, 0x68, 0x21, 0x0d, 0x75, 0x66, 0x0d, 0xf5, 0xd9, 0xa8, 0x39, 0x2a, 0x28, 0x05, 0xea, 0x16,
+        0x12, 0xfc, 0x61, 0x37, 0xcf, 0x5b, 0x35, 0xa6, 0x8f, 0xc1, 0xf0, 0x3a, 0xcf, 0xbf, 0xe4, 0xca,
+    ],
+    [
+        0x05, 0x3c, 0xf3, 0x70, 0x72, 0x83, 0xdf, 0x87, 0x30, 0xb6, 0xe1, 0xf6, 0x37, 0xd2, 0x3c, 0x67,
+        0xb0, 0xb3, 0x53, 0x30, 0x61, 0xa3, 0x5b, 0xcb, 0x0c, 0xb0, 0xf9, 0xbb, 0xe4, 0xe8, 0xad, 0xc2,
+    ],
+    [
+        0x07, 0x57, 0x74, 0x91, 0x48, 0x3d, 0x03, 0xd7, 0x76, 0x9d, 0xac, 0xc7, 0x96, 0x07, 0xb9, 0x89,
+        0x70, 0x81, 0xc0, 0x09, 0x5d, 0xec, 0xdc, 0xe3, 0xd2, 0x27, 0xc0, 0x59, 0xdd, 0xed, 0xdb, 0x66,
+    ],
+    [
+        0x05, 0x63, 0x9b, 0x23, 0x24, 0xc0, 0xb0, 0xaf, 0x81, 0xc5, 0xb2, 0x56, 0xca, 0x6b, 0x2d, 0x46,
+        0xb9, 0x5e, 0xaf, 0x85, 0x5f, 0xc2, 0x6c, 0xed, 0xbf, 0x90, 0x41, 0x13, 0x12, 0xde, 0x4a, 0x12,
+    ],
+    [
+        0x0e, 0xc9, 0x09, 0x44, 0xeb, 0x8f, 0xc3, 0x6c, 0x52, 0xf3, 0xfd, 0x13, 0x76, 0x37, 0x14, 0xf4,
+        0x1c, 0xea, 0x1f, 0xf4, 0x87, 0xea, 0x39, 0xd7, 0x0b, 0xd9, 0x4e, 0x9b, 0x7f, 0x8f, 0x16, 0xd5,
+    ],
+    [
+        0x1e, 0x96, 0xcd, 0x3a, 0xc3, 0x45, 0xbb, 0x2e, 0x5b, 0xd3, 0xe7, 0x55, 0x92, 0x2f, 0x84, 0xaf,
+        0xaf, 0x30, 0x3d, 0x6f, 0xb2, 0x27, 0xfc, 0x68, 0xf7, 0x03, 0xb9, 0x36, 0x65, 0xf5, 0xea, 0x96,
+    ],
+    [
+        0x04, 0x08, 0x42, 0x54, 0x05, 0xc6, 0x6b, 0xaa, 0x7d, 0x49, 0x85, 0x8e, 0x6d, 0x58, 0x71, 0xe5,
+        0xb4, 0x2f, 0xfe, 0xbb, 0x0d, 0xf8, 0x85, 0xa8, 0x24, 0x94, 0x2d, 0xa1, 0xe2, 0xe3, 0x6d, 0x58,
+    ],
+    [
+        0x28, 0x4d, 0x77, 0x56, 0x6b, 0x42, 0xf3, 0x91, 0x3d, 0x41, 0xeb, 0xb0, 0x52, 0x8b, 0x89, 0x94,
+        0x26, 0xe0, 0x80, 0x5a, 0xad, 0xb9, 0x95, 0x7a, 0x53, 0x4b, 0x02, 0x2b, 0x08, 0x1a, 0xa9, 0x77,
+    ],
+    [
+        0x0b, 0x04, 0x4e, 0x59, 0x84, 0x88, 0x7c, 0xda, 0x33, 0x7f, 0x58, 0xc7, 0xca, 0x50, 0xa7, 0x81,
+        0x26, 0xde, 0xb5, 0x62, 0x4f, 0x64, 0x45, 0x16, 0x23, 0x94, 0x27, 0x94, 0x8d, 0x7d, 0x06, 0xbc,
+    ],
+    [
+        0x04, 0x86, 0x97, 0x21, 0x79, 0xee, 0x34, 0x96, 0x79, 0x5b, 0xde, 0xd1, 0xc2, 0xfc, 0x96, 0x74,
+        0xc0, 0xc3, 0xf7, 0x1b, 0x58, 0x15, 0x0c, 0x9c, 0x52, 0x50, 0x63, 0xa6, 0xf2, 0xb3, 0x40, 0xd8,
+    ],
+    [
+        0x25, 0xb7, 0x56, 0x83, 0x16, 0xc4, 0xb2, 0xfe, 0xf9, 0xb7, 0x25, 0x43, 0xb6, 0x03, 0x3b, 0x31,
+        0x7a, 0x7b, 0xfe, 0x40, 0x66, 0x23, 0xe4, 0x7c, 0xb6, 0x8e, 0x5c, 0x79, 0x29, 0x6e, 0x6b, 0xee,
+    ],
+    [
+        0x0c, 0xec, 0x9e, 0x74, 0xc3, 0x44, 0x19, 0x87, 0x03, 0xce, 0xff, 0x28, 0xa4, 0xd1, 0x12, 0x75,
+        0xf6, 0xc5, 0xc1, 0x57, 0x38, 0xaa, 0x5a, 0x54, 0xa1, 0x92, 0x69, 0x26, 0x1e, 0xc6, 0xa8, 0xa8,
+    ],
+    [
+        0x2b, 0xcc, 0x34, 0xa9, 0x93, 0x45, 0x17, 0x4c, 0x97, 0xa6, 0x5e, 0x89, 0x78, 0x19, 0x44, 0x86,
+        0xa9, 0x95, 0x7c, 0x9d, 0x73, 0x6f, 0x0c, 0xa3, 0x9b, 0x98, 0x13, 0x08, 0x01, 0xd1, 0xa1, 0x14,
+    ],
+    [
+        0x2d, 0xdb, 0x2e, 0xbc, 0x0a, 0xc9, 0x8f, 0x64, 0x98, 0xc0, 0xf2, 0xbe, 0x2d, 0x73, 0xe8, 0x6a,
+        0x78, 0x69, 0x40, 0xa1, 0xe8, 0xc5, 0x58, 0xd8, 0xdb, 0xed, 0x8d, 0x6f, 0x44, 0xb0, 0x25, 0x51,
+    ],
+    [
+        0x26, 0x42, 0xb4, 0xa2, 0x08, 0xe5, 0x03, 0xe3, 0x48, 0x07, 0x18, 0xd4, 0xd2, 0x05, 0x35, 0x1f,
+        0x43, 0xda, 0x18, 0x3e, 0xd2, 0x84, 0xed, 0x7a, 0x38, 0x5e, 0xf7, 0xcd, 0xca, 0x80, 0xbe, 0xa8,
+    ],
+    [
+        0x23, 0xfa, 0xbd, 0xb0, 0x9b, 0x48, 0x1b, 0xc7, 0x64, 0x10, 0x2a, 0xe8, 0x5c, 0xcf, 0x1e, 0x6f,
+        0xf7, 0x1c, 0x77, 0x62, 0xf8, 0xba, 0xd0, 0x4a, 0x2d, 0xe3, 0xf7, 0xa0, 0x25, 0xcb, 0x50, 0x87,
+    ],
+    [
+        0x0a, 0xe4, 0xed, 0x0e, 0xa6, 0xad, 0xb7, 0x4b, 0x9d, 0x32, 0x7f, 0xbc, 0xd0, 0x1d, 0xbf, 0x37,
+        0xf5, 0xf6, 0xc4, 0x67, 0x48, 0x0c, 0x8a, 0xa5, 0xf4, 0x95, 0x12, 0x4c, 0xf7, 0x0b, 0x82, 0x3e,
+    ],
+    [
+        0x09, 0x03, 0x4e, 0x1a, 0x64, 0x6d, 0x66, 0x74, 0xbe, 0xf0, 0x41, 0xaf, 0xa7, 0x74, 0xe3, 0x63,
+        0x4f, 0xaf, 0x8a, 0xdc, 0xc7, 0xc7, 0x1b, 0xc2, 0xed, 0x6e, 0x03, 0xb0, 0xb4, 0x49, 0x20, 0x16,
+    ],
+    [
+        0x28, 0x17, 0xea, 0x20, 0xb4, 0x71, 0xa4, 0xbf, 0x13, 0x8d, 0xec, 0x79, 0xd8, 0x4f, 0x9f, 0x6c,
+        0x00, 0x15, 0xe0, 0xca, 0x14, 0x7b, 0x65, 0x06, 0x83, 0xdf, 0x4b, 0x33, 0x72, 0xb5, 0x5e, 0xf2,
+    ],
+    [
+        0x2d, 0xfb, 0x7f, 0x81, 0x42, 0x5c, 0x2d, 0xef, 0x6b, 0xfa, 0x4e, 0x83, 0x3e, 0x51, 0xe9, 0x12,
+        0x29, 0x83, 0x97, 0xde, 0x78, 0xfd, 0x2a, 0xa1, 0x7f, 0x6d, 0x1d, 0xd2, 0xf1, 0x37, 0x9c, 0xa9,
+    ],
+    [
+        0x13, 0x7b, 0x5b, 0xdb, 0x74, 0x65, 0x23, 0xc0, 0x51, 0xd6, 0x80, 0xbe, 0x08, 0xda, 0xa6, 0xb0,
+        0xd5, 0x4c, 0x9f, 0xf7, 0x09, 0x9f, 0x2b, 0xf5, 0xce, 0x89, 0x4c, 0x24, 0xa8, 0xef, 0x1f, 0x37,
+    ],
+    [
+        0x03, 0x90, 0x0e, 0xc7, 0xad, 0xc8, 0xe2, 0x8a, 0xf7, 0x04, 0x68, 0x33, 0xdb, 0xad, 0x3f, 0xa1,
+        0x02, 0x4e, 0x38, 0x2a, 0xeb, 0xe0, 0x2b, 0xcb, 0x53, 0x52, 0x43, 0xdd, 0xca, 0x21, 0xaa, 0x85,
+    ],
+    [
+        0x2b, 0x28, 0x5b, 0x6c, 0x23, 0xb2, 0x5c, 0x52, 0xf2, 0x07, 0x98, 0x63, 0xc7, 0x59, 0xe6, 0xa1,
+        0x0c, 0x33, 0xdf, 0x8f, 0x19, 0x15, 0x51, 0xea, 0xd5, 0x3e, 0xa5, 0x08, 0x51, 0x1c, 0xb5, 0x55,
+    ],
+    [
+        0x12, 0x42, 0x06, 0x7b, 0xe2, 0x20, 0x2d, 0x1b, 0x2c, 0x32, 0xb7, 0x9c, 0x46, 0x4e, 0x2d, 0x6c,
+        0x97, 0x7e, 0x6f, 0xd1, 0x2d, 0x8b, 0x35, 0x28, 0x1e, 0x84, 0xc3, 0x75, 0x86, 0x46, 0x13, 0xd8,
+    ],
+    [
+        0x1e, 0x86, 0x90, 0xf5, 0xb0, 0xa8, 0x72, 0x9b, 0x95, 0x36, 0xef, 0x29, 0x20, 0xab, 0xe7, 0xe6,
+        0xf3, 0xee, 0xe8, 0x05, 0x23, 0x7b, 0x15, 0x8d, 0x99, 0x7d, 0xb6, 0x3b, 0x17, 0x52, 0x5e, 0xef,
+    ],
+    [
+        0x04, 0x7f, 0x51, 0x13, 0xe1, 0x28, 0x7b, 0x55, 0x72, 0xc8, 0x2e, 0xdb, 0x36, 0x9f, 0xb0, 0xf7,
+        0x17, 0xa9, 0x1f, 0x26, 0x1e, 0xf9, 0xd8, 0x17, 0x64, 0xa5, 0xb2, 0x79, 0xad, 0x9c, 0x13, 0xba,
+    ],
+    [
+        0x2d, 0x88, 0x00, 0xf2, 0x1c, 0xd6, 0x77, 0x4d, 0x5a, 0x6a, 0xa5, 0x8a, 0xe2, 0xcd, 0x32, 0x59,
+        0x35, 0x93, 0xd1, 0x73, 0xb6, 0x87, 0x12, 0x2a, 0x4d, 0x61, 0xc4, 0x47, 0x43, 0x90, 0x21, 0x05,
+    ],
+    [
+        0x27, 0x4b, 0xa1, 0x52, 0x1d, 0x5b, 0xc5, 0xc5, 0x80, 0x64, 0x7f, 0x10, 0xd1, 0xa4, 0xc4, 0xca,
+        0xde, 0xef, 0x08, 0x86, 0x85, 0xd1, 0x97, 0x69, 0x79, 0xba, 0xd8, 0x13, 0x75, 0x6f, 0x84, 0xb0,
+    ],
+    [
+        0x17, 0x89, 0x5b, 0x7c, 0x10, 0xd6, 0xa4, 0x75, 0x31, 0x0d, 0x34, 0xa0, 0x73, 0x7e, 0xfa, 0xed,
+        0xdd, 0xca, 0x8f, 0x1e, 0xee, 0x9d, 0x8d, 0x56, 0xe7, 0xfe, 0x49, 0x34, 0xc8, 0xb5, 0x64, 0x41,
+    ],
+    [
+        0x06, 0xee, 0xd1, 0x1a, 0xe9, 0xf7, 0x0d, 0xf5, 0xce, 0xd0, 0x9b, 0x1a, 0x6d, 0xb2, 0x58, 0x97,
+        0x8c, 0xf5, 0xbe, 0xca, 0xd2, 0xf6, 0x6d, 0x15, 0x76, 0x1b, 0xf6, 0x1e, 0x7d, 0xc1, 0x34, 0x40,
+    ],
+    [
+        0x30, 0x36, 0xb5, 0x21, 0xc6, 0x31, 0xce, 0x5d, 0x35, 0x99, 0xe3, 0x7f, 0x6a, 0x5d, 0xaf, 0x92,
+        0x88, 0x42, 0xce, 0xfc, 0xbc, 0xd2, 0xc6, 0xbf, 0x84, 0xe2, 0xdb, 0x5d, 0xed, 0x74, 0x8f, 0x09,
+    ],
+    [
+        0x1f, 0x52, 0xd6, 0xe9, 0xac, 0x28, 0x97, 0x6c, 0x5a, 0x6f, 0xbf, 0x16, 0x8c, 0x81, 0x84, 0x78,
+        0x64, 0x5c, 0x90, 0xd7, 0x2e, 0x36, 0x76, 0x09, 0xd7, 0xd7, 0x80, 0x5a, 0x63, 0x67, 0x48, 0x2d,
+    ],
+    [
+        0x2a, 0x0e, 0x60, 0x35, 0x68, 0x75, 0xd6, 0xac, 0xe1, 0x05, 0x59, 0x32, 0xb3, 0xdd, 0x9e, 0xf3,
+        0x9c, 0x3d, 0xd8, 0x71, 0x09, 0x1e, 0x96, 0xa3, 0x23, 0xc1, 0x59, 0x6c, 0xed, 0xb3, 0x5e, 0x6b,
+    ],
+    [
+        0x02, 0x08, 0x96, 0x33, 0xa6, 0x19, 0x4f, 0x3e, 0xd3, 0x8e, 0xee, 0xc2, 0xc8, 0xf5, 0x4d, 0x0c,
+        0x7f, 0x43, 0x49, 0x38, 0xfd, 0xeb, 0xb9, 0xc0, 0xe1, 0x83, 0x0d, 0xbe, 0x53, 0x11, 0xc3, 0x8f,
+    ],
+    [
+        0x17, 0x8c, 0x73, 0xd2, 0x76, 0x97, 0x65, 0xd8, 0x53, 0x83, 0x28, 0x3f, 0xdb, 0x0d, 0x76, 0x94,
+        0x57, 0xcf, 0xef, 0x06, 0x12, 0xba, 0xa9, 0x63, 0x33, 0x6b, 0xac, 0x12, 0x40, 0xb6, 0x04, 0xc2,
+    ],
+    [
+        0x2f, 0xc2, 0x0b, 0xca, 0xba, 0x9e, 0x3d, 0x96, 0xeb, 0xcd, 0xd7, 0x10, 0xe3, 0xb3, 0x41, 0x83,
+        0x7d, 0x5d, 0x0d, 0xb8, 0x49, 0xe7, 0x89, 0xf2, 0x8c, 0x4b, 0xe5, 0x00, 0x4a, 0xfa, 0x19, 0xa0,
+    ],
+    [
+        0x15, 0x1a, 0x42, 0x3b, 0xee, 0x67, 0xfb, 0x14, 0xd5, 0xad, 0x27, 0x9f, 0x42, 0x52, 0x13, 0xdb,
+        0x7e, 0x0f, 0x21, 0x49, 0x49, 0x82, 0xdf, 0x87, 0x23, 0x54, 0xc0, 0x1f, 0x0f, 0x4a, 0x72, 0x2c,
+    ],
+    [
+        0x25, 0xd6, 0x16, 0x19, 0xe1, 0x35, 0x69, 0x0c, 0x4e, 0x10, 0xe4, 0xaf, 0xeb, 0x4f, 0x55, 0x78,
+        0xad, 0x0f, 0x8e, 0x25, 0xc2, 0x07, 0x35, 0x36, 0x68, 0x95, 0xb3, 0x1e, 0xeb, 0xcd, 0xf4, 0x7e,
+    ],
+    [
+        0x15, 0xdd, 0x57, 0x9b, 0xbd, 0x07, 0x54, 0x29, 0x30, 0x1d, 0x72, 0x95, 0xd3, 0xe6, 0x85, 0xd8,
+        0x32, 0xbf, 0x9f, 0x36, 0xc5, 0x12, 0x77, 0xd0, 0x9f, 0x2c, 0x3a, 0x66, 0x4b, 0x04, 0x5c, 0xd7,
+    ],
+    [
+        0x23, 0x6f, 0xa2, 0x9b, 0x81, 0x67, 0x8c, 0x8a, 0x10, 0x9f, 0xd3, 0x61, 0xc1, 0xca, 0x90, 0xad,
+        0xf4, 0x42, 0x3c, 0x13, 0xcf, 0x5b, 0x8c, 0xc1, 0x03, 0x2b, 0x72, 0x83, 0xbc, 0xc9, 0x59, 0xb1,
+    ],
+    [
+        0x2a, 0x80, 0xcb, 0xac, 0x9b, 0x88, 0x4e, 0x20, 0x36, 0x86, 0x91, 0xbf, 0x15, 0x67, 0xcb, 0x73,
+        0x20, 0x53, 0x7a, 0x97, 0x7d, 0xa1, 0x0e, 0x53, 0xc5, 0xba, 0xea, 0x34, 0x46, 0xc0, 0x1d, 0x1d,
+    ],
+    [
+        0x11, 0xd4, 0xcb, 0x89, 0x57, 0x93, 0x7b, 0x36, 0x28, 0xa3, 0xb3, 0xf4, 0xd2, 0x7f, 0x27, 0x6c,
+        0xc0, 0xc1, 0x79, 0xb0, 0xc2, 0x2b, 0x2c, 0x13, 0xff, 0xfb, 0xde, 0x85, 0xfd, 0x91, 0x23, 0x78,
+    ],
+    [
+        0x12, 0x89, 0xbf, 0x8e, 0xc5, 0x07, 0xbf, 0x75, 0x2a, 0x07, 0xc9, 0xb7, 0x1e, 0xfc, 0x0f, 0x80,
+        0x0a, 0x52, 0xfb, 0xeb, 0x16, 0x37, 0xc5, 0x01, 0x0f, 0xa5, 0x4e, 0xad, 0xab, 0x40, 0x29, 0x46,
+    ],
+    [
+        0x26, 0xf8, 0x42, 0xb1, 0xa9, 0x93, 0x94, 0x29, 0x7d, 0x25, 0x79, 0x9f, 0x2f, 0x03, 0xdc, 0x9e,
+        0x49, 0x03, 0xe7, 0x7d, 0x2d, 0xdc, 0x03, 0xec, 0x2f, 0xb1, 0x55, 0x46, 0x6d, 0xa6, 0xf3, 0x03,
+    ],
+    [
+        0x15, 0x94, 0x4b, 0x99, 0x54, 0xb6, 0x18, 0x23, 0x14, 0xe8, 0x78, 0xfa, 0x2f, 0x9e, 0x09, 0x1d,
+        0xcc, 0x40, 0xea, 0xdc, 0x48, 0xd3, 0x7d, 0x39, 0xbd, 0xd5, 0xf0, 0x99, 0x05, 0xc3, 0xa3, 0x69,
+    ],
+    [
+        0x07, 0x42, 0x16, 0x6d, 0xcd, 0x8e, 0xbd, 0x85, 0x76, 0xa6, 0xbf, 0xe0, 0xf1, 0xfe, 0xd1, 0x37,
+        0x93, 0x92, 0x8f, 0x84, 0x44, 0x2c, 0x74, 0x65, 0xa0, 0x01, 0x2f, 0x58, 0x44, 0x26, 0xfa, 0xdf,
+    ],
+    [
+        0x08, 0x60, 0x3d, 0x5a, 0x0e, 0x93, 0x7b, 0x53, 0x53, 0xb5, 0x6b, 0x05, 0x76, 0x8f, 0xd5, 0x0b,
+        0xde, 0x79, 0x2e, 0x72, 0xfd, 0x61, 0xc0, 0x63, 0x44, 0xcb, 0xd7, 0x35, 0x70, 0x7b, 0x68, 0xff,
+    ],
+    [
+        0x0c, 0xaa, 0x17, 0x11, 0xc6, 0x6e, 0x93, 0x01, 0xb6, 0xf2, 0x5e, 0x21, 0xa3, 0x16, 0x83, 0xd8,
+        0x0b, 0xf5, 0x8d, 0x86, 0x03, 0x2d, 0x03, 0xbf, 0xd2, 0x5b, 0x97, 0x77, 0xed, 0xb7, 0x4a, 0x75,
+    ],
+    [
+        0x2d, 0x65, 0x06, 0xda, 0x69, 0x5d, 0x20, 0x87, 0xdf, 0xf8, 0x7b, 0x5f, 0x63, 0x6e, 0xe6, 0x76,
+        0xfa, 0xb0, 0x0c, 0x32, 0xae, 0x45, 0xc7, 0x5b, 0x3d, 0xb3, 0x45, 0xf3, 0xfe, 0x74, 0xa3, 0x11,
+    ],
+    [
+        0x0d, 0x92, 0x44, 0xb0, 0x7a, 0xf9, 0xb6, 0xea, 0xef, 0xf6, 0xf8, 0x40, 0x49, 0xcb, 0x2e, 0x01,
+        0xd7, 0x5d, 0xc4, 0xcb, 0x68, 0x53, 0x36, 0x06, 0xe9, 0x76, 0xb8, 0xb3, 0x00, 0x75, 0x8c, 0x84,
+    ],
+    [
+        0x24, 0x3a, 0xbc, 0x3c, 0x33, 0x82, 0xfa, 0xa5, 0xdd, 0xe3, 0xf1, 0xc3, 0xb4, 0x32, 0x04, 0x2d,
+        0x3f, 0x03, 0x0e, 0xf6, 0xa1, 0xf5, 0x40, 0x1a, 0xa3, 0x7a, 0xcd, 0x15, 0xcb, 0x1c, 0x8c, 0x46,
+    ],
+    [
+        0x16, 0x84, 0x16, 0xe0, 0xa6, 0xd2, 0x75, 0xd3, 0x0e, 0xda, 0x39, 0x89, 0x24, 0x2e, 0x07, 0x23,
+        0x02, 0x47, 0x27, 0xd3, 0xb0, 0x79, 0x1b, 0x06, 0xf8, 0xe1, 0xbf, 0xe8, 0xef, 0xe4, 0x01, 0x98,
+    ],
+    [
+        0x1b, 0xcd, 0xee, 0xf0, 0x36, 0x93, 0xd1, 0xad, 0x0e, 0xa8, 0x0c, 0x65, 0x68, 0x86, 0x54, 0xaf,
+        0xfe, 0x63, 0x4a, 0xa1, 0x4c, 0xb1, 0x1a, 0xe3, 0xa4, 0x91, 0x68, 0x1d, 0x9c, 0x5f, 0x8e, 0xeb,
+    ],
+    [
+        0x02, 0xf6, 0x6b, 0xfd, 0xa3, 0xd2, 0xa1, 0xbd, 0xed, 0xb0, 0xab, 0x8b, 0xf7, 0xeb, 0x1c, 0x4e,
+        0x55, 0x1b, 0xef, 0x58, 0xd9, 0x4f, 0xad, 0x7f, 0x7c, 0x23, 0x11, 0x07, 0xe5, 0x65, 0x39, 0x09,
+    ],
+    [
+        0x01, 0x9f, 0x68, 0x02, 0x17, 0x1a, 0xb8, 0x2a, 0x59, 0x22, 0xf0, 0x23, 0xfd, 0xec, 0xc0, 0x97,
+        0xeb, 0x8a, 0xc1, 0xfd, 0x0a, 0x1e, 0xd3, 0x49, 0xaf, 0xe9, 0xa7, 0xb9, 0x42, 0x98, 0x97, 0x5a,
+    ],
+    [
+        0x19, 0x5a, 0x1c, 0x73, 0x83, 0x98, 0xfe, 0xed, 0x8c, 0x76, 0x04, 0x68, 0xa0, 0x55, 0x6c, 0x23,
+        0x26, 0x45, 0xa1, 0xdd, 0x86, 0x2d, 0x87, 0xc1, 0xf5, 0x08, 0xc9, 0x49, 0xad, 0x52, 0xc3, 0xae,
+    ],
+    [
+        0x0a, 0x36, 0xa3, 0xa4, 0x6e, 0x6e, 0xc1, 0x65, 0x67, 0xdf, 0xae, 0x1f, 0x85, 0x3f, 0x36, 0x0e,
+        0x6f, 0x24, 0x92, 0x48, 0x10, 0xe8, 0x38, 0xcb, 0x24, 0xed, 0x1b, 0x0f, 0xce, 0x37, 0xf1, 0xe3,
+    ],
+    [
+        0x00, 0x21, 0x23, 0xc2, 0x9e, 0xa8, 0x7f, 0x7e, 0xa7, 0xb3, 0x5c, 0x25, 0xf9, 0xe8, 0xdd, 0x3e,
+        0x81, 0xbb, 0xc3, 0x97, 0x4c, 0x3c, 0x17, 0xc6, 0x07, 0xcd, 0x84, 0x0c, 0x09, 0xf3, 0x74, 0xae,
+    ],
+    [
+        0x02, 0xa7, 0x17, 0x49, 0x9c, 0xb6, 0xc3, 0x1f, 0x71, 0xf0, 0xf0, 0x35, 0x2b, 0x0f, 0x5b, 0xfe,
+        0xf3, 0x00, 0x5b, 0x80, 0xe9, 0x3a, 0x52, 0x0b, 0x6e, 0x42, 0xb3, 0x98, 0x57, 0x89, 0xe2, 0xd3,
+    ],
+    [
+        0x0f, 0xc0, 0x53, 0x58, 0x4b, 0x0a, 0x46, 0x07, 0xa2, 0x29, 0x3e, 0x18, 0xba, 0xa3, 0x09, 0xc5,
+        0x2f, 0xdc, 0x93, 0x3e, 0x9b, 0xa9, 0x0f, 0xd3, 0x27, 0xaa, 0xa8, 0xc8, 0xcf, 0xc4, 0xda, 0xa1,
+    ],
+    [
+        0x13, 0x10, 0xd1, 0x94, 0x21, 0x29, 0x73, 0x7b, 0x32, 0xe8, 0x7a, 0x86, 0xf4, 0x57, 0x30, 0x32,
+        0xb1, 0xb9, 0x9a, 0xe3, 0xc6, 0xd7, 0x1b, 0xaf, 0xef, 0x7b, 0x49, 0x47, 0x94, 0x85, 0x55, 0x41,
+    ],
+    [
+        0x1b, 0xfa, 0xda, 0xe5, 0x3f, 0xc8, 0x29, 0x00, 0xf5, 0x76, 0x1c, 0x55, 0x8f, 0xee, 0x5e, 0x12,
+        0x18, 0xe6, 0xb2, 0xa6, 0x65, 0x7e, 0x74, 0xb5, 0x40, 0x96, 0x9e, 0xeb, 0x49, 0xe0, 0xb8, 0xde,
+    ],
+    [
+        0x28, 0xc0, 0x8a, 0x69, 0xe5, 0xf3, 0x64, 0x14, 0x60, 0xff, 0x2c, 0x60, 0xb7, 0x57, 0x93, 0x95,
+        0x65, 0x0c, 0x5b, 0x6a, 0xfb, 0x39, 0x2d, 0xa1, 0x42, 0xd3, 0x2d, 0xe8, 0x67, 0x75, 0x56, 0xea,
+    ],
+    [
+        0x22, 0x32, 0x22, 0xee, 0x42, 0xb6, 0xca, 0x99, 0x7f, 0x44, 0xd6, 0x43, 0xed, 0x6a, 0x3f, 0x2b,
+        0x04, 0x0f, 0x47, 0xfe, 0x8c, 0xd9, 0x22, 0x16, 0x3d, 0x9d, 0x9d, 0x8b, 0x26, 0x22, 0xd9, 0x09,
+    ],
+    [
+        0x06, 0x4d, 0xad, 0x47, 0x52, 0xe7, 0x08, 0xff, 0x29, 0xbb, 0x08, 0x42, 0xd2, 0xc4, 0x52, 0xa7,
+        0x14, 0x48, 0xb4, 0x39, 0x49, 0x34, 0x56, 0x90, 0x03, 0x1c, 0xb1, 0x25, 0x58, 0x88, 0x76, 0xa0,
+    ],
+    [
+        0x2d, 0x5d, 0xdc, 0x21, 0x79, 0x70, 0x43, 0x99, 0x6b, 0x22, 0xdc, 0xab, 0xb9, 0xa1, 0xba, 0x6c,
+        0x82, 0xb7, 0x1e, 0x9a, 0x1b, 0xc5, 0x91, 0x9d, 0xb8, 0xbe, 0xfd, 0x35, 0x41, 0x10, 0x56, 0xa5,
+    ],
+    [
+        0x26, 0x31, 0x5f, 0xcd, 0x9b, 0x5e, 0x1a, 0x26, 0x6e, 0xc8, 0xe9, 0xf6, 0x60, 0x0f, 0x5a, 0x6e,
+        0x0b, 0xcd, 0xb2, 0x2f, 0x92, 0x6d, 0x61, 0x59, 0x1e, 0x83, 0xfa, 0x21, 0x06, 0x9c, 0x0e, 0x0b,
+    ],
+    [
+        0x07, 0x59, 0x27, 0x3a, 0x41, 0x77, 0xfc, 0xde, 0xab, 0x23, 0xda, 0x38, 0xb4, 0x78, 0xfa, 0xf2,
+        0xd4, 0xf2, 0x2e, 0x82, 0xf3, 0x46, 0xaa, 0x75, 0x4b, 0x0a, 0x13, 0x31, 0xbc, 0x83, 0x71, 0xee,
+    ],
+    [
+        0x09, 0xe1, 0x33, 0x00, 0xea, 0xde, 0x70, 0x56, 0x8f, 0xa0, 0xa8, 0x40, 0xea, 0x97, 0x29, 0x89,
+        0x6d, 0xdd, 0x84, 0xc3, 0x64, 0xd6, 0x27, 0x9d, 0x34, 0xa0, 0xb9, 0xd1, 0x47, 0x8f, 0xcf, 0x2a,
+    ],
+    [
+        0x13, 0xe9, 0x7e, 0xd3, 0xc1, 0x6f, 0xad, 0x6c, 0xb5, 0xb7, 0xbb, 0x95, 0x4f, 0x27, 0x8f, 0x24,
+        0x34, 0xb2, 0x2f, 0x8c, 0xc0, 0x54, 0x46, 0x3a, 0x14, 0x5c, 0xbe, 0x02, 0x40, 0xd4, 0xf8, 0xbc,
+    ],
+    [
+        0x05, 0xcd, 0x34, 0x86, 0xdc, 0xa5, 0x8f, 0x4e, 0xb2, 0x21, 0xbc, 0x2c, 0xbe, 0xdb, 0x8a, 0xa3,
+        0x02, 0x7c, 0xde, 0x57, 0x65, 0x97, 0x1b, 0xb1, 0xf6, 0x1c, 0x27, 0x2c, 0x5a, 0xa5, 0x1a, 0xd0,
+    ],
+    [
+        0x00, 0x5c, 0xe5, 0x23, 0xf6, 0xbc, 0x21, 0xda, 0xf1, 0xdf, 0x46, 0x8b, 0x12, 0xfd, 0x3e, 0x86,
+        0x65, 0xe1, 0xc1, 0x69, 0x78, 0x16, 0xa9, 0xa6, 0x71, 0xa3, 0xb2, 0xb6, 0x14, 0x9c, 0x7d, 0xe4,
+    ],
+    [
+        0x21, 0x03, 0x06, 0x6c, 0xc5, 0xdf, 0x55, 0xe6, 0x7c, 0xd7, 0x91, 0x40, 0x28, 0x14, 0x5f, 0xb7,
+        0x1f, 0x08, 0x9d, 0x67, 0x1c, 0x83, 0xc5, 0xb5, 0x37, 0xab, 0xea, 0x48, 0x2b, 0x2d, 0x8c, 0xbb,
+    ],
+    [
+        0x03, 0x9f, 0x80, 0x77, 0xbd, 0x9b, 0x71, 0xf9, 0x1c, 0xa0, 0x50, 0x2e, 0xac, 0xcc, 0x16, 0x72,
+        0xc5, 0x39, 0xf7, 0x23, 0x29, 0x31, 0x7a, 0xaf, 0x81, 0x73, 0xd2, 0xed, 0xd7, 0x63, 0x69, 0x28,
+    ],
+    [
+        0x22, 0x47, 0xbc, 0x0c, 0xc4, 0x37, 0x37, 0x05, 0xb8, 0x96, 0x72, 0xa2, 0x20, 0x53, 0x46, 0xe6,
+        0xaf, 0x3a, 0x64, 0x22, 0xe8, 0x1b, 0x4f, 0x48, 0x85, 0xdc, 0x3a, 0xf7, 0xca, 0xdc, 0xc5, 0xc5,
+    ],
+    [
+        0x27, 0xbc, 0xc7, 0x68, 0xbd, 0x31, 0xb9, 0xd0, 0x06, 0x87, 0xa7, 0xe5, 0x3c, 0x3c, 0xe5, 0x3c,
+        0x42, 0x67, 0x62, 0x70, 0x61, 0x88, 0x79, 0x0e, 0xa2, 0x0b, 0x2e, 0x81, 0x96, 0x38, 0xdf, 0x80,
+    ],
+    [
+        0x0d, 0x91, 0xce, 0xf5, 0x1a, 0x76, 0xf7, 0xa6, 0x2c, 0x51, 0xc7, 0x3a, 0x28, 0x09, 0x73, 0x2d,
+        0x79, 0xac, 0x35, 0x01, 0x59, 0x81, 0x4e, 0xa5, 0x36, 0x48, 0x73, 0x68, 0xcf, 0xdf, 0xd7, 0x08,
+    ],
+    [
+        0x20, 0xa9, 0xec, 0xdc, 0x64, 0x9a, 0x26, 0x38, 0x8d, 0xc6, 0x8d, 0xc0, 0x23, 0x43, 0xa0, 0xf1,
+        0x67, 0xba, 0xd4, 0x6d, 0x6a, 0xa1, 0xcd, 0x00, 0x85, 0x37, 0xf6, 0x27, 0xba, 0x14, 0x07, 0x30,
+    ],
+    [
+        0x23, 0x13, 0x41, 0x49, 0xa0, 0xd4, 0x56, 0x7c, 0x6d, 0xfc, 0x91, 0x1d, 0x5c, 0xb8, 0x0c, 0x1a,
+        0x10, 0x16, 0x22, 0x0d, 0x39, 0xe0, 0xc2, 0x30, 0x32, 0x5d, 0xae, 0x29, 0x41, 0xf5, 0x28, 0xbb,
+    ],
+    [
+        0x2a, 0x07, 0x50, 0x7e, 0x9f, 0x03, 0x0a, 0x42, 0xc6, 0xf2, 0x9c, 0xcf, 0x1c, 0x9c, 0xc4, 0xf3,
+        0xe5, 0xa5, 0xb0, 0xdb, 0xf0, 0x58, 0x74, 0xd5, 0xed, 0xad, 0xc0, 0x3f, 0xe7, 0x4b, 0x94, 0x74,
+    ],
+    [
+        0x1b, 0xdf, 0x66, 0xbb, 0x1d, 0x57, 0xfd, 0x78, 0x8e, 0x54, 0xef, 0x29, 0xf0, 0x6f, 0x35, 0xfd,
+        0xb2, 0x72, 0x9b, 0x71, 0x8c, 0xe8, 0x11, 0x52, 0xcf, 0x3a, 0xaf, 0xc6, 0x49, 0x66, 0x11, 0x0b,
+    ],
+    [
+        0x27, 0x5c, 0x33, 0xd0, 0x7f, 0x9e, 0x73, 0x38, 0xdd, 0xf5, 0x07, 0x83, 0xbf, 0x15, 0x8d, 0x51,
+        0xbe, 0x61, 0x9b, 0xf7, 0x7a, 0x12, 0x02, 0x0c, 0xf5, 0x63, 0x0b, 0x78, 0xe7, 0xe0, 0xd0, 0xb9,
+    ],
+    [
+        0x16, 0x41, 0x15, 0x54, 0xe5, 0x5f, 0xe6, 0x04, 0xa1, 0xff, 0x1b, 0x3b, 0x5b, 0x7a, 0xfb, 0x6a,
+        0x2b, 0xfd, 0x0f, 0x32, 0xc4, 0x07, 0x0b, 0x55, 0xbf, 0x1a, 0xd2, 0x34, 0xe8, 0xb2, 0x81, 0x16,
+    ],
+    [
+        0x07, 0x3e, 0x6a, 0xc7, 0xe6, 0xaf, 0x22, 0x75, 0x57, 0x24, 0x9c, 0xa1, 0xa5, 0xc5, 0x52, 0x22,
+        0xa3, 0x6c, 0x64, 0x1b, 0xc2, 0x85, 0x1a, 0x0d, 0xfc, 0xfb, 0x60, 0x83, 0x1f, 0x67, 0x59, 0xa8,
+    ],
+    [
+        0x10, 0xa6, 0xfd, 0xe8, 0x8c, 0x5f, 0x62, 0x1d, 0x74, 0x85, 0x43, 0xc7, 0x6a, 0x21, 0x8f, 0x95,
+        0xc2, 0x02, 0xbb, 0xc3, 0xba, 0xca, 0x06, 0x83, 0x8f, 0x31, 0x33, 0x1b, 0x05, 0x56, 0xe1, 0x2f,
+    ],
+    [
+        0x29, 0xc5, 0x86, 0x5a, 0x26, 0x58, 0x8c, 0xa8, 0xa8, 0x81, 0x28, 0xa0, 0x72, 0xc8, 0x43, 0x4c,
+        0xc9, 0x7a, 0x1b, 0xce, 0xc2, 0x3e, 0x13, 0x6f, 0x03, 0x8d, 0x29, 0xdc, 0xf9, 0x6b, 0x0e, 0xb6,
+    ],
+    [
+        0x23, 0x3e, 0x0e, 0xf6, 0xad, 0x13, 0x2e, 0xd2, 0x11, 0xb1, 0x91, 0x1c, 0xdd, 0x56, 0x95, 0x76,
+        0xfe, 0xa8, 0xc1, 0x7c, 0xa6, 0x4e, 0x4d, 0x5d, 0xd5, 0x16, 0xfc, 0xca, 0x07, 0x21, 0x2c, 0x1a,
+    ],
+    [
+        0x09, 0x6f, 0x9b, 0x92, 0xb9, 0xcf, 0x2c, 0x7d, 0x66, 0x44, 0x08, 0xa2, 0x98, 0x60, 0x36, 0x5f,
+        0x5b, 0x6a, 0xdc, 0x23, 0x8d, 0x87, 0x8a, 0x47, 0x6b, 0xde, 0xd3, 0xe4, 0xd2, 0xe0, 0xb8, 0x40,
+    ],
+    [
+        0x2f, 0xc7, 0x88, 0xfe, 0x8a, 0xad, 0x4c, 0x28, 0xb9, 0x43, 0xbb, 0x57, 0xcb, 0x90, 0xed, 0xf6,
+        0xd7, 0xb1, 0xf1, 0xe8, 0x35, 0xde, 0x1f, 0xbf, 0x31, 0x92, 0xe3, 0x95, 0xdb, 0x78, 0x55, 0x54,
+    ],
+    [
+        0x1b, 0xac, 0x0e, 0xd6, 0x09, 0x23, 0x31, 0x48, 0x50, 0x37, 0x95, 0xfc, 0x5e, 0x11, 0x40, 0x76,
+        0xef, 0x7c, 0x9d, 0x72, 0x2b, 0xc6, 0x48, 0xdd, 0x03, 0x38, 0xd9, 0x68, 0x9f, 0x6e, 0x02, 0x3a,
+    ],
+    [
+        0x00, 0x1c, 0x35, 0x26, 0x8a, 0xa0, 0x30, 0xd6, 0xcd, 0x14, 0x8e, 0x10, 0x6e, 0x3f, 0xc2, 0x30,
+        0x94, 0xe5, 0x61, 0x57, 0x3d, 0x8c, 0x72, 0xa6, 0x9f, 0xc3, 0x83, 0x64, 0x3e, 0x2a, 0xdc, 0x82,
+    ],
+    [
+        0x2b, 0xb1, 0xf8, 0xfe, 0x5d, 0x99, 0x8d, 0x0b, 0x8b, 0x92, 0x83, 0x7f, 0xfb, 0x27, 0xbf, 0xe7,
+        0xa3, 0x9c, 0xe3, 0x9f, 0xf9, 0x4f, 0xc1, 0x67, 0xdd, 0xcc, 0x95, 0xa7, 0x20, 0x2e, 0x34, 0xb2,
+    ],
+    [
+        0x1a, 0x98, 0x57, 0x64, 0x50, 0xa4, 0x3e, 0x5e, 0x70, 0x5b, 0x9a, 0xf6, 0x8c, 0xd0, 0xc1, 0x45,
+        0x42, 0x2d, 0xa2, 0x39, 0x45, 0x63, 0x0b, 0x10, 0xb8, 0xe8, 0x21, 0xdd, 0x61, 0xf9, 0xa2, 0xb2,
+    ],
+    [
+        0x27, 0x14, 0xd3, 0x7e, 0xe5, 0x06, 0xef, 0xbc, 0x2b, 0xa9, 0x94, 0x10, 0x43, 0xfb, 0x23, 0xf1,
+        0x6a, 0xf9, 0x66, 0xf1, 0x23, 0xd6, 0x53, 0xf3, 0x83, 0x88, 0xbc, 0xbd, 0xd5, 0x0e, 0x60, 0x37,
+    ],
+    [
+        0x17, 0x5d, 0x04, 0x80, 0xba, 0x14, 0xab, 0xf0, 0xbc, 0xb8, 0x07, 0x5a, 0x03, 0xf4, 0xa2, 0xa0,
+        0x1f, 0xf7, 0xed, 0x75, 0x41, 0x39, 0x17, 0xb5, 0x3e, 0x50, 0xea, 0x00, 0xcc, 0x2a, 0x4a, 0xd0,
+    ],
+    [
+        0x20, 0x3d, 0xe4, 0x5a, 0x03, 0x7b, 0x75, 0xef, 0x63, 0x76, 0x17, 0x50, 0xdc, 0xe0, 0x8d, 0xa2,
+        0xbe, 0x77, 0x09, 0x70, 0x1e, 0xc8, 0x15, 0x8c, 0x17, 0xfc, 0x02, 0x6f, 0xd0, 0x84, 0xd5, 0x41,
+    ],
+    [
+        0x26, 0x5a, 0xab, 0xfc, 0x78, 0xb7, 0x0c, 0xa9, 0x85, 0xc3, 0x29, 0x08, 0x0d, 0x63, 0x29, 0xa1,
+        0x25, 0x60, 0xf7, 0x6b, 0x01, 0xcc, 0x3d, 0x43, 0x1a, 0x72, 0x83, 0x02, 0x21, 0x93, 0xab, 0xf8,
+    ],
+    [
+        0x13, 0x54, 0x68, 0xdf, 0xbe, 0xb7, 0x0e, 0x8c, 0x2e, 0xda, 0xff, 0xac, 0x86, 0x58, 0xa6, 0xc2,
+        0x2b, 0x2a, 0xd1, 0x52, 0x0a, 0xc0, 0x81, 0x9c, 0x12, 0x7a, 0xf2, 0xea, 0x5f, 0x3a, 0x87, 0xa8,
+    ],
+    [
+        0x21, 0x6a, 0x41, 0xe2, 0x43, 0x29, 0xde, 0xda, 0x65, 0xa8, 0xa8, 0x20, 0x01, 0xc8, 0x4c, 0xe8,
+        0x28, 0x6c, 0x61, 0x75, 0x84, 0x47, 0xff, 0x1b, 0xfa, 0x9d, 0x92, 0x93, 0xb6, 0x71, 0x1f, 0xdd,
+    ],
+    [
+        0x28, 0x87, 0xed, 0x49, 0x79, 0x7b, 0xd6, 0x65, 0x2b, 0xed, 0x8b, 0xb9, 0x9a, 0xe3, 0x45, 0xad,
+        0x70, 0xd1, 0xa2, 0x7c, 0xdb, 0x21, 0xb9, 0x46, 0x0b, 0xb6, 0xfd, 0x0d, 0x4f, 0xe4, 0x82, 0xe6,
+    ],
+    [
+        0x03, 0xe2, 0xbf, 0x82, 0x69, 0x0c, 0x80, 0x4b, 0xb6, 0xee, 0x3c, 0x79, 0xda, 0xbd, 0x18, 0x92,
+        0xea, 0x53, 0xa9, 0x81, 0x1e, 0x24, 0x24, 0xa1, 0x9f, 0xae, 0x44, 0x6b, 0x8a, 0xe4, 0x9c, 0xad,
+    ],
+    [
+        0x0e, 0x6d, 0xce, 0x1b, 0xbe, 0x6e, 0x9e, 0x46, 0x5c, 0xbe, 0x14, 0xdc, 0xc6, 0x15, 0x61, 0x18,
+        0x67, 0x41, 0x46, 0x76, 0xdd, 0x8a, 0x8c, 0xe9, 0x94, 0x66, 0x49, 0xb1, 0xc4, 0xe8, 0x11, 0x16,
+    ],
+];
@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+# Lint and test every deployable settlement build
+#
+# Deployments pick the settlement core plus a subset of its features, so
+# the core is checked on its own and once per feature on top of it, the
+# same builds check-wasm-size.sh measures.
+set -euo pipefail
+
+cd "$(dirname "$0")/.."
+
+# Settlement imports the registry and verifier Wasm, so they go first
+cargo build --quiet --target wasm32v1-none --release -p darkpool-registry -p groth16-verifier-bn254
+
+check() {
+    local name=$1
+    shift
+    echo "==    darkpool-settlement ($name)"
+    cargo clippy --quiet -p darkpool-settlement --all-targets --no-default-features "$@" -- -D warnings
+    cargo test --quiet -p darkpool-settlement --no-default-features "$@"
+}
+
+check core
+
+features=$(sed -n '/^default = \[/,/^\]/p' contracts/settlement/Cargo.toml | grep -o '"[a-z-]*"' | tr -d '"')
+for feature in $features; do
+    check "$feature" --features "$feature"
+done
@@ -0,0 +1,46 @@
+#!/usr/bin/env bash
+# Fail if any deployable contract Wasm exceeds the network's 128 KiB limit
+#
+# Builds every contract, then the settlement contract once on its own and
+# once per feature on top of its core: no build with every settlement
+# feature fits, so deployments pick a subset.
+set -euo pipefail
+
+LIMIT=131072
+OUT=target/wasm32v1-none/release
+
+cd "$(dirname "$0")/.."
+
+failed=0
+check() {
+    local name=$1 size
+    size=$(wc -c < "$2")
+    if [ "$size" -gt "$LIMIT" ]; then
+        echo "FAIL  $name: $size bytes (limit $LIMIT)"
+        failed=1
+    else
+        echo "ok    $name: $size bytes"
+    fi
+}
+
+build() {
+    cargo build --quiet --target wasm32v1-none --release "$@"
+}
+
+# Settlement imports the registry and verifier Wasm, so they go first
+for package in darkpool-registry groth16-verifier-bn254 darkpool-commitments darkpool-orderbook \
+    darkpool-trading-account darkpool-treasury; do
+    build -p "$package"
+    check "$package" "$OUT/${package//-/_}.wasm"
+done
+
+build -p darkpool-settlement --no-default-features
+check "darkpool-settlement (core)" "$OUT/darkpool_settlement.wasm"
+
+features=$(sed -n '/^default = \[/,/^\]/p' contracts/settlement/Cargo.toml | grep -o '"[a-z-]*"' | tr -d '"')
+for feature in $features; do
+    build -p darkpool-settlement --no-default-features --features "$feature"
+    check "darkpool-settlement ($feature)" "$OUT/darkpool_settlement.wasm"
+done
+
+exit $failed
@@ -176,6 +176,8 @@ async function main() {
   console.log("For a real test, you need to either:");
   console.log("  1. Register participants in the registry with matching ID hashes");
   console.log("  2. Or temporarily modify the settlement contract to skip whitelist check\n");
+  console.log("The payment asset must also be allowed by the admin first:");
+  console.log(`  stellar contract invoke --id ${CONTRACTS.settlement} --source-account admin --network testnet -- add_payment_asset --admin <ADMIN> --asset_address ${XLM_SAC}\n`);
 
   console.log("Settlement command (will fail if whitelist root mismatch):\n");
   console.log(`stellar contract invoke \\
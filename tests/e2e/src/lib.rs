@@ -0,0 +1,8 @@
+//! End-to-end flows across the registry, orderbook, settlement and verifier
+//!
+//! All contracts are registered in one Soroban test environment and driven
+//! through their public clients only, so these tests catch interface and
+//! cross-contract regressions that the per-contract unit tests mock away.
+
+#[cfg(test)]
+mod test;
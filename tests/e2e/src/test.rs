@@ -0,0 +1,248 @@
+#![cfg(test)]
+
+use darkpool_orderbook::{DarkPoolOrderbook, DarkPoolOrderbookClient, OrderSide, OrderStatus};
+use darkpool_registry::{
+    AssetType, DarkPoolRegistry, DarkPoolRegistryClient, Participant, ParticipantCategory, RWAAsset,
+};
+use darkpool_settlement::{DarkPoolSettlement, DarkPoolSettlementClient, SettlementError};
+use darkpool_testdata::{generate, scalar};
+use groth16_verifier_bn254::Groth16VerifierBN254;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Bytes, BytesN, Env, Symbol,
+};
+
+/// Seed for the settlement circuit's verification key
+const VK_SEED: u64 = 2024;
+const QUANTITY: i128 = 100;
+const PRICE: i128 = 5_000;
+
+/// All pool contracts deployed side by side
+struct Pool<'a> {
+    env: &'a Env,
+    admin: Address,
+    registry: DarkPoolRegistryClient<'a>,
+    orderbook: DarkPoolOrderbookClient<'a>,
+    settlement: DarkPoolSettlementClient<'a>,
+    bond: Address,
+    usdc: Address,
+}
+
+impl<'a> Pool<'a> {
+    fn deploy(env: &'a Env) -> Self {
+        // Whitelist insertions hash a depth-20 tree; cost is covered by the
+        // unit tests, these flows only check behaviour
+        env.cost_estimate().budget().reset_unlimited();
+        let admin = Address::generate(env);
+        let vk = Bytes::from_slice(env, &generate(VK_SEED, &signals(0, &[0u8; 32])).vk);
+
+        let verifier = env.register(Groth16VerifierBN254, ());
+        let registry = env.register(DarkPoolRegistry, (&admin, &verifier, &vk));
+        let settlement = env.register(DarkPoolSettlement, (&admin, &registry, &verifier, &vk));
+        let orderbook = env.register(DarkPoolOrderbook, (&admin, &registry, &settlement));
+
+        let bond = env.register_stellar_asset_contract_v2(admin.clone()).address();
+        let usdc = env.register_stellar_asset_contract_v2(admin.clone()).address();
+
+        let pool = Pool {
+            env,
+            admin,
+            registry: DarkPoolRegistryClient::new(env, &registry),
+            orderbook: DarkPoolOrderbookClient::new(env, &orderbook),
+            settlement: DarkPoolSettlementClient::new(env, &settlement),
+            bond,
+            usdc,
+        };
+        pool.registry.register_asset(
+            &pool.admin,
+            &RWAAsset {
+                token_address: pool.bond.clone(),
+                symbol: Symbol::new(env, "TBOND25"),
+                asset_type: AssetType::TreasuryBond,
+                min_trade_size: 1,
+                max_order_size: 1_000_000,
+                is_active: true,
+            },
+        );
+        pool.settlement.add_payment_asset(&pool.admin, &pool.usdc);
+        pool.settlement.set_whitelist_check(&pool.admin, &true);
+        pool
+    }
+
+    /// Whitelist a new trader and fund them with both tokens
+    fn onboard(&self, id: u8) -> Address {
+        let trader = Address::generate(self.env);
+        self.registry.register_participant(
+            &self.admin,
+            &Participant {
+                id_hash: BytesN::from_array(self.env, &[id; 32]),
+                trading_address: trader.clone(),
+                category: ParticipantCategory::AssetManager,
+                kyc_expiry: self.env.ledger().timestamp() + 31_536_000,
+                is_active: true,
+                tree_index: 0,
+            },
+        );
+        StellarAssetClient::new(self.env, &self.bond).mint(&trader, &1_000);
+        StellarAssetClient::new(self.env, &self.usdc).mint(&trader, &50_000);
+        trader
+    }
+
+    /// Deposit, lock and place matching buy and sell orders, then record the match
+    fn match_orders(&self, buyer: &Address, seller: &Address, id: u8) -> BytesN<32> {
+        let buy = BytesN::from_array(self.env, &[id; 32]);
+        let sell = BytesN::from_array(self.env, &[id + 1; 32]);
+        let match_id = BytesN::from_array(self.env, &[id + 2; 32]);
+
+        self.orderbook
+            .deposit_for_order(buyer, &buy, &self.bond, &OrderSide::Buy, &self.usdc, &PRICE, &3_600);
+        self.orderbook
+            .deposit_for_order(seller, &sell, &self.bond, &OrderSide::Sell, &self.bond, &QUANTITY, &3_600);
+        self.orderbook.record_match(
+            &self.admin,
+            &match_id,
+            &buy,
+            &sell,
+            &self.bond,
+            buyer,
+            seller,
+            &QUANTITY,
+            &PRICE,
+        );
+        match_id
+    }
+
+    fn settle(
+        &self,
+        match_id: &BytesN<32>,
+        buyer: &Address,
+        seller: &Address,
+        proof: &darkpool_testdata::ProofFixture,
+    ) -> Option<SettlementError> {
+        self.settlement
+            .try_settle_trade(
+                match_id,
+                buyer,
+                seller,
+                &self.bond,
+                &self.usdc,
+                &QUANTITY,
+                &PRICE,
+                &Bytes::from_slice(self.env, &proof.proof),
+                &Bytes::from_slice(self.env, &proof.signals),
+            )
+            .err()
+            .map(|e| e.unwrap())
+    }
+
+    fn root(&self) -> [u8; 32] {
+        self.registry.get_whitelist_root().to_array()
+    }
+}
+
+/// Settlement signals: nullifier, commitments, asset hash, quantity, price, root
+fn signals(nullifier: u64, root: &[u8; 32]) -> [[u8; 32]; 7] {
+    [
+        scalar(nullifier),
+        scalar(11),
+        scalar(12),
+        scalar(13),
+        scalar(QUANTITY as u64),
+        scalar(PRICE as u64),
+        *root,
+    ]
+}
+
+fn proof(nullifier: u64, root: &[u8; 32]) -> darkpool_testdata::ProofFixture {
+    generate(VK_SEED, &signals(nullifier, root))
+}
+
+#[test]
+fn test_onboard_trade_settle_withdraw() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let pool = Pool::deploy(&env);
+
+    let buyer = pool.onboard(1);
+    let seller = pool.onboard(2);
+    assert!(pool.registry.is_participant_eligible(&buyer));
+    assert_eq!(pool.registry.get_whitelist_count(), 2);
+
+    // Orders lock escrow in settlement through the orderbook
+    let match_id = pool.match_orders(&buyer, &seller, 10);
+    assert_eq!(pool.settlement.get_locked_balance(&buyer, &pool.usdc), PRICE);
+    assert_eq!(pool.settlement.get_locked_balance(&seller, &pool.bond), QUANTITY);
+    assert_eq!(pool.orderbook.get_pending_matches().len(), 1);
+
+    // The proof commits to the registry's current whitelist root
+    assert_eq!(pool.settle(&match_id, &buyer, &seller, &proof(1, &pool.root())), None);
+    pool.orderbook.mark_settled(&pool.admin, &match_id);
+    assert!(pool.orderbook.get_pending_matches().is_empty());
+    let buy_order = pool.orderbook.get_order(&BytesN::from_array(&env, &[10u8; 32])).unwrap();
+    assert_eq!(buy_order.status, OrderStatus::Settled);
+
+    let record = pool.settlement.get_settlement(&None, &match_id).unwrap();
+    assert_eq!(record.quantity, QUANTITY);
+    assert_eq!(pool.settlement.get_verification_stats().total, 1);
+
+    // Proceeds can be withdrawn back to the traders' wallets
+    pool.settlement.withdraw(&buyer, &pool.bond, &QUANTITY);
+    pool.settlement.withdraw(&seller, &pool.usdc, &PRICE);
+    assert_eq!(TokenClient::new(&env, &pool.bond).balance(&buyer), 1_000 + QUANTITY);
+    assert_eq!(TokenClient::new(&env, &pool.usdc).balance(&seller), 50_000 + PRICE);
+    assert_eq!(TokenClient::new(&env, &pool.bond).balance(&pool.settlement.address), 0);
+    assert_eq!(TokenClient::new(&env, &pool.usdc).balance(&pool.settlement.address), 0);
+}
+
+#[test]
+fn test_settlement_failure_paths() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let pool = Pool::deploy(&env);
+
+    let buyer = pool.onboard(1);
+    let seller = pool.onboard(2);
+    let match_id = pool.match_orders(&buyer, &seller, 10);
+    let root = pool.root();
+
+    // Locked order funds cannot be withdrawn
+    assert_eq!(
+        pool.settlement.try_withdraw(&buyer, &pool.usdc, &1).err(),
+        Some(Ok(SettlementError::InsufficientBalance))
+    );
+
+    // A root the registry never published is rejected
+    assert_eq!(
+        pool.settle(&match_id, &buyer, &seller, &proof(1, &[7u8; 32])),
+        Some(SettlementError::WhitelistRootMismatch)
+    );
+
+    // A proof that does not match its signals fails in the verifier
+    let mut forged = proof(1, &root);
+    forged.signals = proof(2, &root).signals;
+    assert_eq!(pool.settle(&match_id, &buyer, &seller, &forged), Some(SettlementError::InvalidProof));
+
+    // Onboarding moves the root on; once time passes the old one is stale
+    pool.onboard(3);
+    env.ledger().with_mut(|li| li.timestamp += 60);
+    assert_eq!(
+        pool.settle(&match_id, &buyer, &seller, &proof(1, &root)),
+        Some(SettlementError::WhitelistRootStale)
+    );
+
+    // Nothing moved during the failed attempts
+    assert_eq!(pool.settlement.get_locked_balance(&seller, &pool.bond), QUANTITY);
+    assert!(pool.settlement.get_settlement(&None, &match_id).is_none());
+
+    let current = pool.root();
+    assert_eq!(pool.settle(&match_id, &buyer, &seller, &proof(1, &current)), None);
+
+    // The nullifier cannot be reused for another match
+    let second = pool.match_orders(&buyer, &seller, 20);
+    assert_eq!(
+        pool.settle(&second, &buyer, &seller, &proof(1, &current)),
+        Some(SettlementError::NullifierUsed)
+    );
+    assert_eq!(pool.settle(&second, &buyer, &seller, &proof(2, &current)), None);
+}